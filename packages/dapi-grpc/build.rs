@@ -12,20 +12,20 @@ fn main() {
 
 /// Generate Rust definitions from Protobuf definitions
 pub fn generate() -> Result<(), std::io::Error> {
-    // Mapping between protobuf files => output directory
-    let mut input = HashMap::<PathBuf, PathBuf>::new();
+    // Mapping between protobuf files => output directory and whether messages derive serde traits
+    let mut input = HashMap::<PathBuf, (PathBuf, bool)>::new();
     input.insert(
         PathBuf::from("protos/core/v0/core.proto"),
-        PathBuf::from("src/core/proto"),
+        (PathBuf::from("src/core/proto"), false),
     );
     input.insert(
         PathBuf::from("protos/platform/v0/platform.proto"),
-        PathBuf::from("src/platform/proto"),
+        (PathBuf::from("src/platform/proto"), true),
     );
 
     let proto_includes = vec![abs_path(&PathBuf::from("protos"))];
 
-    for (proto, dest) in input {
+    for (proto, (dest, serde)) in input {
         let proto = abs_path(&proto);
         let dest = abs_path(&dest);
         // Remove old compiled files; ignore errors
@@ -34,12 +34,54 @@ pub fn generate() -> Result<(), std::io::Error> {
         }
         create_dir_all(&dest)?;
 
-        generate1(&[proto], &proto_includes, &dest)?;
+        generate1(&[proto], &proto_includes, &dest, serde)?;
     }
 
     Ok(())
 }
 
+/// Bytes fields of platform messages, encoded as base64 strings by serde
+const BYTES_FIELDS: &[&str] = &[
+    "block_id_hash",
+    "contest_id",
+    "contract_id",
+    "data",
+    "data_contract",
+    "data_contract_id",
+    "document_id",
+    "grovedb_proof",
+    "id",
+    "identity",
+    "identity_id",
+    "key",
+    "order_by",
+    "owner_id",
+    "public_key_hash",
+    "quorum_hash",
+    "signature",
+    "start_after",
+    "start_at",
+    "state_transition",
+    "state_transition_hash",
+    "transaction_id",
+    "where",
+    "IdentityValue.value",
+    "PublicKey.value",
+    "DataContractValue.value",
+    "DataContractHistoryEntry.value",
+    "DocumentHistoryEntry.value",
+];
+
+/// Repeated bytes fields of platform messages, encoded as arrays of base64 strings by serde
+const REPEATED_BYTES_FIELDS: &[&str] = &[
+    "identity_ids",
+    "ids",
+    "keys_bytes",
+    "public_key_hashes",
+    "GetDocumentsResponse.Documents.documents",
+    "GetIdentitiesByPublicKeyHashesResponse.Identities.identities",
+];
+
 /// Run single generation process.
 ///
 /// All paths must be absolute
@@ -47,11 +89,39 @@ fn generate1(
     files: &[PathBuf],
     proto_includes: &[PathBuf],
     out_dir: &PathBuf,
+    serde: bool,
 ) -> Result<(), std::io::Error> {
-    let pb = tonic_build::configure()
+    let mut pb = tonic_build::configure()
         .build_server(false)
         .out_dir(out_dir)
         .protoc_arg("--experimental_allow_proto3_optional");
+
+    // Serde traits are derived only with the `serde` feature of this crate
+    if serde {
+        pb = pb
+            .type_attribute(
+                ".",
+                r#"#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]"#,
+            )
+            .type_attribute(
+                ".",
+                r#"#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]"#,
+            );
+
+        for field in BYTES_FIELDS {
+            pb = pb.field_attribute(
+                field,
+                r#"#[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]"#,
+            );
+        }
+
+        for field in REPEATED_BYTES_FIELDS {
+            pb = pb.field_attribute(
+                field,
+                r#"#[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes_vec"))]"#,
+            );
+        }
+    }
     #[cfg(feature = "client")]
     let pb = pb.build_client(true).build_transport(true);
     #[cfg(not(feature = "client"))]
@@ -356,6 +356,161 @@ public final class PlatformGrpc {
     return getGetDocumentsMethod;
   }
 
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> getGetDocumentHistoryMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getDocumentHistory",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> getGetDocumentHistoryMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> getGetDocumentHistoryMethod;
+    if ((getGetDocumentHistoryMethod = PlatformGrpc.getGetDocumentHistoryMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetDocumentHistoryMethod = PlatformGrpc.getGetDocumentHistoryMethod) == null) {
+          PlatformGrpc.getGetDocumentHistoryMethod = getGetDocumentHistoryMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getDocumentHistory"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getDocumentHistory"))
+              .build();
+        }
+      }
+    }
+    return getGetDocumentHistoryMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> getGetDomainCanonicalOwnerMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getDomainCanonicalOwner",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> getGetDomainCanonicalOwnerMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> getGetDomainCanonicalOwnerMethod;
+    if ((getGetDomainCanonicalOwnerMethod = PlatformGrpc.getGetDomainCanonicalOwnerMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetDomainCanonicalOwnerMethod = PlatformGrpc.getGetDomainCanonicalOwnerMethod) == null) {
+          PlatformGrpc.getGetDomainCanonicalOwnerMethod = getGetDomainCanonicalOwnerMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getDomainCanonicalOwner"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getDomainCanonicalOwner"))
+              .build();
+        }
+      }
+    }
+    return getGetDomainCanonicalOwnerMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> getGetContestedResourceVoteTallyMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getContestedResourceVoteTally",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> getGetContestedResourceVoteTallyMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> getGetContestedResourceVoteTallyMethod;
+    if ((getGetContestedResourceVoteTallyMethod = PlatformGrpc.getGetContestedResourceVoteTallyMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetContestedResourceVoteTallyMethod = PlatformGrpc.getGetContestedResourceVoteTallyMethod) == null) {
+          PlatformGrpc.getGetContestedResourceVoteTallyMethod = getGetContestedResourceVoteTallyMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getContestedResourceVoteTally"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getContestedResourceVoteTally"))
+              .build();
+        }
+      }
+    }
+    return getGetContestedResourceVoteTallyMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> getEstimateStateTransitionFeeMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "estimateStateTransitionFee",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> getEstimateStateTransitionFeeMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest, org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> getEstimateStateTransitionFeeMethod;
+    if ((getEstimateStateTransitionFeeMethod = PlatformGrpc.getEstimateStateTransitionFeeMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getEstimateStateTransitionFeeMethod = PlatformGrpc.getEstimateStateTransitionFeeMethod) == null) {
+          PlatformGrpc.getEstimateStateTransitionFeeMethod = getEstimateStateTransitionFeeMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest, org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "estimateStateTransitionFee"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("estimateStateTransitionFee"))
+              .build();
+        }
+      }
+    }
+    return getEstimateStateTransitionFeeMethod;
+  }
+
+  private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> getGetIdentityWithdrawalsMethod;
+
+  @io.grpc.stub.annotations.RpcMethod(
+      fullMethodName = SERVICE_NAME + '/' + "getIdentityWithdrawals",
+      requestType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest.class,
+      responseType = org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse.class,
+      methodType = io.grpc.MethodDescriptor.MethodType.UNARY)
+  public static io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest,
+      org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> getGetIdentityWithdrawalsMethod() {
+    io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> getGetIdentityWithdrawalsMethod;
+    if ((getGetIdentityWithdrawalsMethod = PlatformGrpc.getGetIdentityWithdrawalsMethod) == null) {
+      synchronized (PlatformGrpc.class) {
+        if ((getGetIdentityWithdrawalsMethod = PlatformGrpc.getGetIdentityWithdrawalsMethod) == null) {
+          PlatformGrpc.getGetIdentityWithdrawalsMethod = getGetIdentityWithdrawalsMethod =
+              io.grpc.MethodDescriptor.<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest, org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse>newBuilder()
+              .setType(io.grpc.MethodDescriptor.MethodType.UNARY)
+              .setFullMethodName(generateFullMethodName(SERVICE_NAME, "getIdentityWithdrawals"))
+              .setSampledToLocalTracing(true)
+              .setRequestMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest.getDefaultInstance()))
+              .setResponseMarshaller(io.grpc.protobuf.ProtoUtils.marshaller(
+                  org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse.getDefaultInstance()))
+              .setSchemaDescriptor(new PlatformMethodDescriptorSupplier("getIdentityWithdrawals"))
+              .build();
+        }
+      }
+    }
+    return getGetIdentityWithdrawalsMethod;
+  }
+
   private static volatile io.grpc.MethodDescriptor<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesRequest,
       org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesResponse> getGetIdentitiesByPublicKeyHashesMethod;
 
@@ -608,6 +763,41 @@ public final class PlatformGrpc {
       io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetDocumentsMethod(), responseObserver);
     }
 
+    /**
+     */
+    public void getDocumentHistory(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetDocumentHistoryMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getDomainCanonicalOwner(org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetDomainCanonicalOwnerMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getContestedResourceVoteTally(org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetContestedResourceVoteTallyMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void estimateStateTransitionFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getEstimateStateTransitionFeeMethod(), responseObserver);
+    }
+
+    /**
+     */
+    public void getIdentityWithdrawals(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> responseObserver) {
+      io.grpc.stub.ServerCalls.asyncUnimplementedUnaryCall(getGetIdentityWithdrawalsMethod(), responseObserver);
+    }
+
     /**
      */
     public void getIdentitiesByPublicKeyHashes(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesRequest request,
@@ -715,6 +905,41 @@ public final class PlatformGrpc {
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsRequest,
                 org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsResponse>(
                   this, METHODID_GET_DOCUMENTS)))
+          .addMethod(
+            getGetDocumentHistoryMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse>(
+                  this, METHODID_GET_DOCUMENT_HISTORY)))
+          .addMethod(
+            getGetDomainCanonicalOwnerMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse>(
+                  this, METHODID_GET_DOMAIN_CANONICAL_OWNER)))
+          .addMethod(
+            getGetContestedResourceVoteTallyMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse>(
+                  this, METHODID_GET_CONTESTED_RESOURCE_VOTE_TALLY)))
+          .addMethod(
+            getEstimateStateTransitionFeeMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse>(
+                  this, METHODID_ESTIMATE_STATE_TRANSITION_FEE)))
+          .addMethod(
+            getGetIdentityWithdrawalsMethod(),
+            io.grpc.stub.ServerCalls.asyncUnaryCall(
+              new MethodHandlers<
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest,
+                org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse>(
+                  this, METHODID_GET_IDENTITY_WITHDRAWALS)))
           .addMethod(
             getGetIdentitiesByPublicKeyHashesMethod(),
             io.grpc.stub.ServerCalls.asyncUnaryCall(
@@ -852,6 +1077,46 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetDocumentsMethod(), getCallOptions()), request, responseObserver);
     }
 
+    /**
+     */
+    public void getDocumentHistory(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetDocumentHistoryMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getDomainCanonicalOwner(org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetDomainCanonicalOwnerMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getContestedResourceVoteTally(org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetContestedResourceVoteTallyMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void estimateStateTransitionFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getEstimateStateTransitionFeeMethod(), getCallOptions()), request, responseObserver);
+    }
+
+    /**
+     */
+    public void getIdentityWithdrawals(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest request,
+        io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> responseObserver) {
+      io.grpc.stub.ClientCalls.asyncUnaryCall(
+          getChannel().newCall(getGetIdentityWithdrawalsMethod(), getCallOptions()), request, responseObserver);
+    }
+
     /**
      */
     public void getIdentitiesByPublicKeyHashes(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesRequest request,
@@ -979,6 +1244,41 @@ public final class PlatformGrpc {
           getChannel(), getGetDocumentsMethod(), getCallOptions(), request);
     }
 
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse getDocumentHistory(org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetDocumentHistoryMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse getDomainCanonicalOwner(org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetDomainCanonicalOwnerMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse getContestedResourceVoteTally(org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetContestedResourceVoteTallyMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse estimateStateTransitionFee(org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getEstimateStateTransitionFeeMethod(), getCallOptions(), request);
+    }
+
+    /**
+     */
+    public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse getIdentityWithdrawals(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest request) {
+      return io.grpc.stub.ClientCalls.blockingUnaryCall(
+          getChannel(), getGetIdentityWithdrawalsMethod(), getCallOptions(), request);
+    }
+
     /**
      */
     public org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesResponse getIdentitiesByPublicKeyHashes(org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesRequest request) {
@@ -1113,6 +1413,46 @@ public final class PlatformGrpc {
           getChannel().newCall(getGetDocumentsMethod(), getCallOptions()), request);
     }
 
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse> getDocumentHistory(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetDocumentHistoryMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse> getDomainCanonicalOwner(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetDomainCanonicalOwnerMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse> getContestedResourceVoteTally(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetContestedResourceVoteTallyMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse> estimateStateTransitionFee(
+        org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getEstimateStateTransitionFeeMethod(), getCallOptions()), request);
+    }
+
+    /**
+     */
+    public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse> getIdentityWithdrawals(
+        org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest request) {
+      return io.grpc.stub.ClientCalls.futureUnaryCall(
+          getChannel().newCall(getGetIdentityWithdrawalsMethod(), getCallOptions()), request);
+    }
+
     /**
      */
     public com.google.common.util.concurrent.ListenableFuture<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesResponse> getIdentitiesByPublicKeyHashes(
@@ -1157,10 +1497,15 @@ public final class PlatformGrpc {
   private static final int METHODID_GET_DATA_CONTRACT_HISTORY = 8;
   private static final int METHODID_GET_DATA_CONTRACTS = 9;
   private static final int METHODID_GET_DOCUMENTS = 10;
-  private static final int METHODID_GET_IDENTITIES_BY_PUBLIC_KEY_HASHES = 11;
-  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASHES = 12;
-  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 13;
-  private static final int METHODID_GET_CONSENSUS_PARAMS = 14;
+  private static final int METHODID_GET_DOCUMENT_HISTORY = 11;
+  private static final int METHODID_GET_DOMAIN_CANONICAL_OWNER = 12;
+  private static final int METHODID_GET_CONTESTED_RESOURCE_VOTE_TALLY = 13;
+  private static final int METHODID_ESTIMATE_STATE_TRANSITION_FEE = 14;
+  private static final int METHODID_GET_IDENTITY_WITHDRAWALS = 15;
+  private static final int METHODID_GET_IDENTITIES_BY_PUBLIC_KEY_HASHES = 16;
+  private static final int METHODID_GET_IDENTITY_BY_PUBLIC_KEY_HASHES = 17;
+  private static final int METHODID_WAIT_FOR_STATE_TRANSITION_RESULT = 18;
+  private static final int METHODID_GET_CONSENSUS_PARAMS = 19;
 
   private static final class MethodHandlers<Req, Resp> implements
       io.grpc.stub.ServerCalls.UnaryMethod<Req, Resp>,
@@ -1223,6 +1568,26 @@ public final class PlatformGrpc {
           serviceImpl.getDocuments((org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentsResponse>) responseObserver);
           break;
+        case METHODID_GET_DOCUMENT_HISTORY:
+          serviceImpl.getDocumentHistory((org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDocumentHistoryResponse>) responseObserver);
+          break;
+        case METHODID_GET_DOMAIN_CANONICAL_OWNER:
+          serviceImpl.getDomainCanonicalOwner((org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetDomainCanonicalOwnerResponse>) responseObserver);
+          break;
+        case METHODID_GET_CONTESTED_RESOURCE_VOTE_TALLY:
+          serviceImpl.getContestedResourceVoteTally((org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetContestedResourceVoteTallyResponse>) responseObserver);
+          break;
+        case METHODID_ESTIMATE_STATE_TRANSITION_FEE:
+          serviceImpl.estimateStateTransitionFee((org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.EstimateStateTransitionFeeResponse>) responseObserver);
+          break;
+        case METHODID_GET_IDENTITY_WITHDRAWALS:
+          serviceImpl.getIdentityWithdrawals((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsRequest) request,
+              (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentityWithdrawalsResponse>) responseObserver);
+          break;
         case METHODID_GET_IDENTITIES_BY_PUBLIC_KEY_HASHES:
           serviceImpl.getIdentitiesByPublicKeyHashes((org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesRequest) request,
               (io.grpc.stub.StreamObserver<org.dash.platform.dapi.v0.PlatformOuterClass.GetIdentitiesByPublicKeyHashesResponse>) responseObserver);
@@ -1311,6 +1676,11 @@ public final class PlatformGrpc {
               .addMethod(getGetDataContractHistoryMethod())
               .addMethod(getGetDataContractsMethod())
               .addMethod(getGetDocumentsMethod())
+              .addMethod(getGetDocumentHistoryMethod())
+              .addMethod(getGetDomainCanonicalOwnerMethod())
+              .addMethod(getGetContestedResourceVoteTallyMethod())
+              .addMethod(getEstimateStateTransitionFeeMethod())
+              .addMethod(getGetIdentityWithdrawalsMethod())
               .addMethod(getGetIdentitiesByPublicKeyHashesMethod())
               .addMethod(getGetIdentityByPublicKeyHashesMethod())
               .addMethod(getWaitForStateTransitionResultMethod())
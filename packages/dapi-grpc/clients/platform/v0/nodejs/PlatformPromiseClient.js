@@ -34,12 +34,22 @@ const {
             GetDataContractHistoryResponse: PBJSGetDataContractHistoryResponse,
             GetDocumentsRequest: PBJSGetDocumentsRequest,
             GetDocumentsResponse: PBJSGetDocumentsResponse,
+            GetDocumentHistoryRequest: PBJSGetDocumentHistoryRequest,
+            GetDocumentHistoryResponse: PBJSGetDocumentHistoryResponse,
+            GetDomainCanonicalOwnerRequest: PBJSGetDomainCanonicalOwnerRequest,
+            GetDomainCanonicalOwnerResponse: PBJSGetDomainCanonicalOwnerResponse,
+            GetContestedResourceVoteTallyRequest: PBJSGetContestedResourceVoteTallyRequest,
+            GetContestedResourceVoteTallyResponse: PBJSGetContestedResourceVoteTallyResponse,
+            GetIdentityWithdrawalsRequest: PBJSGetIdentityWithdrawalsRequest,
+            GetIdentityWithdrawalsResponse: PBJSGetIdentityWithdrawalsResponse,
             GetIdentitiesByPublicKeyHashesRequest: PBJSGetIdentitiesByPublicKeyHashesRequest,
             GetIdentitiesByPublicKeyHashesResponse: PBJSGetIdentitiesByPublicKeyHashesResponse,
             WaitForStateTransitionResultRequest: PBJSWaitForStateTransitionResultRequest,
             WaitForStateTransitionResultResponse: PBJSWaitForStateTransitionResultResponse,
             GetConsensusParamsRequest: PBJSGetConsensusParamsRequest,
             GetConsensusParamsResponse: PBJSGetConsensusParamsResponse,
+            EstimateStateTransitionFeeRequest: PBJSEstimateStateTransitionFeeRequest,
+            EstimateStateTransitionFeeResponse: PBJSEstimateStateTransitionFeeResponse,
           },
         },
       },
@@ -53,9 +63,14 @@ const {
   GetDataContractResponse: ProtocGetDataContractResponse,
   GetDataContractHistoryResponse: ProtocGetDataContractHistoryResponse,
   GetDocumentsResponse: ProtocGetDocumentsResponse,
+  GetDocumentHistoryResponse: ProtocGetDocumentHistoryResponse,
+  GetDomainCanonicalOwnerResponse: ProtocGetDomainCanonicalOwnerResponse,
+  GetContestedResourceVoteTallyResponse: ProtocGetContestedResourceVoteTallyResponse,
+  GetIdentityWithdrawalsResponse: ProtocGetIdentityWithdrawalsResponse,
   GetIdentitiesByPublicKeyHashesResponse: ProtocGetIdentitiesByPublicKeyHashesResponse,
   WaitForStateTransitionResultResponse: ProtocWaitForStateTransitionResultResponse,
   GetConsensusParamsResponse: ProtocGetConsensusParamsResponse,
+  EstimateStateTransitionFeeResponse: ProtocEstimateStateTransitionFeeResponse,
 } = require('./platform_protoc');
 
 const getPlatformDefinition = require('../../../../lib/getPlatformDefinition');
@@ -102,6 +117,22 @@ class PlatformPromiseClient {
       this.client.getDocuments.bind(this.client),
     );
 
+    this.client.getDocumentHistory = promisify(
+      this.client.getDocumentHistory.bind(this.client),
+    );
+
+    this.client.getDomainCanonicalOwner = promisify(
+      this.client.getDomainCanonicalOwner.bind(this.client),
+    );
+
+    this.client.getContestedResourceVoteTally = promisify(
+      this.client.getContestedResourceVoteTally.bind(this.client),
+    );
+
+    this.client.getIdentityWithdrawals = promisify(
+      this.client.getIdentityWithdrawals.bind(this.client),
+    );
+
     this.client.getIdentitiesByPublicKeyHashes = promisify(
       this.client.getIdentitiesByPublicKeyHashes.bind(this.client),
     );
@@ -114,6 +145,10 @@ class PlatformPromiseClient {
       this.client.getConsensusParams.bind(this.client),
     );
 
+    this.client.estimateStateTransitionFee = promisify(
+      this.client.estimateStateTransitionFee.bind(this.client),
+    );
+
     this.protocolVersion = undefined;
   }
 
@@ -275,6 +310,130 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetDocumentHistoryRequest} getDocumentHistoryRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetDocumentHistoryResponse>}
+   */
+  getDocumentHistory(getDocumentHistoryRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getDocumentHistory(
+      getDocumentHistoryRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetDocumentHistoryResponse,
+              PBJSGetDocumentHistoryResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetDocumentHistoryRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
+  /**
+   * @param {!GetDomainCanonicalOwnerRequest} getDomainCanonicalOwnerRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetDomainCanonicalOwnerResponse>}
+   */
+  getDomainCanonicalOwner(getDomainCanonicalOwnerRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getDomainCanonicalOwner(
+      getDomainCanonicalOwnerRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetDomainCanonicalOwnerResponse,
+              PBJSGetDomainCanonicalOwnerResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetDomainCanonicalOwnerRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
+  /**
+   * @param {!GetContestedResourceVoteTallyRequest} getContestedResourceVoteTallyRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetContestedResourceVoteTallyResponse>}
+   */
+  getContestedResourceVoteTally(getContestedResourceVoteTallyRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getContestedResourceVoteTally(
+      getContestedResourceVoteTallyRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetContestedResourceVoteTallyResponse,
+              PBJSGetContestedResourceVoteTallyResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetContestedResourceVoteTallyRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
+  /**
+   * @param {!GetIdentityWithdrawalsRequest} getIdentityWithdrawalsRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!GetIdentityWithdrawalsResponse>}
+   */
+  getIdentityWithdrawals(getIdentityWithdrawalsRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.getIdentityWithdrawals(
+      getIdentityWithdrawalsRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocGetIdentityWithdrawalsResponse,
+              PBJSGetIdentityWithdrawalsResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSGetIdentityWithdrawalsRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {!GetIdentitiesByPublicKeyHashesRequest} getIdentitiesByPublicKeyHashesRequest
    * @param {?Object<string, string>} metadata
@@ -374,6 +533,37 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!EstimateStateTransitionFeeRequest} estimateStateTransitionFeeRequest
+   * @param {?Object<string, string>} metadata
+   * @param {CallOptions} [options={}]
+   * @returns {Promise<!EstimateStateTransitionFeeResponse>}
+   */
+  estimateStateTransitionFee(estimateStateTransitionFeeRequest, metadata = {}, options = {}) {
+    if (!isObject(metadata)) {
+      throw new Error('metadata must be an object');
+    }
+
+    return this.client.estimateStateTransitionFee(
+      estimateStateTransitionFeeRequest,
+      convertObjectToMetadata(metadata),
+      {
+        interceptors: [
+          jsonToProtobufInterceptorFactory(
+            jsonToProtobufFactory(
+              ProtocEstimateStateTransitionFeeResponse,
+              PBJSEstimateStateTransitionFeeResponse,
+            ),
+            protobufToJsonFactory(
+              PBJSEstimateStateTransitionFeeRequest,
+            ),
+          ),
+        ],
+        ...options,
+      },
+    );
+  }
+
   /**
    * @param {string} protocolVersion
    */
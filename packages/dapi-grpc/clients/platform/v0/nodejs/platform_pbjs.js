@@ -449,6 +449,171 @@ $root.org = (function() {
                          * @variation 2
                          */
 
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Platform#getDocumentHistory}.
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @typedef getDocumentHistoryCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} [response] GetDocumentHistoryResponse
+                         */
+
+                        /**
+                         * Calls getDocumentHistory.
+                         * @function getDocumentHistory
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest} request GetDocumentHistoryRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Platform.getDocumentHistoryCallback} callback Node-style callback called with the error, if any, and GetDocumentHistoryResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Platform.prototype.getDocumentHistory = function getDocumentHistory(request, callback) {
+                            return this.rpcCall(getDocumentHistory, $root.org.dash.platform.dapi.v0.GetDocumentHistoryRequest, $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse, request, callback);
+                        }, "name", { value: "getDocumentHistory" });
+
+                        /**
+                         * Calls getDocumentHistory.
+                         * @function getDocumentHistory
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest} request GetDocumentHistoryRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetDocumentHistoryResponse>} Promise
+                         * @variation 2
+                         */
+
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Platform#getDomainCanonicalOwner}.
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @typedef getDomainCanonicalOwnerCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} [response] GetDomainCanonicalOwnerResponse
+                         */
+
+                        /**
+                         * Calls getDomainCanonicalOwner.
+                         * @function getDomainCanonicalOwner
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest} request GetDomainCanonicalOwnerRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Platform.getDomainCanonicalOwnerCallback} callback Node-style callback called with the error, if any, and GetDomainCanonicalOwnerResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Platform.prototype.getDomainCanonicalOwner = function getDomainCanonicalOwner(request, callback) {
+                            return this.rpcCall(getDomainCanonicalOwner, $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest, $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse, request, callback);
+                        }, "name", { value: "getDomainCanonicalOwner" });
+
+                        /**
+                         * Calls getDomainCanonicalOwner.
+                         * @function getDomainCanonicalOwner
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest} request GetDomainCanonicalOwnerRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse>} Promise
+                         * @variation 2
+                         */
+
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Platform#getContestedResourceVoteTally}.
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @typedef getContestedResourceVoteTallyCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} [response] GetContestedResourceVoteTallyResponse
+                         */
+
+                        /**
+                         * Calls getContestedResourceVoteTally.
+                         * @function getContestedResourceVoteTally
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest} request GetContestedResourceVoteTallyRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Platform.getContestedResourceVoteTallyCallback} callback Node-style callback called with the error, if any, and GetContestedResourceVoteTallyResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Platform.prototype.getContestedResourceVoteTally = function getContestedResourceVoteTally(request, callback) {
+                            return this.rpcCall(getContestedResourceVoteTally, $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest, $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse, request, callback);
+                        }, "name", { value: "getContestedResourceVoteTally" });
+
+                        /**
+                         * Calls getContestedResourceVoteTally.
+                         * @function getContestedResourceVoteTally
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest} request GetContestedResourceVoteTallyRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse>} Promise
+                         * @variation 2
+                         */
+
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Platform#estimateStateTransitionFee}.
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @typedef estimateStateTransitionFeeCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} [response] EstimateStateTransitionFeeResponse
+                         */
+
+                        /**
+                         * Calls estimateStateTransitionFee.
+                         * @function estimateStateTransitionFee
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest} request EstimateStateTransitionFeeRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Platform.estimateStateTransitionFeeCallback} callback Node-style callback called with the error, if any, and EstimateStateTransitionFeeResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Platform.prototype.estimateStateTransitionFee = function estimateStateTransitionFee(request, callback) {
+                            return this.rpcCall(estimateStateTransitionFee, $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest, $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse, request, callback);
+                        }, "name", { value: "estimateStateTransitionFee" });
+
+                        /**
+                         * Calls estimateStateTransitionFee.
+                         * @function estimateStateTransitionFee
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest} request EstimateStateTransitionFeeRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse>} Promise
+                         * @variation 2
+                         */
+
+                        /**
+                         * Callback as used by {@link org.dash.platform.dapi.v0.Platform#getIdentityWithdrawals}.
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @typedef getIdentityWithdrawalsCallback
+                         * @type {function}
+                         * @param {Error|null} error Error, if any
+                         * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} [response] GetIdentityWithdrawalsResponse
+                         */
+
+                        /**
+                         * Calls getIdentityWithdrawals.
+                         * @function getIdentityWithdrawals
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest} request GetIdentityWithdrawalsRequest message or plain object
+                         * @param {org.dash.platform.dapi.v0.Platform.getIdentityWithdrawalsCallback} callback Node-style callback called with the error, if any, and GetIdentityWithdrawalsResponse
+                         * @returns {undefined}
+                         * @variation 1
+                         */
+                        Object.defineProperty(Platform.prototype.getIdentityWithdrawals = function getIdentityWithdrawals(request, callback) {
+                            return this.rpcCall(getIdentityWithdrawals, $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest, $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse, request, callback);
+                        }, "name", { value: "getIdentityWithdrawals" });
+
+                        /**
+                         * Calls getIdentityWithdrawals.
+                         * @function getIdentityWithdrawals
+                         * @memberof org.dash.platform.dapi.v0.Platform
+                         * @instance
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest} request GetIdentityWithdrawalsRequest message or plain object
+                         * @returns {Promise<org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse>} Promise
+                         * @variation 2
+                         */
+
                         /**
                          * Callback as used by {@link org.dash.platform.dapi.v0.Platform#getIdentitiesByPublicKeyHashes}.
                          * @memberof org.dash.platform.dapi.v0.Platform
@@ -8821,6 +8986,7 @@ $root.org = (function() {
                          * @interface IGetDataContractRequest
                          * @property {Uint8Array|null} [id] GetDataContractRequest id
                          * @property {boolean|null} [prove] GetDataContractRequest prove
+                         * @property {google.protobuf.IUInt64Value|null} [atTimeMs] GetDataContractRequest atTimeMs
                          */
 
                         /**
@@ -8854,6 +9020,14 @@ $root.org = (function() {
                          */
                         GetDataContractRequest.prototype.prove = false;
 
+                        /**
+                         * GetDataContractRequest atTimeMs.
+                         * @member {google.protobuf.IUInt64Value|null|undefined} atTimeMs
+                         * @memberof org.dash.platform.dapi.v0.GetDataContractRequest
+                         * @instance
+                         */
+                        GetDataContractRequest.prototype.atTimeMs = null;
+
                         /**
                          * Creates a new GetDataContractRequest instance using the specified properties.
                          * @function create
@@ -8882,6 +9056,8 @@ $root.org = (function() {
                                 writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.id);
                             if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
                                 writer.uint32(/* id 2, wireType 0 =*/16).bool(message.prove);
+                            if (message.atTimeMs != null && Object.hasOwnProperty.call(message, "atTimeMs"))
+                                $root.google.protobuf.UInt64Value.encode(message.atTimeMs, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
                             return writer;
                         };
 
@@ -8922,6 +9098,9 @@ $root.org = (function() {
                                 case 2:
                                     message.prove = reader.bool();
                                     break;
+                                case 3:
+                                    message.atTimeMs = $root.google.protobuf.UInt64Value.decode(reader, reader.uint32());
+                                    break;
                                 default:
                                     reader.skipType(tag & 7);
                                     break;
@@ -8963,6 +9142,11 @@ $root.org = (function() {
                             if (message.prove != null && message.hasOwnProperty("prove"))
                                 if (typeof message.prove !== "boolean")
                                     return "prove: boolean expected";
+                            if (message.atTimeMs != null && message.hasOwnProperty("atTimeMs")) {
+                                var error = $root.google.protobuf.UInt64Value.verify(message.atTimeMs);
+                                if (error)
+                                    return "atTimeMs." + error;
+                            }
                             return null;
                         };
 
@@ -8985,6 +9169,11 @@ $root.org = (function() {
                                     message.id = object.id;
                             if (object.prove != null)
                                 message.prove = Boolean(object.prove);
+                            if (object.atTimeMs != null) {
+                                if (typeof object.atTimeMs !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDataContractRequest.atTimeMs: object expected");
+                                message.atTimeMs = $root.google.protobuf.UInt64Value.fromObject(object.atTimeMs);
+                            }
                             return message;
                         };
 
@@ -9010,11 +9199,14 @@ $root.org = (function() {
                                         object.id = $util.newBuffer(object.id);
                                 }
                                 object.prove = false;
+                                object.atTimeMs = null;
                             }
                             if (message.id != null && message.hasOwnProperty("id"))
                                 object.id = options.bytes === String ? $util.base64.encode(message.id, 0, message.id.length) : options.bytes === Array ? Array.prototype.slice.call(message.id) : message.id;
                             if (message.prove != null && message.hasOwnProperty("prove"))
                                 object.prove = message.prove;
+                            if (message.atTimeMs != null && message.hasOwnProperty("atTimeMs"))
+                                object.atTimeMs = $root.google.protobuf.UInt64Value.toObject(message.atTimeMs, options);
                             return object;
                         };
 
@@ -11469,6 +11661,7 @@ $root.org = (function() {
                          * @property {Uint8Array|null} [startAfter] GetDocumentsRequest startAfter
                          * @property {Uint8Array|null} [startAt] GetDocumentsRequest startAt
                          * @property {boolean|null} [prove] GetDocumentsRequest prove
+                         * @property {google.protobuf.IUInt64Value|null} [blockTimeMs] GetDocumentsRequest blockTimeMs
                          */
 
                         /**
@@ -11550,6 +11743,14 @@ $root.org = (function() {
                          */
                         GetDocumentsRequest.prototype.prove = false;
 
+                        /**
+                         * GetDocumentsRequest blockTimeMs.
+                         * @member {google.protobuf.IUInt64Value|null|undefined} blockTimeMs
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentsRequest
+                         * @instance
+                         */
+                        GetDocumentsRequest.prototype.blockTimeMs = null;
+
                         // OneOf field names bound to virtual getters and setters
                         var $oneOfFields;
 
@@ -11604,6 +11805,8 @@ $root.org = (function() {
                                 writer.uint32(/* id 7, wireType 2 =*/58).bytes(message.startAt);
                             if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
                                 writer.uint32(/* id 8, wireType 0 =*/64).bool(message.prove);
+                            if (message.blockTimeMs != null && Object.hasOwnProperty.call(message, "blockTimeMs"))
+                                $root.google.protobuf.UInt64Value.encode(message.blockTimeMs, writer.uint32(/* id 9, wireType 2 =*/74).fork()).ldelim();
                             return writer;
                         };
 
@@ -11662,6 +11865,9 @@ $root.org = (function() {
                                 case 8:
                                     message.prove = reader.bool();
                                     break;
+                                case 9:
+                                    message.blockTimeMs = $root.google.protobuf.UInt64Value.decode(reader, reader.uint32());
+                                    break;
                                 default:
                                     reader.skipType(tag & 7);
                                     break;
@@ -11728,6 +11934,11 @@ $root.org = (function() {
                             if (message.prove != null && message.hasOwnProperty("prove"))
                                 if (typeof message.prove !== "boolean")
                                     return "prove: boolean expected";
+                            if (message.blockTimeMs != null && message.hasOwnProperty("blockTimeMs")) {
+                                var error = $root.google.protobuf.UInt64Value.verify(message.blockTimeMs);
+                                if (error)
+                                    return "blockTimeMs." + error;
+                            }
                             return null;
                         };
 
@@ -11774,6 +11985,11 @@ $root.org = (function() {
                                     message.startAt = object.startAt;
                             if (object.prove != null)
                                 message.prove = Boolean(object.prove);
+                            if (object.blockTimeMs != null) {
+                                if (typeof object.blockTimeMs !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentsRequest.blockTimeMs: object expected");
+                                message.blockTimeMs = $root.google.protobuf.UInt64Value.fromObject(object.blockTimeMs);
+                            }
                             return message;
                         };
 
@@ -11815,6 +12031,7 @@ $root.org = (function() {
                                 }
                                 object.limit = 0;
                                 object.prove = false;
+                                object.blockTimeMs = null;
                             }
                             if (message.dataContractId != null && message.hasOwnProperty("dataContractId"))
                                 object.dataContractId = options.bytes === String ? $util.base64.encode(message.dataContractId, 0, message.dataContractId.length) : options.bytes === Array ? Array.prototype.slice.call(message.dataContractId) : message.dataContractId;
@@ -11838,6 +12055,8 @@ $root.org = (function() {
                             }
                             if (message.prove != null && message.hasOwnProperty("prove"))
                                 object.prove = message.prove;
+                            if (message.blockTimeMs != null && message.hasOwnProperty("blockTimeMs"))
+                                object.blockTimeMs = $root.google.protobuf.UInt64Value.toObject(message.blockTimeMs, options);
                             return object;
                         };
 
@@ -12334,6 +12553,5226 @@ $root.org = (function() {
                         return GetDocumentsResponse;
                     })();
 
+                    v0.GetDocumentHistoryRequest = (function() {
+
+                        /**
+                         * Properties of a GetDocumentHistoryRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetDocumentHistoryRequest
+                         * @property {Uint8Array|null} [dataContractId] GetDocumentHistoryRequest dataContractId
+                         * @property {string|null} [documentType] GetDocumentHistoryRequest documentType
+                         * @property {Uint8Array|null} [documentId] GetDocumentHistoryRequest documentId
+                         * @property {number|Long|null} [startAtMs] GetDocumentHistoryRequest startAtMs
+                         * @property {google.protobuf.IUInt64Value|null} [endAtMs] GetDocumentHistoryRequest endAtMs
+                         * @property {google.protobuf.IUInt32Value|null} [limit] GetDocumentHistoryRequest limit
+                         * @property {google.protobuf.IUInt32Value|null} [offset] GetDocumentHistoryRequest offset
+                         * @property {boolean|null} [prove] GetDocumentHistoryRequest prove
+                         */
+
+                        /**
+                         * Constructs a new GetDocumentHistoryRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetDocumentHistoryRequest.
+                         * @implements IGetDocumentHistoryRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest=} [properties] Properties to set
+                         */
+                        function GetDocumentHistoryRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetDocumentHistoryRequest dataContractId.
+                         * @member {Uint8Array} dataContractId
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.dataContractId = $util.newBuffer([]);
+
+                        /**
+                         * GetDocumentHistoryRequest documentType.
+                         * @member {string} documentType
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.documentType = "";
+
+                        /**
+                         * GetDocumentHistoryRequest documentId.
+                         * @member {Uint8Array} documentId
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.documentId = $util.newBuffer([]);
+
+                        /**
+                         * GetDocumentHistoryRequest startAtMs.
+                         * @member {number|Long} startAtMs
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.startAtMs = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                        /**
+                         * GetDocumentHistoryRequest endAtMs.
+                         * @member {google.protobuf.IUInt64Value|null|undefined} endAtMs
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.endAtMs = null;
+
+                        /**
+                         * GetDocumentHistoryRequest limit.
+                         * @member {google.protobuf.IUInt32Value|null|undefined} limit
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.limit = null;
+
+                        /**
+                         * GetDocumentHistoryRequest offset.
+                         * @member {google.protobuf.IUInt32Value|null|undefined} offset
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.offset = null;
+
+                        /**
+                         * GetDocumentHistoryRequest prove.
+                         * @member {boolean} prove
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         */
+                        GetDocumentHistoryRequest.prototype.prove = false;
+
+                        /**
+                         * Creates a new GetDocumentHistoryRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryRequest} GetDocumentHistoryRequest instance
+                         */
+                        GetDocumentHistoryRequest.create = function create(properties) {
+                            return new GetDocumentHistoryRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetDocumentHistoryRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest} message GetDocumentHistoryRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDocumentHistoryRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.dataContractId != null && Object.hasOwnProperty.call(message, "dataContractId"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.dataContractId);
+                            if (message.documentType != null && Object.hasOwnProperty.call(message, "documentType"))
+                                writer.uint32(/* id 2, wireType 2 =*/18).string(message.documentType);
+                            if (message.documentId != null && Object.hasOwnProperty.call(message, "documentId"))
+                                writer.uint32(/* id 3, wireType 2 =*/26).bytes(message.documentId);
+                            if (message.startAtMs != null && Object.hasOwnProperty.call(message, "startAtMs"))
+                                writer.uint32(/* id 4, wireType 0 =*/32).uint64(message.startAtMs);
+                            if (message.endAtMs != null && Object.hasOwnProperty.call(message, "endAtMs"))
+                                $root.google.protobuf.UInt64Value.encode(message.endAtMs, writer.uint32(/* id 5, wireType 2 =*/42).fork()).ldelim();
+                            if (message.limit != null && Object.hasOwnProperty.call(message, "limit"))
+                                $root.google.protobuf.UInt32Value.encode(message.limit, writer.uint32(/* id 6, wireType 2 =*/50).fork()).ldelim();
+                            if (message.offset != null && Object.hasOwnProperty.call(message, "offset"))
+                                $root.google.protobuf.UInt32Value.encode(message.offset, writer.uint32(/* id 7, wireType 2 =*/58).fork()).ldelim();
+                            if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
+                                writer.uint32(/* id 8, wireType 0 =*/64).bool(message.prove);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetDocumentHistoryRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryRequest} message GetDocumentHistoryRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDocumentHistoryRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetDocumentHistoryRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryRequest} GetDocumentHistoryRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDocumentHistoryRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.dataContractId = reader.bytes();
+                                    break;
+                                case 2:
+                                    message.documentType = reader.string();
+                                    break;
+                                case 3:
+                                    message.documentId = reader.bytes();
+                                    break;
+                                case 4:
+                                    message.startAtMs = reader.uint64();
+                                    break;
+                                case 5:
+                                    message.endAtMs = $root.google.protobuf.UInt64Value.decode(reader, reader.uint32());
+                                    break;
+                                case 6:
+                                    message.limit = $root.google.protobuf.UInt32Value.decode(reader, reader.uint32());
+                                    break;
+                                case 7:
+                                    message.offset = $root.google.protobuf.UInt32Value.decode(reader, reader.uint32());
+                                    break;
+                                case 8:
+                                    message.prove = reader.bool();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetDocumentHistoryRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryRequest} GetDocumentHistoryRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDocumentHistoryRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetDocumentHistoryRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetDocumentHistoryRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.dataContractId != null && message.hasOwnProperty("dataContractId"))
+                                if (!(message.dataContractId && typeof message.dataContractId.length === "number" || $util.isString(message.dataContractId)))
+                                    return "dataContractId: buffer expected";
+                            if (message.documentType != null && message.hasOwnProperty("documentType"))
+                                if (!$util.isString(message.documentType))
+                                    return "documentType: string expected";
+                            if (message.documentId != null && message.hasOwnProperty("documentId"))
+                                if (!(message.documentId && typeof message.documentId.length === "number" || $util.isString(message.documentId)))
+                                    return "documentId: buffer expected";
+                            if (message.startAtMs != null && message.hasOwnProperty("startAtMs"))
+                                if (!$util.isInteger(message.startAtMs) && !(message.startAtMs && $util.isInteger(message.startAtMs.low) && $util.isInteger(message.startAtMs.high)))
+                                    return "startAtMs: integer|Long expected";
+                            if (message.endAtMs != null && message.hasOwnProperty("endAtMs")) {
+                                var error = $root.google.protobuf.UInt64Value.verify(message.endAtMs);
+                                if (error)
+                                    return "endAtMs." + error;
+                            }
+                            if (message.limit != null && message.hasOwnProperty("limit")) {
+                                var error = $root.google.protobuf.UInt32Value.verify(message.limit);
+                                if (error)
+                                    return "limit." + error;
+                            }
+                            if (message.offset != null && message.hasOwnProperty("offset")) {
+                                var error = $root.google.protobuf.UInt32Value.verify(message.offset);
+                                if (error)
+                                    return "offset." + error;
+                            }
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                if (typeof message.prove !== "boolean")
+                                    return "prove: boolean expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetDocumentHistoryRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryRequest} GetDocumentHistoryRequest
+                         */
+                        GetDocumentHistoryRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetDocumentHistoryRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryRequest();
+                            if (object.dataContractId != null)
+                                if (typeof object.dataContractId === "string")
+                                    $util.base64.decode(object.dataContractId, message.dataContractId = $util.newBuffer($util.base64.length(object.dataContractId)), 0);
+                                else if (object.dataContractId.length >= 0)
+                                    message.dataContractId = object.dataContractId;
+                            if (object.documentType != null)
+                                message.documentType = String(object.documentType);
+                            if (object.documentId != null)
+                                if (typeof object.documentId === "string")
+                                    $util.base64.decode(object.documentId, message.documentId = $util.newBuffer($util.base64.length(object.documentId)), 0);
+                                else if (object.documentId.length >= 0)
+                                    message.documentId = object.documentId;
+                            if (object.startAtMs != null)
+                                if ($util.Long)
+                                    (message.startAtMs = $util.Long.fromValue(object.startAtMs)).unsigned = true;
+                                else if (typeof object.startAtMs === "string")
+                                    message.startAtMs = parseInt(object.startAtMs, 10);
+                                else if (typeof object.startAtMs === "number")
+                                    message.startAtMs = object.startAtMs;
+                                else if (typeof object.startAtMs === "object")
+                                    message.startAtMs = new $util.LongBits(object.startAtMs.low >>> 0, object.startAtMs.high >>> 0).toNumber(true);
+                            if (object.endAtMs != null) {
+                                if (typeof object.endAtMs !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryRequest.endAtMs: object expected");
+                                message.endAtMs = $root.google.protobuf.UInt64Value.fromObject(object.endAtMs);
+                            }
+                            if (object.limit != null) {
+                                if (typeof object.limit !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryRequest.limit: object expected");
+                                message.limit = $root.google.protobuf.UInt32Value.fromObject(object.limit);
+                            }
+                            if (object.offset != null) {
+                                if (typeof object.offset !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryRequest.offset: object expected");
+                                message.offset = $root.google.protobuf.UInt32Value.fromObject(object.offset);
+                            }
+                            if (object.prove != null)
+                                message.prove = Boolean(object.prove);
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetDocumentHistoryRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetDocumentHistoryRequest} message GetDocumentHistoryRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetDocumentHistoryRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                if (options.bytes === String)
+                                    object.dataContractId = "";
+                                else {
+                                    object.dataContractId = [];
+                                    if (options.bytes !== Array)
+                                        object.dataContractId = $util.newBuffer(object.dataContractId);
+                                }
+                                object.documentType = "";
+                                if (options.bytes === String)
+                                    object.documentId = "";
+                                else {
+                                    object.documentId = [];
+                                    if (options.bytes !== Array)
+                                        object.documentId = $util.newBuffer(object.documentId);
+                                }
+                                if ($util.Long) {
+                                    var long = new $util.Long(0, 0, true);
+                                    object.startAtMs = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                } else
+                                    object.startAtMs = options.longs === String ? "0" : 0;
+                                object.endAtMs = null;
+                                object.limit = null;
+                                object.offset = null;
+                                object.prove = false;
+                            }
+                            if (message.dataContractId != null && message.hasOwnProperty("dataContractId"))
+                                object.dataContractId = options.bytes === String ? $util.base64.encode(message.dataContractId, 0, message.dataContractId.length) : options.bytes === Array ? Array.prototype.slice.call(message.dataContractId) : message.dataContractId;
+                            if (message.documentType != null && message.hasOwnProperty("documentType"))
+                                object.documentType = message.documentType;
+                            if (message.documentId != null && message.hasOwnProperty("documentId"))
+                                object.documentId = options.bytes === String ? $util.base64.encode(message.documentId, 0, message.documentId.length) : options.bytes === Array ? Array.prototype.slice.call(message.documentId) : message.documentId;
+                            if (message.startAtMs != null && message.hasOwnProperty("startAtMs"))
+                                if (typeof message.startAtMs === "number")
+                                    object.startAtMs = options.longs === String ? String(message.startAtMs) : message.startAtMs;
+                                else
+                                    object.startAtMs = options.longs === String ? $util.Long.prototype.toString.call(message.startAtMs) : options.longs === Number ? new $util.LongBits(message.startAtMs.low >>> 0, message.startAtMs.high >>> 0).toNumber(true) : message.startAtMs;
+                            if (message.endAtMs != null && message.hasOwnProperty("endAtMs"))
+                                object.endAtMs = $root.google.protobuf.UInt64Value.toObject(message.endAtMs, options);
+                            if (message.limit != null && message.hasOwnProperty("limit"))
+                                object.limit = $root.google.protobuf.UInt32Value.toObject(message.limit, options);
+                            if (message.offset != null && message.hasOwnProperty("offset"))
+                                object.offset = $root.google.protobuf.UInt32Value.toObject(message.offset, options);
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                object.prove = message.prove;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetDocumentHistoryRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetDocumentHistoryRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetDocumentHistoryRequest;
+                    })();
+
+                    v0.GetDocumentHistoryResponse = (function() {
+
+                        /**
+                         * Properties of a GetDocumentHistoryResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetDocumentHistoryResponse
+                         * @property {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory|null} [documentHistory] GetDocumentHistoryResponse documentHistory
+                         * @property {org.dash.platform.dapi.v0.IProof|null} [proof] GetDocumentHistoryResponse proof
+                         * @property {org.dash.platform.dapi.v0.IResponseMetadata|null} [metadata] GetDocumentHistoryResponse metadata
+                         */
+
+                        /**
+                         * Constructs a new GetDocumentHistoryResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetDocumentHistoryResponse.
+                         * @implements IGetDocumentHistoryResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryResponse=} [properties] Properties to set
+                         */
+                        function GetDocumentHistoryResponse(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetDocumentHistoryResponse documentHistory.
+                         * @member {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory|null|undefined} documentHistory
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @instance
+                         */
+                        GetDocumentHistoryResponse.prototype.documentHistory = null;
+
+                        /**
+                         * GetDocumentHistoryResponse proof.
+                         * @member {org.dash.platform.dapi.v0.IProof|null|undefined} proof
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @instance
+                         */
+                        GetDocumentHistoryResponse.prototype.proof = null;
+
+                        /**
+                         * GetDocumentHistoryResponse metadata.
+                         * @member {org.dash.platform.dapi.v0.IResponseMetadata|null|undefined} metadata
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @instance
+                         */
+                        GetDocumentHistoryResponse.prototype.metadata = null;
+
+                        // OneOf field names bound to virtual getters and setters
+                        var $oneOfFields;
+
+                        /**
+                         * GetDocumentHistoryResponse result.
+                         * @member {"documentHistory"|"proof"|undefined} result
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @instance
+                         */
+                        Object.defineProperty(GetDocumentHistoryResponse.prototype, "result", {
+                            get: $util.oneOfGetter($oneOfFields = ["documentHistory", "proof"]),
+                            set: $util.oneOfSetter($oneOfFields)
+                        });
+
+                        /**
+                         * Creates a new GetDocumentHistoryResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} GetDocumentHistoryResponse instance
+                         */
+                        GetDocumentHistoryResponse.create = function create(properties) {
+                            return new GetDocumentHistoryResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetDocumentHistoryResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryResponse} message GetDocumentHistoryResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDocumentHistoryResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.documentHistory != null && Object.hasOwnProperty.call(message, "documentHistory"))
+                                $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.encode(message.documentHistory, writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                            if (message.proof != null && Object.hasOwnProperty.call(message, "proof"))
+                                $root.org.dash.platform.dapi.v0.Proof.encode(message.proof, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.metadata != null && Object.hasOwnProperty.call(message, "metadata"))
+                                $root.org.dash.platform.dapi.v0.ResponseMetadata.encode(message.metadata, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetDocumentHistoryResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDocumentHistoryResponse} message GetDocumentHistoryResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDocumentHistoryResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetDocumentHistoryResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} GetDocumentHistoryResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDocumentHistoryResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.documentHistory = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.decode(reader, reader.uint32());
+                                    break;
+                                case 2:
+                                    message.proof = $root.org.dash.platform.dapi.v0.Proof.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.decode(reader, reader.uint32());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetDocumentHistoryResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} GetDocumentHistoryResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDocumentHistoryResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetDocumentHistoryResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetDocumentHistoryResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            var properties = {};
+                            if (message.documentHistory != null && message.hasOwnProperty("documentHistory")) {
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.verify(message.documentHistory);
+                                    if (error)
+                                        return "documentHistory." + error;
+                                }
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                if (properties.result === 1)
+                                    return "result: multiple values";
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.Proof.verify(message.proof);
+                                    if (error)
+                                        return "proof." + error;
+                                }
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata")) {
+                                var error = $root.org.dash.platform.dapi.v0.ResponseMetadata.verify(message.metadata);
+                                if (error)
+                                    return "metadata." + error;
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetDocumentHistoryResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} GetDocumentHistoryResponse
+                         */
+                        GetDocumentHistoryResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse();
+                            if (object.documentHistory != null) {
+                                if (typeof object.documentHistory !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryResponse.documentHistory: object expected");
+                                message.documentHistory = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.fromObject(object.documentHistory);
+                            }
+                            if (object.proof != null) {
+                                if (typeof object.proof !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryResponse.proof: object expected");
+                                message.proof = $root.org.dash.platform.dapi.v0.Proof.fromObject(object.proof);
+                            }
+                            if (object.metadata != null) {
+                                if (typeof object.metadata !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryResponse.metadata: object expected");
+                                message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.fromObject(object.metadata);
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetDocumentHistoryResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse} message GetDocumentHistoryResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetDocumentHistoryResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults)
+                                object.metadata = null;
+                            if (message.documentHistory != null && message.hasOwnProperty("documentHistory")) {
+                                object.documentHistory = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.toObject(message.documentHistory, options);
+                                if (options.oneofs)
+                                    object.result = "documentHistory";
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                object.proof = $root.org.dash.platform.dapi.v0.Proof.toObject(message.proof, options);
+                                if (options.oneofs)
+                                    object.result = "proof";
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata"))
+                                object.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.toObject(message.metadata, options);
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetDocumentHistoryResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetDocumentHistoryResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        GetDocumentHistoryResponse.DocumentHistoryEntry = (function() {
+
+                            /**
+                             * Properties of a DocumentHistoryEntry.
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                             * @interface IDocumentHistoryEntry
+                             * @property {number|Long|null} [date] DocumentHistoryEntry date
+                             * @property {number|Long|null} [revision] DocumentHistoryEntry revision
+                             * @property {Uint8Array|null} [value] DocumentHistoryEntry value
+                             */
+
+                            /**
+                             * Constructs a new DocumentHistoryEntry.
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                             * @classdesc Represents a DocumentHistoryEntry.
+                             * @implements IDocumentHistoryEntry
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry=} [properties] Properties to set
+                             */
+                            function DocumentHistoryEntry(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * DocumentHistoryEntry date.
+                             * @member {number|Long} date
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @instance
+                             */
+                            DocumentHistoryEntry.prototype.date = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * DocumentHistoryEntry revision.
+                             * @member {number|Long} revision
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @instance
+                             */
+                            DocumentHistoryEntry.prototype.revision = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * DocumentHistoryEntry value.
+                             * @member {Uint8Array} value
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @instance
+                             */
+                            DocumentHistoryEntry.prototype.value = $util.newBuffer([]);
+
+                            /**
+                             * Creates a new DocumentHistoryEntry instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry} DocumentHistoryEntry instance
+                             */
+                            DocumentHistoryEntry.create = function create(properties) {
+                                return new DocumentHistoryEntry(properties);
+                            };
+
+                            /**
+                             * Encodes the specified DocumentHistoryEntry message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry} message DocumentHistoryEntry message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            DocumentHistoryEntry.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.date != null && Object.hasOwnProperty.call(message, "date"))
+                                    writer.uint32(/* id 1, wireType 0 =*/8).uint64(message.date);
+                                if (message.revision != null && Object.hasOwnProperty.call(message, "revision"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.revision);
+                                if (message.value != null && Object.hasOwnProperty.call(message, "value"))
+                                    writer.uint32(/* id 3, wireType 2 =*/26).bytes(message.value);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified DocumentHistoryEntry message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry} message DocumentHistoryEntry message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            DocumentHistoryEntry.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a DocumentHistoryEntry message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry} DocumentHistoryEntry
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            DocumentHistoryEntry.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.date = reader.uint64();
+                                        break;
+                                    case 2:
+                                        message.revision = reader.uint64();
+                                        break;
+                                    case 3:
+                                        message.value = reader.bytes();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a DocumentHistoryEntry message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry} DocumentHistoryEntry
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            DocumentHistoryEntry.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a DocumentHistoryEntry message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            DocumentHistoryEntry.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.date != null && message.hasOwnProperty("date"))
+                                    if (!$util.isInteger(message.date) && !(message.date && $util.isInteger(message.date.low) && $util.isInteger(message.date.high)))
+                                        return "date: integer|Long expected";
+                                if (message.revision != null && message.hasOwnProperty("revision"))
+                                    if (!$util.isInteger(message.revision) && !(message.revision && $util.isInteger(message.revision.low) && $util.isInteger(message.revision.high)))
+                                        return "revision: integer|Long expected";
+                                if (message.value != null && message.hasOwnProperty("value"))
+                                    if (!(message.value && typeof message.value.length === "number" || $util.isString(message.value)))
+                                        return "value: buffer expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates a DocumentHistoryEntry message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry} DocumentHistoryEntry
+                             */
+                            DocumentHistoryEntry.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry();
+                                if (object.date != null)
+                                    if ($util.Long)
+                                        (message.date = $util.Long.fromValue(object.date)).unsigned = true;
+                                    else if (typeof object.date === "string")
+                                        message.date = parseInt(object.date, 10);
+                                    else if (typeof object.date === "number")
+                                        message.date = object.date;
+                                    else if (typeof object.date === "object")
+                                        message.date = new $util.LongBits(object.date.low >>> 0, object.date.high >>> 0).toNumber(true);
+                                if (object.revision != null)
+                                    if ($util.Long)
+                                        (message.revision = $util.Long.fromValue(object.revision)).unsigned = true;
+                                    else if (typeof object.revision === "string")
+                                        message.revision = parseInt(object.revision, 10);
+                                    else if (typeof object.revision === "number")
+                                        message.revision = object.revision;
+                                    else if (typeof object.revision === "object")
+                                        message.revision = new $util.LongBits(object.revision.low >>> 0, object.revision.high >>> 0).toNumber(true);
+                                if (object.value != null)
+                                    if (typeof object.value === "string")
+                                        $util.base64.decode(object.value, message.value = $util.newBuffer($util.base64.length(object.value)), 0);
+                                    else if (object.value.length >= 0)
+                                        message.value = object.value;
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a DocumentHistoryEntry message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry} message DocumentHistoryEntry
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            DocumentHistoryEntry.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.date = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.date = options.longs === String ? "0" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.revision = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.revision = options.longs === String ? "0" : 0;
+                                    if (options.bytes === String)
+                                        object.value = "";
+                                    else {
+                                        object.value = [];
+                                        if (options.bytes !== Array)
+                                            object.value = $util.newBuffer(object.value);
+                                    }
+                                }
+                                if (message.date != null && message.hasOwnProperty("date"))
+                                    if (typeof message.date === "number")
+                                        object.date = options.longs === String ? String(message.date) : message.date;
+                                    else
+                                        object.date = options.longs === String ? $util.Long.prototype.toString.call(message.date) : options.longs === Number ? new $util.LongBits(message.date.low >>> 0, message.date.high >>> 0).toNumber(true) : message.date;
+                                if (message.revision != null && message.hasOwnProperty("revision"))
+                                    if (typeof message.revision === "number")
+                                        object.revision = options.longs === String ? String(message.revision) : message.revision;
+                                    else
+                                        object.revision = options.longs === String ? $util.Long.prototype.toString.call(message.revision) : options.longs === Number ? new $util.LongBits(message.revision.low >>> 0, message.revision.high >>> 0).toNumber(true) : message.revision;
+                                if (message.value != null && message.hasOwnProperty("value"))
+                                    object.value = options.bytes === String ? $util.base64.encode(message.value, 0, message.value.length) : options.bytes === Array ? Array.prototype.slice.call(message.value) : message.value;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this DocumentHistoryEntry to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            DocumentHistoryEntry.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return DocumentHistoryEntry;
+                        })();
+
+                        GetDocumentHistoryResponse.DocumentHistory = (function() {
+
+                            /**
+                             * Properties of a DocumentHistory.
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                             * @interface IDocumentHistory
+                             * @property {Array.<org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry>|null} [documentEntries] DocumentHistory documentEntries
+                             */
+
+                            /**
+                             * Constructs a new DocumentHistory.
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse
+                             * @classdesc Represents a DocumentHistory.
+                             * @implements IDocumentHistory
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory=} [properties] Properties to set
+                             */
+                            function DocumentHistory(properties) {
+                                this.documentEntries = [];
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * DocumentHistory documentEntries.
+                             * @member {Array.<org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistoryEntry>} documentEntries
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @instance
+                             */
+                            DocumentHistory.prototype.documentEntries = $util.emptyArray;
+
+                            /**
+                             * Creates a new DocumentHistory instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory} DocumentHistory instance
+                             */
+                            DocumentHistory.create = function create(properties) {
+                                return new DocumentHistory(properties);
+                            };
+
+                            /**
+                             * Encodes the specified DocumentHistory message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory} message DocumentHistory message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            DocumentHistory.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.documentEntries != null && message.documentEntries.length)
+                                    for (var i = 0; i < message.documentEntries.length; ++i)
+                                        $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.encode(message.documentEntries[i], writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified DocumentHistory message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.IDocumentHistory} message DocumentHistory message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            DocumentHistory.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a DocumentHistory message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory} DocumentHistory
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            DocumentHistory.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        if (!(message.documentEntries && message.documentEntries.length))
+                                            message.documentEntries = [];
+                                        message.documentEntries.push($root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.decode(reader, reader.uint32()));
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a DocumentHistory message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory} DocumentHistory
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            DocumentHistory.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a DocumentHistory message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            DocumentHistory.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.documentEntries != null && message.hasOwnProperty("documentEntries")) {
+                                    if (!Array.isArray(message.documentEntries))
+                                        return "documentEntries: array expected";
+                                    for (var i = 0; i < message.documentEntries.length; ++i) {
+                                        var error = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.verify(message.documentEntries[i]);
+                                        if (error)
+                                            return "documentEntries." + error;
+                                    }
+                                }
+                                return null;
+                            };
+
+                            /**
+                             * Creates a DocumentHistory message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory} DocumentHistory
+                             */
+                            DocumentHistory.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory();
+                                if (object.documentEntries) {
+                                    if (!Array.isArray(object.documentEntries))
+                                        throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.documentEntries: array expected");
+                                    message.documentEntries = [];
+                                    for (var i = 0; i < object.documentEntries.length; ++i) {
+                                        if (typeof object.documentEntries[i] !== "object")
+                                            throw TypeError(".org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.documentEntries: object expected");
+                                        message.documentEntries[i] = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.fromObject(object.documentEntries[i]);
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a DocumentHistory message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory} message DocumentHistory
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            DocumentHistory.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.arrays || options.defaults)
+                                    object.documentEntries = [];
+                                if (message.documentEntries && message.documentEntries.length) {
+                                    object.documentEntries = [];
+                                    for (var j = 0; j < message.documentEntries.length; ++j)
+                                        object.documentEntries[j] = $root.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.toObject(message.documentEntries[j], options);
+                                }
+                                return object;
+                            };
+
+                            /**
+                             * Converts this DocumentHistory to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            DocumentHistory.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return DocumentHistory;
+                        })();
+
+                        return GetDocumentHistoryResponse;
+                    })();
+
+                    v0.GetDomainCanonicalOwnerRequest = (function() {
+
+                        /**
+                         * Properties of a GetDomainCanonicalOwnerRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetDomainCanonicalOwnerRequest
+                         * @property {string|null} [label] GetDomainCanonicalOwnerRequest label
+                         * @property {string|null} [normalizedParentDomainName] GetDomainCanonicalOwnerRequest normalizedParentDomainName
+                         * @property {boolean|null} [prove] GetDomainCanonicalOwnerRequest prove
+                         */
+
+                        /**
+                         * Constructs a new GetDomainCanonicalOwnerRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetDomainCanonicalOwnerRequest.
+                         * @implements IGetDomainCanonicalOwnerRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest=} [properties] Properties to set
+                         */
+                        function GetDomainCanonicalOwnerRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetDomainCanonicalOwnerRequest label.
+                         * @member {string} label
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerRequest.prototype.label = "";
+
+                        /**
+                         * GetDomainCanonicalOwnerRequest normalizedParentDomainName.
+                         * @member {string} normalizedParentDomainName
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerRequest.prototype.normalizedParentDomainName = "";
+
+                        /**
+                         * GetDomainCanonicalOwnerRequest prove.
+                         * @member {boolean} prove
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerRequest.prototype.prove = false;
+
+                        /**
+                         * Creates a new GetDomainCanonicalOwnerRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest} GetDomainCanonicalOwnerRequest instance
+                         */
+                        GetDomainCanonicalOwnerRequest.create = function create(properties) {
+                            return new GetDomainCanonicalOwnerRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetDomainCanonicalOwnerRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest} message GetDomainCanonicalOwnerRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDomainCanonicalOwnerRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.label != null && Object.hasOwnProperty.call(message, "label"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).string(message.label);
+                            if (message.normalizedParentDomainName != null && Object.hasOwnProperty.call(message, "normalizedParentDomainName"))
+                                writer.uint32(/* id 2, wireType 2 =*/18).string(message.normalizedParentDomainName);
+                            if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
+                                writer.uint32(/* id 3, wireType 0 =*/24).bool(message.prove);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetDomainCanonicalOwnerRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerRequest} message GetDomainCanonicalOwnerRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDomainCanonicalOwnerRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetDomainCanonicalOwnerRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest} GetDomainCanonicalOwnerRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDomainCanonicalOwnerRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.label = reader.string();
+                                    break;
+                                case 2:
+                                    message.normalizedParentDomainName = reader.string();
+                                    break;
+                                case 3:
+                                    message.prove = reader.bool();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetDomainCanonicalOwnerRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest} GetDomainCanonicalOwnerRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDomainCanonicalOwnerRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetDomainCanonicalOwnerRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetDomainCanonicalOwnerRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.label != null && message.hasOwnProperty("label"))
+                                if (!$util.isString(message.label))
+                                    return "label: string expected";
+                            if (message.normalizedParentDomainName != null && message.hasOwnProperty("normalizedParentDomainName"))
+                                if (!$util.isString(message.normalizedParentDomainName))
+                                    return "normalizedParentDomainName: string expected";
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                if (typeof message.prove !== "boolean")
+                                    return "prove: boolean expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetDomainCanonicalOwnerRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest} GetDomainCanonicalOwnerRequest
+                         */
+                        GetDomainCanonicalOwnerRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest();
+                            if (object.label != null)
+                                message.label = String(object.label);
+                            if (object.normalizedParentDomainName != null)
+                                message.normalizedParentDomainName = String(object.normalizedParentDomainName);
+                            if (object.prove != null)
+                                message.prove = Boolean(object.prove);
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetDomainCanonicalOwnerRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest} message GetDomainCanonicalOwnerRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetDomainCanonicalOwnerRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                object.label = "";
+                                object.normalizedParentDomainName = "";
+                                object.prove = false;
+                            }
+                            if (message.label != null && message.hasOwnProperty("label"))
+                                object.label = message.label;
+                            if (message.normalizedParentDomainName != null && message.hasOwnProperty("normalizedParentDomainName"))
+                                object.normalizedParentDomainName = message.normalizedParentDomainName;
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                object.prove = message.prove;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetDomainCanonicalOwnerRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetDomainCanonicalOwnerRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetDomainCanonicalOwnerRequest;
+                    })();
+
+                    v0.GetDomainCanonicalOwnerResponse = (function() {
+
+                        /**
+                         * Properties of a GetDomainCanonicalOwnerResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetDomainCanonicalOwnerResponse
+                         * @property {Uint8Array|null} [ownerId] GetDomainCanonicalOwnerResponse ownerId
+                         * @property {org.dash.platform.dapi.v0.IProof|null} [proof] GetDomainCanonicalOwnerResponse proof
+                         * @property {org.dash.platform.dapi.v0.IResponseMetadata|null} [metadata] GetDomainCanonicalOwnerResponse metadata
+                         */
+
+                        /**
+                         * Constructs a new GetDomainCanonicalOwnerResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetDomainCanonicalOwnerResponse.
+                         * @implements IGetDomainCanonicalOwnerResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerResponse=} [properties] Properties to set
+                         */
+                        function GetDomainCanonicalOwnerResponse(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetDomainCanonicalOwnerResponse ownerId.
+                         * @member {Uint8Array} ownerId
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerResponse.prototype.ownerId = $util.newBuffer([]);
+
+                        /**
+                         * GetDomainCanonicalOwnerResponse proof.
+                         * @member {org.dash.platform.dapi.v0.IProof|null|undefined} proof
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerResponse.prototype.proof = null;
+
+                        /**
+                         * GetDomainCanonicalOwnerResponse metadata.
+                         * @member {org.dash.platform.dapi.v0.IResponseMetadata|null|undefined} metadata
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @instance
+                         */
+                        GetDomainCanonicalOwnerResponse.prototype.metadata = null;
+
+                        // OneOf field names bound to virtual getters and setters
+                        var $oneOfFields;
+
+                        /**
+                         * GetDomainCanonicalOwnerResponse result.
+                         * @member {"ownerId"|"proof"|undefined} result
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @instance
+                         */
+                        Object.defineProperty(GetDomainCanonicalOwnerResponse.prototype, "result", {
+                            get: $util.oneOfGetter($oneOfFields = ["ownerId", "proof"]),
+                            set: $util.oneOfSetter($oneOfFields)
+                        });
+
+                        /**
+                         * Creates a new GetDomainCanonicalOwnerResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} GetDomainCanonicalOwnerResponse instance
+                         */
+                        GetDomainCanonicalOwnerResponse.create = function create(properties) {
+                            return new GetDomainCanonicalOwnerResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetDomainCanonicalOwnerResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerResponse} message GetDomainCanonicalOwnerResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDomainCanonicalOwnerResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.ownerId != null && Object.hasOwnProperty.call(message, "ownerId"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.ownerId);
+                            if (message.proof != null && Object.hasOwnProperty.call(message, "proof"))
+                                $root.org.dash.platform.dapi.v0.Proof.encode(message.proof, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.metadata != null && Object.hasOwnProperty.call(message, "metadata"))
+                                $root.org.dash.platform.dapi.v0.ResponseMetadata.encode(message.metadata, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetDomainCanonicalOwnerResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetDomainCanonicalOwnerResponse} message GetDomainCanonicalOwnerResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetDomainCanonicalOwnerResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetDomainCanonicalOwnerResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} GetDomainCanonicalOwnerResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDomainCanonicalOwnerResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.ownerId = reader.bytes();
+                                    break;
+                                case 2:
+                                    message.proof = $root.org.dash.platform.dapi.v0.Proof.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.decode(reader, reader.uint32());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetDomainCanonicalOwnerResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} GetDomainCanonicalOwnerResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetDomainCanonicalOwnerResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetDomainCanonicalOwnerResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetDomainCanonicalOwnerResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            var properties = {};
+                            if (message.ownerId != null && message.hasOwnProperty("ownerId")) {
+                                properties.result = 1;
+                                if (!(message.ownerId && typeof message.ownerId.length === "number" || $util.isString(message.ownerId)))
+                                    return "ownerId: buffer expected";
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                if (properties.result === 1)
+                                    return "result: multiple values";
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.Proof.verify(message.proof);
+                                    if (error)
+                                        return "proof." + error;
+                                }
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata")) {
+                                var error = $root.org.dash.platform.dapi.v0.ResponseMetadata.verify(message.metadata);
+                                if (error)
+                                    return "metadata." + error;
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetDomainCanonicalOwnerResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} GetDomainCanonicalOwnerResponse
+                         */
+                        GetDomainCanonicalOwnerResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse();
+                            if (object.ownerId != null)
+                                if (typeof object.ownerId === "string")
+                                    $util.base64.decode(object.ownerId, message.ownerId = $util.newBuffer($util.base64.length(object.ownerId)), 0);
+                                else if (object.ownerId.length >= 0)
+                                    message.ownerId = object.ownerId;
+                            if (object.proof != null) {
+                                if (typeof object.proof !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.proof: object expected");
+                                message.proof = $root.org.dash.platform.dapi.v0.Proof.fromObject(object.proof);
+                            }
+                            if (object.metadata != null) {
+                                if (typeof object.metadata !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.metadata: object expected");
+                                message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.fromObject(object.metadata);
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetDomainCanonicalOwnerResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse} message GetDomainCanonicalOwnerResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetDomainCanonicalOwnerResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults)
+                                object.metadata = null;
+                            if (message.ownerId != null && message.hasOwnProperty("ownerId")) {
+                                object.ownerId = options.bytes === String ? $util.base64.encode(message.ownerId, 0, message.ownerId.length) : options.bytes === Array ? Array.prototype.slice.call(message.ownerId) : message.ownerId;
+                                if (options.oneofs)
+                                    object.result = "ownerId";
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                object.proof = $root.org.dash.platform.dapi.v0.Proof.toObject(message.proof, options);
+                                if (options.oneofs)
+                                    object.result = "proof";
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata"))
+                                object.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.toObject(message.metadata, options);
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetDomainCanonicalOwnerResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetDomainCanonicalOwnerResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetDomainCanonicalOwnerResponse;
+                    })();
+
+                    v0.GetContestedResourceVoteTallyRequest = (function() {
+
+                        /**
+                         * Properties of a GetContestedResourceVoteTallyRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetContestedResourceVoteTallyRequest
+                         * @property {Uint8Array|null} [contestId] GetContestedResourceVoteTallyRequest contestId
+                         * @property {boolean|null} [prove] GetContestedResourceVoteTallyRequest prove
+                         */
+
+                        /**
+                         * Constructs a new GetContestedResourceVoteTallyRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetContestedResourceVoteTallyRequest.
+                         * @implements IGetContestedResourceVoteTallyRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest=} [properties] Properties to set
+                         */
+                        function GetContestedResourceVoteTallyRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetContestedResourceVoteTallyRequest contestId.
+                         * @member {Uint8Array} contestId
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @instance
+                         */
+                        GetContestedResourceVoteTallyRequest.prototype.contestId = $util.newBuffer([]);
+
+                        /**
+                         * GetContestedResourceVoteTallyRequest prove.
+                         * @member {boolean} prove
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @instance
+                         */
+                        GetContestedResourceVoteTallyRequest.prototype.prove = false;
+
+                        /**
+                         * Creates a new GetContestedResourceVoteTallyRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest} GetContestedResourceVoteTallyRequest instance
+                         */
+                        GetContestedResourceVoteTallyRequest.create = function create(properties) {
+                            return new GetContestedResourceVoteTallyRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetContestedResourceVoteTallyRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest} message GetContestedResourceVoteTallyRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetContestedResourceVoteTallyRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.contestId != null && Object.hasOwnProperty.call(message, "contestId"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.contestId);
+                            if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
+                                writer.uint32(/* id 2, wireType 0 =*/16).bool(message.prove);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetContestedResourceVoteTallyRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyRequest} message GetContestedResourceVoteTallyRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetContestedResourceVoteTallyRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetContestedResourceVoteTallyRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest} GetContestedResourceVoteTallyRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetContestedResourceVoteTallyRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.contestId = reader.bytes();
+                                    break;
+                                case 2:
+                                    message.prove = reader.bool();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetContestedResourceVoteTallyRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest} GetContestedResourceVoteTallyRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetContestedResourceVoteTallyRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetContestedResourceVoteTallyRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetContestedResourceVoteTallyRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.contestId != null && message.hasOwnProperty("contestId"))
+                                if (!(message.contestId && typeof message.contestId.length === "number" || $util.isString(message.contestId)))
+                                    return "contestId: buffer expected";
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                if (typeof message.prove !== "boolean")
+                                    return "prove: boolean expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetContestedResourceVoteTallyRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest} GetContestedResourceVoteTallyRequest
+                         */
+                        GetContestedResourceVoteTallyRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest();
+                            if (object.contestId != null)
+                                if (typeof object.contestId === "string")
+                                    $util.base64.decode(object.contestId, message.contestId = $util.newBuffer($util.base64.length(object.contestId)), 0);
+                                else if (object.contestId.length >= 0)
+                                    message.contestId = object.contestId;
+                            if (object.prove != null)
+                                message.prove = Boolean(object.prove);
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetContestedResourceVoteTallyRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest} message GetContestedResourceVoteTallyRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetContestedResourceVoteTallyRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                if (options.bytes === String)
+                                    object.contestId = "";
+                                else {
+                                    object.contestId = [];
+                                    if (options.bytes !== Array)
+                                        object.contestId = $util.newBuffer(object.contestId);
+                                }
+                                object.prove = false;
+                            }
+                            if (message.contestId != null && message.hasOwnProperty("contestId"))
+                                object.contestId = options.bytes === String ? $util.base64.encode(message.contestId, 0, message.contestId.length) : options.bytes === Array ? Array.prototype.slice.call(message.contestId) : message.contestId;
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                object.prove = message.prove;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetContestedResourceVoteTallyRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetContestedResourceVoteTallyRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetContestedResourceVoteTallyRequest;
+                    })();
+
+                    v0.GetContestedResourceVoteTallyResponse = (function() {
+
+                        /**
+                         * Properties of a GetContestedResourceVoteTallyResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetContestedResourceVoteTallyResponse
+                         * @property {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally|null} [voteTally] GetContestedResourceVoteTallyResponse voteTally
+                         * @property {org.dash.platform.dapi.v0.IProof|null} [proof] GetContestedResourceVoteTallyResponse proof
+                         * @property {org.dash.platform.dapi.v0.IResponseMetadata|null} [metadata] GetContestedResourceVoteTallyResponse metadata
+                         */
+
+                        /**
+                         * Constructs a new GetContestedResourceVoteTallyResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetContestedResourceVoteTallyResponse.
+                         * @implements IGetContestedResourceVoteTallyResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyResponse=} [properties] Properties to set
+                         */
+                        function GetContestedResourceVoteTallyResponse(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetContestedResourceVoteTallyResponse voteTally.
+                         * @member {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally|null|undefined} voteTally
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @instance
+                         */
+                        GetContestedResourceVoteTallyResponse.prototype.voteTally = null;
+
+                        /**
+                         * GetContestedResourceVoteTallyResponse proof.
+                         * @member {org.dash.platform.dapi.v0.IProof|null|undefined} proof
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @instance
+                         */
+                        GetContestedResourceVoteTallyResponse.prototype.proof = null;
+
+                        /**
+                         * GetContestedResourceVoteTallyResponse metadata.
+                         * @member {org.dash.platform.dapi.v0.IResponseMetadata|null|undefined} metadata
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @instance
+                         */
+                        GetContestedResourceVoteTallyResponse.prototype.metadata = null;
+
+                        // OneOf field names bound to virtual getters and setters
+                        var $oneOfFields;
+
+                        /**
+                         * GetContestedResourceVoteTallyResponse result.
+                         * @member {"voteTally"|"proof"|undefined} result
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @instance
+                         */
+                        Object.defineProperty(GetContestedResourceVoteTallyResponse.prototype, "result", {
+                            get: $util.oneOfGetter($oneOfFields = ["voteTally", "proof"]),
+                            set: $util.oneOfSetter($oneOfFields)
+                        });
+
+                        /**
+                         * Creates a new GetContestedResourceVoteTallyResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} GetContestedResourceVoteTallyResponse instance
+                         */
+                        GetContestedResourceVoteTallyResponse.create = function create(properties) {
+                            return new GetContestedResourceVoteTallyResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetContestedResourceVoteTallyResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyResponse} message GetContestedResourceVoteTallyResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetContestedResourceVoteTallyResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.voteTally != null && Object.hasOwnProperty.call(message, "voteTally"))
+                                $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.encode(message.voteTally, writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                            if (message.proof != null && Object.hasOwnProperty.call(message, "proof"))
+                                $root.org.dash.platform.dapi.v0.Proof.encode(message.proof, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.metadata != null && Object.hasOwnProperty.call(message, "metadata"))
+                                $root.org.dash.platform.dapi.v0.ResponseMetadata.encode(message.metadata, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetContestedResourceVoteTallyResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetContestedResourceVoteTallyResponse} message GetContestedResourceVoteTallyResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetContestedResourceVoteTallyResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetContestedResourceVoteTallyResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} GetContestedResourceVoteTallyResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetContestedResourceVoteTallyResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.voteTally = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.decode(reader, reader.uint32());
+                                    break;
+                                case 2:
+                                    message.proof = $root.org.dash.platform.dapi.v0.Proof.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.decode(reader, reader.uint32());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetContestedResourceVoteTallyResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} GetContestedResourceVoteTallyResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetContestedResourceVoteTallyResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetContestedResourceVoteTallyResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetContestedResourceVoteTallyResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            var properties = {};
+                            if (message.voteTally != null && message.hasOwnProperty("voteTally")) {
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.verify(message.voteTally);
+                                    if (error)
+                                        return "voteTally." + error;
+                                }
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                if (properties.result === 1)
+                                    return "result: multiple values";
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.Proof.verify(message.proof);
+                                    if (error)
+                                        return "proof." + error;
+                                }
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata")) {
+                                var error = $root.org.dash.platform.dapi.v0.ResponseMetadata.verify(message.metadata);
+                                if (error)
+                                    return "metadata." + error;
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetContestedResourceVoteTallyResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} GetContestedResourceVoteTallyResponse
+                         */
+                        GetContestedResourceVoteTallyResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse();
+                            if (object.voteTally != null) {
+                                if (typeof object.voteTally !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.voteTally: object expected");
+                                message.voteTally = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.fromObject(object.voteTally);
+                            }
+                            if (object.proof != null) {
+                                if (typeof object.proof !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.proof: object expected");
+                                message.proof = $root.org.dash.platform.dapi.v0.Proof.fromObject(object.proof);
+                            }
+                            if (object.metadata != null) {
+                                if (typeof object.metadata !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.metadata: object expected");
+                                message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.fromObject(object.metadata);
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetContestedResourceVoteTallyResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse} message GetContestedResourceVoteTallyResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetContestedResourceVoteTallyResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults)
+                                object.metadata = null;
+                            if (message.voteTally != null && message.hasOwnProperty("voteTally")) {
+                                object.voteTally = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.toObject(message.voteTally, options);
+                                if (options.oneofs)
+                                    object.result = "voteTally";
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                object.proof = $root.org.dash.platform.dapi.v0.Proof.toObject(message.proof, options);
+                                if (options.oneofs)
+                                    object.result = "proof";
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata"))
+                                object.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.toObject(message.metadata, options);
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetContestedResourceVoteTallyResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetContestedResourceVoteTallyResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        GetContestedResourceVoteTallyResponse.ContenderVotes = (function() {
+
+                            /**
+                             * Properties of a ContenderVotes.
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                             * @interface IContenderVotes
+                             * @property {Uint8Array|null} [identityId] ContenderVotes identityId
+                             * @property {number|Long|null} [voteCount] ContenderVotes voteCount
+                             */
+
+                            /**
+                             * Constructs a new ContenderVotes.
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                             * @classdesc Represents a ContenderVotes.
+                             * @implements IContenderVotes
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes=} [properties] Properties to set
+                             */
+                            function ContenderVotes(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * ContenderVotes identityId.
+                             * @member {Uint8Array} identityId
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @instance
+                             */
+                            ContenderVotes.prototype.identityId = $util.newBuffer([]);
+
+                            /**
+                             * ContenderVotes voteCount.
+                             * @member {number|Long} voteCount
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @instance
+                             */
+                            ContenderVotes.prototype.voteCount = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Creates a new ContenderVotes instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes} ContenderVotes instance
+                             */
+                            ContenderVotes.create = function create(properties) {
+                                return new ContenderVotes(properties);
+                            };
+
+                            /**
+                             * Encodes the specified ContenderVotes message. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes} message ContenderVotes message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            ContenderVotes.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.identityId != null && Object.hasOwnProperty.call(message, "identityId"))
+                                    writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.identityId);
+                                if (message.voteCount != null && Object.hasOwnProperty.call(message, "voteCount"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.voteCount);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified ContenderVotes message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes} message ContenderVotes message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            ContenderVotes.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a ContenderVotes message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes} ContenderVotes
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            ContenderVotes.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.identityId = reader.bytes();
+                                        break;
+                                    case 2:
+                                        message.voteCount = reader.uint64();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a ContenderVotes message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes} ContenderVotes
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            ContenderVotes.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a ContenderVotes message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            ContenderVotes.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                    if (!(message.identityId && typeof message.identityId.length === "number" || $util.isString(message.identityId)))
+                                        return "identityId: buffer expected";
+                                if (message.voteCount != null && message.hasOwnProperty("voteCount"))
+                                    if (!$util.isInteger(message.voteCount) && !(message.voteCount && $util.isInteger(message.voteCount.low) && $util.isInteger(message.voteCount.high)))
+                                        return "voteCount: integer|Long expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates a ContenderVotes message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes} ContenderVotes
+                             */
+                            ContenderVotes.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes();
+                                if (object.identityId != null)
+                                    if (typeof object.identityId === "string")
+                                        $util.base64.decode(object.identityId, message.identityId = $util.newBuffer($util.base64.length(object.identityId)), 0);
+                                    else if (object.identityId.length >= 0)
+                                        message.identityId = object.identityId;
+                                if (object.voteCount != null)
+                                    if ($util.Long)
+                                        (message.voteCount = $util.Long.fromValue(object.voteCount)).unsigned = true;
+                                    else if (typeof object.voteCount === "string")
+                                        message.voteCount = parseInt(object.voteCount, 10);
+                                    else if (typeof object.voteCount === "number")
+                                        message.voteCount = object.voteCount;
+                                    else if (typeof object.voteCount === "object")
+                                        message.voteCount = new $util.LongBits(object.voteCount.low >>> 0, object.voteCount.high >>> 0).toNumber(true);
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a ContenderVotes message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes} message ContenderVotes
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            ContenderVotes.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    if (options.bytes === String)
+                                        object.identityId = "";
+                                    else {
+                                        object.identityId = [];
+                                        if (options.bytes !== Array)
+                                            object.identityId = $util.newBuffer(object.identityId);
+                                    }
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.voteCount = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.voteCount = options.longs === String ? "0" : 0;
+                                }
+                                if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                    object.identityId = options.bytes === String ? $util.base64.encode(message.identityId, 0, message.identityId.length) : options.bytes === Array ? Array.prototype.slice.call(message.identityId) : message.identityId;
+                                if (message.voteCount != null && message.hasOwnProperty("voteCount"))
+                                    if (typeof message.voteCount === "number")
+                                        object.voteCount = options.longs === String ? String(message.voteCount) : message.voteCount;
+                                    else
+                                        object.voteCount = options.longs === String ? $util.Long.prototype.toString.call(message.voteCount) : options.longs === Number ? new $util.LongBits(message.voteCount.low >>> 0, message.voteCount.high >>> 0).toNumber(true) : message.voteCount;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this ContenderVotes to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            ContenderVotes.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return ContenderVotes;
+                        })();
+
+                        GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally = (function() {
+
+                            /**
+                             * Properties of a ContestedResourceVoteTally.
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                             * @interface IContestedResourceVoteTally
+                             * @property {Array.<org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes>|null} [contenders] ContestedResourceVoteTally contenders
+                             * @property {number|Long|null} [abstainVoteCount] ContestedResourceVoteTally abstainVoteCount
+                             */
+
+                            /**
+                             * Constructs a new ContestedResourceVoteTally.
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse
+                             * @classdesc Represents a ContestedResourceVoteTally.
+                             * @implements IContestedResourceVoteTally
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally=} [properties] Properties to set
+                             */
+                            function ContestedResourceVoteTally(properties) {
+                                this.contenders = [];
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * ContestedResourceVoteTally contenders.
+                             * @member {Array.<org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContenderVotes>} contenders
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @instance
+                             */
+                            ContestedResourceVoteTally.prototype.contenders = $util.emptyArray;
+
+                            /**
+                             * ContestedResourceVoteTally abstainVoteCount.
+                             * @member {number|Long} abstainVoteCount
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @instance
+                             */
+                            ContestedResourceVoteTally.prototype.abstainVoteCount = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Creates a new ContestedResourceVoteTally instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally} ContestedResourceVoteTally instance
+                             */
+                            ContestedResourceVoteTally.create = function create(properties) {
+                                return new ContestedResourceVoteTally(properties);
+                            };
+
+                            /**
+                             * Encodes the specified ContestedResourceVoteTally message. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally} message ContestedResourceVoteTally message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            ContestedResourceVoteTally.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.contenders != null && message.contenders.length)
+                                    for (var i = 0; i < message.contenders.length; ++i)
+                                        $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.encode(message.contenders[i], writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                                if (message.abstainVoteCount != null && Object.hasOwnProperty.call(message, "abstainVoteCount"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.abstainVoteCount);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified ContestedResourceVoteTally message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.IContestedResourceVoteTally} message ContestedResourceVoteTally message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            ContestedResourceVoteTally.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a ContestedResourceVoteTally message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally} ContestedResourceVoteTally
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            ContestedResourceVoteTally.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        if (!(message.contenders && message.contenders.length))
+                                            message.contenders = [];
+                                        message.contenders.push($root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.decode(reader, reader.uint32()));
+                                        break;
+                                    case 2:
+                                        message.abstainVoteCount = reader.uint64();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a ContestedResourceVoteTally message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally} ContestedResourceVoteTally
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            ContestedResourceVoteTally.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a ContestedResourceVoteTally message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            ContestedResourceVoteTally.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.contenders != null && message.hasOwnProperty("contenders")) {
+                                    if (!Array.isArray(message.contenders))
+                                        return "contenders: array expected";
+                                    for (var i = 0; i < message.contenders.length; ++i) {
+                                        var error = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.verify(message.contenders[i]);
+                                        if (error)
+                                            return "contenders." + error;
+                                    }
+                                }
+                                if (message.abstainVoteCount != null && message.hasOwnProperty("abstainVoteCount"))
+                                    if (!$util.isInteger(message.abstainVoteCount) && !(message.abstainVoteCount && $util.isInteger(message.abstainVoteCount.low) && $util.isInteger(message.abstainVoteCount.high)))
+                                        return "abstainVoteCount: integer|Long expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates a ContestedResourceVoteTally message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally} ContestedResourceVoteTally
+                             */
+                            ContestedResourceVoteTally.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally();
+                                if (object.contenders) {
+                                    if (!Array.isArray(object.contenders))
+                                        throw TypeError(".org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.contenders: array expected");
+                                    message.contenders = [];
+                                    for (var i = 0; i < object.contenders.length; ++i) {
+                                        if (typeof object.contenders[i] !== "object")
+                                            throw TypeError(".org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.contenders: object expected");
+                                        message.contenders[i] = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.fromObject(object.contenders[i]);
+                                    }
+                                }
+                                if (object.abstainVoteCount != null)
+                                    if ($util.Long)
+                                        (message.abstainVoteCount = $util.Long.fromValue(object.abstainVoteCount)).unsigned = true;
+                                    else if (typeof object.abstainVoteCount === "string")
+                                        message.abstainVoteCount = parseInt(object.abstainVoteCount, 10);
+                                    else if (typeof object.abstainVoteCount === "number")
+                                        message.abstainVoteCount = object.abstainVoteCount;
+                                    else if (typeof object.abstainVoteCount === "object")
+                                        message.abstainVoteCount = new $util.LongBits(object.abstainVoteCount.low >>> 0, object.abstainVoteCount.high >>> 0).toNumber(true);
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a ContestedResourceVoteTally message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally} message ContestedResourceVoteTally
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            ContestedResourceVoteTally.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.arrays || options.defaults)
+                                    object.contenders = [];
+                                if (options.defaults)
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.abstainVoteCount = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.abstainVoteCount = options.longs === String ? "0" : 0;
+                                if (message.contenders && message.contenders.length) {
+                                    object.contenders = [];
+                                    for (var j = 0; j < message.contenders.length; ++j)
+                                        object.contenders[j] = $root.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.toObject(message.contenders[j], options);
+                                }
+                                if (message.abstainVoteCount != null && message.hasOwnProperty("abstainVoteCount"))
+                                    if (typeof message.abstainVoteCount === "number")
+                                        object.abstainVoteCount = options.longs === String ? String(message.abstainVoteCount) : message.abstainVoteCount;
+                                    else
+                                        object.abstainVoteCount = options.longs === String ? $util.Long.prototype.toString.call(message.abstainVoteCount) : options.longs === Number ? new $util.LongBits(message.abstainVoteCount.low >>> 0, message.abstainVoteCount.high >>> 0).toNumber(true) : message.abstainVoteCount;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this ContestedResourceVoteTally to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            ContestedResourceVoteTally.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return ContestedResourceVoteTally;
+                        })();
+
+                        return GetContestedResourceVoteTallyResponse;
+                    })();
+
+                    v0.EstimateStateTransitionFeeRequest = (function() {
+
+                        /**
+                         * Properties of an EstimateStateTransitionFeeRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IEstimateStateTransitionFeeRequest
+                         * @property {Uint8Array|null} [stateTransition] EstimateStateTransitionFeeRequest stateTransition
+                         */
+
+                        /**
+                         * Constructs a new EstimateStateTransitionFeeRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents an EstimateStateTransitionFeeRequest.
+                         * @implements IEstimateStateTransitionFeeRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest=} [properties] Properties to set
+                         */
+                        function EstimateStateTransitionFeeRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * EstimateStateTransitionFeeRequest stateTransition.
+                         * @member {Uint8Array} stateTransition
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @instance
+                         */
+                        EstimateStateTransitionFeeRequest.prototype.stateTransition = $util.newBuffer([]);
+
+                        /**
+                         * Creates a new EstimateStateTransitionFeeRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest} EstimateStateTransitionFeeRequest instance
+                         */
+                        EstimateStateTransitionFeeRequest.create = function create(properties) {
+                            return new EstimateStateTransitionFeeRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified EstimateStateTransitionFeeRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest} message EstimateStateTransitionFeeRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        EstimateStateTransitionFeeRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.stateTransition != null && Object.hasOwnProperty.call(message, "stateTransition"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.stateTransition);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified EstimateStateTransitionFeeRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeRequest} message EstimateStateTransitionFeeRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        EstimateStateTransitionFeeRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes an EstimateStateTransitionFeeRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest} EstimateStateTransitionFeeRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        EstimateStateTransitionFeeRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.stateTransition = reader.bytes();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes an EstimateStateTransitionFeeRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest} EstimateStateTransitionFeeRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        EstimateStateTransitionFeeRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies an EstimateStateTransitionFeeRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        EstimateStateTransitionFeeRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.stateTransition != null && message.hasOwnProperty("stateTransition"))
+                                if (!(message.stateTransition && typeof message.stateTransition.length === "number" || $util.isString(message.stateTransition)))
+                                    return "stateTransition: buffer expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates an EstimateStateTransitionFeeRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest} EstimateStateTransitionFeeRequest
+                         */
+                        EstimateStateTransitionFeeRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest();
+                            if (object.stateTransition != null)
+                                if (typeof object.stateTransition === "string")
+                                    $util.base64.decode(object.stateTransition, message.stateTransition = $util.newBuffer($util.base64.length(object.stateTransition)), 0);
+                                else if (object.stateTransition.length >= 0)
+                                    message.stateTransition = object.stateTransition;
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from an EstimateStateTransitionFeeRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest} message EstimateStateTransitionFeeRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        EstimateStateTransitionFeeRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults)
+                                if (options.bytes === String)
+                                    object.stateTransition = "";
+                                else {
+                                    object.stateTransition = [];
+                                    if (options.bytes !== Array)
+                                        object.stateTransition = $util.newBuffer(object.stateTransition);
+                                }
+                            if (message.stateTransition != null && message.hasOwnProperty("stateTransition"))
+                                object.stateTransition = options.bytes === String ? $util.base64.encode(message.stateTransition, 0, message.stateTransition.length) : options.bytes === Array ? Array.prototype.slice.call(message.stateTransition) : message.stateTransition;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this EstimateStateTransitionFeeRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        EstimateStateTransitionFeeRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return EstimateStateTransitionFeeRequest;
+                    })();
+
+                    v0.EstimateStateTransitionFeeResponse = (function() {
+
+                        /**
+                         * Properties of an EstimateStateTransitionFeeResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IEstimateStateTransitionFeeResponse
+                         * @property {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate|null} [feeEstimate] EstimateStateTransitionFeeResponse feeEstimate
+                         * @property {org.dash.platform.dapi.v0.IStateTransitionBroadcastError|null} [error] EstimateStateTransitionFeeResponse error
+                         * @property {org.dash.platform.dapi.v0.IResponseMetadata|null} [metadata] EstimateStateTransitionFeeResponse metadata
+                         */
+
+                        /**
+                         * Constructs a new EstimateStateTransitionFeeResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents an EstimateStateTransitionFeeResponse.
+                         * @implements IEstimateStateTransitionFeeResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeResponse=} [properties] Properties to set
+                         */
+                        function EstimateStateTransitionFeeResponse(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * EstimateStateTransitionFeeResponse feeEstimate.
+                         * @member {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate|null|undefined} feeEstimate
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @instance
+                         */
+                        EstimateStateTransitionFeeResponse.prototype.feeEstimate = null;
+
+                        /**
+                         * EstimateStateTransitionFeeResponse error.
+                         * @member {org.dash.platform.dapi.v0.IStateTransitionBroadcastError|null|undefined} error
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @instance
+                         */
+                        EstimateStateTransitionFeeResponse.prototype.error = null;
+
+                        /**
+                         * EstimateStateTransitionFeeResponse metadata.
+                         * @member {org.dash.platform.dapi.v0.IResponseMetadata|null|undefined} metadata
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @instance
+                         */
+                        EstimateStateTransitionFeeResponse.prototype.metadata = null;
+
+                        /**
+                         * Creates a new EstimateStateTransitionFeeResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} EstimateStateTransitionFeeResponse instance
+                         */
+                        EstimateStateTransitionFeeResponse.create = function create(properties) {
+                            return new EstimateStateTransitionFeeResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified EstimateStateTransitionFeeResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeResponse} message EstimateStateTransitionFeeResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        EstimateStateTransitionFeeResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.feeEstimate != null && Object.hasOwnProperty.call(message, "feeEstimate"))
+                                $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.encode(message.feeEstimate, writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                            if (message.error != null && Object.hasOwnProperty.call(message, "error"))
+                                $root.org.dash.platform.dapi.v0.StateTransitionBroadcastError.encode(message.error, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.metadata != null && Object.hasOwnProperty.call(message, "metadata"))
+                                $root.org.dash.platform.dapi.v0.ResponseMetadata.encode(message.metadata, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified EstimateStateTransitionFeeResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IEstimateStateTransitionFeeResponse} message EstimateStateTransitionFeeResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        EstimateStateTransitionFeeResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes an EstimateStateTransitionFeeResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} EstimateStateTransitionFeeResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        EstimateStateTransitionFeeResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.feeEstimate = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.decode(reader, reader.uint32());
+                                    break;
+                                case 2:
+                                    message.error = $root.org.dash.platform.dapi.v0.StateTransitionBroadcastError.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.decode(reader, reader.uint32());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes an EstimateStateTransitionFeeResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} EstimateStateTransitionFeeResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        EstimateStateTransitionFeeResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies an EstimateStateTransitionFeeResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        EstimateStateTransitionFeeResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.feeEstimate != null && message.hasOwnProperty("feeEstimate")) {
+                                var error = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.verify(message.feeEstimate);
+                                if (error)
+                                    return "feeEstimate." + error;
+                            }
+                            if (message.error != null && message.hasOwnProperty("error")) {
+                                var error = $root.org.dash.platform.dapi.v0.StateTransitionBroadcastError.verify(message.error);
+                                if (error)
+                                    return "error." + error;
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata")) {
+                                var error = $root.org.dash.platform.dapi.v0.ResponseMetadata.verify(message.metadata);
+                                if (error)
+                                    return "metadata." + error;
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates an EstimateStateTransitionFeeResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} EstimateStateTransitionFeeResponse
+                         */
+                        EstimateStateTransitionFeeResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse();
+                            if (object.feeEstimate != null) {
+                                if (typeof object.feeEstimate !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.feeEstimate: object expected");
+                                message.feeEstimate = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.fromObject(object.feeEstimate);
+                            }
+                            if (object.error != null) {
+                                if (typeof object.error !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.error: object expected");
+                                message.error = $root.org.dash.platform.dapi.v0.StateTransitionBroadcastError.fromObject(object.error);
+                            }
+                            if (object.metadata != null) {
+                                if (typeof object.metadata !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.metadata: object expected");
+                                message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.fromObject(object.metadata);
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from an EstimateStateTransitionFeeResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse} message EstimateStateTransitionFeeResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        EstimateStateTransitionFeeResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                object.feeEstimate = null;
+                                object.error = null;
+                                object.metadata = null;
+                            }
+                            if (message.feeEstimate != null && message.hasOwnProperty("feeEstimate"))
+                                object.feeEstimate = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.toObject(message.feeEstimate, options);
+                            if (message.error != null && message.hasOwnProperty("error"))
+                                object.error = $root.org.dash.platform.dapi.v0.StateTransitionBroadcastError.toObject(message.error, options);
+                            if (message.metadata != null && message.hasOwnProperty("metadata"))
+                                object.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.toObject(message.metadata, options);
+                            return object;
+                        };
+
+                        /**
+                         * Converts this EstimateStateTransitionFeeResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        EstimateStateTransitionFeeResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        EstimateStateTransitionFeeResponse.EpochStorageFee = (function() {
+
+                            /**
+                             * Properties of an EpochStorageFee.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @interface IEpochStorageFee
+                             * @property {number|null} [epochIndex] EpochStorageFee epochIndex
+                             * @property {number|Long|null} [amount] EpochStorageFee amount
+                             */
+
+                            /**
+                             * Constructs a new EpochStorageFee.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @classdesc Represents an EpochStorageFee.
+                             * @implements IEpochStorageFee
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee=} [properties] Properties to set
+                             */
+                            function EpochStorageFee(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * EpochStorageFee epochIndex.
+                             * @member {number} epochIndex
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @instance
+                             */
+                            EpochStorageFee.prototype.epochIndex = 0;
+
+                            /**
+                             * EpochStorageFee amount.
+                             * @member {number|Long} amount
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @instance
+                             */
+                            EpochStorageFee.prototype.amount = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Creates a new EpochStorageFee instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee} EpochStorageFee instance
+                             */
+                            EpochStorageFee.create = function create(properties) {
+                                return new EpochStorageFee(properties);
+                            };
+
+                            /**
+                             * Encodes the specified EpochStorageFee message. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee} message EpochStorageFee message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            EpochStorageFee.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.epochIndex != null && Object.hasOwnProperty.call(message, "epochIndex"))
+                                    writer.uint32(/* id 1, wireType 0 =*/8).uint32(message.epochIndex);
+                                if (message.amount != null && Object.hasOwnProperty.call(message, "amount"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.amount);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified EpochStorageFee message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee} message EpochStorageFee message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            EpochStorageFee.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes an EpochStorageFee message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee} EpochStorageFee
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            EpochStorageFee.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.epochIndex = reader.uint32();
+                                        break;
+                                    case 2:
+                                        message.amount = reader.uint64();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes an EpochStorageFee message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee} EpochStorageFee
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            EpochStorageFee.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies an EpochStorageFee message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            EpochStorageFee.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.epochIndex != null && message.hasOwnProperty("epochIndex"))
+                                    if (!$util.isInteger(message.epochIndex))
+                                        return "epochIndex: integer expected";
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (!$util.isInteger(message.amount) && !(message.amount && $util.isInteger(message.amount.low) && $util.isInteger(message.amount.high)))
+                                        return "amount: integer|Long expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates an EpochStorageFee message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee} EpochStorageFee
+                             */
+                            EpochStorageFee.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee();
+                                if (object.epochIndex != null)
+                                    message.epochIndex = object.epochIndex >>> 0;
+                                if (object.amount != null)
+                                    if ($util.Long)
+                                        (message.amount = $util.Long.fromValue(object.amount)).unsigned = true;
+                                    else if (typeof object.amount === "string")
+                                        message.amount = parseInt(object.amount, 10);
+                                    else if (typeof object.amount === "number")
+                                        message.amount = object.amount;
+                                    else if (typeof object.amount === "object")
+                                        message.amount = new $util.LongBits(object.amount.low >>> 0, object.amount.high >>> 0).toNumber(true);
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from an EpochStorageFee message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee} message EpochStorageFee
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            EpochStorageFee.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    object.epochIndex = 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.amount = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.amount = options.longs === String ? "0" : 0;
+                                }
+                                if (message.epochIndex != null && message.hasOwnProperty("epochIndex"))
+                                    object.epochIndex = message.epochIndex;
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (typeof message.amount === "number")
+                                        object.amount = options.longs === String ? String(message.amount) : message.amount;
+                                    else
+                                        object.amount = options.longs === String ? $util.Long.prototype.toString.call(message.amount) : options.longs === Number ? new $util.LongBits(message.amount.low >>> 0, message.amount.high >>> 0).toNumber(true) : message.amount;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this EpochStorageFee to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            EpochStorageFee.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return EpochStorageFee;
+                        })();
+
+                        EstimateStateTransitionFeeResponse.IdentityRefund = (function() {
+
+                            /**
+                             * Properties of an IdentityRefund.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @interface IIdentityRefund
+                             * @property {Uint8Array|null} [identityId] IdentityRefund identityId
+                             * @property {number|Long|null} [amount] IdentityRefund amount
+                             */
+
+                            /**
+                             * Constructs a new IdentityRefund.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @classdesc Represents an IdentityRefund.
+                             * @implements IIdentityRefund
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund=} [properties] Properties to set
+                             */
+                            function IdentityRefund(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * IdentityRefund identityId.
+                             * @member {Uint8Array} identityId
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @instance
+                             */
+                            IdentityRefund.prototype.identityId = $util.newBuffer([]);
+
+                            /**
+                             * IdentityRefund amount.
+                             * @member {number|Long} amount
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @instance
+                             */
+                            IdentityRefund.prototype.amount = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Creates a new IdentityRefund instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund} IdentityRefund instance
+                             */
+                            IdentityRefund.create = function create(properties) {
+                                return new IdentityRefund(properties);
+                            };
+
+                            /**
+                             * Encodes the specified IdentityRefund message. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund} message IdentityRefund message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            IdentityRefund.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.identityId != null && Object.hasOwnProperty.call(message, "identityId"))
+                                    writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.identityId);
+                                if (message.amount != null && Object.hasOwnProperty.call(message, "amount"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.amount);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified IdentityRefund message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund} message IdentityRefund message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            IdentityRefund.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes an IdentityRefund message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund} IdentityRefund
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            IdentityRefund.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.identityId = reader.bytes();
+                                        break;
+                                    case 2:
+                                        message.amount = reader.uint64();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes an IdentityRefund message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund} IdentityRefund
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            IdentityRefund.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies an IdentityRefund message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            IdentityRefund.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                    if (!(message.identityId && typeof message.identityId.length === "number" || $util.isString(message.identityId)))
+                                        return "identityId: buffer expected";
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (!$util.isInteger(message.amount) && !(message.amount && $util.isInteger(message.amount.low) && $util.isInteger(message.amount.high)))
+                                        return "amount: integer|Long expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates an IdentityRefund message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund} IdentityRefund
+                             */
+                            IdentityRefund.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund();
+                                if (object.identityId != null)
+                                    if (typeof object.identityId === "string")
+                                        $util.base64.decode(object.identityId, message.identityId = $util.newBuffer($util.base64.length(object.identityId)), 0);
+                                    else if (object.identityId.length >= 0)
+                                        message.identityId = object.identityId;
+                                if (object.amount != null)
+                                    if ($util.Long)
+                                        (message.amount = $util.Long.fromValue(object.amount)).unsigned = true;
+                                    else if (typeof object.amount === "string")
+                                        message.amount = parseInt(object.amount, 10);
+                                    else if (typeof object.amount === "number")
+                                        message.amount = object.amount;
+                                    else if (typeof object.amount === "object")
+                                        message.amount = new $util.LongBits(object.amount.low >>> 0, object.amount.high >>> 0).toNumber(true);
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from an IdentityRefund message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund} message IdentityRefund
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            IdentityRefund.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    if (options.bytes === String)
+                                        object.identityId = "";
+                                    else {
+                                        object.identityId = [];
+                                        if (options.bytes !== Array)
+                                            object.identityId = $util.newBuffer(object.identityId);
+                                    }
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.amount = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.amount = options.longs === String ? "0" : 0;
+                                }
+                                if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                    object.identityId = options.bytes === String ? $util.base64.encode(message.identityId, 0, message.identityId.length) : options.bytes === Array ? Array.prototype.slice.call(message.identityId) : message.identityId;
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (typeof message.amount === "number")
+                                        object.amount = options.longs === String ? String(message.amount) : message.amount;
+                                    else
+                                        object.amount = options.longs === String ? $util.Long.prototype.toString.call(message.amount) : options.longs === Number ? new $util.LongBits(message.amount.low >>> 0, message.amount.high >>> 0).toNumber(true) : message.amount;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this IdentityRefund to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            IdentityRefund.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return IdentityRefund;
+                        })();
+
+                        EstimateStateTransitionFeeResponse.FeeEstimate = (function() {
+
+                            /**
+                             * Properties of a FeeEstimate.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @interface IFeeEstimate
+                             * @property {number|Long|null} [storageFee] FeeEstimate storageFee
+                             * @property {number|Long|null} [processingFee] FeeEstimate processingFee
+                             * @property {number|Long|null} [documentTypeFees] FeeEstimate documentTypeFees
+                             * @property {Array.<org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee>|null} [storageFeePerEpoch] FeeEstimate storageFeePerEpoch
+                             * @property {Array.<org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund>|null} [refunds] FeeEstimate refunds
+                             * @property {google.protobuf.IUInt64Value|null} [balance] FeeEstimate balance
+                             * @property {google.protobuf.IInt64Value|null} [resultingBalance] FeeEstimate resultingBalance
+                             */
+
+                            /**
+                             * Constructs a new FeeEstimate.
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse
+                             * @classdesc Represents a FeeEstimate.
+                             * @implements IFeeEstimate
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate=} [properties] Properties to set
+                             */
+                            function FeeEstimate(properties) {
+                                this.storageFeePerEpoch = [];
+                                this.refunds = [];
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * FeeEstimate storageFee.
+                             * @member {number|Long} storageFee
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.storageFee = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * FeeEstimate processingFee.
+                             * @member {number|Long} processingFee
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.processingFee = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * FeeEstimate documentTypeFees.
+                             * @member {number|Long} documentTypeFees
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.documentTypeFees = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * FeeEstimate storageFeePerEpoch.
+                             * @member {Array.<org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IEpochStorageFee>} storageFeePerEpoch
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.storageFeePerEpoch = $util.emptyArray;
+
+                            /**
+                             * FeeEstimate refunds.
+                             * @member {Array.<org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IIdentityRefund>} refunds
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.refunds = $util.emptyArray;
+
+                            /**
+                             * FeeEstimate balance.
+                             * @member {google.protobuf.IUInt64Value|null|undefined} balance
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.balance = null;
+
+                            /**
+                             * FeeEstimate resultingBalance.
+                             * @member {google.protobuf.IInt64Value|null|undefined} resultingBalance
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             */
+                            FeeEstimate.prototype.resultingBalance = null;
+
+                            /**
+                             * Creates a new FeeEstimate instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate} FeeEstimate instance
+                             */
+                            FeeEstimate.create = function create(properties) {
+                                return new FeeEstimate(properties);
+                            };
+
+                            /**
+                             * Encodes the specified FeeEstimate message. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate} message FeeEstimate message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            FeeEstimate.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.storageFee != null && Object.hasOwnProperty.call(message, "storageFee"))
+                                    writer.uint32(/* id 1, wireType 0 =*/8).uint64(message.storageFee);
+                                if (message.processingFee != null && Object.hasOwnProperty.call(message, "processingFee"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.processingFee);
+                                if (message.documentTypeFees != null && Object.hasOwnProperty.call(message, "documentTypeFees"))
+                                    writer.uint32(/* id 3, wireType 0 =*/24).uint64(message.documentTypeFees);
+                                if (message.storageFeePerEpoch != null && message.storageFeePerEpoch.length)
+                                    for (var i = 0; i < message.storageFeePerEpoch.length; ++i)
+                                        $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.encode(message.storageFeePerEpoch[i], writer.uint32(/* id 4, wireType 2 =*/34).fork()).ldelim();
+                                if (message.refunds != null && message.refunds.length)
+                                    for (var i = 0; i < message.refunds.length; ++i)
+                                        $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.encode(message.refunds[i], writer.uint32(/* id 5, wireType 2 =*/42).fork()).ldelim();
+                                if (message.balance != null && Object.hasOwnProperty.call(message, "balance"))
+                                    $root.google.protobuf.UInt64Value.encode(message.balance, writer.uint32(/* id 6, wireType 2 =*/50).fork()).ldelim();
+                                if (message.resultingBalance != null && Object.hasOwnProperty.call(message, "resultingBalance"))
+                                    $root.google.protobuf.Int64Value.encode(message.resultingBalance, writer.uint32(/* id 7, wireType 2 =*/58).fork()).ldelim();
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified FeeEstimate message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IFeeEstimate} message FeeEstimate message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            FeeEstimate.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a FeeEstimate message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate} FeeEstimate
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            FeeEstimate.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.storageFee = reader.uint64();
+                                        break;
+                                    case 2:
+                                        message.processingFee = reader.uint64();
+                                        break;
+                                    case 3:
+                                        message.documentTypeFees = reader.uint64();
+                                        break;
+                                    case 4:
+                                        if (!(message.storageFeePerEpoch && message.storageFeePerEpoch.length))
+                                            message.storageFeePerEpoch = [];
+                                        message.storageFeePerEpoch.push($root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.decode(reader, reader.uint32()));
+                                        break;
+                                    case 5:
+                                        if (!(message.refunds && message.refunds.length))
+                                            message.refunds = [];
+                                        message.refunds.push($root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.decode(reader, reader.uint32()));
+                                        break;
+                                    case 6:
+                                        message.balance = $root.google.protobuf.UInt64Value.decode(reader, reader.uint32());
+                                        break;
+                                    case 7:
+                                        message.resultingBalance = $root.google.protobuf.Int64Value.decode(reader, reader.uint32());
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a FeeEstimate message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate} FeeEstimate
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            FeeEstimate.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a FeeEstimate message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            FeeEstimate.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.storageFee != null && message.hasOwnProperty("storageFee"))
+                                    if (!$util.isInteger(message.storageFee) && !(message.storageFee && $util.isInteger(message.storageFee.low) && $util.isInteger(message.storageFee.high)))
+                                        return "storageFee: integer|Long expected";
+                                if (message.processingFee != null && message.hasOwnProperty("processingFee"))
+                                    if (!$util.isInteger(message.processingFee) && !(message.processingFee && $util.isInteger(message.processingFee.low) && $util.isInteger(message.processingFee.high)))
+                                        return "processingFee: integer|Long expected";
+                                if (message.documentTypeFees != null && message.hasOwnProperty("documentTypeFees"))
+                                    if (!$util.isInteger(message.documentTypeFees) && !(message.documentTypeFees && $util.isInteger(message.documentTypeFees.low) && $util.isInteger(message.documentTypeFees.high)))
+                                        return "documentTypeFees: integer|Long expected";
+                                if (message.storageFeePerEpoch != null && message.hasOwnProperty("storageFeePerEpoch")) {
+                                    if (!Array.isArray(message.storageFeePerEpoch))
+                                        return "storageFeePerEpoch: array expected";
+                                    for (var i = 0; i < message.storageFeePerEpoch.length; ++i) {
+                                        var error = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.verify(message.storageFeePerEpoch[i]);
+                                        if (error)
+                                            return "storageFeePerEpoch." + error;
+                                    }
+                                }
+                                if (message.refunds != null && message.hasOwnProperty("refunds")) {
+                                    if (!Array.isArray(message.refunds))
+                                        return "refunds: array expected";
+                                    for (var i = 0; i < message.refunds.length; ++i) {
+                                        var error = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.verify(message.refunds[i]);
+                                        if (error)
+                                            return "refunds." + error;
+                                    }
+                                }
+                                if (message.balance != null && message.hasOwnProperty("balance")) {
+                                    var error = $root.google.protobuf.UInt64Value.verify(message.balance);
+                                    if (error)
+                                        return "balance." + error;
+                                }
+                                if (message.resultingBalance != null && message.hasOwnProperty("resultingBalance")) {
+                                    var error = $root.google.protobuf.Int64Value.verify(message.resultingBalance);
+                                    if (error)
+                                        return "resultingBalance." + error;
+                                }
+                                return null;
+                            };
+
+                            /**
+                             * Creates a FeeEstimate message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate} FeeEstimate
+                             */
+                            FeeEstimate.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate();
+                                if (object.storageFee != null)
+                                    if ($util.Long)
+                                        (message.storageFee = $util.Long.fromValue(object.storageFee)).unsigned = true;
+                                    else if (typeof object.storageFee === "string")
+                                        message.storageFee = parseInt(object.storageFee, 10);
+                                    else if (typeof object.storageFee === "number")
+                                        message.storageFee = object.storageFee;
+                                    else if (typeof object.storageFee === "object")
+                                        message.storageFee = new $util.LongBits(object.storageFee.low >>> 0, object.storageFee.high >>> 0).toNumber(true);
+                                if (object.processingFee != null)
+                                    if ($util.Long)
+                                        (message.processingFee = $util.Long.fromValue(object.processingFee)).unsigned = true;
+                                    else if (typeof object.processingFee === "string")
+                                        message.processingFee = parseInt(object.processingFee, 10);
+                                    else if (typeof object.processingFee === "number")
+                                        message.processingFee = object.processingFee;
+                                    else if (typeof object.processingFee === "object")
+                                        message.processingFee = new $util.LongBits(object.processingFee.low >>> 0, object.processingFee.high >>> 0).toNumber(true);
+                                if (object.documentTypeFees != null)
+                                    if ($util.Long)
+                                        (message.documentTypeFees = $util.Long.fromValue(object.documentTypeFees)).unsigned = true;
+                                    else if (typeof object.documentTypeFees === "string")
+                                        message.documentTypeFees = parseInt(object.documentTypeFees, 10);
+                                    else if (typeof object.documentTypeFees === "number")
+                                        message.documentTypeFees = object.documentTypeFees;
+                                    else if (typeof object.documentTypeFees === "object")
+                                        message.documentTypeFees = new $util.LongBits(object.documentTypeFees.low >>> 0, object.documentTypeFees.high >>> 0).toNumber(true);
+                                if (object.storageFeePerEpoch) {
+                                    if (!Array.isArray(object.storageFeePerEpoch))
+                                        throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.storageFeePerEpoch: array expected");
+                                    message.storageFeePerEpoch = [];
+                                    for (var i = 0; i < object.storageFeePerEpoch.length; ++i) {
+                                        if (typeof object.storageFeePerEpoch[i] !== "object")
+                                            throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.storageFeePerEpoch: object expected");
+                                        message.storageFeePerEpoch[i] = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.fromObject(object.storageFeePerEpoch[i]);
+                                    }
+                                }
+                                if (object.refunds) {
+                                    if (!Array.isArray(object.refunds))
+                                        throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.refunds: array expected");
+                                    message.refunds = [];
+                                    for (var i = 0; i < object.refunds.length; ++i) {
+                                        if (typeof object.refunds[i] !== "object")
+                                            throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.refunds: object expected");
+                                        message.refunds[i] = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.fromObject(object.refunds[i]);
+                                    }
+                                }
+                                if (object.balance != null) {
+                                    if (typeof object.balance !== "object")
+                                        throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.balance: object expected");
+                                    message.balance = $root.google.protobuf.UInt64Value.fromObject(object.balance);
+                                }
+                                if (object.resultingBalance != null) {
+                                    if (typeof object.resultingBalance !== "object")
+                                        throw TypeError(".org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.resultingBalance: object expected");
+                                    message.resultingBalance = $root.google.protobuf.Int64Value.fromObject(object.resultingBalance);
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a FeeEstimate message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate} message FeeEstimate
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            FeeEstimate.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.arrays || options.defaults) {
+                                    object.storageFeePerEpoch = [];
+                                    object.refunds = [];
+                                }
+                                if (options.defaults) {
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.storageFee = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.storageFee = options.longs === String ? "0" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.processingFee = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.processingFee = options.longs === String ? "0" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.documentTypeFees = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.documentTypeFees = options.longs === String ? "0" : 0;
+                                    object.balance = null;
+                                    object.resultingBalance = null;
+                                }
+                                if (message.storageFee != null && message.hasOwnProperty("storageFee"))
+                                    if (typeof message.storageFee === "number")
+                                        object.storageFee = options.longs === String ? String(message.storageFee) : message.storageFee;
+                                    else
+                                        object.storageFee = options.longs === String ? $util.Long.prototype.toString.call(message.storageFee) : options.longs === Number ? new $util.LongBits(message.storageFee.low >>> 0, message.storageFee.high >>> 0).toNumber(true) : message.storageFee;
+                                if (message.processingFee != null && message.hasOwnProperty("processingFee"))
+                                    if (typeof message.processingFee === "number")
+                                        object.processingFee = options.longs === String ? String(message.processingFee) : message.processingFee;
+                                    else
+                                        object.processingFee = options.longs === String ? $util.Long.prototype.toString.call(message.processingFee) : options.longs === Number ? new $util.LongBits(message.processingFee.low >>> 0, message.processingFee.high >>> 0).toNumber(true) : message.processingFee;
+                                if (message.documentTypeFees != null && message.hasOwnProperty("documentTypeFees"))
+                                    if (typeof message.documentTypeFees === "number")
+                                        object.documentTypeFees = options.longs === String ? String(message.documentTypeFees) : message.documentTypeFees;
+                                    else
+                                        object.documentTypeFees = options.longs === String ? $util.Long.prototype.toString.call(message.documentTypeFees) : options.longs === Number ? new $util.LongBits(message.documentTypeFees.low >>> 0, message.documentTypeFees.high >>> 0).toNumber(true) : message.documentTypeFees;
+                                if (message.storageFeePerEpoch && message.storageFeePerEpoch.length) {
+                                    object.storageFeePerEpoch = [];
+                                    for (var j = 0; j < message.storageFeePerEpoch.length; ++j)
+                                        object.storageFeePerEpoch[j] = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.toObject(message.storageFeePerEpoch[j], options);
+                                }
+                                if (message.refunds && message.refunds.length) {
+                                    object.refunds = [];
+                                    for (var j = 0; j < message.refunds.length; ++j)
+                                        object.refunds[j] = $root.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.toObject(message.refunds[j], options);
+                                }
+                                if (message.balance != null && message.hasOwnProperty("balance"))
+                                    object.balance = $root.google.protobuf.UInt64Value.toObject(message.balance, options);
+                                if (message.resultingBalance != null && message.hasOwnProperty("resultingBalance"))
+                                    object.resultingBalance = $root.google.protobuf.Int64Value.toObject(message.resultingBalance, options);
+                                return object;
+                            };
+
+                            /**
+                             * Converts this FeeEstimate to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            FeeEstimate.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return FeeEstimate;
+                        })();
+
+                        return EstimateStateTransitionFeeResponse;
+                    })();
+
+                    v0.GetIdentityWithdrawalsRequest = (function() {
+
+                        /**
+                         * Properties of a GetIdentityWithdrawalsRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetIdentityWithdrawalsRequest
+                         * @property {Uint8Array|null} [identityId] GetIdentityWithdrawalsRequest identityId
+                         * @property {google.protobuf.IUInt32Value|null} [limit] GetIdentityWithdrawalsRequest limit
+                         * @property {Uint8Array|null} [startAfter] GetIdentityWithdrawalsRequest startAfter
+                         * @property {boolean|null} [prove] GetIdentityWithdrawalsRequest prove
+                         */
+
+                        /**
+                         * Constructs a new GetIdentityWithdrawalsRequest.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetIdentityWithdrawalsRequest.
+                         * @implements IGetIdentityWithdrawalsRequest
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest=} [properties] Properties to set
+                         */
+                        function GetIdentityWithdrawalsRequest(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetIdentityWithdrawalsRequest identityId.
+                         * @member {Uint8Array} identityId
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsRequest.prototype.identityId = $util.newBuffer([]);
+
+                        /**
+                         * GetIdentityWithdrawalsRequest limit.
+                         * @member {google.protobuf.IUInt32Value|null|undefined} limit
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsRequest.prototype.limit = null;
+
+                        /**
+                         * GetIdentityWithdrawalsRequest startAfter.
+                         * @member {Uint8Array} startAfter
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsRequest.prototype.startAfter = $util.newBuffer([]);
+
+                        /**
+                         * GetIdentityWithdrawalsRequest prove.
+                         * @member {boolean} prove
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsRequest.prototype.prove = false;
+
+                        /**
+                         * Creates a new GetIdentityWithdrawalsRequest instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest} GetIdentityWithdrawalsRequest instance
+                         */
+                        GetIdentityWithdrawalsRequest.create = function create(properties) {
+                            return new GetIdentityWithdrawalsRequest(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetIdentityWithdrawalsRequest message. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest} message GetIdentityWithdrawalsRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetIdentityWithdrawalsRequest.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.identityId != null && Object.hasOwnProperty.call(message, "identityId"))
+                                writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.identityId);
+                            if (message.limit != null && Object.hasOwnProperty.call(message, "limit"))
+                                $root.google.protobuf.UInt32Value.encode(message.limit, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.startAfter != null && Object.hasOwnProperty.call(message, "startAfter"))
+                                writer.uint32(/* id 3, wireType 2 =*/26).bytes(message.startAfter);
+                            if (message.prove != null && Object.hasOwnProperty.call(message, "prove"))
+                                writer.uint32(/* id 4, wireType 0 =*/32).bool(message.prove);
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetIdentityWithdrawalsRequest message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsRequest} message GetIdentityWithdrawalsRequest message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetIdentityWithdrawalsRequest.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetIdentityWithdrawalsRequest message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest} GetIdentityWithdrawalsRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetIdentityWithdrawalsRequest.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.identityId = reader.bytes();
+                                    break;
+                                case 2:
+                                    message.limit = $root.google.protobuf.UInt32Value.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.startAfter = reader.bytes();
+                                    break;
+                                case 4:
+                                    message.prove = reader.bool();
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetIdentityWithdrawalsRequest message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest} GetIdentityWithdrawalsRequest
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetIdentityWithdrawalsRequest.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetIdentityWithdrawalsRequest message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetIdentityWithdrawalsRequest.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                if (!(message.identityId && typeof message.identityId.length === "number" || $util.isString(message.identityId)))
+                                    return "identityId: buffer expected";
+                            if (message.limit != null && message.hasOwnProperty("limit")) {
+                                var error = $root.google.protobuf.UInt32Value.verify(message.limit);
+                                if (error)
+                                    return "limit." + error;
+                            }
+                            if (message.startAfter != null && message.hasOwnProperty("startAfter"))
+                                if (!(message.startAfter && typeof message.startAfter.length === "number" || $util.isString(message.startAfter)))
+                                    return "startAfter: buffer expected";
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                if (typeof message.prove !== "boolean")
+                                    return "prove: boolean expected";
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetIdentityWithdrawalsRequest message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest} GetIdentityWithdrawalsRequest
+                         */
+                        GetIdentityWithdrawalsRequest.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest();
+                            if (object.identityId != null)
+                                if (typeof object.identityId === "string")
+                                    $util.base64.decode(object.identityId, message.identityId = $util.newBuffer($util.base64.length(object.identityId)), 0);
+                                else if (object.identityId.length >= 0)
+                                    message.identityId = object.identityId;
+                            if (object.limit != null) {
+                                if (typeof object.limit !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.limit: object expected");
+                                message.limit = $root.google.protobuf.UInt32Value.fromObject(object.limit);
+                            }
+                            if (object.startAfter != null)
+                                if (typeof object.startAfter === "string")
+                                    $util.base64.decode(object.startAfter, message.startAfter = $util.newBuffer($util.base64.length(object.startAfter)), 0);
+                                else if (object.startAfter.length >= 0)
+                                    message.startAfter = object.startAfter;
+                            if (object.prove != null)
+                                message.prove = Boolean(object.prove);
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetIdentityWithdrawalsRequest message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest} message GetIdentityWithdrawalsRequest
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetIdentityWithdrawalsRequest.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults) {
+                                if (options.bytes === String)
+                                    object.identityId = "";
+                                else {
+                                    object.identityId = [];
+                                    if (options.bytes !== Array)
+                                        object.identityId = $util.newBuffer(object.identityId);
+                                }
+                                object.limit = null;
+                                if (options.bytes === String)
+                                    object.startAfter = "";
+                                else {
+                                    object.startAfter = [];
+                                    if (options.bytes !== Array)
+                                        object.startAfter = $util.newBuffer(object.startAfter);
+                                }
+                                object.prove = false;
+                            }
+                            if (message.identityId != null && message.hasOwnProperty("identityId"))
+                                object.identityId = options.bytes === String ? $util.base64.encode(message.identityId, 0, message.identityId.length) : options.bytes === Array ? Array.prototype.slice.call(message.identityId) : message.identityId;
+                            if (message.limit != null && message.hasOwnProperty("limit"))
+                                object.limit = $root.google.protobuf.UInt32Value.toObject(message.limit, options);
+                            if (message.startAfter != null && message.hasOwnProperty("startAfter"))
+                                object.startAfter = options.bytes === String ? $util.base64.encode(message.startAfter, 0, message.startAfter.length) : options.bytes === Array ? Array.prototype.slice.call(message.startAfter) : message.startAfter;
+                            if (message.prove != null && message.hasOwnProperty("prove"))
+                                object.prove = message.prove;
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetIdentityWithdrawalsRequest to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetIdentityWithdrawalsRequest.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        return GetIdentityWithdrawalsRequest;
+                    })();
+
+                    v0.GetIdentityWithdrawalsResponse = (function() {
+
+                        /**
+                         * Properties of a GetIdentityWithdrawalsResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @interface IGetIdentityWithdrawalsResponse
+                         * @property {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals|null} [withdrawals] GetIdentityWithdrawalsResponse withdrawals
+                         * @property {org.dash.platform.dapi.v0.IProof|null} [proof] GetIdentityWithdrawalsResponse proof
+                         * @property {org.dash.platform.dapi.v0.IResponseMetadata|null} [metadata] GetIdentityWithdrawalsResponse metadata
+                         */
+
+                        /**
+                         * Constructs a new GetIdentityWithdrawalsResponse.
+                         * @memberof org.dash.platform.dapi.v0
+                         * @classdesc Represents a GetIdentityWithdrawalsResponse.
+                         * @implements IGetIdentityWithdrawalsResponse
+                         * @constructor
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsResponse=} [properties] Properties to set
+                         */
+                        function GetIdentityWithdrawalsResponse(properties) {
+                            if (properties)
+                                for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                    if (properties[keys[i]] != null)
+                                        this[keys[i]] = properties[keys[i]];
+                        }
+
+                        /**
+                         * GetIdentityWithdrawalsResponse withdrawals.
+                         * @member {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals|null|undefined} withdrawals
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsResponse.prototype.withdrawals = null;
+
+                        /**
+                         * GetIdentityWithdrawalsResponse proof.
+                         * @member {org.dash.platform.dapi.v0.IProof|null|undefined} proof
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsResponse.prototype.proof = null;
+
+                        /**
+                         * GetIdentityWithdrawalsResponse metadata.
+                         * @member {org.dash.platform.dapi.v0.IResponseMetadata|null|undefined} metadata
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @instance
+                         */
+                        GetIdentityWithdrawalsResponse.prototype.metadata = null;
+
+                        // OneOf field names bound to virtual getters and setters
+                        var $oneOfFields;
+
+                        /**
+                         * GetIdentityWithdrawalsResponse result.
+                         * @member {"withdrawals"|"proof"|undefined} result
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @instance
+                         */
+                        Object.defineProperty(GetIdentityWithdrawalsResponse.prototype, "result", {
+                            get: $util.oneOfGetter($oneOfFields = ["withdrawals", "proof"]),
+                            set: $util.oneOfSetter($oneOfFields)
+                        });
+
+                        /**
+                         * Creates a new GetIdentityWithdrawalsResponse instance using the specified properties.
+                         * @function create
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsResponse=} [properties] Properties to set
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} GetIdentityWithdrawalsResponse instance
+                         */
+                        GetIdentityWithdrawalsResponse.create = function create(properties) {
+                            return new GetIdentityWithdrawalsResponse(properties);
+                        };
+
+                        /**
+                         * Encodes the specified GetIdentityWithdrawalsResponse message. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.verify|verify} messages.
+                         * @function encode
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsResponse} message GetIdentityWithdrawalsResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetIdentityWithdrawalsResponse.encode = function encode(message, writer) {
+                            if (!writer)
+                                writer = $Writer.create();
+                            if (message.withdrawals != null && Object.hasOwnProperty.call(message, "withdrawals"))
+                                $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.encode(message.withdrawals, writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                            if (message.proof != null && Object.hasOwnProperty.call(message, "proof"))
+                                $root.org.dash.platform.dapi.v0.Proof.encode(message.proof, writer.uint32(/* id 2, wireType 2 =*/18).fork()).ldelim();
+                            if (message.metadata != null && Object.hasOwnProperty.call(message, "metadata"))
+                                $root.org.dash.platform.dapi.v0.ResponseMetadata.encode(message.metadata, writer.uint32(/* id 3, wireType 2 =*/26).fork()).ldelim();
+                            return writer;
+                        };
+
+                        /**
+                         * Encodes the specified GetIdentityWithdrawalsResponse message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.verify|verify} messages.
+                         * @function encodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.IGetIdentityWithdrawalsResponse} message GetIdentityWithdrawalsResponse message or plain object to encode
+                         * @param {$protobuf.Writer} [writer] Writer to encode to
+                         * @returns {$protobuf.Writer} Writer
+                         */
+                        GetIdentityWithdrawalsResponse.encodeDelimited = function encodeDelimited(message, writer) {
+                            return this.encode(message, writer).ldelim();
+                        };
+
+                        /**
+                         * Decodes a GetIdentityWithdrawalsResponse message from the specified reader or buffer.
+                         * @function decode
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @param {number} [length] Message length if known beforehand
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} GetIdentityWithdrawalsResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetIdentityWithdrawalsResponse.decode = function decode(reader, length) {
+                            if (!(reader instanceof $Reader))
+                                reader = $Reader.create(reader);
+                            var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse();
+                            while (reader.pos < end) {
+                                var tag = reader.uint32();
+                                switch (tag >>> 3) {
+                                case 1:
+                                    message.withdrawals = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.decode(reader, reader.uint32());
+                                    break;
+                                case 2:
+                                    message.proof = $root.org.dash.platform.dapi.v0.Proof.decode(reader, reader.uint32());
+                                    break;
+                                case 3:
+                                    message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.decode(reader, reader.uint32());
+                                    break;
+                                default:
+                                    reader.skipType(tag & 7);
+                                    break;
+                                }
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Decodes a GetIdentityWithdrawalsResponse message from the specified reader or buffer, length delimited.
+                         * @function decodeDelimited
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} GetIdentityWithdrawalsResponse
+                         * @throws {Error} If the payload is not a reader or valid buffer
+                         * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                         */
+                        GetIdentityWithdrawalsResponse.decodeDelimited = function decodeDelimited(reader) {
+                            if (!(reader instanceof $Reader))
+                                reader = new $Reader(reader);
+                            return this.decode(reader, reader.uint32());
+                        };
+
+                        /**
+                         * Verifies a GetIdentityWithdrawalsResponse message.
+                         * @function verify
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {Object.<string,*>} message Plain object to verify
+                         * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                         */
+                        GetIdentityWithdrawalsResponse.verify = function verify(message) {
+                            if (typeof message !== "object" || message === null)
+                                return "object expected";
+                            var properties = {};
+                            if (message.withdrawals != null && message.hasOwnProperty("withdrawals")) {
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.verify(message.withdrawals);
+                                    if (error)
+                                        return "withdrawals." + error;
+                                }
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                if (properties.result === 1)
+                                    return "result: multiple values";
+                                properties.result = 1;
+                                {
+                                    var error = $root.org.dash.platform.dapi.v0.Proof.verify(message.proof);
+                                    if (error)
+                                        return "proof." + error;
+                                }
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata")) {
+                                var error = $root.org.dash.platform.dapi.v0.ResponseMetadata.verify(message.metadata);
+                                if (error)
+                                    return "metadata." + error;
+                            }
+                            return null;
+                        };
+
+                        /**
+                         * Creates a GetIdentityWithdrawalsResponse message from a plain object. Also converts values to their respective internal types.
+                         * @function fromObject
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {Object.<string,*>} object Plain object
+                         * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} GetIdentityWithdrawalsResponse
+                         */
+                        GetIdentityWithdrawalsResponse.fromObject = function fromObject(object) {
+                            if (object instanceof $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse)
+                                return object;
+                            var message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse();
+                            if (object.withdrawals != null) {
+                                if (typeof object.withdrawals !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.withdrawals: object expected");
+                                message.withdrawals = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.fromObject(object.withdrawals);
+                            }
+                            if (object.proof != null) {
+                                if (typeof object.proof !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.proof: object expected");
+                                message.proof = $root.org.dash.platform.dapi.v0.Proof.fromObject(object.proof);
+                            }
+                            if (object.metadata != null) {
+                                if (typeof object.metadata !== "object")
+                                    throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.metadata: object expected");
+                                message.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.fromObject(object.metadata);
+                            }
+                            return message;
+                        };
+
+                        /**
+                         * Creates a plain object from a GetIdentityWithdrawalsResponse message. Also converts values to other types if specified.
+                         * @function toObject
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @static
+                         * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse} message GetIdentityWithdrawalsResponse
+                         * @param {$protobuf.IConversionOptions} [options] Conversion options
+                         * @returns {Object.<string,*>} Plain object
+                         */
+                        GetIdentityWithdrawalsResponse.toObject = function toObject(message, options) {
+                            if (!options)
+                                options = {};
+                            var object = {};
+                            if (options.defaults)
+                                object.metadata = null;
+                            if (message.withdrawals != null && message.hasOwnProperty("withdrawals")) {
+                                object.withdrawals = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.toObject(message.withdrawals, options);
+                                if (options.oneofs)
+                                    object.result = "withdrawals";
+                            }
+                            if (message.proof != null && message.hasOwnProperty("proof")) {
+                                object.proof = $root.org.dash.platform.dapi.v0.Proof.toObject(message.proof, options);
+                                if (options.oneofs)
+                                    object.result = "proof";
+                            }
+                            if (message.metadata != null && message.hasOwnProperty("metadata"))
+                                object.metadata = $root.org.dash.platform.dapi.v0.ResponseMetadata.toObject(message.metadata, options);
+                            return object;
+                        };
+
+                        /**
+                         * Converts this GetIdentityWithdrawalsResponse to JSON.
+                         * @function toJSON
+                         * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                         * @instance
+                         * @returns {Object.<string,*>} JSON object
+                         */
+                        GetIdentityWithdrawalsResponse.prototype.toJSON = function toJSON() {
+                            return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                        };
+
+                        GetIdentityWithdrawalsResponse.Withdrawal = (function() {
+
+                            /**
+                             * Properties of a Withdrawal.
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                             * @interface IWithdrawal
+                             * @property {Uint8Array|null} [id] Withdrawal id
+                             * @property {number|Long|null} [amount] Withdrawal amount
+                             * @property {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status|null} [status] Withdrawal status
+                             * @property {Uint8Array|null} [transactionId] Withdrawal transactionId
+                             * @property {number|null} [transactionSignHeight] Withdrawal transactionSignHeight
+                             * @property {number|null} [expectedCompletionCoreHeight] Withdrawal expectedCompletionCoreHeight
+                             * @property {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason|null} [failureReason] Withdrawal failureReason
+                             * @property {number|Long|null} [refundedAtMs] Withdrawal refundedAtMs
+                             * @property {number|Long|null} [createdAtMs] Withdrawal createdAtMs
+                             * @property {number|Long|null} [updatedAtMs] Withdrawal updatedAtMs
+                             */
+
+                            /**
+                             * Constructs a new Withdrawal.
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                             * @classdesc Represents a Withdrawal.
+                             * @implements IWithdrawal
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal=} [properties] Properties to set
+                             */
+                            function Withdrawal(properties) {
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * Withdrawal id.
+                             * @member {Uint8Array} id
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.id = $util.newBuffer([]);
+
+                            /**
+                             * Withdrawal amount.
+                             * @member {number|Long} amount
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.amount = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Withdrawal status.
+                             * @member {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status} status
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.status = 0;
+
+                            /**
+                             * Withdrawal transactionId.
+                             * @member {Uint8Array} transactionId
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.transactionId = $util.newBuffer([]);
+
+                            /**
+                             * Withdrawal transactionSignHeight.
+                             * @member {number} transactionSignHeight
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.transactionSignHeight = 0;
+
+                            /**
+                             * Withdrawal expectedCompletionCoreHeight.
+                             * @member {number} expectedCompletionCoreHeight
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.expectedCompletionCoreHeight = 0;
+
+                            /**
+                             * Withdrawal failureReason.
+                             * @member {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason} failureReason
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.failureReason = 0;
+
+                            /**
+                             * Withdrawal refundedAtMs.
+                             * @member {number|Long} refundedAtMs
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.refundedAtMs = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Withdrawal createdAtMs.
+                             * @member {number|Long} createdAtMs
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.createdAtMs = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Withdrawal updatedAtMs.
+                             * @member {number|Long} updatedAtMs
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             */
+                            Withdrawal.prototype.updatedAtMs = $util.Long ? $util.Long.fromBits(0,0,true) : 0;
+
+                            /**
+                             * Creates a new Withdrawal instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal} Withdrawal instance
+                             */
+                            Withdrawal.create = function create(properties) {
+                                return new Withdrawal(properties);
+                            };
+
+                            /**
+                             * Encodes the specified Withdrawal message. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal} message Withdrawal message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            Withdrawal.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.id != null && Object.hasOwnProperty.call(message, "id"))
+                                    writer.uint32(/* id 1, wireType 2 =*/10).bytes(message.id);
+                                if (message.amount != null && Object.hasOwnProperty.call(message, "amount"))
+                                    writer.uint32(/* id 2, wireType 0 =*/16).uint64(message.amount);
+                                if (message.status != null && Object.hasOwnProperty.call(message, "status"))
+                                    writer.uint32(/* id 3, wireType 0 =*/24).int32(message.status);
+                                if (message.transactionId != null && Object.hasOwnProperty.call(message, "transactionId"))
+                                    writer.uint32(/* id 4, wireType 2 =*/34).bytes(message.transactionId);
+                                if (message.transactionSignHeight != null && Object.hasOwnProperty.call(message, "transactionSignHeight"))
+                                    writer.uint32(/* id 5, wireType 0 =*/40).uint32(message.transactionSignHeight);
+                                if (message.expectedCompletionCoreHeight != null && Object.hasOwnProperty.call(message, "expectedCompletionCoreHeight"))
+                                    writer.uint32(/* id 6, wireType 0 =*/48).uint32(message.expectedCompletionCoreHeight);
+                                if (message.failureReason != null && Object.hasOwnProperty.call(message, "failureReason"))
+                                    writer.uint32(/* id 7, wireType 0 =*/56).int32(message.failureReason);
+                                if (message.refundedAtMs != null && Object.hasOwnProperty.call(message, "refundedAtMs"))
+                                    writer.uint32(/* id 8, wireType 0 =*/64).uint64(message.refundedAtMs);
+                                if (message.createdAtMs != null && Object.hasOwnProperty.call(message, "createdAtMs"))
+                                    writer.uint32(/* id 9, wireType 0 =*/72).uint64(message.createdAtMs);
+                                if (message.updatedAtMs != null && Object.hasOwnProperty.call(message, "updatedAtMs"))
+                                    writer.uint32(/* id 10, wireType 0 =*/80).uint64(message.updatedAtMs);
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified Withdrawal message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal} message Withdrawal message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            Withdrawal.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a Withdrawal message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal} Withdrawal
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            Withdrawal.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        message.id = reader.bytes();
+                                        break;
+                                    case 2:
+                                        message.amount = reader.uint64();
+                                        break;
+                                    case 3:
+                                        message.status = reader.int32();
+                                        break;
+                                    case 4:
+                                        message.transactionId = reader.bytes();
+                                        break;
+                                    case 5:
+                                        message.transactionSignHeight = reader.uint32();
+                                        break;
+                                    case 6:
+                                        message.expectedCompletionCoreHeight = reader.uint32();
+                                        break;
+                                    case 7:
+                                        message.failureReason = reader.int32();
+                                        break;
+                                    case 8:
+                                        message.refundedAtMs = reader.uint64();
+                                        break;
+                                    case 9:
+                                        message.createdAtMs = reader.uint64();
+                                        break;
+                                    case 10:
+                                        message.updatedAtMs = reader.uint64();
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a Withdrawal message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal} Withdrawal
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            Withdrawal.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a Withdrawal message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            Withdrawal.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.id != null && message.hasOwnProperty("id"))
+                                    if (!(message.id && typeof message.id.length === "number" || $util.isString(message.id)))
+                                        return "id: buffer expected";
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (!$util.isInteger(message.amount) && !(message.amount && $util.isInteger(message.amount.low) && $util.isInteger(message.amount.high)))
+                                        return "amount: integer|Long expected";
+                                if (message.status != null && message.hasOwnProperty("status"))
+                                    switch (message.status) {
+                                    default:
+                                        return "status: enum value expected";
+                                    case 0:
+                                    case 1:
+                                    case 2:
+                                    case 3:
+                                    case 4:
+                                        break;
+                                    }
+                                if (message.transactionId != null && message.hasOwnProperty("transactionId"))
+                                    if (!(message.transactionId && typeof message.transactionId.length === "number" || $util.isString(message.transactionId)))
+                                        return "transactionId: buffer expected";
+                                if (message.transactionSignHeight != null && message.hasOwnProperty("transactionSignHeight"))
+                                    if (!$util.isInteger(message.transactionSignHeight))
+                                        return "transactionSignHeight: integer expected";
+                                if (message.expectedCompletionCoreHeight != null && message.hasOwnProperty("expectedCompletionCoreHeight"))
+                                    if (!$util.isInteger(message.expectedCompletionCoreHeight))
+                                        return "expectedCompletionCoreHeight: integer expected";
+                                if (message.failureReason != null && message.hasOwnProperty("failureReason"))
+                                    switch (message.failureReason) {
+                                    default:
+                                        return "failureReason: enum value expected";
+                                    case 0:
+                                    case 1:
+                                        break;
+                                    }
+                                if (message.refundedAtMs != null && message.hasOwnProperty("refundedAtMs"))
+                                    if (!$util.isInteger(message.refundedAtMs) && !(message.refundedAtMs && $util.isInteger(message.refundedAtMs.low) && $util.isInteger(message.refundedAtMs.high)))
+                                        return "refundedAtMs: integer|Long expected";
+                                if (message.createdAtMs != null && message.hasOwnProperty("createdAtMs"))
+                                    if (!$util.isInteger(message.createdAtMs) && !(message.createdAtMs && $util.isInteger(message.createdAtMs.low) && $util.isInteger(message.createdAtMs.high)))
+                                        return "createdAtMs: integer|Long expected";
+                                if (message.updatedAtMs != null && message.hasOwnProperty("updatedAtMs"))
+                                    if (!$util.isInteger(message.updatedAtMs) && !(message.updatedAtMs && $util.isInteger(message.updatedAtMs.low) && $util.isInteger(message.updatedAtMs.high)))
+                                        return "updatedAtMs: integer|Long expected";
+                                return null;
+                            };
+
+                            /**
+                             * Creates a Withdrawal message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal} Withdrawal
+                             */
+                            Withdrawal.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal();
+                                if (object.id != null)
+                                    if (typeof object.id === "string")
+                                        $util.base64.decode(object.id, message.id = $util.newBuffer($util.base64.length(object.id)), 0);
+                                    else if (object.id.length >= 0)
+                                        message.id = object.id;
+                                if (object.amount != null)
+                                    if ($util.Long)
+                                        (message.amount = $util.Long.fromValue(object.amount)).unsigned = true;
+                                    else if (typeof object.amount === "string")
+                                        message.amount = parseInt(object.amount, 10);
+                                    else if (typeof object.amount === "number")
+                                        message.amount = object.amount;
+                                    else if (typeof object.amount === "object")
+                                        message.amount = new $util.LongBits(object.amount.low >>> 0, object.amount.high >>> 0).toNumber(true);
+                                switch (object.status) {
+                                case "QUEUED":
+                                case 0:
+                                    message.status = 0;
+                                    break;
+                                case "POOLED":
+                                case 1:
+                                    message.status = 1;
+                                    break;
+                                case "BROADCASTED":
+                                case 2:
+                                    message.status = 2;
+                                    break;
+                                case "COMPLETE":
+                                case 3:
+                                    message.status = 3;
+                                    break;
+                                case "EXPIRED":
+                                case 4:
+                                    message.status = 4;
+                                    break;
+                                }
+                                if (object.transactionId != null)
+                                    if (typeof object.transactionId === "string")
+                                        $util.base64.decode(object.transactionId, message.transactionId = $util.newBuffer($util.base64.length(object.transactionId)), 0);
+                                    else if (object.transactionId.length >= 0)
+                                        message.transactionId = object.transactionId;
+                                if (object.transactionSignHeight != null)
+                                    message.transactionSignHeight = object.transactionSignHeight >>> 0;
+                                if (object.expectedCompletionCoreHeight != null)
+                                    message.expectedCompletionCoreHeight = object.expectedCompletionCoreHeight >>> 0;
+                                switch (object.failureReason) {
+                                case "NONE":
+                                case 0:
+                                    message.failureReason = 0;
+                                    break;
+                                case "EXPIRED_BEFORE_MINED":
+                                case 1:
+                                    message.failureReason = 1;
+                                    break;
+                                }
+                                if (object.refundedAtMs != null)
+                                    if ($util.Long)
+                                        (message.refundedAtMs = $util.Long.fromValue(object.refundedAtMs)).unsigned = true;
+                                    else if (typeof object.refundedAtMs === "string")
+                                        message.refundedAtMs = parseInt(object.refundedAtMs, 10);
+                                    else if (typeof object.refundedAtMs === "number")
+                                        message.refundedAtMs = object.refundedAtMs;
+                                    else if (typeof object.refundedAtMs === "object")
+                                        message.refundedAtMs = new $util.LongBits(object.refundedAtMs.low >>> 0, object.refundedAtMs.high >>> 0).toNumber(true);
+                                if (object.createdAtMs != null)
+                                    if ($util.Long)
+                                        (message.createdAtMs = $util.Long.fromValue(object.createdAtMs)).unsigned = true;
+                                    else if (typeof object.createdAtMs === "string")
+                                        message.createdAtMs = parseInt(object.createdAtMs, 10);
+                                    else if (typeof object.createdAtMs === "number")
+                                        message.createdAtMs = object.createdAtMs;
+                                    else if (typeof object.createdAtMs === "object")
+                                        message.createdAtMs = new $util.LongBits(object.createdAtMs.low >>> 0, object.createdAtMs.high >>> 0).toNumber(true);
+                                if (object.updatedAtMs != null)
+                                    if ($util.Long)
+                                        (message.updatedAtMs = $util.Long.fromValue(object.updatedAtMs)).unsigned = true;
+                                    else if (typeof object.updatedAtMs === "string")
+                                        message.updatedAtMs = parseInt(object.updatedAtMs, 10);
+                                    else if (typeof object.updatedAtMs === "number")
+                                        message.updatedAtMs = object.updatedAtMs;
+                                    else if (typeof object.updatedAtMs === "object")
+                                        message.updatedAtMs = new $util.LongBits(object.updatedAtMs.low >>> 0, object.updatedAtMs.high >>> 0).toNumber(true);
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a Withdrawal message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal} message Withdrawal
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            Withdrawal.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.defaults) {
+                                    if (options.bytes === String)
+                                        object.id = "";
+                                    else {
+                                        object.id = [];
+                                        if (options.bytes !== Array)
+                                            object.id = $util.newBuffer(object.id);
+                                    }
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.amount = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.amount = options.longs === String ? "0" : 0;
+                                    object.status = options.enums === String ? "QUEUED" : 0;
+                                    if (options.bytes === String)
+                                        object.transactionId = "";
+                                    else {
+                                        object.transactionId = [];
+                                        if (options.bytes !== Array)
+                                            object.transactionId = $util.newBuffer(object.transactionId);
+                                    }
+                                    object.transactionSignHeight = 0;
+                                    object.expectedCompletionCoreHeight = 0;
+                                    object.failureReason = options.enums === String ? "NONE" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.refundedAtMs = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.refundedAtMs = options.longs === String ? "0" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.createdAtMs = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.createdAtMs = options.longs === String ? "0" : 0;
+                                    if ($util.Long) {
+                                        var long = new $util.Long(0, 0, true);
+                                        object.updatedAtMs = options.longs === String ? long.toString() : options.longs === Number ? long.toNumber() : long;
+                                    } else
+                                        object.updatedAtMs = options.longs === String ? "0" : 0;
+                                }
+                                if (message.id != null && message.hasOwnProperty("id"))
+                                    object.id = options.bytes === String ? $util.base64.encode(message.id, 0, message.id.length) : options.bytes === Array ? Array.prototype.slice.call(message.id) : message.id;
+                                if (message.amount != null && message.hasOwnProperty("amount"))
+                                    if (typeof message.amount === "number")
+                                        object.amount = options.longs === String ? String(message.amount) : message.amount;
+                                    else
+                                        object.amount = options.longs === String ? $util.Long.prototype.toString.call(message.amount) : options.longs === Number ? new $util.LongBits(message.amount.low >>> 0, message.amount.high >>> 0).toNumber(true) : message.amount;
+                                if (message.status != null && message.hasOwnProperty("status"))
+                                    object.status = options.enums === String ? $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status[message.status] : message.status;
+                                if (message.transactionId != null && message.hasOwnProperty("transactionId"))
+                                    object.transactionId = options.bytes === String ? $util.base64.encode(message.transactionId, 0, message.transactionId.length) : options.bytes === Array ? Array.prototype.slice.call(message.transactionId) : message.transactionId;
+                                if (message.transactionSignHeight != null && message.hasOwnProperty("transactionSignHeight"))
+                                    object.transactionSignHeight = message.transactionSignHeight;
+                                if (message.expectedCompletionCoreHeight != null && message.hasOwnProperty("expectedCompletionCoreHeight"))
+                                    object.expectedCompletionCoreHeight = message.expectedCompletionCoreHeight;
+                                if (message.failureReason != null && message.hasOwnProperty("failureReason"))
+                                    object.failureReason = options.enums === String ? $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason[message.failureReason] : message.failureReason;
+                                if (message.refundedAtMs != null && message.hasOwnProperty("refundedAtMs"))
+                                    if (typeof message.refundedAtMs === "number")
+                                        object.refundedAtMs = options.longs === String ? String(message.refundedAtMs) : message.refundedAtMs;
+                                    else
+                                        object.refundedAtMs = options.longs === String ? $util.Long.prototype.toString.call(message.refundedAtMs) : options.longs === Number ? new $util.LongBits(message.refundedAtMs.low >>> 0, message.refundedAtMs.high >>> 0).toNumber(true) : message.refundedAtMs;
+                                if (message.createdAtMs != null && message.hasOwnProperty("createdAtMs"))
+                                    if (typeof message.createdAtMs === "number")
+                                        object.createdAtMs = options.longs === String ? String(message.createdAtMs) : message.createdAtMs;
+                                    else
+                                        object.createdAtMs = options.longs === String ? $util.Long.prototype.toString.call(message.createdAtMs) : options.longs === Number ? new $util.LongBits(message.createdAtMs.low >>> 0, message.createdAtMs.high >>> 0).toNumber(true) : message.createdAtMs;
+                                if (message.updatedAtMs != null && message.hasOwnProperty("updatedAtMs"))
+                                    if (typeof message.updatedAtMs === "number")
+                                        object.updatedAtMs = options.longs === String ? String(message.updatedAtMs) : message.updatedAtMs;
+                                    else
+                                        object.updatedAtMs = options.longs === String ? $util.Long.prototype.toString.call(message.updatedAtMs) : options.longs === Number ? new $util.LongBits(message.updatedAtMs.low >>> 0, message.updatedAtMs.high >>> 0).toNumber(true) : message.updatedAtMs;
+                                return object;
+                            };
+
+                            /**
+                             * Converts this Withdrawal to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            Withdrawal.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            /**
+                             * Status enum.
+                             * @name org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status
+                             * @enum {number}
+                             * @property {number} QUEUED=0 QUEUED value
+                             * @property {number} POOLED=1 POOLED value
+                             * @property {number} BROADCASTED=2 BROADCASTED value
+                             * @property {number} COMPLETE=3 COMPLETE value
+                             * @property {number} EXPIRED=4 EXPIRED value
+                             */
+                            Withdrawal.Status = (function() {
+                                var valuesById = {}, values = Object.create(valuesById);
+                                values[valuesById[0] = "QUEUED"] = 0;
+                                values[valuesById[1] = "POOLED"] = 1;
+                                values[valuesById[2] = "BROADCASTED"] = 2;
+                                values[valuesById[3] = "COMPLETE"] = 3;
+                                values[valuesById[4] = "EXPIRED"] = 4;
+                                return values;
+                            })();
+
+                            /**
+                             * FailureReason enum.
+                             * @name org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason
+                             * @enum {number}
+                             * @property {number} NONE=0 NONE value
+                             * @property {number} EXPIRED_BEFORE_MINED=1 EXPIRED_BEFORE_MINED value
+                             */
+                            Withdrawal.FailureReason = (function() {
+                                var valuesById = {}, values = Object.create(valuesById);
+                                values[valuesById[0] = "NONE"] = 0;
+                                values[valuesById[1] = "EXPIRED_BEFORE_MINED"] = 1;
+                                return values;
+                            })();
+
+                            return Withdrawal;
+                        })();
+
+                        GetIdentityWithdrawalsResponse.Withdrawals = (function() {
+
+                            /**
+                             * Properties of a Withdrawals.
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                             * @interface IWithdrawals
+                             * @property {Array.<org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal>|null} [withdrawals] Withdrawals withdrawals
+                             */
+
+                            /**
+                             * Constructs a new Withdrawals.
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse
+                             * @classdesc Represents a Withdrawals.
+                             * @implements IWithdrawals
+                             * @constructor
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals=} [properties] Properties to set
+                             */
+                            function Withdrawals(properties) {
+                                this.withdrawals = [];
+                                if (properties)
+                                    for (var keys = Object.keys(properties), i = 0; i < keys.length; ++i)
+                                        if (properties[keys[i]] != null)
+                                            this[keys[i]] = properties[keys[i]];
+                            }
+
+                            /**
+                             * Withdrawals withdrawals.
+                             * @member {Array.<org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawal>} withdrawals
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @instance
+                             */
+                            Withdrawals.prototype.withdrawals = $util.emptyArray;
+
+                            /**
+                             * Creates a new Withdrawals instance using the specified properties.
+                             * @function create
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals=} [properties] Properties to set
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals} Withdrawals instance
+                             */
+                            Withdrawals.create = function create(properties) {
+                                return new Withdrawals(properties);
+                            };
+
+                            /**
+                             * Encodes the specified Withdrawals message. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.verify|verify} messages.
+                             * @function encode
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals} message Withdrawals message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            Withdrawals.encode = function encode(message, writer) {
+                                if (!writer)
+                                    writer = $Writer.create();
+                                if (message.withdrawals != null && message.withdrawals.length)
+                                    for (var i = 0; i < message.withdrawals.length; ++i)
+                                        $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.encode(message.withdrawals[i], writer.uint32(/* id 1, wireType 2 =*/10).fork()).ldelim();
+                                return writer;
+                            };
+
+                            /**
+                             * Encodes the specified Withdrawals message, length delimited. Does not implicitly {@link org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.verify|verify} messages.
+                             * @function encodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.IWithdrawals} message Withdrawals message or plain object to encode
+                             * @param {$protobuf.Writer} [writer] Writer to encode to
+                             * @returns {$protobuf.Writer} Writer
+                             */
+                            Withdrawals.encodeDelimited = function encodeDelimited(message, writer) {
+                                return this.encode(message, writer).ldelim();
+                            };
+
+                            /**
+                             * Decodes a Withdrawals message from the specified reader or buffer.
+                             * @function decode
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @param {number} [length] Message length if known beforehand
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals} Withdrawals
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            Withdrawals.decode = function decode(reader, length) {
+                                if (!(reader instanceof $Reader))
+                                    reader = $Reader.create(reader);
+                                var end = length === undefined ? reader.len : reader.pos + length, message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals();
+                                while (reader.pos < end) {
+                                    var tag = reader.uint32();
+                                    switch (tag >>> 3) {
+                                    case 1:
+                                        if (!(message.withdrawals && message.withdrawals.length))
+                                            message.withdrawals = [];
+                                        message.withdrawals.push($root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.decode(reader, reader.uint32()));
+                                        break;
+                                    default:
+                                        reader.skipType(tag & 7);
+                                        break;
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Decodes a Withdrawals message from the specified reader or buffer, length delimited.
+                             * @function decodeDelimited
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {$protobuf.Reader|Uint8Array} reader Reader or buffer to decode from
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals} Withdrawals
+                             * @throws {Error} If the payload is not a reader or valid buffer
+                             * @throws {$protobuf.util.ProtocolError} If required fields are missing
+                             */
+                            Withdrawals.decodeDelimited = function decodeDelimited(reader) {
+                                if (!(reader instanceof $Reader))
+                                    reader = new $Reader(reader);
+                                return this.decode(reader, reader.uint32());
+                            };
+
+                            /**
+                             * Verifies a Withdrawals message.
+                             * @function verify
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {Object.<string,*>} message Plain object to verify
+                             * @returns {string|null} `null` if valid, otherwise the reason why it is not
+                             */
+                            Withdrawals.verify = function verify(message) {
+                                if (typeof message !== "object" || message === null)
+                                    return "object expected";
+                                if (message.withdrawals != null && message.hasOwnProperty("withdrawals")) {
+                                    if (!Array.isArray(message.withdrawals))
+                                        return "withdrawals: array expected";
+                                    for (var i = 0; i < message.withdrawals.length; ++i) {
+                                        var error = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.verify(message.withdrawals[i]);
+                                        if (error)
+                                            return "withdrawals." + error;
+                                    }
+                                }
+                                return null;
+                            };
+
+                            /**
+                             * Creates a Withdrawals message from a plain object. Also converts values to their respective internal types.
+                             * @function fromObject
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {Object.<string,*>} object Plain object
+                             * @returns {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals} Withdrawals
+                             */
+                            Withdrawals.fromObject = function fromObject(object) {
+                                if (object instanceof $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals)
+                                    return object;
+                                var message = new $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals();
+                                if (object.withdrawals) {
+                                    if (!Array.isArray(object.withdrawals))
+                                        throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.withdrawals: array expected");
+                                    message.withdrawals = [];
+                                    for (var i = 0; i < object.withdrawals.length; ++i) {
+                                        if (typeof object.withdrawals[i] !== "object")
+                                            throw TypeError(".org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.withdrawals: object expected");
+                                        message.withdrawals[i] = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.fromObject(object.withdrawals[i]);
+                                    }
+                                }
+                                return message;
+                            };
+
+                            /**
+                             * Creates a plain object from a Withdrawals message. Also converts values to other types if specified.
+                             * @function toObject
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @static
+                             * @param {org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals} message Withdrawals
+                             * @param {$protobuf.IConversionOptions} [options] Conversion options
+                             * @returns {Object.<string,*>} Plain object
+                             */
+                            Withdrawals.toObject = function toObject(message, options) {
+                                if (!options)
+                                    options = {};
+                                var object = {};
+                                if (options.arrays || options.defaults)
+                                    object.withdrawals = [];
+                                if (message.withdrawals && message.withdrawals.length) {
+                                    object.withdrawals = [];
+                                    for (var j = 0; j < message.withdrawals.length; ++j)
+                                        object.withdrawals[j] = $root.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.toObject(message.withdrawals[j], options);
+                                }
+                                return object;
+                            };
+
+                            /**
+                             * Converts this Withdrawals to JSON.
+                             * @function toJSON
+                             * @memberof org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals
+                             * @instance
+                             * @returns {Object.<string,*>} JSON object
+                             */
+                            Withdrawals.prototype.toJSON = function toJSON() {
+                                return this.constructor.toObject(this, $protobuf.util.toJSONOptions);
+                            };
+
+                            return Withdrawals;
+                        })();
+
+                        return GetIdentityWithdrawalsResponse;
+                    })();
+
                     v0.GetIdentitiesByPublicKeyHashesRequest = (function() {
 
                         /**
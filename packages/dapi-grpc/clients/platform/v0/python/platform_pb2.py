@@ -22,7 +22,7 @@ DESCRIPTOR = _descriptor.FileDescriptor(
   syntax='proto3',
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_pb=b'\n\x0eplatform.proto\x12\x19org.dash.platform.dapi.v0\x1a\x1egoogle/protobuf/wrappers.proto\x1a\x1cgoogle/protobuf/struct.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x81\x01\n\x05Proof\x12\x15\n\rgrovedb_proof\x18\x01 \x01(\x0c\x12\x13\n\x0bquorum_hash\x18\x02 \x01(\x0c\x12\x11\n\tsignature\x18\x03 \x01(\x0c\x12\r\n\x05round\x18\x04 \x01(\r\x12\x15\n\rblock_id_hash\x18\x05 \x01(\x0c\x12\x13\n\x0bquorum_type\x18\x06 \x01(\r\"\x81\x01\n\x10ResponseMetadata\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12 \n\x18\x63ore_chain_locked_height\x18\x02 \x01(\r\x12\x0f\n\x07time_ms\x18\x03 \x01(\x04\x12\x18\n\x10protocol_version\x18\x04 \x01(\r\x12\x10\n\x08\x63hain_id\x18\x05 \x01(\t\"L\n\x1dStateTransitionBroadcastError\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0f\n\x07message\x18\x02 \x01(\t\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\x0c\";\n\x1f\x42roadcastStateTransitionRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\"\n BroadcastStateTransitionResponse\"/\n\x12GetIdentityRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa5\x01\n\x13GetIdentityResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"2\n\x14GetIdentitiesRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xdb\x03\n\x15GetIdentitiesResponse\x12Q\n\nidentities\x18\x01 \x01(\x0b\x32;.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\rIdentityValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1ak\n\rIdentityEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityValue\x1a\x66\n\nIdentities\x12X\n\x10identity_entries\x18\x01 \x03(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityEntryB\x08\n\x06result\"\xc9\x01\n\x1aGetIdentityBalanceResponse\x12/\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"\x8d\x03\n%GetIdentityBalanceAndRevisionResponse\x12s\n\x14\x62\x61lance_and_revision\x18\x01 \x01(\x0b\x32S.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevisionH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1as\n\x12\x42\x61lanceAndRevision\x12-\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12.\n\x08revision\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueB\x08\n\x06result\"\xd1\x01\n\x0eKeyRequestType\x12\x36\n\x08\x61ll_keys\x18\x01 \x01(\x0b\x32\".org.dash.platform.dapi.v0.AllKeysH\x00\x12@\n\rspecific_keys\x18\x02 \x01(\x0b\x32\'.org.dash.platform.dapi.v0.SpecificKeysH\x00\x12:\n\nsearch_key\x18\x03 \x01(\x0b\x32$.org.dash.platform.dapi.v0.SearchKeyH\x00\x42\t\n\x07request\"\t\n\x07\x41llKeys\"\x1f\n\x0cSpecificKeys\x12\x0f\n\x07key_ids\x18\x01 \x03(\r\"\xb6\x01\n\tSearchKey\x12I\n\x0bpurpose_map\x18\x01 \x03(\x0b\x32\x34.org.dash.platform.dapi.v0.SearchKey.PurposeMapEntry\x1a^\n\x0fPurposeMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12:\n\x05value\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.SecurityLevelMap:\x02\x38\x01\"\xbf\x02\n\x10SecurityLevelMap\x12]\n\x12security_level_map\x18\x01 \x03(\x0b\x32\x41.org.dash.platform.dapi.v0.SecurityLevelMap.SecurityLevelMapEntry\x1aw\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12M\n\x05value\x18\x02 \x01(\x0e\x32>.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"S\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\x12\x1c\n\x18\x41LL_KEYS_OF_KIND_REQUEST\x10\x01\"\xd8\x01\n\x16GetIdentityKeysRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xfa\x01\n\x17GetIdentityKeysResponse\x12G\n\x04keys\x18\x01 \x01(\x0b\x32\x37.org.dash.platform.dapi.v0.GetIdentityKeysResponse.KeysH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\x04Keys\x12\x12\n\nkeys_bytes\x18\x01 \x03(\x0c\x42\x08\n\x06result\"\xb2\x04\n\x18GetIdentitiesKeysRequest\x12\x14\n\x0cidentity_ids\x18\x01 \x03(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\x1a\xd4\x02\n\x10SecurityLevelMap\x12v\n\x12security_level_map\x18\x01 \x03(\x0b\x32Z.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.SecurityLevelMapEntry\x1a\x90\x01\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12\x66\n\x05value\x18\x02 \x01(\x0e\x32W.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"5\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\"\xf4\x03\n\x19GetIdentitiesKeysResponse\x12\\\n\x0bpublic_keys\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntriesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\tPublicKey\x12\r\n\x05value\x18\x01 \x01(\x0c\x1al\n\x0ePublicKeyEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey\x1as\n\x10PublicKeyEntries\x12_\n\x12public_key_entries\x18\x01 \x03(\x0b\x32\x43.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntryB\x08\n\x06result\"\xd7\x04\n\x10GetProofsRequest\x12O\n\nidentities\x18\x01 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest\x12N\n\tcontracts\x18\x02 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest\x12N\n\tdocuments\x18\x03 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest\x1aw\n\x0f\x44ocumentRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12#\n\x1b\x64ocument_type_keeps_history\x18\x03 \x01(\x08\x12\x13\n\x0b\x64ocument_id\x18\x04 \x01(\x0c\x1a\xb0\x01\n\x0fIdentityRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12V\n\x0crequest_type\x18\x02 \x01(\x0e\x32@.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type\"0\n\x04Type\x12\x11\n\rFULL_IDENTITY\x10\x00\x12\x0b\n\x07\x42\x41LANCE\x10\x01\x12\x08\n\x04KEYS\x10\x02\x1a&\n\x0f\x43ontractRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\"\x83\x01\n\x11GetProofsResponse\x12/\n\x05proof\x18\x01 \x01(\x0b\x32 .org.dash.platform.dapi.v0.Proof\x12=\n\x08metadata\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\"3\n\x16GetDataContractRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xae\x01\n\x17GetDataContractResponse\x12\x17\n\rdata_contract\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"5\n\x17GetDataContractsRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\x86\x04\n\x18GetDataContractsResponse\x12[\n\x0e\x64\x61ta_contracts\x18\x01 \x01(\x0b\x32\x41.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\"\n\x11\x44\x61taContractValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1av\n\x11\x44\x61taContractEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12T\n\x05value\x18\x02 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue\x1au\n\rDataContracts\x12\x64\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntryB\x08\n\x06result\"\xaa\x01\n\x1dGetDataContractHistoryRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12+\n\x05limit\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\x13\n\x0bstart_at_ms\x18\x04 \x01(\x04\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xd0\x03\n\x1eGetDataContractHistoryResponse\x12n\n\x15\x64\x61ta_contract_history\x18\x01 \x01(\x0b\x32M.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x37\n\x18\x44\x61taContractHistoryEntry\x12\x0c\n\x04\x64\x61te\x18\x01 \x01(\x04\x12\r\n\x05value\x18\x02 \x01(\x0c\x1a\x88\x01\n\x13\x44\x61taContractHistory\x12q\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32R.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntryB\x08\n\x06result\"\xb9\x01\n\x13GetDocumentsRequest\x12\x18\n\x10\x64\x61ta_contract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12\r\n\x05where\x18\x03 \x01(\x0c\x12\x10\n\x08order_by\x18\x04 \x01(\x0c\x12\r\n\x05limit\x18\x05 \x01(\r\x12\x15\n\x0bstart_after\x18\x06 \x01(\x0cH\x00\x12\x12\n\x08start_at\x18\x07 \x01(\x0cH\x00\x12\r\n\x05prove\x18\x08 \x01(\x08\x42\x07\n\x05start\"\x82\x02\n\x14GetDocumentsResponse\x12N\n\tdocuments\x18\x01 \x01(\x0b\x32\x39.org.dash.platform.dapi.v0.GetDocumentsResponse.DocumentsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\tDocuments\x12\x11\n\tdocuments\x18\x01 \x03(\x0c\x42\x08\n\x06result\"Q\n%GetIdentitiesByPublicKeyHashesRequest\x12\x19\n\x11public_key_hashes\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xaa\x02\n&GetIdentitiesByPublicKeyHashesResponse\x12\x62\n\nidentities\x18\x01 \x01(\x0b\x32L.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a \n\nIdentities\x12\x12\n\nidentities\x18\x01 \x03(\x0c\x42\x08\n\x06result\"M\n#GetIdentityByPublicKeyHashesRequest\x12\x17\n\x0fpublic_key_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xb6\x01\n$GetIdentityByPublicKeyHashesResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"S\n#WaitForStateTransitionResultRequest\x12\x1d\n\x15state_transition_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xed\x01\n$WaitForStateTransitionResultResponse\x12I\n\x05\x65rror\x18\x01 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastErrorH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"P\n\x14\x43onsensusParamsBlock\x12\x11\n\tmax_bytes\x18\x01 \x01(\t\x12\x0f\n\x07max_gas\x18\x02 \x01(\t\x12\x14\n\x0ctime_iota_ms\x18\x03 \x01(\t\"b\n\x17\x43onsensusParamsEvidence\x12\x1a\n\x12max_age_num_blocks\x18\x01 \x01(\t\x12\x18\n\x10max_age_duration\x18\x02 \x01(\t\x12\x11\n\tmax_bytes\x18\x03 \x01(\t\":\n\x19GetConsensusParamsRequest\x12\x0e\n\x06height\x18\x01 \x01(\x03\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa2\x01\n\x1aGetConsensusParamsResponse\x12>\n\x05\x62lock\x18\x01 \x01(\x0b\x32/.org.dash.platform.dapi.v0.ConsensusParamsBlock\x12\x44\n\x08\x65vidence\x18\x02 \x01(\x0b\x32\x32.org.dash.platform.dapi.v0.ConsensusParamsEvidence2\xdb\x0f\n\x08Platform\x12\x93\x01\n\x18\x62roadcastStateTransition\x12:.org.dash.platform.dapi.v0.BroadcastStateTransitionRequest\x1a;.org.dash.platform.dapi.v0.BroadcastStateTransitionResponse\x12l\n\x0bgetIdentity\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a..org.dash.platform.dapi.v0.GetIdentityResponse\x12r\n\rgetIdentities\x12/.org.dash.platform.dapi.v0.GetIdentitiesRequest\x1a\x30.org.dash.platform.dapi.v0.GetIdentitiesResponse\x12x\n\x0fgetIdentityKeys\x12\x31.org.dash.platform.dapi.v0.GetIdentityKeysRequest\x1a\x32.org.dash.platform.dapi.v0.GetIdentityKeysResponse\x12z\n\x12getIdentityBalance\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a\x35.org.dash.platform.dapi.v0.GetIdentityBalanceResponse\x12\x90\x01\n\x1dgetIdentityBalanceAndRevision\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a@.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse\x12\x66\n\tgetProofs\x12+.org.dash.platform.dapi.v0.GetProofsRequest\x1a,.org.dash.platform.dapi.v0.GetProofsResponse\x12x\n\x0fgetDataContract\x12\x31.org.dash.platform.dapi.v0.GetDataContractRequest\x1a\x32.org.dash.platform.dapi.v0.GetDataContractResponse\x12\x8d\x01\n\x16getDataContractHistory\x12\x38.org.dash.platform.dapi.v0.GetDataContractHistoryRequest\x1a\x39.org.dash.platform.dapi.v0.GetDataContractHistoryResponse\x12{\n\x10getDataContracts\x12\x32.org.dash.platform.dapi.v0.GetDataContractsRequest\x1a\x33.org.dash.platform.dapi.v0.GetDataContractsResponse\x12o\n\x0cgetDocuments\x12..org.dash.platform.dapi.v0.GetDocumentsRequest\x1a/.org.dash.platform.dapi.v0.GetDocumentsResponse\x12\xa5\x01\n\x1egetIdentitiesByPublicKeyHashes\x12@.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest\x1a\x41.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse\x12\x9f\x01\n\x1cgetIdentityByPublicKeyHashes\x12>.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest\x1a?.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse\x12\x9f\x01\n\x1cwaitForStateTransitionResult\x12>.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest\x1a?.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse\x12\x81\x01\n\x12getConsensusParams\x12\x34.org.dash.platform.dapi.v0.GetConsensusParamsRequest\x1a\x35.org.dash.platform.dapi.v0.GetConsensusParamsResponseb\x06proto3'
+  serialized_pb=b'\n\x0eplatform.proto\x12\x19org.dash.platform.dapi.v0\x1a\x1egoogle/protobuf/wrappers.proto\x1a\x1cgoogle/protobuf/struct.proto\x1a\x1fgoogle/protobuf/timestamp.proto\"\x81\x01\n\x05Proof\x12\x15\n\rgrovedb_proof\x18\x01 \x01(\x0c\x12\x13\n\x0bquorum_hash\x18\x02 \x01(\x0c\x12\x11\n\tsignature\x18\x03 \x01(\x0c\x12\r\n\x05round\x18\x04 \x01(\r\x12\x15\n\rblock_id_hash\x18\x05 \x01(\x0c\x12\x13\n\x0bquorum_type\x18\x06 \x01(\r\"\x81\x01\n\x10ResponseMetadata\x12\x0e\n\x06height\x18\x01 \x01(\x04\x12 \n\x18\x63ore_chain_locked_height\x18\x02 \x01(\r\x12\x0f\n\x07time_ms\x18\x03 \x01(\x04\x12\x18\n\x10protocol_version\x18\x04 \x01(\r\x12\x10\n\x08\x63hain_id\x18\x05 \x01(\t\"L\n\x1dStateTransitionBroadcastError\x12\x0c\n\x04\x63ode\x18\x01 \x01(\r\x12\x0f\n\x07message\x18\x02 \x01(\t\x12\x0c\n\x04\x64\x61ta\x18\x03 \x01(\x0c\";\n\x1f\x42roadcastStateTransitionRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\"\n BroadcastStateTransitionResponse\"/\n\x12GetIdentityRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa5\x01\n\x13GetIdentityResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"2\n\x14GetIdentitiesRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xdb\x03\n\x15GetIdentitiesResponse\x12Q\n\nidentities\x18\x01 \x01(\x0b\x32;.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\rIdentityValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1ak\n\rIdentityEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityValue\x1a\x66\n\nIdentities\x12X\n\x10identity_entries\x18\x01 \x03(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesResponse.IdentityEntryB\x08\n\x06result\"\xc9\x01\n\x1aGetIdentityBalanceResponse\x12/\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"\x8d\x03\n%GetIdentityBalanceAndRevisionResponse\x12s\n\x14\x62\x61lance_and_revision\x18\x01 \x01(\x0b\x32S.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse.BalanceAndRevisionH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1as\n\x12\x42\x61lanceAndRevision\x12-\n\x07\x62\x61lance\x18\x01 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12.\n\x08revision\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueB\x08\n\x06result\"\xd1\x01\n\x0eKeyRequestType\x12\x36\n\x08\x61ll_keys\x18\x01 \x01(\x0b\x32\".org.dash.platform.dapi.v0.AllKeysH\x00\x12@\n\rspecific_keys\x18\x02 \x01(\x0b\x32\'.org.dash.platform.dapi.v0.SpecificKeysH\x00\x12:\n\nsearch_key\x18\x03 \x01(\x0b\x32$.org.dash.platform.dapi.v0.SearchKeyH\x00\x42\t\n\x07request\"\t\n\x07\x41llKeys\"\x1f\n\x0cSpecificKeys\x12\x0f\n\x07key_ids\x18\x01 \x03(\r\"\xb6\x01\n\tSearchKey\x12I\n\x0bpurpose_map\x18\x01 \x03(\x0b\x32\x34.org.dash.platform.dapi.v0.SearchKey.PurposeMapEntry\x1a^\n\x0fPurposeMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12:\n\x05value\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.SecurityLevelMap:\x02\x38\x01\"\xbf\x02\n\x10SecurityLevelMap\x12]\n\x12security_level_map\x18\x01 \x03(\x0b\x32\x41.org.dash.platform.dapi.v0.SecurityLevelMap.SecurityLevelMapEntry\x1aw\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12M\n\x05value\x18\x02 \x01(\x0e\x32>.org.dash.platform.dapi.v0.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"S\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\x12\x1c\n\x18\x41LL_KEYS_OF_KIND_REQUEST\x10\x01\"\xd8\x01\n\x16GetIdentityKeysRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xfa\x01\n\x17GetIdentityKeysResponse\x12G\n\x04keys\x18\x01 \x01(\x0b\x32\x37.org.dash.platform.dapi.v0.GetIdentityKeysResponse.KeysH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\x04Keys\x12\x12\n\nkeys_bytes\x18\x01 \x03(\x0c\x42\x08\n\x06result\"\xb2\x04\n\x18GetIdentitiesKeysRequest\x12\x14\n\x0cidentity_ids\x18\x01 \x03(\x0c\x12?\n\x0crequest_type\x18\x02 \x01(\x0b\x32).org.dash.platform.dapi.v0.KeyRequestType\x12+\n\x05limit\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x04 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x05 \x01(\x08\x1a\xd4\x02\n\x10SecurityLevelMap\x12v\n\x12security_level_map\x18\x01 \x03(\x0b\x32Z.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.SecurityLevelMapEntry\x1a\x90\x01\n\x15SecurityLevelMapEntry\x12\x0b\n\x03key\x18\x01 \x01(\r\x12\x66\n\x05value\x18\x02 \x01(\x0e\x32W.org.dash.platform.dapi.v0.GetIdentitiesKeysRequest.SecurityLevelMap.KeyKindRequestType:\x02\x38\x01\"5\n\x12KeyKindRequestType\x12\x1f\n\x1b\x43URRENT_KEY_OF_KIND_REQUEST\x10\x00\"\xf4\x03\n\x19GetIdentitiesKeysResponse\x12\\\n\x0bpublic_keys\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntriesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1a\n\tPublicKey\x12\r\n\x05value\x18\x01 \x01(\x0c\x1al\n\x0ePublicKeyEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12M\n\x05value\x18\x02 \x01(\x0b\x32>.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKey\x1as\n\x10PublicKeyEntries\x12_\n\x12public_key_entries\x18\x01 \x03(\x0b\x32\x43.org.dash.platform.dapi.v0.GetIdentitiesKeysResponse.PublicKeyEntryB\x08\n\x06result\"\xd7\x04\n\x10GetProofsRequest\x12O\n\nidentities\x18\x01 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest\x12N\n\tcontracts\x18\x02 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.ContractRequest\x12N\n\tdocuments\x18\x03 \x03(\x0b\x32;.org.dash.platform.dapi.v0.GetProofsRequest.DocumentRequest\x1aw\n\x0f\x44ocumentRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12#\n\x1b\x64ocument_type_keeps_history\x18\x03 \x01(\x08\x12\x13\n\x0b\x64ocument_id\x18\x04 \x01(\x0c\x1a\xb0\x01\n\x0fIdentityRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12V\n\x0crequest_type\x18\x02 \x01(\x0e\x32@.org.dash.platform.dapi.v0.GetProofsRequest.IdentityRequest.Type\"0\n\x04Type\x12\x11\n\rFULL_IDENTITY\x10\x00\x12\x0b\n\x07\x42\x41LANCE\x10\x01\x12\x08\n\x04KEYS\x10\x02\x1a&\n\x0f\x43ontractRequest\x12\x13\n\x0b\x63ontract_id\x18\x01 \x01(\x0c\"\x83\x01\n\x11GetProofsResponse\x12/\n\x05proof\x18\x01 \x01(\x0b\x32 .org.dash.platform.dapi.v0.Proof\x12=\n\x08metadata\x18\x02 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\"e\n\x16GetDataContractRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\x12\x30\n\nat_time_ms\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\"\xae\x01\n\x17GetDataContractResponse\x12\x17\n\rdata_contract\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"5\n\x17GetDataContractsRequest\x12\x0b\n\x03ids\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\x86\x04\n\x18GetDataContractsResponse\x12[\n\x0e\x64\x61ta_contracts\x18\x01 \x01(\x0b\x32\x41.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\"\n\x11\x44\x61taContractValue\x12\r\n\x05value\x18\x01 \x01(\x0c\x1av\n\x11\x44\x61taContractEntry\x12\x0b\n\x03key\x18\x01 \x01(\x0c\x12T\n\x05value\x18\x02 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractValue\x1au\n\rDataContracts\x12\x64\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDataContractsResponse.DataContractEntryB\x08\n\x06result\"\xaa\x01\n\x1dGetDataContractHistoryRequest\x12\n\n\x02id\x18\x01 \x01(\x0c\x12+\n\x05limit\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x03 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\x13\n\x0bstart_at_ms\x18\x04 \x01(\x04\x12\r\n\x05prove\x18\x05 \x01(\x08\"\xd0\x03\n\x1eGetDataContractHistoryResponse\x12n\n\x15\x64\x61ta_contract_history\x18\x01 \x01(\x0b\x32M.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x37\n\x18\x44\x61taContractHistoryEntry\x12\x0c\n\x04\x64\x61te\x18\x01 \x01(\x04\x12\r\n\x05value\x18\x02 \x01(\x0c\x1a\x88\x01\n\x13\x44\x61taContractHistory\x12q\n\x15\x64\x61ta_contract_entries\x18\x01 \x03(\x0b\x32R.org.dash.platform.dapi.v0.GetDataContractHistoryResponse.DataContractHistoryEntryB\x08\n\x06result\"\xee\x01\n\x13GetDocumentsRequest\x12\x18\n\x10\x64\x61ta_contract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12\r\n\x05where\x18\x03 \x01(\x0c\x12\x10\n\x08order_by\x18\x04 \x01(\x0c\x12\r\n\x05limit\x18\x05 \x01(\r\x12\x15\n\x0bstart_after\x18\x06 \x01(\x0cH\x00\x12\x12\n\x08start_at\x18\x07 \x01(\x0cH\x00\x12\r\n\x05prove\x18\x08 \x01(\x08\x12\x33\n\rblock_time_ms\x18\t \x01(\x0b\x32\x1c.google.protobuf.UInt64ValueB\x07\n\x05start\"\x82\x02\n\x14GetDocumentsResponse\x12N\n\tdocuments\x18\x01 \x01(\x0b\x32\x39.org.dash.platform.dapi.v0.GetDocumentsResponse.DocumentsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x1e\n\tDocuments\x12\x11\n\tdocuments\x18\x01 \x03(\x0c\x42\x08\n\x06result\"\x91\x02\n\x19GetDocumentHistoryRequest\x12\x18\n\x10\x64\x61ta_contract_id\x18\x01 \x01(\x0c\x12\x15\n\rdocument_type\x18\x02 \x01(\t\x12\x13\n\x0b\x64ocument_id\x18\x03 \x01(\x0c\x12\x13\n\x0bstart_at_ms\x18\x04 \x01(\x04\x12/\n\tend_at_ms\x18\x05 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12+\n\x05limit\x18\x06 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12,\n\x06offset\x18\x07 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\r\n\x05prove\x18\x08 \x01(\x08\"\xbb\x03\n\x1aGetDocumentHistoryResponse\x12\x61\n\x10\x64ocument_history\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x45\n\x14\x44ocumentHistoryEntry\x12\x0c\n\x04\x64\x61te\x18\x01 \x01(\x04\x12\x10\n\x08revision\x18\x02 \x01(\x04\x12\r\n\x05value\x18\x03 \x01(\x0c\x1aw\n\x0f\x44ocumentHistory\x12\x64\n\x10\x64ocument_entries\x18\x01 \x03(\x0b\x32J.org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntryB\x08\n\x06result\"e\n\x1eGetDomainCanonicalOwnerRequest\x12\r\n\x05label\x18\x01 \x01(\t\x12%\n\x1dnormalized_parent_domain_name\x18\x02 \x01(\t\x12\r\n\x05prove\x18\x03 \x01(\x08\"\xb1\x01\n\x1fGetDomainCanonicalOwnerResponse\x12\x12\n\x08owner_id\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"I\n$GetContestedResourceVoteTallyRequest\x12\x12\n\ncontest_id\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xf1\x03\n%GetContestedResourceVoteTallyResponse\x12q\n\nvote_tally\x18\x01 \x01(\x0b\x32[.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTallyH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x39\n\x0e\x43ontenderVotes\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12\x12\n\nvote_count\x18\x02 \x01(\x04\x1a\x9d\x01\n\x1a\x43ontestedResourceVoteTally\x12\x63\n\ncontenders\x18\x01 \x03(\x0b\x32O.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes\x12\x1a\n\x12\x61\x62stain_vote_count\x18\x02 \x01(\x04\x42\x08\n\x06result\"=\n!EstimateStateTransitionFeeRequest\x12\x18\n\x10state_transition\x18\x01 \x01(\x0c\"\x89\x06\n\"EstimateStateTransitionFeeResponse\x12_\n\x0c\x66\x65\x65_estimate\x18\x01 \x01(\x0b\x32I.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate\x12G\n\x05\x65rror\x18\x02 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastError\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x36\n\x0f\x45pochStorageFee\x12\x13\n\x0b\x65poch_index\x18\x01 \x01(\r\x12\x0e\n\x06\x61mount\x18\x02 \x01(\x04\x1a\x35\n\x0eIdentityRefund\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12\x0e\n\x06\x61mount\x18\x02 \x01(\x04\x1a\x8a\x03\n\x0b\x46\x65\x65\x45stimate\x12\x13\n\x0bstorage_fee\x18\x01 \x01(\x04\x12\x16\n\x0eprocessing_fee\x18\x02 \x01(\x04\x12\x1a\n\x12\x64ocument_type_fees\x18\x03 \x01(\x04\x12l\n\x15storage_fee_per_epoch\x18\x04 \x03(\x0b\x32M.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee\x12]\n\x07refunds\x18\x05 \x03(\x0b\x32L.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund\x12-\n\x07\x62\x61lance\x18\x06 \x01(\x0b\x32\x1c.google.protobuf.UInt64Value\x12\x36\n\x11resulting_balance\x18\x07 \x01(\x0b\x32\x1b.google.protobuf.Int64Value\"\x85\x01\n\x1dGetIdentityWithdrawalsRequest\x12\x13\n\x0bidentity_id\x18\x01 \x01(\x0c\x12+\n\x05limit\x18\x02 \x01(\x0b\x32\x1c.google.protobuf.UInt32Value\x12\x13\n\x0bstart_after\x18\x03 \x01(\x0c\x12\r\n\x05prove\x18\x04 \x01(\x08\"\x83\x07\n\x1eGetIdentityWithdrawalsResponse\x12\\\n\x0bwithdrawals\x18\x01 \x01(\x0b\x32\x45.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.WithdrawalsH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a\x9c\x04\n\nWithdrawal\x12\n\n\x02id\x18\x01 \x01(\x0c\x12\x0e\n\x06\x61mount\x18\x02 \x01(\x04\x12[\n\x06status\x18\x03 \x01(\x0e\x32K.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status\x12\x16\n\x0etransaction_id\x18\x04 \x01(\x0c\x12\x1f\n\x17transaction_sign_height\x18\x05 \x01(\r\x12\'\n\x1f\x65xpected_completion_core_height\x18\x06 \x01(\r\x12j\n\x0e\x66\x61ilure_reason\x18\x07 \x01(\x0e\x32R.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason\x12\x16\n\x0erefunded_at_ms\x18\x08 \x01(\x04\x12\x15\n\rcreated_at_ms\x18\t \x01(\x04\x12\x15\n\rupdated_at_ms\x18\n \x01(\x04\"L\n\x06Status\x12\n\n\x06QUEUED\x10\x00\x12\n\n\x06POOLED\x10\x01\x12\x0f\n\x0b\x42ROADCASTED\x10\x02\x12\x0c\n\x08\x43OMPLETE\x10\x03\x12\x0b\n\x07\x45XPIRED\x10\x04\"3\n\rFailureReason\x12\x08\n\x04NONE\x10\x00\x12\x18\n\x14\x45XPIRED_BEFORE_MINED\x10\x01\x1ah\n\x0bWithdrawals\x12Y\n\x0bwithdrawals\x18\x01 \x03(\x0b\x32\x44.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.WithdrawalB\x08\n\x06result\"Q\n%GetIdentitiesByPublicKeyHashesRequest\x12\x19\n\x11public_key_hashes\x18\x01 \x03(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xaa\x02\n&GetIdentitiesByPublicKeyHashesResponse\x12\x62\n\nidentities\x18\x01 \x01(\x0b\x32L.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.IdentitiesH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadata\x1a \n\nIdentities\x12\x12\n\nidentities\x18\x01 \x03(\x0c\x42\x08\n\x06result\"M\n#GetIdentityByPublicKeyHashesRequest\x12\x17\n\x0fpublic_key_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xb6\x01\n$GetIdentityByPublicKeyHashesResponse\x12\x12\n\x08identity\x18\x01 \x01(\x0cH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"S\n#WaitForStateTransitionResultRequest\x12\x1d\n\x15state_transition_hash\x18\x01 \x01(\x0c\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xed\x01\n$WaitForStateTransitionResultResponse\x12I\n\x05\x65rror\x18\x01 \x01(\x0b\x32\x38.org.dash.platform.dapi.v0.StateTransitionBroadcastErrorH\x00\x12\x31\n\x05proof\x18\x02 \x01(\x0b\x32 .org.dash.platform.dapi.v0.ProofH\x00\x12=\n\x08metadata\x18\x03 \x01(\x0b\x32+.org.dash.platform.dapi.v0.ResponseMetadataB\x08\n\x06result\"P\n\x14\x43onsensusParamsBlock\x12\x11\n\tmax_bytes\x18\x01 \x01(\t\x12\x0f\n\x07max_gas\x18\x02 \x01(\t\x12\x14\n\x0ctime_iota_ms\x18\x03 \x01(\t\"b\n\x17\x43onsensusParamsEvidence\x12\x1a\n\x12max_age_num_blocks\x18\x01 \x01(\t\x12\x18\n\x10max_age_duration\x18\x02 \x01(\t\x12\x11\n\tmax_bytes\x18\x03 \x01(\t\":\n\x19GetConsensusParamsRequest\x12\x0e\n\x06height\x18\x01 \x01(\x03\x12\r\n\x05prove\x18\x02 \x01(\x08\"\xa2\x01\n\x1aGetConsensusParamsResponse\x12>\n\x05\x62lock\x18\x01 \x01(\x0b\x32/.org.dash.platform.dapi.v0.ConsensusParamsBlock\x12\x44\n\x08\x65vidence\x18\x02 \x01(\x0b\x32\x32.org.dash.platform.dapi.v0.ConsensusParamsEvidence2\xc3\x15\n\x08Platform\x12\x93\x01\n\x18\x62roadcastStateTransition\x12:.org.dash.platform.dapi.v0.BroadcastStateTransitionRequest\x1a;.org.dash.platform.dapi.v0.BroadcastStateTransitionResponse\x12l\n\x0bgetIdentity\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a..org.dash.platform.dapi.v0.GetIdentityResponse\x12r\n\rgetIdentities\x12/.org.dash.platform.dapi.v0.GetIdentitiesRequest\x1a\x30.org.dash.platform.dapi.v0.GetIdentitiesResponse\x12x\n\x0fgetIdentityKeys\x12\x31.org.dash.platform.dapi.v0.GetIdentityKeysRequest\x1a\x32.org.dash.platform.dapi.v0.GetIdentityKeysResponse\x12z\n\x12getIdentityBalance\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a\x35.org.dash.platform.dapi.v0.GetIdentityBalanceResponse\x12\x90\x01\n\x1dgetIdentityBalanceAndRevision\x12-.org.dash.platform.dapi.v0.GetIdentityRequest\x1a@.org.dash.platform.dapi.v0.GetIdentityBalanceAndRevisionResponse\x12\x66\n\tgetProofs\x12+.org.dash.platform.dapi.v0.GetProofsRequest\x1a,.org.dash.platform.dapi.v0.GetProofsResponse\x12x\n\x0fgetDataContract\x12\x31.org.dash.platform.dapi.v0.GetDataContractRequest\x1a\x32.org.dash.platform.dapi.v0.GetDataContractResponse\x12\x8d\x01\n\x16getDataContractHistory\x12\x38.org.dash.platform.dapi.v0.GetDataContractHistoryRequest\x1a\x39.org.dash.platform.dapi.v0.GetDataContractHistoryResponse\x12{\n\x10getDataContracts\x12\x32.org.dash.platform.dapi.v0.GetDataContractsRequest\x1a\x33.org.dash.platform.dapi.v0.GetDataContractsResponse\x12o\n\x0cgetDocuments\x12..org.dash.platform.dapi.v0.GetDocumentsRequest\x1a/.org.dash.platform.dapi.v0.GetDocumentsResponse\x12\x81\x01\n\x12getDocumentHistory\x12\x34.org.dash.platform.dapi.v0.GetDocumentHistoryRequest\x1a\x35.org.dash.platform.dapi.v0.GetDocumentHistoryResponse\x12\x90\x01\n\x17getDomainCanonicalOwner\x12\x39.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest\x1a:.org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse\x12\xa2\x01\n\x1dgetContestedResourceVoteTally\x12?.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest\x1a@.org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse\x12\x99\x01\n\x1a\x65stimateStateTransitionFee\x12<.org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest\x1a=.org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse\x12\x8d\x01\n\x16getIdentityWithdrawals\x12\x38.org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest\x1a\x39.org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse\x12\xa5\x01\n\x1egetIdentitiesByPublicKeyHashes\x12@.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest\x1a\x41.org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse\x12\x9f\x01\n\x1cgetIdentityByPublicKeyHashes\x12>.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest\x1a?.org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse\x12\x9f\x01\n\x1cwaitForStateTransitionResult\x12>.org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest\x1a?.org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse\x12\x81\x01\n\x12getConsensusParams\x12\x34.org.dash.platform.dapi.v0.GetConsensusParamsRequest\x1a\x35.org.dash.platform.dapi.v0.GetConsensusParamsResponseb\x06proto3'
   ,
   dependencies=[google_dot_protobuf_dot_wrappers__pb2.DESCRIPTOR,google_dot_protobuf_dot_struct__pb2.DESCRIPTOR,google_dot_protobuf_dot_timestamp__pb2.DESCRIPTOR,])
 
@@ -103,6 +103,71 @@ _GETPROOFSREQUEST_IDENTITYREQUEST_TYPE = _descriptor.EnumDescriptor(
 )
 _sym_db.RegisterEnumDescriptor(_GETPROOFSREQUEST_IDENTITYREQUEST_TYPE)
 
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_STATUS = _descriptor.EnumDescriptor(
+  name='Status',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.Status',
+  filename=None,
+  file=DESCRIPTOR,
+  create_key=_descriptor._internal_create_key,
+  values=[
+    _descriptor.EnumValueDescriptor(
+      name='QUEUED', index=0, number=0,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='POOLED', index=1, number=1,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='BROADCASTED', index=2, number=2,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='COMPLETE', index=3, number=3,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='EXPIRED', index=4, number=4,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+  ],
+  containing_type=None,
+  serialized_options=None,
+  serialized_start=10181,
+  serialized_end=10257,
+)
+_sym_db.RegisterEnumDescriptor(_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_STATUS)
+
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_FAILUREREASON = _descriptor.EnumDescriptor(
+  name='FailureReason',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.FailureReason',
+  filename=None,
+  file=DESCRIPTOR,
+  create_key=_descriptor._internal_create_key,
+  values=[
+    _descriptor.EnumValueDescriptor(
+      name='NONE', index=0, number=0,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+    _descriptor.EnumValueDescriptor(
+      name='EXPIRED_BEFORE_MINED', index=1, number=1,
+      serialized_options=None,
+      type=None,
+      create_key=_descriptor._internal_create_key),
+  ],
+  containing_type=None,
+  serialized_options=None,
+  serialized_start=10259,
+  serialized_end=10310,
+)
+_sym_db.RegisterEnumDescriptor(_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_FAILUREREASON)
+
 
 _PROOF = _descriptor.Descriptor(
   name='Proof',
@@ -1655,6 +1720,13 @@ _GETDATACONTRACTREQUEST = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='at_time_ms', full_name='org.dash.platform.dapi.v0.GetDataContractRequest.at_time_ms', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -1668,7 +1740,7 @@ _GETDATACONTRACTREQUEST = _descriptor.Descriptor(
   oneofs=[
   ],
   serialized_start=4969,
-  serialized_end=5020,
+  serialized_end=5070,
 )
 
 
@@ -1718,8 +1790,8 @@ _GETDATACONTRACTRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=5023,
-  serialized_end=5197,
+  serialized_start=5073,
+  serialized_end=5247,
 )
 
 
@@ -1757,8 +1829,8 @@ _GETDATACONTRACTSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5199,
-  serialized_end=5252,
+  serialized_start=5249,
+  serialized_end=5302,
 )
 
 
@@ -1789,8 +1861,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTVALUE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5490,
-  serialized_end=5524,
+  serialized_start=5540,
+  serialized_end=5574,
 )
 
 _GETDATACONTRACTSRESPONSE_DATACONTRACTENTRY = _descriptor.Descriptor(
@@ -1827,8 +1899,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTENTRY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5526,
-  serialized_end=5644,
+  serialized_start=5576,
+  serialized_end=5694,
 )
 
 _GETDATACONTRACTSRESPONSE_DATACONTRACTS = _descriptor.Descriptor(
@@ -1858,8 +1930,8 @@ _GETDATACONTRACTSRESPONSE_DATACONTRACTS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5646,
-  serialized_end=5763,
+  serialized_start=5696,
+  serialized_end=5813,
 )
 
 _GETDATACONTRACTSRESPONSE = _descriptor.Descriptor(
@@ -1908,8 +1980,8 @@ _GETDATACONTRACTSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=5255,
-  serialized_end=5773,
+  serialized_start=5305,
+  serialized_end=5823,
 )
 
 
@@ -1968,8 +2040,8 @@ _GETDATACONTRACTHISTORYREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=5776,
-  serialized_end=5946,
+  serialized_start=5826,
+  serialized_end=5996,
 )
 
 
@@ -2007,8 +2079,8 @@ _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORYENTRY = _descriptor.Descripto
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6209,
-  serialized_end=6264,
+  serialized_start=6259,
+  serialized_end=6314,
 )
 
 _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORY = _descriptor.Descriptor(
@@ -2038,8 +2110,8 @@ _GETDATACONTRACTHISTORYRESPONSE_DATACONTRACTHISTORY = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6267,
-  serialized_end=6403,
+  serialized_start=6317,
+  serialized_end=6453,
 )
 
 _GETDATACONTRACTHISTORYRESPONSE = _descriptor.Descriptor(
@@ -2088,8 +2160,8 @@ _GETDATACONTRACTHISTORYRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=5949,
-  serialized_end=6413,
+  serialized_start=5999,
+  serialized_end=6463,
 )
 
 
@@ -2157,6 +2229,13 @@ _GETDOCUMENTSREQUEST = _descriptor.Descriptor(
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='block_time_ms', full_name='org.dash.platform.dapi.v0.GetDocumentsRequest.block_time_ms', index=8,
+      number=9, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
@@ -2174,8 +2253,8 @@ _GETDOCUMENTSREQUEST = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6416,
-  serialized_end=6601,
+  serialized_start=6466,
+  serialized_end=6704,
 )
 
 
@@ -2206,8 +2285,8 @@ _GETDOCUMENTSRESPONSE_DOCUMENTS = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6822,
-  serialized_end=6852,
+  serialized_start=6925,
+  serialized_end=6955,
 )
 
 _GETDOCUMENTSRESPONSE = _descriptor.Descriptor(
@@ -2256,29 +2335,71 @@ _GETDOCUMENTSRESPONSE = _descriptor.Descriptor(
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6604,
-  serialized_end=6862,
+  serialized_start=6707,
+  serialized_end=6965,
 )
 
 
-_GETIDENTITIESBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
-  name='GetIdentitiesByPublicKeyHashesRequest',
-  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest',
+_GETDOCUMENTHISTORYREQUEST = _descriptor.Descriptor(
+  name='GetDocumentHistoryRequest',
+  full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='public_key_hashes', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.public_key_hashes', index=0,
-      number=1, type=12, cpp_type=9, label=3,
-      has_default_value=False, default_value=[],
+      name='data_contract_id', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.data_contract_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prove', index=1,
-      number=2, type=8, cpp_type=7, label=1,
+      name='document_type', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.document_type', index=1,
+      number=2, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='document_id', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.document_id', index=2,
+      number=3, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='start_at_ms', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.start_at_ms', index=3,
+      number=4, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='end_at_ms', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.end_at_ms', index=4,
+      number=5, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='limit', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.limit', index=5,
+      number=6, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='offset', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.offset', index=6,
+      number=7, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryRequest.prove', index=7,
+      number=8, type=8, cpp_type=7, label=1,
       has_default_value=False, default_value=False,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
@@ -2295,22 +2416,67 @@ _GETIDENTITIESBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=6864,
-  serialized_end=6945,
+  serialized_start=6968,
+  serialized_end=7241,
 )
 
 
-_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES = _descriptor.Descriptor(
-  name='Identities',
-  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities',
+_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORYENTRY = _descriptor.Descriptor(
+  name='DocumentHistoryEntry',
+  full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='identities', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.identities', index=0,
-      number=1, type=12, cpp_type=9, label=3,
+      name='date', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.date', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='revision', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.revision', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='value', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry.value', index=2,
+      number=3, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=7487,
+  serialized_end=7556,
+)
+
+_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY = _descriptor.Descriptor(
+  name='DocumentHistory',
+  full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='document_entries', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory.document_entries', index=0,
+      number=1, type=11, cpp_type=10, label=3,
       has_default_value=False, default_value=[],
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
@@ -2327,34 +2493,34 @@ _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7204,
-  serialized_end=7236,
+  serialized_start=7558,
+  serialized_end=7677,
 )
 
-_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
-  name='GetIdentitiesByPublicKeyHashesResponse',
-  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse',
+_GETDOCUMENTHISTORYRESPONSE = _descriptor.Descriptor(
+  name='GetDocumentHistoryResponse',
+  full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='identities', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.identities', index=0,
+      name='document_history', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.document_history', index=0,
       number=1, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.proof', index=1,
+      name='proof', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.proof', index=1,
       number=2, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.metadata', index=2,
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.metadata', index=2,
       number=3, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
@@ -2363,7 +2529,7 @@ _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
   ],
   extensions=[
   ],
-  nested_types=[_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES, ],
+  nested_types=[_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORYENTRY, _GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY, ],
   enum_types=[
   ],
   serialized_options=None,
@@ -2372,34 +2538,41 @@ _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
     _descriptor.OneofDescriptor(
-      name='result', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.result',
+      name='result', full_name='org.dash.platform.dapi.v0.GetDocumentHistoryResponse.result',
       index=0, containing_type=None,
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=6948,
-  serialized_end=7246,
+  serialized_start=7244,
+  serialized_end=7687,
 )
 
 
-_GETIDENTITYBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
-  name='GetIdentityByPublicKeyHashesRequest',
-  full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest',
+_GETDOMAINCANONICALOWNERREQUEST = _descriptor.Descriptor(
+  name='GetDomainCanonicalOwnerRequest',
+  full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='public_key_hash', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.public_key_hash', index=0,
-      number=1, type=12, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"",
+      name='label', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest.label', index=0,
+      number=1, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prove', index=1,
-      number=2, type=8, cpp_type=7, label=1,
+      name='normalized_parent_domain_name', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest.normalized_parent_domain_name', index=1,
+      number=2, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest.prove', index=2,
+      number=3, type=8, cpp_type=7, label=1,
       has_default_value=False, default_value=False,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
@@ -2416,35 +2589,35 @@ _GETIDENTITYBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7248,
-  serialized_end=7325,
+  serialized_start=7689,
+  serialized_end=7790,
 )
 
 
-_GETIDENTITYBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
-  name='GetIdentityByPublicKeyHashesResponse',
-  full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse',
+_GETDOMAINCANONICALOWNERRESPONSE = _descriptor.Descriptor(
+  name='GetDomainCanonicalOwnerResponse',
+  full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='identity', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.identity', index=0,
+      name='owner_id', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.owner_id', index=0,
       number=1, type=12, cpp_type=9, label=1,
       has_default_value=False, default_value=b"",
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.proof', index=1,
+      name='proof', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.proof', index=1,
       number=2, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.metadata', index=2,
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.metadata', index=2,
       number=3, type=11, cpp_type=10, label=1,
       has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
@@ -2462,33 +2635,33 @@ _GETIDENTITYBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
     _descriptor.OneofDescriptor(
-      name='result', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.result',
+      name='result', full_name='org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse.result',
       index=0, containing_type=None,
       create_key=_descriptor._internal_create_key,
     fields=[]),
   ],
-  serialized_start=7328,
-  serialized_end=7510,
+  serialized_start=7793,
+  serialized_end=7970,
 )
 
 
-_WAITFORSTATETRANSITIONRESULTREQUEST = _descriptor.Descriptor(
-  name='WaitForStateTransitionResultRequest',
-  full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest',
+_GETCONTESTEDRESOURCEVOTETALLYREQUEST = _descriptor.Descriptor(
+  name='GetContestedResourceVoteTallyRequest',
+  full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='state_transition_hash', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.state_transition_hash', index=0,
+      name='contest_id', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest.contest_id', index=0,
       number=1, type=12, cpp_type=9, label=1,
       has_default_value=False, default_value=b"",
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='prove', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prove', index=1,
+      name='prove', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest.prove', index=1,
       number=2, type=8, cpp_type=7, label=1,
       has_default_value=False, default_value=False,
       message_type=None, enum_type=None, containing_type=None,
@@ -2506,37 +2679,30 @@ _WAITFORSTATETRANSITIONRESULTREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7512,
-  serialized_end=7595,
+  serialized_start=7972,
+  serialized_end=8045,
 )
 
 
-_WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
-  name='WaitForStateTransitionResultResponse',
-  full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse',
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTENDERVOTES = _descriptor.Descriptor(
+  name='ContenderVotes',
+  full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='error', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.error', index=0,
-      number=1, type=11, cpp_type=10, label=1,
-      has_default_value=False, default_value=None,
-      message_type=None, enum_type=None, containing_type=None,
-      is_extension=False, extension_scope=None,
-      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
-    _descriptor.FieldDescriptor(
-      name='proof', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.proof', index=1,
-      number=2, type=11, cpp_type=10, label=1,
-      has_default_value=False, default_value=None,
+      name='identity_id', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.identity_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='metadata', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.metadata', index=2,
-      number=3, type=11, cpp_type=10, label=1,
-      has_default_value=False, default_value=None,
+      name='vote_count', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes.vote_count', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
@@ -2551,43 +2717,30 @@ _WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
   syntax='proto3',
   extension_ranges=[],
   oneofs=[
-    _descriptor.OneofDescriptor(
-      name='result', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.result',
-      index=0, containing_type=None,
-      create_key=_descriptor._internal_create_key,
-    fields=[]),
   ],
-  serialized_start=7598,
-  serialized_end=7835,
+  serialized_start=8318,
+  serialized_end=8375,
 )
 
-
-_CONSENSUSPARAMSBLOCK = _descriptor.Descriptor(
-  name='ConsensusParamsBlock',
-  full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock',
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY = _descriptor.Descriptor(
+  name='ContestedResourceVoteTally',
+  full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='max_bytes', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.max_bytes', index=0,
-      number=1, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
-      message_type=None, enum_type=None, containing_type=None,
-      is_extension=False, extension_scope=None,
-      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
-    _descriptor.FieldDescriptor(
-      name='max_gas', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.max_gas', index=1,
-      number=2, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
+      name='contenders', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.contenders', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='time_iota_ms', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.time_iota_ms', index=2,
-      number=3, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
+      name='abstain_vote_count', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.abstain_vote_count', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
@@ -2603,44 +2756,43 @@ _CONSENSUSPARAMSBLOCK = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=7837,
-  serialized_end=7917,
+  serialized_start=8378,
+  serialized_end=8535,
 )
 
-
-_CONSENSUSPARAMSEVIDENCE = _descriptor.Descriptor(
-  name='ConsensusParamsEvidence',
-  full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence',
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE = _descriptor.Descriptor(
+  name='GetContestedResourceVoteTallyResponse',
+  full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='max_age_num_blocks', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_age_num_blocks', index=0,
-      number=1, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
+      name='vote_tally', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.vote_tally', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='max_age_duration', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_age_duration', index=1,
-      number=2, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
+      name='proof', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
     _descriptor.FieldDescriptor(
-      name='max_bytes', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_bytes', index=2,
-      number=3, type=9, cpp_type=9, label=1,
-      has_default_value=False, default_value=b"".decode('utf-8'),
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
   ],
   extensions=[
   ],
-  nested_types=[],
+  nested_types=[_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTENDERVOTES, _GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY, ],
   enum_types=[
   ],
   serialized_options=None,
@@ -2648,24 +2800,880 @@ _CONSENSUSPARAMSEVIDENCE = _descriptor.Descriptor(
   syntax='proto3',
   extension_ranges=[],
   oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
   ],
-  serialized_start=7919,
-  serialized_end=8017,
+  serialized_start=8048,
+  serialized_end=8545,
 )
 
 
-_GETCONSENSUSPARAMSREQUEST = _descriptor.Descriptor(
-  name='GetConsensusParamsRequest',
-  full_name='org.dash.platform.dapi.v0.GetConsensusParamsRequest',
+_ESTIMATESTATETRANSITIONFEEREQUEST = _descriptor.Descriptor(
+  name='EstimateStateTransitionFeeRequest',
+  full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest',
   filename=None,
   file=DESCRIPTOR,
   containing_type=None,
   create_key=_descriptor._internal_create_key,
   fields=[
     _descriptor.FieldDescriptor(
-      name='height', full_name='org.dash.platform.dapi.v0.GetConsensusParamsRequest.height', index=0,
-      number=1, type=3, cpp_type=2, label=1,
-      has_default_value=False, default_value=0,
+      name='state_transition', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest.state_transition', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8547,
+  serialized_end=8608,
+)
+
+
+_ESTIMATESTATETRANSITIONFEERESPONSE_EPOCHSTORAGEFEE = _descriptor.Descriptor(
+  name='EpochStorageFee',
+  full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='epoch_index', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.epoch_index', index=0,
+      number=1, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='amount', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee.amount', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8882,
+  serialized_end=8936,
+)
+
+_ESTIMATESTATETRANSITIONFEERESPONSE_IDENTITYREFUND = _descriptor.Descriptor(
+  name='IdentityRefund',
+  full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identity_id', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.identity_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='amount', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund.amount', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8938,
+  serialized_end=8991,
+)
+
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE = _descriptor.Descriptor(
+  name='FeeEstimate',
+  full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='storage_fee', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.storage_fee', index=0,
+      number=1, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='processing_fee', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.processing_fee', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='document_type_fees', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.document_type_fees', index=2,
+      number=3, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='storage_fee_per_epoch', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.storage_fee_per_epoch', index=3,
+      number=4, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='refunds', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.refunds', index=4,
+      number=5, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='balance', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.balance', index=5,
+      number=6, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='resulting_balance', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate.resulting_balance', index=6,
+      number=7, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8994,
+  serialized_end=9388,
+)
+
+_ESTIMATESTATETRANSITIONFEERESPONSE = _descriptor.Descriptor(
+  name='EstimateStateTransitionFeeResponse',
+  full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='fee_estimate', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.fee_estimate', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='error', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.error', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_ESTIMATESTATETRANSITIONFEERESPONSE_EPOCHSTORAGEFEE, _ESTIMATESTATETRANSITIONFEERESPONSE_IDENTITYREFUND, _ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=8611,
+  serialized_end=9388,
+)
+
+
+_GETIDENTITYWITHDRAWALSREQUEST = _descriptor.Descriptor(
+  name='GetIdentityWithdrawalsRequest',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identity_id', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.identity_id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='limit', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.limit', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='start_after', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.start_after', index=2,
+      number=3, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest.prove', index=3,
+      number=4, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9391,
+  serialized_end=9524,
+)
+
+
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL = _descriptor.Descriptor(
+  name='Withdrawal',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='id', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.id', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='amount', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.amount', index=1,
+      number=2, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='status', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.status', index=2,
+      number=3, type=14, cpp_type=8, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='transaction_id', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.transaction_id', index=3,
+      number=4, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='transaction_sign_height', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.transaction_sign_height', index=4,
+      number=5, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='expected_completion_core_height', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.expected_completion_core_height', index=5,
+      number=6, type=13, cpp_type=3, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='failure_reason', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.failure_reason', index=6,
+      number=7, type=14, cpp_type=8, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='refunded_at_ms', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.refunded_at_ms', index=7,
+      number=8, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='created_at_ms', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.created_at_ms', index=8,
+      number=9, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='updated_at_ms', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal.updated_at_ms', index=9,
+      number=10, type=4, cpp_type=4, label=1,
+      has_default_value=False, default_value=0,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+    _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_STATUS,
+    _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_FAILUREREASON,
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=9770,
+  serialized_end=10310,
+)
+
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS = _descriptor.Descriptor(
+  name='Withdrawals',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='withdrawals', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals.withdrawals', index=0,
+      number=1, type=11, cpp_type=10, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=10312,
+  serialized_end=10416,
+)
+
+_GETIDENTITYWITHDRAWALSRESPONSE = _descriptor.Descriptor(
+  name='GetIdentityWithdrawalsResponse',
+  full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='withdrawals', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.withdrawals', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL, _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=9527,
+  serialized_end=10426,
+)
+
+
+_GETIDENTITIESBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
+  name='GetIdentitiesByPublicKeyHashesRequest',
+  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='public_key_hashes', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.public_key_hashes', index=0,
+      number=1, type=12, cpp_type=9, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesRequest.prove', index=1,
+      number=2, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=10428,
+  serialized_end=10509,
+)
+
+
+_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES = _descriptor.Descriptor(
+  name='Identities',
+  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identities', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.Identities.identities', index=0,
+      number=1, type=12, cpp_type=9, label=3,
+      has_default_value=False, default_value=[],
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=10768,
+  serialized_end=10800,
+)
+
+_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
+  name='GetIdentitiesByPublicKeyHashesResponse',
+  full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identities', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.identities', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES, ],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetIdentitiesByPublicKeyHashesResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=10512,
+  serialized_end=10810,
+)
+
+
+_GETIDENTITYBYPUBLICKEYHASHESREQUEST = _descriptor.Descriptor(
+  name='GetIdentityByPublicKeyHashesRequest',
+  full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='public_key_hash', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.public_key_hash', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesRequest.prove', index=1,
+      number=2, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=10812,
+  serialized_end=10889,
+)
+
+
+_GETIDENTITYBYPUBLICKEYHASHESRESPONSE = _descriptor.Descriptor(
+  name='GetIdentityByPublicKeyHashesResponse',
+  full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='identity', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.identity', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.GetIdentityByPublicKeyHashesResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=10892,
+  serialized_end=11074,
+)
+
+
+_WAITFORSTATETRANSITIONRESULTREQUEST = _descriptor.Descriptor(
+  name='WaitForStateTransitionResultRequest',
+  full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='state_transition_hash', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.state_transition_hash', index=0,
+      number=1, type=12, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"",
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='prove', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultRequest.prove', index=1,
+      number=2, type=8, cpp_type=7, label=1,
+      has_default_value=False, default_value=False,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=11076,
+  serialized_end=11159,
+)
+
+
+_WAITFORSTATETRANSITIONRESULTRESPONSE = _descriptor.Descriptor(
+  name='WaitForStateTransitionResultResponse',
+  full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='error', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.error', index=0,
+      number=1, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='proof', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.proof', index=1,
+      number=2, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='metadata', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.metadata', index=2,
+      number=3, type=11, cpp_type=10, label=1,
+      has_default_value=False, default_value=None,
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+    _descriptor.OneofDescriptor(
+      name='result', full_name='org.dash.platform.dapi.v0.WaitForStateTransitionResultResponse.result',
+      index=0, containing_type=None,
+      create_key=_descriptor._internal_create_key,
+    fields=[]),
+  ],
+  serialized_start=11162,
+  serialized_end=11399,
+)
+
+
+_CONSENSUSPARAMSBLOCK = _descriptor.Descriptor(
+  name='ConsensusParamsBlock',
+  full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='max_bytes', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.max_bytes', index=0,
+      number=1, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='max_gas', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.max_gas', index=1,
+      number=2, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='time_iota_ms', full_name='org.dash.platform.dapi.v0.ConsensusParamsBlock.time_iota_ms', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=11401,
+  serialized_end=11481,
+)
+
+
+_CONSENSUSPARAMSEVIDENCE = _descriptor.Descriptor(
+  name='ConsensusParamsEvidence',
+  full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='max_age_num_blocks', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_age_num_blocks', index=0,
+      number=1, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='max_age_duration', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_age_duration', index=1,
+      number=2, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+    _descriptor.FieldDescriptor(
+      name='max_bytes', full_name='org.dash.platform.dapi.v0.ConsensusParamsEvidence.max_bytes', index=2,
+      number=3, type=9, cpp_type=9, label=1,
+      has_default_value=False, default_value=b"".decode('utf-8'),
+      message_type=None, enum_type=None, containing_type=None,
+      is_extension=False, extension_scope=None,
+      serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
+  ],
+  extensions=[
+  ],
+  nested_types=[],
+  enum_types=[
+  ],
+  serialized_options=None,
+  is_extendable=False,
+  syntax='proto3',
+  extension_ranges=[],
+  oneofs=[
+  ],
+  serialized_start=11483,
+  serialized_end=11581,
+)
+
+
+_GETCONSENSUSPARAMSREQUEST = _descriptor.Descriptor(
+  name='GetConsensusParamsRequest',
+  full_name='org.dash.platform.dapi.v0.GetConsensusParamsRequest',
+  filename=None,
+  file=DESCRIPTOR,
+  containing_type=None,
+  create_key=_descriptor._internal_create_key,
+  fields=[
+    _descriptor.FieldDescriptor(
+      name='height', full_name='org.dash.platform.dapi.v0.GetConsensusParamsRequest.height', index=0,
+      number=1, type=3, cpp_type=2, label=1,
+      has_default_value=False, default_value=0,
       message_type=None, enum_type=None, containing_type=None,
       is_extension=False, extension_scope=None,
       serialized_options=None, file=DESCRIPTOR,  create_key=_descriptor._internal_create_key),
@@ -2688,8 +3696,8 @@ _GETCONSENSUSPARAMSREQUEST = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=8019,
-  serialized_end=8077,
+  serialized_start=11583,
+  serialized_end=11641,
 )
 
 
@@ -2727,8 +3735,8 @@ _GETCONSENSUSPARAMSRESPONSE = _descriptor.Descriptor(
   extension_ranges=[],
   oneofs=[
   ],
-  serialized_start=8080,
-  serialized_end=8242,
+  serialized_start=11644,
+  serialized_end=11806,
 )
 
 _GETIDENTITYRESPONSE.fields_by_name['proof'].message_type = _PROOF
@@ -2838,6 +3846,7 @@ _GETPROOFSREQUEST.fields_by_name['contracts'].message_type = _GETPROOFSREQUEST_C
 _GETPROOFSREQUEST.fields_by_name['documents'].message_type = _GETPROOFSREQUEST_DOCUMENTREQUEST
 _GETPROOFSRESPONSE.fields_by_name['proof'].message_type = _PROOF
 _GETPROOFSRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETDATACONTRACTREQUEST.fields_by_name['at_time_ms'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
 _GETDATACONTRACTRESPONSE.fields_by_name['proof'].message_type = _PROOF
 _GETDATACONTRACTRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
 _GETDATACONTRACTRESPONSE.oneofs_by_name['result'].fields.append(
@@ -2874,6 +3883,7 @@ _GETDATACONTRACTHISTORYRESPONSE.fields_by_name['data_contract_history'].containi
 _GETDATACONTRACTHISTORYRESPONSE.oneofs_by_name['result'].fields.append(
   _GETDATACONTRACTHISTORYRESPONSE.fields_by_name['proof'])
 _GETDATACONTRACTHISTORYRESPONSE.fields_by_name['proof'].containing_oneof = _GETDATACONTRACTHISTORYRESPONSE.oneofs_by_name['result']
+_GETDOCUMENTSREQUEST.fields_by_name['block_time_ms'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
 _GETDOCUMENTSREQUEST.oneofs_by_name['start'].fields.append(
   _GETDOCUMENTSREQUEST.fields_by_name['start_after'])
 _GETDOCUMENTSREQUEST.fields_by_name['start_after'].containing_oneof = _GETDOCUMENTSREQUEST.oneofs_by_name['start']
@@ -2890,6 +3900,68 @@ _GETDOCUMENTSRESPONSE.fields_by_name['documents'].containing_oneof = _GETDOCUMEN
 _GETDOCUMENTSRESPONSE.oneofs_by_name['result'].fields.append(
   _GETDOCUMENTSRESPONSE.fields_by_name['proof'])
 _GETDOCUMENTSRESPONSE.fields_by_name['proof'].containing_oneof = _GETDOCUMENTSRESPONSE.oneofs_by_name['result']
+_GETDOCUMENTHISTORYREQUEST.fields_by_name['end_at_ms'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_GETDOCUMENTHISTORYREQUEST.fields_by_name['limit'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
+_GETDOCUMENTHISTORYREQUEST.fields_by_name['offset'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
+_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORYENTRY.containing_type = _GETDOCUMENTHISTORYRESPONSE
+_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY.fields_by_name['document_entries'].message_type = _GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORYENTRY
+_GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY.containing_type = _GETDOCUMENTHISTORYRESPONSE
+_GETDOCUMENTHISTORYRESPONSE.fields_by_name['document_history'].message_type = _GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY
+_GETDOCUMENTHISTORYRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETDOCUMENTHISTORYRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETDOCUMENTHISTORYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETDOCUMENTHISTORYRESPONSE.fields_by_name['document_history'])
+_GETDOCUMENTHISTORYRESPONSE.fields_by_name['document_history'].containing_oneof = _GETDOCUMENTHISTORYRESPONSE.oneofs_by_name['result']
+_GETDOCUMENTHISTORYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETDOCUMENTHISTORYRESPONSE.fields_by_name['proof'])
+_GETDOCUMENTHISTORYRESPONSE.fields_by_name['proof'].containing_oneof = _GETDOCUMENTHISTORYRESPONSE.oneofs_by_name['result']
+_GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETDOMAINCANONICALOWNERRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['owner_id'])
+_GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['owner_id'].containing_oneof = _GETDOMAINCANONICALOWNERRESPONSE.oneofs_by_name['result']
+_GETDOMAINCANONICALOWNERRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['proof'])
+_GETDOMAINCANONICALOWNERRESPONSE.fields_by_name['proof'].containing_oneof = _GETDOMAINCANONICALOWNERRESPONSE.oneofs_by_name['result']
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTENDERVOTES.containing_type = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY.fields_by_name['contenders'].message_type = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTENDERVOTES
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY.containing_type = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['vote_tally'].message_type = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['vote_tally'])
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['vote_tally'].containing_oneof = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE.oneofs_by_name['result']
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['proof'])
+_GETCONTESTEDRESOURCEVOTETALLYRESPONSE.fields_by_name['proof'].containing_oneof = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE.oneofs_by_name['result']
+_ESTIMATESTATETRANSITIONFEERESPONSE_EPOCHSTORAGEFEE.containing_type = _ESTIMATESTATETRANSITIONFEERESPONSE
+_ESTIMATESTATETRANSITIONFEERESPONSE_IDENTITYREFUND.containing_type = _ESTIMATESTATETRANSITIONFEERESPONSE
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE.fields_by_name['storage_fee_per_epoch'].message_type = _ESTIMATESTATETRANSITIONFEERESPONSE_EPOCHSTORAGEFEE
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE.fields_by_name['refunds'].message_type = _ESTIMATESTATETRANSITIONFEERESPONSE_IDENTITYREFUND
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE.fields_by_name['balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT64VALUE
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE.fields_by_name['resulting_balance'].message_type = google_dot_protobuf_dot_wrappers__pb2._INT64VALUE
+_ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE.containing_type = _ESTIMATESTATETRANSITIONFEERESPONSE
+_ESTIMATESTATETRANSITIONFEERESPONSE.fields_by_name['fee_estimate'].message_type = _ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE
+_ESTIMATESTATETRANSITIONFEERESPONSE.fields_by_name['error'].message_type = _STATETRANSITIONBROADCASTERROR
+_ESTIMATESTATETRANSITIONFEERESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYWITHDRAWALSREQUEST.fields_by_name['limit'].message_type = google_dot_protobuf_dot_wrappers__pb2._UINT32VALUE
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL.fields_by_name['status'].enum_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_STATUS
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL.fields_by_name['failure_reason'].enum_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_FAILUREREASON
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL.containing_type = _GETIDENTITYWITHDRAWALSRESPONSE
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_STATUS.containing_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL_FAILUREREASON.containing_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS.fields_by_name['withdrawals'].message_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL
+_GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS.containing_type = _GETIDENTITYWITHDRAWALSRESPONSE
+_GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['withdrawals'].message_type = _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS
+_GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['proof'].message_type = _PROOF
+_GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['metadata'].message_type = _RESPONSEMETADATA
+_GETIDENTITYWITHDRAWALSRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['withdrawals'])
+_GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['withdrawals'].containing_oneof = _GETIDENTITYWITHDRAWALSRESPONSE.oneofs_by_name['result']
+_GETIDENTITYWITHDRAWALSRESPONSE.oneofs_by_name['result'].fields.append(
+  _GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['proof'])
+_GETIDENTITYWITHDRAWALSRESPONSE.fields_by_name['proof'].containing_oneof = _GETIDENTITYWITHDRAWALSRESPONSE.oneofs_by_name['result']
 _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES.containing_type = _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE
 _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE.fields_by_name['identities'].message_type = _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE_IDENTITIES
 _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE.fields_by_name['proof'].message_type = _PROOF
@@ -2949,6 +4021,16 @@ DESCRIPTOR.message_types_by_name['GetDataContractHistoryRequest'] = _GETDATACONT
 DESCRIPTOR.message_types_by_name['GetDataContractHistoryResponse'] = _GETDATACONTRACTHISTORYRESPONSE
 DESCRIPTOR.message_types_by_name['GetDocumentsRequest'] = _GETDOCUMENTSREQUEST
 DESCRIPTOR.message_types_by_name['GetDocumentsResponse'] = _GETDOCUMENTSRESPONSE
+DESCRIPTOR.message_types_by_name['GetDocumentHistoryRequest'] = _GETDOCUMENTHISTORYREQUEST
+DESCRIPTOR.message_types_by_name['GetDocumentHistoryResponse'] = _GETDOCUMENTHISTORYRESPONSE
+DESCRIPTOR.message_types_by_name['GetDomainCanonicalOwnerRequest'] = _GETDOMAINCANONICALOWNERREQUEST
+DESCRIPTOR.message_types_by_name['GetDomainCanonicalOwnerResponse'] = _GETDOMAINCANONICALOWNERRESPONSE
+DESCRIPTOR.message_types_by_name['GetContestedResourceVoteTallyRequest'] = _GETCONTESTEDRESOURCEVOTETALLYREQUEST
+DESCRIPTOR.message_types_by_name['GetContestedResourceVoteTallyResponse'] = _GETCONTESTEDRESOURCEVOTETALLYRESPONSE
+DESCRIPTOR.message_types_by_name['EstimateStateTransitionFeeRequest'] = _ESTIMATESTATETRANSITIONFEEREQUEST
+DESCRIPTOR.message_types_by_name['EstimateStateTransitionFeeResponse'] = _ESTIMATESTATETRANSITIONFEERESPONSE
+DESCRIPTOR.message_types_by_name['GetIdentityWithdrawalsRequest'] = _GETIDENTITYWITHDRAWALSREQUEST
+DESCRIPTOR.message_types_by_name['GetIdentityWithdrawalsResponse'] = _GETIDENTITYWITHDRAWALSRESPONSE
 DESCRIPTOR.message_types_by_name['GetIdentitiesByPublicKeyHashesRequest'] = _GETIDENTITIESBYPUBLICKEYHASHESREQUEST
 DESCRIPTOR.message_types_by_name['GetIdentitiesByPublicKeyHashesResponse'] = _GETIDENTITIESBYPUBLICKEYHASHESRESPONSE
 DESCRIPTOR.message_types_by_name['GetIdentityByPublicKeyHashesRequest'] = _GETIDENTITYBYPUBLICKEYHASHESREQUEST
@@ -3339,6 +4421,148 @@ GetDocumentsResponse = _reflection.GeneratedProtocolMessageType('GetDocumentsRes
 _sym_db.RegisterMessage(GetDocumentsResponse)
 _sym_db.RegisterMessage(GetDocumentsResponse.Documents)
 
+GetDocumentHistoryRequest = _reflection.GeneratedProtocolMessageType('GetDocumentHistoryRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETDOCUMENTHISTORYREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDocumentHistoryRequest)
+  })
+_sym_db.RegisterMessage(GetDocumentHistoryRequest)
+
+GetDocumentHistoryResponse = _reflection.GeneratedProtocolMessageType('GetDocumentHistoryResponse', (_message.Message,), {
+
+  'DocumentHistoryEntry' : _reflection.GeneratedProtocolMessageType('DocumentHistoryEntry', (_message.Message,), {
+    'DESCRIPTOR' : _GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORYENTRY,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistoryEntry)
+    })
+  ,
+
+  'DocumentHistory' : _reflection.GeneratedProtocolMessageType('DocumentHistory', (_message.Message,), {
+    'DESCRIPTOR' : _GETDOCUMENTHISTORYRESPONSE_DOCUMENTHISTORY,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDocumentHistoryResponse.DocumentHistory)
+    })
+  ,
+  'DESCRIPTOR' : _GETDOCUMENTHISTORYRESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDocumentHistoryResponse)
+  })
+_sym_db.RegisterMessage(GetDocumentHistoryResponse)
+_sym_db.RegisterMessage(GetDocumentHistoryResponse.DocumentHistoryEntry)
+_sym_db.RegisterMessage(GetDocumentHistoryResponse.DocumentHistory)
+
+GetDomainCanonicalOwnerRequest = _reflection.GeneratedProtocolMessageType('GetDomainCanonicalOwnerRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETDOMAINCANONICALOWNERREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDomainCanonicalOwnerRequest)
+  })
+_sym_db.RegisterMessage(GetDomainCanonicalOwnerRequest)
+
+GetDomainCanonicalOwnerResponse = _reflection.GeneratedProtocolMessageType('GetDomainCanonicalOwnerResponse', (_message.Message,), {
+  'DESCRIPTOR' : _GETDOMAINCANONICALOWNERRESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetDomainCanonicalOwnerResponse)
+  })
+_sym_db.RegisterMessage(GetDomainCanonicalOwnerResponse)
+
+GetContestedResourceVoteTallyRequest = _reflection.GeneratedProtocolMessageType('GetContestedResourceVoteTallyRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETCONTESTEDRESOURCEVOTETALLYREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetContestedResourceVoteTallyRequest)
+  })
+_sym_db.RegisterMessage(GetContestedResourceVoteTallyRequest)
+
+GetContestedResourceVoteTallyResponse = _reflection.GeneratedProtocolMessageType('GetContestedResourceVoteTallyResponse', (_message.Message,), {
+
+  'ContenderVotes' : _reflection.GeneratedProtocolMessageType('ContenderVotes', (_message.Message,), {
+    'DESCRIPTOR' : _GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTENDERVOTES,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContenderVotes)
+    })
+  ,
+
+  'ContestedResourceVoteTally' : _reflection.GeneratedProtocolMessageType('ContestedResourceVoteTally', (_message.Message,), {
+    'DESCRIPTOR' : _GETCONTESTEDRESOURCEVOTETALLYRESPONSE_CONTESTEDRESOURCEVOTETALLY,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally)
+    })
+  ,
+  'DESCRIPTOR' : _GETCONTESTEDRESOURCEVOTETALLYRESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetContestedResourceVoteTallyResponse)
+  })
+_sym_db.RegisterMessage(GetContestedResourceVoteTallyResponse)
+_sym_db.RegisterMessage(GetContestedResourceVoteTallyResponse.ContenderVotes)
+_sym_db.RegisterMessage(GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally)
+
+EstimateStateTransitionFeeRequest = _reflection.GeneratedProtocolMessageType('EstimateStateTransitionFeeRequest', (_message.Message,), {
+  'DESCRIPTOR' : _ESTIMATESTATETRANSITIONFEEREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateStateTransitionFeeRequest)
+  })
+_sym_db.RegisterMessage(EstimateStateTransitionFeeRequest)
+
+EstimateStateTransitionFeeResponse = _reflection.GeneratedProtocolMessageType('EstimateStateTransitionFeeResponse', (_message.Message,), {
+
+  'EpochStorageFee' : _reflection.GeneratedProtocolMessageType('EpochStorageFee', (_message.Message,), {
+    'DESCRIPTOR' : _ESTIMATESTATETRANSITIONFEERESPONSE_EPOCHSTORAGEFEE,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.EpochStorageFee)
+    })
+  ,
+
+  'IdentityRefund' : _reflection.GeneratedProtocolMessageType('IdentityRefund', (_message.Message,), {
+    'DESCRIPTOR' : _ESTIMATESTATETRANSITIONFEERESPONSE_IDENTITYREFUND,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.IdentityRefund)
+    })
+  ,
+
+  'FeeEstimate' : _reflection.GeneratedProtocolMessageType('FeeEstimate', (_message.Message,), {
+    'DESCRIPTOR' : _ESTIMATESTATETRANSITIONFEERESPONSE_FEEESTIMATE,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse.FeeEstimate)
+    })
+  ,
+  'DESCRIPTOR' : _ESTIMATESTATETRANSITIONFEERESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.EstimateStateTransitionFeeResponse)
+  })
+_sym_db.RegisterMessage(EstimateStateTransitionFeeResponse)
+_sym_db.RegisterMessage(EstimateStateTransitionFeeResponse.EpochStorageFee)
+_sym_db.RegisterMessage(EstimateStateTransitionFeeResponse.IdentityRefund)
+_sym_db.RegisterMessage(EstimateStateTransitionFeeResponse.FeeEstimate)
+
+GetIdentityWithdrawalsRequest = _reflection.GeneratedProtocolMessageType('GetIdentityWithdrawalsRequest', (_message.Message,), {
+  'DESCRIPTOR' : _GETIDENTITYWITHDRAWALSREQUEST,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityWithdrawalsRequest)
+  })
+_sym_db.RegisterMessage(GetIdentityWithdrawalsRequest)
+
+GetIdentityWithdrawalsResponse = _reflection.GeneratedProtocolMessageType('GetIdentityWithdrawalsResponse', (_message.Message,), {
+
+  'Withdrawal' : _reflection.GeneratedProtocolMessageType('Withdrawal', (_message.Message,), {
+    'DESCRIPTOR' : _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWAL,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawal)
+    })
+  ,
+
+  'Withdrawals' : _reflection.GeneratedProtocolMessageType('Withdrawals', (_message.Message,), {
+    'DESCRIPTOR' : _GETIDENTITYWITHDRAWALSRESPONSE_WITHDRAWALS,
+    '__module__' : 'platform_pb2'
+    # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse.Withdrawals)
+    })
+  ,
+  'DESCRIPTOR' : _GETIDENTITYWITHDRAWALSRESPONSE,
+  '__module__' : 'platform_pb2'
+  # @@protoc_insertion_point(class_scope:org.dash.platform.dapi.v0.GetIdentityWithdrawalsResponse)
+  })
+_sym_db.RegisterMessage(GetIdentityWithdrawalsResponse)
+_sym_db.RegisterMessage(GetIdentityWithdrawalsResponse.Withdrawal)
+_sym_db.RegisterMessage(GetIdentityWithdrawalsResponse.Withdrawals)
+
 GetIdentitiesByPublicKeyHashesRequest = _reflection.GeneratedProtocolMessageType('GetIdentitiesByPublicKeyHashesRequest', (_message.Message,), {
   'DESCRIPTOR' : _GETIDENTITIESBYPUBLICKEYHASHESREQUEST,
   '__module__' : 'platform_pb2'
@@ -3429,8 +4653,8 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   index=0,
   serialized_options=None,
   create_key=_descriptor._internal_create_key,
-  serialized_start=8245,
-  serialized_end=10256,
+  serialized_start=11809,
+  serialized_end=14564,
   methods=[
   _descriptor.MethodDescriptor(
     name='broadcastStateTransition',
@@ -3542,10 +4766,60 @@ _PLATFORM = _descriptor.ServiceDescriptor(
     serialized_options=None,
     create_key=_descriptor._internal_create_key,
   ),
+  _descriptor.MethodDescriptor(
+    name='getDocumentHistory',
+    full_name='org.dash.platform.dapi.v0.Platform.getDocumentHistory',
+    index=11,
+    containing_service=None,
+    input_type=_GETDOCUMENTHISTORYREQUEST,
+    output_type=_GETDOCUMENTHISTORYRESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='getDomainCanonicalOwner',
+    full_name='org.dash.platform.dapi.v0.Platform.getDomainCanonicalOwner',
+    index=12,
+    containing_service=None,
+    input_type=_GETDOMAINCANONICALOWNERREQUEST,
+    output_type=_GETDOMAINCANONICALOWNERRESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='getContestedResourceVoteTally',
+    full_name='org.dash.platform.dapi.v0.Platform.getContestedResourceVoteTally',
+    index=13,
+    containing_service=None,
+    input_type=_GETCONTESTEDRESOURCEVOTETALLYREQUEST,
+    output_type=_GETCONTESTEDRESOURCEVOTETALLYRESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='estimateStateTransitionFee',
+    full_name='org.dash.platform.dapi.v0.Platform.estimateStateTransitionFee',
+    index=14,
+    containing_service=None,
+    input_type=_ESTIMATESTATETRANSITIONFEEREQUEST,
+    output_type=_ESTIMATESTATETRANSITIONFEERESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
+  _descriptor.MethodDescriptor(
+    name='getIdentityWithdrawals',
+    full_name='org.dash.platform.dapi.v0.Platform.getIdentityWithdrawals',
+    index=15,
+    containing_service=None,
+    input_type=_GETIDENTITYWITHDRAWALSREQUEST,
+    output_type=_GETIDENTITYWITHDRAWALSRESPONSE,
+    serialized_options=None,
+    create_key=_descriptor._internal_create_key,
+  ),
   _descriptor.MethodDescriptor(
     name='getIdentitiesByPublicKeyHashes',
     full_name='org.dash.platform.dapi.v0.Platform.getIdentitiesByPublicKeyHashes',
-    index=11,
+    index=16,
     containing_service=None,
     input_type=_GETIDENTITIESBYPUBLICKEYHASHESREQUEST,
     output_type=_GETIDENTITIESBYPUBLICKEYHASHESRESPONSE,
@@ -3555,7 +4829,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getIdentityByPublicKeyHashes',
     full_name='org.dash.platform.dapi.v0.Platform.getIdentityByPublicKeyHashes',
-    index=12,
+    index=17,
     containing_service=None,
     input_type=_GETIDENTITYBYPUBLICKEYHASHESREQUEST,
     output_type=_GETIDENTITYBYPUBLICKEYHASHESRESPONSE,
@@ -3565,7 +4839,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='waitForStateTransitionResult',
     full_name='org.dash.platform.dapi.v0.Platform.waitForStateTransitionResult',
-    index=13,
+    index=18,
     containing_service=None,
     input_type=_WAITFORSTATETRANSITIONRESULTREQUEST,
     output_type=_WAITFORSTATETRANSITIONRESULTRESPONSE,
@@ -3575,7 +4849,7 @@ _PLATFORM = _descriptor.ServiceDescriptor(
   _descriptor.MethodDescriptor(
     name='getConsensusParams',
     full_name='org.dash.platform.dapi.v0.Platform.getConsensusParams',
-    index=14,
+    index=19,
     containing_service=None,
     input_type=_GETCONSENSUSPARAMSREQUEST,
     output_type=_GETCONSENSUSPARAMSRESPONSE,
@@ -69,6 +69,31 @@ class PlatformStub(object):
                 request_serializer=platform__pb2.GetDocumentsRequest.SerializeToString,
                 response_deserializer=platform__pb2.GetDocumentsResponse.FromString,
                 )
+        self.getDocumentHistory = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getDocumentHistory',
+                request_serializer=platform__pb2.GetDocumentHistoryRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetDocumentHistoryResponse.FromString,
+                )
+        self.getDomainCanonicalOwner = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getDomainCanonicalOwner',
+                request_serializer=platform__pb2.GetDomainCanonicalOwnerRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetDomainCanonicalOwnerResponse.FromString,
+                )
+        self.getContestedResourceVoteTally = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getContestedResourceVoteTally',
+                request_serializer=platform__pb2.GetContestedResourceVoteTallyRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetContestedResourceVoteTallyResponse.FromString,
+                )
+        self.estimateStateTransitionFee = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/estimateStateTransitionFee',
+                request_serializer=platform__pb2.EstimateStateTransitionFeeRequest.SerializeToString,
+                response_deserializer=platform__pb2.EstimateStateTransitionFeeResponse.FromString,
+                )
+        self.getIdentityWithdrawals = channel.unary_unary(
+                '/org.dash.platform.dapi.v0.Platform/getIdentityWithdrawals',
+                request_serializer=platform__pb2.GetIdentityWithdrawalsRequest.SerializeToString,
+                response_deserializer=platform__pb2.GetIdentityWithdrawalsResponse.FromString,
+                )
         self.getIdentitiesByPublicKeyHashes = channel.unary_unary(
                 '/org.dash.platform.dapi.v0.Platform/getIdentitiesByPublicKeyHashes',
                 request_serializer=platform__pb2.GetIdentitiesByPublicKeyHashesRequest.SerializeToString,
@@ -161,6 +186,36 @@ class PlatformServicer(object):
         context.set_details('Method not implemented!')
         raise NotImplementedError('Method not implemented!')
 
+    def getDocumentHistory(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getDomainCanonicalOwner(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getContestedResourceVoteTally(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def estimateStateTransitionFee(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
+    def getIdentityWithdrawals(self, request, context):
+        """Missing associated documentation comment in .proto file."""
+        context.set_code(grpc.StatusCode.UNIMPLEMENTED)
+        context.set_details('Method not implemented!')
+        raise NotImplementedError('Method not implemented!')
+
     def getIdentitiesByPublicKeyHashes(self, request, context):
         """Missing associated documentation comment in .proto file."""
         context.set_code(grpc.StatusCode.UNIMPLEMENTED)
@@ -243,6 +298,31 @@ def add_PlatformServicer_to_server(servicer, server):
                     request_deserializer=platform__pb2.GetDocumentsRequest.FromString,
                     response_serializer=platform__pb2.GetDocumentsResponse.SerializeToString,
             ),
+            'getDocumentHistory': grpc.unary_unary_rpc_method_handler(
+                    servicer.getDocumentHistory,
+                    request_deserializer=platform__pb2.GetDocumentHistoryRequest.FromString,
+                    response_serializer=platform__pb2.GetDocumentHistoryResponse.SerializeToString,
+            ),
+            'getDomainCanonicalOwner': grpc.unary_unary_rpc_method_handler(
+                    servicer.getDomainCanonicalOwner,
+                    request_deserializer=platform__pb2.GetDomainCanonicalOwnerRequest.FromString,
+                    response_serializer=platform__pb2.GetDomainCanonicalOwnerResponse.SerializeToString,
+            ),
+            'getContestedResourceVoteTally': grpc.unary_unary_rpc_method_handler(
+                    servicer.getContestedResourceVoteTally,
+                    request_deserializer=platform__pb2.GetContestedResourceVoteTallyRequest.FromString,
+                    response_serializer=platform__pb2.GetContestedResourceVoteTallyResponse.SerializeToString,
+            ),
+            'estimateStateTransitionFee': grpc.unary_unary_rpc_method_handler(
+                    servicer.estimateStateTransitionFee,
+                    request_deserializer=platform__pb2.EstimateStateTransitionFeeRequest.FromString,
+                    response_serializer=platform__pb2.EstimateStateTransitionFeeResponse.SerializeToString,
+            ),
+            'getIdentityWithdrawals': grpc.unary_unary_rpc_method_handler(
+                    servicer.getIdentityWithdrawals,
+                    request_deserializer=platform__pb2.GetIdentityWithdrawalsRequest.FromString,
+                    response_serializer=platform__pb2.GetIdentityWithdrawalsResponse.SerializeToString,
+            ),
             'getIdentitiesByPublicKeyHashes': grpc.unary_unary_rpc_method_handler(
                     servicer.getIdentitiesByPublicKeyHashes,
                     request_deserializer=platform__pb2.GetIdentitiesByPublicKeyHashesRequest.FromString,
@@ -460,6 +540,91 @@ class Platform(object):
             options, channel_credentials,
             insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
 
+    @staticmethod
+    def getDocumentHistory(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getDocumentHistory',
+            platform__pb2.GetDocumentHistoryRequest.SerializeToString,
+            platform__pb2.GetDocumentHistoryResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getDomainCanonicalOwner(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getDomainCanonicalOwner',
+            platform__pb2.GetDomainCanonicalOwnerRequest.SerializeToString,
+            platform__pb2.GetDomainCanonicalOwnerResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getContestedResourceVoteTally(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getContestedResourceVoteTally',
+            platform__pb2.GetContestedResourceVoteTallyRequest.SerializeToString,
+            platform__pb2.GetContestedResourceVoteTallyResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def estimateStateTransitionFee(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/estimateStateTransitionFee',
+            platform__pb2.EstimateStateTransitionFeeRequest.SerializeToString,
+            platform__pb2.EstimateStateTransitionFeeResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
+    @staticmethod
+    def getIdentityWithdrawals(request,
+            target,
+            options=(),
+            channel_credentials=None,
+            call_credentials=None,
+            insecure=False,
+            compression=None,
+            wait_for_ready=None,
+            timeout=None,
+            metadata=None):
+        return grpc.experimental.unary_unary(request, target, '/org.dash.platform.dapi.v0.Platform/getIdentityWithdrawals',
+            platform__pb2.GetIdentityWithdrawalsRequest.SerializeToString,
+            platform__pb2.GetIdentityWithdrawalsResponse.FromString,
+            options, channel_credentials,
+            insecure, call_credentials, compression, wait_for_ready, timeout, metadata)
+
     @staticmethod
     def getIdentitiesByPublicKeyHashes(request,
             target,
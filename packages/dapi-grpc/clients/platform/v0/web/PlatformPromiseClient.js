@@ -86,6 +86,62 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!GetDocumentHistoryRequest} getDocumentHistoryRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetDocumentHistoryResponse>}
+   */
+  getDocumentHistory(getDocumentHistoryRequest, metadata = {}) {
+    return promisify(
+      this.client.getDocumentHistory.bind(this.client),
+    )(
+      getDocumentHistoryRequest,
+      metadata,
+    );
+  }
+
+  /**
+   * @param {!GetDomainCanonicalOwnerRequest} getDomainCanonicalOwnerRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetDomainCanonicalOwnerResponse>}
+   */
+  getDomainCanonicalOwner(getDomainCanonicalOwnerRequest, metadata = {}) {
+    return promisify(
+      this.client.getDomainCanonicalOwner.bind(this.client),
+    )(
+      getDomainCanonicalOwnerRequest,
+      metadata,
+    );
+  }
+
+  /**
+   * @param {!GetContestedResourceVoteTallyRequest} getContestedResourceVoteTallyRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetContestedResourceVoteTallyResponse>}
+   */
+  getContestedResourceVoteTally(getContestedResourceVoteTallyRequest, metadata = {}) {
+    return promisify(
+      this.client.getContestedResourceVoteTally.bind(this.client),
+    )(
+      getContestedResourceVoteTallyRequest,
+      metadata,
+    );
+  }
+
+  /**
+   * @param {!GetIdentityWithdrawalsRequest} getIdentityWithdrawalsRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!GetIdentityWithdrawalsResponse>}
+   */
+  getIdentityWithdrawals(getIdentityWithdrawalsRequest, metadata = {}) {
+    return promisify(
+      this.client.getIdentityWithdrawals.bind(this.client),
+    )(
+      getIdentityWithdrawalsRequest,
+      metadata,
+    );
+  }
+
   /**
    * @param {!GetIdentitiesByPublicKeyHashesRequest} getIdentitiesByPublicKeyHashesRequest
    * @param {?Object<string, string>} metadata
@@ -134,6 +190,20 @@ class PlatformPromiseClient {
     );
   }
 
+  /**
+   * @param {!EstimateStateTransitionFeeRequest} estimateStateTransitionFeeRequest
+   * @param {?Object<string, string>} metadata
+   * @returns {Promise<!EstimateStateTransitionFeeResponse>}
+   */
+  estimateStateTransitionFee(estimateStateTransitionFeeRequest, metadata = {}) {
+    return promisify(
+      this.client.estimateStateTransitionFee.bind(this.client),
+    )(
+      estimateStateTransitionFeeRequest,
+      metadata,
+    );
+  }
+
   /**
    * @param {string} protocolVersion
    */
@@ -1059,6 +1059,11 @@ export class GetDataContractRequest extends jspb.Message {
   getProve(): boolean;
   setProve(value: boolean): void;
 
+  hasAtTimeMs(): boolean;
+  clearAtTimeMs(): void;
+  getAtTimeMs(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+  setAtTimeMs(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
   serializeBinary(): Uint8Array;
   toObject(includeInstance?: boolean): GetDataContractRequest.AsObject;
   static toObject(includeInstance: boolean, msg: GetDataContractRequest): GetDataContractRequest.AsObject;
@@ -1073,6 +1078,7 @@ export namespace GetDataContractRequest {
   export type AsObject = {
     id: Uint8Array | string,
     prove: boolean,
+    atTimeMs?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
   }
 }
 
@@ -1430,6 +1436,11 @@ export class GetDocumentsRequest extends jspb.Message {
   getProve(): boolean;
   setProve(value: boolean): void;
 
+  hasBlockTimeMs(): boolean;
+  clearBlockTimeMs(): void;
+  getBlockTimeMs(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+  setBlockTimeMs(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
   getStartCase(): GetDocumentsRequest.StartCase;
   serializeBinary(): Uint8Array;
   toObject(includeInstance?: boolean): GetDocumentsRequest.AsObject;
@@ -1451,6 +1462,7 @@ export namespace GetDocumentsRequest {
     startAfter: Uint8Array | string,
     startAt: Uint8Array | string,
     prove: boolean,
+    blockTimeMs?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
   }
 
   export enum StartCase {
@@ -1525,6 +1537,683 @@ export namespace GetDocumentsResponse {
   }
 }
 
+export class GetDocumentHistoryRequest extends jspb.Message {
+  getDataContractId(): Uint8Array | string;
+  getDataContractId_asU8(): Uint8Array;
+  getDataContractId_asB64(): string;
+  setDataContractId(value: Uint8Array | string): void;
+
+  getDocumentType(): string;
+  setDocumentType(value: string): void;
+
+  getDocumentId(): Uint8Array | string;
+  getDocumentId_asU8(): Uint8Array;
+  getDocumentId_asB64(): string;
+  setDocumentId(value: Uint8Array | string): void;
+
+  getStartAtMs(): number;
+  setStartAtMs(value: number): void;
+
+  hasEndAtMs(): boolean;
+  clearEndAtMs(): void;
+  getEndAtMs(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+  setEndAtMs(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
+  hasLimit(): boolean;
+  clearLimit(): void;
+  getLimit(): google_protobuf_wrappers_pb.UInt32Value | undefined;
+  setLimit(value?: google_protobuf_wrappers_pb.UInt32Value): void;
+
+  hasOffset(): boolean;
+  clearOffset(): void;
+  getOffset(): google_protobuf_wrappers_pb.UInt32Value | undefined;
+  setOffset(value?: google_protobuf_wrappers_pb.UInt32Value): void;
+
+  getProve(): boolean;
+  setProve(value: boolean): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentHistoryRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentHistoryRequest): GetDocumentHistoryRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentHistoryRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentHistoryRequest;
+  static deserializeBinaryFromReader(message: GetDocumentHistoryRequest, reader: jspb.BinaryReader): GetDocumentHistoryRequest;
+}
+
+export namespace GetDocumentHistoryRequest {
+  export type AsObject = {
+    dataContractId: Uint8Array | string,
+    documentType: string,
+    documentId: Uint8Array | string,
+    startAtMs: number,
+    endAtMs?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
+    limit?: google_protobuf_wrappers_pb.UInt32Value.AsObject,
+    offset?: google_protobuf_wrappers_pb.UInt32Value.AsObject,
+    prove: boolean,
+  }
+}
+
+export class GetDocumentHistoryResponse extends jspb.Message {
+  hasDocumentHistory(): boolean;
+  clearDocumentHistory(): void;
+  getDocumentHistory(): GetDocumentHistoryResponse.DocumentHistory | undefined;
+  setDocumentHistory(value?: GetDocumentHistoryResponse.DocumentHistory): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetDocumentHistoryResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDocumentHistoryResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDocumentHistoryResponse): GetDocumentHistoryResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDocumentHistoryResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDocumentHistoryResponse;
+  static deserializeBinaryFromReader(message: GetDocumentHistoryResponse, reader: jspb.BinaryReader): GetDocumentHistoryResponse;
+}
+
+export namespace GetDocumentHistoryResponse {
+  export type AsObject = {
+    documentHistory?: GetDocumentHistoryResponse.DocumentHistory.AsObject,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class DocumentHistoryEntry extends jspb.Message {
+    getDate(): number;
+    setDate(value: number): void;
+
+    getRevision(): number;
+    setRevision(value: number): void;
+
+    getValue(): Uint8Array | string;
+    getValue_asU8(): Uint8Array;
+    getValue_asB64(): string;
+    setValue(value: Uint8Array | string): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): DocumentHistoryEntry.AsObject;
+    static toObject(includeInstance: boolean, msg: DocumentHistoryEntry): DocumentHistoryEntry.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: DocumentHistoryEntry, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): DocumentHistoryEntry;
+    static deserializeBinaryFromReader(message: DocumentHistoryEntry, reader: jspb.BinaryReader): DocumentHistoryEntry;
+  }
+
+  export namespace DocumentHistoryEntry {
+    export type AsObject = {
+      date: number,
+      revision: number,
+      value: Uint8Array | string,
+    }
+  }
+
+  export class DocumentHistory extends jspb.Message {
+    clearDocumentEntriesList(): void;
+    getDocumentEntriesList(): Array<GetDocumentHistoryResponse.DocumentHistoryEntry>;
+    setDocumentEntriesList(value: Array<GetDocumentHistoryResponse.DocumentHistoryEntry>): void;
+    addDocumentEntries(value?: GetDocumentHistoryResponse.DocumentHistoryEntry, index?: number): GetDocumentHistoryResponse.DocumentHistoryEntry;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): DocumentHistory.AsObject;
+    static toObject(includeInstance: boolean, msg: DocumentHistory): DocumentHistory.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: DocumentHistory, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): DocumentHistory;
+    static deserializeBinaryFromReader(message: DocumentHistory, reader: jspb.BinaryReader): DocumentHistory;
+  }
+
+  export namespace DocumentHistory {
+    export type AsObject = {
+      documentEntriesList: Array<GetDocumentHistoryResponse.DocumentHistoryEntry.AsObject>,
+    }
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    DOCUMENT_HISTORY = 1,
+    PROOF = 2,
+  }
+}
+
+export class GetDomainCanonicalOwnerRequest extends jspb.Message {
+  getLabel(): string;
+  setLabel(value: string): void;
+
+  getNormalizedParentDomainName(): string;
+  setNormalizedParentDomainName(value: string): void;
+
+  getProve(): boolean;
+  setProve(value: boolean): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDomainCanonicalOwnerRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDomainCanonicalOwnerRequest): GetDomainCanonicalOwnerRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDomainCanonicalOwnerRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDomainCanonicalOwnerRequest;
+  static deserializeBinaryFromReader(message: GetDomainCanonicalOwnerRequest, reader: jspb.BinaryReader): GetDomainCanonicalOwnerRequest;
+}
+
+export namespace GetDomainCanonicalOwnerRequest {
+  export type AsObject = {
+    label: string,
+    normalizedParentDomainName: string,
+    prove: boolean,
+  }
+}
+
+export class GetDomainCanonicalOwnerResponse extends jspb.Message {
+  hasOwnerId(): boolean;
+  clearOwnerId(): void;
+  getOwnerId(): Uint8Array | string;
+  getOwnerId_asU8(): Uint8Array;
+  getOwnerId_asB64(): string;
+  setOwnerId(value: Uint8Array | string): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetDomainCanonicalOwnerResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetDomainCanonicalOwnerResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetDomainCanonicalOwnerResponse): GetDomainCanonicalOwnerResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetDomainCanonicalOwnerResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetDomainCanonicalOwnerResponse;
+  static deserializeBinaryFromReader(message: GetDomainCanonicalOwnerResponse, reader: jspb.BinaryReader): GetDomainCanonicalOwnerResponse;
+}
+
+export namespace GetDomainCanonicalOwnerResponse {
+  export type AsObject = {
+    ownerId: Uint8Array | string,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    OWNER_ID = 1,
+    PROOF = 2,
+  }
+}
+
+export class GetContestedResourceVoteTallyRequest extends jspb.Message {
+  getContestId(): Uint8Array | string;
+  getContestId_asU8(): Uint8Array;
+  getContestId_asB64(): string;
+  setContestId(value: Uint8Array | string): void;
+
+  getProve(): boolean;
+  setProve(value: boolean): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetContestedResourceVoteTallyRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetContestedResourceVoteTallyRequest): GetContestedResourceVoteTallyRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetContestedResourceVoteTallyRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetContestedResourceVoteTallyRequest;
+  static deserializeBinaryFromReader(message: GetContestedResourceVoteTallyRequest, reader: jspb.BinaryReader): GetContestedResourceVoteTallyRequest;
+}
+
+export namespace GetContestedResourceVoteTallyRequest {
+  export type AsObject = {
+    contestId: Uint8Array | string,
+    prove: boolean,
+  }
+}
+
+export class GetContestedResourceVoteTallyResponse extends jspb.Message {
+  hasVoteTally(): boolean;
+  clearVoteTally(): void;
+  getVoteTally(): GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally | undefined;
+  setVoteTally(value?: GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetContestedResourceVoteTallyResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetContestedResourceVoteTallyResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetContestedResourceVoteTallyResponse): GetContestedResourceVoteTallyResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetContestedResourceVoteTallyResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetContestedResourceVoteTallyResponse;
+  static deserializeBinaryFromReader(message: GetContestedResourceVoteTallyResponse, reader: jspb.BinaryReader): GetContestedResourceVoteTallyResponse;
+}
+
+export namespace GetContestedResourceVoteTallyResponse {
+  export type AsObject = {
+    voteTally?: GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally.AsObject,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class ContenderVotes extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    getVoteCount(): number;
+    setVoteCount(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): ContenderVotes.AsObject;
+    static toObject(includeInstance: boolean, msg: ContenderVotes): ContenderVotes.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: ContenderVotes, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): ContenderVotes;
+    static deserializeBinaryFromReader(message: ContenderVotes, reader: jspb.BinaryReader): ContenderVotes;
+  }
+
+  export namespace ContenderVotes {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      voteCount: number,
+    }
+  }
+
+  export class ContestedResourceVoteTally extends jspb.Message {
+    clearContendersList(): void;
+    getContendersList(): Array<GetContestedResourceVoteTallyResponse.ContenderVotes>;
+    setContendersList(value: Array<GetContestedResourceVoteTallyResponse.ContenderVotes>): void;
+    addContenders(value?: GetContestedResourceVoteTallyResponse.ContenderVotes, index?: number): GetContestedResourceVoteTallyResponse.ContenderVotes;
+
+    getAbstainVoteCount(): number;
+    setAbstainVoteCount(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): ContestedResourceVoteTally.AsObject;
+    static toObject(includeInstance: boolean, msg: ContestedResourceVoteTally): ContestedResourceVoteTally.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: ContestedResourceVoteTally, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): ContestedResourceVoteTally;
+    static deserializeBinaryFromReader(message: ContestedResourceVoteTally, reader: jspb.BinaryReader): ContestedResourceVoteTally;
+  }
+
+  export namespace ContestedResourceVoteTally {
+    export type AsObject = {
+      contendersList: Array<GetContestedResourceVoteTallyResponse.ContenderVotes.AsObject>,
+      abstainVoteCount: number,
+    }
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    VOTE_TALLY = 1,
+    PROOF = 2,
+  }
+}
+
+export class EstimateStateTransitionFeeRequest extends jspb.Message {
+  getStateTransition(): Uint8Array | string;
+  getStateTransition_asU8(): Uint8Array;
+  getStateTransition_asB64(): string;
+  setStateTransition(value: Uint8Array | string): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateStateTransitionFeeRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeRequest): EstimateStateTransitionFeeRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateStateTransitionFeeRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeRequest;
+  static deserializeBinaryFromReader(message: EstimateStateTransitionFeeRequest, reader: jspb.BinaryReader): EstimateStateTransitionFeeRequest;
+}
+
+export namespace EstimateStateTransitionFeeRequest {
+  export type AsObject = {
+    stateTransition: Uint8Array | string,
+  }
+}
+
+export class EstimateStateTransitionFeeResponse extends jspb.Message {
+  hasFeeEstimate(): boolean;
+  clearFeeEstimate(): void;
+  getFeeEstimate(): EstimateStateTransitionFeeResponse.FeeEstimate | undefined;
+  setFeeEstimate(value?: EstimateStateTransitionFeeResponse.FeeEstimate): void;
+
+  hasError(): boolean;
+  clearError(): void;
+  getError(): StateTransitionBroadcastError | undefined;
+  setError(value?: StateTransitionBroadcastError): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): EstimateStateTransitionFeeResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: EstimateStateTransitionFeeResponse): EstimateStateTransitionFeeResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: EstimateStateTransitionFeeResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): EstimateStateTransitionFeeResponse;
+  static deserializeBinaryFromReader(message: EstimateStateTransitionFeeResponse, reader: jspb.BinaryReader): EstimateStateTransitionFeeResponse;
+}
+
+export namespace EstimateStateTransitionFeeResponse {
+  export type AsObject = {
+    feeEstimate?: EstimateStateTransitionFeeResponse.FeeEstimate.AsObject,
+    error?: StateTransitionBroadcastError.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class EpochStorageFee extends jspb.Message {
+    getEpochIndex(): number;
+    setEpochIndex(value: number): void;
+
+    getAmount(): number;
+    setAmount(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): EpochStorageFee.AsObject;
+    static toObject(includeInstance: boolean, msg: EpochStorageFee): EpochStorageFee.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: EpochStorageFee, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): EpochStorageFee;
+    static deserializeBinaryFromReader(message: EpochStorageFee, reader: jspb.BinaryReader): EpochStorageFee;
+  }
+
+  export namespace EpochStorageFee {
+    export type AsObject = {
+      epochIndex: number,
+      amount: number,
+    }
+  }
+
+  export class IdentityRefund extends jspb.Message {
+    getIdentityId(): Uint8Array | string;
+    getIdentityId_asU8(): Uint8Array;
+    getIdentityId_asB64(): string;
+    setIdentityId(value: Uint8Array | string): void;
+
+    getAmount(): number;
+    setAmount(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): IdentityRefund.AsObject;
+    static toObject(includeInstance: boolean, msg: IdentityRefund): IdentityRefund.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: IdentityRefund, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): IdentityRefund;
+    static deserializeBinaryFromReader(message: IdentityRefund, reader: jspb.BinaryReader): IdentityRefund;
+  }
+
+  export namespace IdentityRefund {
+    export type AsObject = {
+      identityId: Uint8Array | string,
+      amount: number,
+    }
+  }
+
+  export class FeeEstimate extends jspb.Message {
+    getStorageFee(): number;
+    setStorageFee(value: number): void;
+
+    getProcessingFee(): number;
+    setProcessingFee(value: number): void;
+
+    getDocumentTypeFees(): number;
+    setDocumentTypeFees(value: number): void;
+
+    clearStorageFeePerEpochList(): void;
+    getStorageFeePerEpochList(): Array<EstimateStateTransitionFeeResponse.EpochStorageFee>;
+    setStorageFeePerEpochList(value: Array<EstimateStateTransitionFeeResponse.EpochStorageFee>): void;
+    addStorageFeePerEpoch(value?: EstimateStateTransitionFeeResponse.EpochStorageFee, index?: number): EstimateStateTransitionFeeResponse.EpochStorageFee;
+
+    clearRefundsList(): void;
+    getRefundsList(): Array<EstimateStateTransitionFeeResponse.IdentityRefund>;
+    setRefundsList(value: Array<EstimateStateTransitionFeeResponse.IdentityRefund>): void;
+    addRefunds(value?: EstimateStateTransitionFeeResponse.IdentityRefund, index?: number): EstimateStateTransitionFeeResponse.IdentityRefund;
+
+    hasBalance(): boolean;
+    clearBalance(): void;
+    getBalance(): google_protobuf_wrappers_pb.UInt64Value | undefined;
+    setBalance(value?: google_protobuf_wrappers_pb.UInt64Value): void;
+
+    hasResultingBalance(): boolean;
+    clearResultingBalance(): void;
+    getResultingBalance(): google_protobuf_wrappers_pb.Int64Value | undefined;
+    setResultingBalance(value?: google_protobuf_wrappers_pb.Int64Value): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): FeeEstimate.AsObject;
+    static toObject(includeInstance: boolean, msg: FeeEstimate): FeeEstimate.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: FeeEstimate, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): FeeEstimate;
+    static deserializeBinaryFromReader(message: FeeEstimate, reader: jspb.BinaryReader): FeeEstimate;
+  }
+
+  export namespace FeeEstimate {
+    export type AsObject = {
+      storageFee: number,
+      processingFee: number,
+      documentTypeFees: number,
+      storageFeePerEpochList: Array<EstimateStateTransitionFeeResponse.EpochStorageFee.AsObject>,
+      refundsList: Array<EstimateStateTransitionFeeResponse.IdentityRefund.AsObject>,
+      balance?: google_protobuf_wrappers_pb.UInt64Value.AsObject,
+      resultingBalance?: google_protobuf_wrappers_pb.Int64Value.AsObject,
+    }
+  }
+}
+
+export class GetIdentityWithdrawalsRequest extends jspb.Message {
+  getIdentityId(): Uint8Array | string;
+  getIdentityId_asU8(): Uint8Array;
+  getIdentityId_asB64(): string;
+  setIdentityId(value: Uint8Array | string): void;
+
+  hasLimit(): boolean;
+  clearLimit(): void;
+  getLimit(): google_protobuf_wrappers_pb.UInt32Value | undefined;
+  setLimit(value?: google_protobuf_wrappers_pb.UInt32Value): void;
+
+  getStartAfter(): Uint8Array | string;
+  getStartAfter_asU8(): Uint8Array;
+  getStartAfter_asB64(): string;
+  setStartAfter(value: Uint8Array | string): void;
+
+  getProve(): boolean;
+  setProve(value: boolean): void;
+
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityWithdrawalsRequest.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityWithdrawalsRequest): GetIdentityWithdrawalsRequest.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityWithdrawalsRequest, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityWithdrawalsRequest;
+  static deserializeBinaryFromReader(message: GetIdentityWithdrawalsRequest, reader: jspb.BinaryReader): GetIdentityWithdrawalsRequest;
+}
+
+export namespace GetIdentityWithdrawalsRequest {
+  export type AsObject = {
+    identityId: Uint8Array | string,
+    limit?: google_protobuf_wrappers_pb.UInt32Value.AsObject,
+    startAfter: Uint8Array | string,
+    prove: boolean,
+  }
+}
+
+export class GetIdentityWithdrawalsResponse extends jspb.Message {
+  hasWithdrawals(): boolean;
+  clearWithdrawals(): void;
+  getWithdrawals(): GetIdentityWithdrawalsResponse.Withdrawals | undefined;
+  setWithdrawals(value?: GetIdentityWithdrawalsResponse.Withdrawals): void;
+
+  hasProof(): boolean;
+  clearProof(): void;
+  getProof(): Proof | undefined;
+  setProof(value?: Proof): void;
+
+  hasMetadata(): boolean;
+  clearMetadata(): void;
+  getMetadata(): ResponseMetadata | undefined;
+  setMetadata(value?: ResponseMetadata): void;
+
+  getResultCase(): GetIdentityWithdrawalsResponse.ResultCase;
+  serializeBinary(): Uint8Array;
+  toObject(includeInstance?: boolean): GetIdentityWithdrawalsResponse.AsObject;
+  static toObject(includeInstance: boolean, msg: GetIdentityWithdrawalsResponse): GetIdentityWithdrawalsResponse.AsObject;
+  static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+  static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+  static serializeBinaryToWriter(message: GetIdentityWithdrawalsResponse, writer: jspb.BinaryWriter): void;
+  static deserializeBinary(bytes: Uint8Array): GetIdentityWithdrawalsResponse;
+  static deserializeBinaryFromReader(message: GetIdentityWithdrawalsResponse, reader: jspb.BinaryReader): GetIdentityWithdrawalsResponse;
+}
+
+export namespace GetIdentityWithdrawalsResponse {
+  export type AsObject = {
+    withdrawals?: GetIdentityWithdrawalsResponse.Withdrawals.AsObject,
+    proof?: Proof.AsObject,
+    metadata?: ResponseMetadata.AsObject,
+  }
+
+  export class Withdrawal extends jspb.Message {
+    getId(): Uint8Array | string;
+    getId_asU8(): Uint8Array;
+    getId_asB64(): string;
+    setId(value: Uint8Array | string): void;
+
+    getAmount(): number;
+    setAmount(value: number): void;
+
+    getStatus(): GetIdentityWithdrawalsResponse.Withdrawal.StatusMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.StatusMap];
+    setStatus(value: GetIdentityWithdrawalsResponse.Withdrawal.StatusMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.StatusMap]): void;
+
+    getTransactionId(): Uint8Array | string;
+    getTransactionId_asU8(): Uint8Array;
+    getTransactionId_asB64(): string;
+    setTransactionId(value: Uint8Array | string): void;
+
+    getTransactionSignHeight(): number;
+    setTransactionSignHeight(value: number): void;
+
+    getExpectedCompletionCoreHeight(): number;
+    setExpectedCompletionCoreHeight(value: number): void;
+
+    getFailureReason(): GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap];
+    setFailureReason(value: GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap]): void;
+
+    getRefundedAtMs(): number;
+    setRefundedAtMs(value: number): void;
+
+    getCreatedAtMs(): number;
+    setCreatedAtMs(value: number): void;
+
+    getUpdatedAtMs(): number;
+    setUpdatedAtMs(value: number): void;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): Withdrawal.AsObject;
+    static toObject(includeInstance: boolean, msg: Withdrawal): Withdrawal.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: Withdrawal, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): Withdrawal;
+    static deserializeBinaryFromReader(message: Withdrawal, reader: jspb.BinaryReader): Withdrawal;
+  }
+
+  export namespace Withdrawal {
+    export type AsObject = {
+      id: Uint8Array | string,
+      amount: number,
+      status: GetIdentityWithdrawalsResponse.Withdrawal.StatusMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.StatusMap],
+      transactionId: Uint8Array | string,
+      transactionSignHeight: number,
+      expectedCompletionCoreHeight: number,
+      failureReason: GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap[keyof GetIdentityWithdrawalsResponse.Withdrawal.FailureReasonMap],
+      refundedAtMs: number,
+      createdAtMs: number,
+      updatedAtMs: number,
+    }
+
+    export interface StatusMap {
+      QUEUED: 0;
+      POOLED: 1;
+      BROADCASTED: 2;
+      COMPLETE: 3;
+      EXPIRED: 4;
+    }
+
+    export const Status: StatusMap;
+
+    export interface FailureReasonMap {
+      NONE: 0;
+      EXPIRED_BEFORE_MINED: 1;
+    }
+
+    export const FailureReason: FailureReasonMap;
+  }
+
+  export class Withdrawals extends jspb.Message {
+    clearWithdrawalsList(): void;
+    getWithdrawalsList(): Array<GetIdentityWithdrawalsResponse.Withdrawal>;
+    setWithdrawalsList(value: Array<GetIdentityWithdrawalsResponse.Withdrawal>): void;
+    addWithdrawals(value?: GetIdentityWithdrawalsResponse.Withdrawal, index?: number): GetIdentityWithdrawalsResponse.Withdrawal;
+
+    serializeBinary(): Uint8Array;
+    toObject(includeInstance?: boolean): Withdrawals.AsObject;
+    static toObject(includeInstance: boolean, msg: Withdrawals): Withdrawals.AsObject;
+    static extensions: {[key: number]: jspb.ExtensionFieldInfo<jspb.Message>};
+    static extensionsBinary: {[key: number]: jspb.ExtensionFieldBinaryInfo<jspb.Message>};
+    static serializeBinaryToWriter(message: Withdrawals, writer: jspb.BinaryWriter): void;
+    static deserializeBinary(bytes: Uint8Array): Withdrawals;
+    static deserializeBinaryFromReader(message: Withdrawals, reader: jspb.BinaryReader): Withdrawals;
+  }
+
+  export namespace Withdrawals {
+    export type AsObject = {
+      withdrawalsList: Array<GetIdentityWithdrawalsResponse.Withdrawal.AsObject>,
+    }
+  }
+
+  export enum ResultCase {
+    RESULT_NOT_SET = 0,
+    WITHDRAWALS = 1,
+    PROOF = 2,
+  }
+}
+
 export class GetIdentitiesByPublicKeyHashesRequest extends jspb.Message {
   clearPublicKeyHashesList(): void;
   getPublicKeyHashesList(): Array<Uint8Array | string>;
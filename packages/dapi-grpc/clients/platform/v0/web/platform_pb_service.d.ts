@@ -103,6 +103,51 @@ type PlatformgetDocuments = {
   readonly responseType: typeof platform_pb.GetDocumentsResponse;
 };
 
+type PlatformgetDocumentHistory = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetDocumentHistoryRequest;
+  readonly responseType: typeof platform_pb.GetDocumentHistoryResponse;
+};
+
+type PlatformgetDomainCanonicalOwner = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetDomainCanonicalOwnerRequest;
+  readonly responseType: typeof platform_pb.GetDomainCanonicalOwnerResponse;
+};
+
+type PlatformgetContestedResourceVoteTally = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetContestedResourceVoteTallyRequest;
+  readonly responseType: typeof platform_pb.GetContestedResourceVoteTallyResponse;
+};
+
+type PlatformestimateStateTransitionFee = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.EstimateStateTransitionFeeRequest;
+  readonly responseType: typeof platform_pb.EstimateStateTransitionFeeResponse;
+};
+
+type PlatformgetIdentityWithdrawals = {
+  readonly methodName: string;
+  readonly service: typeof Platform;
+  readonly requestStream: false;
+  readonly responseStream: false;
+  readonly requestType: typeof platform_pb.GetIdentityWithdrawalsRequest;
+  readonly responseType: typeof platform_pb.GetIdentityWithdrawalsResponse;
+};
+
 type PlatformgetIdentitiesByPublicKeyHashes = {
   readonly methodName: string;
   readonly service: typeof Platform;
@@ -152,6 +197,11 @@ export class Platform {
   static readonly getDataContractHistory: PlatformgetDataContractHistory;
   static readonly getDataContracts: PlatformgetDataContracts;
   static readonly getDocuments: PlatformgetDocuments;
+  static readonly getDocumentHistory: PlatformgetDocumentHistory;
+  static readonly getDomainCanonicalOwner: PlatformgetDomainCanonicalOwner;
+  static readonly getContestedResourceVoteTally: PlatformgetContestedResourceVoteTally;
+  static readonly estimateStateTransitionFee: PlatformestimateStateTransitionFee;
+  static readonly getIdentityWithdrawals: PlatformgetIdentityWithdrawals;
   static readonly getIdentitiesByPublicKeyHashes: PlatformgetIdentitiesByPublicKeyHashes;
   static readonly getIdentityByPublicKeyHashes: PlatformgetIdentityByPublicKeyHashes;
   static readonly waitForStateTransitionResult: PlatformwaitForStateTransitionResult;
@@ -289,6 +339,51 @@ export class PlatformClient {
     requestMessage: platform_pb.GetDocumentsRequest,
     callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentsResponse|null) => void
   ): UnaryResponse;
+  getDocumentHistory(
+    requestMessage: platform_pb.GetDocumentHistoryRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentHistoryResponse|null) => void
+  ): UnaryResponse;
+  getDocumentHistory(
+    requestMessage: platform_pb.GetDocumentHistoryRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDocumentHistoryResponse|null) => void
+  ): UnaryResponse;
+  getDomainCanonicalOwner(
+    requestMessage: platform_pb.GetDomainCanonicalOwnerRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDomainCanonicalOwnerResponse|null) => void
+  ): UnaryResponse;
+  getDomainCanonicalOwner(
+    requestMessage: platform_pb.GetDomainCanonicalOwnerRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetDomainCanonicalOwnerResponse|null) => void
+  ): UnaryResponse;
+  getContestedResourceVoteTally(
+    requestMessage: platform_pb.GetContestedResourceVoteTallyRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetContestedResourceVoteTallyResponse|null) => void
+  ): UnaryResponse;
+  getContestedResourceVoteTally(
+    requestMessage: platform_pb.GetContestedResourceVoteTallyRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetContestedResourceVoteTallyResponse|null) => void
+  ): UnaryResponse;
+  estimateStateTransitionFee(
+    requestMessage: platform_pb.EstimateStateTransitionFeeRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateStateTransitionFeeResponse|null) => void
+  ): UnaryResponse;
+  estimateStateTransitionFee(
+    requestMessage: platform_pb.EstimateStateTransitionFeeRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.EstimateStateTransitionFeeResponse|null) => void
+  ): UnaryResponse;
+  getIdentityWithdrawals(
+    requestMessage: platform_pb.GetIdentityWithdrawalsRequest,
+    metadata: grpc.Metadata,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityWithdrawalsResponse|null) => void
+  ): UnaryResponse;
+  getIdentityWithdrawals(
+    requestMessage: platform_pb.GetIdentityWithdrawalsRequest,
+    callback: (error: ServiceError|null, responseMessage: platform_pb.GetIdentityWithdrawalsResponse|null) => void
+  ): UnaryResponse;
   getIdentitiesByPublicKeyHashes(
     requestMessage: platform_pb.GetIdentitiesByPublicKeyHashesRequest,
     metadata: grpc.Metadata,
@@ -1,5 +1,8 @@
 pub use prost::Message;
 
+#[cfg(feature = "serde")]
+pub mod serialization;
+
 pub mod core {
     #[cfg(feature = "core_v0")]
     pub mod v0 {
@@ -1,19 +1,27 @@
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct Proof {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub grovedb_proof: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "2")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub quorum_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub signature: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag = "4")]
     pub round: u32,
     #[prost(bytes = "vec", tag = "5")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub block_id_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag = "6")]
     pub quorum_type: u32,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ResponseMetadata {
@@ -28,6 +36,8 @@ pub struct ResponseMetadata {
     #[prost(string, tag = "5")]
     pub chain_id: ::prost::alloc::string::String,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct StateTransitionBroadcastError {
@@ -36,25 +46,36 @@ pub struct StateTransitionBroadcastError {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub data: ::prost::alloc::vec::Vec<u8>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastStateTransitionRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub state_transition: ::prost::alloc::vec::Vec<u8>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct BroadcastStateTransitionResponse {}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub id: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityResponse {
@@ -65,23 +86,37 @@ pub struct GetIdentityResponse {
 }
 /// Nested message and enum types in `GetIdentityResponse`.
 pub mod get_identity_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
         #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         Identity(::prost::alloc::vec::Vec<u8>),
         #[prost(message, tag = "2")]
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesRequest {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::base64_bytes_vec")
+    )]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesResponse {
@@ -92,26 +127,42 @@ pub struct GetIdentitiesResponse {
 }
 /// Nested message and enum types in `GetIdentitiesResponse`.
 pub mod get_identities_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct IdentityValue {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub value: ::prost::alloc::vec::Vec<u8>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct IdentityEntry {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub key: ::prost::alloc::vec::Vec<u8>,
         #[prost(message, optional, tag = "2")]
         pub value: ::core::option::Option<IdentityValue>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Identities {
         #[prost(message, repeated, tag = "1")]
         pub identity_entries: ::prost::alloc::vec::Vec<IdentityEntry>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -121,6 +172,8 @@ pub mod get_identities_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityBalanceResponse {
@@ -131,6 +184,8 @@ pub struct GetIdentityBalanceResponse {
 }
 /// Nested message and enum types in `GetIdentityBalanceResponse`.
 pub mod get_identity_balance_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -140,6 +195,8 @@ pub mod get_identity_balance_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityBalanceAndRevisionResponse {
@@ -152,6 +209,8 @@ pub struct GetIdentityBalanceAndRevisionResponse {
 }
 /// Nested message and enum types in `GetIdentityBalanceAndRevisionResponse`.
 pub mod get_identity_balance_and_revision_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct BalanceAndRevision {
@@ -160,6 +219,8 @@ pub mod get_identity_balance_and_revision_response {
         #[prost(message, optional, tag = "2")]
         pub revision: ::core::option::Option<u64>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -169,6 +230,8 @@ pub mod get_identity_balance_and_revision_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct KeyRequestType {
@@ -177,6 +240,8 @@ pub struct KeyRequestType {
 }
 /// Nested message and enum types in `KeyRequestType`.
 pub mod key_request_type {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Request {
@@ -188,21 +253,29 @@ pub mod key_request_type {
         SearchKey(super::SearchKey),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AllKeys {}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SpecificKeys {
     #[prost(uint32, repeated, tag = "1")]
     pub key_ids: ::prost::alloc::vec::Vec<u32>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SearchKey {
     #[prost(map = "uint32, message", tag = "1")]
     pub purpose_map: ::std::collections::HashMap<u32, SecurityLevelMap>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SecurityLevelMap {
@@ -214,6 +287,8 @@ pub struct SecurityLevelMap {
 }
 /// Nested message and enum types in `SecurityLevelMap`.
 pub mod security_level_map {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[derive(
         Clone,
         Copy,
@@ -253,10 +328,13 @@ pub mod security_level_map {
         }
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityKeysRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub identity_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub request_type: ::core::option::Option<KeyRequestType>,
@@ -267,6 +345,8 @@ pub struct GetIdentityKeysRequest {
     #[prost(bool, tag = "5")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityKeysResponse {
@@ -277,12 +357,20 @@ pub struct GetIdentityKeysResponse {
 }
 /// Nested message and enum types in `GetIdentityKeysResponse`.
 pub mod get_identity_keys_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Keys {
         #[prost(bytes = "vec", repeated, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes_vec")
+        )]
         pub keys_bytes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -292,10 +380,16 @@ pub mod get_identity_keys_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesKeysRequest {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::base64_bytes_vec")
+    )]
     pub identity_ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(message, optional, tag = "2")]
     pub request_type: ::core::option::Option<KeyRequestType>,
@@ -308,6 +402,8 @@ pub struct GetIdentitiesKeysRequest {
 }
 /// Nested message and enum types in `GetIdentitiesKeysRequest`.
 pub mod get_identities_keys_request {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SecurityLevelMap {
@@ -319,6 +415,8 @@ pub mod get_identities_keys_request {
     }
     /// Nested message and enum types in `SecurityLevelMap`.
     pub mod security_level_map {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
         #[derive(
             Clone,
             Copy,
@@ -356,6 +454,8 @@ pub mod get_identities_keys_request {
         }
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesKeysResponse {
@@ -366,26 +466,42 @@ pub struct GetIdentitiesKeysResponse {
 }
 /// Nested message and enum types in `GetIdentitiesKeysResponse`.
 pub mod get_identities_keys_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PublicKey {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub value: ::prost::alloc::vec::Vec<u8>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PublicKeyEntry {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub key: ::prost::alloc::vec::Vec<u8>,
         #[prost(message, optional, tag = "2")]
         pub value: ::core::option::Option<PublicKey>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct PublicKeyEntries {
         #[prost(message, repeated, tag = "1")]
         pub public_key_entries: ::prost::alloc::vec::Vec<PublicKeyEntry>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -395,6 +511,8 @@ pub mod get_identities_keys_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetProofsRequest {
@@ -407,28 +525,46 @@ pub struct GetProofsRequest {
 }
 /// Nested message and enum types in `GetProofsRequest`.
 pub mod get_proofs_request {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DocumentRequest {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub contract_id: ::prost::alloc::vec::Vec<u8>,
         #[prost(string, tag = "2")]
         pub document_type: ::prost::alloc::string::String,
         #[prost(bool, tag = "3")]
         pub document_type_keeps_history: bool,
         #[prost(bytes = "vec", tag = "4")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub document_id: ::prost::alloc::vec::Vec<u8>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct IdentityRequest {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub identity_id: ::prost::alloc::vec::Vec<u8>,
         #[prost(enumeration = "identity_request::Type", tag = "2")]
         pub request_type: i32,
     }
     /// Nested message and enum types in `IdentityRequest`.
     pub mod identity_request {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
         #[derive(
             Clone,
             Copy,
@@ -469,13 +605,21 @@ pub mod get_proofs_request {
             }
         }
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct ContractRequest {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub contract_id: ::prost::alloc::vec::Vec<u8>,
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetProofsResponse {
@@ -484,14 +628,21 @@ pub struct GetProofsResponse {
     #[prost(message, optional, tag = "2")]
     pub metadata: ::core::option::Option<ResponseMetadata>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub id: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
+    #[prost(message, optional, tag = "3")]
+    pub at_time_ms: ::core::option::Option<u64>,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractResponse {
@@ -502,23 +653,37 @@ pub struct GetDataContractResponse {
 }
 /// Nested message and enum types in `GetDataContractResponse`.
 pub mod get_data_contract_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
         #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         DataContract(::prost::alloc::vec::Vec<u8>),
         #[prost(message, tag = "2")]
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractsRequest {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::base64_bytes_vec")
+    )]
     pub ids: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractsResponse {
@@ -529,26 +694,42 @@ pub struct GetDataContractsResponse {
 }
 /// Nested message and enum types in `GetDataContractsResponse`.
 pub mod get_data_contracts_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DataContractValue {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub value: ::prost::alloc::vec::Vec<u8>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DataContractEntry {
         #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub key: ::prost::alloc::vec::Vec<u8>,
         #[prost(message, optional, tag = "2")]
         pub value: ::core::option::Option<DataContractValue>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DataContracts {
         #[prost(message, repeated, tag = "1")]
         pub data_contract_entries: ::prost::alloc::vec::Vec<DataContractEntry>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -558,10 +739,13 @@ pub mod get_data_contracts_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractHistoryRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub id: ::prost::alloc::vec::Vec<u8>,
     #[prost(message, optional, tag = "2")]
     pub limit: ::core::option::Option<u32>,
@@ -572,6 +756,8 @@ pub struct GetDataContractHistoryRequest {
     #[prost(bool, tag = "5")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDataContractHistoryResponse {
@@ -582,20 +768,30 @@ pub struct GetDataContractHistoryResponse {
 }
 /// Nested message and enum types in `GetDataContractHistoryResponse`.
 pub mod get_data_contract_history_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DataContractHistoryEntry {
         #[prost(uint64, tag = "1")]
         pub date: u64,
         #[prost(bytes = "vec", tag = "2")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         pub value: ::prost::alloc::vec::Vec<u8>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct DataContractHistory {
         #[prost(message, repeated, tag = "1")]
         pub data_contract_entries: ::prost::alloc::vec::Vec<DataContractHistoryEntry>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -605,35 +801,54 @@ pub mod get_data_contract_history_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDocumentsRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub data_contract_id: ::prost::alloc::vec::Vec<u8>,
     #[prost(string, tag = "2")]
     pub document_type: ::prost::alloc::string::String,
     #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub r#where: ::prost::alloc::vec::Vec<u8>,
     #[prost(bytes = "vec", tag = "4")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub order_by: ::prost::alloc::vec::Vec<u8>,
     #[prost(uint32, tag = "5")]
     pub limit: u32,
     #[prost(bool, tag = "8")]
     pub prove: bool,
+    #[prost(message, optional, tag = "9")]
+    pub block_time_ms: ::core::option::Option<u64>,
     #[prost(oneof = "get_documents_request::Start", tags = "6, 7")]
     pub start: ::core::option::Option<get_documents_request::Start>,
 }
 /// Nested message and enum types in `GetDocumentsRequest`.
 pub mod get_documents_request {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Start {
         #[prost(bytes, tag = "6")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         StartAfter(::prost::alloc::vec::Vec<u8>),
         #[prost(bytes, tag = "7")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         StartAt(::prost::alloc::vec::Vec<u8>),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetDocumentsResponse {
@@ -644,12 +859,20 @@ pub struct GetDocumentsResponse {
 }
 /// Nested message and enum types in `GetDocumentsResponse`.
 pub mod get_documents_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Documents {
         #[prost(bytes = "vec", repeated, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes_vec")
+        )]
         pub documents: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -659,14 +882,431 @@ pub mod get_documents_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDocumentHistoryRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub data_contract_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub document_type: ::prost::alloc::string::String,
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub document_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(uint64, tag = "4")]
+    pub start_at_ms: u64,
+    #[prost(message, optional, tag = "5")]
+    pub end_at_ms: ::core::option::Option<u64>,
+    #[prost(message, optional, tag = "6")]
+    pub limit: ::core::option::Option<u32>,
+    #[prost(message, optional, tag = "7")]
+    pub offset: ::core::option::Option<u32>,
+    #[prost(bool, tag = "8")]
+    pub prove: bool,
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDocumentHistoryResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_document_history_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<get_document_history_response::Result>,
+}
+/// Nested message and enum types in `GetDocumentHistoryResponse`.
+pub mod get_document_history_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DocumentHistoryEntry {
+        #[prost(uint64, tag = "1")]
+        pub date: u64,
+        #[prost(uint64, tag = "2")]
+        pub revision: u64,
+        #[prost(bytes = "vec", tag = "3")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        pub value: ::prost::alloc::vec::Vec<u8>,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct DocumentHistory {
+        #[prost(message, repeated, tag = "1")]
+        pub document_entries: ::prost::alloc::vec::Vec<DocumentHistoryEntry>,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        DocumentHistory(DocumentHistory),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDomainCanonicalOwnerRequest {
+    #[prost(string, tag = "1")]
+    pub label: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub normalized_parent_domain_name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub prove: bool,
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetDomainCanonicalOwnerResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_domain_canonical_owner_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<get_domain_canonical_owner_response::Result>,
+}
+/// Nested message and enum types in `GetDomainCanonicalOwnerResponse`.
+pub mod get_domain_canonical_owner_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        OwnerId(::prost::alloc::vec::Vec<u8>),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetContestedResourceVoteTallyRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub contest_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag = "2")]
+    pub prove: bool,
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetContestedResourceVoteTallyResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_contested_resource_vote_tally_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<
+        get_contested_resource_vote_tally_response::Result,
+    >,
+}
+/// Nested message and enum types in `GetContestedResourceVoteTallyResponse`.
+pub mod get_contested_resource_vote_tally_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ContenderVotes {
+        #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        pub identity_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint64, tag = "2")]
+        pub vote_count: u64,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ContestedResourceVoteTally {
+        #[prost(message, repeated, tag = "1")]
+        pub contenders: ::prost::alloc::vec::Vec<ContenderVotes>,
+        #[prost(uint64, tag = "2")]
+        pub abstain_vote_count: u64,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        VoteTally(ContestedResourceVoteTally),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateStateTransitionFeeRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub state_transition: ::prost::alloc::vec::Vec<u8>,
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EstimateStateTransitionFeeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub fee_estimate: ::core::option::Option<
+        estimate_state_transition_fee_response::FeeEstimate,
+    >,
+    #[prost(message, optional, tag = "2")]
+    pub error: ::core::option::Option<StateTransitionBroadcastError>,
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+}
+/// Nested message and enum types in `EstimateStateTransitionFeeResponse`.
+pub mod estimate_state_transition_fee_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EpochStorageFee {
+        #[prost(uint32, tag = "1")]
+        pub epoch_index: u32,
+        #[prost(uint64, tag = "2")]
+        pub amount: u64,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct IdentityRefund {
+        #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        pub identity_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint64, tag = "2")]
+        pub amount: u64,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct FeeEstimate {
+        #[prost(uint64, tag = "1")]
+        pub storage_fee: u64,
+        #[prost(uint64, tag = "2")]
+        pub processing_fee: u64,
+        #[prost(uint64, tag = "3")]
+        pub document_type_fees: u64,
+        #[prost(message, repeated, tag = "4")]
+        pub storage_fee_per_epoch: ::prost::alloc::vec::Vec<EpochStorageFee>,
+        #[prost(message, repeated, tag = "5")]
+        pub refunds: ::prost::alloc::vec::Vec<IdentityRefund>,
+        #[prost(message, optional, tag = "6")]
+        pub balance: ::core::option::Option<u64>,
+        #[prost(message, optional, tag = "7")]
+        pub resulting_balance: ::core::option::Option<i64>,
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityWithdrawalsRequest {
+    #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub identity_id: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "2")]
+    pub limit: ::core::option::Option<u32>,
+    #[prost(bytes = "vec", tag = "3")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
+    pub start_after: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bool, tag = "4")]
+    pub prove: bool,
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetIdentityWithdrawalsResponse {
+    #[prost(message, optional, tag = "3")]
+    pub metadata: ::core::option::Option<ResponseMetadata>,
+    #[prost(oneof = "get_identity_withdrawals_response::Result", tags = "1, 2")]
+    pub result: ::core::option::Option<get_identity_withdrawals_response::Result>,
+}
+/// Nested message and enum types in `GetIdentityWithdrawalsResponse`.
+pub mod get_identity_withdrawals_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Withdrawal {
+        #[prost(bytes = "vec", tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        pub id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint64, tag = "2")]
+        pub amount: u64,
+        #[prost(enumeration = "withdrawal::Status", tag = "3")]
+        pub status: i32,
+        #[prost(bytes = "vec", tag = "4")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
+        pub transaction_id: ::prost::alloc::vec::Vec<u8>,
+        #[prost(uint32, tag = "5")]
+        pub transaction_sign_height: u32,
+        #[prost(uint32, tag = "6")]
+        pub expected_completion_core_height: u32,
+        #[prost(enumeration = "withdrawal::FailureReason", tag = "7")]
+        pub failure_reason: i32,
+        #[prost(uint64, tag = "8")]
+        pub refunded_at_ms: u64,
+        #[prost(uint64, tag = "9")]
+        pub created_at_ms: u64,
+        #[prost(uint64, tag = "10")]
+        pub updated_at_ms: u64,
+    }
+    /// Nested message and enum types in `Withdrawal`.
+    pub mod withdrawal {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            ::prost::Enumeration
+        )]
+        #[repr(i32)]
+        pub enum Status {
+            Queued = 0,
+            Pooled = 1,
+            Broadcasted = 2,
+            Complete = 3,
+            Expired = 4,
+        }
+        impl Status {
+            /// String value of the enum field names used in the ProtoBuf definition.
+            ///
+            /// The values are not transformed in any way and thus are considered stable
+            /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    Status::Queued => "QUEUED",
+                    Status::Pooled => "POOLED",
+                    Status::Broadcasted => "BROADCASTED",
+                    Status::Complete => "COMPLETE",
+                    Status::Expired => "EXPIRED",
+                }
+            }
+            /// Creates an enum from field names used in the ProtoBuf definition.
+            pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    "QUEUED" => Some(Self::Queued),
+                    "POOLED" => Some(Self::Pooled),
+                    "BROADCASTED" => Some(Self::Broadcasted),
+                    "COMPLETE" => Some(Self::Complete),
+                    "EXPIRED" => Some(Self::Expired),
+                    _ => None,
+                }
+            }
+        }
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            PartialEq,
+            Eq,
+            Hash,
+            PartialOrd,
+            Ord,
+            ::prost::Enumeration
+        )]
+        #[repr(i32)]
+        pub enum FailureReason {
+            None = 0,
+            ExpiredBeforeMined = 1,
+        }
+        impl FailureReason {
+            /// String value of the enum field names used in the ProtoBuf definition.
+            ///
+            /// The values are not transformed in any way and thus are considered stable
+            /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+            pub fn as_str_name(&self) -> &'static str {
+                match self {
+                    FailureReason::None => "NONE",
+                    FailureReason::ExpiredBeforeMined => "EXPIRED_BEFORE_MINED",
+                }
+            }
+            /// Creates an enum from field names used in the ProtoBuf definition.
+            pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+                match value {
+                    "NONE" => Some(Self::None),
+                    "EXPIRED_BEFORE_MINED" => Some(Self::ExpiredBeforeMined),
+                    _ => None,
+                }
+            }
+        }
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Withdrawals {
+        #[prost(message, repeated, tag = "1")]
+        pub withdrawals: ::prost::alloc::vec::Vec<Withdrawal>,
+    }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Result {
+        #[prost(message, tag = "1")]
+        Withdrawals(Withdrawals),
+        #[prost(message, tag = "2")]
+        Proof(super::Proof),
+    }
+}
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesByPublicKeyHashesRequest {
     #[prost(bytes = "vec", repeated, tag = "1")]
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "crate::serialization::base64_bytes_vec")
+    )]
     pub public_key_hashes: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentitiesByPublicKeyHashesResponse {
@@ -682,12 +1322,20 @@ pub struct GetIdentitiesByPublicKeyHashesResponse {
 }
 /// Nested message and enum types in `GetIdentitiesByPublicKeyHashesResponse`.
 pub mod get_identities_by_public_key_hashes_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct Identities {
         #[prost(bytes = "vec", repeated, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes_vec")
+        )]
         pub identities: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
     }
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -697,14 +1345,19 @@ pub mod get_identities_by_public_key_hashes_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityByPublicKeyHashesRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub public_key_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetIdentityByPublicKeyHashesResponse {
@@ -717,23 +1370,34 @@ pub struct GetIdentityByPublicKeyHashesResponse {
 }
 /// Nested message and enum types in `GetIdentityByPublicKeyHashesResponse`.
 pub mod get_identity_by_public_key_hashes_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
         #[prost(bytes, tag = "1")]
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "crate::serialization::base64_bytes")
+        )]
         Identity(::prost::alloc::vec::Vec<u8>),
         #[prost(message, tag = "2")]
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WaitForStateTransitionResultRequest {
     #[prost(bytes = "vec", tag = "1")]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::base64_bytes"))]
     pub state_transition_hash: ::prost::alloc::vec::Vec<u8>,
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WaitForStateTransitionResultResponse {
@@ -746,6 +1410,8 @@ pub struct WaitForStateTransitionResultResponse {
 }
 /// Nested message and enum types in `WaitForStateTransitionResultResponse`.
 pub mod wait_for_state_transition_result_response {
+    #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+    #[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
     #[allow(clippy::derive_partial_eq_without_eq)]
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Result {
@@ -755,6 +1421,8 @@ pub mod wait_for_state_transition_result_response {
         Proof(super::Proof),
     }
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusParamsBlock {
@@ -765,6 +1433,8 @@ pub struct ConsensusParamsBlock {
     #[prost(string, tag = "3")]
     pub time_iota_ms: ::prost::alloc::string::String,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConsensusParamsEvidence {
@@ -775,6 +1445,8 @@ pub struct ConsensusParamsEvidence {
     #[prost(string, tag = "3")]
     pub max_bytes: ::prost::alloc::string::String,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConsensusParamsRequest {
@@ -783,6 +1455,8 @@ pub struct GetConsensusParamsRequest {
     #[prost(bool, tag = "2")]
     pub prove: bool,
 }
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetConsensusParamsResponse {
@@ -1198,6 +1872,156 @@ pub mod platform_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn get_document_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDocumentHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDocumentHistoryResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getDocumentHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getDocumentHistory",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_domain_canonical_owner(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetDomainCanonicalOwnerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetDomainCanonicalOwnerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getDomainCanonicalOwner",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getDomainCanonicalOwner",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_contested_resource_vote_tally(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetContestedResourceVoteTallyRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetContestedResourceVoteTallyResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getContestedResourceVoteTally",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getContestedResourceVoteTally",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn estimate_state_transition_fee(
+            &mut self,
+            request: impl tonic::IntoRequest<super::EstimateStateTransitionFeeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EstimateStateTransitionFeeResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/estimateStateTransitionFee",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "estimateStateTransitionFee",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_identity_withdrawals(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetIdentityWithdrawalsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetIdentityWithdrawalsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/org.dash.platform.dapi.v0.Platform/getIdentityWithdrawals",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "org.dash.platform.dapi.v0.Platform",
+                        "getIdentityWithdrawals",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn get_identities_by_public_key_hashes(
             &mut self,
             request: impl tonic::IntoRequest<
@@ -0,0 +1,47 @@
+//! Serde helpers for generated messages.
+//!
+//! Bytes fields are represented as base64 strings, so messages can be exchanged as JSON.
+
+/// (De)serializes `Vec<u8>` as a base64 string
+pub mod base64_bytes {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&STANDARD.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+
+        STANDARD.decode(encoded).map_err(serde::de::Error::custom)
+    }
+}
+
+/// (De)serializes `Vec<Vec<u8>>` as an array of base64 strings
+pub mod base64_bytes_vec {
+    use base64::engine::general_purpose::STANDARD;
+    use base64::Engine;
+    use serde::ser::SerializeSeq;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(items: &[Vec<u8>], serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(items.len()))?;
+
+        for bytes in items {
+            seq.serialize_element(&STANDARD.encode(bytes))?;
+        }
+
+        seq.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Vec::<String>::deserialize(deserializer)?
+            .into_iter()
+            .map(|encoded| STANDARD.decode(encoded).map_err(serde::de::Error::custom))
+            .collect()
+    }
+}
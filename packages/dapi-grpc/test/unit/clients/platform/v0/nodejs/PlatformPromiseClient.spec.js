@@ -15,6 +15,11 @@ describe('PlatformPromiseClient', () => {
       getIdentity: this.sinon.stub().resolves(response),
       getDataContract: this.sinon.stub().resolves(response),
       getDocuments: this.sinon.stub().resolves(response),
+      getDomainCanonicalOwner: this.sinon.stub().resolves(response),
+      getIdentityWithdrawals: this.sinon.stub().resolves(response),
+      getDocumentHistory: this.sinon.stub().resolves(response),
+      getContestedResourceVoteTally: this.sinon.stub().resolves(response),
+      estimateStateTransitionFee: this.sinon.stub().resolves(response),
     };
   });
 
@@ -84,4 +89,99 @@ describe('PlatformPromiseClient', () => {
       expect(platformPromiseClient.client.getDocuments).to.be.calledOnceWith(request);
     });
   });
+
+  describe('#getDomainCanonicalOwner', () => {
+    it('should get domain canonical owner', async () => {
+      const result = await platformPromiseClient.getDomainCanonicalOwner(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getDomainCanonicalOwner).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getDomainCanonicalOwner({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
+  describe('#getIdentityWithdrawals', () => {
+    it('should get identity withdrawals', async () => {
+      const result = await platformPromiseClient.getIdentityWithdrawals(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getIdentityWithdrawals).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getIdentityWithdrawals({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
+  describe('#getDocumentHistory', () => {
+    it('should get document history', async () => {
+      const result = await platformPromiseClient.getDocumentHistory(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getDocumentHistory).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getDocumentHistory({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
+  describe('#getContestedResourceVoteTally', () => {
+    it('should get contested resource vote tally', async () => {
+      const result = await platformPromiseClient.getContestedResourceVoteTally(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.getContestedResourceVoteTally).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.getContestedResourceVoteTally({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
+
+  describe('#estimateStateTransitionFee', () => {
+    it('should estimate state transition fee', async () => {
+      const result = await platformPromiseClient.estimateStateTransitionFee(request);
+
+      expect(result).to.equal(response);
+      expect(platformPromiseClient.client.estimateStateTransitionFee).to.be.calledOnceWith(request);
+    });
+
+    it('should throw an error when metadata is not an object', async () => {
+      try {
+        platformPromiseClient.estimateStateTransitionFee({}, 'metadata');
+
+        expect.fail('Error was not thrown');
+      } catch (e) {
+        expect(e.message).to.equal('metadata must be an object');
+      }
+    });
+  });
 });
@@ -133,6 +133,76 @@ class DriveClient {
       request.serializeBinary(),
     );
   }
+
+  /**
+   * Fetch the identity owning the canonical (skeleton) form of a domain label
+   *
+   * @param {GetDomainCanonicalOwnerRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchDomainCanonicalOwner(request) {
+    return this.request(
+      '/domain/canonicalOwner',
+      request.serializeBinary(),
+    );
+  }
+
+  /**
+   * Fetch withdrawals requested by an identity
+   *
+   * @param {GetIdentityWithdrawalsRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchIdentityWithdrawals(request) {
+    return this.request(
+      '/identity/withdrawals',
+      request.serializeBinary(),
+    );
+  }
+
+  /**
+   * Fetch revision history of a document
+   *
+   * @param {GetDocumentHistoryRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchDocumentHistory(request) {
+    return this.request(
+      '/documentHistory',
+      request.serializeBinary(),
+    );
+  }
+
+  /**
+   * Fetch masternode vote tally of a contested resource
+   *
+   * @param {GetContestedResourceVoteTallyRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchContestedResourceVoteTally(request) {
+    return this.request(
+      '/contestedResource/voteTally',
+      request.serializeBinary(),
+    );
+  }
+
+  /**
+   * Fetch fee estimate of an unsigned state transition
+   *
+   * @param {EstimateStateTransitionFeeRequest} request
+   *
+   * @return {Promise<Buffer>}
+   */
+  async fetchStateTransitionFeeEstimate(request) {
+    return this.request(
+      '/estimateFee',
+      request.serializeBinary(),
+    );
+  }
 }
 
 module.exports = DriveClient;
@@ -0,0 +1,43 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    EstimateStateTransitionFeeResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @returns {estimateStateTransitionFeeHandler}
+ */
+function estimateStateTransitionFeeHandlerFactory(driveClient) {
+  /**
+   * @typedef estimateStateTransitionFeeHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<EstimateStateTransitionFeeResponse>}
+   */
+  async function estimateStateTransitionFeeHandler(call) {
+    const { request } = call;
+
+    if (!request.getStateTransition()) {
+      throw new InvalidArgumentGrpcError('stateTransition is not specified');
+    }
+
+    const feeEstimateResponseBuffer = await driveClient.fetchStateTransitionFeeEstimate(request);
+
+    return EstimateStateTransitionFeeResponse.deserializeBinary(feeEstimateResponseBuffer);
+  }
+
+  return estimateStateTransitionFeeHandler;
+}
+
+module.exports = estimateStateTransitionFeeHandlerFactory;
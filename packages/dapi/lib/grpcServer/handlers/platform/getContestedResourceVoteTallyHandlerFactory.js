@@ -0,0 +1,43 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetContestedResourceVoteTallyResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @returns {getContestedResourceVoteTallyHandler}
+ */
+function getContestedResourceVoteTallyHandlerFactory(driveClient) {
+  /**
+   * @typedef getContestedResourceVoteTallyHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<GetContestedResourceVoteTallyResponse>}
+   */
+  async function getContestedResourceVoteTallyHandler(call) {
+    const { request } = call;
+
+    if (!request.getContestId()) {
+      throw new InvalidArgumentGrpcError('contestId is not specified');
+    }
+
+    const voteTallyResponseBuffer = await driveClient.fetchContestedResourceVoteTally(request);
+
+    return GetContestedResourceVoteTallyResponse.deserializeBinary(voteTallyResponseBuffer);
+  }
+
+  return getContestedResourceVoteTallyHandler;
+}
+
+module.exports = getContestedResourceVoteTallyHandlerFactory;
@@ -0,0 +1,51 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDocumentHistoryResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @returns {getDocumentHistoryHandler}
+ */
+function getDocumentHistoryHandlerFactory(driveClient) {
+  /**
+   * @typedef getDocumentHistoryHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<GetDocumentHistoryResponse>}
+   */
+  async function getDocumentHistoryHandler(call) {
+    const { request } = call;
+
+    if (!request.getDataContractId()) {
+      throw new InvalidArgumentGrpcError('dataContractId is not specified');
+    }
+
+    if (!request.getDocumentType()) {
+      throw new InvalidArgumentGrpcError('documentType is not specified');
+    }
+
+    if (!request.getDocumentId()) {
+      throw new InvalidArgumentGrpcError('documentId is not specified');
+    }
+
+    const documentHistoryResponseBuffer = await driveClient.fetchDocumentHistory(request);
+
+    return GetDocumentHistoryResponse.deserializeBinary(documentHistoryResponseBuffer);
+  }
+
+  return getDocumentHistoryHandler;
+}
+
+module.exports = getDocumentHistoryHandlerFactory;
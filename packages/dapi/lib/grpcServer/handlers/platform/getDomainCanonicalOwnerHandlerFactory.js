@@ -0,0 +1,43 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDomainCanonicalOwnerResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @returns {getDomainCanonicalOwnerHandler}
+ */
+function getDomainCanonicalOwnerHandlerFactory(driveClient) {
+  /**
+   * @typedef getDomainCanonicalOwnerHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<GetDomainCanonicalOwnerResponse>}
+   */
+  async function getDomainCanonicalOwnerHandler(call) {
+    const { request } = call;
+
+    if (!request.getLabel()) {
+      throw new InvalidArgumentGrpcError('label is not specified');
+    }
+
+    const canonicalOwnerResponseBuffer = await driveClient.fetchDomainCanonicalOwner(request);
+
+    return GetDomainCanonicalOwnerResponse.deserializeBinary(canonicalOwnerResponseBuffer);
+  }
+
+  return getDomainCanonicalOwnerHandler;
+}
+
+module.exports = getDomainCanonicalOwnerHandlerFactory;
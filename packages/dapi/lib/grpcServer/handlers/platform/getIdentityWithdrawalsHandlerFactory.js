@@ -0,0 +1,43 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetIdentityWithdrawalsResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+/**
+ * @param {DriveClient} driveClient
+ *
+ * @returns {getIdentityWithdrawalsHandler}
+ */
+function getIdentityWithdrawalsHandlerFactory(driveClient) {
+  /**
+   * @typedef getIdentityWithdrawalsHandler
+   *
+   * @param {Object} call
+   *
+   * @return {Promise<GetIdentityWithdrawalsResponse>}
+   */
+  async function getIdentityWithdrawalsHandler(call) {
+    const { request } = call;
+
+    if (!request.getIdentityId()) {
+      throw new InvalidArgumentGrpcError('identityId is not specified');
+    }
+
+    const identityWithdrawalsResponseBuffer = await driveClient.fetchIdentityWithdrawals(request);
+
+    return GetIdentityWithdrawalsResponse.deserializeBinary(identityWithdrawalsResponseBuffer);
+  }
+
+  return getIdentityWithdrawalsHandler;
+}
+
+module.exports = getIdentityWithdrawalsHandlerFactory;
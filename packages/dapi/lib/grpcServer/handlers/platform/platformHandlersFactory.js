@@ -23,6 +23,11 @@ const {
     GetIdentitiesByPublicKeyHashesRequest,
     WaitForStateTransitionResultRequest,
     GetConsensusParamsRequest,
+    GetDomainCanonicalOwnerRequest,
+    GetIdentityWithdrawalsRequest,
+    GetDocumentHistoryRequest,
+    GetContestedResourceVoteTallyRequest,
+    EstimateStateTransitionFeeRequest,
     pbjs: {
       BroadcastStateTransitionRequest: PBJSBroadcastStateTransitionRequest,
       BroadcastStateTransitionResponse: PBJSBroadcastStateTransitionResponse,
@@ -40,6 +45,16 @@ const {
       GetConsensusParamsResponse: PBJSGetConsensusParamsResponse,
       GetDataContractHistoryRequest: PBJSGetDataContractHistoryRequest,
       GetDataContractHistoryResponse: PBJSGetDataContractHistoryResponse,
+      GetDomainCanonicalOwnerRequest: PBJSGetDomainCanonicalOwnerRequest,
+      GetDomainCanonicalOwnerResponse: PBJSGetDomainCanonicalOwnerResponse,
+      GetIdentityWithdrawalsRequest: PBJSGetIdentityWithdrawalsRequest,
+      GetIdentityWithdrawalsResponse: PBJSGetIdentityWithdrawalsResponse,
+      GetDocumentHistoryRequest: PBJSGetDocumentHistoryRequest,
+      GetDocumentHistoryResponse: PBJSGetDocumentHistoryResponse,
+      GetContestedResourceVoteTallyRequest: PBJSGetContestedResourceVoteTallyRequest,
+      GetContestedResourceVoteTallyResponse: PBJSGetContestedResourceVoteTallyResponse,
+      EstimateStateTransitionFeeRequest: PBJSEstimateStateTransitionFeeRequest,
+      EstimateStateTransitionFeeResponse: PBJSEstimateStateTransitionFeeResponse,
     },
   },
 } = require('@dashevo/dapi-grpc');
@@ -72,6 +87,21 @@ const waitForStateTransitionResultHandlerFactory = require(
 const getConsensusParamsHandlerFactory = require(
   './getConsensusParamsHandlerFactory',
 );
+const getDomainCanonicalOwnerHandlerFactory = require(
+  './getDomainCanonicalOwnerHandlerFactory',
+);
+const getIdentityWithdrawalsHandlerFactory = require(
+  './getIdentityWithdrawalsHandlerFactory',
+);
+const getDocumentHistoryHandlerFactory = require(
+  './getDocumentHistoryHandlerFactory',
+);
+const getContestedResourceVoteTallyHandlerFactory = require(
+  './getContestedResourceVoteTallyHandlerFactory',
+);
+const estimateStateTransitionFeeHandlerFactory = require(
+  './estimateStateTransitionFeeHandlerFactory',
+);
 
 const fetchProofForStateTransitionFactory = require('../../../externalApis/drive/fetchProofForStateTransitionFactory');
 const waitForTransactionToBeProvableFactory = require('../../../externalApis/tenderdash/waitForTransactionToBeProvable/waitForTransactionToBeProvableFactory');
@@ -239,6 +269,86 @@ function platformHandlersFactory(
     wrapInErrorHandler(getConsensusParamsHandler),
   );
 
+  // getDomainCanonicalOwner
+  const getDomainCanonicalOwnerHandler = getDomainCanonicalOwnerHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetDomainCanonicalOwner = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetDomainCanonicalOwnerRequest,
+      PBJSGetDomainCanonicalOwnerRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetDomainCanonicalOwnerResponse,
+    ),
+    wrapInErrorHandler(getDomainCanonicalOwnerHandler),
+  );
+
+  // getIdentityWithdrawals
+  const getIdentityWithdrawalsHandler = getIdentityWithdrawalsHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetIdentityWithdrawals = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetIdentityWithdrawalsRequest,
+      PBJSGetIdentityWithdrawalsRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetIdentityWithdrawalsResponse,
+    ),
+    wrapInErrorHandler(getIdentityWithdrawalsHandler),
+  );
+
+  // getDocumentHistory
+  const getDocumentHistoryHandler = getDocumentHistoryHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetDocumentHistory = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetDocumentHistoryRequest,
+      PBJSGetDocumentHistoryRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetDocumentHistoryResponse,
+    ),
+    wrapInErrorHandler(getDocumentHistoryHandler),
+  );
+
+  // getContestedResourceVoteTally
+  const getContestedResourceVoteTallyHandler = getContestedResourceVoteTallyHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedGetContestedResourceVoteTally = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      GetContestedResourceVoteTallyRequest,
+      PBJSGetContestedResourceVoteTallyRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSGetContestedResourceVoteTallyResponse,
+    ),
+    wrapInErrorHandler(getContestedResourceVoteTallyHandler),
+  );
+
+  // estimateStateTransitionFee
+  const estimateStateTransitionFeeHandler = estimateStateTransitionFeeHandlerFactory(
+    driveClient,
+  );
+
+  const wrappedEstimateStateTransitionFee = jsonToProtobufHandlerWrapper(
+    jsonToProtobufFactory(
+      EstimateStateTransitionFeeRequest,
+      PBJSEstimateStateTransitionFeeRequest,
+    ),
+    protobufToJsonFactory(
+      PBJSEstimateStateTransitionFeeResponse,
+    ),
+    wrapInErrorHandler(estimateStateTransitionFeeHandler),
+  );
+
   return {
     broadcastStateTransition: wrappedBroadcastStateTransition,
     getIdentity: wrappedGetIdentity,
@@ -248,6 +358,11 @@ function platformHandlersFactory(
     getIdentitiesByPublicKeyHashes: wrappedGetIdentitiesByPublicKeyHashes,
     waitForStateTransitionResult: wrappedWaitForStateTransitionResult,
     getConsensusParams: wrappedGetConsensusParams,
+    getDomainCanonicalOwner: wrappedGetDomainCanonicalOwner,
+    getIdentityWithdrawals: wrappedGetIdentityWithdrawals,
+    getDocumentHistory: wrappedGetDocumentHistory,
+    getContestedResourceVoteTally: wrappedGetContestedResourceVoteTally,
+    estimateStateTransitionFee: wrappedEstimateStateTransitionFee,
   };
 }
 
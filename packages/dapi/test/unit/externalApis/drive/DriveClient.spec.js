@@ -20,6 +20,16 @@ const {
     GetIdentityResponse,
     GetProofsRequest,
     GetProofsResponse,
+    GetDomainCanonicalOwnerRequest,
+    GetDomainCanonicalOwnerResponse,
+    GetIdentityWithdrawalsRequest,
+    GetIdentityWithdrawalsResponse,
+    GetDocumentHistoryRequest,
+    GetDocumentHistoryResponse,
+    GetContestedResourceVoteTallyRequest,
+    GetContestedResourceVoteTallyResponse,
+    EstimateStateTransitionFeeRequest,
+    EstimateStateTransitionFeeResponse,
     Proof,
     ResponseMetadata,
   },
@@ -268,4 +278,156 @@ describe('DriveClient', () => {
       );
     });
   });
+
+  describe('#fetchDomainCanonicalOwner', () => {
+    it('should call \'fetchDomainCanonicalOwner\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const request = new GetDomainCanonicalOwnerRequest();
+      request.setLabel('alice');
+      request.setNormalizedParentDomainName('dash');
+      request.setProve(true);
+
+      const response = new GetDomainCanonicalOwnerResponse();
+      response.setProof(new Proof());
+      response.setMetadata(new ResponseMetadata());
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchDomainCanonicalOwner(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/domain/canonicalOwner',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
+  describe('#fetchIdentityWithdrawals', () => {
+    it('should call \'fetchIdentityWithdrawals\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const request = new GetIdentityWithdrawalsRequest();
+      request.setIdentityId(Buffer.alloc(32, 1));
+      request.setProve(true);
+
+      const response = new GetIdentityWithdrawalsResponse();
+      response.setProof(new Proof());
+      response.setMetadata(new ResponseMetadata());
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchIdentityWithdrawals(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/identity/withdrawals',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
+  describe('#fetchDocumentHistory', () => {
+    it('should call \'fetchDocumentHistory\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const request = new GetDocumentHistoryRequest();
+      request.setDataContractId(Buffer.alloc(32, 1));
+      request.setDocumentType('note');
+      request.setDocumentId(Buffer.alloc(32, 2));
+      request.setProve(true);
+
+      const response = new GetDocumentHistoryResponse();
+      response.setProof(new Proof());
+      response.setMetadata(new ResponseMetadata());
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchDocumentHistory(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/documentHistory',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
+  describe('#fetchContestedResourceVoteTally', () => {
+    it('should call \'fetchContestedResourceVoteTally\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const request = new GetContestedResourceVoteTallyRequest();
+      request.setContestId(Buffer.alloc(32, 1));
+      request.setProve(true);
+
+      const response = new GetContestedResourceVoteTallyResponse();
+      response.setProof(new Proof());
+      response.setMetadata(new ResponseMetadata());
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchContestedResourceVoteTally(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/contestedResource/voteTally',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
+
+  describe('#fetchStateTransitionFeeEstimate', () => {
+    it('should call \'fetchStateTransitionFeeEstimate\' RPC with the given parameters', async () => {
+      const drive = new DriveClient({ host: '127.0.0.1', port: 3000 });
+
+      const request = new EstimateStateTransitionFeeRequest();
+      request.setStateTransition(Buffer.from('stateTransition'));
+
+      const response = new EstimateStateTransitionFeeResponse();
+      response.setFeeEstimate(new EstimateStateTransitionFeeResponse.FeeEstimate());
+      response.setMetadata(new ResponseMetadata());
+      const responseBytes = response.serializeBinary();
+
+      sinon.stub(drive.client, 'request')
+        .resolves({
+          result: {
+            response: { code: 0, value: responseBytes },
+          },
+        });
+
+      const result = await drive.fetchStateTransitionFeeEstimate(request);
+
+      expect(drive.client.request).to.have.been.calledOnceWithExactly('abci_query', {
+        path: '/estimateFee',
+        data: Buffer.from(request.serializeBinary()).toString('hex'),
+      });
+      expect(result).to.be.deep.equal(responseBytes);
+    });
+  });
 });
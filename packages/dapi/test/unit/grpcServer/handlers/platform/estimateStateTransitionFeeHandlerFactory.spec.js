@@ -0,0 +1,94 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    EstimateStateTransitionFeeResponse,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const estimateStateTransitionFeeHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/estimateStateTransitionFeeHandlerFactory',
+);
+
+describe('estimateStateTransitionFeeHandlerFactory', () => {
+  let call;
+  let estimateStateTransitionFeeHandler;
+  let driveClientMock;
+  let request;
+  let stateTransition;
+  let feeEstimate;
+  let response;
+
+  beforeEach(async function beforeEach() {
+    stateTransition = Buffer.from('stateTransition');
+
+    feeEstimate = new EstimateStateTransitionFeeResponse.FeeEstimate();
+    feeEstimate.setStorageFee(1000);
+    feeEstimate.setProcessingFee(200);
+
+    request = {
+      getStateTransition: this.sinon.stub().returns(stateTransition),
+    };
+
+    call = new GrpcCallMock(this.sinon, request);
+
+    response = new EstimateStateTransitionFeeResponse();
+    response.setFeeEstimate(feeEstimate);
+
+    driveClientMock = {
+      fetchStateTransitionFeeEstimate: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    estimateStateTransitionFeeHandler = estimateStateTransitionFeeHandlerFactory(
+      driveClientMock,
+    );
+  });
+
+  it('should return fee estimate', async () => {
+    const result = await estimateStateTransitionFeeHandler(call);
+
+    expect(result).to.be.an.instanceOf(EstimateStateTransitionFeeResponse);
+
+    expect(result.getFeeEstimate().toObject()).to.deep.equal(feeEstimate.toObject());
+    expect(result.getError()).to.be.undefined();
+
+    expect(driveClientMock.fetchStateTransitionFeeEstimate).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if stateTransition is not specified', async () => {
+    request.getStateTransition.returns('');
+
+    try {
+      await estimateStateTransitionFeeHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('stateTransition is not specified');
+      expect(driveClientMock.fetchStateTransitionFeeEstimate).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveClient throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    driveClientMock.fetchStateTransitionFeeEstimate.throws(abciResponseError);
+
+    try {
+      await estimateStateTransitionFeeHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
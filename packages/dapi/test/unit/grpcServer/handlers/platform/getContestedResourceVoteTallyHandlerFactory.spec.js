@@ -0,0 +1,132 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetContestedResourceVoteTallyResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getContestedResourceVoteTallyHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/getContestedResourceVoteTallyHandlerFactory',
+);
+
+describe('getContestedResourceVoteTallyHandlerFactory', () => {
+  let call;
+  let getContestedResourceVoteTallyHandler;
+  let driveClientMock;
+  let request;
+  let contestId;
+  let contenderVotes;
+  let proofFixture;
+  let proofMock;
+  let response;
+  let proofResponse;
+
+  beforeEach(async function beforeEach() {
+    contestId = Buffer.alloc(32, 1);
+
+    contenderVotes = new GetContestedResourceVoteTallyResponse.ContenderVotes();
+    contenderVotes.setIdentityId(Buffer.alloc(32, 2));
+    contenderVotes.setVoteCount(3);
+
+    request = {
+      getContestId: this.sinon.stub().returns(contestId),
+      getProve: this.sinon.stub().returns(false),
+    };
+
+    call = new GrpcCallMock(this.sinon, request);
+
+    proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    response = new GetContestedResourceVoteTallyResponse();
+    const voteTally = new GetContestedResourceVoteTallyResponse.ContestedResourceVoteTally();
+    voteTally.setContendersList([contenderVotes]);
+    voteTally.setAbstainVoteCount(1);
+    response.setVoteTally(voteTally);
+
+    proofResponse = new GetContestedResourceVoteTallyResponse();
+    proofResponse.setProof(proofMock);
+
+    driveClientMock = {
+      fetchContestedResourceVoteTally: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    getContestedResourceVoteTallyHandler = getContestedResourceVoteTallyHandlerFactory(
+      driveClientMock,
+    );
+  });
+
+  it('should return vote tally', async () => {
+    const result = await getContestedResourceVoteTallyHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetContestedResourceVoteTallyResponse);
+
+    const voteTally = result.getVoteTally();
+    const [resultContenderVotes] = voteTally.getContendersList();
+    expect(resultContenderVotes.toObject()).to.deep.equal(contenderVotes.toObject());
+    expect(voteTally.getAbstainVoteCount()).to.equal(1);
+
+    expect(result.getProof()).to.be.undefined();
+
+    expect(driveClientMock.fetchContestedResourceVoteTally).to.be.calledOnceWith(call.request);
+  });
+
+  it('should return proof', async () => {
+    request.getProve.returns(true);
+    driveClientMock.fetchContestedResourceVoteTally.resolves(proofResponse.serializeBinary());
+
+    const result = await getContestedResourceVoteTallyHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetContestedResourceVoteTallyResponse);
+
+    const proof = result.getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    expect(proof.getGrovedbProof()).to.deep.equal(proofFixture.merkleProof);
+
+    expect(driveClientMock.fetchContestedResourceVoteTally).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if contestId is not specified', async () => {
+    request.getContestId.returns('');
+
+    try {
+      await getContestedResourceVoteTallyHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('contestId is not specified');
+      expect(driveClientMock.fetchContestedResourceVoteTally).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveClient throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    driveClientMock.fetchContestedResourceVoteTally.throws(abciResponseError);
+
+    try {
+      await getContestedResourceVoteTallyHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
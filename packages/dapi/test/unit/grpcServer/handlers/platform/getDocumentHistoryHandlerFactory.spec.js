@@ -0,0 +1,164 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDocumentHistoryResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getDocumentHistoryHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/getDocumentHistoryHandlerFactory',
+);
+
+describe('getDocumentHistoryHandlerFactory', () => {
+  let call;
+  let getDocumentHistoryHandler;
+  let driveClientMock;
+  let request;
+  let dataContractId;
+  let documentType;
+  let documentId;
+  let historyEntry;
+  let proofFixture;
+  let proofMock;
+  let response;
+  let proofResponse;
+
+  beforeEach(async function beforeEach() {
+    dataContractId = Buffer.alloc(32, 1);
+    documentType = 'note';
+    documentId = Buffer.alloc(32, 2);
+
+    historyEntry = new GetDocumentHistoryResponse.DocumentHistoryEntry();
+    historyEntry.setDate(1000);
+    historyEntry.setRevision(2);
+    historyEntry.setValue(Buffer.from('document'));
+
+    request = {
+      getDataContractId: this.sinon.stub().returns(dataContractId),
+      getDocumentType: this.sinon.stub().returns(documentType),
+      getDocumentId: this.sinon.stub().returns(documentId),
+      getProve: this.sinon.stub().returns(false),
+    };
+
+    call = new GrpcCallMock(this.sinon, request);
+
+    proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    response = new GetDocumentHistoryResponse();
+    const documentHistory = new GetDocumentHistoryResponse.DocumentHistory();
+    documentHistory.setDocumentEntriesList([historyEntry]);
+    response.setDocumentHistory(documentHistory);
+
+    proofResponse = new GetDocumentHistoryResponse();
+    proofResponse.setProof(proofMock);
+
+    driveClientMock = {
+      fetchDocumentHistory: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    getDocumentHistoryHandler = getDocumentHistoryHandlerFactory(
+      driveClientMock,
+    );
+  });
+
+  it('should return document history', async () => {
+    const result = await getDocumentHistoryHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDocumentHistoryResponse);
+
+    const [resultEntry] = result.getDocumentHistory().getDocumentEntriesList();
+    expect(resultEntry.toObject()).to.deep.equal(historyEntry.toObject());
+
+    expect(result.getProof()).to.be.undefined();
+
+    expect(driveClientMock.fetchDocumentHistory).to.be.calledOnceWith(call.request);
+  });
+
+  it('should return proof', async () => {
+    request.getProve.returns(true);
+    driveClientMock.fetchDocumentHistory.resolves(proofResponse.serializeBinary());
+
+    const result = await getDocumentHistoryHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDocumentHistoryResponse);
+
+    const proof = result.getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    expect(proof.getGrovedbProof()).to.deep.equal(proofFixture.merkleProof);
+
+    expect(driveClientMock.fetchDocumentHistory).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if dataContractId is not specified', async () => {
+    request.getDataContractId.returns('');
+
+    try {
+      await getDocumentHistoryHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('dataContractId is not specified');
+      expect(driveClientMock.fetchDocumentHistory).to.be.not.called();
+    }
+  });
+
+  it('should throw InvalidArgumentGrpcError error if documentType is not specified', async () => {
+    request.getDocumentType.returns('');
+
+    try {
+      await getDocumentHistoryHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('documentType is not specified');
+      expect(driveClientMock.fetchDocumentHistory).to.be.not.called();
+    }
+  });
+
+  it('should throw InvalidArgumentGrpcError error if documentId is not specified', async () => {
+    request.getDocumentId.returns('');
+
+    try {
+      await getDocumentHistoryHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('documentId is not specified');
+      expect(driveClientMock.fetchDocumentHistory).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveClient throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    driveClientMock.fetchDocumentHistory.throws(abciResponseError);
+
+    try {
+      await getDocumentHistoryHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
@@ -0,0 +1,126 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetDomainCanonicalOwnerResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getDomainCanonicalOwnerHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/getDomainCanonicalOwnerHandlerFactory',
+);
+
+describe('getDomainCanonicalOwnerHandlerFactory', () => {
+  let call;
+  let getDomainCanonicalOwnerHandler;
+  let driveClientMock;
+  let request;
+  let label;
+  let normalizedParentDomainName;
+  let ownerId;
+  let proofFixture;
+  let proofMock;
+  let response;
+  let proofResponse;
+
+  beforeEach(async function beforeEach() {
+    label = 'AIice';
+    normalizedParentDomainName = 'dash';
+    ownerId = Buffer.alloc(32, 1);
+
+    request = {
+      getLabel: this.sinon.stub().returns(label),
+      getNormalizedParentDomainName: this.sinon.stub().returns(normalizedParentDomainName),
+      getProve: this.sinon.stub().returns(false),
+    };
+
+    call = new GrpcCallMock(this.sinon, request);
+
+    proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    response = new GetDomainCanonicalOwnerResponse();
+    response.setOwnerId(ownerId);
+
+    proofResponse = new GetDomainCanonicalOwnerResponse();
+    proofResponse.setProof(proofMock);
+
+    driveClientMock = {
+      fetchDomainCanonicalOwner: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    getDomainCanonicalOwnerHandler = getDomainCanonicalOwnerHandlerFactory(
+      driveClientMock,
+    );
+  });
+
+  it('should return canonical owner id', async () => {
+    const result = await getDomainCanonicalOwnerHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDomainCanonicalOwnerResponse);
+
+    expect(Buffer.from(result.getOwnerId_asU8())).to.deep.equal(ownerId);
+
+    expect(result.getProof()).to.be.undefined();
+
+    expect(driveClientMock.fetchDomainCanonicalOwner).to.be.calledOnceWith(call.request);
+  });
+
+  it('should return proof', async () => {
+    request.getProve.returns(true);
+    driveClientMock.fetchDomainCanonicalOwner.resolves(proofResponse.serializeBinary());
+
+    const result = await getDomainCanonicalOwnerHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetDomainCanonicalOwnerResponse);
+
+    const proof = result.getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    expect(proof.getGrovedbProof()).to.deep.equal(proofFixture.merkleProof);
+
+    expect(driveClientMock.fetchDomainCanonicalOwner).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if label is not specified', async () => {
+    request.getLabel.returns('');
+
+    try {
+      await getDomainCanonicalOwnerHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('label is not specified');
+      expect(driveClientMock.fetchDomainCanonicalOwner).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveClient throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    driveClientMock.fetchDomainCanonicalOwner.throws(abciResponseError);
+
+    try {
+      await getDomainCanonicalOwnerHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
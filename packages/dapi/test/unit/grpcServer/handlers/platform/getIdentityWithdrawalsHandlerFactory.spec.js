@@ -0,0 +1,132 @@
+const {
+  server: {
+    error: {
+      InvalidArgumentGrpcError,
+    },
+  },
+} = require('@dashevo/grpc-common');
+
+const {
+  v0: {
+    GetIdentityWithdrawalsResponse,
+    Proof,
+  },
+} = require('@dashevo/dapi-grpc');
+
+const GrpcCallMock = require('../../../../../lib/test/mock/GrpcCallMock');
+
+const getIdentityWithdrawalsHandlerFactory = require(
+  '../../../../../lib/grpcServer/handlers/platform/getIdentityWithdrawalsHandlerFactory',
+);
+
+describe('getIdentityWithdrawalsHandlerFactory', () => {
+  let call;
+  let getIdentityWithdrawalsHandler;
+  let driveClientMock;
+  let request;
+  let identityId;
+  let withdrawal;
+  let proofFixture;
+  let proofMock;
+  let response;
+  let proofResponse;
+
+  beforeEach(async function beforeEach() {
+    identityId = Buffer.alloc(32, 1);
+
+    withdrawal = new GetIdentityWithdrawalsResponse.Withdrawal();
+    withdrawal.setId(Buffer.alloc(32, 2));
+    withdrawal.setAmount(1000);
+    withdrawal.setStatus(GetIdentityWithdrawalsResponse.Withdrawal.Status.QUEUED);
+
+    request = {
+      getIdentityId: this.sinon.stub().returns(identityId),
+      getLimit: this.sinon.stub().returns(undefined),
+      getStartAfter: this.sinon.stub().returns(''),
+      getProve: this.sinon.stub().returns(false),
+    };
+
+    call = new GrpcCallMock(this.sinon, request);
+
+    proofFixture = {
+      merkleProof: Buffer.alloc(1, 1),
+    };
+
+    proofMock = new Proof();
+    proofMock.setGrovedbProof(proofFixture.merkleProof);
+
+    response = new GetIdentityWithdrawalsResponse();
+    const withdrawals = new GetIdentityWithdrawalsResponse.Withdrawals();
+    withdrawals.setWithdrawalsList([withdrawal]);
+    response.setWithdrawals(withdrawals);
+
+    proofResponse = new GetIdentityWithdrawalsResponse();
+    proofResponse.setProof(proofMock);
+
+    driveClientMock = {
+      fetchIdentityWithdrawals: this.sinon.stub().resolves(response.serializeBinary()),
+    };
+
+    getIdentityWithdrawalsHandler = getIdentityWithdrawalsHandlerFactory(
+      driveClientMock,
+    );
+  });
+
+  it('should return identity withdrawals', async () => {
+    const result = await getIdentityWithdrawalsHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetIdentityWithdrawalsResponse);
+
+    const [resultWithdrawal] = result.getWithdrawals().getWithdrawalsList();
+    expect(resultWithdrawal.toObject()).to.deep.equal(withdrawal.toObject());
+
+    expect(result.getProof()).to.be.undefined();
+
+    expect(driveClientMock.fetchIdentityWithdrawals).to.be.calledOnceWith(call.request);
+  });
+
+  it('should return proof', async () => {
+    request.getProve.returns(true);
+    driveClientMock.fetchIdentityWithdrawals.resolves(proofResponse.serializeBinary());
+
+    const result = await getIdentityWithdrawalsHandler(call);
+
+    expect(result).to.be.an.instanceOf(GetIdentityWithdrawalsResponse);
+
+    const proof = result.getProof();
+
+    expect(proof).to.be.an.instanceOf(Proof);
+    expect(proof.getGrovedbProof()).to.deep.equal(proofFixture.merkleProof);
+
+    expect(driveClientMock.fetchIdentityWithdrawals).to.be.calledOnceWith(call.request);
+  });
+
+  it('should throw InvalidArgumentGrpcError error if identityId is not specified', async () => {
+    request.getIdentityId.returns('');
+
+    try {
+      await getIdentityWithdrawalsHandler(call);
+
+      expect.fail('should thrown InvalidArgumentGrpcError error');
+    } catch (e) {
+      expect(e).to.be.instanceOf(InvalidArgumentGrpcError);
+      expect(e.getMessage()).to.equal('identityId is not specified');
+      expect(driveClientMock.fetchIdentityWithdrawals).to.be.not.called();
+    }
+  });
+
+  it('should throw error if driveClient throws an error', async () => {
+    const message = 'Some error';
+    const abciResponseError = new Error(message);
+
+    driveClientMock.fetchIdentityWithdrawals.throws(abciResponseError);
+
+    try {
+      await getIdentityWithdrawalsHandler(call);
+
+      expect.fail('should throw error');
+    } catch (e) {
+      expect(e).to.equal(abciResponseError);
+    }
+  });
+});
@@ -0,0 +1,18 @@
+use platform_version::version::FeatureVersion;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Error {
+    /// Platform expected some specific versions
+    #[error("system data contract unknown version on {method}, received: {received}")]
+    UnknownVersionMismatch {
+        /// method
+        method: String,
+        /// the allowed versions for this method
+        known_versions: Vec<FeatureVersion>,
+        /// requested version
+        received: FeatureVersion,
+    },
+    #[error("schema deserialize error: {0}")]
+    InvalidSchemaJson(#[from] serde_json::Error),
+}
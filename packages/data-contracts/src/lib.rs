@@ -1,10 +1,14 @@
-use serde_json::{Error, Value};
+mod error;
+
+use serde_json::Value;
 
 pub use dashpay_contract;
 pub use dpns_contract;
+pub use error::Error;
 pub use feature_flags_contract;
 pub use masternode_reward_shares_contract;
 use platform_value::Identifier;
+use platform_version::version::PlatformVersion;
 pub use withdrawals_contract;
 
 #[repr(u8)]
@@ -35,38 +39,88 @@ impl SystemDataContract {
         };
         Identifier::new(bytes)
     }
-    /// Returns [DataContractSource]
-    pub fn source(self) -> Result<DataContractSource, Error> {
+    /// Returns [DataContractSource] with the document schemas of the contract version
+    /// used by the platform version
+    pub fn source(self, platform_version: &PlatformVersion) -> Result<DataContractSource, Error> {
         let data = match self {
             SystemDataContract::Withdrawals => DataContractSource {
                 id_bytes: withdrawals_contract::ID_BYTES,
                 owner_id_bytes: withdrawals_contract::OWNER_ID_BYTES,
                 definitions: None,
-                document_schemas: withdrawals_contract::load_documents_schemas()?,
+                document_schemas: match platform_version.system_data_contracts.withdrawals {
+                    1 => withdrawals_contract::load_documents_schemas()?,
+                    version => {
+                        return Err(Error::UnknownVersionMismatch {
+                            method: "Withdrawals::source".to_string(),
+                            known_versions: vec![1],
+                            received: version,
+                        })
+                    }
+                },
             },
             SystemDataContract::MasternodeRewards => DataContractSource {
                 id_bytes: masternode_reward_shares_contract::ID_BYTES,
                 owner_id_bytes: masternode_reward_shares_contract::OWNER_ID_BYTES,
                 definitions: None,
-                document_schemas: masternode_reward_shares_contract::load_documents_schemas()?,
+                document_schemas: match platform_version
+                    .system_data_contracts
+                    .masternode_reward_shares
+                {
+                    1 => masternode_reward_shares_contract::load_documents_schemas()?,
+                    version => {
+                        return Err(Error::UnknownVersionMismatch {
+                            method: "MasternodeRewards::source".to_string(),
+                            known_versions: vec![1],
+                            received: version,
+                        })
+                    }
+                },
             },
             SystemDataContract::FeatureFlags => DataContractSource {
                 id_bytes: feature_flags_contract::ID_BYTES,
                 owner_id_bytes: feature_flags_contract::OWNER_ID_BYTES,
                 definitions: None,
-                document_schemas: feature_flags_contract::load_documents_schemas()?,
+                document_schemas: match platform_version.system_data_contracts.feature_flags {
+                    1 => feature_flags_contract::load_documents_schemas()?,
+                    version => {
+                        return Err(Error::UnknownVersionMismatch {
+                            method: "FeatureFlags::source".to_string(),
+                            known_versions: vec![1],
+                            received: version,
+                        })
+                    }
+                },
             },
             SystemDataContract::DPNS => DataContractSource {
                 id_bytes: dpns_contract::ID_BYTES,
                 owner_id_bytes: dpns_contract::OWNER_ID_BYTES,
                 definitions: None,
-                document_schemas: dpns_contract::load_documents_schemas()?,
+                document_schemas: match platform_version.system_data_contracts.dpns {
+                    1 => dpns_contract::load_documents_schemas()?,
+                    2 => dpns_contract::load_documents_schemas_v2()?,
+                    version => {
+                        return Err(Error::UnknownVersionMismatch {
+                            method: "DPNS::source".to_string(),
+                            known_versions: vec![1, 2],
+                            received: version,
+                        })
+                    }
+                },
             },
             SystemDataContract::Dashpay => DataContractSource {
                 id_bytes: dashpay_contract::ID_BYTES,
                 owner_id_bytes: dashpay_contract::OWNER_ID_BYTES,
                 definitions: None,
-                document_schemas: dashpay_contract::load_documents_schemas()?,
+                document_schemas: match platform_version.system_data_contracts.dashpay {
+                    1 => dashpay_contract::load_documents_schemas()?,
+                    version => {
+                        return Err(Error::UnknownVersionMismatch {
+                            method: "Dashpay::source".to_string(),
+                            known_versions: vec![1],
+                            received: version,
+                        })
+                    }
+                },
             },
         };
 
@@ -0,0 +1,77 @@
+//! Confusable skeletons of domain labels.
+//!
+//! Follows the skeleton algorithm of [Unicode TR39](https://www.unicode.org/reports/tr39/#Confusable_Detection)
+//! restricted to the label charset `[a-zA-Z0-9-]`: every character is replaced by the prototype
+//! of its confusable class, so two labels that can be mistaken for each other share a skeleton.
+
+/// Returns the prototype of a label character, mapping is taken from the TR39
+/// confusables table for characters that are allowed in labels and extended with
+/// digits that are commonly mistaken for letters
+fn prototype(character: char) -> &'static str {
+    match character {
+        '0' | 'o' | 'O' => "o",
+        '1' | 'l' | 'I' | 'i' => "l",
+        '2' | 'z' | 'Z' => "z",
+        '5' | 's' | 'S' => "s",
+        '6' | '8' | 'b' | 'B' => "b",
+        'm' | 'M' => "rn",
+        'w' | 'W' => "vv",
+        'd' | 'D' => "cl",
+        _ => "",
+    }
+}
+
+/// Computes the confusable skeleton of a domain label.
+///
+/// The skeleton is computed from the label as submitted and not from the normalized label,
+/// since lowercasing merges `I` with `i` while it is confusable with `l`.
+pub fn label_skeleton(label: &str) -> String {
+    let mut skeleton = String::with_capacity(label.len());
+
+    for character in label.chars() {
+        match prototype(character) {
+            "" => skeleton.extend(character.to_lowercase()),
+            prototype => skeleton.push_str(prototype),
+        }
+    }
+
+    skeleton
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_return_same_skeleton_for_confusable_labels() {
+        assert_eq!(label_skeleton("alice"), label_skeleton("a1ice"));
+        assert_eq!(label_skeleton("alice"), label_skeleton("aIice"));
+        assert_eq!(label_skeleton("o"), label_skeleton("0"));
+        assert_eq!(label_skeleton("modern"), label_skeleton("rnodern"));
+        assert_eq!(label_skeleton("Wallet"), label_skeleton("vvallet"));
+    }
+
+    #[test]
+    fn should_return_same_skeleton_for_confusable_characters() {
+        for (character, confusable) in [
+            ("i", "l"),
+            ("i", "1"),
+            ("5", "s"),
+            ("8", "b"),
+            ("2", "z"),
+            ("6", "b"),
+        ] {
+            assert_eq!(
+                label_skeleton(&format!("ab{character}c")),
+                label_skeleton(&format!("ab{confusable}c")),
+                "expected {character} to be confusable with {confusable}"
+            );
+        }
+    }
+
+    #[test]
+    fn should_return_different_skeletons_for_distinct_labels() {
+        assert_ne!(label_skeleton("alice"), label_skeleton("alise"));
+        assert_ne!(label_skeleton("bob-1"), label_skeleton("bob-2"));
+    }
+}
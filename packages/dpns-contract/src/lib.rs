@@ -6,6 +6,8 @@ pub const ID_BYTES: [u8; 32] = [
     196, 13, 87, 33, 246, 34, 191, 83, 197, 49, 85,
 ];
 
+pub mod confusables;
+
 pub const OWNER_ID_BYTES: [u8; 32] = [
     48, 18, 193, 155, 152, 236, 0, 51, 173, 219, 54, 205, 100, 183, 245, 16, 103, 15, 42, 53, 26,
     67, 4, 181, 246, 153, 65, 68, 40, 110, 253, 172,
@@ -18,6 +20,7 @@ pub mod document_types {
         pub mod properties {
             pub const LABEL: &str = "label";
             pub const NORMALIZED_LABEL: &str = "normalizedLabel";
+            pub const LABEL_SKELETON: &str = "labelSkeleton";
             pub const NORMALIZED_PARENT_DOMAIN_NAME: &str = "normalizedParentDomainName";
             pub const PREORDER_SALT: &str = "preorderSalt";
             pub const ALLOW_SUBDOMAINS: &str = "subdomainRules.allowSubdomains";
@@ -26,6 +29,14 @@ pub mod document_types {
             pub const DASH_ALIAS_IDENTITY_ID: &str = "dashAliasIdentityId";
         }
     }
+
+    pub mod preorder {
+        pub const NAME: &str = "preorder";
+
+        pub mod properties {
+            pub const SALTED_DOMAIN_HASH: &str = "saltedDomainHash";
+        }
+    }
 }
 
 pub const ID: Identifier = Identifier(IdentifierBytes32(ID_BYTES));
@@ -34,3 +45,8 @@ pub const OWNER_ID: Identifier = Identifier(IdentifierBytes32(OWNER_ID_BYTES));
 pub fn load_documents_schemas() -> Result<Value, Error> {
     serde_json::from_str(include_str!("../schema/dpns-contract-documents.json"))
 }
+
+/// Domains expire and are renewed based on `$updatedAt`, stale preorders are purged by `$createdAt`
+pub fn load_documents_schemas_v2() -> Result<Value, Error> {
+    serde_json::from_str(include_str!("../schema/v2/dpns-contract-documents.json"))
+}
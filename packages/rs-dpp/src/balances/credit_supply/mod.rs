@@ -0,0 +1,203 @@
+use crate::balances::credits::Creditable;
+use crate::fee::{Credits, SignedCredits};
+use crate::ProtocolError;
+
+/// Credits held by every part of Platform state that stores them
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreditSupply {
+    /// Credits that entered platform minus all withdrawn credits, including pending withdrawals
+    pub total_credits_in_platform: Credits,
+    /// Credits of withdrawals that are not completed or expired yet
+    pub pending_withdrawals: Credits,
+    /// Credits in identity balances
+    pub identity_balances: SignedCredits,
+    /// Credits in epoch pools
+    pub epoch_pools: SignedCredits,
+    /// Credits in the storage fee distribution pool
+    pub storage_pool: SignedCredits,
+    /// Pending epoch refunds, they are stored as negative values
+    pub pending_refunds: SignedCredits,
+    /// Credits summed by the pools sum tree
+    pub total_in_pools: SignedCredits,
+    /// Credits that ever entered Platform, tracked since protocol version 2
+    pub minted_credits: Option<Credits>,
+    /// Credits of completed withdrawals, tracked since protocol version 2
+    pub completed_withdrawals: Option<Credits>,
+}
+
+impl CreditSupply {
+    /// Credits that entered platform minus completed withdrawals.
+    ///
+    /// Without minted and completed withdrawals counters it falls back to the total system
+    /// credits plus pending withdrawals. Pending withdrawals then cancel out with their
+    /// subtree, so the check can't catch credits lost by withdrawals.
+    pub fn expected_total(&self) -> Result<SignedCredits, ProtocolError> {
+        match (self.minted_credits, self.completed_withdrawals) {
+            (Some(minted_credits), Some(completed_withdrawals)) => minted_credits
+                .to_signed()?
+                .checked_sub(completed_withdrawals.to_signed()?)
+                .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                    "Overflow of expected total credits".to_string(),
+                )),
+            _ => self.system_credits_with_pending_withdrawals(),
+        }
+    }
+
+    /// Total system credits plus pending withdrawals
+    fn system_credits_with_pending_withdrawals(&self) -> Result<SignedCredits, ProtocolError> {
+        self.total_credits_in_platform
+            .to_signed()?
+            .checked_add(self.pending_withdrawals.to_signed()?)
+            .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                "Overflow of total system credits with pending withdrawals".to_string(),
+            ))
+    }
+
+    /// How many more credits the total system credits and pending withdrawals hold than expected
+    pub fn system_credits_difference(&self) -> Result<SignedCredits, ProtocolError> {
+        self.system_credits_with_pending_withdrawals()?
+            .checked_sub(self.expected_total()?)
+            .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                "Overflow of system credits difference".to_string(),
+            ))
+    }
+
+    /// Credits per subtree, named as they should be reported
+    pub fn subtrees(&self) -> Result<[(&'static str, SignedCredits); 5], ProtocolError> {
+        Ok([
+            ("identity balances", self.identity_balances),
+            ("epoch pools", self.epoch_pools),
+            ("storage pool", self.storage_pool),
+            ("pending refunds", self.pending_refunds),
+            ("pending withdrawals", self.pending_withdrawals.to_signed()?),
+        ])
+    }
+
+    /// Sum of credits in all subtrees
+    pub fn total_in_subtrees(&self) -> Result<SignedCredits, ProtocolError> {
+        self.subtrees()?
+            .into_iter()
+            .try_fold(0 as SignedCredits, |total, (_, credits)| {
+                total.checked_add(credits)
+            })
+            .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                "Overflow of total credits in subtrees".to_string(),
+            ))
+    }
+
+    /// How many more credits the pools subtrees hold than the pools sum tree
+    pub fn pools_difference(&self) -> Result<SignedCredits, ProtocolError> {
+        self.epoch_pools
+            .checked_add(self.storage_pool)
+            .and_then(|total| total.checked_add(self.pending_refunds))
+            .and_then(|total| total.checked_sub(self.total_in_pools))
+            .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                "Overflow of credits in pools".to_string(),
+            ))
+    }
+
+    /// How many more credits the subtrees hold than expected
+    pub fn difference(&self) -> Result<SignedCredits, ProtocolError> {
+        self.total_in_subtrees()?
+            .checked_sub(self.expected_total()?)
+            .ok_or(ProtocolError::CriticalCorruptedCreditsCodeExecution(
+                "Overflow of credits difference".to_string(),
+            ))
+    }
+
+    /// Are no credits created or destroyed
+    /// Errors in case of overflow
+    pub fn ok(&self) -> Result<bool, ProtocolError> {
+        Ok(self.difference()? == 0
+            && self.pools_difference()? == 0
+            && self.system_credits_difference()? == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_balance_when_subtrees_hold_all_credits() {
+        let credit_supply = CreditSupply {
+            total_credits_in_platform: 1000,
+            pending_withdrawals: 100,
+            identity_balances: 600,
+            epoch_pools: 250,
+            storage_pool: 200,
+            pending_refunds: -50,
+            total_in_pools: 400,
+            ..Default::default()
+        };
+
+        assert_eq!(credit_supply.expected_total().unwrap(), 1100);
+        assert_eq!(credit_supply.total_in_subtrees().unwrap(), 1100);
+        assert!(credit_supply.ok().unwrap());
+    }
+
+    #[test]
+    fn should_report_differences() {
+        let credit_supply = CreditSupply {
+            total_credits_in_platform: 1000,
+            pending_withdrawals: 100,
+            identity_balances: 610,
+            epoch_pools: 250,
+            storage_pool: 200,
+            pending_refunds: -50,
+            total_in_pools: 395,
+            ..Default::default()
+        };
+
+        assert_eq!(credit_supply.difference().unwrap(), 10);
+        assert_eq!(credit_supply.pools_difference().unwrap(), 5);
+        assert!(!credit_supply.ok().unwrap());
+    }
+
+    #[test]
+    fn should_expect_minted_credits_minus_completed_withdrawals() {
+        let credit_supply = CreditSupply {
+            total_credits_in_platform: 1000,
+            pending_withdrawals: 100,
+            identity_balances: 600,
+            epoch_pools: 250,
+            storage_pool: 200,
+            pending_refunds: -50,
+            total_in_pools: 400,
+            minted_credits: Some(1300),
+            completed_withdrawals: Some(200),
+        };
+
+        assert_eq!(credit_supply.expected_total().unwrap(), 1100);
+        assert!(credit_supply.ok().unwrap());
+    }
+
+    #[test]
+    fn should_report_lost_pending_withdrawals() {
+        // A pending withdrawal disappeared together with its system credits
+        let credit_supply = CreditSupply {
+            total_credits_in_platform: 1000,
+            pending_withdrawals: 0,
+            identity_balances: 600,
+            epoch_pools: 250,
+            storage_pool: 200,
+            pending_refunds: -50,
+            total_in_pools: 400,
+            minted_credits: Some(1300),
+            completed_withdrawals: Some(200),
+        };
+
+        assert_eq!(credit_supply.difference().unwrap(), -100);
+        assert_eq!(credit_supply.system_credits_difference().unwrap(), -100);
+        assert!(!credit_supply.ok().unwrap());
+
+        // Without counters the loss cancels out
+        let credit_supply = CreditSupply {
+            minted_credits: None,
+            completed_withdrawals: None,
+            ..credit_supply
+        };
+
+        assert!(credit_supply.ok().unwrap());
+    }
+}
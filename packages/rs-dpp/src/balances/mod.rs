@@ -1,3 +1,5 @@
 pub mod total_credits_balance;
 
+pub mod credit_supply;
+
 pub mod credits;
@@ -8,6 +8,7 @@ use crate::data_contract::document_type::{DocumentType, DocumentTypeMutRef, Docu
 use platform_value::{Identifier, Value};
 
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::fee::Credits;
 use crate::identity::SecurityLevel;
 use std::collections::{BTreeMap, BTreeSet};
 pub use v0::*;
@@ -108,6 +109,24 @@ impl DocumentTypeV0Getters for DocumentType {
             DocumentType::V0(v0) => v0.security_level_requirement(),
         }
     }
+
+    fn document_creation_fee(&self) -> Credits {
+        match self {
+            DocumentType::V0(v0) => v0.document_creation_fee(),
+        }
+    }
+
+    fn document_replace_fee(&self) -> Credits {
+        match self {
+            DocumentType::V0(v0) => v0.document_replace_fee(),
+        }
+    }
+
+    fn document_fee_beneficiary(&self) -> Option<Identifier> {
+        match self {
+            DocumentType::V0(v0) => v0.document_fee_beneficiary(),
+        }
+    }
 }
 
 impl<'a> DocumentTypeV0Getters for DocumentTypeRef<'a> {
@@ -206,6 +225,24 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeRef<'a> {
             DocumentTypeRef::V0(v0) => v0.security_level_requirement(),
         }
     }
+
+    fn document_creation_fee(&self) -> Credits {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.document_creation_fee(),
+        }
+    }
+
+    fn document_replace_fee(&self) -> Credits {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.document_replace_fee(),
+        }
+    }
+
+    fn document_fee_beneficiary(&self) -> Option<Identifier> {
+        match self {
+            DocumentTypeRef::V0(v0) => v0.document_fee_beneficiary(),
+        }
+    }
 }
 
 impl<'a> DocumentTypeV0Getters for DocumentTypeMutRef<'a> {
@@ -304,4 +341,22 @@ impl<'a> DocumentTypeV0Getters for DocumentTypeMutRef<'a> {
             DocumentTypeMutRef::V0(v0) => v0.security_level_requirement(),
         }
     }
+
+    fn document_creation_fee(&self) -> Credits {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.document_creation_fee(),
+        }
+    }
+
+    fn document_replace_fee(&self) -> Credits {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.document_replace_fee(),
+        }
+    }
+
+    fn document_fee_beneficiary(&self) -> Option<Identifier> {
+        match self {
+            DocumentTypeMutRef::V0(v0) => v0.document_fee_beneficiary(),
+        }
+    }
 }
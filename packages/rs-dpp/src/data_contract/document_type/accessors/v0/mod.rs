@@ -5,6 +5,7 @@ use crate::data_contract::document_type::property::DocumentProperty;
 use platform_value::{Identifier, Value};
 
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::fee::Credits;
 use crate::identity::SecurityLevel;
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -19,6 +20,11 @@ pub trait DocumentTypeV0Getters {
     /// Returns the indices of the document type.
     fn indices(&self) -> &Vec<Index>;
 
+    /// Returns the contested index of the document type if there is one.
+    fn contested_index(&self) -> Option<&Index> {
+        self.indices().iter().find(|index| index.contested)
+    }
+
     /// Returns the index structure of the document type.
     fn index_structure(&self) -> &IndexLevel;
 
@@ -54,4 +60,13 @@ pub trait DocumentTypeV0Getters {
 
     /// The security level requirements
     fn security_level_requirement(&self) -> SecurityLevel;
+
+    /// Returns the additional fee in credits charged for creating a document
+    fn document_creation_fee(&self) -> Credits;
+
+    /// Returns the additional fee in credits charged for replacing a document
+    fn document_replace_fee(&self) -> Credits;
+
+    /// Returns the identity receiving document fees, if it's not the contract owner
+    fn document_fee_beneficiary(&self) -> Option<Identifier>;
 }
@@ -11,23 +11,24 @@ use crate::consensus::basic::data_contract::{
 };
 use crate::consensus::ConsensusError;
 use crate::data_contract::document_type::array::ArrayItemType;
-use crate::data_contract::document_type::index::Index;
+use crate::data_contract::document_type::index::{Index, IndexPropertyNormalization};
 use crate::data_contract::document_type::index_level::IndexLevel;
 use crate::data_contract::document_type::property::{DocumentProperty, DocumentPropertyType};
 #[cfg(feature = "validation")]
 use crate::data_contract::document_type::schema::{
-    byte_array_has_no_items_as_parent_validator, pattern_is_valid_regex_validator,
-    traversal_validator, validate_max_depth,
+    byte_array_has_no_items_as_parent_validator, custom_keywords_validator,
+    pattern_is_valid_regex_validator, traversal_validator, validate_max_depth,
 };
 
 use crate::data_contract::document_type::schema::enrich_with_base_schema;
 use crate::data_contract::document_type::{property_names, DocumentType};
 use crate::data_contract::errors::{DataContractError, StructureError};
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::fee::Credits;
 use crate::identity::SecurityLevel;
 use crate::util::json_schema::resolve_uri;
 #[cfg(feature = "validation")]
-use crate::validation::meta_validators::DOCUMENT_META_SCHEMA_V0;
+use crate::validation::meta_validators::document_meta_schema;
 use crate::version::PlatformVersion;
 use crate::ProtocolError;
 use platform_value::btreemap_extensions::BTreeValueMapHelper;
@@ -74,7 +75,7 @@ impl DocumentTypeV0 {
             json_schema_validator.compile(&root_json_schema, platform_version)?;
 
             // Validate against JSON Schema
-            DOCUMENT_META_SCHEMA_V0
+            document_meta_schema(platform_version)?
                 .validate(
                     &root_schema
                         .try_to_validating_json()
@@ -93,12 +94,13 @@ impl DocumentTypeV0 {
 
             // TODO: Are we still aiming to use RE2 with linear time complexity to protect from ReDoS attacks?
             //  If not we can remove this validation
-            // Validate reg exp compatibility with RE2 and byteArray usage
+            // Validate reg exp compatibility with RE2, byteArray and custom keywords usage
             result.merge(traversal_validator(
                 &root_schema,
                 &[
                     pattern_is_valid_regex_validator,
                     byte_array_has_no_items_as_parent_validator,
+                    custom_keywords_validator,
                 ],
                 platform_version,
             )?);
@@ -173,6 +175,12 @@ impl DocumentTypeV0 {
 
         let mut index_names: HashSet<String> = HashSet::new();
         let mut unique_indices_count = 0;
+        let mut contested_indices_count = 0;
+        // Index keys are normalized by property, so every index of a property must use the same options
+        let mut indexed_properties_options: BTreeMap<
+            String,
+            (bool, Option<IndexPropertyNormalization>),
+        > = BTreeMap::new();
 
         let indices: Vec<Index> = index_values
             .map(|index_values| {
@@ -206,6 +214,19 @@ impl DocumentTypeV0 {
                                 }
                             }
 
+                            // Votes resolve a single contested value per document type
+                            if index.contested {
+                                contested_indices_count += 1;
+                                if contested_indices_count > 1 {
+                                    return Err(ProtocolError::DataContractError(
+                                        DataContractError::InvalidContractStructure(format!(
+                                            "document type {} can only have one contested index",
+                                            name
+                                        )),
+                                    ));
+                                }
+                            }
+
                             // Index names must be unique for the document type
                             if !index_names.insert(index.name.to_owned()) {
                                 return Err(ProtocolError::ConsensusError(Box::new(
@@ -216,6 +237,44 @@ impl DocumentTypeV0 {
 
                             // Validate indexed properties
                             index.properties.iter().try_for_each(|index_property| {
+                                let options =
+                                    (index_property.case_insensitive, index_property.normalize);
+                                let (case_insensitive, normalize) = *indexed_properties_options
+                                    .entry(index_property.name.to_owned())
+                                    .or_insert(options);
+                                if (case_insensitive, normalize) != options {
+                                    let (constraint_name, reason) =
+                                        if case_insensitive != index_property.case_insensitive {
+                                            (
+                                                "caseInsensitive",
+                                                "should be the same in all indices of the property",
+                                            )
+                                        } else {
+                                            (
+                                                "normalize",
+                                                "should use the same normalization form in all indices of the property",
+                                            )
+                                        };
+                                    return Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexedPropertyConstraintError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                            constraint_name.to_string(),
+                                            reason.to_string(),
+                                        )
+                                        .into(),
+                                    )));
+                                }
+
+                                // String options are reported by the option which is set
+                                let normalization_constraint_name =
+                                    if index_property.case_insensitive {
+                                        "caseInsensitive"
+                                    } else {
+                                        "normalize"
+                                    };
+
                                 // Do not allow to index already indexed system properties
                                 if NOT_ALLOWED_SYSTEM_PROPERTIES
                                     .contains(&index_property.name.as_str())
@@ -245,6 +304,26 @@ impl DocumentTypeV0 {
                                             ))
                                         })?;
 
+                                    // Only strings can be stored in a normalized form
+                                    if index_property.is_normalized()
+                                        && !matches!(
+                                            property_definition.property_type,
+                                            DocumentPropertyType::String(..)
+                                        )
+                                    {
+                                        return Err(ProtocolError::ConsensusError(Box::new(
+                                            InvalidIndexedPropertyConstraintError::new(
+                                                name.to_owned(),
+                                                index.name.to_owned(),
+                                                index_property.name.to_owned(),
+                                                normalization_constraint_name.to_string(),
+                                                "should only be used with string properties"
+                                                    .to_string(),
+                                            )
+                                            .into(),
+                                        )));
+                                    }
+
                                     // Validate indexed property type
                                     match property_definition.property_type {
                                         // Array and objects aren't supported for indexing yet
@@ -303,6 +382,18 @@ impl DocumentTypeV0 {
                                         }
                                         _ => Ok(()),
                                     }
+                                } else if index_property.is_normalized() {
+                                    Err(ProtocolError::ConsensusError(Box::new(
+                                        InvalidIndexedPropertyConstraintError::new(
+                                            name.to_owned(),
+                                            index.name.to_owned(),
+                                            index_property.name.to_owned(),
+                                            normalization_constraint_name.to_string(),
+                                            "should only be used with string properties"
+                                                .to_string(),
+                                        )
+                                        .into(),
+                                    )))
                                 } else {
                                     Ok(())
                                 }
@@ -344,6 +435,18 @@ impl DocumentTypeV0 {
             .map(StorageKeyRequirements::try_from)
             .transpose()?;
 
+        // Fees paid to the contract owner or to the declared beneficiary on top of platform fees
+        let document_creation_fee = schema
+            .get_optional_integer::<Credits>(property_names::DOCUMENT_CREATION_FEE)?
+            .unwrap_or_default();
+
+        let document_replace_fee = schema
+            .get_optional_integer::<Credits>(property_names::DOCUMENT_REPLACE_FEE)?
+            .unwrap_or_default();
+
+        let document_fee_beneficiary =
+            schema.get_optional_identifier(property_names::DOCUMENT_FEE_BENEFICIARY)?;
+
         Ok(DocumentTypeV0 {
             name: String::from(name),
             schema,
@@ -360,6 +463,9 @@ impl DocumentTypeV0 {
             requires_identity_encryption_bounded_key,
             requires_identity_decryption_bounded_key,
             security_level_requirement,
+            document_creation_fee,
+            document_replace_fee,
+            document_fee_beneficiary,
             #[cfg(feature = "validation")]
             json_schema_validator,
         })
@@ -16,6 +16,7 @@ use anyhow::anyhow;
 use platform_value::{Value, ValueMap};
 use rand::distributions::{Alphanumeric, DistString};
 use std::{collections::BTreeMap, convert::TryFrom};
+use unicode_normalization::UnicodeNormalization;
 
 pub mod random_index;
 
@@ -25,6 +26,9 @@ pub struct Index {
     pub name: String,
     pub properties: Vec<IndexProperty>,
     pub unique: bool,
+    /// Documents that would conflict in this unique index are held as contenders
+    /// and the winner is chosen by masternode votes
+    pub contested: bool,
 }
 
 impl Index {
@@ -41,7 +45,12 @@ impl Index {
             let Some(value2) = Value::get_optional_from_map(object2, property.name.as_str()) else {
                 return false;
             };
-            value1 == value2
+            match (value1, value2) {
+                (Value::Text(text1), Value::Text(text2)) if property.is_normalized() => {
+                    property.normalize_str(text1) == property.normalize_str(text2)
+                }
+                _ => value1 == value2,
+            }
         })
     }
     /// The field names of the index
@@ -53,10 +62,62 @@ impl Index {
     }
 }
 
+/// Unicode normalization form of string values stored in an index
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IndexPropertyNormalization {
+    /// Compatibility decomposition followed by canonical composition
+    Nfkc,
+}
+
+impl TryFrom<&str> for IndexPropertyNormalization {
+    type Error = ProtocolError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "NFKC" => Ok(Self::Nfkc),
+            normalization => Err(ProtocolError::Error(anyhow!(
+                "unsupported index property normalization: '{}'",
+                normalization
+            ))),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct IndexProperty {
     pub name: String,
     pub ascending: bool,
+    /// String values are lowercased before they are stored in the index,
+    /// so values that only differ in case conflict in unique indices
+    pub case_insensitive: bool,
+    /// String values are normalized before they are stored in the index
+    pub normalize: Option<IndexPropertyNormalization>,
+}
+
+impl IndexProperty {
+    /// Are string values stored in a different form than they were submitted in
+    pub fn is_normalized(&self) -> bool {
+        self.case_insensitive || self.normalize.is_some()
+    }
+
+    /// Returns the form of a string value stored in the index
+    pub fn normalize_str(&self, value: &str) -> String {
+        let mut normalized = match self.normalize {
+            Some(IndexPropertyNormalization::Nfkc) => value.nfkc().collect(),
+            None => value.to_string(),
+        };
+
+        if self.case_insensitive {
+            normalized = normalized.to_lowercase();
+
+            // Lowercasing can produce sequences that aren't normalized anymore
+            if self.normalize == Some(IndexPropertyNormalization::Nfkc) {
+                normalized = normalized.nfkc().collect();
+            }
+        }
+
+        normalized
+    }
 }
 
 impl TryFrom<BTreeMap<String, String>> for IndexProperty {
@@ -91,6 +152,8 @@ impl TryFrom<BTreeMap<String, String>> for IndexProperty {
         Ok(Self {
             name: raw_property.0,
             ascending,
+            case_insensitive: false,
+            normalize: None,
         })
     }
 }
@@ -184,6 +247,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
         // For properties, we iterate each and move it to IndexProperty
 
         let mut unique = false;
+        let mut contested = false;
         let mut name = None;
         let mut index_properties: Vec<IndexProperty> = Vec::new();
 
@@ -210,6 +274,15 @@ impl TryFrom<&[(Value, Value)]> for Index {
                         unique = value_value.as_bool().expect("confirmed as bool");
                     }
                 }
+                "contested" => {
+                    contested = value_value
+                        .as_bool()
+                        .ok_or(ProtocolError::DataContractError(
+                            DataContractError::ValueWrongType(
+                                "contested should be of type boolean",
+                            ),
+                        ))?;
+                }
                 "properties" => {
                     let properties =
                         value_value.as_array().ok_or(ProtocolError::StructureError(
@@ -236,6 +309,14 @@ impl TryFrom<&[(Value, Value)]> for Index {
             }
         }
 
+        if contested && !unique {
+            return Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(
+                    "only unique indices can be contested".to_string(),
+                ),
+            ));
+        }
+
         // if the index didn't have a name let's make one
         //todo: we should remove the name altogether
         let name = name.unwrap_or_else(|| Alphanumeric.sample_string(&mut rand::thread_rng(), 24));
@@ -244,6 +325,7 @@ impl TryFrom<&[(Value, Value)]> for Index {
             name,
             properties: index_properties,
             unique,
+            contested,
         })
     }
 }
@@ -260,18 +342,117 @@ impl IndexProperty {
             .ok_or(ProtocolError::DataContractError(
                 DataContractError::KeyWrongType("key should be of type string"),
             ))?;
-        let value = property
-            .1 // value
-            .as_text()
-            .ok_or(ProtocolError::DataContractError(
-                DataContractError::ValueWrongType("value should be of type string"),
-            ))?;
 
-        let ascending = value == "asc";
+        // The value is either the sort order or a map with the sort order and string options
+        let Some(options) = property.1.as_map() else {
+            let value = property
+                .1 // value
+                .as_text()
+                .ok_or(ProtocolError::DataContractError(
+                    DataContractError::ValueWrongType("value should be of type string or map"),
+                ))?;
+
+            return Ok(IndexProperty {
+                name: key.to_string(),
+                ascending: value == "asc",
+                case_insensitive: false,
+                normalize: None,
+            });
+        };
 
-        Ok(IndexProperty {
+        let mut index_property = IndexProperty {
             name: key.to_string(),
-            ascending,
-        })
+            ascending: true,
+            case_insensitive: false,
+            normalize: None,
+        };
+
+        for (option_key, option_value) in options {
+            match option_key.to_str().map_err(ProtocolError::ValueError)? {
+                "order" => {
+                    index_property.ascending = option_value.to_str()? == "asc";
+                }
+                "caseInsensitive" => {
+                    index_property.case_insensitive =
+                        option_value
+                            .as_bool()
+                            .ok_or(ProtocolError::DataContractError(
+                                DataContractError::ValueWrongType(
+                                    "caseInsensitive should be of type boolean",
+                                ),
+                            ))?;
+                }
+                "normalize" => {
+                    index_property.normalize = Some(option_value.to_str()?.try_into()?);
+                }
+                _ => {
+                    return Err(ProtocolError::StructureError(
+                        StructureError::ValueWrongType("unexpected index property option"),
+                    ))
+                }
+            }
+        }
+
+        Ok(index_property)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use platform_value::platform_value;
+
+    #[test]
+    fn should_parse_index_property_with_string_options() {
+        let value = platform_value!({
+            "label": {"order": "asc", "caseInsensitive": true, "normalize": "NFKC"}
+        });
+
+        let index_property =
+            IndexProperty::from_platform_value(value.as_map().expect("expected a map"))
+                .expect("expected to parse index property");
+
+        assert_eq!(
+            index_property,
+            IndexProperty {
+                name: "label".to_string(),
+                ascending: true,
+                case_insensitive: true,
+                normalize: Some(IndexPropertyNormalization::Nfkc),
+            }
+        );
+    }
+
+    #[test]
+    fn should_normalize_strings_only_differing_in_case_and_form_to_the_same_value() {
+        let index_property = IndexProperty {
+            name: "label".to_string(),
+            ascending: true,
+            case_insensitive: true,
+            normalize: Some(IndexPropertyNormalization::Nfkc),
+        };
+
+        assert_eq!(index_property.normalize_str("Ａｌｉｃｅ"), "alice");
+        assert_eq!(index_property.normalize_str("ALICE"), "alice");
+        // "e" followed by a combining acute accent composes into "é"
+        assert_eq!(index_property.normalize_str("Ame\u{301}lie"), "amélie");
+    }
+
+    #[test]
+    fn should_not_allow_contested_non_unique_index() {
+        let value = platform_value!({
+            "name": "label",
+            "properties": [{"label": "asc"}],
+            "contested": true,
+        });
+
+        let result = Index::try_from(value.as_map().expect("expected a map").as_slice());
+
+        assert!(matches!(
+            result,
+            Err(ProtocolError::DataContractError(
+                DataContractError::InvalidContractStructure(_)
+            ))
+        ));
     }
 }
@@ -28,6 +28,8 @@ impl Index {
                 .map(|field_name| IndexProperty {
                     name: field_name,
                     ascending: rng.gen(),
+                    case_insensitive: false,
+                    normalize: None,
                 })
                 .collect::<Vec<_>>();
 
@@ -52,6 +54,7 @@ impl Index {
             name: index_name,
             properties,
             unique,
+            contested: false,
         })
     }
 }
@@ -116,9 +116,10 @@ impl DocumentTypeV0Methods for DocumentTypeV0 {
             .serialize_value_for_key
         {
             0 => self.serialize_value_for_key_v0(key, value),
+            1 => self.serialize_value_for_key_v1(key, value),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "serialize_value_for_key".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
@@ -1 +1,2 @@
 mod v0;
+mod v1;
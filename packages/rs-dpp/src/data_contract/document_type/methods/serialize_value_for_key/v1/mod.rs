@@ -0,0 +1,64 @@
+use crate::data_contract::document_type::v0::{DocumentTypeV0, DEFAULT_HASH_SIZE, MAX_INDEX_SIZE};
+use crate::data_contract::document_type::DocumentPropertyType;
+use crate::data_contract::errors::DataContractError;
+use crate::ProtocolError;
+use itertools::Itertools;
+use platform_value::Value;
+
+impl DocumentTypeV0 {
+    pub(in crate::data_contract::document_type) fn serialize_value_for_key_v1(
+        &self,
+        key: &str,
+        value: &Value,
+    ) -> Result<Vec<u8>, ProtocolError> {
+        match key {
+            "$ownerId" | "$id" => {
+                let bytes = value
+                    .to_identifier_bytes()
+                    .map_err(ProtocolError::ValueError)?;
+                if bytes.len() != DEFAULT_HASH_SIZE {
+                    Err(ProtocolError::DataContractError(
+                        DataContractError::FieldRequirementUnmet(
+                            "expected system value to be 32 bytes long",
+                        ),
+                    ))
+                } else {
+                    Ok(bytes)
+                }
+            }
+            "$createdAt" | "$updatedAt" => DocumentPropertyType::encode_date_timestamp(
+                value.to_integer().map_err(ProtocolError::ValueError)?,
+            ),
+            _ => {
+                let property = self.flattened_properties.get(key).ok_or_else(|| {
+                    DataContractError::DocumentTypeFieldNotFound(format!("expected contract to have field: {key}, contract fields are {} on document type {}", self.flattened_properties.keys().join(" | "), self.name))
+                })?;
+                // Indices with string options store the normalized form of the value,
+                // all indices of a property are validated to use the same options
+                let normalized_value = self
+                    .indices
+                    .iter()
+                    .flat_map(|index| index.properties.iter())
+                    .find(|index_property| index_property.name == key)
+                    .filter(|index_property| index_property.is_normalized())
+                    .and_then(|index_property| {
+                        value
+                            .as_text()
+                            .map(|text| Value::Text(index_property.normalize_str(text)))
+                    });
+                let bytes = property
+                    .property_type
+                    .encode_value_for_tree_keys(normalized_value.as_ref().unwrap_or(value))?;
+                if bytes.len() > MAX_INDEX_SIZE {
+                    Err(ProtocolError::DataContractError(
+                        DataContractError::FieldRequirementUnmet(
+                            "value must be less than 256 bytes long",
+                        ),
+                    ))
+                } else {
+                    Ok(bytes)
+                }
+            }
+        }
+    }
+}
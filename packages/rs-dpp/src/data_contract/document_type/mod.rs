@@ -31,6 +31,9 @@ pub(self) mod property_names {
         "requiresIdentityEncryptionBoundedKey";
     pub const REQUIRES_IDENTITY_DECRYPTION_BOUNDED_KEY: &str =
         "requiresIdentityDecryptionBoundedKey";
+    pub const DOCUMENT_CREATION_FEE: &str = "documentCreationFee";
+    pub const DOCUMENT_REPLACE_FEE: &str = "documentReplaceFee";
+    pub const DOCUMENT_FEE_BENEFICIARY: &str = "documentFeeBeneficiary";
     pub const INDICES: &str = "indices";
     pub const PROPERTIES: &str = "properties";
     pub const REQUIRED: &str = "required";
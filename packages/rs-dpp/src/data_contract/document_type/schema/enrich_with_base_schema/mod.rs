@@ -1,4 +1,5 @@
 mod v0;
+mod v1;
 
 use crate::ProtocolError;
 use platform_value::Value;
@@ -17,9 +18,10 @@ pub fn enrich_with_base_schema(
         .enrich_with_base_schema
     {
         0 => v0::enrich_with_base_schema_v0(schema, schema_defs),
+        1 => v1::enrich_with_base_schema_v1(schema, schema_defs),
         version => Err(ProtocolError::UnknownVersionMismatch {
             method: "enrich_with_base_schema".to_string(),
-            known_versions: vec![0],
+            known_versions: vec![0, 1],
             received: version,
         }),
     }
@@ -0,0 +1,26 @@
+use crate::data_contract::document_type::schema::enrich_with_base_schema::v0::{
+    enrich_with_base_schema_v0, PROPERTY_SCHEMA,
+};
+use crate::ProtocolError;
+use platform_value::{Value, ValueMapHelper};
+
+pub const DATA_CONTRACT_SCHEMA_URI_V1: &str =
+    "https://github.com/dashpay/platform/blob/master/packages/rs-dpp/schema/meta_schemas/document/v1/document-meta.json";
+
+/// The base schema is the same as in v0, only the meta schema it's validated against has changed
+pub fn enrich_with_base_schema_v1(
+    schema: Value,
+    schema_defs: Option<Value>,
+) -> Result<Value, ProtocolError> {
+    let mut root_schema = enrich_with_base_schema_v0(schema, schema_defs)?;
+
+    let schema_map = root_schema
+        .to_map_mut()
+        .map_err(ProtocolError::ValueError)?;
+
+    *schema_map
+        .get_key_mut(PROPERTY_SCHEMA)
+        .map_err(ProtocolError::ValueError)? = DATA_CONTRACT_SCHEMA_URI_V1.into();
+
+    Ok(root_schema)
+}
@@ -0,0 +1,31 @@
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_value::Value;
+use platform_version::version::PlatformVersion;
+
+mod v0;
+
+pub fn custom_keywords_validator(
+    path: &str,
+    key: &str,
+    parent: &Value,
+    value: &Value,
+    result: &mut SimpleConsensusValidationResult,
+    platform_version: &PlatformVersion,
+) -> Result<(), ProtocolError> {
+    match platform_version
+        .dpp
+        .contract_versions
+        .document_type_versions
+        .schema
+        .recursive_schema_validator_versions
+        .custom_keywords_validator
+    {
+        0 => v0::custom_keywords_validator_v0(path, key, parent, value, result, platform_version),
+        version => Err(ProtocolError::UnknownVersionMismatch {
+            method: "custom_keywords_validator".to_string(),
+            known_versions: vec![0],
+            received: version,
+        }),
+    }
+}
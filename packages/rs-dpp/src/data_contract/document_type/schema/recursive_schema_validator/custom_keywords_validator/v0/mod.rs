@@ -0,0 +1,20 @@
+use crate::validation::custom_keywords::custom_keyword;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use platform_value::Value;
+use platform_version::version::PlatformVersion;
+
+pub fn custom_keywords_validator_v0(
+    path: &str,
+    key: &str,
+    parent: &Value,
+    value: &Value,
+    result: &mut SimpleConsensusValidationResult,
+    platform_version: &PlatformVersion,
+) -> Result<(), ProtocolError> {
+    let Some((keyword, version)) = custom_keyword(key, platform_version) else {
+        return Ok(());
+    };
+
+    keyword.validate_definition(path, parent, value, result, version)
+}
@@ -1,5 +1,7 @@
 mod byte_array_has_no_items_as_parent_validator;
 pub use byte_array_has_no_items_as_parent_validator::*;
+mod custom_keywords_validator;
+pub use custom_keywords_validator::*;
 mod pattern_is_valid_regex_validator;
 pub use pattern_is_valid_regex_validator::*;
 mod traversal_validator;
@@ -193,6 +195,145 @@ mod test {
         }
     }
 
+    #[test]
+    fn should_return_invalid_result_for_too_long_pattern() {
+        let pattern = "a".repeat(257);
+        let schema: Value = platform_value!({
+            "type": "object",
+            "properties": {
+              "bar": {
+                "type": "string",
+                "pattern": pattern.clone(),
+                "maxLength": 300,
+              },
+            },
+            "additionalProperties": false,
+        });
+        let result = traversal_validator(
+            &schema,
+            &[pattern_is_valid_regex_validator],
+            PlatformVersion::latest(),
+        )
+        .expect("expected traversal validator to succeed");
+
+        match result.errors.get(0).expect("the error should be returned") {
+            ConsensusError::BasicError(BasicError::IncompatibleRe2PatternError(err)) => {
+                assert_eq!(err.path(), "/properties/bar".to_string());
+                assert_eq!(err.pattern(), pattern);
+            }
+            _ => panic!("Expected error to be IncompatibleRe2PatternError"),
+        }
+    }
+
+    #[test]
+    fn should_return_invalid_result_for_deeply_nested_pattern() {
+        let pattern = format!("{}a{}", "(".repeat(20), ")*".repeat(20));
+        let schema: Value = platform_value!({
+            "type": "object",
+            "properties": {
+              "bar": {
+                "type": "string",
+                "pattern": pattern,
+                "maxLength": 100,
+              },
+            },
+            "additionalProperties": false,
+        });
+        let result = traversal_validator(
+            &schema,
+            &[pattern_is_valid_regex_validator],
+            PlatformVersion::latest(),
+        )
+        .expect("expected traversal validator to succeed");
+
+        assert!(matches!(
+            result.errors.get(0),
+            Some(ConsensusError::BasicError(
+                BasicError::IncompatibleRe2PatternError(_)
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_return_valid_result_for_transient_properties() {
+        let schema: Value = platform_value!({
+            "type": "object",
+            "properties": {
+              "label": { "type": "string" },
+              "salt": { "type": "string" },
+              "transient": { "type": "string" },
+            },
+            "transient": ["salt"],
+            "additionalProperties": false,
+        });
+
+        assert!(traversal_validator(
+            &schema,
+            &[custom_keywords_validator],
+            PlatformVersion::latest()
+        )
+        .expect("expected traversal validator to succeed")
+        .is_valid());
+    }
+
+    #[test]
+    fn should_return_invalid_result_for_undefined_transient_properties() {
+        let schema: Value = platform_value!({
+            "type": "object",
+            "properties": {
+              "label": { "type": "string" },
+            },
+            "transient": ["label", "salt", "label"],
+            "additionalProperties": false,
+        });
+        let mut result = traversal_validator(
+            &schema,
+            &[custom_keywords_validator],
+            PlatformVersion::latest(),
+        )
+        .expect("expected traversal validator to succeed");
+
+        assert_eq!(2, result.errors.len());
+
+        let duplicate_error = get_basic_error(result.errors.pop().unwrap());
+        let undefined_error = get_basic_error(result.errors.pop().unwrap());
+
+        assert!(matches!(
+            undefined_error,
+            BasicError::JsonSchemaCompilationError(msg) if msg.compilation_error() == "invalid path: '/transient': property 'salt' is not defined",
+        ));
+        assert!(matches!(
+            duplicate_error,
+            BasicError::JsonSchemaCompilationError(msg) if msg.compilation_error() == "invalid path: '/transient': property 'label' is listed twice",
+        ));
+    }
+
+    #[test]
+    fn should_ignore_transient_if_it_is_not_supported_by_platform_version() {
+        let mut platform_version = PlatformVersion::latest().clone();
+        platform_version
+            .dpp
+            .validation
+            .json_schema_validator
+            .custom_keywords
+            .transient = None;
+
+        let schema: Value = platform_value!({
+            "type": "object",
+            "properties": {
+              "label": { "type": "string" },
+            },
+            "transient": "salt",
+            "additionalProperties": false,
+        });
+
+        assert!(
+            traversal_validator(&schema, &[custom_keywords_validator], &platform_version)
+                .expect("expected traversal validator to succeed")
+                .is_valid()
+        );
+    }
+
     fn get_document_schema() -> Value {
         platform_value!({
             "properties": {
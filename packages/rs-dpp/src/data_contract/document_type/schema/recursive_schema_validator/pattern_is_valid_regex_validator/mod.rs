@@ -4,6 +4,7 @@ use platform_value::Value;
 use platform_version::version::PlatformVersion;
 
 mod v0;
+mod v1;
 
 pub fn pattern_is_valid_regex_validator(
     path: &str,
@@ -25,9 +26,13 @@ pub fn pattern_is_valid_regex_validator(
             v0::pattern_is_valid_regex_validator_v0(path, key, parent, value, result);
             Ok(())
         }
+        1 => {
+            v1::pattern_is_valid_regex_validator_v1(path, key, parent, value, result);
+            Ok(())
+        }
         version => Err(ProtocolError::UnknownVersionMismatch {
             method: "pattern_is_valid_regex_validator".to_string(),
-            known_versions: vec![0],
+            known_versions: vec![0, 1],
             received: version,
         }),
     }
@@ -0,0 +1,55 @@
+use crate::consensus::basic::data_contract::IncompatibleRe2PatternError;
+use crate::validation::SimpleConsensusValidationResult;
+use platform_value::Value;
+use regex::RegexBuilder;
+
+/// Patterns are matched on every document create and replace,
+/// so their complexity is limited to bound processing cost
+const MAX_PATTERN_LENGTH: usize = 256;
+/// Max size of a compiled pattern in bytes
+const MAX_COMPILED_PATTERN_SIZE: usize = 1024 * 1024;
+/// Max nesting depth of groups and repetitions
+const MAX_PATTERN_NESTING: u32 = 16;
+
+pub fn pattern_is_valid_regex_validator_v1(
+    path: &str,
+    key: &str,
+    _parent: &Value,
+    value: &Value,
+    result: &mut SimpleConsensusValidationResult,
+) {
+    if key == "pattern" {
+        if let Some(pattern) = value.as_str() {
+            if pattern.len() > MAX_PATTERN_LENGTH {
+                result.add_error(IncompatibleRe2PatternError::new(
+                    String::from(pattern),
+                    path.to_string(),
+                    format!(
+                        "pattern length {} exceeds the limit of {}",
+                        pattern.len(),
+                        MAX_PATTERN_LENGTH
+                    ),
+                ));
+                return;
+            }
+
+            if let Err(err) = RegexBuilder::new(pattern)
+                .size_limit(MAX_COMPILED_PATTERN_SIZE)
+                .nest_limit(MAX_PATTERN_NESTING)
+                .build()
+            {
+                result.add_error(IncompatibleRe2PatternError::new(
+                    String::from(pattern),
+                    path.to_string(),
+                    err.to_string(),
+                ));
+            }
+        } else {
+            result.add_error(IncompatibleRe2PatternError::new(
+                String::new(),
+                path.to_string(),
+                format!("{} is not a string", path),
+            ));
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::data_contract::document_type::v0::DocumentTypeV0;
 use platform_value::{Identifier, Value};
 
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::fee::Credits;
 use crate::identity::SecurityLevel;
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -74,4 +75,16 @@ impl DocumentTypeV0Getters for DocumentTypeV0 {
     fn security_level_requirement(&self) -> SecurityLevel {
         self.security_level_requirement
     }
+
+    fn document_creation_fee(&self) -> Credits {
+        self.document_creation_fee
+    }
+
+    fn document_replace_fee(&self) -> Credits {
+        self.document_replace_fee
+    }
+
+    fn document_fee_beneficiary(&self) -> Option<Identifier> {
+        self.document_fee_beneficiary
+    }
 }
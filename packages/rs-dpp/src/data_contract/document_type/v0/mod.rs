@@ -8,6 +8,7 @@ use crate::data_contract::storage_requirements::keys_for_document_type::StorageK
 #[cfg(feature = "validation")]
 pub(in crate::data_contract) use validator::StatelessJsonSchemaLazyValidator;
 
+use crate::fee::Credits;
 use crate::identity::SecurityLevel;
 use platform_value::{Identifier, Value};
 
@@ -60,6 +61,12 @@ pub struct DocumentTypeV0 {
     pub(in crate::data_contract) requires_identity_decryption_bounded_key:
         Option<StorageKeyRequirements>,
     pub(in crate::data_contract) security_level_requirement: SecurityLevel,
+    /// Additional fee charged for creating a document, paid to the fee beneficiary
+    pub(in crate::data_contract) document_creation_fee: Credits,
+    /// Additional fee charged for replacing a document, paid to the fee beneficiary
+    pub(in crate::data_contract) document_replace_fee: Credits,
+    /// The identity receiving document fees, the contract owner if not set
+    pub(in crate::data_contract) document_fee_beneficiary: Option<Identifier>,
     #[cfg(feature = "validation")]
     pub(in crate::data_contract) json_schema_validator: StatelessJsonSchemaLazyValidator,
 }
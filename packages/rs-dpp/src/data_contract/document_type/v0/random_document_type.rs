@@ -256,6 +256,9 @@ impl DocumentTypeV0 {
             requires_identity_encryption_bounded_key: None,
             requires_identity_decryption_bounded_key: None,
             security_level_requirement: SecurityLevel::HIGH,
+            document_creation_fee: 0,
+            document_replace_fee: 0,
+            document_fee_beneficiary: None,
             json_schema_validator: StatelessJsonSchemaLazyValidator::new(),
         })
     }
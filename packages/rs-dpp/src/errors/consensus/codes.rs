@@ -129,6 +129,8 @@ impl ErrorWithCode for SignatureError {
             Self::SignatureShouldNotBePresentError(_) => 2008,
             Self::BasicECDSAError(_) => 2009,
             Self::BasicBLSError(_) => 2010,
+            Self::PublicKeyContractBoundsNotMetError(_) => 2011,
+            Self::SponsorPublicKeyNotContractBoundError(_) => 2012,
         }
     }
 }
@@ -150,6 +152,7 @@ impl ErrorWithCode for StateError {
             #[cfg(feature = "validation")]
             Self::DataTriggerError(ref e) => e.code(),
             Self::DataContractConfigUpdateError { .. } => 4027,
+            Self::DataContractFeeBeneficiaryNotFoundError(_) => 4034,
 
             // Document
             Self::DocumentAlreadyPresentError { .. } => 4004,
@@ -174,6 +177,16 @@ impl ErrorWithCode for StateError {
             Self::MissingIdentityPublicKeyIdsError { .. } => 4024,
             Self::IdentityInsufficientBalanceError(_) => 4026,
             Self::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError(_) => 4027,
+            Self::SponsorshipSpendingLimitExceededError(_) => 4035,
+
+            // Voting
+            Self::MasternodeNotFoundError(_) => 4028,
+            Self::MasternodeVoterIdentityMismatchError(_) => 4029,
+            Self::ContestedResourceVotePollNotFoundError(_) => 4030,
+            Self::ContestedResourceVotePollEndedError(_) => 4031,
+            Self::ContestedResourceContenderNotFoundError(_) => 4032,
+            Self::ContestedResourceContenderAlreadyPresentError(_) => 4033,
+            Self::MasternodeVoteChangesExceededError(_) => 4036,
         }
     }
 }
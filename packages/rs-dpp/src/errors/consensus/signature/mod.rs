@@ -5,10 +5,12 @@ mod invalid_identity_public_key_type_error;
 mod invalid_signature_public_key_security_level_error;
 mod invalid_state_transition_signature_error;
 mod missing_public_key_error;
+mod public_key_contract_bounds_not_met_error;
 mod public_key_is_disabled_error;
 mod public_key_security_level_not_met_error;
 mod signature_error;
 mod signature_should_not_be_present_error;
+mod sponsor_public_key_not_contract_bound_error;
 mod wrong_public_key_purpose_error;
 
 pub use crate::consensus::signature::basic_bls_error::BasicBLSError;
@@ -18,8 +20,10 @@ pub use crate::consensus::signature::invalid_identity_public_key_type_error::Inv
 pub use crate::consensus::signature::invalid_signature_public_key_security_level_error::InvalidSignaturePublicKeySecurityLevelError;
 pub use crate::consensus::signature::invalid_state_transition_signature_error::InvalidStateTransitionSignatureError;
 pub use crate::consensus::signature::missing_public_key_error::MissingPublicKeyError;
+pub use crate::consensus::signature::public_key_contract_bounds_not_met_error::PublicKeyContractBoundsNotMetError;
 pub use crate::consensus::signature::public_key_is_disabled_error::PublicKeyIsDisabledError;
 pub use crate::consensus::signature::public_key_security_level_not_met_error::PublicKeySecurityLevelNotMetError;
 pub use crate::consensus::signature::signature_error::SignatureError;
 pub use crate::consensus::signature::signature_should_not_be_present_error::SignatureShouldNotBePresentError;
+pub use crate::consensus::signature::sponsor_public_key_not_contract_bound_error::SponsorPublicKeyNotContractBoundError;
 pub use crate::consensus::signature::wrong_public_key_purpose_error::WrongPublicKeyPurposeError;
@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::identity::KeyID;
+use crate::prelude::Identifier;
+
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity key {public_key_id} is bound to data contract {data_contract_id} and can only sign its documents")]
+#[platform_serialize(unversioned)]
+pub struct PublicKeyContractBoundsNotMetError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    public_key_id: KeyID,
+    data_contract_id: Identifier,
+}
+
+impl PublicKeyContractBoundsNotMetError {
+    pub fn new(public_key_id: KeyID, data_contract_id: Identifier) -> Self {
+        Self {
+            public_key_id,
+            data_contract_id,
+        }
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+}
+
+impl From<PublicKeyContractBoundsNotMetError> for ConsensusError {
+    fn from(err: PublicKeyContractBoundsNotMetError) -> Self {
+        Self::SignatureError(SignatureError::PublicKeyContractBoundsNotMetError(err))
+    }
+}
@@ -1,8 +1,9 @@
 use crate::consensus::signature::{
     BasicBLSError, BasicECDSAError, IdentityNotFoundError, InvalidIdentityPublicKeyTypeError,
     InvalidSignaturePublicKeySecurityLevelError, InvalidStateTransitionSignatureError,
-    MissingPublicKeyError, PublicKeyIsDisabledError, PublicKeySecurityLevelNotMetError,
-    SignatureShouldNotBePresentError, WrongPublicKeyPurposeError,
+    MissingPublicKeyError, PublicKeyContractBoundsNotMetError, PublicKeyIsDisabledError,
+    PublicKeySecurityLevelNotMetError, SignatureShouldNotBePresentError,
+    SponsorPublicKeyNotContractBoundError, WrongPublicKeyPurposeError,
 };
 use crate::consensus::ConsensusError;
 use bincode::{Decode, Encode};
@@ -50,6 +51,12 @@ pub enum SignatureError {
 
     #[error(transparent)]
     BasicBLSError(BasicBLSError),
+
+    #[error(transparent)]
+    PublicKeyContractBoundsNotMetError(PublicKeyContractBoundsNotMetError),
+
+    #[error(transparent)]
+    SponsorPublicKeyNotContractBoundError(SponsorPublicKeyNotContractBoundError),
 }
 
 impl From<SignatureError> for ConsensusError {
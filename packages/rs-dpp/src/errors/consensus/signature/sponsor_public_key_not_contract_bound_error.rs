@@ -0,0 +1,49 @@
+use thiserror::Error;
+
+use crate::consensus::signature::signature_error::SignatureError;
+use crate::consensus::ConsensusError;
+use crate::identity::KeyID;
+use crate::prelude::Identifier;
+
+use crate::errors::ProtocolError;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Sponsor {sponsor_id} must sign with a key bound to the data contract of the sponsored documents, key {public_key_id} has no contract bounds")]
+#[platform_serialize(unversioned)]
+pub struct SponsorPublicKeyNotContractBoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    sponsor_id: Identifier,
+    public_key_id: KeyID,
+}
+
+impl SponsorPublicKeyNotContractBoundError {
+    pub fn new(sponsor_id: Identifier, public_key_id: KeyID) -> Self {
+        Self {
+            sponsor_id,
+            public_key_id,
+        }
+    }
+
+    pub fn sponsor_id(&self) -> &Identifier {
+        &self.sponsor_id
+    }
+
+    pub fn public_key_id(&self) -> KeyID {
+        self.public_key_id
+    }
+}
+
+impl From<SponsorPublicKeyNotContractBoundError> for ConsensusError {
+    fn from(err: SponsorPublicKeyNotContractBoundError) -> Self {
+        Self::SignatureError(SignatureError::SponsorPublicKeyNotContractBoundError(err))
+    }
+}
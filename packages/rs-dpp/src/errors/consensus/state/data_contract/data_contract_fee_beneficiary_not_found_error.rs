@@ -0,0 +1,55 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Document type {document_type_name} of the Data Contract {data_contract_id} declares fee beneficiary {beneficiary_id} which is not an existing identity")]
+#[platform_serialize(unversioned)]
+pub struct DataContractFeeBeneficiaryNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    data_contract_id: Identifier,
+    document_type_name: String,
+    beneficiary_id: Identifier,
+}
+
+impl DataContractFeeBeneficiaryNotFoundError {
+    pub fn new(
+        data_contract_id: Identifier,
+        document_type_name: String,
+        beneficiary_id: Identifier,
+    ) -> Self {
+        Self {
+            data_contract_id,
+            document_type_name,
+            beneficiary_id,
+        }
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn document_type_name(&self) -> &String {
+        &self.document_type_name
+    }
+
+    pub fn beneficiary_id(&self) -> &Identifier {
+        &self.beneficiary_id
+    }
+}
+
+impl From<DataContractFeeBeneficiaryNotFoundError> for ConsensusError {
+    fn from(err: DataContractFeeBeneficiaryNotFoundError) -> Self {
+        Self::StateError(StateError::DataContractFeeBeneficiaryNotFoundError(err))
+    }
+}
@@ -1,3 +1,4 @@
 pub mod data_contract_already_present_error;
 pub mod data_contract_config_update_error;
+pub mod data_contract_fee_beneficiary_not_found_error;
 pub mod data_contract_is_readonly_error;
@@ -13,3 +13,4 @@ pub mod invalid_identity_public_key_id_error;
 pub mod invalid_identity_revision_error;
 pub mod max_identity_public_key_limit_reached_error;
 pub mod missing_identity_public_key_ids_error;
+pub mod sponsorship_spending_limit_exceeded_error;
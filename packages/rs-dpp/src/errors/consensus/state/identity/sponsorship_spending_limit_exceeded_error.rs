@@ -0,0 +1,65 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use crate::fee::Credits;
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use thiserror::Error;
+
+use crate::prelude::Identifier;
+
+use bincode::{Decode, Encode};
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Sponsor {sponsor_id} can spend {remaining_spending_limit} more credits on data contract {data_contract_id}, required {required_amount}")]
+#[platform_serialize(unversioned)]
+pub struct SponsorshipSpendingLimitExceededError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    sponsor_id: Identifier,
+    data_contract_id: Identifier,
+    remaining_spending_limit: Credits,
+    required_amount: Credits,
+}
+
+impl SponsorshipSpendingLimitExceededError {
+    pub fn new(
+        sponsor_id: Identifier,
+        data_contract_id: Identifier,
+        remaining_spending_limit: Credits,
+        required_amount: Credits,
+    ) -> Self {
+        Self {
+            sponsor_id,
+            data_contract_id,
+            remaining_spending_limit,
+            required_amount,
+        }
+    }
+
+    pub fn sponsor_id(&self) -> &Identifier {
+        &self.sponsor_id
+    }
+
+    pub fn data_contract_id(&self) -> &Identifier {
+        &self.data_contract_id
+    }
+
+    pub fn remaining_spending_limit(&self) -> Credits {
+        self.remaining_spending_limit
+    }
+
+    pub fn required_amount(&self) -> Credits {
+        self.required_amount
+    }
+}
+
+impl From<SponsorshipSpendingLimitExceededError> for ConsensusError {
+    fn from(err: SponsorshipSpendingLimitExceededError) -> Self {
+        Self::StateError(StateError::SponsorshipSpendingLimitExceededError(err))
+    }
+}
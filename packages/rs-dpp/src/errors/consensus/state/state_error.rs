@@ -5,6 +5,7 @@ use thiserror::Error;
 
 use crate::consensus::state::data_contract::data_contract_already_present_error::DataContractAlreadyPresentError;
 use crate::consensus::state::data_contract::data_contract_config_update_error::DataContractConfigUpdateError;
+use crate::consensus::state::data_contract::data_contract_fee_beneficiary_not_found_error::DataContractFeeBeneficiaryNotFoundError;
 use crate::consensus::state::data_contract::data_contract_is_readonly_error::DataContractIsReadonlyError;
 #[cfg(feature = "state-transition-validation")]
 use crate::consensus::state::data_trigger::DataTriggerError;
@@ -24,10 +25,18 @@ use crate::consensus::state::identity::invalid_identity_public_key_id_error::Inv
 use crate::consensus::state::identity::invalid_identity_revision_error::InvalidIdentityRevisionError;
 use crate::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use crate::consensus::state::identity::missing_identity_public_key_ids_error::MissingIdentityPublicKeyIdsError;
+use crate::consensus::state::identity::sponsorship_spending_limit_exceeded_error::SponsorshipSpendingLimitExceededError;
 use crate::consensus::state::identity::{
     IdentityAlreadyExistsError, IdentityInsufficientBalanceError,
 };
 use crate::consensus::ConsensusError;
+use crate::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
+use crate::consensus::state::voting::masternode_voter_identity_mismatch_error::MasternodeVoterIdentityMismatchError;
+use crate::consensus::state::voting::masternode_vote_changes_exceeded_error::MasternodeVoteChangesExceededError;
+use crate::consensus::state::voting::contested_resource_vote_poll_not_found_error::ContestedResourceVotePollNotFoundError;
+use crate::consensus::state::voting::contested_resource_vote_poll_ended_error::ContestedResourceVotePollEndedError;
+use crate::consensus::state::voting::contested_resource_contender_not_found_error::ContestedResourceContenderNotFoundError;
+use crate::consensus::state::voting::contested_resource_contender_already_present_error::ContestedResourceContenderAlreadyPresentError;
 use crate::consensus::state::identity::identity_public_key_already_exists_for_unique_contract_bounds_error::IdentityPublicKeyAlreadyExistsForUniqueContractBoundsError;
 
 use super::document::document_timestamps_are_equal_error::DocumentTimestampsAreEqualError;
@@ -117,6 +126,33 @@ pub enum StateError {
 
     #[error(transparent)]
     DataContractConfigUpdateError(DataContractConfigUpdateError),
+
+    #[error(transparent)]
+    MasternodeNotFoundError(MasternodeNotFoundError),
+
+    #[error(transparent)]
+    MasternodeVoterIdentityMismatchError(MasternodeVoterIdentityMismatchError),
+
+    #[error(transparent)]
+    ContestedResourceVotePollNotFoundError(ContestedResourceVotePollNotFoundError),
+
+    #[error(transparent)]
+    ContestedResourceVotePollEndedError(ContestedResourceVotePollEndedError),
+
+    #[error(transparent)]
+    ContestedResourceContenderNotFoundError(ContestedResourceContenderNotFoundError),
+
+    #[error(transparent)]
+    ContestedResourceContenderAlreadyPresentError(ContestedResourceContenderAlreadyPresentError),
+
+    #[error(transparent)]
+    MasternodeVoteChangesExceededError(MasternodeVoteChangesExceededError),
+
+    #[error(transparent)]
+    DataContractFeeBeneficiaryNotFoundError(DataContractFeeBeneficiaryNotFoundError),
+
+    #[error(transparent)]
+    SponsorshipSpendingLimitExceededError(SponsorshipSpendingLimitExceededError),
 }
 
 impl From<StateError> for ConsensusError {
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {contender_id} is not a contender in contested resource vote poll {contest_id}")]
+#[platform_serialize(unversioned)]
+pub struct ContestedResourceContenderNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    contest_id: Identifier,
+    contender_id: Identifier,
+}
+
+impl ContestedResourceContenderNotFoundError {
+    pub fn new(contest_id: Identifier, contender_id: Identifier) -> Self {
+        Self {
+            contest_id,
+            contender_id,
+        }
+    }
+
+    pub fn contest_id(&self) -> &Identifier {
+        &self.contest_id
+    }
+
+    pub fn contender_id(&self) -> &Identifier {
+        &self.contender_id
+    }
+}
+
+impl From<ContestedResourceContenderNotFoundError> for ConsensusError {
+    fn from(err: ContestedResourceContenderNotFoundError) -> Self {
+        Self::StateError(StateError::ContestedResourceContenderNotFoundError(err))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Contested resource vote poll {contest_id} ended at {end_time_ms}")]
+#[platform_serialize(unversioned)]
+pub struct ContestedResourceVotePollEndedError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    contest_id: Identifier,
+    end_time_ms: u64,
+}
+
+impl ContestedResourceVotePollEndedError {
+    pub fn new(contest_id: Identifier, end_time_ms: u64) -> Self {
+        Self {
+            contest_id,
+            end_time_ms,
+        }
+    }
+
+    pub fn contest_id(&self) -> &Identifier {
+        &self.contest_id
+    }
+
+    pub fn end_time_ms(&self) -> u64 {
+        self.end_time_ms
+    }
+}
+
+impl From<ContestedResourceVotePollEndedError> for ConsensusError {
+    fn from(err: ContestedResourceVotePollEndedError) -> Self {
+        Self::StateError(StateError::ContestedResourceVotePollEndedError(err))
+    }
+}
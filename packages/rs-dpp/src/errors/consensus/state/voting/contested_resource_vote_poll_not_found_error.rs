@@ -0,0 +1,37 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Contested resource vote poll {contest_id} was not found")]
+#[platform_serialize(unversioned)]
+pub struct ContestedResourceVotePollNotFoundError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    contest_id: Identifier,
+}
+
+impl ContestedResourceVotePollNotFoundError {
+    pub fn new(contest_id: Identifier) -> Self {
+        Self { contest_id }
+    }
+
+    pub fn contest_id(&self) -> &Identifier {
+        &self.contest_id
+    }
+}
+
+impl From<ContestedResourceVotePollNotFoundError> for ConsensusError {
+    fn from(err: ContestedResourceVotePollNotFoundError) -> Self {
+        Self::StateError(StateError::ContestedResourceVotePollNotFoundError(err))
+    }
+}
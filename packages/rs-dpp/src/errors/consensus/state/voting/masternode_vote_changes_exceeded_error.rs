@@ -0,0 +1,51 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Masternode {pro_tx_hash} already changed its vote on contested resource vote poll {contest_id} {max_vote_changes} times")]
+#[platform_serialize(unversioned)]
+pub struct MasternodeVoteChangesExceededError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pro_tx_hash: Identifier,
+    contest_id: Identifier,
+    max_vote_changes: u16,
+}
+
+impl MasternodeVoteChangesExceededError {
+    pub fn new(pro_tx_hash: Identifier, contest_id: Identifier, max_vote_changes: u16) -> Self {
+        Self {
+            pro_tx_hash,
+            contest_id,
+            max_vote_changes,
+        }
+    }
+
+    pub fn pro_tx_hash(&self) -> &Identifier {
+        &self.pro_tx_hash
+    }
+
+    pub fn contest_id(&self) -> &Identifier {
+        &self.contest_id
+    }
+
+    pub fn max_vote_changes(&self) -> u16 {
+        self.max_vote_changes
+    }
+}
+
+impl From<MasternodeVoteChangesExceededError> for ConsensusError {
+    fn from(err: MasternodeVoteChangesExceededError) -> Self {
+        Self::StateError(StateError::MasternodeVoteChangesExceededError(err))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::consensus::state::state_error::StateError;
+use crate::consensus::ConsensusError;
+use crate::errors::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::Identifier;
+use thiserror::Error;
+
+#[derive(
+    Error, Debug, Clone, PartialEq, Eq, Encode, Decode, PlatformSerialize, PlatformDeserialize,
+)]
+#[error("Identity {voter_identity_id} is not the voter identity of masternode {pro_tx_hash}")]
+#[platform_serialize(unversioned)]
+pub struct MasternodeVoterIdentityMismatchError {
+    /*
+
+    DO NOT CHANGE ORDER OF FIELDS WITHOUT INTRODUCING OF NEW VERSION
+
+    */
+    pro_tx_hash: Identifier,
+    voter_identity_id: Identifier,
+}
+
+impl MasternodeVoterIdentityMismatchError {
+    pub fn new(pro_tx_hash: Identifier, voter_identity_id: Identifier) -> Self {
+        Self {
+            pro_tx_hash,
+            voter_identity_id,
+        }
+    }
+
+    pub fn pro_tx_hash(&self) -> &Identifier {
+        &self.pro_tx_hash
+    }
+
+    pub fn voter_identity_id(&self) -> &Identifier {
+        &self.voter_identity_id
+    }
+}
+
+impl From<MasternodeVoterIdentityMismatchError> for ConsensusError {
+    fn from(err: MasternodeVoterIdentityMismatchError) -> Self {
+        Self::StateError(StateError::MasternodeVoterIdentityMismatchError(err))
+    }
+}
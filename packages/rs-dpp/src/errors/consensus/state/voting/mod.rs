@@ -0,0 +1,7 @@
+pub mod contested_resource_contender_already_present_error;
+pub mod contested_resource_contender_not_found_error;
+pub mod contested_resource_vote_poll_ended_error;
+pub mod contested_resource_vote_poll_not_found_error;
+pub mod masternode_not_found_error;
+pub mod masternode_vote_changes_exceeded_error;
+pub mod masternode_voter_identity_mismatch_error;
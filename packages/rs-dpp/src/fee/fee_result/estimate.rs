@@ -0,0 +1,131 @@
+//! Fee estimate
+//!
+//! A breakdown of the fees a state transition would pay, calculated by
+//! dry running it against the current state without applying it.
+//!
+
+use crate::block::epoch::EpochIndex;
+use crate::fee::epoch::distribution::distribute_storage_fee_to_epochs_collection;
+use crate::fee::epoch::SignedCreditsPerEpoch;
+use crate::fee::fee_result::{BalanceChange, FeeResult};
+use crate::fee::{Credits, SignedCredits};
+use crate::ProtocolError;
+use platform_value::Identifier;
+use std::collections::BTreeMap;
+
+/// Fee estimate
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct FeeEstimate {
+    /// The estimated fee result
+    pub fee_result: FeeResult,
+    /// How the storage fee would be distributed among epochs, starting from the current one.
+    /// Rounding leftovers stay in the storage distribution pool and aren't included.
+    pub storage_fee_per_epoch: BTreeMap<EpochIndex, Credits>,
+    /// Credits that would be refunded for removed storage by identity
+    pub refunds_per_identity: BTreeMap<Identifier, Credits>,
+    /// The balance of the identity paying for the state transition before it's applied
+    pub balance: Option<Credits>,
+    /// The balance of the paying identity after the fees are paid and refunds are received,
+    /// negative if the identity can't afford the state transition
+    pub resulting_balance: Option<SignedCredits>,
+}
+
+impl FeeEstimate {
+    /// Creates a fee estimate from the fee result of a dry run, the paying identity with its
+    /// balance if there is one, and the current epoch
+    pub fn new(
+        fee_result: FeeResult,
+        payer: Option<(Identifier, Credits)>,
+        current_epoch_index: EpochIndex,
+    ) -> Result<Self, ProtocolError> {
+        let mut credits_per_epochs = SignedCreditsPerEpoch::default();
+
+        distribute_storage_fee_to_epochs_collection(
+            &mut credits_per_epochs,
+            fee_result.storage_fee,
+            current_epoch_index,
+        )?;
+
+        let storage_fee_per_epoch = credits_per_epochs
+            .into_iter()
+            .map(|(epoch_index, credits)| (epoch_index, credits.unsigned_abs()))
+            .collect();
+
+        let refunds_per_identity = fee_result
+            .fee_refunds
+            .iter()
+            .filter_map(|(identity_id, _)| {
+                let identity_id = Identifier::new(*identity_id);
+                fee_result
+                    .fee_refunds
+                    .calculate_refunds_amount_for_identity(identity_id)
+                    .map(|credits| (identity_id, credits))
+            })
+            .collect();
+
+        let (balance, resulting_balance) = match payer {
+            Some((identity_id, balance)) => {
+                let resulting_balance =
+                    match fee_result.clone().into_balance_change(identity_id).change() {
+                        BalanceChange::AddToBalance(credits) => balance as i128 + *credits as i128,
+                        BalanceChange::RemoveFromBalance {
+                            desired_removed_balance,
+                            ..
+                        } => balance as i128 - *desired_removed_balance as i128,
+                        BalanceChange::NoBalanceChange => balance as i128,
+                    };
+
+                let resulting_balance = SignedCredits::try_from(resulting_balance)
+                    .map_err(|_| ProtocolError::Overflow("resulting balance overflow"))?;
+
+                (Some(balance), Some(resulting_balance))
+            }
+            None => (None, None),
+        };
+
+        Ok(Self {
+            fee_result,
+            storage_fee_per_epoch,
+            refunds_per_identity,
+            balance,
+            resulting_balance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fee::fee_result::refunds::FeeRefunds;
+
+    #[test]
+    fn should_calculate_resulting_balance_and_refunds() {
+        let payer = Identifier::new([1; 32]);
+        let other = Identifier::new([2; 32]);
+
+        let fee_result = FeeResult {
+            storage_fee: 1_000_000,
+            processing_fee: 20_000,
+            fee_refunds: FeeRefunds(BTreeMap::from([
+                (payer.to_buffer(), [(0, 5_000)].into_iter().collect()),
+                (other.to_buffer(), [(0, 3_000)].into_iter().collect()),
+            ])),
+            ..Default::default()
+        };
+
+        let estimate = FeeEstimate::new(fee_result, Some((payer, 100_000)), 0)
+            .expect("expected to create fee estimate");
+
+        assert_eq!(estimate.balance, Some(100_000));
+        assert_eq!(
+            estimate.resulting_balance,
+            Some(100_000 - 1_020_000 + 5_000)
+        );
+        assert_eq!(
+            estimate.refunds_per_identity,
+            BTreeMap::from([(payer, 5_000), (other, 3_000)])
+        );
+        assert!(estimate.storage_fee_per_epoch.contains_key(&0));
+        assert!(estimate.storage_fee_per_epoch.values().sum::<Credits>() <= 1_000_000);
+    }
+}
@@ -47,6 +47,7 @@ use std::cmp::Ordering;
 use std::collections::BTreeMap;
 use std::convert::TryFrom;
 
+pub mod estimate;
 pub mod refunds;
 
 /// Fee Result
@@ -60,6 +61,9 @@ pub struct FeeResult {
     pub fee_refunds: FeeRefunds,
     /// Removed bytes not needing to be refunded to identities
     pub removed_bytes_from_system: u32,
+    /// Document fees declared by data contracts, paid to contract owners or their beneficiaries
+    /// instead of the epoch pools
+    pub document_type_fees: Credits,
 }
 
 impl TryFrom<Vec<FeeResult>> for FeeResult {
@@ -190,13 +194,29 @@ impl FeeResult {
             processing_fee: credits,
             fee_refunds: Default::default(),
             removed_bytes_from_system: 0,
+            document_type_fees: 0,
         }
     }
+
+    /// Convenience method to create a fee result from document fees declared by a data contract
+    pub fn new_from_document_type_fees(credits: Credits) -> Self {
+        Self {
+            document_type_fees: credits,
+            ..Default::default()
+        }
+    }
+
     /// Convenience method to get total fee
     pub fn total_base_fee(&self) -> Credits {
         self.storage_fee + self.processing_fee
     }
 
+    /// Convenience method to get the total amount of credits the identity is required to have,
+    /// including document fees paid to contract owners
+    pub fn total_required_balance(&self) -> Credits {
+        self.total_base_fee() + self.document_type_fees
+    }
+
     /// Convenience method to get required removed balance
     pub fn into_balance_change(self, identity_id: Identifier) -> BalanceChangeForIdentity {
         let storage_credits_returned = self
@@ -204,8 +224,10 @@ impl FeeResult {
             .calculate_refunds_amount_for_identity(identity_id)
             .unwrap_or_default();
 
-        let base_required_removed_balance = self.storage_fee;
-        let base_desired_removed_balance = self.storage_fee + self.processing_fee;
+        // Document fees are transferred to contract owners so they must be paid in full
+        let base_required_removed_balance = self.storage_fee + self.document_type_fees;
+        let base_desired_removed_balance =
+            self.storage_fee + self.processing_fee + self.document_type_fees;
 
         let balance_change = match storage_credits_returned.cmp(&base_desired_removed_balance) {
             Ordering::Less => {
@@ -266,6 +288,10 @@ impl FeeResult {
             .ok_or(ProtocolError::Overflow(
                 "removed_bytes_from_system overflow error",
             ))?;
+        self.document_type_fees = self
+            .document_type_fees
+            .checked_add(rhs.document_type_fees)
+            .ok_or(ProtocolError::Overflow("document type fees overflow error"))?;
         Ok(())
     }
 }
@@ -16,6 +16,7 @@ pub mod state_transition;
 mod credits_converter;
 pub mod errors;
 pub mod signer;
+pub mod sponsorship;
 
 pub mod accessors;
 pub(crate) mod conversion;
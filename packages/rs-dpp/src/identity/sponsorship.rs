@@ -0,0 +1,17 @@
+use crate::fee::Credits;
+use crate::identifier::Identifier;
+use bincode::{Decode, Encode};
+use serde::{Deserialize, Serialize};
+
+/// A cap on the credits an identity pays when sponsoring documents of a data contract.
+///
+/// Sponsors without a spending limit for a data contract pay for all documents signed with
+/// their keys bound to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Encode, Decode)]
+#[serde(rename_all = "camelCase")]
+pub struct SponsorshipSpendingLimit {
+    /// The data contract of the sponsored documents
+    pub data_contract_id: Identifier,
+    /// The total amount of credits the sponsor is willing to pay, `None` removes the limit
+    pub limit: Option<Credits>,
+}
@@ -43,6 +43,7 @@ pub mod serialization;
 pub mod signing;
 #[cfg(feature = "system_contracts")]
 pub mod system_data_contracts;
+pub mod voting;
 pub mod withdrawal;
 
 pub use async_trait;
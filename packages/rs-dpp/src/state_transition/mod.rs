@@ -74,7 +74,12 @@ use crate::state_transition::identity_topup_transition::{
 use crate::state_transition::identity_update_transition::{
     IdentityUpdateTransition, IdentityUpdateTransitionSignable,
 };
+use crate::state_transition::masternode_vote_transition::{
+    MasternodeVoteTransition, MasternodeVoteTransitionSignable,
+};
+use crate::state_transition::state_transitions::document::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV1;
 use crate::state_transition::state_transitions::document::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use crate::state_transition::state_transitions::document::documents_batch_transition::methods::v1::DocumentsBatchTransitionMethodsV1;
 
 pub type GetDataContractSecurityLevelRequirementFn =
     fn(Identifier, String) -> Result<SecurityLevel, ProtocolError>;
@@ -90,6 +95,7 @@ macro_rules! call_method {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::MasternodeVote(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -102,6 +108,7 @@ macro_rules! call_method {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::MasternodeVote(st) => st.$method(),
         }
     };
 }
@@ -117,6 +124,7 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => Some(st.$method($args)),
             StateTransition::IdentityUpdate(st) => Some(st.$method($args)),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method($args)),
+            StateTransition::MasternodeVote(st) => Some(st.$method($args)),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -129,6 +137,7 @@ macro_rules! call_getter_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => Some(st.$method()),
             StateTransition::IdentityUpdate(st) => Some(st.$method()),
             StateTransition::IdentityCreditTransfer(st) => Some(st.$method()),
+            StateTransition::MasternodeVote(st) => Some(st.$method()),
         }
     };
 }
@@ -144,6 +153,7 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::MasternodeVote(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -156,6 +166,7 @@ macro_rules! call_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::MasternodeVote(st) => st.$method(),
         }
     };
 }
@@ -175,6 +186,7 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method($args),
             StateTransition::IdentityUpdate(st) => st.$method($args),
             StateTransition::IdentityCreditTransfer(st) => st.$method($args),
+            StateTransition::MasternodeVote(st) => st.$method($args),
         }
     };
     ($state_transition:expr, $method:ident ) => {
@@ -191,6 +203,7 @@ macro_rules! call_errorable_method_identity_signed {
             StateTransition::IdentityCreditWithdrawal(st) => st.$method(),
             StateTransition::IdentityUpdate(st) => st.$method(),
             StateTransition::IdentityCreditTransfer(st) => st.$method(),
+            StateTransition::MasternodeVote(st) => st.$method(),
         }
     };
 }
@@ -210,6 +223,7 @@ macro_rules! call_static_method {
             StateTransition::IdentityCreditTransfer(_) => {
                 IdentityCreditTransferTransition::$method()
             }
+            StateTransition::MasternodeVote(_) => MasternodeVoteTransition::$method(),
         }
     };
 }
@@ -241,6 +255,7 @@ pub enum StateTransition {
     IdentityCreditWithdrawal(IdentityCreditWithdrawalTransition),
     IdentityUpdate(IdentityUpdateTransition),
     IdentityCreditTransfer(IdentityCreditTransferTransition),
+    MasternodeVote(MasternodeVoteTransition),
 }
 
 impl StateTransition {
@@ -270,6 +285,11 @@ impl StateTransition {
         }
     }
 
+    /// returns the type of the state transition
+    pub fn state_transition_type(&self) -> StateTransitionType {
+        call_method!(self, state_transition_type)
+    }
+
     /// returns the signature as a byte-array
     pub fn signature(&self) -> &BinaryData {
         call_method!(self, signature)
@@ -358,6 +378,10 @@ impl StateTransition {
                 st.verify_public_key_level_and_purpose(identity_public_key)?;
                 st.verify_public_key_is_enabled(identity_public_key)?;
             }
+            StateTransition::MasternodeVote(st) => {
+                st.verify_public_key_level_and_purpose(identity_public_key)?;
+                st.verify_public_key_is_enabled(identity_public_key)?;
+            }
             StateTransition::IdentityCreate(_) => {
                 return Err(ProtocolError::CorruptedCodeExecution(
                     "identity create can not be called for identity signing".to_string(),
@@ -375,6 +399,46 @@ impl StateTransition {
         Ok(())
     }
 
+    #[cfg(feature = "state-transition-signing")]
+    /// Co-signs a sponsored documents batch as its sponsor.
+    ///
+    /// The sponsor and its key are part of the signable bytes, they must be set before
+    /// the owner signs the batch.
+    pub fn sign_as_sponsor_external<S: Signer>(
+        &mut self,
+        identity_public_key: &IdentityPublicKey,
+        signer: &S,
+    ) -> Result<(), ProtocolError> {
+        if identity_public_key.purpose() != Purpose::AUTHENTICATION {
+            return Err(ProtocolError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(
+                    identity_public_key.purpose(),
+                    Purpose::AUTHENTICATION,
+                ),
+            ));
+        }
+        let data = self.signable_bytes()?;
+        let StateTransition::DocumentsBatch(documents_batch_transition) = self else {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "only documents batch transitions can be sponsored".to_string(),
+            ));
+        };
+        let Some(sponsor) = documents_batch_transition.sponsor() else {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "the sponsor must be set before signing as a sponsor".to_string(),
+            ));
+        };
+        if sponsor.signature_public_key_id != identity_public_key.id() {
+            return Err(ProtocolError::Generic(format!(
+                "the sponsor signs with key {}, got key {}",
+                sponsor.signature_public_key_id,
+                identity_public_key.id()
+            )));
+        }
+        documents_batch_transition
+            .set_sponsor_signature(signer.sign(identity_public_key, data.as_slice())?)
+    }
+
     #[cfg(feature = "state-transition-signing")]
     pub fn sign(
         &mut self,
@@ -538,6 +602,77 @@ impl StateTransition {
         }
     }
 
+    #[cfg(feature = "state-transition-validation")]
+    /// Verifies the signature of the identity sponsoring a documents batch,
+    /// the sponsor signs the same signable bytes as the owner
+    pub fn verify_sponsor_signature(
+        &self,
+        public_key: &IdentityPublicKey,
+        bls: &impl BlsModule,
+    ) -> Result<(), ProtocolError> {
+        let StateTransition::DocumentsBatch(documents_batch_transition) = self else {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "only documents batch transitions can be sponsored".to_string(),
+            ));
+        };
+        let Some(sponsor) = documents_batch_transition.sponsor() else {
+            return Err(ProtocolError::CorruptedCodeExecution(
+                "documents batch transition is not sponsored".to_string(),
+            ));
+        };
+
+        if public_key.disabled_at().is_some() {
+            return Err(ProtocolError::PublicKeyIsDisabledError(
+                PublicKeyIsDisabledError::new(public_key.id()),
+            ));
+        }
+
+        let Some(signature) = documents_batch_transition
+            .sponsor_signature()
+            .filter(|signature| !signature.is_empty())
+        else {
+            return Err(ProtocolError::StateTransitionIsNotSignedError(
+                StateTransitionIsNotSignedError::new(self.clone()),
+            ));
+        };
+
+        if sponsor.signature_public_key_id != public_key.id() {
+            return Err(ProtocolError::PublicKeyMismatchError(
+                PublicKeyMismatchError::new(public_key.clone()),
+            ));
+        }
+
+        let data = self.signable_bytes()?;
+        let public_key_bytes = public_key.data().as_slice();
+        match public_key.key_type() {
+            KeyType::ECDSA_SECP256K1 | KeyType::ECDSA_HASH160 => {
+                signer::verify_data_signature(&data, signature.as_slice(), public_key_bytes)
+                    .map_err(|_| {
+                        ProtocolError::from(ConsensusError::SignatureError(
+                            SignatureError::InvalidStateTransitionSignatureError(
+                                InvalidStateTransitionSignatureError::new(),
+                            ),
+                        ))
+                    })
+            }
+            KeyType::BLS12_381 => bls
+                .verify_signature(signature.as_slice(), &data, public_key_bytes)
+                .map(|_| ())
+                .map_err(|_| {
+                    ProtocolError::from(ConsensusError::SignatureError(
+                        SignatureError::InvalidStateTransitionSignatureError(
+                            InvalidStateTransitionSignatureError::new(),
+                        ),
+                    ))
+                }),
+            KeyType::BIP13_SCRIPT_HASH | KeyType::EDDSA_25519_HASH160 => {
+                Err(ProtocolError::InvalidIdentityPublicKeyTypeError(
+                    InvalidIdentityPublicKeyTypeError::new(public_key.key_type()),
+                ))
+            }
+        }
+    }
+
     #[cfg(all(feature = "state-transition-validation"))]
     fn verify_ecdsa_hash_160_signature_by_public_key_hash(
         &self,
@@ -30,7 +30,8 @@ mod tests {
     };
     use crate::state_transition::documents_batch_transition::document_transition::action_type::DocumentTransitionActionType;
     use crate::state_transition::documents_batch_transition::{
-        DocumentsBatchTransition, DocumentsBatchTransitionV0,
+        DocumentsBatchSponsor, DocumentsBatchTransition, DocumentsBatchTransitionV0,
+        DocumentsBatchTransitionV1,
     };
     use crate::state_transition::identity_create_transition::v0::IdentityCreateTransitionV0;
     use crate::state_transition::identity_create_transition::IdentityCreateTransition;
@@ -47,6 +48,7 @@ mod tests {
     use crate::version::PlatformVersion;
     use crate::withdrawal::Pooling;
     use crate::{NativeBlsModule, ProtocolError};
+    use platform_value::Identifier;
     use platform_version::version::LATEST_PLATFORM_VERSION;
     use platform_version::TryIntoPlatformVersioned;
     use rand::rngs::StdRng;
@@ -347,4 +349,50 @@ mod tests {
             .expect("expected to deserialize state transition");
         assert_eq!(state_transition, recovered_state_transition);
     }
+
+    #[test]
+    fn sponsored_document_batch_transition_ser_de() {
+        let platform_version = PlatformVersion::latest();
+        let data_contract = get_data_contract_fixture(None, platform_version.protocol_version)
+            .data_contract_owned();
+        let documents = get_extended_documents_fixture_with_owner_id_from_contract(
+            &data_contract,
+            platform_version.protocol_version,
+        )
+        .unwrap();
+        let documents = documents
+            .iter()
+            .map(|extended_document| {
+                let document = extended_document.document().clone();
+                let data_contract = extended_document.data_contract();
+                (
+                    document,
+                    data_contract
+                        .document_type_for_name(extended_document.document_type_name())
+                        .unwrap(),
+                    extended_document.entropy().clone(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let transitions =
+            get_document_transitions_fixture([(DocumentTransitionActionType::Create, documents)]);
+        let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV1 {
+            owner_id: data_contract.owner_id(),
+            transitions,
+            sponsor: DocumentsBatchSponsor {
+                sponsor_id: Identifier::new([1; 32]),
+                signature_public_key_id: 2,
+            },
+            sponsor_signature: vec![3; 65].into(),
+            ..Default::default()
+        }
+        .into();
+        let state_transition: StateTransition = documents_batch_transition.into();
+        let bytes = state_transition
+            .serialize_to_bytes()
+            .expect("expected to serialize");
+        let recovered_state_transition = StateTransition::deserialize_from_bytes(&bytes)
+            .expect("expected to deserialize state transition");
+        assert_eq!(state_transition, recovered_state_transition);
+    }
 }
@@ -1,19 +1,42 @@
 mod v0;
+mod v1;
 
 use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
-use crate::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use crate::state_transition::documents_batch_transition::{
+    DocumentsBatchSponsor, DocumentsBatchTransition,
+};
+use platform_value::BinaryData;
 pub use v0::*;
+pub use v1::*;
 
 impl DocumentsBatchTransitionAccessorsV0 for DocumentsBatchTransition {
     fn transitions(&self) -> &Vec<DocumentTransition> {
         match self {
             DocumentsBatchTransition::V0(v0) => &v0.transitions,
+            DocumentsBatchTransition::V1(v1) => &v1.transitions,
         }
     }
 
     fn transitions_slice(&self) -> &[DocumentTransition] {
         match self {
             DocumentsBatchTransition::V0(v0) => v0.transitions.as_slice(),
+            DocumentsBatchTransition::V1(v1) => v1.transitions.as_slice(),
+        }
+    }
+}
+
+impl DocumentsBatchTransitionAccessorsV1 for DocumentsBatchTransition {
+    fn sponsor(&self) -> Option<&DocumentsBatchSponsor> {
+        match self {
+            DocumentsBatchTransition::V0(_) => None,
+            DocumentsBatchTransition::V1(v1) => v1.sponsor(),
+        }
+    }
+
+    fn sponsor_signature(&self) -> Option<&BinaryData> {
+        match self {
+            DocumentsBatchTransition::V0(_) => None,
+            DocumentsBatchTransition::V1(v1) => v1.sponsor_signature(),
         }
     }
 }
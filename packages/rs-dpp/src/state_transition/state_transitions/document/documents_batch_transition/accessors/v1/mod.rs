@@ -0,0 +1,10 @@
+use crate::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use crate::state_transition::documents_batch_transition::DocumentsBatchSponsor;
+use platform_value::BinaryData;
+
+pub trait DocumentsBatchTransitionAccessorsV1: DocumentsBatchTransitionAccessorsV0 {
+    /// The identity paying the fees instead of the owner, if the batch is sponsored
+    fn sponsor(&self) -> Option<&DocumentsBatchSponsor>;
+    /// The signature of the sponsor, if the batch is sponsored
+    fn sponsor_signature(&self) -> Option<&BinaryData>;
+}
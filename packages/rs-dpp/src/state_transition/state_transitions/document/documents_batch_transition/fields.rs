@@ -19,6 +19,9 @@ pub mod property_names {
     pub const SECURITY_LEVEL_REQUIREMENT: &str = "signatureSecurityLevelRequirement";
     pub const CREATED_AT: &str = "$createdAt";
     pub const UPDATED_AT: &str = "$updatedAt";
+    pub const SPONSOR: &str = "sponsor";
+    pub const SPONSOR_ID: &str = "sponsor.sponsorId";
+    pub const SPONSOR_SIGNATURE: &str = "sponsorSignature";
 }
 
 pub const IDENTIFIER_FIELDS: [&str; 3] = [OWNER_ID, TRANSITIONS_ID, TRANSITIONS_DATA_CONTRACT_ID];
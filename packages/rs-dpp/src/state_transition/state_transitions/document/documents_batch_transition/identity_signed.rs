@@ -6,6 +6,7 @@ impl StateTransitionIdentitySigned for DocumentsBatchTransition {
     fn signature_public_key_id(&self) -> KeyID {
         match self {
             DocumentsBatchTransition::V0(transition) => transition.signature_public_key_id(),
+            DocumentsBatchTransition::V1(transition) => transition.signature_public_key_id(),
         }
     }
 
@@ -14,12 +15,16 @@ impl StateTransitionIdentitySigned for DocumentsBatchTransition {
             DocumentsBatchTransition::V0(transition) => {
                 transition.set_signature_public_key_id(key_id)
             }
+            DocumentsBatchTransition::V1(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
         }
     }
 
     fn security_level_requirement(&self) -> Vec<SecurityLevel> {
         match self {
             DocumentsBatchTransition::V0(transition) => transition.security_level_requirement(),
+            DocumentsBatchTransition::V1(transition) => transition.security_level_requirement(),
         }
     }
 }
@@ -4,19 +4,24 @@ use crate::identity::signer::Signer;
 use crate::identity::IdentityPublicKey;
 use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
 use crate::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use crate::state_transition::documents_batch_transition::methods::v1::DocumentsBatchTransitionMethodsV1;
 use crate::state_transition::documents_batch_transition::{
-    DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    DocumentsBatchSponsor, DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    DocumentsBatchTransitionV1,
 };
 use crate::state_transition::StateTransition;
 use crate::ProtocolError;
+use platform_value::BinaryData;
 use platform_version::version::{FeatureVersion, PlatformVersion};
 
 pub mod v0;
+pub mod v1;
 
 impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransition {
     fn set_transitions(&mut self, transitions: Vec<DocumentTransition>) {
         match self {
             DocumentsBatchTransition::V0(v0) => v0.set_transitions(transitions),
+            DocumentsBatchTransition::V1(v1) => v1.transitions = transitions,
         }
     }
 
@@ -100,3 +105,43 @@ impl DocumentsBatchTransitionMethodsV0 for DocumentsBatchTransition {
         }
     }
 }
+
+impl DocumentsBatchTransitionMethodsV1 for DocumentsBatchTransition {
+    fn set_sponsor(&mut self, sponsor: DocumentsBatchSponsor) {
+        match self {
+            DocumentsBatchTransition::V0(v0) => {
+                let DocumentsBatchTransitionV0 {
+                    owner_id,
+                    transitions,
+                    signature_public_key_id,
+                    signature,
+                } = std::mem::take(v0);
+                *self = DocumentsBatchTransitionV1 {
+                    owner_id,
+                    transitions,
+                    sponsor,
+                    signature_public_key_id,
+                    signature,
+                    sponsor_signature: Default::default(),
+                }
+                .into();
+            }
+            DocumentsBatchTransition::V1(v1) => v1.sponsor = sponsor,
+        }
+    }
+
+    fn set_sponsor_signature(
+        &mut self,
+        sponsor_signature: BinaryData,
+    ) -> Result<(), ProtocolError> {
+        match self {
+            DocumentsBatchTransition::V0(_) => Err(ProtocolError::CorruptedCodeExecution(
+                "the sponsor must be set before its signature".to_string(),
+            )),
+            DocumentsBatchTransition::V1(v1) => {
+                v1.sponsor_signature = sponsor_signature;
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV1;
+use crate::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use crate::state_transition::documents_batch_transition::DocumentsBatchSponsor;
+use crate::ProtocolError;
+use platform_value::BinaryData;
+
+pub trait DocumentsBatchTransitionMethodsV1:
+    DocumentsBatchTransitionMethodsV0 + DocumentsBatchTransitionAccessorsV1
+{
+    /// Sets the identity paying the fees instead of the owner, an unsponsored batch becomes a
+    /// sponsored one
+    fn set_sponsor(&mut self, sponsor: DocumentsBatchSponsor);
+
+    /// Sets the signature of the sponsor, the batch must be sponsored
+    fn set_sponsor_signature(&mut self, sponsor_signature: BinaryData)
+        -> Result<(), ProtocolError>;
+}
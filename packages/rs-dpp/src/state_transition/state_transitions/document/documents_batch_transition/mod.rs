@@ -27,8 +27,10 @@ mod identity_signed;
 #[cfg(feature = "state-transition-json-conversion")]
 mod json_conversion;
 pub mod methods;
+mod sponsor;
 mod state_transition_like;
 mod v0;
+mod v1;
 #[cfg(feature = "validation")]
 mod validation;
 #[cfg(feature = "state-transition-value-conversion")]
@@ -41,7 +43,9 @@ use crate::state_transition::data_contract_update_transition::{
 
 use crate::state_transition::documents_batch_transition::fields::property_names;
 
+pub use sponsor::DocumentsBatchSponsor;
 pub use v0::*;
+pub use v1::*;
 
 #[derive(
     Debug,
@@ -67,6 +71,8 @@ pub use v0::*;
 pub enum DocumentsBatchTransition {
     #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "0"))]
     V0(DocumentsBatchTransitionV0),
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(rename = "1"))]
+    V1(DocumentsBatchTransitionV1),
 }
 
 //
@@ -307,15 +313,19 @@ pub enum DocumentsBatchTransition {
 
 impl StateTransitionFieldTypes for DocumentsBatchTransition {
     fn binary_property_paths() -> Vec<&'static str> {
-        vec![SIGNATURE]
+        vec![SIGNATURE, property_names::SPONSOR_SIGNATURE]
     }
 
     fn identifiers_property_paths() -> Vec<&'static str> {
-        vec![property_names::OWNER_ID]
+        vec![property_names::OWNER_ID, property_names::SPONSOR_ID]
     }
 
     fn signature_property_paths() -> Vec<&'static str> {
-        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID]
+        vec![
+            SIGNATURE,
+            SIGNATURE_PUBLIC_KEY_ID,
+            property_names::SPONSOR_SIGNATURE,
+        ]
     }
     //
     // fn to_json(&self, skip_signature: bool) -> Result<JsonValue, ProtocolError> {
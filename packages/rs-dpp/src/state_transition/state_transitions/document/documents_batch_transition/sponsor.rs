@@ -0,0 +1,23 @@
+use bincode::{Decode, Encode};
+use platform_value::Identifier;
+#[cfg(feature = "state-transition-serde-conversion")]
+use serde::{Deserialize, Serialize};
+
+use crate::identity::KeyID;
+
+/// An identity paying the fees of a documents batch instead of its owner.
+///
+/// The sponsor co-signs the batch with a key bound to the data contract of its transitions,
+/// both the owner and the sponsor sign the same signable bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Encode, Decode)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct DocumentsBatchSponsor {
+    /// The identity paying the fees
+    pub sponsor_id: Identifier,
+    /// The id of the key the sponsor signs with
+    pub signature_public_key_id: KeyID,
+}
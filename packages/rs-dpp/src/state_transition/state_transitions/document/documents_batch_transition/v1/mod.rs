@@ -0,0 +1,41 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+mod v1_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use crate::identity::KeyID;
+
+use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
+use crate::state_transition::documents_batch_transition::DocumentsBatchSponsor;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+
+use platform_value::{BinaryData, Identifier};
+use serde::{Deserialize, Serialize};
+
+/// A documents batch whose fees are paid by a sponsor instead of its owner
+#[derive(Debug, Clone, PartialEq, Encode, Decode, PlatformSignable)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize)
+)]
+#[derive(Default)]
+pub struct DocumentsBatchTransitionV1 {
+    pub owner_id: Identifier,
+    pub transitions: Vec<DocumentTransition>,
+    /// The identity paying the fees instead of the owner
+    pub sponsor: DocumentsBatchSponsor,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+    /// The signature of the sponsor
+    #[platform_signable(exclude_from_sig_hash)]
+    pub sponsor_signature: BinaryData,
+}
@@ -0,0 +1,18 @@
+use crate::state_transition::documents_batch_transition::fields::property_names::*;
+use crate::state_transition::documents_batch_transition::fields::*;
+use crate::state_transition::documents_batch_transition::DocumentsBatchTransitionV1;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for DocumentsBatchTransitionV1 {
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SPONSOR_SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![OWNER_ID, SPONSOR_ID]
+    }
+
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, SPONSOR_SIGNATURE]
+    }
+}
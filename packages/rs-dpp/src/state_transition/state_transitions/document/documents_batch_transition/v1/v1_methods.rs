@@ -0,0 +1,28 @@
+use crate::state_transition::documents_batch_transition::accessors::{
+    DocumentsBatchTransitionAccessorsV0, DocumentsBatchTransitionAccessorsV1,
+};
+use crate::state_transition::documents_batch_transition::document_transition::DocumentTransition;
+use crate::state_transition::documents_batch_transition::{
+    DocumentsBatchSponsor, DocumentsBatchTransitionV1,
+};
+use platform_value::BinaryData;
+
+impl DocumentsBatchTransitionAccessorsV0 for DocumentsBatchTransitionV1 {
+    fn transitions(&self) -> &Vec<DocumentTransition> {
+        &self.transitions
+    }
+
+    fn transitions_slice(&self) -> &[DocumentTransition] {
+        self.transitions.as_slice()
+    }
+}
+
+impl DocumentsBatchTransitionAccessorsV1 for DocumentsBatchTransitionV1 {
+    fn sponsor(&self) -> Option<&DocumentsBatchSponsor> {
+        Some(&self.sponsor)
+    }
+
+    fn sponsor_signature(&self) -> Option<&BinaryData> {
+        Some(&self.sponsor_signature)
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::documents_batch_transition::DocumentsBatchTransitionV1;
+use crate::state_transition::StateTransitionValueConvert;
+
+impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransitionV1 {}
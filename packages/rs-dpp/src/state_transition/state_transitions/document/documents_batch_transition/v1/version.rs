@@ -0,0 +1,9 @@
+use crate::state_transition::documents_batch_transition::DocumentsBatchTransitionV1;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for DocumentsBatchTransitionV1 {
+    fn feature_version(&self) -> FeatureVersion {
+        1
+    }
+}
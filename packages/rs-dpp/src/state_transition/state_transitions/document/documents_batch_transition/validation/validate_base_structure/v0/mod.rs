@@ -10,6 +10,7 @@ use crate::state_transition::documents_batch_transition::document_transition::{
 };
 use crate::state_transition::documents_batch_transition::validation::find_duplicates_by_id::find_duplicates_by_id;
 use crate::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use crate::state_transition::FeatureVersioned;
 use crate::validation::SimpleConsensusValidationResult;
 use crate::ProtocolError;
 use platform_value::Identifier;
@@ -24,6 +25,14 @@ impl DocumentsBatchTransition {
         &self,
         platform_version: &PlatformVersion,
     ) -> Result<SimpleConsensusValidationResult, ProtocolError> {
+        // Sponsored batches are only accepted from the version they were introduced in
+        let result = platform_version
+            .validate_documents_batch_state_transition_version(self.feature_version());
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
+
         if self.transitions().len() > MAX_TRANSITIONS_IN_BATCH {
             return Ok(SimpleConsensusValidationResult::new_with_error(
                 MaxDocumentsTransitionsExceededError::new(MAX_TRANSITIONS_IN_BATCH as u32).into(),
@@ -5,7 +5,7 @@ use platform_value::Value;
 use crate::ProtocolError;
 
 use crate::state_transition::documents_batch_transition::{
-    DocumentsBatchTransition, DocumentsBatchTransitionV0,
+    DocumentsBatchTransition, DocumentsBatchTransitionV0, DocumentsBatchTransitionV1,
 };
 use crate::state_transition::state_transitions::documents_batch_transition::fields::*;
 use crate::state_transition::StateTransitionValueConvert;
@@ -21,6 +21,11 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            DocumentsBatchTransition::V1(transition) => {
+                let mut value = transition.to_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -31,6 +36,11 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            DocumentsBatchTransition::V1(transition) => {
+                let mut value = transition.to_canonical_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -41,6 +51,11 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            DocumentsBatchTransition::V1(transition) => {
+                let mut value = transition.to_canonical_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -51,6 +66,11 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
                 value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(0))?;
                 Ok(value)
             }
+            DocumentsBatchTransition::V1(transition) => {
+                let mut value = transition.to_cleaned_object(skip_signature)?;
+                value.insert(STATE_TRANSITION_PROTOCOL_VERSION.to_string(), Value::U16(1))?;
+                Ok(value)
+            }
         }
     }
 
@@ -71,6 +91,7 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
 
         match version {
             0 => Ok(DocumentsBatchTransitionV0::from_object(raw_object, platform_version)?.into()),
+            1 => Ok(DocumentsBatchTransitionV1::from_object(raw_object, platform_version)?.into()),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown DataContractCreateTransition version {n}"
             ))),
@@ -96,6 +117,9 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
             0 => Ok(
                 DocumentsBatchTransitionV0::from_value_map(raw_value_map, platform_version)?.into(),
             ),
+            1 => Ok(
+                DocumentsBatchTransitionV1::from_value_map(raw_value_map, platform_version)?.into(),
+            ),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown DataContractCreateTransition version {n}"
             ))),
@@ -109,6 +133,7 @@ impl<'a> StateTransitionValueConvert<'a> for DocumentsBatchTransition {
 
         match version {
             0 => DocumentsBatchTransitionV0::clean_value(value),
+            1 => DocumentsBatchTransitionV1::clean_value(value),
             n => Err(ProtocolError::UnknownVersionError(format!(
                 "Unknown DataContractCreateTransition version {n}"
             ))),
@@ -6,6 +6,7 @@ impl FeatureVersioned for DocumentsBatchTransition {
     fn feature_version(&self) -> FeatureVersion {
         match self {
             DocumentsBatchTransition::V0(v0) => v0.feature_version(),
+            DocumentsBatchTransition::V1(v1) => v1.feature_version(),
         }
     }
 }
@@ -1,34 +1,43 @@
 mod v0;
+mod v1;
 
+use crate::identity::sponsorship::SponsorshipSpendingLimit;
 use crate::identity::{KeyID, TimestampMillis};
 use crate::prelude::Revision;
+use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 use crate::state_transition::identity_update_transition::IdentityUpdateTransition;
 use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
 use platform_value::Identifier;
 pub use v0::*;
+pub use v1::*;
 
 impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
     fn set_identity_id(&mut self, id: Identifier) {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.set_identity_id(id),
+            IdentityUpdateTransition::V1(transition) => transition.set_identity_id(id),
         }
     }
 
     fn identity_id(&self) -> Identifier {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.identity_id(),
+            IdentityUpdateTransition::V1(transition) => transition.identity_id(),
         }
     }
 
     fn set_revision(&mut self, revision: Revision) {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.set_revision(revision),
+            IdentityUpdateTransition::V1(transition) => transition.set_revision(revision),
         }
     }
 
     fn revision(&self) -> Revision {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.revision(),
+            IdentityUpdateTransition::V1(transition) => transition.revision(),
         }
     }
 
@@ -37,18 +46,23 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_keys_to_add(add_public_keys)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_keys_to_add(add_public_keys)
+            }
         }
     }
 
     fn public_keys_to_add(&self) -> &[IdentityPublicKeyInCreation] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_to_add(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_to_add(),
         }
     }
 
     fn public_keys_to_add_mut(&mut self) -> &mut [IdentityPublicKeyInCreation] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_to_add_mut(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_to_add_mut(),
         }
     }
 
@@ -57,12 +71,16 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_key_ids_to_disable(disable_public_keys)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_key_ids_to_disable(disable_public_keys)
+            }
         }
     }
 
     fn public_key_ids_to_disable(&self) -> &[KeyID] {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_key_ids_to_disable(),
+            IdentityUpdateTransition::V1(transition) => transition.public_key_ids_to_disable(),
         }
     }
 
@@ -71,18 +89,65 @@ impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransition {
             IdentityUpdateTransition::V0(transition) => {
                 transition.set_public_keys_disabled_at(public_keys_disabled_at)
             }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_public_keys_disabled_at(public_keys_disabled_at)
+            }
         }
     }
 
     fn public_keys_disabled_at(&self) -> Option<TimestampMillis> {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.public_keys_disabled_at(),
+            IdentityUpdateTransition::V1(transition) => transition.public_keys_disabled_at(),
         }
     }
 
     fn owner_id(&self) -> Identifier {
         match self {
             IdentityUpdateTransition::V0(transition) => transition.owner_id(),
+            IdentityUpdateTransition::V1(transition) => transition.owner_id(),
+        }
+    }
+}
+
+impl IdentityUpdateTransitionAccessorsV1 for IdentityUpdateTransition {
+    fn set_sponsorship_spending_limits(
+        &mut self,
+        sponsorship_spending_limits: Vec<SponsorshipSpendingLimit>,
+    ) {
+        match self {
+            IdentityUpdateTransition::V0(transition) => {
+                let IdentityUpdateTransitionV0 {
+                    identity_id,
+                    revision,
+                    add_public_keys,
+                    disable_public_keys,
+                    public_keys_disabled_at,
+                    signature_public_key_id,
+                    signature,
+                } = std::mem::take(transition);
+                *self = IdentityUpdateTransitionV1 {
+                    identity_id,
+                    revision,
+                    add_public_keys,
+                    disable_public_keys,
+                    public_keys_disabled_at,
+                    sponsorship_spending_limits,
+                    signature_public_key_id,
+                    signature,
+                }
+                .into();
+            }
+            IdentityUpdateTransition::V1(transition) => {
+                transition.set_sponsorship_spending_limits(sponsorship_spending_limits)
+            }
+        }
+    }
+
+    fn sponsorship_spending_limits(&self) -> &[SponsorshipSpendingLimit] {
+        match self {
+            IdentityUpdateTransition::V0(_) => &[],
+            IdentityUpdateTransition::V1(transition) => transition.sponsorship_spending_limits(),
         }
     }
 }
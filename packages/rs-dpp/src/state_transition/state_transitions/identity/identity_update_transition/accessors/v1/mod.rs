@@ -0,0 +1,10 @@
+use crate::identity::sponsorship::SponsorshipSpendingLimit;
+use crate::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+
+pub trait IdentityUpdateTransitionAccessorsV1: IdentityUpdateTransitionAccessorsV0 {
+    fn set_sponsorship_spending_limits(
+        &mut self,
+        sponsorship_spending_limits: Vec<SponsorshipSpendingLimit>,
+    );
+    fn sponsorship_spending_limits(&self) -> &[SponsorshipSpendingLimit];
+}
@@ -15,6 +15,9 @@ pub mod property_names {
     pub const ADD_PUBLIC_KEYS: &str = "addPublicKeys";
     pub const DISABLE_PUBLIC_KEYS: &str = "disablePublicKeys";
     pub const PUBLIC_KEYS_DISABLED_AT: &str = "publicKeysDisabledAt";
+    pub const SPONSORSHIP_SPENDING_LIMITS: &str = "sponsorshipSpendingLimits";
+    pub const SPONSORSHIP_SPENDING_LIMITS_DATA_CONTRACT_ID: &str =
+        "sponsorshipSpendingLimits[].dataContractId";
 }
 
 pub const IDENTIFIER_FIELDS: [&str; 1] = [IDENTITY_ID];
@@ -0,0 +1,4 @@
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for IdentityUpdateTransitionV1 {}
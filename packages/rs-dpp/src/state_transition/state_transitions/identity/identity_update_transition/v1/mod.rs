@@ -0,0 +1,66 @@
+mod identity_signed;
+#[cfg(feature = "state-transition-json-conversion")]
+mod json_conversion;
+mod state_transition_like;
+mod types;
+pub(super) mod v1_methods;
+#[cfg(feature = "state-transition-value-conversion")]
+mod value_conversion;
+mod version;
+
+use bincode::{Decode, Encode};
+use platform_serialization_derive::PlatformSignable;
+use serde::{Deserialize, Serialize};
+
+use crate::identity::sponsorship::SponsorshipSpendingLimit;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreationSignable;
+use platform_value::BinaryData;
+
+use crate::{
+    identity::KeyID,
+    prelude::{Identifier, Revision, TimestampMillis},
+};
+
+#[derive(Encode, Decode, PlatformSignable, Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "state-transition-serde-conversion",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+// There is a problem deriving bincode for a borrowed vector
+// Hence we set to do it somewhat manually inside the PlatformSignable proc macro
+// Instead of inside of bincode_derive
+#[platform_signable(derive_bincode_with_borrowed_vec)]
+#[derive(Default)]
+pub struct IdentityUpdateTransitionV1 {
+    /// Unique identifier of the identity to be updated
+    pub identity_id: Identifier,
+
+    /// Identity Update revision number
+    pub revision: Revision,
+
+    /// Public Keys to add to the Identity
+    /// we want to skip serialization of transitions, as we does it manually in `to_object()`  and `to_json()`
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    #[platform_signable(into = "Vec<IdentityPublicKeyInCreationSignable>")]
+    pub add_public_keys: Vec<IdentityPublicKeyInCreation>,
+
+    /// Identity Public Keys ID's to disable for the Identity
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub disable_public_keys: Vec<KeyID>,
+
+    /// Timestamp when keys were disabled
+    pub public_keys_disabled_at: Option<TimestampMillis>,
+
+    /// Spending limits to set on documents the Identity sponsors, by data contract
+    #[cfg_attr(feature = "state-transition-serde-conversion", serde(default))]
+    pub sponsorship_spending_limits: Vec<SponsorshipSpendingLimit>,
+
+    /// The ID of the public key used to sing the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature_public_key_id: KeyID,
+    /// Cryptographic signature of the State Transition
+    #[platform_signable(exclude_from_sig_hash)]
+    pub signature: BinaryData,
+}
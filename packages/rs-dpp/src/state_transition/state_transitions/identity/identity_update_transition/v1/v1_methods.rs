@@ -0,0 +1,73 @@
+use crate::identity::sponsorship::SponsorshipSpendingLimit;
+use crate::state_transition::identity_update_transition::accessors::{
+    IdentityUpdateTransitionAccessorsV0, IdentityUpdateTransitionAccessorsV1,
+};
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+use crate::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use crate::{
+    identity::KeyID,
+    prelude::{Identifier, Revision, TimestampMillis},
+};
+
+impl IdentityUpdateTransitionAccessorsV0 for IdentityUpdateTransitionV1 {
+    fn set_identity_id(&mut self, id: Identifier) {
+        self.identity_id = id;
+    }
+
+    fn identity_id(&self) -> Identifier {
+        self.identity_id
+    }
+
+    fn set_revision(&mut self, revision: Revision) {
+        self.revision = revision;
+    }
+
+    fn revision(&self) -> Revision {
+        self.revision
+    }
+
+    fn set_public_keys_to_add(&mut self, add_public_keys: Vec<IdentityPublicKeyInCreation>) {
+        self.add_public_keys = add_public_keys;
+    }
+
+    fn public_keys_to_add(&self) -> &[IdentityPublicKeyInCreation] {
+        &self.add_public_keys
+    }
+
+    fn public_keys_to_add_mut(&mut self) -> &mut [IdentityPublicKeyInCreation] {
+        &mut self.add_public_keys
+    }
+
+    fn set_public_key_ids_to_disable(&mut self, disable_public_keys: Vec<KeyID>) {
+        self.disable_public_keys = disable_public_keys;
+    }
+
+    fn public_key_ids_to_disable(&self) -> &[KeyID] {
+        &self.disable_public_keys
+    }
+
+    fn set_public_keys_disabled_at(&mut self, public_keys_disabled_at: Option<TimestampMillis>) {
+        self.public_keys_disabled_at = public_keys_disabled_at;
+    }
+
+    fn public_keys_disabled_at(&self) -> Option<TimestampMillis> {
+        self.public_keys_disabled_at
+    }
+
+    fn owner_id(&self) -> Identifier {
+        self.identity_id
+    }
+}
+
+impl IdentityUpdateTransitionAccessorsV1 for IdentityUpdateTransitionV1 {
+    fn set_sponsorship_spending_limits(
+        &mut self,
+        sponsorship_spending_limits: Vec<SponsorshipSpendingLimit>,
+    ) {
+        self.sponsorship_spending_limits = sponsorship_spending_limits;
+    }
+
+    fn sponsorship_spending_limits(&self) -> &[SponsorshipSpendingLimit] {
+        &self.sponsorship_spending_limits
+    }
+}
@@ -0,0 +1,98 @@
+use platform_value::{ReplacementType, Value};
+
+use crate::ProtocolError;
+
+use crate::state_transition::identity_update_transition::fields::*;
+use crate::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+use crate::state_transition::StateTransitionValueConvert;
+
+use platform_version::version::PlatformVersion;
+
+impl IdentityUpdateTransitionV1 {
+    /// The fields shared with the first version, used to reuse its value conversion
+    fn to_v0(&self) -> IdentityUpdateTransitionV0 {
+        IdentityUpdateTransitionV0 {
+            identity_id: self.identity_id,
+            revision: self.revision,
+            add_public_keys: self.add_public_keys.clone(),
+            disable_public_keys: self.disable_public_keys.clone(),
+            public_keys_disabled_at: self.public_keys_disabled_at,
+            signature_public_key_id: self.signature_public_key_id,
+            signature: self.signature.clone(),
+        }
+    }
+
+    fn insert_sponsorship_spending_limits(&self, value: &mut Value) -> Result<(), ProtocolError> {
+        if !self.sponsorship_spending_limits.is_empty() {
+            value.insert(
+                property_names::SPONSORSHIP_SPENDING_LIMITS.to_owned(),
+                platform_value::to_value(&self.sponsorship_spending_limits)?,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> StateTransitionValueConvert<'a> for IdentityUpdateTransitionV1 {
+    fn from_object(
+        mut raw_object: Value,
+        platform_version: &PlatformVersion,
+    ) -> Result<Self, ProtocolError> {
+        let sponsorship_spending_limits = raw_object
+            .remove_optional_array(property_names::SPONSORSHIP_SPENDING_LIMITS)
+            .map_err(ProtocolError::ValueError)?
+            .unwrap_or_default()
+            .into_iter()
+            .map(platform_value::from_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ProtocolError::ValueError)?;
+
+        let IdentityUpdateTransitionV0 {
+            identity_id,
+            revision,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            signature_public_key_id,
+            signature,
+        } = IdentityUpdateTransitionV0::from_object(raw_object, platform_version)?;
+
+        Ok(IdentityUpdateTransitionV1 {
+            identity_id,
+            revision,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            sponsorship_spending_limits,
+            signature_public_key_id,
+            signature,
+        })
+    }
+
+    fn clean_value(value: &mut Value) -> Result<(), ProtocolError> {
+        IdentityUpdateTransitionV0::clean_value(value)?;
+        value.replace_at_paths(
+            [property_names::SPONSORSHIP_SPENDING_LIMITS_DATA_CONTRACT_ID],
+            ReplacementType::Identifier,
+        )?;
+        Ok(())
+    }
+
+    fn to_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        let mut value = self.to_v0().to_object(skip_signature)?;
+        self.insert_sponsorship_spending_limits(&mut value)?;
+        Ok(value)
+    }
+
+    fn to_cleaned_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        let mut value = self.to_v0().to_cleaned_object(skip_signature)?;
+        self.insert_sponsorship_spending_limits(&mut value)?;
+        Ok(value)
+    }
+
+    // Override to_canonical_cleaned_object to manage add_public_keys individually
+    fn to_canonical_cleaned_object(&self, skip_signature: bool) -> Result<Value, ProtocolError> {
+        self.to_cleaned_object(skip_signature)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
+use crate::state_transition::FeatureVersioned;
+use crate::version::FeatureVersion;
+
+impl FeatureVersioned for IdentityUpdateTransitionV1 {
+    fn feature_version(&self) -> FeatureVersion {
+        1
+    }
+}
@@ -0,0 +1,64 @@
+mod v0;
+
+use crate::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use crate::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+use platform_value::Identifier;
+pub use v0::*;
+
+impl MasternodeVoteTransitionAccessorsV0 for MasternodeVoteTransition {
+    fn pro_tx_hash(&self) -> Identifier {
+        match self {
+            MasternodeVoteTransition::V0(transition) => transition.pro_tx_hash,
+        }
+    }
+
+    fn set_pro_tx_hash(&mut self, pro_tx_hash: Identifier) {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.pro_tx_hash = pro_tx_hash;
+            }
+        }
+    }
+
+    fn voter_identity_id(&self) -> Identifier {
+        match self {
+            MasternodeVoteTransition::V0(transition) => transition.voter_identity_id,
+        }
+    }
+
+    fn set_voter_identity_id(&mut self, voter_identity_id: Identifier) {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.voter_identity_id = voter_identity_id;
+            }
+        }
+    }
+
+    fn vote_poll(&self) -> &ContestedDocumentResourceVotePoll {
+        match self {
+            MasternodeVoteTransition::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    fn set_vote_poll(&mut self, vote_poll: ContestedDocumentResourceVotePoll) {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.vote_poll = vote_poll;
+            }
+        }
+    }
+
+    fn choice(&self) -> ResourceVoteChoice {
+        match self {
+            MasternodeVoteTransition::V0(transition) => transition.choice,
+        }
+    }
+
+    fn set_choice(&mut self, choice: ResourceVoteChoice) {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.choice = choice;
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+use platform_value::Identifier;
+
+pub trait MasternodeVoteTransitionAccessorsV0 {
+    fn pro_tx_hash(&self) -> Identifier;
+    fn set_pro_tx_hash(&mut self, pro_tx_hash: Identifier);
+    fn voter_identity_id(&self) -> Identifier;
+    fn set_voter_identity_id(&mut self, voter_identity_id: Identifier);
+    fn vote_poll(&self) -> &ContestedDocumentResourceVotePoll;
+    fn set_vote_poll(&mut self, vote_poll: ContestedDocumentResourceVotePoll);
+    fn choice(&self) -> ResourceVoteChoice;
+    fn set_choice(&mut self, choice: ResourceVoteChoice);
+}
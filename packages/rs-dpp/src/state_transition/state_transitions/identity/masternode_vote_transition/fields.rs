@@ -0,0 +1,19 @@
+use crate::state_transition::state_transitions;
+
+use crate::state_transition::masternode_vote_transition::fields::property_names::{
+    PRO_TX_HASH, VOTER_IDENTITY_ID,
+};
+pub use state_transitions::common_fields::property_names::{
+    SIGNATURE, SIGNATURE_PUBLIC_KEY_ID, STATE_TRANSITION_PROTOCOL_VERSION, TRANSITION_TYPE,
+};
+
+pub(crate) mod property_names {
+    pub const PRO_TX_HASH: &str = "proTxHash";
+    pub const VOTER_IDENTITY_ID: &str = "voterIdentityId";
+}
+
+pub use property_names::VOTER_IDENTITY_ID;
+
+pub const IDENTIFIER_FIELDS: [&str; 2] = [PRO_TX_HASH, VOTER_IDENTITY_ID];
+pub const BINARY_FIELDS: [&str; 1] = [SIGNATURE];
+pub const U32_FIELDS: [&str; 1] = [STATE_TRANSITION_PROTOCOL_VERSION];
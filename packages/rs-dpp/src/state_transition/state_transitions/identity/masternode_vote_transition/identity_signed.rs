@@ -0,0 +1,50 @@
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::identity::IdentityPublicKey;
+use crate::identity::{KeyID, SecurityLevel};
+use crate::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use crate::state_transition::StateTransitionIdentitySigned;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::ProtocolError;
+
+impl StateTransitionIdentitySigned for MasternodeVoteTransition {
+    fn signature_public_key_id(&self) -> KeyID {
+        match self {
+            MasternodeVoteTransition::V0(transition) => transition.signature_public_key_id(),
+        }
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.set_signature_public_key_id(key_id)
+            }
+        }
+    }
+
+    #[cfg(any(
+        feature = "state-transition-signing",
+        feature = "state-transition-validation"
+    ))]
+    fn verify_public_key_level_and_purpose(
+        &self,
+        public_key: &IdentityPublicKey,
+    ) -> Result<(), ProtocolError> {
+        match self {
+            MasternodeVoteTransition::V0(transition) => {
+                transition.verify_public_key_level_and_purpose(public_key)
+            }
+        }
+    }
+
+    fn security_level_requirement(&self) -> Vec<SecurityLevel> {
+        match self {
+            MasternodeVoteTransition::V0(transition) => transition.security_level_requirement(),
+        }
+    }
+}
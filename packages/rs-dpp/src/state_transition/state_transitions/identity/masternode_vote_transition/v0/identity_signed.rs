@@ -0,0 +1,55 @@
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use crate::identity::SecurityLevel::HIGH;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::identity::{IdentityPublicKey, Purpose};
+use crate::identity::{KeyID, SecurityLevel};
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::state_transition::errors::WrongPublicKeyPurposeError;
+use crate::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+use crate::state_transition::StateTransitionIdentitySigned;
+#[cfg(any(
+    feature = "state-transition-signing",
+    feature = "state-transition-validation"
+))]
+use crate::ProtocolError;
+
+impl StateTransitionIdentitySigned for MasternodeVoteTransitionV0 {
+    fn signature_public_key_id(&self) -> KeyID {
+        self.signature_public_key_id
+    }
+
+    fn set_signature_public_key_id(&mut self, key_id: KeyID) {
+        self.signature_public_key_id = key_id
+    }
+
+    /// Votes can only be signed with the voting key of the masternode
+    #[cfg(any(
+        feature = "state-transition-signing",
+        feature = "state-transition-validation"
+    ))]
+    fn verify_public_key_level_and_purpose(
+        &self,
+        public_key: &IdentityPublicKey,
+    ) -> Result<(), ProtocolError> {
+        if public_key.purpose() != Purpose::VOTING {
+            return Err(ProtocolError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), Purpose::VOTING),
+            ));
+        }
+        Ok(())
+    }
+
+    fn security_level_requirement(&self) -> Vec<SecurityLevel> {
+        vec![HIGH]
+    }
+}
@@ -0,0 +1,4 @@
+use crate::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+use crate::state_transition::StateTransitionJsonConvert;
+
+impl<'a> StateTransitionJsonConvert<'a> for MasternodeVoteTransitionV0 {}
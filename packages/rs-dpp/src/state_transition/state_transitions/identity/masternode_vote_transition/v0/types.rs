@@ -0,0 +1,18 @@
+use crate::state_transition::masternode_vote_transition::fields::property_names::*;
+use crate::state_transition::masternode_vote_transition::fields::*;
+use crate::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+use crate::state_transition::StateTransitionFieldTypes;
+
+impl StateTransitionFieldTypes for MasternodeVoteTransitionV0 {
+    fn signature_property_paths() -> Vec<&'static str> {
+        vec![SIGNATURE]
+    }
+
+    fn identifiers_property_paths() -> Vec<&'static str> {
+        vec![PRO_TX_HASH, VOTER_IDENTITY_ID]
+    }
+
+    fn binary_property_paths() -> Vec<&'static str> {
+        vec![]
+    }
+}
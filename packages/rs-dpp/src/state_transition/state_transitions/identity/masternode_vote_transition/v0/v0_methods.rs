@@ -0,0 +1,4 @@
+use crate::state_transition::masternode_vote_transition::methods::MasternodeVoteTransitionMethodsV0;
+use crate::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+
+impl MasternodeVoteTransitionMethodsV0 for MasternodeVoteTransitionV0 {}
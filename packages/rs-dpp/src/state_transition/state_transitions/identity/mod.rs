@@ -4,4 +4,5 @@ pub mod identity_credit_transfer_transition;
 pub mod identity_credit_withdrawal_transition;
 pub mod identity_topup_transition;
 pub mod identity_update_transition;
+pub mod masternode_vote_transition;
 pub mod public_key_in_creation;
@@ -1,5 +1,6 @@
 use crate::data_contract::DataContractFactory;
 use crate::prelude::*;
+use crate::version::PlatformVersion;
 use crate::ProtocolError;
 use std::collections::{BTreeMap, BTreeSet};
 
@@ -9,6 +10,7 @@ pub use data_contracts::*;
 fn create_data_contract(
     factory: &DataContractFactory,
     system_contract: SystemDataContract,
+    platform_version: &PlatformVersion,
 ) -> Result<DataContract, ProtocolError> {
     let DataContractSource {
         id_bytes,
@@ -16,7 +18,7 @@ fn create_data_contract(
         definitions,
         document_schemas,
     } = system_contract
-        .source()
+        .source(platform_version)
         .map_err(|e| ProtocolError::Generic(e.to_string()))?;
 
     let id = Identifier::from(id_bytes);
@@ -38,21 +40,23 @@ pub fn load_system_data_contract(
     system_contract: SystemDataContract,
     protocol_version: u32,
 ) -> Result<DataContract, ProtocolError> {
+    let platform_version = PlatformVersion::get(protocol_version)?;
     let factory = DataContractFactory::new(protocol_version, None)?;
 
-    create_data_contract(&factory, system_contract)
+    create_data_contract(&factory, system_contract, platform_version)
 }
 
 pub fn load_system_data_contracts(
     system_contracts: BTreeSet<SystemDataContract>,
     protocol_version: u32,
 ) -> Result<BTreeMap<SystemDataContract, DataContract>, ProtocolError> {
+    let platform_version = PlatformVersion::get(protocol_version)?;
     let factory = DataContractFactory::new(protocol_version, None)?;
 
     system_contracts
         .into_iter()
         .map(|system_contract| {
-            let data_contract = create_data_contract(&factory, system_contract)?;
+            let data_contract = create_data_contract(&factory, system_contract, platform_version)?;
 
             Ok((system_contract, data_contract))
         })
@@ -4,6 +4,7 @@ use crate::{
 };
 
 use crate::data_contract::created_data_contract::CreatedDataContract;
+use crate::version::PlatformVersion;
 use data_contracts::SystemDataContract;
 
 pub fn get_dashpay_contract_fixture(
@@ -13,7 +14,7 @@ pub fn get_dashpay_contract_fixture(
     let factory =
         DataContractFactory::new(protocol_version, None).expect("expected to create factory");
     let dpns_schema = SystemDataContract::Dashpay
-        .source()
+        .source(PlatformVersion::get(protocol_version).expect("expected to get platform version"))
         .expect("DPNS contract must be defined")
         .document_schemas;
     let owner_id = owner_id.unwrap_or_else(generate_random_identifier_struct);
@@ -6,6 +6,7 @@ use crate::{
 use crate::data_contract::config::v0::DataContractConfigV0;
 use crate::data_contract::created_data_contract::CreatedDataContract;
 use crate::data_contract::storage_requirements::keys_for_document_type::StorageKeyRequirements;
+use crate::version::PlatformVersion;
 use data_contracts::SystemDataContract;
 
 pub fn get_dashpay_contract_with_generalized_encryption_key_fixture(
@@ -15,7 +16,7 @@ pub fn get_dashpay_contract_with_generalized_encryption_key_fixture(
     let factory =
         DataContractFactory::new(protocol_version, None).expect("expected to create factory");
     let dpns_schema = SystemDataContract::Dashpay
-        .source()
+        .source(PlatformVersion::get(protocol_version).expect("expected to get platform version"))
         .expect("DPNS contract must be defined")
         .document_schemas;
     let owner_id = owner_id.unwrap_or_else(generate_random_identifier_struct);
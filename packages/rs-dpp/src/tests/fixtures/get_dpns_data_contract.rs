@@ -6,6 +6,7 @@ use crate::data_contract::created_data_contract::CreatedDataContract;
 use crate::data_contract::DataContractFactory;
 use crate::prelude::*;
 use crate::tests::utils::generate_random_identifier_struct;
+use crate::version::PlatformVersion;
 
 pub fn get_dpns_data_contract_fixture(
     owner_id: Option<Identifier>,
@@ -20,7 +21,7 @@ pub fn get_dpns_data_contract_fixture(
         mut document_schemas,
         ..
     } = SystemDataContract::DPNS
-        .source()
+        .source(PlatformVersion::get(protocol_version).expect("expected to get platform version"))
         .expect("should return DPNS data contract source");
 
     let defs = platform_value!({
@@ -3,8 +3,12 @@ use std::collections::BTreeMap;
 use getrandom::getrandom;
 use platform_value::{Identifier, Value};
 
+use crate::data_contract::accessors::v0::DataContractV0Getters;
+use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use crate::data_contract::DataContract;
 use crate::document::document_factory::DocumentFactory;
 use crate::document::Document;
+use crate::system_data_contracts::dpns_contract::confusables::label_skeleton;
 use crate::tests::utils::generate_random_identifier_struct;
 
 use super::get_dpns_data_contract_fixture;
@@ -18,6 +22,14 @@ pub struct ParentDocumentOptions {
     pub owner_id: Identifier,
 }
 
+/// Label skeletons are only part of the DPNS contract since its second version
+fn defines_label_skeleton(data_contract: &DataContract) -> bool {
+    data_contract
+        .document_type_for_name("domain")
+        .map(|document_type| document_type.properties().contains_key("labelSkeleton"))
+        .unwrap_or_default()
+}
+
 impl Default for ParentDocumentOptions {
     fn default() -> Self {
         Self {
@@ -39,6 +51,12 @@ pub fn get_dpns_parent_document_fixture(
     let _ = getrandom(&mut pre_order_salt);
 
     let mut map = BTreeMap::new();
+    if defines_label_skeleton(data_contract.data_contract()) {
+        map.insert(
+            "labelSkeleton".to_string(),
+            Value::Text(label_skeleton(&options.label)),
+        );
+    }
     map.insert("label".to_string(), Value::Text(options.label));
     map.insert(
         "normalizedLabel".to_string(),
@@ -86,6 +104,12 @@ pub fn get_dpns_parent_extended_document_fixture(
     let _ = getrandom(&mut pre_order_salt);
 
     let mut map = BTreeMap::new();
+    if defines_label_skeleton(data_contract.data_contract()) {
+        map.insert(
+            "labelSkeleton".to_string(),
+            Value::Text(label_skeleton(&options.label)),
+        );
+    }
     map.insert("label".to_string(), Value::Text(options.label));
     map.insert(
         "normalizedLabel".to_string(),
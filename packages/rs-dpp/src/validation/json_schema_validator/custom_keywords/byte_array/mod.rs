@@ -0,0 +1,65 @@
+use crate::validation::json_schema_validator::custom_keywords::JsonSchemaCustomKeyword;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use jsonschema::KeywordDefinition;
+use platform_value::Value;
+use platform_version::version::{FeatureVersion, OptionalFeatureVersion, PlatformVersion};
+use serde_json::json;
+
+/// `byteArray` marks an array property as binary data, every item must be a byte
+pub struct ByteArrayKeyword;
+
+impl JsonSchemaCustomKeyword for ByteArrayKeyword {
+    fn name(&self) -> &'static str {
+        "byteArray"
+    }
+
+    fn version(&self, platform_version: &PlatformVersion) -> OptionalFeatureVersion {
+        platform_version
+            .dpp
+            .validation
+            .json_schema_validator
+            .custom_keywords
+            .byte_array
+    }
+
+    fn validate_definition(
+        &self,
+        _path: &str,
+        _parent: &Value,
+        _value: &Value,
+        _result: &mut SimpleConsensusValidationResult,
+        version: FeatureVersion,
+    ) -> Result<(), ProtocolError> {
+        match version {
+            // Usage is validated by the document meta schema
+            // and `byte_array_has_no_items_as_parent_validator`
+            0 => Ok(()),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "ByteArrayKeyword::validate_definition".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    fn data_definition(
+        &self,
+        version: FeatureVersion,
+    ) -> Result<Option<KeywordDefinition>, ProtocolError> {
+        match version {
+            0 => Ok(Some(KeywordDefinition::Schema(json!({
+                "items": {
+                    "type": "integer",
+                    "minimum": 0,
+                    "maximum": 255,
+                },
+            })))),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "ByteArrayKeyword::data_definition".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
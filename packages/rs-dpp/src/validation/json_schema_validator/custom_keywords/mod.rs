@@ -0,0 +1,64 @@
+mod byte_array;
+mod transient;
+
+pub use byte_array::ByteArrayKeyword;
+pub use transient::TransientKeyword;
+
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use jsonschema::KeywordDefinition;
+use platform_value::Value;
+use platform_version::version::{FeatureVersion, OptionalFeatureVersion, PlatformVersion};
+
+/// A platform specific JSON Schema keyword which can be used in document type schemas.
+///
+/// Keywords are versioned in `JsonSchemaCustomKeywordVersions`. Before the platform version
+/// which introduces a keyword it's unknown, and like any other unknown keyword it's ignored
+/// by the validator. To add a new keyword implement this trait, add its version to the
+/// platform version and register it in [`DOCUMENT_SCHEMA_CUSTOM_KEYWORDS`].
+pub trait JsonSchemaCustomKeyword: Sync {
+    /// Name of the keyword as it's used in a schema
+    fn name(&self) -> &'static str;
+
+    /// Version of the keyword, `None` if the keyword isn't supported by the platform version
+    fn version(&self, platform_version: &PlatformVersion) -> OptionalFeatureVersion;
+
+    /// Validates usage of the keyword in a document type schema.
+    /// `path` is the path of the `parent` sub schema and `value` is the keyword value.
+    fn validate_definition(
+        &self,
+        path: &str,
+        parent: &Value,
+        value: &Value,
+        result: &mut SimpleConsensusValidationResult,
+        version: FeatureVersion,
+    ) -> Result<(), ProtocolError>;
+
+    /// Keyword definition used to validate document data,
+    /// `None` if the keyword is an annotation and doesn't constrain data
+    fn data_definition(
+        &self,
+        version: FeatureVersion,
+    ) -> Result<Option<KeywordDefinition>, ProtocolError>;
+}
+
+/// Custom keywords known by the document schema validator
+pub const DOCUMENT_SCHEMA_CUSTOM_KEYWORDS: &[&dyn JsonSchemaCustomKeyword] =
+    &[&ByteArrayKeyword, &TransientKeyword];
+
+/// Custom keywords supported by the platform version along with their versions
+pub fn enabled_custom_keywords(
+    platform_version: &PlatformVersion,
+) -> impl Iterator<Item = (&'static dyn JsonSchemaCustomKeyword, FeatureVersion)> + '_ {
+    DOCUMENT_SCHEMA_CUSTOM_KEYWORDS
+        .iter()
+        .filter_map(|keyword| Some((*keyword, keyword.version(platform_version)?)))
+}
+
+/// Finds a custom keyword by name if it's supported by the platform version
+pub fn custom_keyword(
+    name: &str,
+    platform_version: &PlatformVersion,
+) -> Option<(&'static dyn JsonSchemaCustomKeyword, FeatureVersion)> {
+    enabled_custom_keywords(platform_version).find(|(keyword, _)| keyword.name() == name)
+}
@@ -0,0 +1,72 @@
+use crate::validation::json_schema_validator::custom_keywords::JsonSchemaCustomKeyword;
+use crate::validation::SimpleConsensusValidationResult;
+use crate::ProtocolError;
+use jsonschema::KeywordDefinition;
+use platform_value::Value;
+use platform_version::version::{FeatureVersion, OptionalFeatureVersion, PlatformVersion};
+
+mod v0;
+
+/// `transient` lists document properties which are only needed to validate a transition
+/// and aren't meant to be kept in state. It's defined on the document type level:
+///
+/// ```json
+/// {
+///   "type": "object",
+///   "properties": { "label": { "type": "string" }, "salt": { "type": "string" } },
+///   "transient": ["salt"],
+///   "additionalProperties": false
+/// }
+/// ```
+pub struct TransientKeyword;
+
+impl JsonSchemaCustomKeyword for TransientKeyword {
+    fn name(&self) -> &'static str {
+        "transient"
+    }
+
+    fn version(&self, platform_version: &PlatformVersion) -> OptionalFeatureVersion {
+        platform_version
+            .dpp
+            .validation
+            .json_schema_validator
+            .custom_keywords
+            .transient
+    }
+
+    fn validate_definition(
+        &self,
+        path: &str,
+        parent: &Value,
+        value: &Value,
+        result: &mut SimpleConsensusValidationResult,
+        version: FeatureVersion,
+    ) -> Result<(), ProtocolError> {
+        match version {
+            0 => {
+                v0::validate_transient_definition_v0(path, parent, value, result);
+                Ok(())
+            }
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TransientKeyword::validate_definition".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+
+    fn data_definition(
+        &self,
+        version: FeatureVersion,
+    ) -> Result<Option<KeywordDefinition>, ProtocolError> {
+        match version {
+            // Annotation only, it doesn't constrain document data
+            0 => Ok(None),
+            version => Err(ProtocolError::UnknownVersionMismatch {
+                method: "TransientKeyword::data_definition".to_string(),
+                known_versions: vec![0],
+                received: version,
+            }),
+        }
+    }
+}
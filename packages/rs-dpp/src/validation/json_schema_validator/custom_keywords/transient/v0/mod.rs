@@ -0,0 +1,65 @@
+use crate::consensus::basic::json_schema_compilation_error::JsonSchemaCompilationError;
+use crate::consensus::basic::BasicError;
+use crate::data_contract::document_type::property_names;
+use crate::validation::SimpleConsensusValidationResult;
+use platform_value::Value;
+use std::collections::BTreeSet;
+
+pub(super) fn validate_transient_definition_v0(
+    path: &str,
+    parent: &Value,
+    value: &Value,
+    result: &mut SimpleConsensusValidationResult,
+) {
+    // `transient` is only a keyword on the document type level,
+    // deeper it's a property name or is rejected by the meta schema
+    if !path.is_empty() {
+        return;
+    }
+
+    let mut add_error = |message: String| {
+        result.add_error(BasicError::JsonSchemaCompilationError(
+            JsonSchemaCompilationError::new(format!(
+                "invalid path: '{}/transient': {}",
+                path, message
+            )),
+        ));
+    };
+
+    let Some(property_names_to_check) = value.as_array() else {
+        add_error("transient must be an array of property names".to_string());
+        return;
+    };
+
+    let defined_properties = parent
+        .get_optional_value(property_names::PROPERTIES)
+        .ok()
+        .flatten()
+        .and_then(|properties| properties.as_map());
+
+    let mut seen = BTreeSet::new();
+
+    for property_name in property_names_to_check {
+        let Some(property_name) = property_name.as_str() else {
+            add_error("transient must be an array of property names".to_string());
+            continue;
+        };
+
+        if !seen.insert(property_name) {
+            add_error(format!("property '{}' is listed twice", property_name));
+            continue;
+        }
+
+        let is_defined = defined_properties
+            .map(|properties| {
+                properties
+                    .iter()
+                    .any(|(key, _)| key.as_str() == Some(property_name))
+            })
+            .unwrap_or(false);
+
+        if !is_defined {
+            add_error(format!("property '{}' is not defined", property_name));
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::ProtocolError;
 use platform_version::version::PlatformVersion;
 
 mod v0;
+mod v1;
 
 impl JsonSchemaValidator {
     pub fn compile(
@@ -18,9 +19,10 @@ impl JsonSchemaValidator {
             .compile
         {
             0 => self.compile_v0(json_schema),
+            1 => self.compile_v1(json_schema, platform_version),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "JsonSchemaLazyValidator.compile".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
@@ -33,10 +35,10 @@ impl JsonSchemaValidator {
             .json_schema_validator
             .compile
         {
-            0 => Ok(self.is_compiled_v0()),
+            0 | 1 => Ok(self.is_compiled_v0()),
             version => Err(ProtocolError::UnknownVersionMismatch {
                 method: "JsonSchemaLazyValidator.is_compiled".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             }),
         }
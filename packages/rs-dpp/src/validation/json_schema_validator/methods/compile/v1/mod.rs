@@ -0,0 +1,49 @@
+use crate::consensus::ConsensusError;
+use crate::data_contract::JsonValue;
+use crate::validation::custom_keywords::enabled_custom_keywords;
+use crate::validation::JsonSchemaValidator;
+use crate::ProtocolError;
+use jsonschema::JSONSchema;
+use platform_version::version::PlatformVersion;
+
+impl JsonSchemaValidator {
+    pub(super) fn compile_v1(
+        &self,
+        json_schema: &JsonValue,
+        platform_version: &PlatformVersion,
+    ) -> Result<bool, ProtocolError> {
+        if self.is_compiled_v0() {
+            return Ok(false);
+        }
+
+        let mut validator_guard = self.validator.write().unwrap();
+
+        // Check again to ensure no other thread has modified it after dropping the read lock
+        if validator_guard.is_some() {
+            return Ok(false);
+        }
+
+        let mut options = JSONSchema::options();
+
+        options
+            .with_meta_schemas()
+            .should_ignore_unknown_formats(false)
+            .should_validate_formats(true)
+            .with_draft(jsonschema::Draft::Draft202012);
+
+        // Keywords which aren't enabled for the platform version are ignored as unknown
+        for (keyword, version) in enabled_custom_keywords(platform_version) {
+            if let Some(definition) = keyword.data_definition(version)? {
+                options.add_keyword(keyword.name(), definition);
+            }
+        }
+
+        let validator = options.compile(json_schema).map_err(|error| {
+            ProtocolError::ConsensusError(Box::new(ConsensusError::from(error)))
+        })?;
+
+        *validator_guard = Some(validator);
+
+        Ok(true)
+    }
+}
@@ -1,3 +1,4 @@
+pub mod custom_keywords;
 pub mod methods;
 
 use crate::data_contract::JsonValue;
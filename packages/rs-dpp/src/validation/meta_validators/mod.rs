@@ -1,5 +1,7 @@
+use crate::ProtocolError;
 use jsonschema::{Draft, JSONSchema, KeywordDefinition};
 use lazy_static::lazy_static;
+use platform_version::version::PlatformVersion;
 use serde_json::{json, Value};
 
 lazy_static! {
@@ -39,6 +41,10 @@ lazy_static! {
         "../../../schema/meta_schemas/document/v0/document-meta.json"
     ))
     .unwrap();
+    static ref DATA_CONTRACT_V1: Value = serde_json::from_str::<Value>(include_str!(
+        "../../../schema/meta_schemas/document/v1/document-meta.json"
+    ))
+    .unwrap();
 
     pub static ref DRAFT_202012_META_SCHEMA: JSONSchema = JSONSchema::options()
         .with_draft(Draft::Draft202012)
@@ -133,4 +139,162 @@ lazy_static! {
         .to_owned()
         .compile(&DATA_CONTRACT_V0)
         .expect("Invalid data contract schema");
+
+    // Compiled version of data contract meta schema with conditional and unevaluated keywords
+    pub static ref DOCUMENT_META_SCHEMA_V1: JSONSchema = JSONSchema::options()
+        .add_keyword(
+                "byteArray",
+                KeywordDefinition::Schema(json!({
+                    "items": {
+                        "type": "integer",
+                        "minimum": 0,
+                        "maximum": 255,
+                    },
+                })),
+            )
+        .should_validate_formats(true)
+        .with_draft(Draft::Draft202012)
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/applicator".to_string(),
+            DRAFT202012_APPLICATOR.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/core".to_string(),
+            DRAFT202012_CORE.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/applicator".to_string(),
+            DRAFT202012_APPLICATOR.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/unevaluated".to_string(),
+            DRAFT202012_UNEVALUATED.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/validation".to_string(),
+            DRAFT202012_VALIDATION.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/meta-data".to_string(),
+            DRAFT202012_META_DATA.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/format-annotation".to_string(),
+            DRAFT202012_FORMAT_ANNOTATION.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/meta/content".to_string(),
+            DRAFT202012_CONTENT.clone(),
+        )
+        .with_document(
+            "https://json-schema.org/draft/2020-12/schema".to_string(),
+            DRAFT202012.clone(),
+        )
+        .to_owned()
+        .compile(&DATA_CONTRACT_V1)
+        .expect("Invalid data contract schema");
+}
+
+/// Returns the compiled meta schema which document type schemas are validated against
+pub fn document_meta_schema(
+    platform_version: &PlatformVersion,
+) -> Result<&'static JSONSchema, ProtocolError> {
+    match platform_version
+        .dpp
+        .contract_versions
+        .document_type_versions
+        .schema
+        .document_meta_schema
+    {
+        0 => Ok(&DOCUMENT_META_SCHEMA_V0),
+        1 => Ok(&DOCUMENT_META_SCHEMA_V1),
+        version => Err(ProtocolError::UnknownVersionMismatch {
+            method: "document_meta_schema".to_string(),
+            known_versions: vec![0, 1],
+            received: version,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_contract::document_type::schema::enrich_with_base_schema;
+    use platform_value::platform_value;
+
+    fn conditional_document_schema() -> platform_value::Value {
+        platform_value!({
+            "type": "object",
+            "properties": {
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "country": { "type": "string", "maxLength": 2 },
+                        "postalCode": {
+                            "type": "string",
+                            "pattern": "^[0-9]{5}$",
+                            "maxLength": 5
+                        },
+                        "state": { "type": "string", "maxLength": 2 }
+                    },
+                    "if": {
+                        "properties": { "country": { "const": "US" } }
+                    },
+                    "then": {
+                        "required": ["postalCode", "state"]
+                    },
+                    "else": {
+                        "required": ["postalCode"]
+                    },
+                    "dependentRequired": {
+                        "state": ["country"]
+                    },
+                    "additionalProperties": false
+                }
+            },
+            "unevaluatedProperties": false,
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn should_allow_conditional_and_unevaluated_keywords() {
+        let platform_version = PlatformVersion::latest();
+
+        let root_schema =
+            enrich_with_base_schema(conditional_document_schema(), None, platform_version)
+                .expect("expected to enrich schema")
+                .try_into_validating_json()
+                .expect("expected to convert to json");
+
+        let result = document_meta_schema(platform_version)
+            .expect("expected to get document meta schema")
+            .validate(&root_schema)
+            .map_err(|errors| errors.map(|error| error.to_string()).collect::<Vec<_>>());
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn should_not_allow_conditional_keywords_in_first_meta_schema() {
+        let mut platform_version = PlatformVersion::latest().clone();
+        let schema_versions = &mut platform_version
+            .dpp
+            .contract_versions
+            .document_type_versions
+            .schema;
+        schema_versions.enrich_with_base_schema = 0;
+        schema_versions.document_meta_schema = 0;
+
+        let root_schema =
+            enrich_with_base_schema(conditional_document_schema(), None, &platform_version)
+                .expect("expected to enrich schema")
+                .try_into_validating_json()
+                .expect("expected to convert to json");
+
+        assert!(document_meta_schema(&platform_version)
+            .expect("expected to get document meta schema")
+            .validate(&root_schema)
+            .is_err());
+    }
 }
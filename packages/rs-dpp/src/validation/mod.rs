@@ -1,4 +1,6 @@
 #[cfg(feature = "validation")]
+pub use json_schema_validator::custom_keywords;
+#[cfg(feature = "validation")]
 pub(crate) use json_schema_validator::JsonSchemaValidator;
 
 pub use validation_result::{
@@ -202,4 +202,33 @@ impl PlatformVersion {
             )
         }
     }
+
+    pub fn validate_documents_batch_state_transition_version(
+        &self,
+        version: u16,
+    ) -> SimpleConsensusValidationResult {
+        if self
+            .dpp
+            .state_transition_serialization_versions
+            .documents_batch_state_transition
+            .check_version(version)
+        {
+            SimpleConsensusValidationResult::default()
+        } else {
+            SimpleConsensusValidationResult::new_with_error(
+                UnsupportedVersionError::new(
+                    version,
+                    self.dpp
+                        .state_transition_serialization_versions
+                        .documents_batch_state_transition
+                        .min_version,
+                    self.dpp
+                        .state_transition_serialization_versions
+                        .documents_batch_state_transition
+                        .max_version,
+                )
+                .into(),
+            )
+        }
+    }
 }
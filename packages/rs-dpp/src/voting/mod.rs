@@ -0,0 +1,131 @@
+use crate::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use crate::data_contract::document_type::DocumentTypeRef;
+use crate::document::{Document, DocumentV0Getters};
+use crate::serialization::PlatformSerializable;
+use crate::util::hash::hash;
+use crate::ProtocolError;
+use bincode::{Decode, Encode};
+use platform_serialization_derive::{PlatformDeserialize, PlatformSerialize};
+use platform_value::{Identifier, Value};
+use serde::{Deserialize, Serialize};
+
+/// A poll on who should own a document for a value of a contested unique index
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Default,
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(unversioned)]
+pub struct ContestedDocumentResourceVotePoll {
+    pub contract_id: Identifier,
+    pub document_type_name: String,
+    pub index_name: String,
+    /// The values of the index properties, as they are stored in the index
+    pub index_values: Vec<Value>,
+}
+
+impl ContestedDocumentResourceVotePoll {
+    /// The poll a document would be a contender in, `None` if the document type has no
+    /// contested index or the document doesn't have a value for every property of it
+    pub fn for_document(
+        contract_id: Identifier,
+        document_type: DocumentTypeRef,
+        document: &Document,
+    ) -> Option<Self> {
+        let index = document_type.contested_index()?;
+
+        let index_values = index
+            .properties
+            .iter()
+            .map(|property| {
+                let value = match property.name.as_str() {
+                    "$ownerId" => Value::Identifier(document.owner_id().to_buffer()),
+                    name => document.get(name)?.clone(),
+                };
+
+                Some(match value {
+                    Value::Text(text) if property.is_normalized() => {
+                        Value::Text(property.normalize_str(&text))
+                    }
+                    value => value,
+                })
+            })
+            .collect::<Option<Vec<Value>>>()?;
+
+        Some(ContestedDocumentResourceVotePoll {
+            contract_id,
+            document_type_name: document_type.name().clone(),
+            index_name: index.name.clone(),
+            index_values,
+        })
+    }
+
+    /// A unique identifier of the poll, used as the key of the contest in state
+    pub fn unique_id(&self) -> Result<Identifier, ProtocolError> {
+        let bytes = self.serialize_to_bytes()?;
+        Ok(Identifier::new(hash(bytes)))
+    }
+}
+
+/// The choice of a voter in a contested resource vote poll
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Serialize,
+    Deserialize,
+    Encode,
+    Decode,
+    PlatformSerialize,
+    PlatformDeserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[platform_serialize(unversioned)]
+pub enum ResourceVoteChoice {
+    /// The document should be awarded to this contender
+    TowardsIdentity(Identifier),
+    /// The voter doesn't support any contender
+    #[default]
+    Abstain,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_have_different_ids_for_different_index_values() {
+        let poll = ContestedDocumentResourceVotePoll {
+            contract_id: Identifier::new([1; 32]),
+            document_type_name: "domain".to_string(),
+            index_name: "parentNameAndLabel".to_string(),
+            index_values: vec![
+                Value::Text("dash".to_string()),
+                Value::Text("a".to_string()),
+            ],
+        };
+
+        let mut other_poll = poll.clone();
+        other_poll.index_values[1] = Value::Text("b".to_string());
+
+        assert_eq!(
+            poll.unique_id().expect("expected id"),
+            poll.clone().unique_id().expect("expected id")
+        );
+        assert_ne!(
+            poll.unique_id().expect("expected id"),
+            other_poll.unique_id().expect("expected id")
+        );
+    }
+}
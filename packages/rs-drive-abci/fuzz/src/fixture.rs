@@ -0,0 +1,122 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+use dpp::identity::accessors::IdentitySettersV0;
+use dpp::identity::Identity;
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::version::PlatformVersion;
+use drive_abci::config::PlatformConfig;
+use drive_abci::rpc::core::MockCoreRPCLike;
+use drive_abci::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+use drive_abci::test::helpers::signer::SimpleSigner;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Seed used to create the fixture identities, so every run sees the same state
+pub const FIXTURE_SEED: u64 = 9421;
+
+/// Amount of identities registered in the fixture platform
+pub const FIXTURE_IDENTITY_COUNT: u16 = 5;
+
+/// Amount of keys every fixture identity has (master, critical and high)
+pub const FIXTURE_IDENTITY_KEY_COUNT: u16 = 3;
+
+/// Balance given to every fixture identity, enough to pay for any generated transition
+pub const FIXTURE_IDENTITY_BALANCE: u64 = 100_000_000_000;
+
+/// Identities, their private keys and the system contracts used to build valid, signed
+/// state transitions. It does not need any state, so it is cheap enough to be used by the
+/// pure deserialization targets.
+pub struct TransitionFixture {
+    /// Identities that can sign transitions
+    pub identities: Vec<Identity>,
+    /// Signer holding the private keys of all fixture identities
+    pub signer: SimpleSigner,
+    /// The DPNS system contract
+    pub dpns_contract: DataContract,
+    /// The Dashpay system contract
+    pub dashpay_contract: DataContract,
+}
+
+impl TransitionFixture {
+    /// Creates the fixture deterministically from `FIXTURE_SEED`
+    pub fn new(platform_version: &PlatformVersion) -> Self {
+        let mut rng = StdRng::seed_from_u64(FIXTURE_SEED);
+        let mut signer = SimpleSigner::default();
+
+        let identities = (0..FIXTURE_IDENTITY_COUNT)
+            .map(|_| {
+                let (mut identity, keys) =
+                    Identity::random_identity_with_main_keys_with_private_key::<Vec<_>>(
+                        FIXTURE_IDENTITY_KEY_COUNT,
+                        &mut rng,
+                        platform_version,
+                    )
+                    .expect("expected to create a fixture identity");
+                identity.set_balance(FIXTURE_IDENTITY_BALANCE);
+                signer.add_keys(keys);
+                identity
+            })
+            .collect();
+
+        let dpns_contract =
+            load_system_data_contract(SystemDataContract::DPNS, platform_version.protocol_version)
+                .expect("expected to load dpns contract");
+        let dashpay_contract = load_system_data_contract(
+            SystemDataContract::Dashpay,
+            platform_version.protocol_version,
+        )
+        .expect("expected to load dashpay contract");
+
+        TransitionFixture {
+            identities,
+            signer,
+            dpns_contract,
+            dashpay_contract,
+        }
+    }
+}
+
+/// A platform in genesis state with the `TransitionFixture` identities registered
+pub struct PlatformFixture {
+    /// The platform, backed by a temporary directory
+    pub platform: TempPlatform<MockCoreRPCLike>,
+    /// The identities and contracts known to the platform
+    pub transitions: TransitionFixture,
+}
+
+impl PlatformFixture {
+    /// Creates a platform in genesis state and registers the fixture identities
+    pub fn new(platform_version: &PlatformVersion) -> Self {
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig::default())
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        // Chain asset lock proofs are resolved through Core. Arbitrary input must never reach
+        // an unconfigured mock expectation, that would be reported as a crash.
+        platform
+            .core_rpc
+            .expect_get_transaction_extended_info()
+            .returning(|_| Err(dashcore_rpc::Error::UnexpectedStructure));
+
+        let transitions = TransitionFixture::new(platform_version);
+
+        for identity in &transitions.identities {
+            platform
+                .drive
+                .add_new_identity(
+                    identity.clone(),
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add a fixture identity");
+        }
+
+        PlatformFixture {
+            platform,
+            transitions,
+        }
+    }
+}
@@ -0,0 +1,606 @@
+use crate::fixture::TransitionFixture;
+use arbitrary::Arbitrary;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+use dpp::data_contract::document_type::v0::random_document_type::{
+    FieldMinMaxBounds, FieldTypeWeights, RandomDocumentTypeParameters,
+};
+use dpp::data_contract::document_type::v0::DocumentTypeV0;
+use dpp::data_contract::document_type::{DocumentPropertyType, DocumentTypeRef};
+use dpp::data_contract::DataContract;
+use dpp::document::DocumentV0Getters;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::platform_value::{Bytes32, Identifier, Value};
+use dpp::serialization::PlatformSerializable;
+use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+use dpp::util::cbor_serializer;
+use dpp::version::PlatformVersion;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::{json, Map, Value as JsonValue};
+
+/// Where clause operators understood by `DriveQuery`
+const WHERE_OPERATORS: [&str; 11] = [
+    "==",
+    ">",
+    ">=",
+    "<",
+    "<=",
+    "in",
+    "startsWith",
+    "Between",
+    "BetweenExcludeBounds",
+    "BetweenExcludeLeft",
+    "BetweenExcludeRight",
+];
+
+/// The key id of the critical authentication key of the fixture identities
+const SIGNING_KEY_ID: u32 = 1;
+
+/// A byte flip applied to a serialized, well formed input
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct Corruption {
+    /// Offset of the byte, wrapped around the input length
+    pub offset: u16,
+    /// Mask xored into the byte
+    pub mask: u8,
+}
+
+/// Applies corruptions in order to the given bytes
+pub fn corrupt(bytes: &mut [u8], corruptions: &[Corruption]) {
+    if bytes.is_empty() {
+        return;
+    }
+    for corruption in corruptions {
+        let offset = corruption.offset as usize % bytes.len();
+        bytes[offset] ^= corruption.mask;
+    }
+}
+
+/// Input of the state transition targets
+#[derive(Arbitrary, Debug)]
+pub enum StateTransitionInput {
+    /// Raw bytes as they would come from the network
+    Raw(Vec<u8>),
+    /// A valid, signed documents batch from the fixture, optionally corrupted afterwards
+    DocumentsBatch {
+        /// Seed for the document generator
+        seed: u64,
+        /// Byte flips applied after serialization
+        corruptions: Vec<Corruption>,
+    },
+}
+
+impl StateTransitionInput {
+    /// Turns the input into serialized state transition bytes
+    pub fn into_bytes(
+        self,
+        fixture: &TransitionFixture,
+        platform_version: &PlatformVersion,
+    ) -> Vec<u8> {
+        match self {
+            StateTransitionInput::Raw(bytes) => bytes,
+            StateTransitionInput::DocumentsBatch { seed, corruptions } => {
+                let Some(mut bytes) = signed_documents_batch(fixture, seed, platform_version)
+                else {
+                    return vec![];
+                };
+                corrupt(&mut bytes, &corruptions);
+                bytes
+            }
+        }
+    }
+}
+
+/// Creates a signed documents batch transition for a random fixture identity with a random
+/// document of a random Dashpay or DPNS document type.
+///
+/// Returns `None` if the document generator can't produce a document for the picked type.
+pub fn signed_documents_batch(
+    fixture: &TransitionFixture,
+    seed: u64,
+    platform_version: &PlatformVersion,
+) -> Option<Vec<u8>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let identity = &fixture.identities[rng.gen_range(0..fixture.identities.len())];
+    let key = identity.get_public_key_by_id(SIGNING_KEY_ID)?;
+
+    let contract = if rng.gen_bool(0.5) {
+        &fixture.dashpay_contract
+    } else {
+        &fixture.dpns_contract
+    };
+    let document_type = pick_document_type(contract, &mut rng)?;
+
+    let entropy = Bytes32::random_with_rng(&mut rng);
+    let document = document_type
+        .random_document_with_identifier_and_entropy(
+            &mut rng,
+            identity.id(),
+            entropy,
+            platform_version,
+        )
+        .ok()?;
+
+    let transition = if rng.gen_bool(0.8) {
+        DocumentsBatchTransition::new_document_creation_transition_from_document(
+            document,
+            document_type,
+            entropy.0,
+            key,
+            &fixture.signer,
+            platform_version,
+            None,
+            None,
+            None,
+        )
+    } else {
+        DocumentsBatchTransition::new_document_replacement_transition_from_document(
+            document,
+            document_type,
+            key,
+            &fixture.signer,
+            platform_version,
+            None,
+            None,
+            None,
+        )
+    }
+    .ok()?;
+
+    transition.serialize_to_bytes().ok()
+}
+
+fn pick_document_type<'a>(
+    contract: &'a DataContract,
+    rng: &mut StdRng,
+) -> Option<DocumentTypeRef<'a>> {
+    let document_types = contract.document_types();
+    if document_types.is_empty() {
+        return None;
+    }
+    document_types
+        .values()
+        .nth(rng.gen_range(0..document_types.len()))
+        .map(|document_type| document_type.as_ref())
+}
+
+/// A keyword that a schema mutation can set on a property
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub enum SchemaKeyword {
+    /// `minLength`
+    MinLength,
+    /// `maxLength`
+    MaxLength,
+    /// `minItems`
+    MinItems,
+    /// `maxItems`
+    MaxItems,
+    /// `minimum`
+    Minimum,
+    /// `maximum`
+    Maximum,
+    /// `byteArray`
+    ByteArray,
+    /// `position`
+    Position,
+}
+
+impl SchemaKeyword {
+    fn name(&self) -> &'static str {
+        match self {
+            SchemaKeyword::MinLength => "minLength",
+            SchemaKeyword::MaxLength => "maxLength",
+            SchemaKeyword::MinItems => "minItems",
+            SchemaKeyword::MaxItems => "maxItems",
+            SchemaKeyword::Minimum => "minimum",
+            SchemaKeyword::Maximum => "maximum",
+            SchemaKeyword::ByteArray => "byteArray",
+            SchemaKeyword::Position => "position",
+        }
+    }
+}
+
+/// A mutation applied to a generated document schema
+#[derive(Arbitrary, Debug, Clone)]
+pub enum SchemaMutation {
+    /// Removes a property, leaving indices and `required` pointing at it
+    DropProperty(u8),
+    /// Sets a numeric keyword on a property
+    SetKeyword(u8, SchemaKeyword, i64),
+    /// Sets an arbitrary keyword with an arbitrary value, parsed as JSON when possible
+    InjectKeyword(u8, String, String),
+    /// Sets a regular expression `pattern` on a property
+    SetPattern(u8, String),
+    /// Flips uniqueness of an index
+    ToggleIndexUnique(u8),
+    /// Appends a copy of an index
+    DuplicateIndex(u8),
+    /// Clears the `required` list
+    ClearRequired,
+    /// Allows additional properties
+    AllowAdditionalProperties,
+}
+
+/// Input of the data contract creation target
+#[derive(Arbitrary, Debug)]
+pub enum DataContractSchemaInput {
+    /// Raw JSON text, used as the `documents` part of the contract
+    Raw(String),
+    /// Schemas derived from random document types, then mutated
+    Generated {
+        /// Seed for the document type generator
+        seed: u64,
+        /// Amount of document types, wrapped to 1..=4
+        document_type_count: u8,
+        /// Mutations applied to the first document type schema
+        mutations: Vec<SchemaMutation>,
+    },
+}
+
+impl DataContractSchemaInput {
+    /// Turns the input into the JSON `documents` part of a data contract, if possible
+    pub fn into_documents_json(self, platform_version: &PlatformVersion) -> Option<JsonValue> {
+        match self {
+            DataContractSchemaInput::Raw(text) => serde_json::from_str(&text).ok(),
+            DataContractSchemaInput::Generated {
+                seed,
+                document_type_count,
+                mutations,
+            } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let mut documents = Map::new();
+                for _ in 0..(document_type_count % 4) + 1 {
+                    let document_type = DocumentTypeV0::random_document_type(
+                        random_document_type_parameters(),
+                        Identifier::random_with_rng(&mut rng),
+                        &mut rng,
+                        platform_version,
+                    )
+                    .ok()?;
+                    documents.insert(
+                        document_type.name().clone(),
+                        document_type_json_schema(&document_type),
+                    );
+                }
+                if let Some((_, schema)) = documents.iter_mut().next() {
+                    for mutation in &mutations {
+                        mutate_schema(schema, mutation);
+                    }
+                }
+                Some(JsonValue::Object(documents))
+            }
+        }
+    }
+}
+
+/// Parameters for the random document types, covering every field type the generator knows
+pub fn random_document_type_parameters() -> RandomDocumentTypeParameters {
+    RandomDocumentTypeParameters {
+        new_fields_optional_count_range: 0..6,
+        new_fields_required_count_range: 1..4,
+        new_indexes_count_range: 0..4,
+        field_weights: FieldTypeWeights {
+            string_weight: 40,
+            float_weight: 10,
+            integer_weight: 20,
+            date_weight: 10,
+            boolean_weight: 5,
+            byte_array_weight: 15,
+        },
+        field_bounds: FieldMinMaxBounds {
+            string_min_len: 0..4,
+            string_has_min_len_chance: 0.3,
+            string_max_len: 4..100,
+            string_has_max_len_chance: 0.7,
+            integer_min: 0..10,
+            integer_has_min_chance: 0.3,
+            integer_max: 10..1000,
+            integer_has_max_chance: 0.3,
+            float_min: 0.0..10.0,
+            float_has_min_chance: 0.3,
+            float_max: 10.0..1000.0,
+            float_has_max_chance: 0.3,
+            date_min: 0,
+            date_max: 2_000_000_000_000,
+            byte_array_min_len: 0..4,
+            byte_array_has_min_len_chance: 0.3,
+            byte_array_max_len: 4..64,
+            byte_array_has_max_len_chance: 0.7,
+        },
+        keep_history_chance: 0.25,
+        documents_mutable_chance: 0.75,
+    }
+}
+
+/// Writes a generated document type back as the JSON schema a contract owner would submit
+pub fn document_type_json_schema(document_type: &DocumentTypeV0) -> JsonValue {
+    let properties: Map<String, JsonValue> = document_type
+        .properties()
+        .iter()
+        .enumerate()
+        .map(|(position, (name, property))| {
+            let mut schema = property_json_schema(&property.property_type);
+            schema["position"] = json!(position);
+            (name.clone(), schema)
+        })
+        .collect();
+
+    let indices: Vec<JsonValue> = document_type
+        .indices()
+        .iter()
+        .map(|index| {
+            let properties: Vec<JsonValue> = index
+                .properties
+                .iter()
+                .map(|property| {
+                    json!({ property.name.clone(): if property.ascending { "asc" } else { "desc" } })
+                })
+                .collect();
+            json!({
+                "name": index.name,
+                "properties": properties,
+                "unique": index.unique,
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": document_type.required_fields(),
+        "indices": indices,
+        "documentsKeepHistory": document_type.documents_keep_history(),
+        "documentsMutable": document_type.documents_mutable(),
+        "additionalProperties": false,
+    })
+}
+
+fn property_json_schema(property_type: &DocumentPropertyType) -> JsonValue {
+    match property_type {
+        DocumentPropertyType::Integer => json!({ "type": "integer" }),
+        DocumentPropertyType::Number => json!({ "type": "number" }),
+        DocumentPropertyType::Date => json!({ "type": "integer", "minimum": 0 }),
+        DocumentPropertyType::Boolean => json!({ "type": "boolean" }),
+        DocumentPropertyType::String(min_length, max_length) => {
+            let mut schema = json!({ "type": "string" });
+            if let Some(min_length) = min_length {
+                schema["minLength"] = json!(min_length);
+            }
+            if let Some(max_length) = max_length {
+                schema["maxLength"] = json!(max_length);
+            }
+            schema
+        }
+        DocumentPropertyType::ByteArray(min_items, max_items) => {
+            let mut schema = json!({ "type": "array", "byteArray": true });
+            if let Some(min_items) = min_items {
+                schema["minItems"] = json!(min_items);
+            }
+            if let Some(max_items) = max_items {
+                schema["maxItems"] = json!(max_items);
+            }
+            schema
+        }
+        DocumentPropertyType::Identifier => json!({
+            "type": "array",
+            "byteArray": true,
+            "minItems": 32,
+            "maxItems": 32,
+            "contentMediaType": "application/x.dash.dpp.identifier",
+        }),
+        _ => json!({ "type": "object", "properties": {}, "additionalProperties": false }),
+    }
+}
+
+fn nth_key(map: &Map<String, JsonValue>, index: u8) -> Option<String> {
+    if map.is_empty() {
+        return None;
+    }
+    map.keys().nth(index as usize % map.len()).cloned()
+}
+
+fn mutate_schema(schema: &mut JsonValue, mutation: &SchemaMutation) {
+    let Some(schema) = schema.as_object_mut() else {
+        return;
+    };
+    match mutation {
+        SchemaMutation::DropProperty(index) => {
+            if let Some(properties) = schema.get_mut("properties").and_then(|p| p.as_object_mut()) {
+                if let Some(name) = nth_key(properties, *index) {
+                    properties.remove(&name);
+                }
+            }
+        }
+        SchemaMutation::SetKeyword(index, keyword, value) => {
+            set_property_keyword(schema, *index, keyword.name(), json!(value));
+        }
+        SchemaMutation::InjectKeyword(index, keyword, value) => {
+            let value = serde_json::from_str(value).unwrap_or_else(|_| json!(value));
+            set_property_keyword(schema, *index, keyword, value);
+        }
+        SchemaMutation::SetPattern(index, pattern) => {
+            set_property_keyword(schema, *index, "pattern", json!(pattern));
+        }
+        SchemaMutation::ToggleIndexUnique(index) => {
+            if let Some(indices) = schema.get_mut("indices").and_then(|i| i.as_array_mut()) {
+                if !indices.is_empty() {
+                    let len = indices.len();
+                    let unique = &mut indices[*index as usize % len]["unique"];
+                    *unique = json!(!unique.as_bool().unwrap_or_default());
+                }
+            }
+        }
+        SchemaMutation::DuplicateIndex(index) => {
+            if let Some(indices) = schema.get_mut("indices").and_then(|i| i.as_array_mut()) {
+                if !indices.is_empty() {
+                    let copy = indices[*index as usize % indices.len()].clone();
+                    indices.push(copy);
+                }
+            }
+        }
+        SchemaMutation::ClearRequired => {
+            schema.insert("required".to_string(), json!([]));
+        }
+        SchemaMutation::AllowAdditionalProperties => {
+            schema.insert("additionalProperties".to_string(), json!(true));
+        }
+    }
+}
+
+fn set_property_keyword(
+    schema: &mut Map<String, JsonValue>,
+    index: u8,
+    keyword: &str,
+    value: JsonValue,
+) {
+    let Some(properties) = schema.get_mut("properties").and_then(|p| p.as_object_mut()) else {
+        return;
+    };
+    let Some(name) = nth_key(properties, index) else {
+        return;
+    };
+    if let Some(property) = properties.get_mut(&name).and_then(|p| p.as_object_mut()) {
+        property.insert(keyword.to_string(), value);
+    }
+}
+
+/// A where clause over one of the document type properties
+#[derive(Arbitrary, Debug, Clone, Copy)]
+pub struct WhereClauseInput {
+    /// Index of the property, wrapped around the property count
+    pub property: u8,
+    /// Index of the operator, wrapped around `WHERE_OPERATORS`
+    pub operator: u8,
+}
+
+/// Input of the drive query target
+#[derive(Arbitrary, Debug)]
+pub enum DriveQueryInput {
+    /// Raw CBOR bytes as they would come from a query request
+    Raw {
+        /// Index of the document type in the queried contract
+        document_type: u8,
+        /// The CBOR encoded query
+        cbor: Vec<u8>,
+    },
+    /// A query built from the properties and values of a random document
+    Generated {
+        /// Index of the document type in the queried contract
+        document_type: u8,
+        /// Seed for the document generator providing the clause values
+        seed: u64,
+        /// Where clauses
+        clauses: Vec<WhereClauseInput>,
+        /// Order by clauses as property index and direction
+        order_by: Vec<(u8, bool)>,
+        /// Query limit
+        limit: Option<u16>,
+        /// Whether to start at (`true`) or after (`false`) a random document id
+        start: Option<bool>,
+    },
+}
+
+impl DriveQueryInput {
+    /// The index of the document type the query is made for
+    pub fn document_type_index(&self) -> u8 {
+        match self {
+            DriveQueryInput::Raw { document_type, .. } => *document_type,
+            DriveQueryInput::Generated { document_type, .. } => *document_type,
+        }
+    }
+
+    /// Turns the input into the CBOR encoded query for the given document type
+    pub fn into_cbor(
+        self,
+        document_type: DocumentTypeRef,
+        platform_version: &PlatformVersion,
+    ) -> Option<Vec<u8>> {
+        match self {
+            DriveQueryInput::Raw { cbor, .. } => Some(cbor),
+            DriveQueryInput::Generated {
+                seed,
+                clauses,
+                order_by,
+                limit,
+                start,
+                ..
+            } => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let document = document_type
+                    .random_document_with_rng(&mut rng, platform_version)
+                    .ok()?;
+                let mut property_names: Vec<String> =
+                    document_type.properties().keys().cloned().collect();
+                property_names
+                    .extend(["$id", "$ownerId", "$createdAt", "$updatedAt"].map(String::from));
+
+                let value_for = |name: &str| -> Value {
+                    match name {
+                        "$id" => document.id().into(),
+                        "$ownerId" => document.owner_id().into(),
+                        _ => document
+                            .properties()
+                            .get(name)
+                            .cloned()
+                            .unwrap_or(Value::Null),
+                    }
+                };
+
+                let where_clauses: Vec<Value> = clauses
+                    .iter()
+                    .map(|clause| {
+                        let name = &property_names[clause.property as usize % property_names.len()];
+                        let operator =
+                            WHERE_OPERATORS[clause.operator as usize % WHERE_OPERATORS.len()];
+                        let value = value_for(name);
+                        let operand = match operator {
+                            "in"
+                            | "Between"
+                            | "BetweenExcludeBounds"
+                            | "BetweenExcludeLeft"
+                            | "BetweenExcludeRight" => Value::Array(vec![value.clone(), value]),
+                            _ => value,
+                        };
+                        Value::Array(vec![
+                            Value::Text(name.clone()),
+                            Value::Text(operator.to_string()),
+                            operand,
+                        ])
+                    })
+                    .collect();
+
+                let order_by: Vec<Value> = order_by
+                    .iter()
+                    .map(|(property, ascending)| {
+                        let name = &property_names[*property as usize % property_names.len()];
+                        Value::Array(vec![
+                            Value::Text(name.clone()),
+                            Value::Text(if *ascending { "asc" } else { "desc" }.to_string()),
+                        ])
+                    })
+                    .collect();
+
+                let mut query = vec![
+                    (
+                        Value::Text("where".to_string()),
+                        Value::Array(where_clauses),
+                    ),
+                    (Value::Text("orderBy".to_string()), Value::Array(order_by)),
+                ];
+                if let Some(limit) = limit {
+                    query.push((Value::Text("limit".to_string()), Value::U16(limit)));
+                }
+                if let Some(start_at) = start {
+                    let key = if start_at { "startAt" } else { "startAfter" };
+                    query.push((Value::Text(key.to_string()), document.id().into()));
+                }
+
+                cbor_serializer::serializable_value_to_cbor(&Value::Map(query), None).ok()
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Drive ABCI fuzzing harness
+//!
+//! Shared fixtures, structured input generators and target bodies for the cargo-fuzz targets
+//! in `fuzz_targets`. Every target is deterministic: the same input bytes always produce the
+//! same generated transitions, schemas and queries, so a crash found by the fuzzer can be
+//! minimized with `cargo fuzz tmin` and replayed forever as a regression test.
+//!
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+/// Platform and identity fixtures shared by the targets
+pub mod fixture;
+/// Structured input generators built on top of the dpp random helpers
+pub mod generators;
+/// Target bodies, called both by the fuzz targets and the regression tests
+pub mod targets;
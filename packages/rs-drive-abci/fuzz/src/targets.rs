@@ -0,0 +1,170 @@
+use crate::fixture::{PlatformFixture, TransitionFixture};
+use crate::generators::{DataContractSchemaInput, DriveQueryInput, StateTransitionInput};
+use arbitrary::{Arbitrary, Unstructured};
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+use dpp::data_contract::{DataContract, DataContractFactory};
+use dpp::platform_value::Identifier;
+use dpp::serialization::{
+    PlatformDeserializable, PlatformDeserializableWithPotentialValidationFromVersionedStructure,
+    PlatformSerializable, PlatformSerializableWithPlatformVersion,
+};
+use dpp::state_transition::StateTransition;
+use dpp::util::entropy_generator::EntropyGenerator;
+use dpp::version::PlatformVersion;
+use drive::query::DriveQuery;
+
+thread_local! {
+    static TRANSITION_FIXTURE: TransitionFixture = TransitionFixture::new(PlatformVersion::latest());
+    static PLATFORM_FIXTURE: PlatformFixture = PlatformFixture::new(PlatformVersion::latest());
+}
+
+/// Contract ids are derived from entropy, keep them stable between runs
+struct FixedEntropyGenerator;
+
+impl EntropyGenerator for FixedEntropyGenerator {
+    fn generate(&self) -> anyhow::Result<[u8; 32]> {
+        Ok([7; 32])
+    }
+}
+
+/// Deserializes arbitrary or corrupted state transition bytes.
+///
+/// Anything that deserializes must serialize again, and the serialization must be stable.
+pub fn state_transition_deserialization(data: &[u8]) {
+    let platform_version = PlatformVersion::latest();
+    let Ok(input) = StateTransitionInput::arbitrary_take_rest(Unstructured::new(data)) else {
+        return;
+    };
+    let bytes = TRANSITION_FIXTURE.with(|fixture| input.into_bytes(fixture, platform_version));
+
+    let Ok(state_transition) = StateTransition::deserialize_from_bytes(&bytes) else {
+        return;
+    };
+
+    let serialized = state_transition
+        .serialize_to_bytes()
+        .expect("a deserialized state transition must serialize");
+    let restored = StateTransition::deserialize_from_bytes(&serialized)
+        .expect("a serialized state transition must deserialize");
+    assert_eq!(
+        restored
+            .serialize_to_bytes()
+            .expect("a deserialized state transition must serialize"),
+        serialized,
+        "state transition serialization must be stable"
+    );
+}
+
+/// Creates a data contract from an arbitrary or mutated JSON schema.
+///
+/// Any accepted contract must round trip through its serialization format, and the size and
+/// document generation methods of its document types must not panic.
+pub fn data_contract_from_schema(data: &[u8]) {
+    let platform_version = PlatformVersion::latest();
+    let Ok(input) = DataContractSchemaInput::arbitrary_take_rest(Unstructured::new(data)) else {
+        return;
+    };
+    let Some(documents) = input.into_documents_json(platform_version) else {
+        return;
+    };
+
+    let factory = DataContractFactory::new(
+        platform_version.protocol_version,
+        Some(Box::new(FixedEntropyGenerator)),
+    )
+    .expect("expected to create a data contract factory");
+
+    let Ok(created_data_contract) =
+        factory.create_with_value_config(Identifier::new([1; 32]), documents.into(), None, None)
+    else {
+        return;
+    };
+    let data_contract = created_data_contract.data_contract_owned();
+
+    let serialized = data_contract
+        .serialize_to_bytes_with_platform_version(platform_version)
+        .expect("an accepted data contract must serialize");
+    let restored = DataContract::versioned_deserialize(&serialized, true, platform_version)
+        .expect("a serialized data contract must deserialize and validate");
+    assert_eq!(
+        restored
+            .serialize_to_bytes_with_platform_version(platform_version)
+            .expect("a deserialized data contract must serialize"),
+        serialized,
+        "data contract serialization must be stable"
+    );
+
+    for document_type in data_contract.document_types().values() {
+        let document_type = document_type.as_ref();
+        let _ = document_type.max_size(platform_version);
+        let _ = document_type.estimated_size(platform_version);
+        let _ = document_type.random_document(Some(0), platform_version);
+    }
+}
+
+/// Builds a `DriveQuery` from arbitrary or generated CBOR against the DPNS and Dashpay
+/// contracts, and executes it against the fixture platform.
+pub fn drive_query_from_cbor(data: &[u8]) {
+    let platform_version = PlatformVersion::latest();
+    let Ok(input) = DriveQueryInput::arbitrary_take_rest(Unstructured::new(data)) else {
+        return;
+    };
+
+    PLATFORM_FIXTURE.with(|fixture| {
+        let contracts = [
+            &fixture.transitions.dpns_contract,
+            &fixture.transitions.dashpay_contract,
+        ];
+        let document_types: Vec<_> = contracts
+            .iter()
+            .flat_map(|contract| {
+                contract
+                    .document_types()
+                    .values()
+                    .map(move |document_type| (*contract, document_type.as_ref()))
+            })
+            .collect();
+        let (contract, document_type) =
+            document_types[input.document_type_index() as usize % document_types.len()];
+
+        let Some(cbor) = input.into_cbor(document_type, platform_version) else {
+            return;
+        };
+
+        let drive = &fixture.platform.drive;
+        let Ok(query) = DriveQuery::from_cbor(&cbor, contract, document_type, &drive.config) else {
+            return;
+        };
+
+        let _ = query.construct_path_query(None, platform_version);
+        let _ = query.execute_raw_results_no_proof(drive, None, None, platform_version);
+    });
+}
+
+/// Runs `check_tx` on arbitrary or corrupted state transition bytes.
+///
+/// Malformed input must be reported as consensus errors, never as internal errors, and every
+/// consensus error must be serializable to be sent back to Tenderdash.
+pub fn check_tx(data: &[u8]) {
+    let platform_version = PlatformVersion::latest();
+    let Ok(input) = StateTransitionInput::arbitrary_take_rest(Unstructured::new(data)) else {
+        return;
+    };
+
+    PLATFORM_FIXTURE.with(|fixture| {
+        let bytes = input.into_bytes(&fixture.transitions, platform_version);
+
+        let validation_result = fixture
+            .platform
+            .check_tx(&bytes)
+            .unwrap_or_else(|e| panic!("check_tx must not fail with an internal error: {e:?}"));
+
+        for error in &validation_result.errors {
+            error
+                .serialize_to_bytes_with_platform_version(platform_version)
+                .expect("consensus errors must be serializable");
+        }
+    });
+}
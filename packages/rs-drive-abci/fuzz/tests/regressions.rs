@@ -0,0 +1,56 @@
+//! Replays every minimized crash stored in `regressions/<target>` through its target
+
+use std::fs;
+use std::path::Path;
+
+fn replay(target: &str, run: fn(&[u8])) {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("regressions")
+        .join(target);
+
+    // Targets without stored crashes have no regressions directory
+    if !dir.exists() {
+        return;
+    }
+
+    let mut entries: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("expected to read {}: {e}", dir.display()))
+        .map(|entry| entry.expect("expected a directory entry").path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        let data =
+            fs::read(&path).unwrap_or_else(|e| panic!("expected to read {}: {e}", path.display()));
+        run(&data);
+    }
+}
+
+#[test]
+fn state_transition_deserialization_regressions() {
+    replay(
+        "state_transition_deserialization",
+        drive_abci_fuzz::targets::state_transition_deserialization,
+    );
+}
+
+#[test]
+fn data_contract_from_schema_regressions() {
+    replay(
+        "data_contract_from_schema",
+        drive_abci_fuzz::targets::data_contract_from_schema,
+    );
+}
+
+#[test]
+fn drive_query_from_cbor_regressions() {
+    replay(
+        "drive_query_from_cbor",
+        drive_abci_fuzz::targets::drive_query_from_cbor,
+    );
+}
+
+#[test]
+fn check_tx_regressions() {
+    replay("check_tx", drive_abci_fuzz::targets::check_tx);
+}
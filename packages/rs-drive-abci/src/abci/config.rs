@@ -36,6 +36,19 @@ pub struct AbciConfig {
     #[serde(default, rename = "abci_prometheus_bind_address")]
     pub prometheus_bind_address: Option<String>,
 
+    /// Address to listen for HTTP JSON queries.
+    ///
+    /// Optional.
+    ///
+    /// Address should be an URL with scheme `http://`, for example:
+    /// - `http://127.0.0.1:29091`
+    ///
+    /// Port number defaults to [DEFAULT_QUERY_GATEWAY_PORT].
+    ///
+    /// [DEFAULT_QUERY_GATEWAY_PORT]: crate::query::gateway::DEFAULT_QUERY_GATEWAY_PORT
+    #[serde(default, rename = "abci_query_gateway_bind_address")]
+    pub query_gateway_bind_address: Option<String>,
+
     /// Public keys used for system identity
     #[serde(flatten)]
     pub keys: Keys,
@@ -73,6 +86,7 @@ impl Default for AbciConfig {
         Self {
             bind_address: "tcp://127.0.0.1:1234".to_string(),
             prometheus_bind_address: None,
+            query_gateway_bind_address: None,
             keys: Keys::new_random_keys_with_seed(18012014, PlatformVersion::first())
                 .expect("random keys for first version can not error"), //Dash genesis day
             genesis_height: AbciConfig::default_genesis_height(),
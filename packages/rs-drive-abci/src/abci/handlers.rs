@@ -65,6 +65,7 @@ use crate::execution::types::block_state_info::v0::{
 };
 use crate::platform_types::block_execution_outcome;
 use crate::platform_types::block_proposal::v0::BlockProposal;
+use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::withdrawal::withdrawal_txs;
@@ -577,6 +578,23 @@ where
 
         self.commit_transaction()?;
 
+        self.platform
+            .event_bus
+            .publish(block_finalization_outcome.events);
+
+        // Followers serve queries from checkpoints, a failure must not stop block execution
+        let height = self.platform.state.read().unwrap().last_block_height();
+        if self
+            .platform
+            .config
+            .follower
+            .should_publish_checkpoint(height)
+        {
+            if let Err(error) = self.platform.publish_checkpoint(height) {
+                tracing::error!(?error, height, "cannot publish checkpoint for followers");
+            }
+        }
+
         Ok(ResponseFinalizeBlock {
             events: vec![],
             retain_height: 0,
@@ -594,6 +612,8 @@ where
                 let first_consensus_error = validation_result.errors.first();
 
                 let (code, info) = if let Some(consensus_error) = first_consensus_error {
+                    crate::metrics::check_tx_rejection(consensus_error.code());
+
                     let consensus_error_bytes = consensus_error
                         .serialize_to_bytes_with_platform_version(platform_version)
                         .map_err(|e| ResponseException::from(Error::Protocol(e)))?;
@@ -659,71 +679,82 @@ where
     fn query(&self, request: RequestQuery) -> Result<ResponseQuery, ResponseException> {
         let _timer = crate::metrics::abci_request_duration("query");
 
-        let RequestQuery { data, path, .. } = &request;
-
-        let Some(platform_version) = PlatformVersion::get_maybe_current() else {
-            let response = ResponseQuery {
-                //todo: right now just put GRPC error codes,
-                //  later we will use own error codes
-                code: 1,
-                log: "".to_string(),
-                info: "Platform not initialized".to_string(),
-                index: 0,
-                key: vec![],
-                value: vec![],
-                proof_ops: None,
-                height: self.platform.state.read().unwrap().height() as i64,
-                codespace: "".to_string(),
-            };
-            tracing::trace!(method = "query", ?request, ?response);
-
-            return Ok(response);
-        };
-
-        let result = self
-            .platform
-            .query(path.as_str(), data.as_slice(), platform_version)?;
-
-        let (code, data, info) = if result.is_valid() {
-            (0, result.data.unwrap_or_default(), "success".to_string())
-        } else {
-            let error = result.errors.first();
-
-            let error_message = if let Some(error) = error {
-                error.to_string()
-            } else {
-                "Unknown Drive error".to_string()
-            };
-
-            let mut error_data = Map::new();
-            error_data.insert("message".to_string(), Value::String(error_message));
+        query_response(self.platform, request)
+    }
+}
 
-            let mut error_data_buffer: Vec<u8> = Vec::new();
-            ciborium::ser::into_writer(&error_data, &mut error_data_buffer)
-                .map_err(|e| e.to_string())?;
-            // TODO(rs-drive-abci): restore different error codes?
-            //   For now return error code 2, because it is recognized by DAPI as UNKNOWN error
-            //   and error code 1 corresponds to CANCELED grpc request which is not suitable
-            (2, vec![], encode(&error_data_buffer, Encoding::Base64))
-        };
+/// Executes a query against the platform and converts the result into an ABCI response.
+///
+/// Shared by the ABCI application and the read-only follower.
+pub(crate) fn query_response<C>(
+    platform: &Platform<C>,
+    request: RequestQuery,
+) -> Result<ResponseQuery, ResponseException>
+where
+    C: CoreRPCLike,
+{
+    let RequestQuery { data, path, .. } = &request;
 
+    let Some(platform_version) = PlatformVersion::get_maybe_current() else {
         let response = ResponseQuery {
             //todo: right now just put GRPC error codes,
             //  later we will use own error codes
-            code,
+            code: 1,
             log: "".to_string(),
-            info,
+            info: "Platform not initialized".to_string(),
             index: 0,
             key: vec![],
-            value: data,
+            value: vec![],
             proof_ops: None,
-            height: self.platform.state.read().unwrap().height() as i64,
+            height: platform.state.read().unwrap().height() as i64,
             codespace: "".to_string(),
         };
         tracing::trace!(method = "query", ?request, ?response);
 
-        Ok(response)
-    }
+        return Ok(response);
+    };
+
+    let result = platform.query(path.as_str(), data.as_slice(), platform_version)?;
+
+    let (code, data, info) = if result.is_valid() {
+        (0, result.data.unwrap_or_default(), "success".to_string())
+    } else {
+        let error = result.errors.first();
+
+        let error_message = if let Some(error) = error {
+            error.to_string()
+        } else {
+            "Unknown Drive error".to_string()
+        };
+
+        let mut error_data = Map::new();
+        error_data.insert("message".to_string(), Value::String(error_message));
+
+        let mut error_data_buffer: Vec<u8> = Vec::new();
+        ciborium::ser::into_writer(&error_data, &mut error_data_buffer)
+            .map_err(|e| e.to_string())?;
+        // TODO(rs-drive-abci): restore different error codes?
+        //   For now return error code 2, because it is recognized by DAPI as UNKNOWN error
+        //   and error code 1 corresponds to CANCELED grpc request which is not suitable
+        (2, vec![], encode(&error_data_buffer, Encoding::Base64))
+    };
+
+    let response = ResponseQuery {
+        //todo: right now just put GRPC error codes,
+        //  later we will use own error codes
+        code,
+        log: "".to_string(),
+        info,
+        index: 0,
+        key: vec![],
+        value: data,
+        proof_ops: None,
+        height: platform.state.read().unwrap().height() as i64,
+        codespace: "".to_string(),
+    };
+    tracing::trace!(method = "query", ?request, ?response);
+
+    Ok(response)
 }
 //
 // #[cfg(test)]
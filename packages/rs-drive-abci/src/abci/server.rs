@@ -1,13 +1,18 @@
 //! This module implements ABCI application server.
 //!
 use crate::error::execution::ExecutionError;
-use crate::{
-    config::PlatformConfig, error::Error, platform_types::platform::Platform,
-    rpc::core::CoreRPCLike,
-};
+use crate::{error::Error, platform_types::platform::Platform, rpc::core::CoreRPCLike};
 use drive::grovedb::Transaction;
 use std::fmt::Debug;
 use std::sync::RwLock;
+
+#[cfg(feature = "server")]
+use crate::config::PlatformConfig;
+#[cfg(feature = "server")]
+use crate::query::gateway::start_query_gateway;
+#[cfg(feature = "server")]
+use std::sync::Arc;
+#[cfg(feature = "server")]
 use tokio_util::sync::CancellationToken;
 
 /// AbciApp is an implementation of ABCI Application, as defined by Tenderdash.
@@ -23,16 +28,38 @@ pub struct AbciApplication<'a, C> {
 
 /// Start ABCI server and process incoming connections.
 ///
+/// The query gateway is started as well if it's configured.
+///
 /// Should never return.
-pub fn start<C: CoreRPCLike>(
+#[cfg(feature = "server")]
+pub fn start<C>(
     config: &PlatformConfig,
     core_rpc: C,
     cancel: CancellationToken,
-) -> Result<(), Error> {
+) -> Result<(), Error>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
     let bind_address = config.abci.bind_address.clone();
 
-    let platform: Platform<C> =
-        Platform::open_with_client(&config.db_path, Some(config.clone()), core_rpc)?;
+    let platform: Arc<Platform<C>> = Arc::new(Platform::open_with_client(
+        &config.db_path,
+        Some(config.clone()),
+        core_rpc,
+    )?);
+
+    if let Some(query_gateway_bind_address) = config
+        .abci
+        .query_gateway_bind_address
+        .as_deref()
+        .filter(|address| !address.is_empty())
+    {
+        start_query_gateway(
+            Arc::clone(&platform),
+            query_gateway_bind_address,
+            cancel.clone(),
+        )?;
+    }
 
     let abci = AbciApplication::new(&platform)?;
 
@@ -33,7 +33,9 @@ use dpp::util::deserializer::ProtocolVersion;
 use drive::drive::config::DriveConfig;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+use crate::follower::config::FollowerConfig;
 use crate::logging::LogConfigs;
+use crate::rpc::cache::DEFAULT_CORE_RPC_CACHE_SIZE;
 use crate::{abci::config::AbciConfig, error::Error};
 
 /// Configuration for Dash Core RPC client
@@ -87,6 +89,14 @@ pub struct CoreConfig {
     pub dkg_interval: String, // String due to https://github.com/softprops/envy/issues/26
     /// Minimum number of valid members to use the quorum
     pub min_quorum_valid_members: String, // String due to https://github.com/softprops/envy/issues/26
+    /// Maximum number of cached responses per Core RPC method, 0 disables the cache
+    #[serde(rename = "core_rpc_cache_size")]
+    pub rpc_cache_size: String, // String due to https://github.com/softprops/envy/issues/26
+    /// Core ZMQ address publishing chain lock notifications, eg. `tcp://127.0.0.1:29998`.
+    /// Cached Core RPC responses are invalidated when a new chain lock arrives.
+    /// Requires the `chain-lock-listener` feature.
+    #[serde(rename = "core_zmq_address")]
+    pub zmq_address: Option<String>,
 }
 
 impl CoreConfig {
@@ -102,17 +112,81 @@ impl CoreConfig {
             .parse::<u32>()
             .expect("MIN_QUORUM_VALID_MEMBERS is not an int")
     }
+    /// Returns maximum number of cached responses per Core RPC method
+    pub fn rpc_cache_size(&self) -> usize {
+        self.rpc_cache_size
+            .parse::<usize>()
+            .expect("CORE_RPC_CACHE_SIZE is not an int")
+    }
 }
 impl Default for CoreConfig {
     fn default() -> Self {
         Self {
             dkg_interval: String::from("24"),
             min_quorum_valid_members: String::from("3"),
+            rpc_cache_size: DEFAULT_CORE_RPC_CACHE_SIZE.to_string(),
+            zmq_address: None,
             rpc: Default::default(),
         }
     }
 }
 
+/// Configuration of the credit supply invariant checker.
+///
+/// The checker verifies that identity balances, pools, pending refunds and pending withdrawals
+/// hold exactly the credits that entered Platform minus completed withdrawals, and halts
+/// block finalization if they don't.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreditSupplyCheckConfig {
+    /// Check the credit supply every N blocks, `0` disables periodic checks
+    #[serde(
+        rename = "credit_supply_check_interval",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub interval: u64,
+
+    /// Debug mode, check the credit supply after every block
+    #[serde(
+        rename = "credit_supply_check_debug",
+        deserialize_with = "from_str_or_number"
+    )]
+    pub debug: bool,
+}
+
+impl CreditSupplyCheckConfig {
+    /// Returns true if the credit supply should be checked at the given height
+    pub fn should_check(&self, height: u64) -> bool {
+        if self.debug {
+            return true;
+        }
+
+        self.interval > 0 && height % self.interval == 0
+    }
+}
+
+impl Default for CreditSupplyCheckConfig {
+    fn default() -> Self {
+        Self {
+            interval: 0,
+            debug: false,
+        }
+    }
+}
+
+// Values of flattened structs are always passed as strings, see https://github.com/softprops/envy/issues/26
+fn from_str_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: serde::Deserialize<'de> + std::str::FromStr,
+    <T as std::str::FromStr>::Err: std::fmt::Display,
+{
+    use serde::de::Error;
+
+    let s = String::deserialize(deserializer)?;
+    s.parse::<T>().map_err(Error::custom)
+}
+
 /// Configurtion of Dash Platform.
 ///
 /// All fields in this struct can be configured using environment variables.
@@ -148,6 +222,10 @@ pub struct PlatformConfig {
     #[serde(default = "PlatformConfig::default_verify_sum_trees")]
     pub verify_sum_trees: bool,
 
+    /// Credit supply invariant checker config
+    #[serde(flatten)]
+    pub credit_supply_check: CreditSupplyCheckConfig,
+
     /// The default quorum type
     pub quorum_type: String,
 
@@ -168,6 +246,10 @@ pub struct PlatformConfig {
     /// Path to data storage
     pub db_path: PathBuf,
 
+    /// Checkpoints and read-only follower config
+    #[serde(flatten)]
+    pub follower: FollowerConfig,
+
     // todo: put this in tests like #[cfg(test)]
     /// This should be None, except in the case of Testing platform
     #[serde(skip)]
@@ -228,6 +310,7 @@ impl Default for PlatformConfig {
     fn default() -> Self {
         Self {
             verify_sum_trees: true,
+            credit_supply_check: Default::default(),
             quorum_type: "llmq_100_67".to_string(),
             quorum_size: 100,
             block_spacing_ms: 5000,
@@ -236,6 +319,7 @@ impl Default for PlatformConfig {
             abci: Default::default(),
             core: Default::default(),
             db_path: PathBuf::from("/var/lib/dash-platform/data"),
+            follower: Default::default(),
             testing_configs: PlatformTestConfig::default(),
             initial_protocol_version: 1,
         }
@@ -272,7 +356,8 @@ impl Default for PlatformTestConfig {
 
 #[cfg(test)]
 mod tests {
-    use super::FromEnv;
+    use super::{CreditSupplyCheckConfig, FromEnv};
+    use crate::error::Error;
     use dashcore_rpc::dashcore_rpc_json::QuorumType;
     use std::env;
 
@@ -297,4 +382,29 @@ mod tests {
             assert_eq!(config.abci.log[*id].destination.as_str(), "bytes");
         }
     }
+
+    #[test]
+    fn test_credit_supply_check_config_rejects_invalid_values() {
+        let config: CreditSupplyCheckConfig = envy::from_iter([
+            ("CREDIT_SUPPLY_CHECK_INTERVAL".to_string(), "10".to_string()),
+            ("CREDIT_SUPPLY_CHECK_DEBUG".to_string(), "true".to_string()),
+        ])
+        .expect("valid config");
+        assert_eq!(config.interval, 10);
+        assert!(config.debug);
+
+        let result = envy::from_iter::<_, CreditSupplyCheckConfig>([(
+            "CREDIT_SUPPLY_CHECK_INTERVAL".to_string(),
+            "ten".to_string(),
+        )])
+        .map_err(Error::from);
+        assert!(matches!(result, Err(Error::Configuration(_))));
+
+        let result = envy::from_iter::<_, CreditSupplyCheckConfig>([(
+            "CREDIT_SUPPLY_CHECK_DEBUG".to_string(),
+            "yes".to_string(),
+        )])
+        .map_err(Error::from);
+        assert!(matches!(result, Err(Error::Configuration(_))));
+    }
 }
@@ -121,4 +121,8 @@ pub enum ExecutionError {
     /// General Bls Error
     #[error("bls error: {0}")]
     BlsErrorGeneral(#[from] BlsError),
+
+    /// Credits were created or destroyed, the credit supply invariant doesn't hold.
+    #[error("credit supply invariant violated: {0}")]
+    CreditSupplyInvariantViolated(String),
 }
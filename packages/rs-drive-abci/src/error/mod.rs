@@ -48,6 +48,12 @@ pub enum Error {
     /// Error from metrics subsystem
     #[error("metrics: {0}")]
     Metrics(#[from] crate::metrics::Error),
+    /// Checkpoints and read-only follower error
+    #[error("follower: {0}")]
+    Follower(#[from] crate::follower::error::FollowerError),
+    /// HTTP query gateway error
+    #[error("query gateway: {0}")]
+    QueryGateway(#[from] crate::query::gateway::error::QueryGatewayError),
 }
 
 impl From<PlatformVersionError> for Error {
@@ -0,0 +1,115 @@
+use crate::event_bus::state_changes::DocumentChange;
+use dpp::block::epoch::EpochIndex;
+use dpp::block::extended_block_info::ExtendedBlockInfo;
+use dpp::platform_value::Identifier;
+use dpp::util::deserializer::ProtocolVersion;
+
+/// Kind of a platform event, subscribers choose the kinds of events they receive
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlatformEventKind {
+    /// See [PlatformEvent::BlockCommitted]
+    BlockCommitted,
+    /// See [PlatformEvent::EpochChanged]
+    EpochChanged,
+    /// See [PlatformEvent::ProtocolVersionChanged]
+    ProtocolVersionChanged,
+    /// See [PlatformEvent::ValidatorSetRotated]
+    ValidatorSetRotated,
+    /// See [PlatformEvent::DocumentChanged]
+    DocumentChanged,
+    /// See [PlatformEvent::IdentityChanged]
+    IdentityChanged,
+}
+
+impl PlatformEventKind {
+    /// All kinds of events
+    pub const ALL: [PlatformEventKind; 6] = [
+        PlatformEventKind::BlockCommitted,
+        PlatformEventKind::EpochChanged,
+        PlatformEventKind::ProtocolVersionChanged,
+        PlatformEventKind::ValidatorSetRotated,
+        PlatformEventKind::DocumentChanged,
+        PlatformEventKind::IdentityChanged,
+    ];
+
+    /// Name of the kind, used as a metrics label
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlatformEventKind::BlockCommitted => "block_committed",
+            PlatformEventKind::EpochChanged => "epoch_changed",
+            PlatformEventKind::ProtocolVersionChanged => "protocol_version_changed",
+            PlatformEventKind::ValidatorSetRotated => "validator_set_rotated",
+            PlatformEventKind::DocumentChanged => "document_changed",
+            PlatformEventKind::IdentityChanged => "identity_changed",
+        }
+    }
+}
+
+/// A change of Platform state, published after the block that made it is committed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlatformEvent {
+    /// A block was committed, it's published after all other events of the block
+    BlockCommitted {
+        /// The committed block
+        block_info: ExtendedBlockInfo,
+    },
+    /// The first block of an epoch was committed
+    EpochChanged {
+        /// The height of the block
+        height: u64,
+        /// The previous epoch, none at genesis
+        previous_epoch_index: Option<EpochIndex>,
+        /// The new epoch
+        epoch_index: EpochIndex,
+    },
+    /// The protocol version in consensus changed
+    ProtocolVersionChanged {
+        /// The height of the block
+        height: u64,
+        /// The previous protocol version
+        previous_protocol_version: ProtocolVersion,
+        /// The new protocol version
+        protocol_version: ProtocolVersion,
+    },
+    /// Another quorum became the validator set
+    ValidatorSetRotated {
+        /// The height of the block
+        height: u64,
+        /// The quorum hash of the previous validator set
+        previous_quorum_hash: [u8; 32],
+        /// The quorum hash of the new validator set
+        quorum_hash: [u8; 32],
+    },
+    /// A document was created, replaced or deleted by a state transition or at the end of a block,
+    /// like withdrawal status updates, awarded contested resources and purged DPNS documents
+    DocumentChanged {
+        /// The height of the block
+        height: u64,
+        /// The change
+        change: DocumentChange,
+    },
+    /// An identity, its keys or its balance were changed by a state transition or at the end
+    /// of a block, like refunds and fee pool distributions
+    IdentityChanged {
+        /// The height of the block
+        height: u64,
+        /// The changed identity
+        identity_id: Identifier,
+    },
+}
+
+impl PlatformEvent {
+    /// The kind of the event
+    pub fn kind(&self) -> PlatformEventKind {
+        match self {
+            PlatformEvent::BlockCommitted { .. } => PlatformEventKind::BlockCommitted,
+            PlatformEvent::EpochChanged { .. } => PlatformEventKind::EpochChanged,
+            PlatformEvent::ProtocolVersionChanged { .. } => {
+                PlatformEventKind::ProtocolVersionChanged
+            }
+            PlatformEvent::ValidatorSetRotated { .. } => PlatformEventKind::ValidatorSetRotated,
+            PlatformEvent::DocumentChanged { .. } => PlatformEventKind::DocumentChanged,
+            PlatformEvent::IdentityChanged { .. } => PlatformEventKind::IdentityChanged,
+        }
+    }
+}
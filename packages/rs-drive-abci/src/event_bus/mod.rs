@@ -0,0 +1,184 @@
+//! In-process event bus.
+//!
+//! Components embedded in the same process as drive-abci, like a query cache, an indexer
+//! or a websocket server, subscribe to the bus to learn about state changes. Events are
+//! published once the block that made them is committed.
+//!
+//! Every subscriber gets its own bounded channel. Publishing never waits for a subscriber:
+//! events that don't fit into a full channel are dropped for that subscriber and counted,
+//! so a slow consumer can't stall consensus. Consumers that can't tolerate gaps should
+//! check [PlatformEventSubscription::missed_events] and resync from the state.
+
+mod events;
+mod state_changes;
+
+pub use events::{PlatformEvent, PlatformEventKind};
+pub use state_changes::{DocumentChange, DocumentChangeKind, StateChanges};
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::{TryRecvError, TrySendError};
+
+/// Typed publish/subscribe of Platform events
+#[derive(Default)]
+pub struct PlatformEventBus {
+    subscribers: Mutex<Vec<Subscriber>>,
+}
+
+struct Subscriber {
+    kinds: BTreeSet<PlatformEventKind>,
+    sender: mpsc::Sender<PlatformEvent>,
+    missed_events: Arc<AtomicU64>,
+}
+
+/// Receiving side of a subscription, the subscription ends when it's dropped
+pub struct PlatformEventSubscription {
+    receiver: mpsc::Receiver<PlatformEvent>,
+    missed_events: Arc<AtomicU64>,
+}
+
+impl PlatformEventSubscription {
+    /// Receives the next event, waiting for it if there is none yet
+    pub async fn recv(&mut self) -> Option<PlatformEvent> {
+        self.receiver.recv().await
+    }
+
+    /// Receives the next event, blocking the thread until there is one.
+    ///
+    /// Must not be called from an async context.
+    pub fn blocking_recv(&mut self) -> Option<PlatformEvent> {
+        self.receiver.blocking_recv()
+    }
+
+    /// Receives the next event if there is one
+    pub fn try_recv(&mut self) -> Result<PlatformEvent, TryRecvError> {
+        self.receiver.try_recv()
+    }
+
+    /// Number of events dropped because the channel was full
+    pub fn missed_events(&self) -> u64 {
+        self.missed_events.load(Ordering::Relaxed)
+    }
+}
+
+impl PlatformEventBus {
+    /// Subscribes to events of the given kinds.
+    ///
+    /// Up to `capacity` events are buffered for the subscriber, at least one.
+    pub fn subscribe(
+        &self,
+        kinds: &[PlatformEventKind],
+        capacity: usize,
+    ) -> PlatformEventSubscription {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        let missed_events = Arc::new(AtomicU64::new(0));
+
+        self.subscribers.lock().unwrap().push(Subscriber {
+            kinds: kinds.iter().copied().collect(),
+            sender,
+            missed_events: Arc::clone(&missed_events),
+        });
+
+        PlatformEventSubscription {
+            receiver,
+            missed_events,
+        }
+    }
+
+    /// Number of active subscriptions
+    pub fn subscribers(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+
+    /// Sends events to subscribers of their kinds without waiting for any of them
+    pub fn publish(&self, events: impl IntoIterator<Item = PlatformEvent>) {
+        let mut subscribers = self.subscribers.lock().unwrap();
+
+        if subscribers.is_empty() {
+            return;
+        }
+
+        for event in events {
+            let kind = event.kind();
+
+            subscribers.retain(|subscriber| {
+                if !subscriber.kinds.contains(&kind) {
+                    return !subscriber.sender.is_closed();
+                }
+
+                match subscriber.sender.try_send(event.clone()) {
+                    Ok(()) => true,
+                    Err(TrySendError::Full(_)) => {
+                        subscriber.missed_events.fetch_add(1, Ordering::Relaxed);
+                        crate::metrics::platform_event_dropped(kind);
+                        true
+                    }
+                    // The subscription was dropped
+                    Err(TrySendError::Closed(_)) => false,
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::platform_value::Identifier;
+
+    fn identity_changed(height: u64) -> PlatformEvent {
+        PlatformEvent::IdentityChanged {
+            height,
+            identity_id: Identifier::new([1; 32]),
+        }
+    }
+
+    #[test]
+    fn should_deliver_events_of_subscribed_kinds_only() {
+        let bus = PlatformEventBus::default();
+
+        let mut identities = bus.subscribe(&[PlatformEventKind::IdentityChanged], 10);
+        let mut epochs = bus.subscribe(&[PlatformEventKind::EpochChanged], 10);
+
+        bus.publish([identity_changed(1)]);
+
+        assert_eq!(identities.try_recv(), Ok(identity_changed(1)));
+        assert_eq!(identities.try_recv(), Err(TryRecvError::Empty));
+        assert_eq!(epochs.try_recv(), Err(TryRecvError::Empty));
+    }
+
+    #[test]
+    fn should_drop_events_for_full_subscribers() {
+        let bus = PlatformEventBus::default();
+
+        let mut subscription = bus.subscribe(&PlatformEventKind::ALL, 2);
+
+        bus.publish((1..=5).map(identity_changed));
+
+        assert_eq!(subscription.missed_events(), 3);
+        assert_eq!(subscription.try_recv(), Ok(identity_changed(1)));
+        assert_eq!(subscription.try_recv(), Ok(identity_changed(2)));
+        assert_eq!(subscription.try_recv(), Err(TryRecvError::Empty));
+
+        bus.publish([identity_changed(6)]);
+
+        assert_eq!(subscription.try_recv(), Ok(identity_changed(6)));
+    }
+
+    #[test]
+    fn should_remove_dropped_subscriptions() {
+        let bus = PlatformEventBus::default();
+
+        let subscription = bus.subscribe(&PlatformEventKind::ALL, 1);
+
+        assert_eq!(bus.subscribers(), 1);
+
+        drop(subscription);
+
+        bus.publish([identity_changed(1)]);
+
+        assert_eq!(bus.subscribers(), 0);
+    }
+}
@@ -0,0 +1,298 @@
+use crate::event_bus::PlatformEvent;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::platform_value::Identifier;
+use dpp::system_data_contracts::withdrawals_contract;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+use drive::drive::batch::drive_op_batch::{DocumentOperation, UpdateOperationInfo};
+use drive::drive::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use drive::drive::object_size_info::{DocumentInfo, DocumentInfoV0Methods};
+use std::collections::BTreeSet;
+
+/// How a document was changed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DocumentChangeKind {
+    /// The document was created
+    Created,
+    /// The document was replaced with a new revision
+    Replaced,
+    /// The document was deleted
+    Deleted,
+}
+
+/// A document changed by a state transition or at the end of a block
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocumentChange {
+    /// The data contract of the document
+    pub data_contract_id: Identifier,
+    /// The document type of the document
+    pub document_type_name: String,
+    /// The document
+    pub document_id: Identifier,
+    /// How the document was changed
+    pub kind: DocumentChangeKind,
+}
+
+/// Documents and identities changed by executed state transitions and at the end of a block
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateChanges {
+    /// Changed documents, in the order of changes
+    pub documents: Vec<DocumentChange>,
+    /// Changed identities
+    pub identities: BTreeSet<Identifier>,
+}
+
+impl StateChanges {
+    /// Moves all changes of `other` to self
+    pub fn append(&mut self, mut other: StateChanges) {
+        self.documents.append(&mut other.documents);
+        self.identities.append(&mut other.identities);
+    }
+
+    /// Adds documents and identities changed by Drive operations
+    pub fn add_drive_operations(&mut self, operations: &[DriveOperation]) {
+        for operation in operations {
+            match operation {
+                DriveOperation::DocumentOperation(document_operation) => {
+                    self.add_document_operation(document_operation)
+                }
+                DriveOperation::IdentityOperation(identity_operation) => {
+                    self.identities
+                        .insert(Self::identity_id_of_operation(identity_operation));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Events of the changes made in the block at the given height
+    pub fn into_events(self, height: u64) -> impl Iterator<Item = PlatformEvent> {
+        let document_events = self
+            .documents
+            .into_iter()
+            .map(move |change| PlatformEvent::DocumentChanged { height, change });
+
+        let identity_events =
+            self.identities
+                .into_iter()
+                .map(move |identity_id| PlatformEvent::IdentityChanged {
+                    height,
+                    identity_id,
+                });
+
+        document_events.chain(identity_events)
+    }
+
+    fn add_document_operation(&mut self, operation: &DocumentOperationType) {
+        let change = match operation {
+            DocumentOperationType::AddDocument {
+                owned_document_info,
+                contract_id,
+                document_type_name,
+                ..
+            } => Self::document_id_of_info(&owned_document_info.document_info).map(|document_id| {
+                DocumentChange {
+                    data_contract_id: *contract_id,
+                    document_type_name: document_type_name.to_string(),
+                    document_id,
+                    kind: DocumentChangeKind::Created,
+                }
+            }),
+            DocumentOperationType::AddWithdrawalDocument {
+                owned_document_info,
+            } => Self::document_id_of_info(&owned_document_info.document_info).map(|document_id| {
+                DocumentChange {
+                    data_contract_id: withdrawals_contract::ID,
+                    document_type_name: withdrawal::NAME.to_string(),
+                    document_id,
+                    kind: DocumentChangeKind::Created,
+                }
+            }),
+            DocumentOperationType::AddDocumentForContract {
+                document_and_contract_info,
+                ..
+            } => Self::document_id_of_info(
+                &document_and_contract_info.owned_document_info.document_info,
+            )
+            .map(|document_id| DocumentChange {
+                data_contract_id: document_and_contract_info.contract.id(),
+                document_type_name: document_and_contract_info.document_type.name().to_string(),
+                document_id,
+                kind: DocumentChangeKind::Created,
+            }),
+            DocumentOperationType::UpdateDocument {
+                owned_document_info,
+                contract_id,
+                document_type_name,
+            } => Self::document_id_of_info(&owned_document_info.document_info).map(|document_id| {
+                DocumentChange {
+                    data_contract_id: *contract_id,
+                    document_type_name: document_type_name.to_string(),
+                    document_id,
+                    kind: DocumentChangeKind::Replaced,
+                }
+            }),
+            DocumentOperationType::UpdateDocumentForContract {
+                document,
+                contract,
+                document_type_name,
+                ..
+            } => Some(DocumentChange {
+                data_contract_id: contract.id(),
+                document_type_name: document_type_name.to_string(),
+                document_id: document.id(),
+                kind: DocumentChangeKind::Replaced,
+            }),
+            DocumentOperationType::DeleteDocumentOfNamedTypeForContractId {
+                document_id,
+                contract_id,
+                document_type_name,
+            } => Some(DocumentChange {
+                data_contract_id: Identifier::new(*contract_id),
+                document_type_name: document_type_name.to_string(),
+                document_id: Identifier::new(*document_id),
+                kind: DocumentChangeKind::Deleted,
+            }),
+            DocumentOperationType::DeleteDocumentOfNamedTypeForContract {
+                document_id,
+                contract,
+                document_type_name,
+            } => Some(DocumentChange {
+                data_contract_id: contract.id(),
+                document_type_name: document_type_name.to_string(),
+                document_id: Identifier::new(*document_id),
+                kind: DocumentChangeKind::Deleted,
+            }),
+            DocumentOperationType::DeleteDocumentForContract {
+                document_id,
+                contract,
+                document_type,
+            } => Some(DocumentChange {
+                data_contract_id: contract.id(),
+                document_type_name: document_type.name().to_string(),
+                document_id: Identifier::new(*document_id),
+                kind: DocumentChangeKind::Deleted,
+            }),
+            DocumentOperationType::MultipleDocumentOperationsForSameContractDocumentType {
+                document_operations,
+            } => {
+                let data_contract_id = document_operations.contract.id();
+                let document_type_name = document_operations.document_type.name();
+
+                for document_operation in &document_operations.operations {
+                    let change = match document_operation {
+                        DocumentOperation::AddOperation {
+                            owned_document_info,
+                            ..
+                        } => Self::document_id_of_info(&owned_document_info.document_info)
+                            .map(|document_id| (document_id, DocumentChangeKind::Created)),
+                        DocumentOperation::UpdateOperation(UpdateOperationInfo {
+                            document,
+                            ..
+                        }) => Some((document.id(), DocumentChangeKind::Replaced)),
+                    };
+
+                    if let Some((document_id, kind)) = change {
+                        self.documents.push(DocumentChange {
+                            data_contract_id,
+                            document_type_name: document_type_name.to_string(),
+                            document_id,
+                            kind,
+                        });
+                    }
+                }
+
+                None
+            }
+            // Platform never adds or updates serialized documents
+            DocumentOperationType::AddSerializedDocumentForContract { .. }
+            | DocumentOperationType::UpdateSerializedDocumentForContract { .. } => None,
+        };
+
+        if let Some(change) = change {
+            self.documents.push(change);
+        }
+    }
+
+    fn document_id_of_info(document_info: &DocumentInfo) -> Option<Identifier> {
+        document_info
+            .get_borrowed_document()
+            .map(|document| document.id())
+    }
+
+    fn identity_id_of_operation(operation: &IdentityOperationType) -> Identifier {
+        match operation {
+            IdentityOperationType::AddNewIdentity { identity } => identity.id(),
+            IdentityOperationType::AddToIdentityBalance { identity_id, .. }
+            | IdentityOperationType::RemoveFromIdentityBalance { identity_id, .. }
+            | IdentityOperationType::AddNewKeysToIdentity { identity_id, .. }
+            | IdentityOperationType::DisableIdentityKeys { identity_id, .. }
+            | IdentityOperationType::ReEnableIdentityKeys { identity_id, .. }
+            | IdentityOperationType::UpdateIdentityRevision { identity_id, .. } => {
+                Identifier::new(*identity_id)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn should_collect_changes_of_drive_operations() {
+        let mut state_changes = StateChanges::default();
+
+        state_changes.add_drive_operations(&[
+            DriveOperation::IdentityOperation(IdentityOperationType::RemoveFromIdentityBalance {
+                identity_id: [1; 32],
+                balance_to_remove: 10,
+            }),
+            DriveOperation::IdentityOperation(IdentityOperationType::AddToIdentityBalance {
+                identity_id: [2; 32],
+                added_balance: 10,
+            }),
+            DriveOperation::DocumentOperation(
+                DocumentOperationType::DeleteDocumentOfNamedTypeForContractId {
+                    document_id: [3; 32],
+                    contract_id: [4; 32],
+                    document_type_name: Cow::Owned("note".to_string()),
+                },
+            ),
+        ]);
+
+        assert_eq!(
+            state_changes.clone().into_events(7).collect::<Vec<_>>(),
+            vec![
+                PlatformEvent::DocumentChanged {
+                    height: 7,
+                    change: DocumentChange {
+                        data_contract_id: Identifier::new([4; 32]),
+                        document_type_name: "note".to_string(),
+                        document_id: Identifier::new([3; 32]),
+                        kind: DocumentChangeKind::Deleted,
+                    },
+                },
+                PlatformEvent::IdentityChanged {
+                    height: 7,
+                    identity_id: Identifier::new([1; 32]),
+                },
+                PlatformEvent::IdentityChanged {
+                    height: 7,
+                    identity_id: Identifier::new([2; 32]),
+                },
+            ]
+        );
+
+        let mut block_changes = StateChanges::default();
+
+        block_changes.append(state_changes.clone());
+        block_changes.append(state_changes);
+
+        assert_eq!(block_changes.documents.len(), 2);
+        assert_eq!(block_changes.identities.len(), 2);
+    }
+}
@@ -8,6 +8,7 @@ use drive::grovedb::Transaction;
 
 use dpp::block::block_info::BlockInfo;
 use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0;
+use dpp::block::extended_block_info::ExtendedBlockInfo;
 use dpp::version::PlatformVersion;
 
 use tenderdash_abci::{
@@ -19,6 +20,7 @@ use crate::abci::AbciError;
 use crate::error::execution::ExecutionError;
 
 use crate::error::Error;
+use crate::event_bus::PlatformEvent;
 use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
 use crate::execution::types::block_state_info::v0::{
     BlockStateInfoV0Getters, BlockStateInfoV0Methods,
@@ -133,7 +135,8 @@ where
         }
 
         let state_cache = self.state.read().unwrap();
-        let current_quorum_hash = state_cache.current_validator_set_quorum_hash().into();
+        let current_quorum_hash: [u8; 32] = state_cache.current_validator_set_quorum_hash().into();
+        let previous_protocol_version = state_cache.current_protocol_version_in_consensus();
         if current_quorum_hash != commit_info.quorum_hash {
             validation_result.add_error(AbciError::WrongFinalizeBlockReceived(format!(
                 "received a block for h: {} r: {} with validator set quorum hash {} expected current validator set quorum hash is {}",
@@ -202,14 +205,32 @@ where
 
         to_commit_block_info.core_height = block_header.core_chain_locked_height;
 
+        let epoch_index = to_commit_block_info.epoch.index;
+
         // // Finalize withdrawal processing
         // our_withdrawals.finalize(Some(transaction), &self.drive, &to_commit_block_info)?;
 
-        // At the end we update the state cache
+        // Events are published by the caller once the block is committed
+        let mut events = vec![];
+
+        if epoch_info.is_epoch_change() {
+            events.push(PlatformEvent::EpochChanged {
+                height,
+                previous_epoch_index: epoch_info.previous_epoch_index(),
+                epoch_index,
+            });
+        }
+
+        let state_changes = block_execution_context.state_changes().clone();
 
         drop(guarded_block_execution_context);
 
-        let extended_block_info = ExtendedBlockInfoV0 {
+        // Halt before committing if credits were created or destroyed
+        self.verify_credit_supply(height, transaction, platform_version)?;
+
+        // At the end we update the state cache
+
+        let extended_block_info: ExtendedBlockInfo = ExtendedBlockInfoV0 {
             basic_info: to_commit_block_info,
             app_hash: block_header.app_hash,
             quorum_hash: current_quorum_hash,
@@ -219,7 +240,34 @@ where
         }
         .into();
 
-        self.update_state_cache(extended_block_info, transaction, platform_version)?;
+        self.update_state_cache(extended_block_info.clone(), transaction, platform_version)?;
+
+        let state_cache = self.state.read().unwrap();
+        let protocol_version = state_cache.current_protocol_version_in_consensus();
+        let quorum_hash: [u8; 32] = state_cache.current_validator_set_quorum_hash().into();
+        drop(state_cache);
+
+        if protocol_version != previous_protocol_version {
+            events.push(PlatformEvent::ProtocolVersionChanged {
+                height,
+                previous_protocol_version,
+                protocol_version,
+            });
+        }
+
+        if quorum_hash != current_quorum_hash {
+            events.push(PlatformEvent::ValidatorSetRotated {
+                height,
+                previous_quorum_hash: current_quorum_hash,
+                quorum_hash,
+            });
+        }
+
+        events.extend(state_changes.into_events(height));
+
+        events.push(PlatformEvent::BlockCommitted {
+            block_info: extended_block_info,
+        });
 
         let mut drive_cache = self.drive.cache.write().unwrap();
 
@@ -229,7 +277,11 @@ where
         crate::metrics::abci_last_block_time(block_header.time.seconds as u64);
         crate::metrics::abci_last_platform_height(height);
         crate::metrics::abci_last_finalized_round(round);
+        crate::metrics::proposed_block(&block_header.proposer_pro_tx_hash);
 
-        Ok(validation_result.into())
+        Ok(block_execution_outcome::v0::BlockFinalizationOutcome {
+            validation_result,
+            events,
+        })
     }
 }
@@ -14,6 +14,7 @@ use crate::abci::AbciError;
 use crate::error::execution::ExecutionError;
 
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::v0::{
     BlockExecutionContextV0Getters, BlockExecutionContextV0MutableGetters,
     BlockExecutionContextV0Setters,
@@ -141,6 +142,7 @@ where
             withdrawal_transactions: BTreeMap::new(),
             block_platform_state,
             proposer_results: None,
+            state_changes: Default::default(),
         };
 
         // If last synced Core block height is not set instead of scanning
@@ -196,9 +198,13 @@ where
 
         let mut block_execution_context: BlockExecutionContext = block_execution_context.into();
 
+        // Documents and identities changed by the block, published once it's committed
+        let mut state_changes = StateChanges::default();
+
         self.update_broadcasted_withdrawal_transaction_statuses(
             last_synced_core_height,
             &block_execution_context,
+            &mut state_changes,
             transaction,
             platform_version,
         )?;
@@ -225,22 +231,44 @@ where
                 .collect(),
         );
 
-        let (block_fees, tx_results) = self.process_raw_state_transitions(
-            raw_state_transitions,
-            block_execution_context.block_platform_state(),
-            &block_info,
-            transaction,
-            platform_version,
-        )?;
+        let (mut block_fees, tx_results, state_transitions_changes) = self
+            .process_raw_state_transitions(
+                raw_state_transitions,
+                block_execution_context.block_platform_state(),
+                &block_info,
+                transaction,
+                platform_version,
+            )?;
+
+        state_changes.append(state_transitions_changes);
 
         let mut block_execution_context: BlockExecutionContext = block_execution_context;
 
         self.pool_withdrawals_into_transactions_queue(
             &block_execution_context,
+            &mut state_changes,
+            transaction,
+            platform_version,
+        )?;
+
+        self.clean_up_expired_dpns_documents(
+            &block_info,
+            &mut state_changes,
             transaction,
             platform_version,
         )?;
 
+        // Refunds of contenders that lost a contested resource are paid from the epochs
+        // their storage fees were distributed to, like the refunds of state transitions
+        let contested_resource_refunds = self.resolve_ended_contested_resources(
+            &block_info,
+            &mut state_changes,
+            transaction,
+            platform_version,
+        )?;
+
+        block_fees.checked_add_assign(contested_resource_refunds)?;
+
         // while we have the state transitions executed, we now need to process the block fees
 
         let block_fees_v0: BlockFeesV0 = block_fees.into();
@@ -250,10 +278,28 @@ where
             block_execution_context.block_state_info(),
             &epoch_info,
             block_fees_v0.into(),
+            &mut state_changes,
             transaction,
             platform_version,
         )?;
 
+        // The first block of a new protocol version is executed with the previous one,
+        // so the state is migrated before the block is finalized with the new one
+        let new_protocol_version = block_execution_context
+            .block_platform_state()
+            .current_protocol_version_in_consensus();
+
+        if new_protocol_version != platform_version.protocol_version {
+            self.perform_events_on_first_block_of_protocol_change(
+                platform_version.protocol_version,
+                &block_info,
+                transaction,
+                PlatformVersion::get(new_protocol_version)?,
+            )?;
+        }
+
+        block_execution_context.set_state_changes(state_changes);
+
         let root_hash = self
             .drive
             .grove
@@ -266,8 +312,12 @@ where
             .set_app_hash(Some(root_hash));
 
         let state = self.state.read().unwrap();
-        let validator_set_update =
-            self.validator_set_update(&state, &mut block_execution_context, platform_version)?;
+        let validator_set_update = self.validator_set_update(
+            &state,
+            &mut block_execution_context,
+            transaction,
+            platform_version,
+        )?;
 
         self.block_execution_context
             .write()
@@ -0,0 +1,60 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::event_bus::StateChanges;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Releases DPNS domains that weren't renewed within the grace period after they expired
+    /// and purges stale preorders.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the clean_up_expired_dpns_documents function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block.
+    /// * `state_changes` - Collects released domains and purged preorders.
+    /// * `transaction` - The current block transaction.
+    /// * `platform_version` - The platform version with the registration, grace and preorder periods.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns `Ok(())` if successful, otherwise returns an `Error`.
+    pub(in crate::execution) fn clean_up_expired_dpns_documents(
+        &self,
+        block_info: &BlockInfo,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .clean_up_expired_dpns_documents
+        {
+            Some(0) => self.clean_up_expired_dpns_documents_v0(
+                block_info,
+                state_changes,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "clean_up_expired_dpns_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // DPNS domains don't expire in this protocol version
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,433 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::event_bus::StateChanges;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::document_types::{domain, preorder};
+use dpp::version::PlatformVersion;
+use drive::drive::batch::{DocumentOperationType, DriveOperation};
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::grovedb::Transaction;
+use drive::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn clean_up_expired_dpns_documents_v0(
+        &self,
+        block_info: &BlockInfo,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let dpns_parameters = &platform_version.drive_abci.dpns;
+
+        let (_, Some(contract_fetch_info)) = self.drive.get_contract_with_fetch_info_and_fee(
+            dpns_contract::ID.to_buffer(),
+            None,
+            true,
+            Some(transaction),
+            platform_version,
+        )?
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "can't fetch dpns data contract",
+            )));
+        };
+
+        let contract = &contract_fetch_info.contract;
+
+        let released_before = block_info.time_ms.saturating_sub(
+            dpns_parameters
+                .registration_period_ms
+                .saturating_add(dpns_parameters.grace_period_ms),
+        );
+
+        let released_domains = self.fetch_dpns_documents_before_v0(
+            contract,
+            domain::NAME,
+            "$updatedAt",
+            released_before,
+            transaction,
+            platform_version,
+        )?;
+
+        let purged_before = block_info
+            .time_ms
+            .saturating_sub(dpns_parameters.preorder_lifetime_ms);
+
+        let stale_preorders = self.fetch_dpns_documents_before_v0(
+            contract,
+            preorder::NAME,
+            "$createdAt",
+            purged_before,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut drive_operations = vec![];
+
+        for released_domain in released_domains {
+            // Top level domains are registered by the DPNS contract owner and never expire
+            let is_top_level_domain = released_domain
+                .properties()
+                .get(domain::properties::NORMALIZED_PARENT_DOMAIN_NAME)
+                .and_then(|parent_domain_name| parent_domain_name.as_text())
+                .map(|parent_domain_name| parent_domain_name.is_empty())
+                .unwrap_or_default();
+
+            if is_top_level_domain {
+                continue;
+            }
+
+            drive_operations.push(DriveOperation::DocumentOperation(
+                DocumentOperationType::DeleteDocumentOfNamedTypeForContract {
+                    document_id: released_domain.id().to_buffer(),
+                    contract,
+                    document_type_name: domain::NAME,
+                },
+            ));
+        }
+
+        for stale_preorder in stale_preorders {
+            drive_operations.push(DriveOperation::DocumentOperation(
+                DocumentOperationType::DeleteDocumentOfNamedTypeForContract {
+                    document_id: stale_preorder.id().to_buffer(),
+                    contract,
+                    document_type_name: preorder::NAME,
+                },
+            ));
+        }
+
+        if drive_operations.is_empty() {
+            return Ok(());
+        }
+
+        state_changes.add_drive_operations(&drive_operations);
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+
+    /// Fetches the oldest documents of a DPNS document type with a timestamp before the given time
+    fn fetch_dpns_documents_before_v0(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        timestamp_field: &str,
+        before_time_ms: u64,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Document>, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        // DPNS contracts created before domains expired don't store and index the timestamps
+        if !document_type.required_fields().contains(timestamp_field) {
+            return Ok(vec![]);
+        }
+
+        let drive_query = DriveQuery {
+            contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: Some(WhereClause {
+                    field: timestamp_field.to_string(),
+                    operator: WhereOperator::LessThan,
+                    value: Value::U64(before_time_ms),
+                }),
+                equal_clauses: BTreeMap::default(),
+            },
+            offset: None,
+            limit: Some(
+                platform_version
+                    .drive_abci
+                    .dpns
+                    .max_cleaned_up_documents_per_block,
+            ),
+            order_by: IndexMap::from([(
+                timestamp_field.to_string(),
+                OrderClause {
+                    field: timestamp_field.to_string(),
+                    ascending: true,
+                },
+            )]),
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        let documents = self
+            .drive
+            .query_documents(
+                drive_query,
+                None,
+                false,
+                Some(transaction),
+                Some(platform_version.protocol_version),
+            )?
+            .documents_owned();
+
+        Ok(documents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::{DocumentChange, DocumentChangeKind};
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::document::{DocumentV0, DocumentV0Setters};
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::{get_dpns_parent_document_fixture, ParentDocumentOptions};
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use drive::drive::object_size_info::{
+        DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo,
+    };
+
+    #[test]
+    fn should_release_expired_domains_except_top_level_domains() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+        let contract = &contract_fetch_info.contract;
+
+        let mut document = get_dpns_parent_document_fixture(
+            ParentDocumentOptions {
+                label: "alice".to_string(),
+                normalized_label: "alice".to_string(),
+                owner_id: generate_random_identifier_struct(),
+            },
+            platform_version.protocol_version,
+        );
+        document.set(
+            domain::properties::NORMALIZED_PARENT_DOMAIN_NAME,
+            Value::Text("dash".to_string()),
+        );
+        document.set_created_at(Some(1));
+        document.set_updated_at(Some(1));
+
+        platform
+            .drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentInfo::DocumentRefInfo((&document, None)),
+                        owner_id: None,
+                    },
+                    contract,
+                    document_type: contract
+                        .document_type_for_name(domain::NAME)
+                        .expect("expected the domain document type"),
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add the domain");
+
+        let dpns_parameters = &platform_version.drive_abci.dpns;
+        let block_info = BlockInfo::default_with_time(
+            dpns_parameters.registration_period_ms + dpns_parameters.grace_period_ms + 2,
+        );
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let mut state_changes = StateChanges::default();
+
+        platform
+            .clean_up_expired_dpns_documents_v0(
+                &block_info,
+                &mut state_changes,
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to clean up expired dpns documents");
+
+        let remaining_domains = platform
+            .fetch_dpns_documents_before_v0(
+                contract,
+                domain::NAME,
+                "$updatedAt",
+                u64::MAX,
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to fetch domains");
+
+        assert_eq!(remaining_domains.len(), 1);
+        assert_ne!(remaining_domains[0].id(), document.id());
+
+        assert_eq!(
+            state_changes.documents,
+            vec![DocumentChange {
+                data_contract_id: dpns_contract::ID,
+                document_type_name: domain::NAME.to_string(),
+                document_id: document.id(),
+                kind: DocumentChangeKind::Deleted,
+            }]
+        );
+    }
+
+    #[test]
+    fn should_purge_stale_preorders() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+        let contract = &contract_fetch_info.contract;
+
+        let dpns_parameters = &platform_version.drive_abci.dpns;
+        let block_time_ms = dpns_parameters.preorder_lifetime_ms + 10;
+
+        let stale_preorder: Document = DocumentV0 {
+            id: generate_random_identifier_struct(),
+            owner_id: generate_random_identifier_struct(),
+            properties: BTreeMap::from([(
+                preorder::properties::SALTED_DOMAIN_HASH.to_string(),
+                Value::Bytes32([1; 32]),
+            )]),
+            revision: Some(1),
+            created_at: Some(5),
+            updated_at: None,
+        }
+        .into();
+
+        let recent_preorder: Document = DocumentV0 {
+            id: generate_random_identifier_struct(),
+            owner_id: generate_random_identifier_struct(),
+            properties: BTreeMap::from([(
+                preorder::properties::SALTED_DOMAIN_HASH.to_string(),
+                Value::Bytes32([2; 32]),
+            )]),
+            revision: Some(1),
+            created_at: Some(block_time_ms - 5),
+            updated_at: None,
+        }
+        .into();
+
+        for preorder_document in [&stale_preorder, &recent_preorder] {
+            platform
+                .drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentInfo::DocumentRefInfo((preorder_document, None)),
+                            owner_id: None,
+                        },
+                        contract,
+                        document_type: contract
+                            .document_type_for_name(preorder::NAME)
+                            .expect("expected the preorder document type"),
+                    },
+                    false,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add the preorder");
+        }
+
+        let block_info = BlockInfo::default_with_time(block_time_ms);
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        platform
+            .clean_up_expired_dpns_documents_v0(
+                &block_info,
+                &mut StateChanges::default(),
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to clean up expired dpns documents");
+
+        let remaining_preorders = platform
+            .fetch_dpns_documents_before_v0(
+                contract,
+                preorder::NAME,
+                "$createdAt",
+                u64::MAX,
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to fetch preorders");
+
+        assert_eq!(remaining_preorders.len(), 1);
+        assert_eq!(remaining_preorders[0].id(), recent_preorder.id());
+    }
+
+    #[test]
+    fn should_not_clean_up_dpns_contracts_without_timestamps() {
+        let platform_version = PlatformVersion::first();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let contract =
+            load_system_data_contract(SystemDataContract::DPNS, platform_version.protocol_version)
+                .expect("expected to load the version 1 dpns contract");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let documents = platform
+            .fetch_dpns_documents_before_v0(
+                &contract,
+                domain::NAME,
+                "$updatedAt",
+                u64::MAX,
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to skip the query");
+
+        assert!(documents.is_empty());
+    }
+}
@@ -1,6 +1,12 @@
+/// Releasing expired DPNS domains and purging stale preorders
+pub(in crate::execution) mod clean_up_expired_dpns_documents;
+/// Awarding contested resources once their voting period ended
+pub(in crate::execution) mod resolve_ended_contested_resources;
 /// Storage of the ephemeral state
 pub(in crate::execution) mod store_ephemeral_state;
 /// Updating the state cache happens as the final part of block finalization
 pub(in crate::execution) mod update_state_cache;
 /// Validator set update
 pub(in crate::execution) mod validator_set_update;
+/// Verifying that no credits were created or destroyed
+pub(in crate::execution) mod verify_credit_supply;
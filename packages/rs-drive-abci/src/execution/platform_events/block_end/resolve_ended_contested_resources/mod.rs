@@ -0,0 +1,62 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::event_bus::StateChanges;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Awards contested resources whose voting period ended to the contender with the most votes
+    /// and refunds the storage fees of the other contenders.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the resolve_ended_contested_resources function.
+    ///
+    /// # Arguments
+    ///
+    /// * `block_info` - Information about the current block.
+    /// * `state_changes` - Collects awarded documents and refunded contenders.
+    /// * `transaction` - The current block transaction.
+    /// * `platform_version` - The platform version with the voting parameters.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FeeResult, Error>` - The refunds paid out to the losing contenders,
+    ///   they have to be deducted from the epochs their storage fees were paid to.
+    pub(in crate::execution) fn resolve_ended_contested_resources(
+        &self,
+        block_info: &BlockInfo,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .resolve_ended_contested_resources
+        {
+            Some(0) => self.resolve_ended_contested_resources_v0(
+                block_info,
+                state_changes,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "resolve_ended_contested_resources".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // Resources can't be contested in this protocol version
+            None => Ok(FeeResult::default()),
+        }
+    }
+}
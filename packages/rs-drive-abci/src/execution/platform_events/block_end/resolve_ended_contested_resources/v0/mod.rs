@@ -0,0 +1,411 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::event_bus::StateChanges;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::Document;
+use dpp::fee::fee_result::FeeResult;
+use dpp::version::PlatformVersion;
+use drive::drive::batch::{
+    DocumentOperationType, DriveOperation, IdentityOperationType, VoteOperationType,
+};
+use drive::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
+use drive::drive::object_size_info::OwnedDocumentInfo;
+use drive::drive::votes::ContestedResource;
+use drive::grovedb::Transaction;
+use std::borrow::Cow;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn resolve_ended_contested_resources_v0(
+        &self,
+        block_info: &BlockInfo,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let ended_contest_ids = self.drive.fetch_ended_contest_ids(
+            block_info.time_ms,
+            platform_version
+                .drive_abci
+                .voting
+                .max_resolved_contests_per_block,
+            Some(transaction),
+            &platform_version.drive,
+        )?;
+
+        let mut fee_result = FeeResult::default();
+
+        for contest_id in ended_contest_ids {
+            let Some(contested_resource) = self.drive.fetch_contested_resource(
+                &contest_id,
+                Some(transaction),
+                &platform_version.drive,
+            )?
+            else {
+                return Err(Error::Execution(ExecutionError::CorruptedDriveResponse(
+                    "ended contest must exist".to_string(),
+                )));
+            };
+
+            // Contests are resolved one by one, an identity can win one contest
+            // and lose another one in the same block
+            let refunds = self.resolve_contested_resource_v0(
+                contested_resource,
+                block_info,
+                state_changes,
+                transaction,
+                platform_version,
+            )?;
+
+            fee_result.checked_add_assign(refunds)?;
+        }
+
+        Ok(fee_result)
+    }
+
+    /// Removes a contest, awards the document to the winner and refunds the other contenders
+    fn resolve_contested_resource_v0(
+        &self,
+        contested_resource: ContestedResource,
+        block_info: &BlockInfo,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<FeeResult, Error> {
+        let ContestedResource {
+            contest_id,
+            vote_poll,
+            end_time_ms,
+            mut contenders,
+            vote_tally,
+            votes,
+            vote_changes,
+        } = contested_resource;
+
+        // Masternodes abstaining can prevent the resource from being awarded
+        let winner = vote_tally.winner().filter(|winner| {
+            vote_tally
+                .votes_by_contender
+                .get(winner)
+                .is_some_and(|winner_votes| *winner_votes > vote_tally.abstain_votes)
+        });
+
+        let contender_ids = contenders.keys().copied().collect::<Vec<_>>();
+
+        let has_vote_changes = !vote_changes.is_empty();
+
+        let mut drive_operations = vec![DriveOperation::VoteOperation(
+            VoteOperationType::RemoveContestEntries {
+                contest_id,
+                end_time_ms,
+                contender_ids: contender_ids.clone(),
+                votes: votes.into_iter().collect(),
+                vote_changes: vote_changes.into_keys().collect(),
+            },
+        )];
+
+        let winner_document = match winner.and_then(|winner| contenders.remove(&winner)) {
+            Some(contender) => {
+                let (_, Some(contract_fetch_info)) =
+                    self.drive.get_contract_with_fetch_info_and_fee(
+                        vote_poll.contract_id.to_buffer(),
+                        None,
+                        true,
+                        Some(transaction),
+                        platform_version,
+                    )?
+                else {
+                    return Err(Error::Execution(ExecutionError::CorruptedDriveResponse(
+                        "contract of a contest must exist".to_string(),
+                    )));
+                };
+
+                let document_type = contract_fetch_info
+                    .contract
+                    .document_type_for_name(&vote_poll.document_type_name)?;
+
+                let document = Document::from_bytes(
+                    &contender.serialized_document,
+                    document_type,
+                    platform_version,
+                )?;
+
+                Some((document, contender.storage_flags))
+            }
+            None => None,
+        };
+
+        if let Some((document, storage_flags)) = winner_document {
+            // The document keeps the storage flags of the contender,
+            // its storage was already paid for when it became a contender
+            drive_operations.push(DriveOperation::DocumentOperation(
+                DocumentOperationType::AddDocument {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentOwnedInfo((document, storage_flags.map(Cow::Owned))),
+                        owner_id: winner.map(|winner| winner.to_buffer()),
+                    },
+                    contract_id: vote_poll.contract_id,
+                    document_type_name: Cow::Owned(vote_poll.document_type_name.clone()),
+                    override_document: false,
+                },
+            ));
+        }
+
+        state_changes.add_drive_operations(&drive_operations);
+
+        let mut removal_fee_result = self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        // Only the contenders that lost get their storage fees back
+        if let Some(winner) = winner {
+            removal_fee_result.fee_refunds.0.remove(winner.as_bytes());
+        }
+
+        let mut drive_operations = vec![DriveOperation::VoteOperation(
+            VoteOperationType::RemoveContestTrees {
+                contest_id,
+                contender_ids,
+                has_vote_changes,
+            },
+        )];
+
+        for contender_id in contenders.keys() {
+            let Some(refund) = removal_fee_result
+                .fee_refunds
+                .calculate_refunds_amount_for_identity(*contender_id)
+            else {
+                continue;
+            };
+
+            drive_operations.push(DriveOperation::IdentityOperation(
+                IdentityOperationType::AddToIdentityBalance {
+                    identity_id: contender_id.to_buffer(),
+                    added_balance: refund,
+                },
+            ));
+        }
+
+        state_changes.add_drive_operations(&drive_operations);
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        Ok(FeeResult {
+            fee_refunds: removal_fee_result.fee_refunds,
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event_bus::{DocumentChange, DocumentChangeKind};
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::document::DocumentV0Getters;
+    use dpp::platform_value::{Identifier, Value};
+    use dpp::system_data_contracts::dpns_contract;
+    use dpp::system_data_contracts::dpns_contract::document_types::domain;
+    use dpp::tests::fixtures::{get_dpns_parent_document_fixture, ParentDocumentOptions};
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use dpp::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+    use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+    use drive::drive::flags::StorageFlags;
+    use drive::query::DriveQuery;
+
+    #[test]
+    fn should_award_ended_contest_to_the_contender_with_most_votes() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+        let contract = &contract_fetch_info.contract;
+        let document_type = contract
+            .document_type_for_name(domain::NAME)
+            .expect("expected the domain document type");
+
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            contract_id: dpns_contract::ID,
+            document_type_name: domain::NAME.to_string(),
+            index_name: "parentNameAndLabel".to_string(),
+            index_values: vec![Value::Text(String::new()), Value::Text("alice".to_string())],
+        };
+        let contest_id = vote_poll
+            .unique_id()
+            .expect("expected a contest id")
+            .to_buffer();
+
+        let contenders = [
+            generate_random_identifier_struct(),
+            generate_random_identifier_struct(),
+        ];
+
+        let block_info = BlockInfo::default_with_time(1);
+
+        let mut drive_operations = vec![];
+        let mut documents = vec![];
+
+        for contender_id in contenders {
+            let document = get_dpns_parent_document_fixture(
+                ParentDocumentOptions {
+                    label: "alice".to_string(),
+                    normalized_label: "alice".to_string(),
+                    owner_id: contender_id,
+                },
+                platform_version.protocol_version,
+            );
+
+            drive_operations.push(DriveOperation::VoteOperation(
+                VoteOperationType::AddContender {
+                    vote_poll: vote_poll.clone(),
+                    contender_id,
+                    serialized_document: document
+                        .serialize(document_type, platform_version)
+                        .expect("expected to serialize the document"),
+                    storage_flags: StorageFlags::new_single_epoch(0, None),
+                },
+            ));
+
+            documents.push(document);
+        }
+
+        platform
+            .drive
+            .apply_drive_operations(drive_operations, true, &block_info, None, platform_version)
+            .expect("expected to add contenders");
+
+        // The masternode changes its vote, the vote change is removed with the contest
+        for contender_id in contenders {
+            platform
+                .drive
+                .apply_drive_operations(
+                    vec![DriveOperation::VoteOperation(VoteOperationType::CastVote {
+                        vote_poll: vote_poll.clone(),
+                        pro_tx_hash: Identifier::new([1; 32]),
+                        choice: ResourceVoteChoice::TowardsIdentity(contender_id),
+                    })],
+                    true,
+                    &block_info,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to cast a vote");
+        }
+
+        assert_eq!(
+            platform
+                .drive
+                .fetch_masternode_vote_changes(
+                    &contest_id,
+                    Identifier::new([1; 32]),
+                    None,
+                    &mut vec![],
+                    &platform_version.drive,
+                )
+                .expect("expected to fetch the vote changes"),
+            1
+        );
+
+        let end_time_ms = platform
+            .drive
+            .fetch_contest_end_time(&contest_id, None, &platform_version.drive)
+            .expect("expected to fetch the end time")
+            .expect("expected the contest to exist");
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        // Nothing happens while the contest is running
+        platform
+            .resolve_ended_contested_resources_v0(
+                &block_info,
+                &mut StateChanges::default(),
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to resolve contests");
+
+        assert!(platform
+            .drive
+            .fetch_contest_end_time(&contest_id, Some(&transaction), &platform_version.drive)
+            .expect("expected to fetch the end time")
+            .is_some());
+
+        let mut state_changes = StateChanges::default();
+
+        platform
+            .resolve_ended_contested_resources_v0(
+                &BlockInfo::default_with_time(end_time_ms),
+                &mut state_changes,
+                &transaction,
+                platform_version,
+            )
+            .expect("expected to resolve contests");
+
+        assert!(platform
+            .drive
+            .fetch_contest_end_time(&contest_id, Some(&transaction), &platform_version.drive)
+            .expect("expected to fetch the end time")
+            .is_none());
+
+        let mut domains_query = DriveQuery::any_item_query(contract, document_type);
+        domains_query.limit = None;
+
+        let domains = platform
+            .drive
+            .query_documents(
+                domains_query,
+                None,
+                false,
+                Some(&transaction),
+                Some(platform_version.protocol_version),
+            )
+            .expect("expected to query domains")
+            .documents_owned();
+
+        assert!(domains
+            .iter()
+            .any(|domain| domain.id() == documents[1].id()));
+        assert!(!domains
+            .iter()
+            .any(|domain| domain.id() == documents[0].id()));
+
+        assert_eq!(
+            state_changes.documents,
+            vec![DocumentChange {
+                data_contract_id: dpns_contract::ID,
+                document_type_name: domain::NAME.to_string(),
+                document_id: documents[1].id(),
+                kind: DocumentChangeKind::Created,
+            }]
+        );
+    }
+}
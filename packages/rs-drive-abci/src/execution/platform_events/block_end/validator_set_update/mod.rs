@@ -1,4 +1,5 @@
 mod v0;
+mod v1;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
@@ -11,6 +12,7 @@ use crate::platform_types::platform_state::PlatformState;
 
 use crate::rpc::core::CoreRPCLike;
 use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
 
 impl<C> Platform<C>
@@ -26,6 +28,8 @@ where
     ///
     /// * `platform_state` - A `PlatformState` reference.
     /// * `block_execution_context` - A mutable `BlockExecutionContext` reference.
+    /// * `transaction` - The current block transaction, used to read the epoch proposers
+    ///   to rotate away from unhealthy validator sets since version 1.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
     ///
@@ -38,6 +42,7 @@ where
         &self,
         platform_state: &PlatformState,
         block_execution_context: &mut BlockExecutionContext,
+        transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<Option<ValidatorSetUpdate>, Error> {
         match platform_version
@@ -47,9 +52,15 @@ where
             .validator_set_update
         {
             0 => self.validator_set_update_v0(platform_state, block_execution_context),
+            1 => self.validator_set_update_v1(
+                platform_state,
+                block_execution_context,
+                transaction,
+                platform_version,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "validator_set_update".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -0,0 +1,274 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::block_execution_context::v0::{
+    BlockExecutionContextV0Getters, BlockExecutionContextV0MutableGetters,
+};
+use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::execution::types::block_state_info::v0::BlockStateInfoV0Getters;
+use crate::platform_types::epoch_info::v0::EpochInfoV0Getters;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::validator_set::v0::ValidatorSetV0Getters;
+use crate::rpc::core::CoreRPCLike;
+use dashcore_rpc::dashcore::hashes::Hash;
+use dashcore_rpc::dashcore::ProTxHash;
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+use std::collections::BTreeMap;
+
+use tenderdash_abci::proto::abci::ValidatorSetUpdate;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// We need to validate against the platform state for rotation and not the block execution
+    /// context state
+    pub(super) fn validator_set_update_v1(
+        &self,
+        platform_state: &PlatformState,
+        block_execution_context: &mut BlockExecutionContext,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<ValidatorSetUpdate>, Error> {
+        let mut perform_rotation = false;
+
+        if block_execution_context.block_state_info().height()
+            % self.config.validator_set_quorum_rotation_block_count as u64
+            == 0
+        {
+            tracing::debug!(
+                method = "validator_set_update_v1",
+                "rotation: previous quorum finished members"
+            );
+            perform_rotation = true;
+        }
+        // we also need to perform a rotation if the validator set is being removed
+        if block_execution_context
+            .block_platform_state()
+            .validator_sets()
+            .get(&platform_state.current_validator_set_quorum_hash())
+            .is_none()
+        {
+            tracing::debug!(
+                method = "validator_set_update_v1",
+                "rotation: new quorums not containing current quorum current {:?}, {}",
+                block_execution_context
+                    .block_platform_state()
+                    .validator_sets()
+                    .keys()
+                    .map(|quorum_hash| format!("{}", quorum_hash)),
+                &platform_state.current_validator_set_quorum_hash()
+            );
+            perform_rotation = true;
+        }
+
+        let proposer_block_counts = self.current_epoch_proposer_block_counts_v1(
+            block_execution_context,
+            transaction,
+            platform_version,
+        )?;
+
+        // we also need to perform a rotation if the current validator set is unhealthy
+        let mut rotation_for_health = false;
+        if !perform_rotation {
+            let health = block_execution_context
+                .block_platform_state()
+                .current_validator_set()?
+                .health(
+                    block_execution_context.block_platform_state(),
+                    &proposer_block_counts,
+                    platform_version,
+                )?;
+            if !health.is_healthy(platform_version) {
+                tracing::debug!(
+                    method = "validator_set_update_v1",
+                    "rotation: current quorum health is low {:?}",
+                    health
+                );
+                perform_rotation = true;
+                rotation_for_health = true;
+            }
+        }
+
+        if perform_rotation {
+            // get the index of the previous quorum
+            let mut index = platform_state
+                .validator_sets()
+                .get_index_of(&platform_state.current_validator_set_quorum_hash())
+                .ok_or(Error::Execution(ExecutionError::CorruptedCachedState(
+                    "current quorums do not contain current validator set",
+                )))?;
+            // we should rotate the quorum
+            let quorum_count = platform_state.validator_sets().len();
+            match quorum_count {
+                0 => Err(Error::Execution(ExecutionError::CorruptedCachedState(
+                    "no current quorums",
+                ))),
+                1 if rotation_for_health => {
+                    tracing::debug!(
+                        method = "validator_set_update_v1",
+                        "rotation: no other quorum to rotate to"
+                    );
+                    Self::validator_set_update_without_rotation_v1(
+                        platform_state,
+                        block_execution_context,
+                    )
+                }
+                1 => Ok(None),
+                count => {
+                    let start_index = index;
+                    index = (index + 1) % count;
+                    // The first quorum still in the state, used if none of them is healthy
+                    let mut unhealthy_quorum_hash = None;
+                    // We can't just take the next item because it might no longer be in the state
+                    while index != start_index {
+                        let (quorum_hash, _) = platform_state
+                            .validator_sets()
+                            .get_index(index)
+                            .expect("expected next validator set");
+
+                        // We still have it in the state
+                        if let Some(new_validator_set) = block_execution_context
+                            .block_platform_state()
+                            .validator_sets()
+                            .get(quorum_hash)
+                        {
+                            // Rotating to an unhealthy quorum could stall the chain
+                            if !new_validator_set
+                                .health(
+                                    block_execution_context.block_platform_state(),
+                                    &proposer_block_counts,
+                                    platform_version,
+                                )?
+                                .is_healthy(platform_version)
+                            {
+                                unhealthy_quorum_hash.get_or_insert(*quorum_hash);
+                                index = (index + 1) % count;
+                                continue;
+                            }
+
+                            tracing::debug!(
+                                method = "validator_set_update_v1",
+                                "rotation: to new quorum: {} with {} members",
+                                &quorum_hash,
+                                new_validator_set.members().len()
+                            );
+                            let validator_set_update = new_validator_set.into();
+                            block_execution_context
+                                .block_platform_state_mut()
+                                .set_next_validator_set_quorum_hash(Some(*quorum_hash));
+                            return Ok(Some(validator_set_update));
+                        }
+                        index = (index + 1) % count;
+                    }
+                    if let Some(quorum_hash) = unhealthy_quorum_hash {
+                        if rotation_for_health {
+                            // Other quorums are not healthier, we keep the current one
+                            tracing::debug!(
+                                method = "validator_set_update_v1",
+                                "rotation: no healthy quorum to rotate to"
+                            );
+                            return Self::validator_set_update_without_rotation_v1(
+                                platform_state,
+                                block_execution_context,
+                            );
+                        }
+
+                        let new_validator_set = block_execution_context
+                            .block_platform_state()
+                            .validator_sets()
+                            .get(&quorum_hash)
+                            .ok_or(Error::Execution(ExecutionError::CorruptedCachedState(
+                                "unhealthy quorum must be in the validator sets",
+                            )))?;
+                        tracing::debug!(
+                            method = "validator_set_update_v1",
+                            "rotation: to new unhealthy quorum: {} with {} members",
+                            &quorum_hash,
+                            new_validator_set.members().len()
+                        );
+                        let validator_set_update = new_validator_set.into();
+                        block_execution_context
+                            .block_platform_state_mut()
+                            .set_next_validator_set_quorum_hash(Some(quorum_hash));
+                        return Ok(Some(validator_set_update));
+                    }
+                    // All quorums changed
+                    if let Some((quorum_hash, new_validator_set)) = block_execution_context
+                        .block_platform_state()
+                        .validator_sets()
+                        .first()
+                    {
+                        tracing::debug!(
+                            method = "validator_set_update_v1",
+                            "rotation: all quorums changed, rotation to new quorum: {}",
+                            &quorum_hash
+                        );
+                        let validator_set_update = new_validator_set.into();
+                        let new_quorum_hash = *quorum_hash;
+                        block_execution_context
+                            .block_platform_state_mut()
+                            .set_next_validator_set_quorum_hash(Some(new_quorum_hash));
+                        return Ok(Some(validator_set_update));
+                    }
+                    tracing::debug!("no new quorums to choose from");
+                    Ok(None)
+                }
+            }
+        } else {
+            Self::validator_set_update_without_rotation_v1(platform_state, block_execution_context)
+        }
+    }
+
+    /// Updates the current validator set if its members changed
+    fn validator_set_update_without_rotation_v1(
+        platform_state: &PlatformState,
+        block_execution_context: &BlockExecutionContext,
+    ) -> Result<Option<ValidatorSetUpdate>, Error> {
+        let current_validator_set = block_execution_context
+            .block_platform_state()
+            .current_validator_set()?;
+        if current_validator_set != platform_state.current_validator_set()? {
+            // Something changed, for example the IP of a validator changed, or someone's ban status
+
+            tracing::debug!(
+                method = "validator_set_update_v1",
+                "validator set update without rotation"
+            );
+            Ok(Some(current_validator_set.into()))
+        } else {
+            tracing::debug!(
+                method = "validator_set_update_v1",
+                "no validator set update"
+            );
+            Ok(None)
+        }
+    }
+
+    /// Blocks proposed by each proposer in the current epoch, including the current block
+    fn current_epoch_proposer_block_counts_v1(
+        &self,
+        block_execution_context: &BlockExecutionContext,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<ProTxHash, u64>, Error> {
+        let epoch = Epoch::new(block_execution_context.epoch_info().current_epoch_index())?;
+
+        self.drive
+            .get_epoch_proposers(&epoch, None, Some(transaction), platform_version)
+            .map_err(Error::Drive)?
+            .into_iter()
+            .map(|(pro_tx_hash, block_count)| {
+                let pro_tx_hash: [u8; 32] = pro_tx_hash.try_into().map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedDriveResponse(
+                        "epoch proposer pro_tx_hash must be 32 bytes".to_string(),
+                    ))
+                })?;
+                Ok((ProTxHash::from_byte_array(pro_tx_hash), block_count))
+            })
+            .collect()
+    }
+}
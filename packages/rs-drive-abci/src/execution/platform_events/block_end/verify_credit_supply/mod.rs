@@ -0,0 +1,55 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Verifies that no credits were created or destroyed.
+    ///
+    /// The check runs every `CREDIT_SUPPLY_CHECK_INTERVAL` blocks or on every block in
+    /// credit supply debug mode. When credits don't add up, the credits of every subtree
+    /// before and after the block are logged and block finalization is halted.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the verify_credit_supply function.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The height of the block being finalized.
+    /// * `transaction` - The current block transaction.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - `ExecutionError::CreditSupplyInvariantViolated` if credits
+    ///   were created or destroyed.
+    pub(in crate::execution) fn verify_credit_supply(
+        &self,
+        height: u64,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .verify_credit_supply
+        {
+            Some(0) => self.verify_credit_supply_v0(height, transaction, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "verify_credit_supply".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // The credit supply isn't verified in this protocol version
+            None => Ok(()),
+        }
+    }
+}
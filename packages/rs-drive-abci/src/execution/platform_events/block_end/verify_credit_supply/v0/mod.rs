@@ -0,0 +1,98 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::balances::credit_supply::CreditSupply;
+use dpp::balances::credits::Creditable;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+use std::fmt::Write;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn verify_credit_supply_v0(
+        &self,
+        height: u64,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if !self.config.credit_supply_check.should_check(height) {
+            return Ok(());
+        }
+
+        let credit_supply = self
+            .drive
+            .calculate_credit_supply(Some(transaction), platform_version)?;
+
+        if credit_supply.ok()? {
+            return Ok(());
+        }
+
+        // The committed state is the state before this block
+        let previous_credit_supply = self.drive.calculate_credit_supply(None, platform_version)?;
+
+        let report = credit_supply_report_v0(height, &previous_credit_supply, &credit_supply)?;
+
+        tracing::error!(height, %report, "credit supply invariant violated");
+
+        Err(Error::Execution(
+            ExecutionError::CreditSupplyInvariantViolated(report),
+        ))
+    }
+}
+
+/// Describes credits of every subtree before and after the block
+fn credit_supply_report_v0(
+    height: u64,
+    previous: &CreditSupply,
+    current: &CreditSupply,
+) -> Result<String, Error> {
+    let mut report = format!(
+        "credits are off by {}, pools sum tree is off by {} and system credits are off by {} at height {}",
+        current.difference()?,
+        current.pools_difference()?,
+        current.system_credits_difference()?,
+        height
+    );
+
+    let previous_subtrees = previous.subtrees()?;
+    let current_subtrees = current.subtrees()?;
+
+    let rows = previous_subtrees
+        .into_iter()
+        .zip(current_subtrees)
+        .map(|((name, before), (_, after))| (name, before, after))
+        .chain([
+            (
+                "pools sum tree",
+                previous.total_in_pools,
+                current.total_in_pools,
+            ),
+            (
+                "system credits",
+                previous.total_credits_in_platform.to_signed()?,
+                current.total_credits_in_platform.to_signed()?,
+            ),
+            (
+                "expected total",
+                previous.expected_total()?,
+                current.expected_total()?,
+            ),
+        ]);
+
+    for (name, before, after) in rows {
+        // Writing to a string can't fail
+        let _ = write!(
+            report,
+            "; {}: {} -> {} ({:+})",
+            name,
+            before,
+            after,
+            after as i128 - before as i128
+        );
+    }
+
+    Ok(report)
+}
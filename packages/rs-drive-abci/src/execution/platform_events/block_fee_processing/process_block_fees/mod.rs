@@ -6,6 +6,7 @@ use drive::grovedb::Transaction;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 
 use crate::execution::types::block_fees::BlockFees;
 
@@ -26,6 +27,7 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
     /// * `block_info` - A `BlockStateInfo` reference that holds block state information.
     /// * `epoch_info` - A `EpochInfo` reference that holds epoch information.
     /// * `block_fees` - A `BlockFees` instance that holds block fee details.
+    /// * `state_changes` - Collects identities the fees are paid out to.
     /// * `transaction` - A `Transaction` reference.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
@@ -40,6 +42,7 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
         block_info: &BlockStateInfo,
         epoch_info: &EpochInfo,
         block_fees: BlockFees,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<processed_block_fees_outcome::v0::ProcessedBlockFeesOutcome, Error> {
@@ -53,6 +56,7 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
                 block_info,
                 epoch_info,
                 block_fees,
+                state_changes,
                 transaction,
                 platform_version,
             ),
@@ -43,6 +43,7 @@ use drive::grovedb::Transaction;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_fees::v0::BlockFeesV0Getters;
 use crate::execution::types::block_fees::BlockFees;
 use crate::execution::types::block_state_info::v0::{
@@ -78,6 +79,7 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
         block_info: &BlockStateInfo,
         epoch_info: &EpochInfo,
         block_fees: BlockFees,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<processed_block_fees_outcome::v0::ProcessedBlockFeesOutcome, Error> {
@@ -137,6 +139,7 @@ impl<CoreRPCLike> Platform<CoreRPCLike> {
                 cached_current_epoch_start_block_core_height,
                 transaction,
                 &mut batch,
+                state_changes,
                 platform_version,
             )?;
 
@@ -271,6 +274,7 @@ mod tests {
                     &block_info.clone().into(),
                     &epoch_info,
                     block_fees.clone(),
+                    &mut StateChanges::default(),
                     transaction,
                     platform_version,
                 )
@@ -345,6 +349,8 @@ mod tests {
         let platform = TestPlatformBuilder::new()
             .with_config(PlatformConfig {
                 verify_sum_trees: false,
+                // Core block rewards are counted as minted credits since protocol version 2
+                initial_protocol_version: 2,
                 ..Default::default()
             })
             .build_with_mock_rpc()
@@ -2,6 +2,7 @@ mod v0;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 
 use crate::execution::types::proposer_payouts::v0::ProposersPayouts;
 use crate::platform_types::platform::Platform;
@@ -24,6 +25,7 @@ impl<C> Platform<C> {
     /// * `cached_current_epoch_start_block_core_height` - An Option wrapping a u32 value representing the current epoch start block core height.
     /// * `transaction` - A Transaction reference.
     /// * `batch` - A mutable reference to a vector of DriveOperation.
+    /// * `state_changes` - Collects identities the fees are paid out to.
     /// * `platform_version` - A PlatformVersion reference that dictates which version of the method to call.
     ///
     /// # Returns
@@ -36,6 +38,7 @@ impl<C> Platform<C> {
         cached_current_epoch_start_block_core_height: Option<u32>,
         transaction: &Transaction,
         batch: &mut Vec<DriveOperation>,
+        state_changes: &mut StateChanges,
         platform_version: &PlatformVersion,
     ) -> Result<Option<ProposersPayouts>, Error> {
         match platform_version
@@ -50,6 +53,7 @@ impl<C> Platform<C> {
                 cached_current_epoch_start_block_core_height,
                 transaction,
                 batch,
+                state_changes,
                 platform_version,
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
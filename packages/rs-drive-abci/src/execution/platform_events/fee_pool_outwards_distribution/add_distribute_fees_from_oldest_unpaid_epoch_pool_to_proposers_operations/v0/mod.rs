@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::proposer_payouts;
 use crate::platform_types::platform::Platform;
 use dpp::block::epoch::Epoch;
@@ -23,6 +24,7 @@ impl<C> Platform<C> {
         cached_current_epoch_start_block_core_height: Option<u32>,
         transaction: &Transaction,
         batch: &mut Vec<DriveOperation>,
+        state_changes: &mut StateChanges,
         platform_version: &PlatformVersion,
     ) -> Result<Option<proposer_payouts::v0::ProposersPayouts>, Error> {
         let unpaid_epoch = self.find_oldest_epoch_needing_payment(
@@ -51,6 +53,11 @@ impl<C> Platform<C> {
                 amount: core_block_rewards,
             },
         ));
+        batch.push(DriveOperation::SystemOperation(
+            SystemOperationType::AddToMintedCredits {
+                amount: core_block_rewards,
+            },
+        ));
 
         let unpaid_epoch = unpaid_epoch.into();
 
@@ -59,6 +66,7 @@ impl<C> Platform<C> {
             core_block_rewards,
             transaction,
             batch,
+            state_changes,
             platform_version,
         )?;
 
@@ -94,6 +102,7 @@ mod tests {
 
     use drive::common::identities::create_test_masternode_identities_and_add_them_as_epoch_block_proposers;
 
+    use crate::config::PlatformConfig;
     use crate::test::helpers::setup::TestPlatformBuilder;
 
     use crate::execution::types::proposer_payouts::v0::ProposersPayouts;
@@ -119,6 +128,7 @@ mod tests {
                 None,
                 &transaction,
                 &mut batch,
+                &mut StateChanges::default(),
                 platform_version,
             )
             .expect("should distribute fees");
@@ -130,6 +140,11 @@ mod tests {
     fn test_mark_epoch_as_paid_and_update_next_update_epoch_index_if_all_proposers_paid() {
         let platform_version = PlatformVersion::latest();
         let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                // Core block rewards are counted as minted credits since protocol version 2
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
             .build_with_mock_rpc()
             .set_initial_state_structure();
         let transaction = platform.drive.grove.start_transaction();
@@ -191,6 +206,7 @@ mod tests {
                 None,
                 &transaction,
                 &mut batch,
+                &mut StateChanges::default(),
                 platform_version,
             )
             .expect("should distribute fees");
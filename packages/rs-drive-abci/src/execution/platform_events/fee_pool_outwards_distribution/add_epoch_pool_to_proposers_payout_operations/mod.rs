@@ -1,5 +1,6 @@
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::unpaid_epoch::UnpaidEpoch;
 use crate::platform_types::platform::Platform;
 use dpp::fee::Credits;
@@ -22,6 +23,7 @@ impl<C> Platform<C> {
     /// * `core_block_rewards` - A `Credits` value representing the core block rewards.
     /// * `transaction` - A `Transaction` reference.
     /// * `batch` - A mutable reference to a vector of `DriveOperation`.
+    /// * `state_changes` - Collects identities the fees are paid out to.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
     ///
@@ -34,6 +36,7 @@ impl<C> Platform<C> {
         core_block_rewards: Credits,
         transaction: &Transaction,
         batch: &mut Vec<DriveOperation>,
+        state_changes: &mut StateChanges,
         platform_version: &PlatformVersion,
     ) -> Result<u16, Error> {
         match platform_version
@@ -47,6 +50,7 @@ impl<C> Platform<C> {
                 core_block_rewards,
                 transaction,
                 batch,
+                state_changes,
                 platform_version,
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
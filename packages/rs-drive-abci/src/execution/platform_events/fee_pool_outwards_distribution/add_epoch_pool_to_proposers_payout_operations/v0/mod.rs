@@ -1,5 +1,6 @@
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 
 use crate::execution::types::unpaid_epoch::v0::{UnpaidEpochV0Getters, UnpaidEpochV0Methods};
 use crate::execution::types::unpaid_epoch::UnpaidEpoch;
@@ -28,6 +29,7 @@ impl<C> Platform<C> {
         core_block_rewards: Credits,
         transaction: &Transaction,
         batch: &mut Vec<DriveOperation>,
+        state_changes: &mut StateChanges,
         platform_version: &PlatformVersion,
     ) -> Result<u16, Error> {
         let mut drive_operations = vec![];
@@ -147,6 +149,8 @@ impl<C> Platform<C> {
             }));
         }
 
+        state_changes.add_drive_operations(&drive_operations);
+
         let operations = self.drive.convert_drive_operations_to_grove_operations(
             drive_operations,
             &BlockInfo::default(),
@@ -174,12 +178,14 @@ mod tests {
         use dpp::block::block_info::BlockInfo;
         use dpp::identity::accessors::IdentityGettersV0;
         use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+        use dpp::platform_value::Identifier;
         use drive::common::identities::create_test_masternode_identities_and_add_them_as_epoch_block_proposers;
         use drive::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
         use drive::drive::batch::GroveDbOpBatch;
         use drive::fee_pools::epochs::operations_factory::EpochOperations;
         use rust_decimal::Decimal;
         use rust_decimal_macros::dec;
+        use std::collections::BTreeSet;
 
         #[test]
         fn test_payout_to_proposers() {
@@ -263,12 +269,15 @@ mod tests {
                 next_epoch_start_block_core_height: 1,
             };
 
+            let mut state_changes = StateChanges::default();
+
             let proposers_paid_count = platform
                 .add_epoch_pool_to_proposers_payout_operations_v0(
                     &unpaid_epoch.into(),
                     0,
                     &transaction,
                     &mut batch,
+                    &mut state_changes,
                     platform_version,
                 )
                 .expect("should distribute fees");
@@ -325,6 +334,16 @@ mod tests {
             for (_, balance) in refetched_share_identities_balances {
                 assert_eq!(balance, payout_credits);
             }
+
+            // Proposers and reward share holders are paid
+            assert_eq!(
+                state_changes.identities,
+                pro_tx_hashes
+                    .iter()
+                    .chain(share_identities.iter())
+                    .map(|identity_id| Identifier::new(*identity_id))
+                    .collect::<BTreeSet<_>>()
+            );
         }
     }
 }
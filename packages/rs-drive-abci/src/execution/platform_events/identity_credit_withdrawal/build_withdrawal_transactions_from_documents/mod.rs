@@ -11,6 +11,7 @@ use drive::grovedb::TransactionArg;
 use std::collections::HashMap;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -30,6 +31,7 @@ where
     /// # Returns
     ///
     /// * `Result<HashMap<Identifier, WithdrawalTransactionIdAndBytes>, Error>` - Returns a HashMap containing withdrawal transactions if found, otherwise returns an `Error`.
+    ///   Withdrawals pooled together map to the same transaction.
     pub(in crate::execution::platform_events::identity_credit_withdrawal) fn build_withdrawal_transactions_from_documents(
         &self,
         documents: &[Document],
@@ -48,9 +50,14 @@ where
                 drive_operation_types,
                 transaction,
             ),
+            1 => self.build_withdrawal_transactions_from_documents_v1(
+                documents,
+                drive_operation_types,
+                transaction,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "build_withdrawal_transactions_from_documents".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -0,0 +1,400 @@
+use std::collections::{BTreeMap, HashMap};
+
+use dashcore_rpc::dashcore::{
+    blockdata::transaction::special_transaction::asset_unlock::unqualified_asset_unlock::{
+        AssetUnlockBasePayload, AssetUnlockBaseTransactionInfo,
+    },
+    consensus::Encodable,
+    ScriptBuf, TxOut,
+};
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+use dpp::withdrawal::Pooling;
+
+use drive::dpp::identifier::Identifier;
+use drive::dpp::identity::convert_credits_to_duffs;
+use drive::drive::identity::withdrawals::WithdrawalTransactionIdAndBytes;
+use drive::{drive::batch::DriveOperation, query::TransactionArg};
+
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+/// Size of an asset unlock transaction without outputs
+const ASSET_UNLOCK_TRANSACTION_BASE_SIZE: u64 = 156;
+/// Size of a single asset unlock transaction output
+const ASSET_UNLOCK_TRANSACTION_OUTPUT_SIZE: u64 = 34;
+/// Maximum number of withdrawals merged into one asset unlock transaction
+const MAX_WITHDRAWALS_PER_POOLED_TRANSACTION: usize = 16;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Build list of Core transactions from withdrawal documents
+    ///
+    /// Withdrawals with `Never` pooling get their own transaction while pooled withdrawals
+    /// with the same pooling preference and fee per byte tier are merged into shared
+    /// transactions with an output per withdrawal. Every withdrawal pays its own
+    /// fee per byte rate for its output and an even share of the transaction base size,
+    /// which is deducted from its output. The transaction fee is the sum of the deductions.
+    pub(super) fn build_withdrawal_transactions_from_documents_v1(
+        &self,
+        documents: &[Document],
+        drive_operation_types: &mut Vec<DriveOperation>,
+        transaction: TransactionArg,
+    ) -> Result<HashMap<Identifier, WithdrawalTransactionIdAndBytes>, Error> {
+        let mut withdrawals: HashMap<Identifier, WithdrawalTransactionIdAndBytes> = HashMap::new();
+
+        let latest_withdrawal_index = self
+            .drive
+            .fetch_and_remove_latest_withdrawal_transaction_index_operations(
+                drive_operation_types,
+                transaction,
+            )?;
+
+        let mut unpooled: Vec<(u32, Vec<&Document>)> = vec![];
+        let mut pools: BTreeMap<(u8, u32), Vec<&Document>> = BTreeMap::new();
+
+        for document in documents {
+            let pooling: u8 = document
+                .properties()
+                .get_integer(withdrawal::properties::POOLING)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get pooling from withdrawal document",
+                    ))
+                })?;
+
+            let core_fee_per_byte: u32 = document
+                .properties()
+                .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't get coreFeePerByte from withdrawal document",
+                    ))
+                })?;
+
+            if pooling == Pooling::Never as u8 {
+                unpooled.push((core_fee_per_byte, vec![document]));
+            } else {
+                pools
+                    .entry((pooling, core_fee_per_byte))
+                    .or_default()
+                    .push(document);
+            }
+        }
+
+        let pooled = pools
+            .into_iter()
+            .flat_map(|((_, core_fee_per_byte), documents)| {
+                documents
+                    .chunks(MAX_WITHDRAWALS_PER_POOLED_TRANSACTION)
+                    .map(|chunk| (core_fee_per_byte, chunk.to_vec()))
+                    .collect::<Vec<_>>()
+            });
+
+        for (i, (core_fee_per_byte, documents)) in unpooled.into_iter().chain(pooled).enumerate() {
+            let transaction_index = latest_withdrawal_index + i as u64;
+
+            let fees = output_fees(core_fee_per_byte, documents.len())?;
+
+            let output = documents
+                .iter()
+                .zip(fees)
+                .map(|(document, fee)| {
+                    let output_script_bytes = document
+                        .properties()
+                        .get_bytes(withdrawal::properties::OUTPUT_SCRIPT)
+                        .map_err(|_| {
+                            Error::Execution(ExecutionError::CorruptedCodeExecution(
+                                "Can't get outputScript from withdrawal document",
+                            ))
+                        })?;
+
+                    let amount = document
+                        .properties()
+                        .get_integer(withdrawal::properties::AMOUNT)
+                        .map_err(|_| {
+                            Error::Execution(ExecutionError::CorruptedCodeExecution(
+                                "Can't get amount from withdrawal document",
+                            ))
+                        })?;
+
+                    let value = convert_credits_to_duffs(amount)?;
+
+                    // A withdrawal can't pay more than its amount
+                    let fee = fee.min(value);
+
+                    Ok((
+                        TxOut {
+                            value: value - fee,
+                            script_pubkey: ScriptBuf::from_bytes(output_script_bytes),
+                        },
+                        fee,
+                    ))
+                })
+                .collect::<Result<Vec<(TxOut, u64)>, Error>>()?;
+
+            let (output, fees): (Vec<TxOut>, Vec<u64>) = output.into_iter().unzip();
+
+            let fee: u32 = fees
+                .into_iter()
+                .try_fold(0u64, |total, fee| total.checked_add(fee))
+                .and_then(|fee| fee.try_into().ok())
+                .ok_or(Error::Execution(ExecutionError::Overflow(
+                    "withdrawal transaction fee overflow",
+                )))?;
+
+            let withdrawal_transaction = AssetUnlockBaseTransactionInfo {
+                version: 1,
+                lock_time: 0,
+                output,
+                base_payload: AssetUnlockBasePayload {
+                    version: 1,
+                    index: transaction_index,
+                    fee,
+                },
+            };
+
+            let mut transaction_buffer: Vec<u8> = vec![];
+
+            withdrawal_transaction
+                .consensus_encode(&mut transaction_buffer)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't consensus encode a withdrawal transaction",
+                    ))
+                })?;
+
+            for document in documents {
+                withdrawals.insert(
+                    document.id(),
+                    (
+                        transaction_index.to_be_bytes().to_vec(),
+                        transaction_buffer.clone(),
+                    ),
+                );
+            }
+        }
+
+        Ok(withdrawals)
+    }
+}
+
+/// Fees of outputs of a transaction, in duffs.
+///
+/// Every output pays for its own size and an even share of the base size, the first output
+/// pays the remainder of the share.
+fn output_fees(core_fee_per_byte: u32, outputs: usize) -> Result<Vec<u64>, Error> {
+    let overflow = || {
+        Error::Execution(ExecutionError::Overflow(
+            "withdrawal transaction fee overflow",
+        ))
+    };
+
+    if outputs == 0 {
+        return Ok(vec![]);
+    }
+
+    let core_fee_per_byte = core_fee_per_byte as u64;
+
+    let base_fee = ASSET_UNLOCK_TRANSACTION_BASE_SIZE
+        .checked_mul(core_fee_per_byte)
+        .ok_or_else(overflow)?;
+
+    let output_fee = ASSET_UNLOCK_TRANSACTION_OUTPUT_SIZE
+        .checked_mul(core_fee_per_byte)
+        .ok_or_else(overflow)?;
+
+    let base_fee_share = base_fee / outputs as u64;
+    let base_fee_remainder = base_fee % outputs as u64;
+
+    (0..outputs)
+        .map(|i| {
+            let share = if i == 0 {
+                base_fee_share + base_fee_remainder
+            } else {
+                base_fee_share
+            };
+
+            output_fee.checked_add(share).ok_or_else(overflow)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::withdrawals_contract;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::version::PlatformVersion;
+    use drive::tests::helpers::setup::{setup_document, setup_system_data_contract};
+
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn test_pooled_withdrawals_share_transaction() {
+        let platform_version = PlatformVersion::latest();
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let data_contract = load_system_data_contract(
+            SystemDataContract::Withdrawals,
+            platform_version.protocol_version,
+        )
+        .expect("to load system data contract");
+
+        setup_system_data_contract(&platform.drive, &data_contract, Some(&transaction));
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let documents = [
+            (Pooling::Never, 1u32),
+            (Pooling::Standard, 1u32),
+            (Pooling::Standard, 1u32),
+            (Pooling::Standard, 2u32),
+        ]
+        .into_iter()
+        .map(|(pooling, core_fee_per_byte)| {
+            let document = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": 1000u64,
+                    "coreFeePerByte": core_fee_per_byte,
+                    "pooling": pooling as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            setup_document(
+                &platform.drive,
+                &document,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+
+            document
+        })
+        .collect::<Vec<_>>();
+
+        let mut batch = vec![];
+
+        let transactions = platform
+            .build_withdrawal_transactions_from_documents_v1(
+                &documents,
+                &mut batch,
+                Some(&transaction),
+            )
+            .expect("to build transactions from documents");
+
+        platform
+            .drive
+            .apply_drive_operations(
+                batch,
+                true,
+                &BlockInfo::default(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to apply drive op batch");
+
+        assert_eq!(transactions.len(), 4);
+
+        let transaction_index = |document: &Document| {
+            transactions
+                .get(&document.id())
+                .expect("expected a transaction for every document")
+                .0
+                .clone()
+        };
+
+        // the unpooled withdrawal comes first and gets its own transaction
+        assert_eq!(
+            transaction_index(&documents[0]),
+            vec![0, 0, 0, 0, 0, 0, 0, 0]
+        );
+
+        // withdrawals with the same fee tier are merged
+        assert_eq!(
+            transaction_index(&documents[1]),
+            vec![0, 0, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(
+            transaction_index(&documents[2]),
+            vec![0, 0, 0, 0, 0, 0, 0, 1]
+        );
+
+        // while a different fee tier gets a separate transaction
+        assert_eq!(
+            transaction_index(&documents[3]),
+            vec![0, 0, 0, 0, 0, 0, 0, 2]
+        );
+
+        let unpooled_transaction = &transactions[&documents[0].id()].1;
+        let pooled_transaction = &transactions[&documents[1].id()].1;
+
+        assert_eq!(
+            pooled_transaction.len() - unpooled_transaction.len(),
+            // one more output of a value and a 23 byte script
+            8 + 1 + 23
+        );
+    }
+
+    #[test]
+    fn test_outputs_share_base_fee_evenly() {
+        let fees = output_fees(2, 3).expect("expected to calculate fees");
+
+        // 156 * 2 = 312 base fee split into three shares of 104, 34 * 2 = 68 per output
+        assert_eq!(fees, vec![68 + 104, 68 + 104, 68 + 104]);
+
+        let fees = output_fees(1, 5).expect("expected to calculate fees");
+
+        // 156 base fee doesn't split evenly, the first output pays the remainder
+        assert_eq!(fees, vec![34 + 31 + 1, 34 + 31, 34 + 31, 34 + 31, 34 + 31]);
+        assert_eq!(
+            fees.iter().sum::<u64>(),
+            ASSET_UNLOCK_TRANSACTION_BASE_SIZE + 5 * ASSET_UNLOCK_TRANSACTION_OUTPUT_SIZE
+        );
+    }
+
+    #[test]
+    fn test_high_fee_per_byte_does_not_overflow() {
+        let fees = output_fees(6765, MAX_WITHDRAWALS_PER_POOLED_TRANSACTION)
+            .expect("expected to calculate fees");
+
+        assert_eq!(
+            fees.iter().sum::<u64>(),
+            (ASSET_UNLOCK_TRANSACTION_BASE_SIZE
+                + MAX_WITHDRAWALS_PER_POOLED_TRANSACTION as u64
+                    * ASSET_UNLOCK_TRANSACTION_OUTPUT_SIZE)
+                * 6765
+        );
+
+        let fees = output_fees(u32::MAX, MAX_WITHDRAWALS_PER_POOLED_TRANSACTION)
+            .expect("expected to calculate fees");
+
+        assert_eq!(fees.len(), MAX_WITHDRAWALS_PER_POOLED_TRANSACTION);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
@@ -7,6 +8,7 @@ use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -18,6 +20,7 @@ where
     /// # Arguments
     ///
     /// * `block_execution_context` - A `BlockExecutionContext` reference that provides context for block execution.
+    /// * `state_changes` - Collects pooled withdrawal documents.
     /// * `transaction` - A `Transaction` reference representing the current transaction.
     /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
     ///   the method to call.
@@ -28,6 +31,7 @@ where
     pub(in crate::execution) fn pool_withdrawals_into_transactions_queue(
         &self,
         block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<(), Error> {
@@ -39,12 +43,19 @@ where
         {
             0 => self.pool_withdrawals_into_transactions_queue_v0(
                 block_execution_context,
+                state_changes,
+                transaction,
+                platform_version,
+            ),
+            1 => self.pool_withdrawals_into_transactions_queue_v1(
+                block_execution_context,
+                state_changes,
                 transaction,
                 platform_version,
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "pool_withdrawals_into_transactions_queue".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -12,6 +12,7 @@ use drive::grovedb::Transaction;
 use dpp::system_data_contracts::withdrawals_contract;
 use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
 
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::execution::types::block_state_info::v0::BlockStateInfoV0Getters;
@@ -30,6 +31,7 @@ where
     pub(super) fn pool_withdrawals_into_transactions_queue_v0(
         &self,
         block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<(), Error> {
@@ -134,6 +136,8 @@ where
             &mut drive_operations,
         );
 
+        state_changes.add_drive_operations(&drive_operations);
+
         self.drive.apply_drive_operations(
             drive_operations,
             true,
@@ -216,6 +220,7 @@ mod tests {
                 }
                 .into(),
                 proposer_results: None,
+                state_changes: Default::default(),
             }
             .into(),
         );
@@ -287,6 +292,7 @@ mod tests {
         platform
             .pool_withdrawals_into_transactions_queue_v0(
                 block_execution_context,
+                &mut StateChanges::default(),
                 &transaction,
                 platform_version,
             )
@@ -0,0 +1,439 @@
+use std::collections::BTreeMap;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::block::epoch::Epoch;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::version::PlatformVersion;
+use dpp::withdrawal::Pooling;
+
+use drive::dpp::util::hash;
+use drive::drive::identity::withdrawals::WithdrawalTransactionIdAndBytes;
+use drive::grovedb::Transaction;
+
+use dpp::system_data_contracts::withdrawals_contract;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+
+use crate::event_bus::StateChanges;
+use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
+use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::execution::types::block_state_info::v0::BlockStateInfoV0Getters;
+use crate::metrics;
+use crate::platform_types::epoch_info::v0::EpochInfoV0Getters;
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+/// Minimum number of withdrawals with `Standard` pooling and the same fee tier
+/// required to build a pooled transaction
+const MIN_WITHDRAWALS_PER_STANDARD_POOL: usize = 5;
+/// How long withdrawals with `Standard` pooling wait for a pool to fill up
+const STANDARD_POOL_MAX_WAIT_MS: u64 = 60 * 60 * 1000;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Pool withdrawal documents into transactions
+    ///
+    /// Withdrawals with `Never` and `IfAvailable` pooling are processed right away.
+    /// Withdrawals with `Standard` pooling stay queued until enough withdrawals with
+    /// the same fee tier are available or the oldest one waited long enough.
+    ///
+    /// The amount of withdrawn credits is limited per block and per rolling window
+    /// to a share of the total credits in the system.
+    pub(super) fn pool_withdrawals_into_transactions_queue_v1(
+        &self,
+        block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let block_info = BlockInfo {
+            time_ms: block_execution_context.block_state_info().block_time_ms(),
+            height: block_execution_context.block_state_info().height(),
+            core_height: block_execution_context
+                .block_state_info()
+                .core_chain_locked_height(),
+            epoch: Epoch::new(block_execution_context.epoch_info().current_epoch_index())?,
+        };
+
+        let data_contract_id = withdrawals_contract::ID;
+
+        let (_, Some(contract_fetch_info)) = self.drive.get_contract_with_fetch_info_and_fee(
+            data_contract_id.to_buffer(),
+            None,
+            true,
+            Some(transaction),
+            platform_version,
+        )?
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "can't fetch withdrawal data contract",
+            )));
+        };
+
+        let mut documents = vec![];
+
+        for pooling in [Pooling::Never, Pooling::IfAvailable] {
+            documents.extend(
+                self.drive
+                    .fetch_withdrawal_documents_by_status_and_pooling(
+                        withdrawals_contract::WithdrawalStatus::QUEUED.into(),
+                        pooling,
+                        Some(transaction),
+                        platform_version,
+                    )?,
+            );
+        }
+
+        let standard_documents = self
+            .drive
+            .fetch_withdrawal_documents_by_status_and_pooling(
+                withdrawals_contract::WithdrawalStatus::QUEUED.into(),
+                Pooling::Standard,
+                Some(transaction),
+                platform_version,
+            )?;
+
+        let queued_count = documents.len() + standard_documents.len();
+
+        if queued_count == 0 {
+            metrics::withdrawal_queue_depth(0);
+
+            return Ok(());
+        }
+
+        documents.extend(ready_standard_pools(
+            standard_documents,
+            block_info.time_ms,
+        )?);
+
+        // Oldest withdrawals are pooled first
+        documents.sort_by_key(|document| (document.updated_at(), document.id()));
+
+        let mut drive_operations = vec![];
+
+        let withdrawal_limits = &platform_version.drive_abci.withdrawal_limits;
+
+        let withdrawn_in_window = self
+            .drive
+            .fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+                block_info
+                    .time_ms
+                    .saturating_sub(withdrawal_limits.window_ms),
+                &mut drive_operations,
+                Some(transaction),
+            )?;
+
+        let total_credits = self
+            .drive
+            .calculate_total_credits_balance(Some(transaction), &platform_version.drive)?
+            .total_credits_in_platform;
+
+        let available =
+            basis_points_of(total_credits, withdrawal_limits.max_per_block_basis_points).min(
+                basis_points_of(total_credits, withdrawal_limits.max_per_window_basis_points)
+                    .saturating_sub(withdrawn_in_window),
+            );
+
+        // Withdrawals exceeding the limit stay queued until the limit allows them
+        let (mut documents, withdrawn_amount) =
+            take_within_limit(documents, available, withdrawn_in_window == 0)?;
+
+        metrics::withdrawal_limit(available);
+        metrics::withdrawal_queue_depth(queued_count - documents.len());
+
+        if documents.is_empty() {
+            self.drive.apply_drive_operations(
+                drive_operations,
+                true,
+                &block_info,
+                Some(transaction),
+                platform_version,
+            )?;
+
+            return Ok(());
+        }
+
+        let withdrawal_transactions = self.build_withdrawal_transactions_from_documents(
+            &documents,
+            &mut drive_operations,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        for document in documents.iter_mut() {
+            let Some((transaction_index, transaction_bytes)) =
+                withdrawal_transactions.get(&document.id())
+            else {
+                return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "transactions must contain a transaction",
+                )));
+            };
+
+            let transaction_id = hash::hash_to_vec(transaction_bytes);
+
+            let transaction_index =
+                u64::from_be_bytes(transaction_index.clone().try_into().map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "transaction index must be an u64",
+                    ))
+                })?);
+
+            document.set_bytes(withdrawal::properties::TRANSACTION_ID, transaction_id);
+
+            document.set_u64(withdrawal::properties::TRANSACTION_INDEX, transaction_index);
+
+            document.set_u8(
+                withdrawal::properties::STATUS,
+                withdrawals_contract::WithdrawalStatus::POOLED as u8,
+            );
+
+            document.set_i64(
+                withdrawal::properties::UPDATED_AT,
+                block_info.time_ms.try_into().map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't convert u64 block time to i64 updated_at",
+                    ))
+                })?,
+            );
+
+            document.increment_revision().map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Could not increment document revision",
+                ))
+            })?;
+        }
+
+        self.drive.add_update_multiple_documents_operations(
+            &documents,
+            &contract_fetch_info.contract,
+            contract_fetch_info
+                .contract
+                .document_type_for_name(withdrawal::NAME)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't fetch withdrawal data contract",
+                    ))
+                })?,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        // Pooled withdrawals share a transaction, so it must be enqueued only once
+        let withdrawal_transactions: Vec<WithdrawalTransactionIdAndBytes> = withdrawal_transactions
+            .into_values()
+            .collect::<BTreeMap<_, _>>()
+            .into_iter()
+            .collect();
+
+        self.drive.add_enqueue_withdrawal_transaction_operations(
+            &withdrawal_transactions,
+            &mut drive_operations,
+        );
+
+        self.drive.add_insert_withdrawn_amount_operation(
+            block_info.time_ms,
+            withdrawn_amount,
+            &mut drive_operations,
+        );
+
+        state_changes.add_drive_operations(&drive_operations);
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            &block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Calculate an amount of credits from basis points of the total
+fn basis_points_of(total: Credits, basis_points: u16) -> Credits {
+    (total as u128 * basis_points as u128 / 10_000) as Credits
+}
+
+/// Take withdrawals in order as long as their total amount is within the limit.
+///
+/// Withdrawals which don't fit are skipped, so they don't hold back smaller ones queued
+/// after them. A withdrawal larger than the limit itself is taken alone once nothing was
+/// withdrawn in the window, otherwise it could never be pooled.
+fn take_within_limit(
+    documents: Vec<Document>,
+    limit: Credits,
+    window_is_empty: bool,
+) -> Result<(Vec<Document>, Credits), Error> {
+    let mut total: Credits = 0;
+    let mut taken = vec![];
+
+    for document in documents {
+        let amount: Credits = document
+            .properties()
+            .get_integer(withdrawal::properties::AMOUNT)
+            .map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Can't get amount from withdrawal document",
+                ))
+            })?;
+
+        if amount > limit && window_is_empty && taken.is_empty() {
+            return Ok((vec![document], amount));
+        }
+
+        if let Some(new_total) = total.checked_add(amount).filter(|total| *total <= limit) {
+            total = new_total;
+            taken.push(document);
+        }
+    }
+
+    Ok((taken, total))
+}
+
+/// Select withdrawals with `Standard` pooling which are ready to be pooled
+fn ready_standard_pools(
+    documents: Vec<Document>,
+    block_time_ms: u64,
+) -> Result<Vec<Document>, Error> {
+    let mut pools: BTreeMap<u32, Vec<Document>> = BTreeMap::new();
+
+    for document in documents {
+        let core_fee_per_byte: u32 = document
+            .properties()
+            .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+            .map_err(|_| {
+                Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    "Can't get coreFeePerByte from withdrawal document",
+                ))
+            })?;
+
+        pools.entry(core_fee_per_byte).or_default().push(document);
+    }
+
+    Ok(pools
+        .into_values()
+        .filter(|pool| {
+            let waited_long_enough = pool
+                .iter()
+                .filter_map(|document| document.updated_at())
+                .min()
+                .map(|updated_at| updated_at + STANDARD_POOL_MAX_WAIT_MS <= block_time_ms)
+                .unwrap_or(true);
+
+            pool.len() >= MIN_WITHDRAWALS_PER_STANDARD_POOL || waited_long_enough
+        })
+        .flatten()
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::identifier::Identifier;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+
+    fn standard_withdrawals(count: usize, core_fee_per_byte: u32) -> Vec<Document> {
+        withdrawals_of_amount(count, core_fee_per_byte, 1000)
+    }
+
+    fn withdrawals_of_amount(count: usize, core_fee_per_byte: u32, amount: u64) -> Vec<Document> {
+        let platform_version = PlatformVersion::latest();
+
+        let data_contract = load_system_data_contract(
+            SystemDataContract::Withdrawals,
+            platform_version.protocol_version,
+        )
+        .expect("to load system data contract");
+
+        (0..count)
+            .map(|_| {
+                let mut document = get_withdrawal_document_fixture(
+                    &data_contract,
+                    Identifier::new([1u8; 32]),
+                    platform_value!({
+                        "amount": amount,
+                        "coreFeePerByte": core_fee_per_byte,
+                        "pooling": Pooling::Standard as u8,
+                        "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                        "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                    }),
+                    None,
+                    platform_version.protocol_version,
+                )
+                .expect("expected withdrawal document");
+
+                document.set_updated_at(Some(1000));
+
+                document
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_wait_for_standard_pool_to_fill_up() {
+        let mut documents = standard_withdrawals(MIN_WITHDRAWALS_PER_STANDARD_POOL, 1);
+        documents.extend(standard_withdrawals(1, 2));
+
+        let ready = ready_standard_pools(documents, 2000).expect("expected to select pools");
+
+        assert_eq!(ready.len(), MIN_WITHDRAWALS_PER_STANDARD_POOL);
+    }
+
+    #[test]
+    fn should_pool_standard_withdrawals_after_max_wait() {
+        let documents = standard_withdrawals(1, 2);
+
+        let ready = ready_standard_pools(documents, 1000 + STANDARD_POOL_MAX_WAIT_MS)
+            .expect("expected to select pools");
+
+        assert_eq!(ready.len(), 1);
+    }
+
+    #[test]
+    fn should_keep_withdrawals_exceeding_limit_queued() {
+        let documents = standard_withdrawals(3, 1);
+
+        let (taken, withdrawn_amount) =
+            take_within_limit(documents, 2500, false).expect("expected to take withdrawals");
+
+        assert_eq!(taken.len(), 2);
+        assert_eq!(withdrawn_amount, 2000);
+    }
+
+    #[test]
+    fn should_not_block_queue_behind_oversized_withdrawal() {
+        let mut documents = withdrawals_of_amount(1, 1, 5000);
+        documents.extend(standard_withdrawals(3, 1));
+
+        let (taken, withdrawn_amount) = take_within_limit(documents.clone(), 2500, false)
+            .expect("expected to take withdrawals");
+
+        assert_eq!(taken.len(), 2);
+        assert_eq!(withdrawn_amount, 2000);
+
+        // Once nothing was withdrawn in the window the oversized withdrawal is pooled alone
+        let (taken, withdrawn_amount) =
+            take_within_limit(documents, 2500, true).expect("expected to take withdrawals");
+
+        assert_eq!(taken.len(), 1);
+        assert_eq!(withdrawn_amount, 5000);
+    }
+
+    #[test]
+    fn should_calculate_basis_points_of_total_credits() {
+        assert_eq!(basis_points_of(1_000_000, 10), 1_000);
+        assert_eq!(basis_points_of(u64::MAX, 10_000), u64::MAX);
+    }
+}
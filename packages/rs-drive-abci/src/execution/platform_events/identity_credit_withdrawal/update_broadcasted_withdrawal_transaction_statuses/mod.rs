@@ -1,5 +1,6 @@
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
@@ -7,6 +8,7 @@ use dpp::version::PlatformVersion;
 use drive::grovedb::Transaction;
 
 mod v0;
+mod v1;
 
 impl<C> Platform<C>
 where
@@ -16,12 +18,14 @@ where
     ///
     /// This method is responsible for updating the status of withdrawal transactions that have been broadcasted.
     /// This is done based on the height of the last synced core block, which helps in determining whether the withdrawal
-    /// transaction has been completed or expired.
+    /// transaction has been completed or expired. Since v1 the amount of expired withdrawals
+    /// is refunded to the identity.
     ///
     /// # Arguments
     ///
     /// * `last_synced_core_height` - The height of the last synced core block.
     /// * `block_execution_context` - Contextual information about the current block execution.
+    /// * `state_changes` - Collects changed withdrawal documents and refunded identities.
     /// * `transaction` - A transaction argument to interact with the underlying storage.
     ///
     /// # Returns
@@ -40,6 +44,7 @@ where
         &self,
         last_synced_core_height: u32,
         block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<(), Error> {
@@ -52,12 +57,20 @@ where
             0 => self.update_broadcasted_withdrawal_transaction_statuses_v0(
                 last_synced_core_height,
                 block_execution_context,
+                state_changes,
+                transaction,
+                platform_version,
+            ),
+            1 => self.update_broadcasted_withdrawal_transaction_statuses_v1(
+                last_synced_core_height,
+                block_execution_context,
+                state_changes,
                 transaction,
                 platform_version,
             ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "update_broadcasted_withdrawal_transaction_statuses".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
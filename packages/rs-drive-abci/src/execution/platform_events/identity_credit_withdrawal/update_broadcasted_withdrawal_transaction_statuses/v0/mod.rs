@@ -11,6 +11,7 @@ use dpp::version::PlatformVersion;
 use drive::drive::batch::DriveOperation;
 use drive::grovedb::Transaction;
 
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
 use crate::execution::types::block_execution_context::BlockExecutionContext;
 use crate::execution::types::block_state_info::v0::BlockStateInfoV0Getters;
@@ -32,6 +33,7 @@ where
         &self,
         last_synced_core_height: u32,
         block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
     ) -> Result<(), Error> {
@@ -158,6 +160,8 @@ where
             &platform_version.drive,
         )?;
 
+        state_changes.add_drive_operations(&drive_operations);
+
         self.drive.apply_drive_operations(
             drive_operations,
             true,
@@ -180,6 +184,7 @@ mod tests {
     use serde_json::json;
     use std::str::FromStr;
 
+    use crate::event_bus::StateChanges;
     use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0;
     use crate::execution::types::block_state_info::v0::BlockStateInfoV0;
     use crate::platform_types::epoch_info::v0::EpochInfoV0;
@@ -297,6 +302,7 @@ mod tests {
             }
             .into(),
             proposer_results: None,
+            state_changes: Default::default(),
         };
 
         let data_contract = load_system_data_contract(
@@ -368,6 +374,7 @@ mod tests {
             .update_broadcasted_withdrawal_transaction_statuses_v0(
                 95,
                 &block_execution_context.into(),
+                &mut StateChanges::default(),
                 &transaction,
                 platform_version,
             )
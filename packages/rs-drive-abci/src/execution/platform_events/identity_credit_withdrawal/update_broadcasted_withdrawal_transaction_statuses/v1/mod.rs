@@ -0,0 +1,458 @@
+use dpp::block::block_info::BlockInfo;
+use dpp::block::epoch::Epoch;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::document_methods::DocumentMethodsV0;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::withdrawals_contract;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+use dpp::version::PlatformVersion;
+
+use drive::drive::batch::DriveOperation::{IdentityOperation, SystemOperation};
+use drive::drive::batch::{DriveOperation, IdentityOperationType, SystemOperationType};
+use drive::drive::identity::withdrawals::NUMBER_OF_BLOCKS_BEFORE_EXPIRED;
+use drive::grovedb::Transaction;
+
+use crate::event_bus::StateChanges;
+use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0Getters;
+use crate::execution::types::block_execution_context::BlockExecutionContext;
+use crate::execution::types::block_state_info::v0::BlockStateInfoV0Getters;
+use crate::platform_types::epoch_info::v0::EpochInfoV0Getters;
+use crate::{
+    error::{execution::ExecutionError, Error},
+    platform_types::platform::Platform,
+    rpc::core::CoreRPCLike,
+};
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Update statuses for broadcasted withdrawals and refund expired withdrawals
+    /// back to the identity balance
+    pub(super) fn update_broadcasted_withdrawal_transaction_statuses_v1(
+        &self,
+        last_synced_core_height: u32,
+        block_execution_context: &BlockExecutionContext,
+        state_changes: &mut StateChanges,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let block_info = BlockInfo {
+            time_ms: block_execution_context.block_state_info().block_time_ms(),
+            height: block_execution_context.block_state_info().height(),
+            core_height: block_execution_context
+                .block_state_info()
+                .core_chain_locked_height(),
+            epoch: Epoch::new(block_execution_context.epoch_info().current_epoch_index())?,
+        };
+
+        let data_contract_id = withdrawals_contract::ID;
+
+        let (_, Some(contract_fetch_info)) = self.drive.get_contract_with_fetch_info_and_fee(
+            data_contract_id.to_buffer(),
+            None,
+            true,
+            Some(transaction),
+            platform_version,
+        )?
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "can't fetch withdrawal data contract",
+            )));
+        };
+
+        let core_transactions = self.fetch_core_block_transactions(
+            last_synced_core_height,
+            block_execution_context
+                .block_state_info()
+                .core_chain_locked_height(),
+            platform_version,
+        )?;
+
+        let broadcasted_withdrawal_documents = self.drive.fetch_withdrawal_documents_by_status(
+            withdrawals_contract::WithdrawalStatus::BROADCASTED.into(),
+            Some(transaction),
+            platform_version,
+        )?;
+
+        let mut drive_operations: Vec<DriveOperation> = vec![];
+
+        // System credit operations read the stored value, so they can't see each other in one batch
+        let mut refunded_credits: Credits = 0;
+        let mut completed_withdrawal_credits: Credits = 0;
+
+        // Collecting only documents that have been updated
+        let documents_to_update: Vec<Document> = broadcasted_withdrawal_documents
+            .into_iter()
+            .map(|mut document| {
+                let transaction_sign_height: u32 = document
+                    .properties()
+                    .get_integer(withdrawal::properties::TRANSACTION_SIGN_HEIGHT)
+                    .map_err(|_| {
+                        Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "Can't get transactionSignHeight from withdrawal document",
+                        ))
+                    })?;
+
+                let transaction_id_bytes = document
+                    .properties()
+                    .get_bytes(withdrawal::properties::TRANSACTION_ID)
+                    .map_err(|_| {
+                        Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "Can't get transactionId from withdrawal document",
+                        ))
+                    })?;
+
+                let transaction_index = document
+                    .properties()
+                    .get_integer(withdrawal::properties::TRANSACTION_INDEX)
+                    .map_err(|_| {
+                        Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "Can't get transactionIdex from withdrawal document",
+                        ))
+                    })?;
+
+                let transaction_id = hex::encode(transaction_id_bytes);
+
+                let block_height_difference = block_execution_context
+                    .block_state_info()
+                    .core_chain_locked_height()
+                    - transaction_sign_height;
+
+                let status;
+
+                if core_transactions.contains(&transaction_id) {
+                    status = withdrawals_contract::WithdrawalStatus::COMPLETE;
+                } else if block_height_difference > NUMBER_OF_BLOCKS_BEFORE_EXPIRED {
+                    status = withdrawals_contract::WithdrawalStatus::EXPIRED;
+                } else {
+                    return Ok(None);
+                };
+
+                document.set_u8(withdrawal::properties::STATUS, status.into());
+
+                document.set_u64(withdrawal::properties::UPDATED_AT, block_info.time_ms);
+
+                document.increment_revision().map_err(Error::Protocol)?;
+
+                let amount: Credits = document
+                    .properties()
+                    .get_integer(withdrawal::properties::AMOUNT)
+                    .map_err(|_| {
+                        Error::Execution(ExecutionError::CorruptedCodeExecution(
+                            "Can't get amount from withdrawal document",
+                        ))
+                    })?;
+
+                if status == withdrawals_contract::WithdrawalStatus::COMPLETE {
+                    completed_withdrawal_credits = completed_withdrawal_credits
+                        .checked_add(amount)
+                        .ok_or(Error::Execution(ExecutionError::Overflow(
+                            "completed withdrawal credits overflow",
+                        )))?;
+                }
+
+                if status == withdrawals_contract::WithdrawalStatus::EXPIRED {
+                    self.drive.add_insert_expired_index_operation(
+                        transaction_index,
+                        &mut drive_operations,
+                    );
+
+                    // Credits never left Platform, so they are returned to the identity
+                    // and the document keeps a record of the refund
+                    drive_operations.push(IdentityOperation(
+                        IdentityOperationType::AddToIdentityBalance {
+                            identity_id: document.owner_id().to_buffer(),
+                            added_balance: amount,
+                        },
+                    ));
+
+                    refunded_credits =
+                        refunded_credits
+                            .checked_add(amount)
+                            .ok_or(Error::Execution(ExecutionError::Overflow(
+                                "refunded credits overflow",
+                            )))?;
+
+                    document.set_u8(
+                        withdrawal::properties::FAILURE_REASON,
+                        withdrawals_contract::WithdrawalFailureReason::EXPIRED.into(),
+                    );
+                    document.set_u64(withdrawal::properties::REFUNDED_AT, block_info.time_ms);
+                }
+
+                Ok(Some(document))
+            })
+            .collect::<Result<Vec<Option<Document>>, Error>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+
+        if refunded_credits > 0 {
+            drive_operations.push(SystemOperation(SystemOperationType::AddToSystemCredits {
+                amount: refunded_credits,
+            }));
+        }
+
+        if completed_withdrawal_credits > 0 {
+            drive_operations.push(SystemOperation(
+                SystemOperationType::AddToCompletedWithdrawals {
+                    amount: completed_withdrawal_credits,
+                },
+            ));
+        }
+
+        self.drive.add_update_multiple_documents_operations(
+            &documents_to_update,
+            &contract_fetch_info.contract,
+            contract_fetch_info
+                .contract
+                .document_type_for_name(withdrawal::NAME)
+                .map_err(|_| {
+                    Error::Execution(ExecutionError::CorruptedCodeExecution(
+                        "Can't fetch withdrawal data contract",
+                    ))
+                })?,
+            &mut drive_operations,
+            &platform_version.drive,
+        )?;
+
+        state_changes.add_drive_operations(&drive_operations);
+
+        self.drive.apply_drive_operations(
+            drive_operations,
+            true,
+            &block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dashcore_rpc::dashcore::{BlockHash, QuorumHash};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::dashcore::hashes::Hash;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::document::DocumentV0Getters;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::platform_value::platform_value;
+    use dpp::prelude::{Identifier, Identity};
+    use dpp::system_data_contracts::withdrawals_contract;
+    use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::version::PlatformVersion;
+    use dpp::withdrawal::Pooling;
+    use drive::tests::helpers::setup::{setup_document, setup_system_data_contract};
+    use serde_json::json;
+    use std::collections::BTreeSet;
+    use std::str::FromStr;
+
+    use crate::config::PlatformConfig;
+    use crate::event_bus::{DocumentChangeKind, StateChanges};
+    use crate::execution::types::block_execution_context::v0::BlockExecutionContextV0;
+    use crate::execution::types::block_state_info::v0::BlockStateInfoV0;
+    use crate::platform_types::epoch_info::v0::EpochInfoV0;
+    use crate::platform_types::platform_state::v0::PlatformStateV0;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn test_expired_withdrawals_are_refunded() {
+        let platform_version = PlatformVersion::latest();
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                // Completed withdrawals are counted since protocol version 2
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut mock_rpc_client = MockCoreRPCLike::new();
+
+        mock_rpc_client
+            .expect_get_block_hash()
+            .returning(|_| Ok(BlockHash::all_zeros()));
+
+        mock_rpc_client.expect_get_block_json().returning(|_| {
+            Ok(json!({
+                "tx": ["0101010101010101010101010101010101010101010101010101010101010101"]
+            }))
+        });
+
+        platform.core_rpc = mock_rpc_client;
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let block_execution_context = BlockExecutionContextV0 {
+            block_state_info: BlockStateInfoV0 {
+                height: 1,
+                round: 0,
+                block_time_ms: 1,
+                previous_block_time_ms: Some(1),
+                proposer_pro_tx_hash: [0; 32],
+                core_chain_locked_height: 96,
+                block_hash: None,
+                app_hash: None,
+            }
+            .into(),
+            epoch_info: EpochInfoV0 {
+                current_epoch_index: 1,
+                previous_epoch_index: None,
+                is_epoch_change: false,
+            }
+            .into(),
+            hpmn_count: 100,
+            withdrawal_transactions: Default::default(),
+            block_platform_state: PlatformStateV0 {
+                last_committed_block_info: None,
+                current_protocol_version_in_consensus: 0,
+                next_epoch_protocol_version: 0,
+                quorums_extended_info: Default::default(),
+                current_validator_set_quorum_hash: QuorumHash::all_zeros(),
+                next_validator_set_quorum_hash: None,
+                validator_sets: Default::default(),
+                full_masternode_list: Default::default(),
+                hpmn_masternode_list: Default::default(),
+                initialization_information: None,
+            }
+            .into(),
+            proposer_results: None,
+            state_changes: Default::default(),
+        };
+
+        let data_contract = load_system_data_contract(
+            SystemDataContract::Withdrawals,
+            platform_version.protocol_version,
+        )
+        .expect("to load system data contract");
+
+        setup_system_data_contract(&platform.drive, &data_contract, Some(&transaction));
+
+        let owner_id = Identifier::new([1u8; 32]);
+
+        let identity = Identity::create_basic_identity(owner_id.to_buffer(), platform_version)
+            .expect("expected to create an identity");
+
+        platform
+            .drive
+            .add_new_identity(
+                identity,
+                &BlockInfo::default(),
+                true,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        // the first withdrawal is mined, the second one expired
+        let withdrawals = [
+            (1u64, 93u64, [1u8; 32], 1000u64),
+            (2u64, 10u64, [3u8; 32], 5000u64),
+        ]
+        .map(|(transaction_index, sign_height, transaction_id, amount)| {
+            let document = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": amount,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Never as u8,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::BROADCASTED as u8,
+                    "transactionIndex": transaction_index,
+                    "transactionSignHeight": sign_height,
+                    "transactionId": Identifier::new(transaction_id),
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            setup_document(
+                &platform.drive,
+                &document,
+                &data_contract,
+                document_type,
+                Some(&transaction),
+            );
+
+            document
+        });
+
+        let credits_before = platform
+            .drive
+            .calculate_total_credits_balance(Some(&transaction), &platform_version.drive)
+            .expect("expected to calculate total credits")
+            .total_credits_in_platform;
+
+        let mut state_changes = StateChanges::default();
+
+        platform
+            .update_broadcasted_withdrawal_transaction_statuses_v1(
+                95,
+                &block_execution_context.into(),
+                &mut state_changes,
+                &transaction,
+                platform_version,
+            )
+            .expect("to update withdrawal statuses");
+
+        let expired_documents = platform
+            .drive
+            .fetch_withdrawal_documents_by_status(
+                withdrawals_contract::WithdrawalStatus::EXPIRED.into(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to fetch documents by status");
+
+        assert_eq!(expired_documents.len(), 1);
+        assert_eq!(expired_documents[0].id(), withdrawals[1].id());
+
+        // Only the amount of the expired withdrawal is refunded
+        let balance = platform
+            .drive
+            .fetch_identity_balance(owner_id.to_buffer(), Some(&transaction), platform_version)
+            .expect("expected to fetch balance");
+
+        assert_eq!(balance, Some(5000));
+
+        let credits_after = platform
+            .drive
+            .calculate_total_credits_balance(Some(&transaction), &platform_version.drive)
+            .expect("expected to calculate total credits")
+            .total_credits_in_platform;
+
+        assert_eq!(credits_after, credits_before + 5000);
+
+        // Both withdrawals changed their status and the refunded identity changed its balance
+        assert!(state_changes
+            .documents
+            .iter()
+            .all(|change| change.kind == DocumentChangeKind::Replaced));
+        assert_eq!(
+            state_changes
+                .documents
+                .iter()
+                .map(|change| change.document_id)
+                .collect::<BTreeSet<_>>(),
+            withdrawals
+                .iter()
+                .map(|withdrawal| withdrawal.id())
+                .collect::<BTreeSet<_>>()
+        );
+        assert_eq!(state_changes.identities, BTreeSet::from([owner_id]));
+    }
+}
@@ -29,7 +29,7 @@
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
 
-use dpp::platform_value::{platform_value, BinaryData};
+use dpp::platform_value::{platform_value, BinaryData, Value};
 use dpp::ProtocolError;
 
 use drive::dpp::identity::{Identity, KeyType, Purpose, SecurityLevel, TimestampMillis};
@@ -38,12 +38,16 @@ use crate::platform_types::system_identity_public_keys::v0::SystemIdentityPublic
 use crate::platform_types::system_identity_public_keys::SystemIdentityPublicKeys;
 use dpp::block::block_info::BlockInfo;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::data_contract::DataContract;
+use dpp::document::property_names::{CREATED_AT, UPDATED_AT};
 use dpp::document::DocumentV0;
 use dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
 use dpp::identity::IdentityV0;
 use dpp::serialization::PlatformSerializableWithPlatformVersion;
 use dpp::version::PlatformVersion;
+use drive::dpp::system_data_contracts::dpns_contract::confusables::label_skeleton;
+use drive::dpp::system_data_contracts::dpns_contract::document_types::domain::properties::LABEL_SKELETON;
 use drive::dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
 use drive::drive::batch::{
     DataContractOperationType, DocumentOperationType, DriveOperation, IdentityOperationType,
@@ -183,7 +187,11 @@ impl<C> Platform<C> {
             self.register_system_identity_operations(identity, &mut operations);
         }
 
-        self.register_dpns_top_level_domain_operations(&dpns_contract, &mut operations)?;
+        self.register_dpns_top_level_domain_operations(
+            &dpns_contract,
+            genesis_time,
+            &mut operations,
+        )?;
 
         let block_info = BlockInfo::default_with_time(genesis_time);
 
@@ -230,6 +238,7 @@ impl<C> Platform<C> {
     fn register_dpns_top_level_domain_operations<'a>(
         &'a self,
         contract: &'a DataContract,
+        genesis_time: TimestampMillis,
         operations: &mut Vec<DriveOperation<'a>>,
     ) -> Result<(), Error> {
         let domain = "dash";
@@ -247,22 +256,39 @@ impl<C> Platform<C> {
             }
         });
 
-        let document_stub_properties = document_stub_properties_value
-            .into_btree_string_map()
-            .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))?;
+        let mut document_stub_properties =
+            document_stub_properties_value
+                .into_btree_string_map()
+                .map_err(|e| Error::Protocol(ProtocolError::ValueError(e)))?;
+
+        let document_type = contract.document_type_for_name("domain")?;
+
+        // Label skeletons are only stored by DPNS contract versions that prevent homographs
+        if document_type.properties().contains_key(LABEL_SKELETON) {
+            document_stub_properties.insert(
+                LABEL_SKELETON.to_string(),
+                Value::Text(label_skeleton(domain)),
+            );
+        }
+
+        // Domain timestamps are only stored by DPNS contract versions that expire domains
+        let required_timestamp = |property_name: &str| {
+            document_type
+                .required_fields()
+                .contains(property_name)
+                .then_some(genesis_time)
+        };
 
         let document = DocumentV0 {
             id: DPNS_DASH_TLD_DOCUMENT_ID.into(),
             properties: document_stub_properties,
             owner_id: contract.owner_id(),
             revision: None,
-            created_at: None,
-            updated_at: None,
+            created_at: required_timestamp(CREATED_AT),
+            updated_at: required_timestamp(UPDATED_AT),
         }
         .into();
 
-        let document_type = contract.document_type_for_name("domain")?;
-
         let operation =
             DriveOperation::DocumentOperation(DocumentOperationType::AddDocumentForContract {
                 document_and_contract_info: DocumentAndContractInfo {
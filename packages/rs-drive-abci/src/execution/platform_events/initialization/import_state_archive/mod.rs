@@ -0,0 +1,39 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::system_identity_public_keys::SystemIdentityPublicKeys;
+use dpp::version::PlatformVersion;
+use drive::drive::state_archive::{StateArchiveReader, StateArchiveSummary};
+use std::io::Read;
+
+pub mod v0;
+
+impl<C> Platform<C> {
+    /// Creates the genesis state with the genesis time of the archive and imports the archive
+    /// on top of it. The state must be empty.
+    ///
+    /// The import is committed only if the system state of the archive proves the root hash
+    /// it was exported with.
+    pub fn import_state_archive<R: Read>(
+        &self,
+        archive: StateArchiveReader<R>,
+        system_identity_public_keys: SystemIdentityPublicKeys,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .initialization
+            .import_state_archive
+        {
+            0 => {
+                self.import_state_archive_v0(archive, system_identity_public_keys, platform_version)
+            }
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "import_state_archive".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::platform_types::system_identity_public_keys::SystemIdentityPublicKeys;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use drive::drive::state_archive::{StateArchiveReader, StateArchiveSummary};
+use drive::error::state_archive::StateArchiveError;
+use std::io::Read;
+
+impl<C> Platform<C> {
+    pub(super) fn import_state_archive_v0<R: Read>(
+        &self,
+        archive: StateArchiveReader<R>,
+        system_identity_public_keys: SystemIdentityPublicKeys,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        if self.drive.get_genesis_time(None)?.is_some() {
+            return Err(Error::Drive(drive::error::Error::StateArchive(
+                StateArchiveError::StateNotEmpty("genesis state already exists"),
+            )));
+        }
+
+        // Without a saved platform state the node starts with the initial protocol version,
+        // so it must be the one the archive was exported with
+        let archive_protocol_version = archive.header().protocol_version;
+        if archive_protocol_version != self.config.initial_protocol_version {
+            return Err(Error::Drive(drive::error::Error::StateArchive(
+                StateArchiveError::ProtocolVersionMismatch {
+                    archive: archive_protocol_version,
+                    platform: self.config.initial_protocol_version,
+                },
+            )));
+        }
+
+        let genesis_time = archive.header().genesis_time_ms.unwrap_or_default();
+
+        let transaction = self.drive.grove.start_transaction();
+
+        self.create_genesis_state(
+            genesis_time,
+            system_identity_public_keys,
+            Some(&transaction),
+            platform_version,
+        )?;
+
+        // The system state of the archive is verified against the exported root hash
+        // before anything is committed
+        let summary = self.drive.import_state_archive(
+            archive,
+            &BlockInfo::default_with_time(genesis_time),
+            Some(&transaction),
+            platform_version,
+        )?;
+
+        self.drive
+            .commit_transaction(transaction, &platform_version.drive)?;
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::PlatformConfig;
+    use crate::error::Error;
+    use crate::test::fixture::abci::static_system_identity_public_keys_v0;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::epoch::Epoch;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::Identity;
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+    use drive::drive::batch::GroveDbOpBatch;
+    use drive::drive::state_archive::StateArchiveReader;
+    use drive::error::state_archive::StateArchiveError;
+    use drive::fee_pools::epochs::operations_factory::EpochOperations;
+    use drive::fee_pools::update_storage_fee_distribution_pool_operation;
+
+    fn export_platform_state(protocol_version: u32) -> (Vec<u8>, Identity) {
+        let platform_version =
+            PlatformVersion::get(protocol_version).expect("expected a platform version");
+
+        let source = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: protocol_version,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let identity = Identity::random_identity(3, Some(1), platform_version)
+            .expect("expected a random identity");
+
+        source
+            .drive
+            .add_new_identity(
+                identity.clone(),
+                &BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        let mut batch = GroveDbOpBatch::new();
+        batch.push(
+            update_storage_fee_distribution_pool_operation(1000)
+                .expect("expected a storage fee pool operation"),
+        );
+        batch.push(
+            Epoch::new(0)
+                .unwrap()
+                .update_processing_fee_pool_operation(500)
+                .expect("expected a processing fee pool operation"),
+        );
+        source
+            .drive
+            .grove_apply_batch(batch, false, None, &platform_version.drive)
+            .expect("expected to update the fee pools");
+
+        source
+            .drive
+            .add_to_system_credits(identity.balance() + 1500, None, platform_version)
+            .expect("expected to add system credits");
+
+        let mut archive = vec![];
+        source
+            .drive
+            .export_state_archive(&mut archive, None, platform_version)
+            .expect("expected to export the state");
+
+        (archive, identity)
+    }
+
+    #[test]
+    fn should_import_exported_platform_state_with_fee_pools_and_total_credits() {
+        let platform_version = PlatformVersion::latest();
+
+        let (archive, identity) = export_platform_state(platform_version.protocol_version);
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: platform_version.protocol_version,
+                ..Default::default()
+            })
+            .build_with_mock_rpc();
+
+        let summary = platform
+            .import_state_archive(
+                StateArchiveReader::new(archive.as_slice()).expect("expected to read archive"),
+                static_system_identity_public_keys_v0().into(),
+                platform_version,
+            )
+            .expect("expected to import the archive");
+
+        assert_eq!(summary.identities, 1);
+
+        assert_eq!(
+            platform
+                .drive
+                .fetch_identity_balance(identity.id().to_buffer(), None, platform_version)
+                .expect("expected to fetch the balance"),
+            Some(identity.balance())
+        );
+
+        let total_credits = platform
+            .drive
+            .calculate_total_credits_balance(None, &platform_version.drive)
+            .expect("expected to calculate total credits");
+
+        assert_eq!(
+            total_credits.total_credits_in_platform,
+            identity.balance() + 1500
+        );
+        assert!(total_credits.ok().expect("expected credits to add up"));
+
+        assert_eq!(
+            platform
+                .drive
+                .get_storage_fees_from_distribution_pool(None, platform_version)
+                .expect("expected to get the storage fee pool"),
+            1000
+        );
+        assert_eq!(
+            platform
+                .drive
+                .get_epoch_processing_credits_for_distribution(
+                    &Epoch::new(0).unwrap(),
+                    None,
+                    platform_version
+                )
+                .expect("expected to get the epoch processing fees"),
+            500
+        );
+    }
+
+    #[test]
+    fn should_reject_archive_of_another_protocol_version() {
+        let platform_version = PlatformVersion::latest();
+
+        let (archive, _) = export_platform_state(platform_version.protocol_version);
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 1,
+                ..Default::default()
+            })
+            .build_with_mock_rpc();
+
+        let result = platform.import_state_archive(
+            StateArchiveReader::new(archive.as_slice()).expect("expected to read archive"),
+            static_system_identity_public_keys_v0().into(),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(drive::error::Error::StateArchive(
+                StateArchiveError::ProtocolVersionMismatch { .. }
+            )))
+        ));
+    }
+}
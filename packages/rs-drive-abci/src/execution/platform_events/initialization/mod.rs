@@ -2,3 +2,5 @@
 pub(in crate::execution) mod create_genesis_state;
 /// Initial core height
 pub(in crate::execution) mod initial_core_height;
+/// Importing a state archive into a fresh state
+pub(in crate::execution) mod import_state_archive;
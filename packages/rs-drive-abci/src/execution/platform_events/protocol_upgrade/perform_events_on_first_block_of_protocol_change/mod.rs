@@ -0,0 +1,63 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::grovedb::Transaction;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Migrates the state to a new protocol version.
+    ///
+    /// The first block of an epoch that activates a new protocol version is executed with the
+    /// previous protocol version but finalized with the new one, so this runs at the end of
+    /// that block proposal and creates the state the new protocol version expects.
+    ///
+    /// This function is a version handler that directs to specific version implementations
+    /// of the perform_events_on_first_block_of_protocol_change function.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_protocol_version` - The protocol version the chain is upgraded from.
+    /// * `block_info` - Information about the first block of the new protocol version.
+    /// * `transaction` - The current block transaction.
+    /// * `platform_version` - The platform version the chain is upgraded to.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - Returns an error if the state couldn't be migrated.
+    pub(in crate::execution) fn perform_events_on_first_block_of_protocol_change(
+        &self,
+        previous_protocol_version: ProtocolVersion,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .protocol_upgrade
+            .perform_events_on_first_block_of_protocol_change
+        {
+            Some(0) => self.perform_events_on_first_block_of_protocol_change_v0(
+                previous_protocol_version,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "perform_events_on_first_block_of_protocol_change".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // Nothing has to be migrated to this protocol version
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters, DocumentV0Setters};
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::confusables::label_skeleton;
+use dpp::system_data_contracts::dpns_contract::document_types::domain::properties::{
+    LABEL, LABEL_SKELETON,
+};
+use dpp::system_data_contracts::dpns_contract::document_types::{domain, preorder};
+use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::grovedb::Transaction;
+use drive::query::DriveQuery;
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+/// How many documents are read at once when they are migrated
+const MIGRATED_DOCUMENTS_PAGE_SIZE: u16 = 100;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn perform_events_on_first_block_of_protocol_change_v0(
+        &self,
+        previous_protocol_version: ProtocolVersion,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        if previous_protocol_version < 2 {
+            self.transition_to_version_2_v0(block_info, transaction, platform_version)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the credit supply counters and updates the DPNS contract of chains started
+    /// before protocol version 2.
+    ///
+    /// Credits that entered Platform and completed withdrawals before the upgrade aren't known,
+    /// so minted credits start from the credits Platform holds, including pending withdrawals,
+    /// and completed withdrawals start from zero.
+    fn transition_to_version_2_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let credit_supply = self
+            .drive
+            .calculate_credit_supply(Some(transaction), platform_version)?;
+
+        let minted_credits = credit_supply
+            .total_credits_in_platform
+            .checked_add(credit_supply.pending_withdrawals)
+            .ok_or(Error::Execution(ExecutionError::Overflow(
+                "overflow of system credits with pending withdrawals",
+            )))?;
+
+        self.drive.create_credit_supply_counters(
+            minted_credits,
+            0,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        self.migrate_dpns_contract_to_version_2_v0(block_info, transaction, platform_version)?;
+
+        Ok(())
+    }
+
+    /// Updates the DPNS contract to the version that prevents homographs and expires domains.
+    ///
+    /// Existing domains start their registration period at the upgrade and existing preorders
+    /// are purged as if they were created at the upgrade. Domains get the skeletons of their
+    /// labels. Confusable domains registered by different identities before the upgrade are kept,
+    /// but their skeletons can't be registered again.
+    fn migrate_dpns_contract_to_version_2_v0(
+        &self,
+        block_info: &BlockInfo,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let (_, Some(contract_fetch_info)) = self.drive.get_contract_with_fetch_info_and_fee(
+            dpns_contract::ID.to_buffer(),
+            None,
+            true,
+            Some(transaction),
+            platform_version,
+        )?
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "can't fetch dpns data contract",
+            )));
+        };
+
+        let previous_contract = &contract_fetch_info.contract;
+
+        // Chains started with the DPNS contract that prevents homographs have nothing to migrate
+        if previous_contract
+            .document_type_for_name(domain::NAME)?
+            .properties()
+            .contains_key(LABEL_SKELETON)
+        {
+            return Ok(());
+        }
+
+        let mut contract =
+            load_system_data_contract(SystemDataContract::DPNS, platform_version.protocol_version)?;
+
+        contract.set_version(previous_contract.version() + 1);
+
+        let mut domains = self.fetch_all_documents_v0(
+            previous_contract,
+            domain::NAME,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut preorders = self.fetch_all_documents_v0(
+            previous_contract,
+            preorder::NAME,
+            transaction,
+            platform_version,
+        )?;
+
+        for domain in domains.iter_mut() {
+            let Some(label) = domain
+                .properties()
+                .get(LABEL)
+                .and_then(|label| label.as_text())
+            else {
+                return Err(Error::Execution(ExecutionError::CorruptedDriveResponse(
+                    "dpns domain must have a label".to_string(),
+                )));
+            };
+
+            let skeleton = label_skeleton(label);
+
+            domain
+                .properties_mut()
+                .insert(LABEL_SKELETON.to_string(), Value::Text(skeleton));
+
+            domain.set_created_at(Some(block_info.time_ms));
+            domain.set_updated_at(Some(block_info.time_ms));
+        }
+
+        for preorder in preorders.iter_mut() {
+            preorder.set_created_at(Some(block_info.time_ms));
+        }
+
+        self.drive.update_contract_and_reinsert_documents(
+            &contract,
+            BTreeMap::from([
+                (domain::NAME.to_string(), domains),
+                (preorder::NAME.to_string(), preorders),
+            ]),
+            block_info,
+            Some(transaction),
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+
+    /// Fetches all documents of a document type ordered by id
+    fn fetch_all_documents_v0(
+        &self,
+        contract: &DataContract,
+        document_type_name: &str,
+        transaction: &Transaction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Document>, Error> {
+        let document_type = contract.document_type_for_name(document_type_name)?;
+
+        let mut documents = vec![];
+
+        let mut start_at = None;
+
+        loop {
+            let drive_query = DriveQuery {
+                contract,
+                document_type,
+                internal_clauses: Default::default(),
+                offset: None,
+                limit: Some(MIGRATED_DOCUMENTS_PAGE_SIZE),
+                order_by: IndexMap::new(),
+                start_at,
+                start_at_included: false,
+                block_time_ms: None,
+            };
+
+            let page = self
+                .drive
+                .query_documents(
+                    drive_query,
+                    None,
+                    false,
+                    Some(transaction),
+                    Some(platform_version.protocol_version),
+                )?
+                .documents_owned();
+
+            let page_len = page.len();
+
+            start_at = page.last().map(|document| document.id().to_buffer());
+
+            documents.extend(page);
+
+            if page_len < MIGRATED_DOCUMENTS_PAGE_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(documents)
+    }
+}
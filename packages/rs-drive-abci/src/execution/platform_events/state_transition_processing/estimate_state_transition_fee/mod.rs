@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+
+use crate::rpc::core::CoreRPCLike;
+use dpp::fee::fee_result::estimate::FeeEstimate;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    /// Estimates the fees of a state transition by dry running it against the current state.
+    ///
+    /// The state transition goes through structure and state validation but its signatures are
+    /// not verified, so it can be unsigned or dummy-signed. Nothing is applied to the state.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_transition` - The state transition to estimate fees for.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ConsensusValidationResult<FeeEstimate>, Error>` - On success, returns a
+    ///   `ConsensusValidationResult` containing the `FeeEstimate` if the state transition could be
+    ///   dry run, along with consensus errors if it's invalid or the identity can't afford it.
+    ///
+    /// # Errors
+    ///
+    /// * This function may return an `Error::Drive` if there's an issue with applying drive operations.
+    pub fn estimate_state_transition_fee(
+        &self,
+        state_transition: StateTransition,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<FeeEstimate>, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .state_transition_processing
+            .estimate_state_transition_fee
+        {
+            0 => self.estimate_state_transition_fee_v0(state_transition, platform_version),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "estimate_state_transition_fee".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
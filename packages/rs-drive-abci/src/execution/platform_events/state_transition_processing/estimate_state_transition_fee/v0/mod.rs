@@ -0,0 +1,74 @@
+use crate::error::Error;
+use crate::execution::types::execution_event::ExecutionEvent;
+use crate::execution::validation::state_transition::processor::process_state_transition_in_dry_run;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0Getters;
+use dpp::fee::fee_result::estimate::FeeEstimate;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::StateTransition;
+use dpp::version::PlatformVersion;
+use dpp::ProtocolError;
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
+    pub(super) fn estimate_state_transition_fee_v0(
+        &self,
+        state_transition: StateTransition,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<FeeEstimate>, Error> {
+        let state_read_guard = self.state.read().unwrap();
+        let platform_ref = PlatformRef {
+            drive: &self.drive,
+            state: &state_read_guard,
+            config: &self.config,
+            core_rpc: &self.core_rpc,
+        };
+
+        // We do not put the transaction, because this happens outside of a block
+        let execution_event =
+            process_state_transition_in_dry_run(&platform_ref, state_transition, None)?;
+
+        // We need the approximate block info
+        let block_info = state_read_guard
+            .last_committed_block_info()
+            .as_ref()
+            .map(|block_info| block_info.basic_info().clone())
+            .unwrap_or_default();
+
+        execution_event.and_then_borrowed_validation(|execution_event| {
+            // Operations are never applied when validating fees
+            let fee_validation_result =
+                self.validate_fees_of_event(execution_event, &block_info, None, platform_version)?;
+
+            let payer = match execution_event {
+                ExecutionEvent::PaidDriveEvent { identity, .. }
+                | ExecutionEvent::PaidBySponsorDriveEvent { identity, .. } => {
+                    Some((identity.id, identity.balance.unwrap_or_default()))
+                }
+                ExecutionEvent::PaidFromAssetLockDriveEvent {
+                    identity,
+                    added_balance,
+                    ..
+                } => {
+                    let balance = identity
+                        .balance
+                        .unwrap_or_default()
+                        .checked_add(*added_balance)
+                        .ok_or(Error::Protocol(ProtocolError::Overflow(
+                            "identity balance with added asset lock balance overflow",
+                        )))?;
+                    Some((identity.id, balance))
+                }
+                ExecutionEvent::FreeDriveEvent { .. } => None,
+            };
+
+            fee_validation_result.map_result(|fee_result| {
+                FeeEstimate::new(fee_result, payer, block_info.epoch.index).map_err(Error::Protocol)
+            })
+        })
+    }
+}
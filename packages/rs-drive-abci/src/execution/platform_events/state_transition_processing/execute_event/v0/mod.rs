@@ -9,6 +9,7 @@ use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::version::PlatformVersion;
+use drive::drive::batch::{DriveOperation, SponsorshipOperationType};
 use drive::drive::identity::update::apply_balance_change_outcome::ApplyBalanceChangeOutcomeV0Methods;
 use drive::grovedb::Transaction;
 
@@ -94,6 +95,67 @@ where
                     ))
                 }
             }
+            ExecutionEvent::PaidBySponsorDriveEvent {
+                identity,
+                data_contract_id,
+                operations,
+            } => {
+                if validation_result.is_valid_with_data() {
+                    let individual_fee_result = self
+                        .drive
+                        .apply_drive_operations(
+                            operations,
+                            true,
+                            block_info,
+                            Some(transaction),
+                            platform_version,
+                        )
+                        .map_err(Error::Drive)?;
+
+                    // Storage written by sponsored batches is attributed to the sponsor,
+                    // so refunds of that storage are paid back to the sponsor
+                    let refunded_credits = individual_fee_result
+                        .fee_refunds
+                        .calculate_refunds_amount_for_identity(identity.id)
+                        .unwrap_or_default();
+
+                    let balance_change = individual_fee_result.into_balance_change(identity.id);
+
+                    let outcome = self.drive.apply_balance_change_from_fee_to_identity(
+                        balance_change,
+                        Some(transaction),
+                        platform_version,
+                    )?;
+
+                    let actual_fee_paid = outcome.actual_fee_paid_owned();
+
+                    self.drive
+                        .apply_drive_operations(
+                            vec![DriveOperation::SponsorshipOperation(
+                                SponsorshipOperationType::AddSpentCredits {
+                                    sponsor_id: identity.id,
+                                    data_contract_id,
+                                    spent_credits: actual_fee_paid.total_required_balance(),
+                                    refunded_credits,
+                                },
+                            )],
+                            true,
+                            block_info,
+                            Some(transaction),
+                            platform_version,
+                        )
+                        .map_err(Error::Drive)?;
+
+                    Ok(SuccessfulPaidExecution(
+                        validation_result.into_data()?,
+                        actual_fee_paid,
+                    ))
+                } else {
+                    Ok(ConsensusExecutionError(
+                        SimpleConsensusValidationResult::new_with_errors(validation_result.errors),
+                    ))
+                }
+            }
             ExecutionEvent::FreeDriveEvent { operations } => {
                 self.drive
                     .apply_drive_operations(
@@ -1,3 +1,4 @@
+mod estimate_state_transition_fee;
 mod execute_event;
 mod process_raw_state_transitions;
 mod validate_fees_of_event;
@@ -2,6 +2,7 @@ mod v0;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::platform_types::platform::Platform;
 use crate::platform_types::platform_state::PlatformState;
 use crate::rpc::core::CoreRPCLike;
@@ -28,8 +29,9 @@ where
     ///
     /// # Returns
     ///
-    /// * `Result<(FeeResult, Vec<ExecTxResult>), Error>` - If the processing is successful, it returns
-    ///   a tuple consisting of a `FeeResult` and a vector of `ExecTxResult`. If the processing fails,
+    /// * `Result<(FeeResult, Vec<ExecTxResult>, StateChanges), Error>` - If the processing is successful,
+    ///   it returns a tuple consisting of a `FeeResult`, a vector of `ExecTxResult` and documents and
+    ///   identities changed by successfully executed state transitions. If the processing fails,
     ///   it returns an `Error`.
     ///
     /// # Errors
@@ -43,7 +45,7 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
-    ) -> Result<(FeeResult, Vec<(Vec<u8>, ExecTxResult)>), Error> {
+    ) -> Result<(FeeResult, Vec<(Vec<u8>, ExecTxResult)>, StateChanges), Error> {
         match platform_version
             .drive_abci
             .methods
@@ -1,8 +1,10 @@
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::execution_result::ExecutionResult::{
     ConsensusExecutionError, SuccessfulPaidExecution,
 };
 use crate::execution::validation::state_transition::processor::process_state_transition;
+use crate::metrics::{self, StateTransitionStage};
 use crate::platform_types::platform::{Platform, PlatformRef};
 use crate::platform_types::platform_state::PlatformState;
 use crate::rpc::core::CoreRPCLike;
@@ -33,8 +35,9 @@ where
     ///
     /// # Returns
     ///
-    /// * `Result<(FeeResult, Vec<ExecTxResult>), Error>` - If the processing is successful, it returns
-    ///   a tuple consisting of a `FeeResult` and a vector of `ExecTxResult`. If the processing fails,
+    /// * `Result<(FeeResult, Vec<ExecTxResult>, StateChanges), Error>` - If the processing is successful,
+    ///   it returns a tuple consisting of a `FeeResult`, a vector of `ExecTxResult` and documents and
+    ///   identities changed by successfully executed state transitions. If the processing fails,
     ///   it returns an `Error`.
     ///
     /// # Errors
@@ -49,9 +52,10 @@ where
         block_info: &BlockInfo,
         transaction: &Transaction,
         platform_version: &PlatformVersion,
-    ) -> Result<(FeeResult, Vec<(Vec<u8>, ExecTxResult)>), Error> {
+    ) -> Result<(FeeResult, Vec<(Vec<u8>, ExecTxResult)>, StateChanges), Error> {
         let state_transitions = StateTransition::deserialize_many(raw_state_transitions)?;
         let mut aggregate_fee_result = FeeResult::default();
+        let mut state_changes = StateChanges::default();
         let platform_ref = PlatformRef {
             drive: &self.drive,
             state: block_platform_state,
@@ -62,24 +66,54 @@ where
             .into_iter()
             .zip(raw_state_transitions.iter())
             .map(|(state_transition, raw_state_transition)| {
+                let state_transition_type = state_transition.state_transition_type();
+
                 let state_transition_execution_event =
                     process_state_transition(&platform_ref, state_transition, Some(transaction))?;
 
                 let execution_result = if state_transition_execution_event.is_valid() {
                     let execution_event = state_transition_execution_event.into_data()?;
-                    self.execute_event(execution_event, block_info, transaction, platform_version)?
+
+                    let event_state_changes = execution_event.state_changes();
+
+                    let _timer = metrics::state_transition_stage_duration(
+                        state_transition_type,
+                        StateTransitionStage::Apply,
+                    );
+
+                    let execution_result = self.execute_event(
+                        execution_event,
+                        block_info,
+                        transaction,
+                        platform_version,
+                    )?;
+
+                    let applied = !matches!(execution_result, ConsensusExecutionError(_));
+
+                    metrics::state_transition_stage_result(
+                        state_transition_type,
+                        StateTransitionStage::Apply,
+                        applied,
+                    );
+
+                    if applied {
+                        state_changes.append(event_state_changes);
+                    }
+
+                    execution_result
                 } else {
                     ConsensusExecutionError(SimpleConsensusValidationResult::new_with_errors(
                         state_transition_execution_event.errors,
                     ))
                 };
                 if let SuccessfulPaidExecution(_, fee_result) = &execution_result {
+                    metrics::state_transition_fees(state_transition_type, fee_result);
                     aggregate_fee_result.checked_add_assign(fee_result.clone())?;
                 }
 
                 Ok((raw_state_transition.clone(), execution_result.into()))
             })
             .collect::<Result<Vec<(Vec<u8>, ExecTxResult)>, Error>>()?;
-        Ok((aggregate_fee_result, exec_tx_results))
+        Ok((aggregate_fee_result, exec_tx_results, state_changes))
     }
 }
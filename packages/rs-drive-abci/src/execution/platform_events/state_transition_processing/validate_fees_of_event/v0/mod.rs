@@ -4,6 +4,7 @@ use crate::execution::types::execution_event::ExecutionEvent;
 use crate::platform_types::platform::Platform;
 use crate::rpc::core::CoreRPCLike;
 use dpp::block::block_info::BlockInfo;
+use dpp::consensus::state::identity::sponsorship_spending_limit_exceeded_error::SponsorshipSpendingLimitExceededError;
 use dpp::consensus::state::identity::IdentityInsufficientBalanceError;
 use dpp::consensus::state::state_error::StateError;
 use dpp::fee::fee_result::FeeResult;
@@ -31,6 +32,8 @@ where
     /// # Errors
     ///
     /// * This function may return an `Error::Execution` if the identity balance is not found.
+    /// * Sponsored events must also be covered by the spending limit the sponsor set for the
+    ///   data contract, if any.
     /// * This function may return an `Error::Drive` if there's an issue with applying drive operations.
     pub(in crate::execution) fn validate_fees_of_event_v0(
         &self,
@@ -61,7 +64,8 @@ where
                     .map_err(Error::Drive)?;
 
                 // TODO: Should take into account refunds as well
-                let total_fee = estimated_fee_result.total_base_fee();
+                // Document fees declared by data contracts must be covered as well
+                let total_fee = estimated_fee_result.total_required_balance();
                 if previous_balance_with_top_up >= total_fee {
                     Ok(ConsensusValidationResult::new_with_data(
                         estimated_fee_result,
@@ -99,7 +103,8 @@ where
                     .map_err(Error::Drive)?;
 
                 // TODO: Should take into account refunds as well
-                let required_balance = estimated_fee_result.total_base_fee();
+                // Document fees declared by data contracts must be covered as well
+                let required_balance = estimated_fee_result.total_required_balance();
                 if balance >= required_balance {
                     Ok(ConsensusValidationResult::new_with_data(
                         estimated_fee_result,
@@ -118,9 +123,189 @@ where
                     ))
                 }
             }
+            ExecutionEvent::PaidBySponsorDriveEvent {
+                identity,
+                data_contract_id,
+                operations,
+            } => {
+                let balance = identity.balance.ok_or(Error::Execution(
+                    ExecutionError::CorruptedCodeExecution("partial identity info with no balance"),
+                ))?;
+                let estimated_fee_result = self
+                    .drive
+                    .apply_drive_operations(
+                        operations.clone(),
+                        false,
+                        block_info,
+                        transaction,
+                        platform_version,
+                    )
+                    .map_err(Error::Drive)?;
+
+                let required_balance = estimated_fee_result.total_required_balance();
+
+                let mut errors = vec![];
+
+                if balance < required_balance {
+                    errors.push(
+                        StateError::IdentityInsufficientBalanceError(
+                            IdentityInsufficientBalanceError::new(
+                                identity.id,
+                                balance,
+                                required_balance,
+                            ),
+                        )
+                        .into(),
+                    );
+                }
+
+                let maybe_spending = self.drive.fetch_sponsorship_spending(
+                    identity.id,
+                    *data_contract_id,
+                    transaction,
+                    &platform_version.drive,
+                )?;
+
+                if let Some(spending) = maybe_spending {
+                    if spending.remaining() < required_balance {
+                        errors.push(
+                            StateError::SponsorshipSpendingLimitExceededError(
+                                SponsorshipSpendingLimitExceededError::new(
+                                    identity.id,
+                                    *data_contract_id,
+                                    spending.remaining(),
+                                    required_balance,
+                                ),
+                            )
+                            .into(),
+                        );
+                    }
+                }
+
+                Ok(ConsensusValidationResult::new_with_data_and_errors(
+                    estimated_fee_result,
+                    errors,
+                ))
+            }
             ExecutionEvent::FreeDriveEvent { .. } => Ok(ConsensusValidationResult::new_with_data(
                 FeeResult::default(),
             )),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::execution::types::execution_event::ExecutionEvent;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::identity::sponsorship::SponsorshipSpendingLimit;
+    use dpp::identity::PartialIdentity;
+    use dpp::platform_value::Identifier;
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::{DriveOperation, IdentityOperationType, SponsorshipOperationType};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    #[test]
+    fn should_validate_sponsored_events_against_balance_and_spending_limit() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let sponsor_id = Identifier::new([1; 32]);
+        let data_contract_id = Identifier::new([2; 32]);
+
+        let sponsored_event = |balance| ExecutionEvent::PaidBySponsorDriveEvent {
+            identity: PartialIdentity {
+                id: sponsor_id,
+                loaded_public_keys: BTreeMap::new(),
+                balance: Some(balance),
+                revision: None,
+                not_found_public_keys: BTreeSet::new(),
+            },
+            data_contract_id,
+            operations: vec![DriveOperation::IdentityOperation(
+                IdentityOperationType::AddToIdentityBalance {
+                    identity_id: [3; 32],
+                    added_balance: 100,
+                },
+            )],
+        };
+
+        let set_limit = |limit| {
+            platform
+                .drive
+                .apply_drive_operations(
+                    vec![DriveOperation::SponsorshipOperation(
+                        SponsorshipOperationType::SetSpendingLimits {
+                            sponsor_id,
+                            spending_limits: vec![SponsorshipSpendingLimit {
+                                data_contract_id,
+                                limit: Some(limit),
+                            }],
+                        },
+                    )],
+                    true,
+                    &BlockInfo::default(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to set the spending limit");
+        };
+
+        let validate = |balance| {
+            platform
+                .validate_fees_of_event_v0(
+                    &sponsored_event(balance),
+                    &BlockInfo::default(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to validate fees")
+        };
+
+        // Without a spending limit the sponsor only needs to cover the fees
+        let validation_result = validate(u64::MAX);
+
+        assert!(validation_result.is_valid());
+
+        let required_balance = validation_result
+            .into_data()
+            .expect("expected a fee result")
+            .total_required_balance();
+
+        assert!(required_balance > 0);
+
+        let validation_result = validate(required_balance - 1);
+
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::IdentityInsufficientBalanceError(_)
+            )]
+        ));
+
+        set_limit(required_balance);
+
+        assert!(validate(u64::MAX).is_valid());
+
+        set_limit(required_balance - 1);
+
+        let validation_result = validate(u64::MAX);
+
+        let [ConsensusError::StateError(StateError::SponsorshipSpendingLimitExceededError(error))] =
+            validation_result.errors.as_slice()
+        else {
+            panic!("expected a spending limit exceeded error");
+        };
+
+        assert_eq!(error.sponsor_id(), &sponsor_id);
+        assert_eq!(error.data_contract_id(), &data_contract_id);
+        assert_eq!(error.remaining_spending_limit(), required_balance - 1);
+        assert_eq!(error.required_amount(), required_balance);
+    }
+}
@@ -2,6 +2,7 @@
 pub mod v0;
 
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_execution_context::v0::{
     BlockExecutionContextV0Getters, BlockExecutionContextV0MutableGetters,
     BlockExecutionContextV0OwnedGetters, BlockExecutionContextV0Setters,
@@ -66,6 +67,12 @@ impl BlockExecutionContextV0Getters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results.as_ref(),
         }
     }
+
+    fn state_changes(&self) -> &StateChanges {
+        match self {
+            BlockExecutionContext::V0(v0) => &v0.state_changes,
+        }
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContext {
@@ -104,6 +111,12 @@ impl BlockExecutionContextV0Setters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results = results,
         }
     }
+
+    fn set_state_changes(&mut self, state_changes: StateChanges) {
+        match self {
+            BlockExecutionContext::V0(v0) => v0.state_changes = state_changes,
+        }
+    }
 }
 
 impl BlockExecutionContextV0MutableGetters for BlockExecutionContext {
@@ -136,6 +149,12 @@ impl BlockExecutionContextV0MutableGetters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results_mut(),
         }
     }
+
+    fn state_changes_mut(&mut self) -> &mut StateChanges {
+        match self {
+            BlockExecutionContext::V0(v0) => v0.state_changes_mut(),
+        }
+    }
 }
 
 impl BlockExecutionContextV0OwnedGetters for BlockExecutionContext {
@@ -173,4 +192,11 @@ impl BlockExecutionContextV0OwnedGetters for BlockExecutionContext {
             BlockExecutionContext::V0(v0) => v0.proposer_results,
         }
     }
+
+    /// Consumes the object and returns the owned `StateChanges`.
+    fn state_changes_owned(self) -> StateChanges {
+        match self {
+            BlockExecutionContext::V0(v0) => v0.state_changes,
+        }
+    }
 }
@@ -27,6 +27,7 @@
 // DEALINGS IN THE SOFTWARE.
 //
 
+use crate::event_bus::StateChanges;
 use crate::execution::types::block_state_info::BlockStateInfo;
 
 use crate::platform_types::epoch_info::EpochInfo;
@@ -50,6 +51,8 @@ pub struct BlockExecutionContextV0 {
     pub block_platform_state: PlatformState,
     /// The response prepare proposal if proposed by us
     pub proposer_results: Option<ResponsePrepareProposal>,
+    /// Documents and identities changed by state transitions and at the end of the block
+    pub state_changes: StateChanges,
 }
 /// A trait defining getter methods for interacting with a BlockExecutionContextV0.
 pub trait BlockExecutionContextV0Getters {
@@ -70,6 +73,9 @@ pub trait BlockExecutionContextV0Getters {
 
     /// Returns a reference of the proposer_results field.
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal>;
+
+    /// Returns a reference of the state_changes field.
+    fn state_changes(&self) -> &StateChanges;
 }
 
 /// A trait defining setter methods for interacting with a BlockExecutionContextV0.
@@ -91,6 +97,9 @@ pub trait BlockExecutionContextV0Setters {
 
     /// Sets the proposer_results field.
     fn set_proposer_results(&mut self, results: Option<ResponsePrepareProposal>);
+
+    /// Sets the state_changes field.
+    fn set_state_changes(&mut self, state_changes: StateChanges);
 }
 
 /// A trait defining methods for interacting with a BlockExecutionContextV0.
@@ -109,6 +118,9 @@ pub trait BlockExecutionContextV0MutableGetters {
 
     /// Returns a mutable reference to the proposer_results field.
     fn proposer_results_mut(&mut self) -> Option<&mut ResponsePrepareProposal>;
+
+    /// Returns a mutable reference to the state_changes field.
+    fn state_changes_mut(&mut self) -> &mut StateChanges;
 }
 
 /// A trait defining methods for interacting with a BlockExecutionContextV0.
@@ -127,6 +139,9 @@ pub trait BlockExecutionContextV0OwnedGetters {
 
     /// Consumes the BlockExecutionContextV0 and returns the proposer_results field.
     fn proposer_results_owned(self) -> Option<ResponsePrepareProposal>;
+
+    /// Consumes the BlockExecutionContextV0 and returns the state_changes field.
+    fn state_changes_owned(self) -> StateChanges;
 }
 
 impl BlockExecutionContextV0Getters for BlockExecutionContextV0 {
@@ -159,6 +174,11 @@ impl BlockExecutionContextV0Getters for BlockExecutionContextV0 {
     fn proposer_results(&self) -> Option<&ResponsePrepareProposal> {
         self.proposer_results.as_ref()
     }
+
+    /// Returns a reference to the state_changes field.
+    fn state_changes(&self) -> &StateChanges {
+        &self.state_changes
+    }
 }
 
 impl BlockExecutionContextV0Setters for BlockExecutionContextV0 {
@@ -186,6 +206,10 @@ impl BlockExecutionContextV0Setters for BlockExecutionContextV0 {
     fn set_proposer_results(&mut self, results: Option<ResponsePrepareProposal>) {
         self.proposer_results = results;
     }
+    /// Sets the state_changes field.
+    fn set_state_changes(&mut self, state_changes: StateChanges) {
+        self.state_changes = state_changes;
+    }
 }
 
 impl BlockExecutionContextV0MutableGetters for BlockExecutionContextV0 {
@@ -213,6 +237,11 @@ impl BlockExecutionContextV0MutableGetters for BlockExecutionContextV0 {
     fn proposer_results_mut(&mut self) -> Option<&mut ResponsePrepareProposal> {
         self.proposer_results.as_mut()
     }
+
+    /// Returns a mutable reference to the state_changes field.
+    fn state_changes_mut(&mut self) -> &mut StateChanges {
+        &mut self.state_changes
+    }
 }
 
 impl BlockExecutionContextV0OwnedGetters for BlockExecutionContextV0 {
@@ -240,4 +269,9 @@ impl BlockExecutionContextV0OwnedGetters for BlockExecutionContextV0 {
     fn proposer_results_owned(self) -> Option<ResponsePrepareProposal> {
         self.proposer_results
     }
+
+    /// Consumes the object and returns the owned `StateChanges`.
+    fn state_changes_owned(self) -> StateChanges {
+        self.state_changes
+    }
 }
@@ -2,19 +2,33 @@ mod v0;
 
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::StateChanges;
 use crate::execution::types::execution_event::ExecutionEvent::{
-    PaidDriveEvent, PaidFromAssetLockDriveEvent,
+    FreeDriveEvent, PaidBySponsorDriveEvent, PaidDriveEvent, PaidFromAssetLockDriveEvent,
 };
 use dpp::block::epoch::Epoch;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
 use dpp::fee::Credits;
 
 use dpp::identity::PartialIdentity;
+use dpp::platform_value::Identifier;
+use std::collections::BTreeMap;
 
 use dpp::version::PlatformVersion;
 use drive::state_transition_action::StateTransitionAction;
 
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::document_types::domain;
+use dpp::ProtocolError;
 use drive::drive::batch::transitions::DriveHighLevelOperationConverter;
-use drive::drive::batch::DriveOperation;
+use drive::drive::batch::{DriveOperation, IdentityOperationType, SystemOperationType};
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentReplaceTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
+use drive::state_transition_action::document::documents_batch::DocumentsBatchTransitionAction;
 
 /// An execution event
 #[derive(Clone)]
@@ -35,6 +49,16 @@ pub(in crate::execution) enum ExecutionEvent<'a> {
         /// the operations that should be performed
         operations: Vec<DriveOperation<'a>>,
     },
+    /// A drive event that is paid by an identity sponsoring documents of a data contract,
+    /// within the spending limit the sponsor set for it
+    PaidBySponsorDriveEvent {
+        /// The identity paying for the event
+        identity: PartialIdentity,
+        /// The sponsored data contract
+        data_contract_id: Identifier,
+        /// the operations that the owner of the documents is requesting to perform
+        operations: Vec<DriveOperation<'a>>,
+    },
     /// A drive event that is free
     FreeDriveEvent {
         /// the operations that should be performed
@@ -73,6 +97,36 @@ impl<'a> ExecutionEvent<'a> {
             operations,
         }
     }
+
+    /// Documents and identities the event changes, including the identity paying for it
+    pub(in crate::execution) fn state_changes(&self) -> StateChanges {
+        let mut state_changes = StateChanges::default();
+
+        let operations = match self {
+            PaidDriveEvent {
+                identity,
+                operations,
+            }
+            | PaidFromAssetLockDriveEvent {
+                identity,
+                operations,
+                ..
+            }
+            | PaidBySponsorDriveEvent {
+                identity,
+                operations,
+                ..
+            } => {
+                state_changes.identities.insert(identity.id);
+                operations
+            }
+            FreeDriveEvent { operations } => operations,
+        };
+
+        state_changes.add_drive_operations(operations);
+
+        state_changes
+    }
 }
 
 impl<'a> ExecutionEvent<'a> {
@@ -110,20 +164,690 @@ impl<'a> ExecutionEvent<'a> {
                     )))
                 }
             }
-            _ => {
+            StateTransitionAction::MasternodeVoteAction(_) => {
+                // Masternodes don't pay for their votes, voter identities usually have no balance
                 let operations =
                     action.into_high_level_drive_operations(epoch, platform_version)?;
-                if let Some(identity) = identity {
-                    Ok(PaidDriveEvent {
+                Ok(FreeDriveEvent { operations })
+            }
+            _ => {
+                let (dpns_registration_fees, document_type_fees, sponsored_data_contract_id) =
+                    if let StateTransitionAction::DocumentsBatchAction(documents_batch_action) =
+                        &action
+                    {
+                        (
+                            Self::dpns_registration_fees(documents_batch_action, platform_version)?,
+                            Self::document_type_fees(documents_batch_action)?,
+                            Self::sponsored_data_contract_id(documents_batch_action)?,
+                        )
+                    } else {
+                        (0, BTreeMap::new(), None)
+                    };
+                let mut operations =
+                    action.into_high_level_drive_operations(epoch, platform_version)?;
+                if dpns_registration_fees > 0 {
+                    operations.push(DriveOperation::SystemOperation(
+                        SystemOperationType::AddFixedProcessingFee {
+                            amount: dpns_registration_fees,
+                        },
+                    ));
+                }
+                operations.extend(Self::document_type_fee_operations(
+                    document_type_fees,
+                    platform_version,
+                )?);
+                match (identity, sponsored_data_contract_id) {
+                    (Some(identity), Some(data_contract_id)) => Ok(PaidBySponsorDriveEvent {
                         identity,
+                        data_contract_id,
                         operations,
-                    })
-                } else {
-                    Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                    }),
+                    (Some(identity), None) => Ok(PaidDriveEvent {
+                        identity,
+                        operations,
+                    }),
+                    (None, _) => Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
                         "partial identity should be present",
-                    )))
+                    ))),
+                }
+            }
+        }
+    }
+
+    /// The data contract of a sponsored documents batch, sponsor keys are bound to a single
+    /// contract so all transitions of the batch belong to it
+    fn sponsored_data_contract_id(
+        documents_batch_action: &DocumentsBatchTransitionAction,
+    ) -> Result<Option<Identifier>, Error> {
+        if documents_batch_action.sponsor_id().is_none() {
+            return Ok(None);
+        }
+
+        documents_batch_action
+            .transitions()
+            .first()
+            .map(|transition| Some(transition.base().data_contract_id()))
+            .ok_or(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "a sponsored documents batch should have transitions",
+            )))
+    }
+
+    /// The registration and renewal fees of DPNS domains created or replaced in a documents batch,
+    /// the fee of a domain depends on the length of its label
+    fn dpns_registration_fees(
+        documents_batch_action: &DocumentsBatchTransitionAction,
+        platform_version: &PlatformVersion,
+    ) -> Result<Credits, Error> {
+        let fee_schedule = platform_version
+            .drive_abci
+            .dpns
+            .registration_fee_by_label_length;
+
+        let mut fees: Credits = 0;
+
+        for transition in documents_batch_action.transitions() {
+            let (base, data) = match transition {
+                DocumentTransitionAction::CreateAction(create_action) => {
+                    (create_action.base(), create_action.data())
                 }
+                DocumentTransitionAction::ReplaceAction(replace_action) => {
+                    (replace_action.base(), replace_action.data())
+                }
+                _ => continue,
+            };
+
+            if base.data_contract_id() != dpns_contract::ID
+                || base.document_type_name() != domain::NAME
+            {
+                continue;
             }
+
+            let label_length = data
+                .get_str(domain::properties::LABEL)
+                .map_err(ProtocolError::ValueError)?
+                .len();
+
+            let Some((_, fee)) = fee_schedule
+                .iter()
+                .find(|(max_label_length, _)| label_length <= *max_label_length as usize)
+                .or(fee_schedule.last())
+            else {
+                continue;
+            };
+
+            fees = fees
+                .checked_add(*fee)
+                .ok_or(Error::Execution(ExecutionError::Overflow(
+                    "dpns registration fees overflow",
+                )))?;
         }
+
+        Ok(fees)
+    }
+
+    /// The document fees declared by data contracts for documents created or replaced in a
+    /// documents batch, by the identity receiving them
+    fn document_type_fees(
+        documents_batch_action: &DocumentsBatchTransitionAction,
+    ) -> Result<BTreeMap<Identifier, Credits>, Error> {
+        let mut fees: BTreeMap<Identifier, Credits> = BTreeMap::new();
+
+        for transition in documents_batch_action.transitions() {
+            let (base, is_creation) = match transition {
+                DocumentTransitionAction::CreateAction(create_action) => {
+                    (create_action.base(), true)
+                }
+                DocumentTransitionAction::ReplaceAction(replace_action) => {
+                    (replace_action.base(), false)
+                }
+                _ => continue,
+            };
+
+            let data_contract_fetch_info = base.data_contract_fetch_info();
+            let data_contract = &data_contract_fetch_info.contract;
+            let document_type = data_contract.document_type_for_name(base.document_type_name())?;
+
+            let fee = if is_creation {
+                document_type.document_creation_fee()
+            } else {
+                document_type.document_replace_fee()
+            };
+
+            if fee == 0 {
+                continue;
+            }
+
+            let beneficiary = document_type
+                .document_fee_beneficiary()
+                .unwrap_or(data_contract.owner_id());
+
+            let beneficiary_fees = fees.entry(beneficiary).or_default();
+            *beneficiary_fees = beneficiary_fees.checked_add(fee).ok_or(Error::Execution(
+                ExecutionError::Overflow("document type fees overflow"),
+            ))?;
+        }
+
+        Ok(fees)
+    }
+
+    /// Charges document fees to the identity paying for the state transition and transfers
+    /// them to their beneficiaries, the platform cut goes to the epoch pools
+    fn document_type_fee_operations(
+        document_type_fees: BTreeMap<Identifier, Credits>,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        let platform_cut_basis_points = platform_version
+            .drive_abci
+            .document_fees
+            .platform_cut_basis_points as u128;
+
+        let mut operations = vec![];
+        let mut platform_cut: Credits = 0;
+
+        for (beneficiary, fee) in document_type_fees {
+            let cut = (fee as u128 * platform_cut_basis_points / 10_000) as Credits;
+            let beneficiary_share = fee - cut;
+
+            platform_cut += cut;
+
+            if beneficiary_share > 0 {
+                operations.push(DriveOperation::SystemOperation(
+                    SystemOperationType::AddDocumentTypeFee {
+                        amount: beneficiary_share,
+                    },
+                ));
+                operations.push(DriveOperation::IdentityOperation(
+                    IdentityOperationType::AddToIdentityBalance {
+                        identity_id: beneficiary.to_buffer(),
+                        added_balance: beneficiary_share,
+                    },
+                ));
+            }
+        }
+
+        if platform_cut > 0 {
+            operations.push(DriveOperation::SystemOperation(
+                SystemOperationType::AddFixedProcessingFee {
+                    amount: platform_cut,
+                },
+            ));
+        }
+
+        Ok(operations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PlatformConfig;
+    use crate::execution::types::execution_result::ExecutionResult::{
+        ConsensusExecutionError, SuccessfulPaidExecution,
+    };
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use crate::test::helpers::signer::SimpleSigner;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::{Identity, IdentityPublicKey, IdentityV0};
+    use dpp::platform_value::{platform_value, Bytes32};
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+    use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+    use dpp::tests::fixtures::{
+        get_data_contract_fixture, get_document_transitions_fixture,
+        get_dpns_parent_document_fixture, ParentDocumentOptions,
+    };
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
+    use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionActionType;
+    use drive::state_transition_action::document::documents_batch::v0::DocumentsBatchTransitionActionV0;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const DOCUMENT_CREATION_FEE: Credits = 100_000_000_000;
+
+    struct DocumentTypeFeeTestData {
+        platform: TempPlatform<MockCoreRPCLike>,
+        payer_id: Identifier,
+        beneficiary_id: Identifier,
+        documents_batch_transition: Vec<u8>,
+    }
+
+    /// Registers a contract with a document type charging a creation fee to a beneficiary
+    /// and prepares a documents batch creating such a document on behalf of the payer
+    fn setup_document_type_fee_test(payer_balance: Credits) -> DocumentTypeFeeTestData {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = platform
+            .state
+            .read()
+            .unwrap()
+            .current_platform_version()
+            .expect("expected a platform version");
+
+        let mut rng = StdRng::seed_from_u64(44);
+
+        let mut signer = SimpleSigner::default();
+
+        let (key, private_key) = IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+            1,
+            Some(44),
+            platform_version,
+        )
+        .expect("expected to get key pair");
+
+        signer.add_key(key.clone(), private_key);
+
+        let payer: Identity = IdentityV0 {
+            id: Identifier::new([1; 32]),
+            public_keys: BTreeMap::from([(1, key.clone())]),
+            balance: payer_balance,
+            revision: 0,
+        }
+        .into();
+
+        let owner = Identity::random_identity(3, Some(45), platform_version)
+            .expect("expected a random identity");
+
+        let beneficiary = Identity::random_identity(3, Some(46), platform_version)
+            .expect("expected a random identity");
+
+        let payer_id = payer.id();
+        let beneficiary_id = beneficiary.id();
+
+        for identity in [payer, owner.clone(), beneficiary] {
+            platform
+                .drive
+                .add_new_identity(
+                    identity,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add identity");
+        }
+
+        let mut data_contract =
+            get_data_contract_fixture(Some(owner.id()), platform_version.protocol_version)
+                .data_contract_owned();
+
+        let mut paid_document_schema = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 32
+                }
+            },
+            "additionalProperties": false,
+            "documentCreationFee": DOCUMENT_CREATION_FEE,
+        });
+
+        paid_document_schema
+            .set_value("documentFeeBeneficiary", beneficiary_id.into())
+            .expect("expected to set the fee beneficiary");
+
+        data_contract
+            .set_document_schema("paidDocument", paid_document_schema, true, platform_version)
+            .expect("expected to set the paid document schema");
+
+        platform
+            .drive
+            .apply_contract(
+                &data_contract,
+                BlockInfo::default(),
+                true,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        let document_type = data_contract
+            .document_type_for_name("paidDocument")
+            .expect("expected a paid document type");
+
+        let entropy = Bytes32::random_with_rng(&mut rng);
+
+        let document = document_type
+            .random_document_with_identifier_and_entropy(
+                &mut rng,
+                payer_id,
+                entropy,
+                platform_version,
+            )
+            .expect("expected a random document");
+
+        let documents_batch_transition =
+            DocumentsBatchTransition::new_document_creation_transition_from_document(
+                document,
+                document_type,
+                entropy.0,
+                &key,
+                &signer,
+                platform_version,
+                None,
+                None,
+                None,
+            )
+            .expect("expected to create documents batch transition")
+            .serialize_to_bytes()
+            .expect("expected to serialize documents batch transition");
+
+        DocumentTypeFeeTestData {
+            platform,
+            payer_id,
+            beneficiary_id,
+            documents_batch_transition,
+        }
+    }
+
+    fn fetch_balance(platform: &TempPlatform<MockCoreRPCLike>, identity_id: Identifier) -> Credits {
+        platform
+            .drive
+            .fetch_identity_balance(identity_id.to_buffer(), None, PlatformVersion::latest())
+            .expect("expected to fetch balance")
+            .expect("expected the identity to exist")
+    }
+
+    #[test]
+    fn document_creation_fee_should_be_paid_by_the_creator_to_the_beneficiary() {
+        let DocumentTypeFeeTestData {
+            platform,
+            payer_id,
+            beneficiary_id,
+            documents_batch_transition,
+        } = setup_document_type_fee_test(2 * DOCUMENT_CREATION_FEE);
+
+        let platform_version = PlatformVersion::latest();
+
+        let platform_cut = DOCUMENT_CREATION_FEE
+            * platform_version
+                .drive_abci
+                .document_fees
+                .platform_cut_basis_points as Credits
+            / 10_000;
+
+        let payer_balance_before = fetch_balance(&platform, payer_id);
+        let beneficiary_balance_before = fetch_balance(&platform, beneficiary_id);
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(
+                documents_batch_transition,
+                &BlockInfo::default(),
+                &transaction,
+            )
+            .expect("expected to execute documents batch");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        let SuccessfulPaidExecution(_, fee_result) = execution_result else {
+            panic!(
+                "expected a successful paid execution, got {:?}",
+                execution_result
+            );
+        };
+
+        assert_eq!(
+            fee_result.document_type_fees,
+            DOCUMENT_CREATION_FEE - platform_cut
+        );
+
+        // The platform cut is charged as a processing fee and goes to the epoch pools
+        assert!(fee_result.processing_fee > platform_cut);
+
+        assert_eq!(
+            fetch_balance(&platform, payer_id),
+            payer_balance_before - fee_result.total_required_balance()
+        );
+
+        assert_eq!(
+            fetch_balance(&platform, beneficiary_id),
+            beneficiary_balance_before + DOCUMENT_CREATION_FEE - platform_cut
+        );
+    }
+
+    #[test]
+    fn document_creation_fee_should_require_the_creator_to_cover_it() {
+        let DocumentTypeFeeTestData {
+            platform,
+            payer_id,
+            beneficiary_id,
+            documents_batch_transition,
+        } = setup_document_type_fee_test(DOCUMENT_CREATION_FEE / 2);
+
+        let payer_balance_before = fetch_balance(&platform, payer_id);
+        let beneficiary_balance_before = fetch_balance(&platform, beneficiary_id);
+
+        let transaction = platform.drive.grove.start_transaction();
+
+        let execution_result = platform
+            .execute_tx(
+                documents_batch_transition,
+                &BlockInfo::default(),
+                &transaction,
+            )
+            .expect("expected to execute documents batch");
+
+        platform
+            .drive
+            .grove
+            .commit_transaction(transaction)
+            .unwrap()
+            .expect("expected to commit transaction");
+
+        let ConsensusExecutionError(validation_result) = execution_result else {
+            panic!("expected a consensus error, got {:?}", execution_result);
+        };
+
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::IdentityInsufficientBalanceError(_)
+            )]
+        ));
+
+        assert_eq!(fetch_balance(&platform, payer_id), payer_balance_before);
+        assert_eq!(
+            fetch_balance(&platform, beneficiary_id),
+            beneficiary_balance_before
+        );
+    }
+
+    #[test]
+    fn document_type_fees_should_be_split_between_beneficiaries_and_platform() {
+        let platform_version = PlatformVersion::latest();
+
+        let beneficiary = Identifier::new([1; 32]);
+
+        let operations = ExecutionEvent::document_type_fee_operations(
+            BTreeMap::from([(beneficiary, 1_000_000)]),
+            platform_version,
+        )
+        .expect("expected to create document type fee operations");
+
+        let platform_cut = 1_000_000
+            * platform_version
+                .drive_abci
+                .document_fees
+                .platform_cut_basis_points as Credits
+            / 10_000;
+
+        assert_eq!(operations.len(), 3);
+
+        assert!(matches!(
+            operations[0],
+            DriveOperation::SystemOperation(SystemOperationType::AddDocumentTypeFee { amount })
+                if amount == 1_000_000 - platform_cut
+        ));
+
+        assert!(matches!(
+            operations[1],
+            DriveOperation::IdentityOperation(IdentityOperationType::AddToIdentityBalance {
+                identity_id,
+                added_balance,
+            }) if identity_id == beneficiary.to_buffer() && added_balance == 1_000_000 - platform_cut
+        ));
+
+        assert!(matches!(
+            operations[2],
+            DriveOperation::SystemOperation(SystemOperationType::AddFixedProcessingFee { amount })
+                if amount == platform_cut
+        ));
+    }
+
+    /// Creates a documents batch action registering DPNS domains with the given labels
+    fn dpns_domain_registrations_action(
+        platform: &TempPlatform<MockCoreRPCLike>,
+        labels: &[&str],
+        platform_version: &PlatformVersion,
+    ) -> DocumentsBatchTransitionAction {
+        let dpns_contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+
+        let document_type = dpns_contract_fetch_info
+            .contract
+            .document_type_for_name(domain::NAME)
+            .expect("expected to get domain document type");
+
+        let owner_id = generate_random_identifier_struct();
+
+        let documents = labels
+            .iter()
+            .map(|label| {
+                let document = get_dpns_parent_document_fixture(
+                    ParentDocumentOptions {
+                        label: label.to_string(),
+                        normalized_label: label.to_lowercase(),
+                        owner_id,
+                    },
+                    platform_version.protocol_version,
+                );
+                (document, document_type, Bytes32::default())
+            })
+            .collect();
+
+        let transitions =
+            get_document_transitions_fixture([(DocumentTransitionActionType::Create, documents)]);
+
+        let transitions = transitions
+            .iter()
+            .map(|transition| {
+                let document_create_transition = transition
+                    .as_transition_create()
+                    .expect("expected a document create transition");
+                DocumentCreateTransitionAction::from_document_borrowed_create_transition_with_contract_lookup(
+                    document_create_transition,
+                    |_identifier| Ok(dpns_contract_fetch_info.clone()),
+                )
+                .expect("expected to create action")
+                .into()
+            })
+            .collect();
+
+        DocumentsBatchTransitionActionV0 {
+            owner_id,
+            sponsor_id: None,
+            transitions,
+        }
+        .into()
+    }
+
+    #[test]
+    fn dpns_registration_fees_should_depend_on_label_length() {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = PlatformVersion::get(2).expect("expected platform version 2");
+
+        let fee_schedule = platform_version
+            .drive_abci
+            .dpns
+            .registration_fee_by_label_length;
+
+        let fee_for_label_length = |label_length: usize| {
+            fee_schedule
+                .iter()
+                .find(|(max_label_length, _)| label_length <= *max_label_length as usize)
+                .map(|(_, fee)| *fee)
+                .expect("expected a fee for the label length")
+        };
+
+        for label in ["abc", "abcd", "alice", "alice-and-bob"] {
+            let action = dpns_domain_registrations_action(&platform, &[label], platform_version);
+
+            assert_eq!(
+                ExecutionEvent::dpns_registration_fees(&action, platform_version)
+                    .expect("expected to calculate dpns registration fees"),
+                fee_for_label_length(label.len()),
+                "unexpected registration fee for {}",
+                label
+            );
+        }
+
+        // Shorter labels are more expensive
+        assert!(fee_for_label_length(3) > fee_for_label_length(4));
+        assert!(fee_for_label_length(4) > fee_for_label_length(5));
+        assert!(fee_for_label_length(5) > fee_for_label_length(13));
+
+        let action =
+            dpns_domain_registrations_action(&platform, &["abc", "alice"], platform_version);
+
+        assert_eq!(
+            ExecutionEvent::dpns_registration_fees(&action, platform_version)
+                .expect("expected to calculate dpns registration fees"),
+            fee_for_label_length(3) + fee_for_label_length(5)
+        );
+    }
+
+    #[test]
+    fn dpns_registration_fees_should_not_be_charged_in_protocol_version_1() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = PlatformVersion::first();
+
+        let action = dpns_domain_registrations_action(&platform, &["abc"], platform_version);
+
+        assert_eq!(
+            ExecutionEvent::dpns_registration_fees(&action, platform_version)
+                .expect("expected to calculate dpns registration fees"),
+            0
+        );
     }
 }
@@ -0,0 +1,39 @@
+use dpp::data_contract::DataContract;
+use dpp::validation::SimpleConsensusValidationResult;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use dpp::version::PlatformVersion;
+use crate::error::Error;
+use crate::error::execution::ExecutionError;
+use crate::execution::validation::state_transition::common::validate_data_contract_fee_beneficiaries_exist_in_state::v0::validate_data_contract_fee_beneficiaries_exist_in_state_v0;
+
+pub mod v0;
+
+pub(crate) fn validate_data_contract_fee_beneficiaries_exist_in_state(
+    data_contract: &DataContract,
+    drive: &Drive,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    match platform_version
+        .drive_abci
+        .validation_and_processing
+        .state_transitions
+        .common_validation_methods
+        .validate_data_contract_fee_beneficiaries_exist_in_state
+    {
+        Some(0) => validate_data_contract_fee_beneficiaries_exist_in_state_v0(
+            data_contract,
+            drive,
+            transaction,
+            platform_version,
+        ),
+        // Document fees can't be declared in this protocol version
+        None => Ok(SimpleConsensusValidationResult::default()),
+        Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+            method: "validate_data_contract_fee_beneficiaries_exist_in_state".to_string(),
+            known_versions: vec![0],
+            received: version,
+        })),
+    }
+}
@@ -0,0 +1,45 @@
+use crate::error::Error;
+
+use dpp::consensus::state::data_contract::data_contract_fee_beneficiary_not_found_error::DataContractFeeBeneficiaryNotFoundError;
+
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+
+use dpp::validation::SimpleConsensusValidationResult;
+
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+
+use dpp::version::PlatformVersion;
+
+/// This will validate that identities receiving document fees of the contract exist,
+/// otherwise the fees could not be credited to them
+pub(super) fn validate_data_contract_fee_beneficiaries_exist_in_state_v0(
+    data_contract: &DataContract,
+    drive: &Drive,
+    transaction: TransactionArg,
+    platform_version: &PlatformVersion,
+) -> Result<SimpleConsensusValidationResult, Error> {
+    for (document_type_name, document_type) in data_contract.document_types() {
+        let Some(beneficiary_id) = document_type.document_fee_beneficiary() else {
+            continue;
+        };
+
+        if drive
+            .fetch_identity_balance(beneficiary_id.to_buffer(), transaction, platform_version)?
+            .is_none()
+        {
+            return Ok(SimpleConsensusValidationResult::new_with_error(
+                DataContractFeeBeneficiaryNotFoundError::new(
+                    data_contract.id(),
+                    document_type_name.clone(),
+                    beneficiary_id,
+                )
+                .into(),
+            ));
+        }
+    }
+
+    Ok(SimpleConsensusValidationResult::default())
+}
@@ -14,8 +14,10 @@ use crate::error::Error;
 use crate::error::execution::ExecutionError;
 use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::ValidateStateTransitionIdentitySignatureV0;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v1::ValidateStateTransitionIdentitySignatureV1;
 
 pub mod v0;
+pub mod v1;
 
 pub type GetDataContractFn = fn(Identifier) -> Result<Arc<DataContractFetchInfo>, ProtocolError>;
 
@@ -56,9 +58,17 @@ impl ValidateStateTransitionIdentitySignature for StateTransition {
                 execution_context,
                 platform_version,
             ),
+            1 => self.validate_state_transition_identity_signed_v1(
+                drive,
+                action,
+                request_revision,
+                transaction,
+                execution_context,
+                platform_version,
+            ),
             version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
                 method: "StateTransition::validate_state_transition_identity_signature".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -3,6 +3,7 @@ use crate::error::Error;
 use dpp::consensus::signature::{
     IdentityNotFoundError, InvalidSignaturePublicKeySecurityLevelError,
     InvalidStateTransitionSignatureError, PublicKeySecurityLevelNotMetError,
+    WrongPublicKeyPurposeError,
 };
 
 use dpp::identity::PartialIdentity;
@@ -30,7 +31,7 @@ use dpp::{
     },
     NativeBlsModule,
 };
-use drive::dpp::identity::KeyType;
+use drive::dpp::identity::{KeyType, Purpose};
 use drive::drive::identity::key::fetch::IdentityKeysRequest;
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
@@ -156,6 +157,16 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
             return Ok(validation_result);
         }
 
+        // Masternodes vote with the voting key of their voter identity
+        if matches!(self, StateTransition::MasternodeVote(_))
+            && public_key.purpose() != Purpose::VOTING
+        {
+            validation_result.add_error(SignatureError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), Purpose::VOTING),
+            ));
+            return Ok(validation_result);
+        }
+
         if public_key.is_disabled() {
             validation_result.add_error(SignatureError::PublicKeyIsDisabledError(
                 PublicKeyIsDisabledError::new(public_key.id()),
@@ -166,9 +177,11 @@ impl<'a> ValidateStateTransitionIdentitySignatureV0<'a> for StateTransition {
         let operation = SignatureVerificationOperation::new(public_key.key_type());
         execution_context.add_operation(ExecutionOperation::SignatureVerification(operation));
 
-        // if execution_context.is_dry_run() {
-        //     return Ok(validation_result);
-        // }
+        // Fees of unsigned state transitions are estimated in dry run
+        if execution_context.in_dry_run() {
+            validation_result.set_data(partial_identity);
+            return Ok(validation_result);
+        }
 
         let signature_is_valid = self.verify_signature(public_key, &NativeBlsModule);
 
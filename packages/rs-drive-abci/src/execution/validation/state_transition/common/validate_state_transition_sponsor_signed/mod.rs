@@ -0,0 +1,58 @@
+use dpp::identity::PartialIdentity;
+use dpp::state_transition::StateTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+use crate::error::Error;
+use crate::error::execution::ExecutionError;
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::validation::state_transition::common::validate_state_transition_sponsor_signed::v0::ValidateStateTransitionSponsorSignatureV0;
+
+pub mod v0;
+
+pub trait ValidateStateTransitionSponsorSignature {
+    /// Validates the signature of the identity sponsoring a documents batch and returns it
+    /// as the identity paying for the state transition
+    fn validate_state_transition_sponsor_signed(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl ValidateStateTransitionSponsorSignature for StateTransition {
+    fn validate_state_transition_sponsor_signed(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .common_validation_methods
+            .validate_state_transition_sponsor_signed
+        {
+            0 => self.validate_state_transition_sponsor_signed_v0(
+                drive,
+                action,
+                transaction,
+                execution_context,
+                platform_version,
+            ),
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "StateTransition::validate_state_transition_sponsor_signed".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
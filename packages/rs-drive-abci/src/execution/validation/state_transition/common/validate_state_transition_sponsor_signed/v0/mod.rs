@@ -0,0 +1,721 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::execution::types::execution_operation::signature_verification_operation::SignatureVerificationOperation;
+use crate::execution::types::execution_operation::ExecutionOperation;
+use crate::execution::types::state_transition_execution_context::{
+    StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0,
+};
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v0::convert_to_consensus_signature_error;
+use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::v1::contract_bounds_are_met;
+use dpp::consensus::signature::{
+    IdentityNotFoundError, InvalidIdentityPublicKeyTypeError,
+    InvalidSignaturePublicKeySecurityLevelError, MissingPublicKeyError,
+    PublicKeyContractBoundsNotMetError, PublicKeyIsDisabledError, SignatureError,
+    SponsorPublicKeyNotContractBoundError, WrongPublicKeyPurposeError,
+};
+use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+use dpp::identity::{KeyType, PartialIdentity, Purpose};
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV1;
+use dpp::state_transition::StateTransition;
+use dpp::validation::ConsensusValidationResult;
+use dpp::version::PlatformVersion;
+use dpp::NativeBlsModule;
+use drive::drive::identity::key::fetch::IdentityKeysRequest;
+use drive::drive::Drive;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(super) trait ValidateStateTransitionSponsorSignatureV0 {
+    fn validate_state_transition_sponsor_signed_v0(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error>;
+}
+
+impl ValidateStateTransitionSponsorSignatureV0 for StateTransition {
+    fn validate_state_transition_sponsor_signed_v0(
+        &self,
+        drive: &Drive,
+        action: Option<&StateTransitionAction>,
+        transaction: TransactionArg,
+        execution_context: &mut StateTransitionExecutionContext,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<PartialIdentity>, Error> {
+        let mut validation_result = ConsensusValidationResult::<PartialIdentity>::default();
+
+        let StateTransition::DocumentsBatch(documents_batch_transition) = self else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "only documents batch transitions can be sponsored",
+            )));
+        };
+
+        let Some(sponsor) = documents_batch_transition.sponsor() else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "we expect a sponsor when validating the sponsor signature",
+            )));
+        };
+
+        let sponsor_id = sponsor.sponsor_id;
+        let key_id = sponsor.signature_public_key_id;
+
+        let Some(StateTransitionAction::DocumentsBatchAction(documents_batch_action)) = action
+        else {
+            return Err(Error::Execution(ExecutionError::CorruptedCodeExecution(
+                "we expect a documents batch state transition action when validating the sponsor signature",
+            )));
+        };
+
+        // The sponsor key must meet the same security level as a key of the owner would
+        let security_levels = documents_batch_action.contract_based_security_level_requirement()?;
+
+        let key_request =
+            IdentityKeysRequest::new_specific_key_query(sponsor_id.as_bytes(), key_id);
+
+        let Some(partial_identity) =
+            drive.fetch_identity_balance_with_keys(key_request, transaction, platform_version)?
+        else {
+            validation_result.add_error(SignatureError::IdentityNotFoundError(
+                IdentityNotFoundError::new(sponsor_id),
+            ));
+            return Ok(validation_result);
+        };
+
+        let Some(public_key) = partial_identity.loaded_public_keys.get(&key_id) else {
+            validation_result.add_error(SignatureError::MissingPublicKeyError(
+                MissingPublicKeyError::new(key_id),
+            ));
+            return Ok(validation_result);
+        };
+
+        if !matches!(
+            public_key.key_type(),
+            KeyType::ECDSA_SECP256K1 | KeyType::BLS12_381 | KeyType::ECDSA_HASH160
+        ) {
+            validation_result.add_error(SignatureError::InvalidIdentityPublicKeyTypeError(
+                InvalidIdentityPublicKeyTypeError::new(public_key.key_type()),
+            ));
+            return Ok(validation_result);
+        }
+
+        if !security_levels.contains(&public_key.security_level()) {
+            validation_result.add_error(
+                SignatureError::InvalidSignaturePublicKeySecurityLevelError(
+                    InvalidSignaturePublicKeySecurityLevelError::new(
+                        public_key.security_level(),
+                        security_levels,
+                    ),
+                ),
+            );
+            return Ok(validation_result);
+        }
+
+        if public_key.purpose() != Purpose::AUTHENTICATION {
+            validation_result.add_error(SignatureError::WrongPublicKeyPurposeError(
+                WrongPublicKeyPurposeError::new(public_key.purpose(), Purpose::AUTHENTICATION),
+            ));
+            return Ok(validation_result);
+        }
+
+        if public_key.is_disabled() {
+            validation_result.add_error(SignatureError::PublicKeyIsDisabledError(
+                PublicKeyIsDisabledError::new(public_key.id()),
+            ));
+            return Ok(validation_result);
+        }
+
+        // Sponsors only pay for documents of the contract their key is bound to
+        let Some(contract_bounds) = public_key.contract_bounds() else {
+            validation_result.add_error(SignatureError::SponsorPublicKeyNotContractBoundError(
+                SponsorPublicKeyNotContractBoundError::new(sponsor_id, key_id),
+            ));
+            return Ok(validation_result);
+        };
+
+        if !contract_bounds_are_met(contract_bounds, documents_batch_transition) {
+            validation_result.add_error(SignatureError::PublicKeyContractBoundsNotMetError(
+                PublicKeyContractBoundsNotMetError::new(key_id, *contract_bounds.identifier()),
+            ));
+            return Ok(validation_result);
+        }
+
+        let operation = SignatureVerificationOperation::new(public_key.key_type());
+        execution_context.add_operation(ExecutionOperation::SignatureVerification(operation));
+
+        // Fees of unsigned state transitions are estimated in dry run
+        if execution_context.in_dry_run() {
+            validation_result.set_data(partial_identity);
+            return Ok(validation_result);
+        }
+
+        if let Err(err) = self.verify_sponsor_signature(public_key, &NativeBlsModule) {
+            let consensus_error = convert_to_consensus_signature_error(err)?;
+            validation_result.add_error(consensus_error);
+            return Ok(validation_result);
+        }
+
+        validation_result.set_data(partial_identity);
+
+        Ok(validation_result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidateStateTransitionSponsorSignatureV0;
+    use crate::config::PlatformConfig;
+    use crate::execution::types::execution_result::ExecutionResult;
+    use crate::execution::types::execution_result::ExecutionResult::{
+        ConsensusExecutionError, SuccessfulPaidExecution,
+    };
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+    use crate::platform_types::platform::PlatformRef;
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+    use crate::test::helpers::signer::SimpleSigner;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::basic::BasicError;
+    use dpp::consensus::signature::SignatureError;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::data_contract::DataContract;
+    use dpp::document::{Document, DocumentV0Setters};
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::contract_bounds::ContractBounds;
+    use dpp::identity::identity_public_key::accessors::v0::IdentityPublicKeyGettersV0;
+    use dpp::identity::identity_public_key::v0::IdentityPublicKeyV0;
+    use dpp::identity::sponsorship::SponsorshipSpendingLimit;
+    use dpp::identity::{Identity, IdentityPublicKey, IdentityV0, SecurityLevel};
+    use dpp::platform_value::{BinaryData, Bytes32, Identifier};
+    use dpp::serialization::PlatformSerializable;
+    use dpp::state_transition::documents_batch_transition::document_transition::{
+        DocumentCreateTransition, DocumentDeleteTransition, DocumentTransition,
+    };
+    use dpp::state_transition::documents_batch_transition::{
+        DocumentsBatchSponsor, DocumentsBatchTransition, DocumentsBatchTransitionV1,
+    };
+    use dpp::state_transition::StateTransition;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::version::PlatformVersion;
+    use drive::drive::batch::{DriveOperation, SponsorshipOperationType};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::collections::BTreeMap;
+
+    const SPONSOR_BALANCE: u64 = 100_000_000_000;
+
+    struct SponsorshipSetup {
+        platform: TempPlatform<MockCoreRPCLike>,
+        signer: SimpleSigner,
+        owner_id: Identifier,
+        owner_key: IdentityPublicKey,
+        sponsor_id: Identifier,
+        sponsor_key: IdentityPublicKey,
+        dashpay: DataContract,
+        rng: StdRng,
+    }
+
+    /// Sets up a platform on the version introducing sponsorship, with a documents owner
+    /// without credits and a sponsor with the given key
+    fn setup_sponsorship(sponsor_key_contract_bounds: Option<ContractBounds>) -> SponsorshipSetup {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = PlatformVersion::get(2).expect("expected platform version 2");
+
+        let mut rng = StdRng::seed_from_u64(567);
+
+        let mut signer = SimpleSigner::default();
+
+        let (owner_key, owner_private_key) =
+            IdentityPublicKey::random_ecdsa_critical_level_authentication_key_with_rng(
+                1,
+                &mut rng,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+
+        signer.add_key(owner_key.clone(), owner_private_key);
+
+        let (mut sponsor_key, sponsor_private_key) =
+            IdentityPublicKeyV0::random_ecdsa_high_level_authentication_key_with_rng(
+                1,
+                &mut rng,
+                platform_version,
+            )
+            .expect("expected to get key pair");
+
+        sponsor_key.contract_bounds = sponsor_key_contract_bounds;
+
+        let sponsor_key: IdentityPublicKey = sponsor_key.into();
+
+        signer.add_key(sponsor_key.clone(), sponsor_private_key);
+
+        let owner: Identity = IdentityV0 {
+            id: Identifier::random_with_rng(&mut rng),
+            public_keys: BTreeMap::from([(1, owner_key.clone())]),
+            balance: 0,
+            revision: 0,
+        }
+        .into();
+
+        let sponsor: Identity = IdentityV0 {
+            id: Identifier::random_with_rng(&mut rng),
+            public_keys: BTreeMap::from([(1, sponsor_key.clone())]),
+            balance: SPONSOR_BALANCE,
+            revision: 0,
+        }
+        .into();
+
+        let owner_id = owner.id();
+        let sponsor_id = sponsor.id();
+
+        for identity in [owner, sponsor] {
+            platform
+                .drive
+                .add_new_identity(
+                    identity,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add identity");
+        }
+
+        let dashpay = load_system_data_contract(
+            SystemDataContract::Dashpay,
+            platform_version.protocol_version,
+        )
+        .expect("expected to load dashpay contract");
+
+        SponsorshipSetup {
+            platform,
+            signer,
+            owner_id,
+            owner_key,
+            sponsor_id,
+            sponsor_key,
+            dashpay,
+            rng,
+        }
+    }
+
+    impl SponsorshipSetup {
+        fn platform_version(&self) -> &'static PlatformVersion {
+            PlatformVersion::get(2).expect("expected platform version 2")
+        }
+
+        fn set_spending_limit(&self, limit: Option<u64>) {
+            self.platform
+                .drive
+                .apply_drive_operations(
+                    vec![DriveOperation::SponsorshipOperation(
+                        SponsorshipOperationType::SetSpendingLimits {
+                            sponsor_id: self.sponsor_id,
+                            spending_limits: vec![SponsorshipSpendingLimit {
+                                data_contract_id: self.dashpay.id(),
+                                limit,
+                            }],
+                        },
+                    )],
+                    true,
+                    &BlockInfo::default(),
+                    None,
+                    self.platform_version(),
+                )
+                .expect("expected to set the spending limit");
+        }
+
+        fn random_profile(&mut self) -> (Document, Bytes32) {
+            let platform_version = self.platform_version();
+            let profile = self
+                .dashpay
+                .document_type_for_name("profile")
+                .expect("expected a profile document type");
+
+            let entropy = Bytes32::random_with_rng(&mut self.rng);
+
+            let mut document = profile
+                .random_document_with_identifier_and_entropy(
+                    &mut self.rng,
+                    self.owner_id,
+                    entropy,
+                    platform_version,
+                )
+                .expect("expected a random document");
+
+            document.set("avatarUrl", "http://test.com/bob.jpg".into());
+
+            (document, entropy)
+        }
+
+        fn create_transition(&self, document: Document, entropy: Bytes32) -> DocumentTransition {
+            let profile = self
+                .dashpay
+                .document_type_for_name("profile")
+                .expect("expected a profile document type");
+
+            DocumentCreateTransition::from_document(
+                document,
+                profile,
+                entropy.0,
+                self.platform_version(),
+                None,
+                None,
+            )
+            .expect("expected a create transition")
+            .into()
+        }
+
+        fn delete_transition(&self, document: Document) -> DocumentTransition {
+            let profile = self
+                .dashpay
+                .document_type_for_name("profile")
+                .expect("expected a profile document type");
+
+            DocumentDeleteTransition::from_document(
+                document,
+                profile,
+                self.platform_version(),
+                None,
+                None,
+            )
+            .expect("expected a delete transition")
+            .into()
+        }
+
+        /// A batch signed by its owner, the sponsor co-signs it if `sponsor_signs` is set
+        fn sponsored_batch(
+            &self,
+            transitions: Vec<DocumentTransition>,
+            sponsor_signs: bool,
+        ) -> StateTransition {
+            let documents_batch_transition: DocumentsBatchTransition = DocumentsBatchTransitionV1 {
+                owner_id: self.owner_id,
+                transitions,
+                sponsor: DocumentsBatchSponsor {
+                    sponsor_id: self.sponsor_id,
+                    signature_public_key_id: self.sponsor_key.id(),
+                },
+                signature_public_key_id: 0,
+                signature: BinaryData::default(),
+                sponsor_signature: BinaryData::default(),
+            }
+            .into();
+
+            let mut state_transition: StateTransition = documents_batch_transition.into();
+
+            state_transition
+                .sign_external(
+                    &self.owner_key,
+                    &self.signer,
+                    Some(|_data_contract_id, _document_type_name| Ok(SecurityLevel::HIGH)),
+                )
+                .expect("expected the owner to sign");
+
+            if sponsor_signs {
+                state_transition
+                    .sign_as_sponsor_external(&self.sponsor_key, &self.signer)
+                    .expect("expected the sponsor to sign");
+            }
+
+            state_transition
+        }
+
+        fn execute(&self, state_transition: StateTransition) -> ExecutionResult {
+            let transaction = self.platform.drive.grove.start_transaction();
+
+            let result = self
+                .platform
+                .execute_tx(
+                    state_transition
+                        .serialize_to_bytes()
+                        .expect("expected to serialize"),
+                    &BlockInfo::default(),
+                    &transaction,
+                )
+                .expect("expected to execute the state transition");
+
+            self.platform
+                .drive
+                .grove
+                .commit_transaction(transaction)
+                .unwrap()
+                .expect("expected to commit transaction");
+
+            result
+        }
+
+        fn balance(&self, identity_id: Identifier) -> u64 {
+            self.platform
+                .drive
+                .fetch_identity_balance(identity_id.to_buffer(), None, self.platform_version())
+                .expect("expected to fetch balance")
+                .expect("expected a balance")
+        }
+
+        fn spent_credits(&self) -> Option<u64> {
+            self.platform
+                .drive
+                .fetch_sponsorship_spending(
+                    self.sponsor_id,
+                    self.dashpay.id(),
+                    None,
+                    &self.platform_version().drive,
+                )
+                .expect("expected to fetch the sponsorship spending")
+                .map(|spending| spending.spent)
+        }
+    }
+
+    fn expect_consensus_error(result: ExecutionResult) -> ConsensusError {
+        let ConsensusExecutionError(validation_result) = result else {
+            panic!("expected a consensus error");
+        };
+
+        validation_result
+            .errors
+            .into_iter()
+            .next()
+            .expect("expected an error")
+    }
+
+    fn dashpay_bounds() -> Option<ContractBounds> {
+        Some(ContractBounds::SingleContract {
+            id: SystemDataContract::Dashpay.id(),
+        })
+    }
+
+    #[test]
+    fn should_charge_the_sponsor_and_refund_it_for_sponsored_storage() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        setup.set_spending_limit(Some(SPONSOR_BALANCE));
+
+        let (document, entropy) = setup.random_profile();
+
+        let create_batch = setup.sponsored_batch(
+            vec![setup.create_transition(document.clone(), entropy)],
+            true,
+        );
+
+        let SuccessfulPaidExecution(_, create_fee) = setup.execute(create_batch) else {
+            panic!("expected the sponsored batch to be executed");
+        };
+
+        let create_required_balance = create_fee.total_required_balance();
+
+        assert!(create_required_balance > 0);
+        assert_eq!(setup.balance(setup.owner_id), 0);
+        assert_eq!(
+            setup.balance(setup.sponsor_id),
+            SPONSOR_BALANCE - create_required_balance
+        );
+        assert_eq!(setup.spent_credits(), Some(create_required_balance));
+
+        let delete_batch = setup.sponsored_batch(vec![setup.delete_transition(document)], true);
+
+        let SuccessfulPaidExecution(_, delete_fee) = setup.execute(delete_batch) else {
+            panic!("expected the sponsored batch to be executed");
+        };
+
+        // The storage of the document was attributed to the sponsor that paid for it
+        let refunded_credits = delete_fee
+            .fee_refunds
+            .calculate_refunds_amount_for_identity(setup.sponsor_id)
+            .expect("expected the sponsor to be refunded");
+
+        assert!(refunded_credits > 0);
+        assert_eq!(setup.balance(setup.owner_id), 0);
+        assert_eq!(
+            setup.spent_credits(),
+            Some(
+                (create_required_balance + delete_fee.total_required_balance())
+                    .saturating_sub(refunded_credits)
+            )
+        );
+    }
+
+    #[test]
+    fn should_return_the_sponsor_as_the_paying_identity() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], true);
+
+        let state = setup.platform.state.read().unwrap();
+
+        let platform_ref = PlatformRef {
+            drive: &setup.platform.drive,
+            state: &state,
+            config: &setup.platform.config,
+            core_rpc: &setup.platform.core_rpc,
+        };
+
+        let platform_version = state
+            .current_platform_version()
+            .expect("expected a platform version");
+
+        let action = state_transition
+            .transform_into_action(&platform_ref, true, None)
+            .expect("expected to transform into an action")
+            .into_data()
+            .expect("expected an action");
+
+        let mut execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .expect("expected an execution context");
+
+        let validation_result = state_transition
+            .validate_state_transition_sponsor_signed_v0(
+                &setup.platform.drive,
+                Some(&action),
+                None,
+                &mut execution_context,
+                platform_version,
+            )
+            .expect("expected to validate the sponsor signature");
+
+        assert!(validation_result.is_valid());
+
+        let sponsor = validation_result
+            .into_data()
+            .expect("expected the sponsor identity");
+
+        assert_eq!(sponsor.id, setup.sponsor_id);
+        assert_eq!(sponsor.balance, Some(SPONSOR_BALANCE));
+    }
+
+    #[test]
+    fn should_reject_a_sponsor_key_bound_to_another_contract() {
+        let mut setup = setup_sponsorship(Some(ContractBounds::SingleContract {
+            id: SystemDataContract::DPNS.id(),
+        }));
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], true);
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::SignatureError(SignatureError::PublicKeyContractBoundsNotMetError(_))
+        ));
+        assert_eq!(setup.balance(setup.sponsor_id), SPONSOR_BALANCE);
+    }
+
+    #[test]
+    fn should_reject_a_sponsor_key_without_contract_bounds() {
+        let mut setup = setup_sponsorship(None);
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], true);
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::SignatureError(SignatureError::SponsorPublicKeyNotContractBoundError(
+                _
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_reject_a_batch_without_sponsor_signature() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], false);
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::SignatureError(SignatureError::InvalidStateTransitionSignatureError(_))
+        ));
+        assert_eq!(setup.balance(setup.sponsor_id), SPONSOR_BALANCE);
+    }
+
+    #[test]
+    fn should_reject_a_batch_with_an_invalid_sponsor_signature() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        let (document, entropy) = setup.random_profile();
+
+        let mut state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], false);
+
+        // The owner signature is valid, but not from the sponsor key
+        let StateTransition::DocumentsBatch(DocumentsBatchTransition::V1(batch)) =
+            &mut state_transition
+        else {
+            panic!("expected a sponsored documents batch");
+        };
+
+        batch.sponsor_signature = batch.signature.clone();
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::SignatureError(SignatureError::InvalidStateTransitionSignatureError(_))
+        ));
+        assert_eq!(setup.balance(setup.sponsor_id), SPONSOR_BALANCE);
+    }
+
+    #[test]
+    fn should_reject_a_batch_exceeding_the_spending_limit() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        setup.set_spending_limit(Some(1));
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], true);
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::StateError(StateError::SponsorshipSpendingLimitExceededError(_))
+        ));
+        assert_eq!(setup.balance(setup.sponsor_id), SPONSOR_BALANCE);
+        assert_eq!(setup.spent_credits(), Some(0));
+    }
+
+    #[test]
+    fn should_reject_sponsored_batches_before_protocol_version_2() {
+        let mut setup = setup_sponsorship(dashpay_bounds());
+
+        let (document, entropy) = setup.random_profile();
+
+        let state_transition =
+            setup.sponsored_batch(vec![setup.create_transition(document, entropy)], true);
+
+        setup
+            .platform
+            .state
+            .write()
+            .unwrap()
+            .set_current_protocol_version_in_consensus(1);
+
+        assert!(matches!(
+            expect_consensus_error(setup.execute(state_transition)),
+            ConsensusError::BasicError(BasicError::UnsupportedVersionError(_))
+        ));
+        assert_eq!(setup.balance(setup.sponsor_id), SPONSOR_BALANCE);
+    }
+}
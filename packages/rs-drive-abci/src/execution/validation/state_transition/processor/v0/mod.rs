@@ -1,6 +1,7 @@
 use crate::error::Error;
 use crate::execution::types::execution_event::ExecutionEvent;
 use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::metrics::{self, StateTransitionStage};
 use crate::platform_types::platform::{PlatformRef, PlatformStateRef};
 use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::rpc::core::CoreRPCLike;
@@ -15,8 +16,10 @@ use dpp::version::{DefaultForPlatformVersion, PlatformVersion};
 use drive::drive::Drive;
 use drive::grovedb::TransactionArg;
 use crate::error::execution::ExecutionError;
-use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+use crate::execution::types::state_transition_execution_context::{StateTransitionExecutionContext, StateTransitionExecutionContextMethodsV0};
 use crate::execution::validation::state_transition::common::validate_state_transition_identity_signed::{ValidateStateTransitionIdentitySignature};
+use crate::execution::validation::state_transition::common::validate_state_transition_sponsor_signed::ValidateStateTransitionSponsorSignature;
+use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV1;
 use crate::execution::validation::state_transition::state_transitions::identity_update::identity_and_signatures::v0::IdentityUpdateStateTransitionIdentityAndSignaturesValidationV0;
 use crate::execution::validation::state_transition::state_transitions::identity_create::identity_and_signatures::v0::IdentityCreateStateTransitionIdentityAndSignaturesValidationV0;
 use crate::execution::validation::state_transition::state_transitions::identity_top_up::identity_retrieval::v0::IdentityTopUpStateTransitionIdentityRetrievalV0;
@@ -24,12 +27,24 @@ use crate::execution::validation::state_transition::state_transitions::identity_
 pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     platform: &'a PlatformRef<C>,
     state_transition: StateTransition,
+    in_dry_run: bool,
     transaction: TransactionArg,
     platform_version: &PlatformVersion,
 ) -> Result<ConsensusValidationResult<ExecutionEvent<'a>>, Error> {
     let mut state_transition_execution_context =
         StateTransitionExecutionContext::default_for_platform_version(platform_version)?;
 
+    if in_dry_run {
+        state_transition_execution_context.enable_dry_run();
+    }
+
+    let state_transition_type = state_transition.state_transition_type();
+
+    let structure_timer = metrics::state_transition_stage_duration(
+        state_transition_type,
+        StateTransitionStage::Structure,
+    );
+
     let action = if state_transition.requires_state_to_validate_structure() {
         let state_transition_action_result =
             state_transition.transform_into_action(platform, true, transaction)?;
@@ -51,10 +66,21 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         action.as_ref(),
         platform.state.current_protocol_version_in_consensus(),
     )?;
+    drop(structure_timer);
+    metrics::state_transition_stage_result(
+        state_transition_type,
+        StateTransitionStage::Structure,
+        result.is_valid(),
+    );
     if !result.is_valid() {
         return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_errors(result.errors));
     }
 
+    let signature_timer = metrics::state_transition_stage_duration(
+        state_transition_type,
+        StateTransitionStage::Signature,
+    );
+
     let action = if state_transition.requires_state_to_validate_identity_and_signatures() {
         if let Some(action) = action {
             Some(action)
@@ -82,6 +108,12 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
         &mut state_transition_execution_context,
         platform_version,
     )?;
+    drop(signature_timer);
+    metrics::state_transition_stage_result(
+        state_transition_type,
+        StateTransitionStage::Signature,
+        result.is_valid(),
+    );
     // Validating signatures
     if !result.is_valid() {
         return Ok(ConsensusValidationResult::<ExecutionEvent>::new_with_errors(result.errors));
@@ -89,7 +121,17 @@ pub(in crate::execution) fn process_state_transition_v0<'a, C: CoreRPCLike>(
     let maybe_identity = result.into_data()?;
 
     // Validating state
+    let state_timer = metrics::state_transition_stage_duration(
+        state_transition_type,
+        StateTransitionStage::State,
+    );
     let result = state_transition.validate_state(action, platform, transaction)?;
+    drop(state_timer);
+    metrics::state_transition_stage_result(
+        state_transition_type,
+        StateTransitionStage::State,
+        result.is_valid(),
+    );
 
     result.map_result(|action| {
         ExecutionEvent::create_from_state_transition_action(
@@ -206,6 +248,9 @@ impl StateTransitionStructureValidationV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.validate_structure(platform, action, protocol_version)
             }
+            StateTransition::MasternodeVote(st) => {
+                st.validate_structure(platform, action, protocol_version)
+            }
         }
     }
 }
@@ -224,7 +269,7 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
             | StateTransition::DataContractUpdate(_)
             | StateTransition::IdentityCreditWithdrawal(_)
             | StateTransition::IdentityCreditTransfer(_)
-            | StateTransition::DocumentsBatch(_) => {
+            | StateTransition::MasternodeVote(_) => {
                 //Basic signature verification
                 Ok(self
                     .validate_state_transition_identity_signed(
@@ -237,6 +282,29 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                     )?
                     .map(Some))
             }
+            StateTransition::DocumentsBatch(st) => {
+                let validation_result = self.validate_state_transition_identity_signed(
+                    drive,
+                    action,
+                    false,
+                    tx,
+                    execution_context,
+                    platform_version,
+                )?;
+                if !validation_result.is_valid() || st.sponsor().is_none() {
+                    return Ok(validation_result.map(Some));
+                }
+                // The sponsor co-signs the batch and pays for it instead of the owner
+                Ok(self
+                    .validate_state_transition_sponsor_signed(
+                        drive,
+                        action,
+                        tx,
+                        execution_context,
+                        platform_version,
+                    )?
+                    .map(Some))
+            }
             StateTransition::IdentityUpdate(st) => {
                 match platform_version
                     .drive_abci
@@ -256,7 +324,7 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                                 execution_context,
                                 platform_version,
                             )?;
-                        if !validation_result.is_valid() {
+                        if !validation_result.is_valid() || execution_context.in_dry_run() {
                             Ok(validation_result.map(Some))
                         } else {
                             let partial_identity = validation_result.data_as_borrowed()?;
@@ -295,11 +363,15 @@ impl StateTransitionSignatureValidationV0 for StateTransition {
                     Some(0) => {
                         let mut validation_result =
                             ConsensusValidationResult::<Option<PartialIdentity>>::default();
-                        let signable_bytes: Vec<u8> = self.signable_bytes()?;
-                        let result = st.validate_identity_create_state_transition_signatures_v0(
-                            signable_bytes,
-                        )?;
-                        validation_result.merge(result);
+                        // Keys of unsigned identity create transitions can't prove their ownership
+                        if !execution_context.in_dry_run() {
+                            let signable_bytes: Vec<u8> = self.signable_bytes()?;
+                            let result = st
+                                .validate_identity_create_state_transition_signatures_v0(
+                                    signable_bytes,
+                                )?;
+                            validation_result.merge(result);
+                        }
                         validation_result.set_data(None);
                         Ok(validation_result)
                     }
@@ -367,6 +439,7 @@ impl StateTransitionStateValidationV0 for StateTransition {
             }
             StateTransition::DocumentsBatch(st) => st.validate_state(action, platform, tx),
             StateTransition::IdentityCreditTransfer(st) => st.validate_state(action, platform, tx),
+            StateTransition::MasternodeVote(st) => st.validate_state(action, platform, tx),
         }
     }
 }
@@ -99,3 +99,81 @@ impl StateTransitionStateValidationV0 for DataContractCreateTransition {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PlatformConfig;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::assert_state_consensus_errors;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::data_contract::schema::DataContractSchemaMethodsV0;
+    use dpp::platform_value::{platform_value, Identifier};
+    use dpp::tests::fixtures::get_data_contract_fixture;
+    use platform_version::TryIntoPlatformVersioned;
+
+    #[test]
+    fn should_return_error_if_document_fee_beneficiary_does_not_exist() {
+        let platform_version = PlatformVersion::get(2).expect("expected platform version 2");
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: platform_version.protocol_version,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let mut created_data_contract =
+            get_data_contract_fixture(None, platform_version.protocol_version);
+
+        let beneficiary_id = Identifier::new([7; 32]);
+
+        let mut paid_document_type = platform_value!({
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "maxLength": 32
+                }
+            },
+            "additionalProperties": false,
+            "documentCreationFee": 1_000_000u64,
+        });
+
+        paid_document_type
+            .set_value("documentFeeBeneficiary", beneficiary_id.into())
+            .expect("expected to set the fee beneficiary");
+
+        created_data_contract
+            .data_contract_mut()
+            .set_document_schema("paidDocument", paid_document_type, true, platform_version)
+            .expect("to be able to set document schema");
+
+        let state_transition: DataContractCreateTransition = created_data_contract
+            .try_into_platform_versioned(platform_version)
+            .expect("expected a create transition");
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &platform.state.read().unwrap(),
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+        };
+
+        let result = state_transition
+            .validate_state(None, &platform_ref, None)
+            .expect("state transition to be validated");
+
+        assert!(!result.is_valid());
+        let errors = assert_state_consensus_errors!(
+            result,
+            StateError::DataContractFeeBeneficiaryNotFoundError,
+            1
+        );
+        let error = errors.get(0).expect("to have an error");
+        assert_eq!(error.document_type_name(), "paidDocument");
+        assert_eq!(error.beneficiary_id(), &beneficiary_id);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_data_contract_fee_beneficiaries_exist_in_state::validate_data_contract_fee_beneficiaries_exist_in_state;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
 
@@ -54,7 +55,27 @@ impl DataContractCreateStateTransitionStateValidationV0 for DataContractCreateTr
                 .into(),
             ]))
         } else {
-            self.transform_into_action_v0::<C>(platform_version)
+            let action = self.transform_into_action_v0::<C>(platform_version)?;
+
+            // Identities receiving document fees should exist
+            if let Some(StateTransitionAction::DataContractCreateAction(create_action)) =
+                action.data.as_ref()
+            {
+                let validation_result = validate_data_contract_fee_beneficiaries_exist_in_state(
+                    create_action.data_contract_ref(),
+                    drive,
+                    tx,
+                    platform_version,
+                )?;
+
+                if !validation_result.is_valid() {
+                    return Ok(ConsensusValidationResult::new_with_errors(
+                        validation_result.errors,
+                    ));
+                }
+            }
+
+            Ok(action)
         }
     }
 
@@ -178,7 +178,7 @@ mod tests {
         use dpp::data_contract::schema::DataContractSchemaMethodsV0;
 
         use dpp::data_contract::serialized_version::DataContractInSerializationFormat;
-        use dpp::platform_value::platform_value;
+        use dpp::platform_value::{platform_value, Identifier};
         use dpp::state_transition::data_contract_update_transition::DataContractUpdateTransition;
 
         use dpp::version::TryFromPlatformVersioned;
@@ -462,5 +462,76 @@ mod tests {
                 "contract can not change whether it keeps history: changing from true to false"
             );
         }
+
+        #[test]
+        fn should_return_error_if_document_fee_beneficiary_does_not_exist() {
+            let platform_version = PlatformVersion::get(2).expect("expected platform version 2");
+
+            let platform = TestPlatformBuilder::new()
+                .with_config(PlatformConfig {
+                    initial_protocol_version: platform_version.protocol_version,
+                    ..Default::default()
+                })
+                .build_with_mock_rpc()
+                .set_initial_state_structure();
+
+            let mut data_contract =
+                get_data_contract_fixture(None, platform_version.protocol_version)
+                    .data_contract_owned();
+
+            data_contract.config_mut().set_readonly(false);
+
+            apply_contract(&platform, &data_contract, Default::default());
+
+            let beneficiary_id = Identifier::new([7; 32]);
+
+            let mut paid_document_type = platform_value!({
+                "type": "object",
+                "properties": {
+                    "name": {
+                        "type": "string",
+                        "maxLength": 32
+                    }
+                },
+                "additionalProperties": false,
+                "documentCreationFee": 1_000_000u64,
+            });
+
+            paid_document_type
+                .set_value("documentFeeBeneficiary", beneficiary_id.into())
+                .expect("expected to set the fee beneficiary");
+
+            data_contract.increment_version();
+            data_contract
+                .set_document_schema("paidDocument", paid_document_type, true, platform_version)
+                .expect("to be able to set document schema");
+
+            let state_transition: DataContractUpdateTransitionV0 = data_contract
+                .try_into_platform_versioned(platform_version)
+                .expect("expected an update transition");
+
+            let state_transition: DataContractUpdateTransition = state_transition.into();
+
+            let platform_ref = PlatformRef {
+                drive: &platform.drive,
+                state: &platform.state.read().unwrap(),
+                config: &platform.config,
+                core_rpc: &platform.core_rpc,
+            };
+
+            let result = state_transition
+                .validate_state(None, &platform_ref, None)
+                .expect("state transition to be validated");
+
+            assert!(!result.is_valid());
+            let errors = assert_state_consensus_errors!(
+                result,
+                StateError::DataContractFeeBeneficiaryNotFoundError,
+                1
+            );
+            let error = errors.get(0).expect("to have an error");
+            assert_eq!(error.document_type_name(), "paidDocument");
+            assert_eq!(error.beneficiary_id(), &beneficiary_id);
+        }
     }
 }
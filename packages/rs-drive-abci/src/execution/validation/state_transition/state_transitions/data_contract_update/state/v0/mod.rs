@@ -1,4 +1,5 @@
 use crate::error::Error;
+use crate::execution::validation::state_transition::common::validate_data_contract_fee_beneficiaries_exist_in_state::validate_data_contract_fee_beneficiaries_exist_in_state;
 use crate::platform_types::platform::PlatformRef;
 use crate::rpc::core::CoreRPCLike;
 use serde::Serialize;
@@ -244,6 +245,21 @@ impl DataContractUpdateStateTransitionStateValidationV0 for DataContractUpdateTr
             }
         }
 
+        // Identities receiving document fees should exist
+        let fee_beneficiaries_validation_result =
+            validate_data_contract_fee_beneficiaries_exist_in_state(
+                new_data_contract,
+                drive,
+                tx,
+                platform_version,
+            )?;
+
+        if !fee_beneficiaries_validation_result.is_valid() {
+            validation_result.add_errors(fee_beneficiaries_validation_result.errors);
+
+            return Ok(validation_result);
+        }
+
         Ok(action)
     }
 
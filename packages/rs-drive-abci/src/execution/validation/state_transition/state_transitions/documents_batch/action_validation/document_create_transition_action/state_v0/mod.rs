@@ -3,16 +3,20 @@ use dpp::consensus::ConsensusError;
 use dpp::consensus::state::document::document_already_present_error::DocumentAlreadyPresentError;
 use dpp::consensus::state::state_error::StateError;
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
-use dpp::document::{DocumentV0Getters};
+use dpp::consensus::state::voting::contested_resource_contender_already_present_error::ContestedResourceContenderAlreadyPresentError;
+use dpp::consensus::state::voting::contested_resource_vote_poll_ended_error::ContestedResourceVotePollEndedError;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::voting::ContestedDocumentResourceVotePoll;
 use dpp::prelude::{ConsensusValidationResult, Identifier};
 use dpp::validation::SimpleConsensusValidationResult;
 use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
-use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::{DocumentCreateTransitionAction, DocumentCreateTransitionActionAccessorsV0};
+use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::{DocumentCreateTransitionAction, DocumentCreateTransitionActionAccessorsV0, DocumentFromCreateTransition};
 use dpp::version::PlatformVersion;
 use drive::query::TransactionArg;
 use crate::error::Error;
 use crate::execution::validation::state_transition::documents_batch::state::v0::fetch_documents::fetch_document_with_id;
 use crate::platform_types::platform::PlatformStateRef;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 
 pub(super) trait DocumentCreateTransitionActionStateValidationV0 {
     fn validate_state_v0(
@@ -63,6 +67,44 @@ impl DocumentCreateTransitionActionStateValidationV0 for DocumentCreateTransitio
             ));
         }
 
+        // a document with a value in a contested index joins the contest on that value,
+        // an identity can only be a contender once and only while the contest is running
+        let document = Document::try_from_create_transition(self, owner_id, platform_version)?;
+
+        if let Some(vote_poll) =
+            ContestedDocumentResourceVotePoll::for_document(contract.id(), document_type, &document)
+        {
+            let contest_id = vote_poll.unique_id()?;
+
+            if let Some(end_time_ms) = platform.drive.fetch_contest_end_time(
+                contest_id.as_bytes(),
+                transaction,
+                &platform_version.drive,
+            )? {
+                if platform
+                    .state
+                    .last_block_time_ms()
+                    .is_some_and(|last_block_time_ms| end_time_ms <= last_block_time_ms)
+                {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ContestedResourceVotePollEndedError::new(contest_id, end_time_ms).into(),
+                    ));
+                }
+
+                if platform.drive.has_contested_resource_contender(
+                    contest_id.as_bytes(),
+                    owner_id,
+                    transaction,
+                    &platform_version.drive,
+                )? {
+                    return Ok(SimpleConsensusValidationResult::new_with_error(
+                        ContestedResourceContenderAlreadyPresentError::new(contest_id, owner_id)
+                            .into(),
+                    ));
+                }
+            }
+        }
+
         // we also need to validate that the new document wouldn't conflict with any other document
         // this means for example having overlapping unique indexes
         platform
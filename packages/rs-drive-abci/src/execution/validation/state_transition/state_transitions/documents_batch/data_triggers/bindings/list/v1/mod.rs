@@ -0,0 +1,35 @@
+use crate::execution::validation::state_transition::documents_batch::data_triggers::bindings::data_trigger_binding::DataTriggerBindingV0;
+use crate::execution::validation::state_transition::documents_batch::data_triggers::bindings::list::v0::data_trigger_bindings_list_v0;
+use crate::execution::validation::state_transition::documents_batch::data_triggers::triggers::dpns::renew_domain_data_trigger;
+
+use dpp::errors::ProtocolError;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::document_types::domain;
+use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionActionType;
+
+/// Retrieves a list of data triggers binding with matching params.
+///
+/// Same as [data_trigger_bindings_list_v0], except that DPNS domains can be replaced
+/// to renew them.
+///
+/// # Returns
+///
+/// A `Vec<DataTriggerBinding>` containing all known data triggers.
+///
+/// # Errors
+///
+/// Returns a `ProtocolError` if there was an error.
+pub fn data_trigger_bindings_list_v1() -> Result<Vec<DataTriggerBindingV0>, ProtocolError> {
+    let mut data_triggers = data_trigger_bindings_list_v0()?;
+
+    for binding in data_triggers.iter_mut() {
+        if binding.data_contract_id == dpns_contract::ID
+            && binding.document_type == domain::NAME
+            && binding.transition_action_type == DocumentTransitionActionType::Replace
+        {
+            binding.data_trigger = renew_domain_data_trigger;
+        }
+    }
+
+    Ok(data_triggers)
+}
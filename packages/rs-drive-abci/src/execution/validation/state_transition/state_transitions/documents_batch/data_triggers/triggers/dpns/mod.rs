@@ -4,8 +4,13 @@ use crate::error::Error;
 use crate::error::execution::ExecutionError;
 use crate::execution::validation::state_transition::documents_batch::data_triggers::{DataTriggerExecutionContext, DataTriggerExecutionResult};
 use crate::execution::validation::state_transition::documents_batch::data_triggers::triggers::dpns::v0::create_domain_data_trigger_v0;
+use crate::execution::validation::state_transition::documents_batch::data_triggers::triggers::dpns::v1::create_domain_data_trigger_v1;
 
+mod renew_domain;
 mod v0;
+mod v1;
+
+pub use renew_domain::renew_domain_data_trigger;
 
 pub fn create_domain_data_trigger(
     document_transition: &DocumentTransitionAction,
@@ -22,9 +27,10 @@ pub fn create_domain_data_trigger(
         .create_domain_data_trigger
     {
         0 => create_domain_data_trigger_v0(document_transition, context, platform_version),
+        1 => create_domain_data_trigger_v1(document_transition, context, platform_version),
         version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
             method: "create_domain_data_trigger".to_string(),
-            known_versions: vec![0],
+            known_versions: vec![0, 1],
             received: version,
         })),
     }
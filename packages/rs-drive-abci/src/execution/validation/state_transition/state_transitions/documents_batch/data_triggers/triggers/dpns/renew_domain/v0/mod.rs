@@ -0,0 +1,341 @@
+use dpp::consensus::state::data_trigger::data_trigger_condition_error::DataTriggerConditionError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use std::collections::BTreeMap;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::execution::validation::state_transition::documents_batch::data_triggers::{
+    DataTriggerExecutionContext, DataTriggerExecutionResult,
+};
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use dpp::document::DocumentV0Getters;
+use dpp::platform_value::Value;
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentReplaceTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
+
+/// Creates a data trigger for handling domain renewals.
+///
+/// A domain is renewed by replacing it without changing its properties, which moves `$updatedAt`
+/// and so the end of the registration period. Domains that were released can't be renewed.
+///
+/// # Arguments
+///
+/// * `document_transition` - A reference to the document transition that triggered the data trigger.
+/// * `context` - A reference to the data trigger execution context.
+/// * `platform_version` - The platform version with the registration and grace periods.
+///
+/// # Returns
+///
+/// A `DataTriggerExecutionResult` indicating the success or failure of the trigger execution.
+pub fn renew_domain_data_trigger_v0(
+    document_transition: &DocumentTransitionAction,
+    context: &DataTriggerExecutionContext<'_>,
+    platform_version: &PlatformVersion,
+) -> Result<DataTriggerExecutionResult, Error> {
+    let data_contract_fetch_info = document_transition.base().data_contract_fetch_info();
+    let data_contract = &data_contract_fetch_info.contract;
+    let is_dry_run = context.state_transition_execution_context.in_dry_run();
+    let DocumentTransitionAction::ReplaceAction(document_replace_transition) = document_transition
+    else {
+        return Err(Error::Execution(ExecutionError::DataTriggerExecutionError(
+            format!(
+                "the Document Transition {} isn't 'REPLACE",
+                document_transition.base().id()
+            ),
+        )));
+    };
+
+    let mut result = DataTriggerExecutionResult::default();
+
+    let document_type = data_contract.document_type_for_name(
+        document_replace_transition
+            .base()
+            .document_type_name()
+            .as_str(),
+    )?;
+
+    let drive_query = DriveQuery {
+        contract: data_contract,
+        document_type,
+        internal_clauses: InternalClauses {
+            primary_key_in_clause: None,
+            primary_key_equal_clause: Some(WhereClause {
+                field: "$id".to_string(),
+                operator: WhereOperator::Equal,
+                value: Value::Identifier(document_replace_transition.base().id().to_buffer()),
+            }),
+            in_clause: None,
+            range_clause: None,
+            equal_clauses: BTreeMap::default(),
+        },
+        offset: None,
+        limit: Some(1),
+        order_by: Default::default(),
+        start_at: None,
+        start_at_included: false,
+        block_time_ms: None,
+    };
+
+    let documents = context
+        .platform
+        .drive
+        .query_documents(
+            drive_query,
+            None,
+            is_dry_run,
+            context.transaction,
+            Some(platform_version.protocol_version),
+        )?
+        .documents_owned();
+
+    if is_dry_run {
+        return Ok(result);
+    }
+
+    let Some(domain) = documents.first() else {
+        let err = DataTriggerConditionError::new(
+            data_contract.id(),
+            document_transition.base().id(),
+            "Domain is not present".to_string(),
+        );
+
+        result.add_error(err);
+
+        return Ok(result);
+    };
+
+    if domain.properties() != document_replace_transition.data() {
+        let err = DataTriggerConditionError::new(
+            data_contract.id(),
+            document_transition.base().id(),
+            "Domain properties can't be changed, a domain can only be renewed".to_string(),
+        );
+
+        result.add_error(err);
+    }
+
+    let dpns_parameters = &platform_version.drive_abci.dpns;
+
+    let released_at = domain.updated_at().map(|updated_at| {
+        updated_at
+            .saturating_add(dpns_parameters.registration_period_ms)
+            .saturating_add(dpns_parameters.grace_period_ms)
+    });
+
+    let last_block_time_ms = context.platform.state.last_block_time_ms();
+
+    if let (Some(released_at), Some(last_block_time_ms)) = (released_at, last_block_time_ms) {
+        if released_at <= last_block_time_ms {
+            let err = DataTriggerConditionError::new(
+                data_contract.id(),
+                document_transition.base().id(),
+                "Domain has expired and can't be renewed after the grace period".to_string(),
+            );
+
+            result.add_error(err);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0;
+    use dpp::consensus::state::data_trigger::DataTriggerError;
+    use dpp::document::{Document, DocumentV0Setters};
+    use dpp::platform_value::Bytes32;
+    use dpp::system_data_contracts::dpns_contract;
+    use dpp::system_data_contracts::dpns_contract::document_types::domain;
+    use drive::drive::object_size_info::{DocumentAndContractInfo, DocumentInfo, OwnedDocumentInfo};
+    use drive::state_transition_action::document::documents_batch::document_transition::document_replace_transition_action::DocumentReplaceTransitionAction;
+    use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionActionType;
+    use dpp::tests::fixtures::{get_document_transitions_fixture, get_dpns_parent_document_fixture, ParentDocumentOptions};
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use dpp::version::DefaultForPlatformVersion;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use crate::platform_types::platform::PlatformStateRef;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use super::*;
+
+    /// Registers a domain that was last renewed at `updated_at` and replaces it with `replace`
+    /// applied to it, in a block following one committed at `last_block_time_ms`
+    fn execute_trigger_for_renewal(
+        updated_at: u64,
+        last_block_time_ms: u64,
+        replace: impl FnOnce(&mut Document),
+    ) -> DataTriggerExecutionResult {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let platform_version = PlatformVersion::latest();
+
+        let dpns_contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+        let contract = &dpns_contract_fetch_info.contract;
+        let document_type = contract
+            .document_type_for_name(domain::NAME)
+            .expect("expected to get domain document type");
+
+        let owner_id = generate_random_identifier_struct();
+        let mut document = get_dpns_parent_document_fixture(
+            ParentDocumentOptions {
+                label: "alice".to_string(),
+                normalized_label: "alice".to_string(),
+                owner_id,
+            },
+            platform_version.protocol_version,
+        );
+        document.set(
+            domain::properties::NORMALIZED_PARENT_DOMAIN_NAME,
+            Value::Text("dash".to_string()),
+        );
+        document.set_created_at(Some(updated_at));
+        document.set_updated_at(Some(updated_at));
+
+        platform
+            .drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentInfo::DocumentRefInfo((&document, None)),
+                        owner_id: None,
+                    },
+                    contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default(),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to add the domain");
+
+        let mut state_write_guard = platform.state.write().unwrap();
+
+        state_write_guard.set_last_committed_block_info(Some(
+            ExtendedBlockInfoV0 {
+                basic_info: BlockInfo::default_with_time(last_block_time_ms),
+                app_hash: platform.drive.grove.root_hash(None).unwrap().unwrap(),
+                quorum_hash: [0u8; 32],
+                block_id_hash: [0u8; 32],
+                signature: [0u8; 96],
+                round: 0,
+            }
+            .into(),
+        ));
+
+        let platform_ref = PlatformStateRef {
+            drive: &platform.drive,
+            state: &state_write_guard,
+            config: &platform.config,
+        };
+
+        let created_at = document.created_at();
+        replace(&mut document);
+
+        let transitions = get_document_transitions_fixture([(
+            DocumentTransitionActionType::Replace,
+            vec![(document, document_type, Bytes32::default())],
+        )]);
+        let document_replace_transition = transitions[0]
+            .as_transition_replace()
+            .expect("expected a document replace transition");
+
+        let transition_execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .unwrap();
+
+        let data_trigger_context = DataTriggerExecutionContext {
+            platform: &platform_ref,
+            owner_id: &owner_id,
+            state_transition_execution_context: &transition_execution_context,
+            transaction: None,
+        };
+
+        renew_domain_data_trigger_v0(
+            &DocumentReplaceTransitionAction::try_from_borrowed_document_replace_transition(
+                document_replace_transition,
+                created_at,
+                |_identifier| Ok(dpns_contract_fetch_info.clone()),
+            )
+            .expect("expected to create action")
+            .into(),
+            &data_trigger_context,
+            platform_version,
+        )
+        .expect("the execution result should be returned")
+    }
+
+    fn condition_error_messages(result: &DataTriggerExecutionResult) -> Vec<String> {
+        result
+            .errors
+            .iter()
+            .filter_map(|error| match error {
+                DataTriggerError::DataTriggerConditionError(e) => Some(e.message().to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_renew_domain_without_changes_during_grace_period() {
+        let dpns_parameters = &PlatformVersion::latest().drive_abci.dpns;
+
+        // The domain expired but it's still in the grace period
+        let result = execute_trigger_for_renewal(
+            1,
+            dpns_parameters.registration_period_ms + dpns_parameters.grace_period_ms - 1,
+            |_| {},
+        );
+
+        assert!(result.is_valid(), "{:?}", result.errors);
+    }
+
+    #[test]
+    fn should_reject_changing_domain_properties() {
+        let result = execute_trigger_for_renewal(1, 2, |document| {
+            document.set(domain::properties::PREORDER_SALT, Value::Bytes32([7; 32]));
+        });
+
+        assert_eq!(
+            condition_error_messages(&result),
+            vec!["Domain properties can't be changed, a domain can only be renewed".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_reject_renewing_domain_after_grace_period() {
+        let dpns_parameters = &PlatformVersion::latest().drive_abci.dpns;
+
+        let result = execute_trigger_for_renewal(
+            1,
+            dpns_parameters.registration_period_ms + dpns_parameters.grace_period_ms + 1,
+            |_| {},
+        );
+
+        assert_eq!(
+            condition_error_messages(&result),
+            vec!["Domain has expired and can't be renewed after the grace period".to_string()]
+        );
+    }
+}
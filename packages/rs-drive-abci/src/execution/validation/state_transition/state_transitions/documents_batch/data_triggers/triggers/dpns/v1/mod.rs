@@ -0,0 +1,281 @@
+use dpp::consensus::state::data_trigger::data_trigger_condition_error::DataTriggerConditionError;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use std::collections::BTreeMap;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+
+use crate::execution::validation::state_transition::documents_batch::data_triggers::{
+    DataTriggerExecutionContext, DataTriggerExecutionResult,
+};
+use crate::execution::validation::state_transition::documents_batch::data_triggers::triggers::dpns::v0::create_domain_data_trigger_v0;
+use dpp::document::DocumentV0Getters;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::platform_value::Value;
+use dpp::ProtocolError;
+use drive::state_transition_action::document::documents_batch::document_transition::document_base_transition_action::DocumentBaseTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionActionAccessorsV0;
+use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
+use dpp::system_data_contracts::dpns_contract::confusables::label_skeleton;
+use dpp::system_data_contracts::dpns_contract::document_types::domain::properties::{LABEL, LABEL_SKELETON, NORMALIZED_PARENT_DOMAIN_NAME};
+use dpp::version::PlatformVersion;
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::query::{DriveQuery, InternalClauses, WhereClause, WhereOperator};
+use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContextMethodsV0;
+
+/// Creates a data trigger for handling domain documents.
+///
+/// Performs all checks of [create_domain_data_trigger_v0] and also makes domain names
+/// homograph-safe: the label skeleton must match the label and labels with the same skeleton
+/// under the same parent domain can't be owned by different identities.
+pub fn create_domain_data_trigger_v1(
+    document_transition: &DocumentTransitionAction,
+    context: &DataTriggerExecutionContext<'_>,
+    platform_version: &PlatformVersion,
+) -> Result<DataTriggerExecutionResult, Error> {
+    let mut result = create_domain_data_trigger_v0(document_transition, context, platform_version)?;
+
+    let data_contract_fetch_info = document_transition.base().data_contract_fetch_info();
+    let data_contract = &data_contract_fetch_info.contract;
+    let is_dry_run = context.state_transition_execution_context.in_dry_run();
+    let DocumentTransitionAction::CreateAction(document_create_transition) = document_transition
+    else {
+        return Err(Error::Execution(ExecutionError::DataTriggerExecutionError(
+            format!(
+                "the Document Transition {} isn't 'CREATE",
+                document_transition.base().id()
+            ),
+        )));
+    };
+
+    let data = document_create_transition.data();
+
+    let label = data.get_str(LABEL).map_err(ProtocolError::ValueError)?;
+    let skeleton = label_skeleton(label);
+    let normalized_parent_domain_name = data
+        .get_string(NORMALIZED_PARENT_DOMAIN_NAME)
+        .map_err(ProtocolError::ValueError)?;
+
+    if !is_dry_run {
+        let label_skeleton_value = data
+            .get_optional_str(LABEL_SKELETON)
+            .map_err(ProtocolError::ValueError)?;
+
+        if label_skeleton_value != Some(skeleton.as_str()) {
+            let err = DataTriggerConditionError::new(
+                data_contract.id(),
+                document_transition.base().id(),
+                format!(
+                    "Label skeleton doesn't match label: {} != {}",
+                    label_skeleton_value.unwrap_or_default(),
+                    skeleton
+                ),
+            );
+
+            result.add_error(err);
+        }
+    }
+
+    let document_type = data_contract.document_type_for_name(
+        document_create_transition
+            .base()
+            .document_type_name()
+            .as_str(),
+    )?;
+
+    let drive_query = DriveQuery {
+        contract: data_contract,
+        document_type,
+        internal_clauses: InternalClauses {
+            primary_key_in_clause: None,
+            primary_key_equal_clause: None,
+            in_clause: None,
+            range_clause: None,
+            equal_clauses: BTreeMap::from([
+                (
+                    NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+                    WhereClause {
+                        field: NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::Text(normalized_parent_domain_name),
+                    },
+                ),
+                (
+                    LABEL_SKELETON.to_string(),
+                    WhereClause {
+                        field: LABEL_SKELETON.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::Text(skeleton),
+                    },
+                ),
+            ]),
+        },
+        offset: None,
+        // All domains sharing a skeleton have the same owner, so one is enough
+        limit: Some(1),
+        order_by: Default::default(),
+        start_at: None,
+        start_at_included: false,
+        block_time_ms: None,
+    };
+
+    let confusable_documents = context
+        .platform
+        .drive
+        .query_documents(
+            drive_query,
+            None,
+            is_dry_run,
+            context.transaction,
+            Some(platform_version.protocol_version),
+        )?
+        .documents_owned();
+
+    if is_dry_run {
+        return Ok(result);
+    }
+
+    if let Some(confusable_document) = confusable_documents.first() {
+        if &confusable_document.owner_id() != context.owner_id {
+            let err = DataTriggerConditionError::new(
+                data_contract.id(),
+                document_transition.base().id(),
+                format!(
+                    "Label {} is confusable with a domain owned by {}",
+                    label,
+                    confusable_document.owner_id()
+                ),
+            );
+
+            result.add_error(err);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use dpp::consensus::state::data_trigger::DataTriggerError;
+    use dpp::platform_value::Bytes32;
+    use dpp::system_data_contracts::dpns_contract;
+    use drive::state_transition_action::document::documents_batch::document_transition::document_create_transition_action::DocumentCreateTransitionAction;
+    use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionActionType;
+    use dpp::tests::fixtures::{get_document_transitions_fixture, get_dpns_parent_document_fixture, ParentDocumentOptions};
+    use dpp::tests::utils::generate_random_identifier_struct;
+    use dpp::version::DefaultForPlatformVersion;
+    use crate::execution::types::state_transition_execution_context::StateTransitionExecutionContext;
+    use crate::platform_types::platform::PlatformStateRef;
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::config::PlatformConfig;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use super::*;
+
+    fn execute_trigger_for_label(label: &str) -> DataTriggerExecutionResult {
+        // Label skeletons are stored since the second version of the DPNS contract
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+        let state_read_guard = platform.state.read().unwrap();
+
+        let platform_ref = PlatformStateRef {
+            drive: &platform.drive,
+            state: &state_read_guard,
+            config: &platform.config,
+        };
+
+        let platform_version = PlatformVersion::latest();
+
+        let dpns_contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info_and_fee(
+                dpns_contract::ID.to_buffer(),
+                None,
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch the dpns contract")
+            .1
+            .expect("expected the dpns contract to exist");
+
+        let transition_execution_context =
+            StateTransitionExecutionContext::default_for_platform_version(platform_version)
+                .unwrap();
+        let owner_id = generate_random_identifier_struct();
+        let document = get_dpns_parent_document_fixture(
+            ParentDocumentOptions {
+                label: label.to_string(),
+                normalized_label: label.to_lowercase(),
+                owner_id,
+            },
+            platform_version.protocol_version,
+        );
+        let document_type = dpns_contract_fetch_info
+            .contract
+            .document_type_for_name("domain")
+            .expect("expected to get domain document type");
+        let transitions = get_document_transitions_fixture([(
+            DocumentTransitionActionType::Create,
+            vec![(document, document_type, Bytes32::default())],
+        )]);
+        let document_create_transition = transitions[0]
+            .as_transition_create()
+            .expect("expected a document create transition");
+
+        let data_trigger_context = DataTriggerExecutionContext {
+            platform: &platform_ref,
+            owner_id: &owner_id,
+            state_transition_execution_context: &transition_execution_context,
+            transaction: None,
+        };
+
+        create_domain_data_trigger_v1(
+            &DocumentCreateTransitionAction::from_document_borrowed_create_transition_with_contract_lookup(
+                document_create_transition, |_identifier| {
+                    Ok(dpns_contract_fetch_info.clone())
+                }).expect("expected to create action").into(),
+            &data_trigger_context,
+            platform_version,
+        )
+        .expect("the execution result should be returned")
+    }
+
+    fn condition_error_messages(result: &DataTriggerExecutionResult) -> Vec<String> {
+        result
+            .errors
+            .iter()
+            .filter_map(|error| match error {
+                DataTriggerError::DataTriggerConditionError(e) => Some(e.message().to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_reject_label_confusable_with_domain_of_another_identity() {
+        // "clash" has the same skeleton as the "dash" top level domain
+        let result = execute_trigger_for_label("clash");
+
+        assert!(
+            condition_error_messages(&result)
+                .iter()
+                .any(|message| message
+                    .starts_with("Label clash is confusable with a domain owned by"))
+        );
+    }
+
+    #[test]
+    fn should_not_reject_label_without_confusable_domains() {
+        let result = execute_trigger_for_label("alice");
+
+        assert!(!condition_error_messages(&result)
+            .iter()
+            .any(|message| message.contains("confusable") || message.contains("skeleton")));
+    }
+}
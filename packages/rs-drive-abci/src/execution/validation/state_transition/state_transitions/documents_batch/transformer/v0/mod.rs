@@ -19,7 +19,7 @@ use dpp::validation::SimpleConsensusValidationResult;
 use dpp::{consensus::ConsensusError, prelude::Identifier, validation::ConsensusValidationResult};
 
 use dpp::state_transition::documents_batch_transition::{DocumentsBatchTransition};
-use dpp::state_transition::documents_batch_transition::accessors::DocumentsBatchTransitionAccessorsV0;
+use dpp::state_transition::documents_batch_transition::accessors::{DocumentsBatchTransitionAccessorsV0, DocumentsBatchTransitionAccessorsV1};
 use dpp::state_transition::documents_batch_transition::document_base_transition::v0::v0_methods::DocumentBaseTransitionV0Methods;
 use dpp::state_transition::documents_batch_transition::document_transition::{DocumentTransition, DocumentReplaceTransition, DocumentTransitionV0Methods};
 use dpp::state_transition::StateTransitionLike;
@@ -155,6 +155,7 @@ impl DocumentsBatchTransitionTransformerV0 for DocumentsBatchTransition {
         if validation_result.is_valid() {
             let batch_transition_action = DocumentsBatchTransitionActionV0 {
                 owner_id,
+                sponsor_id: self.sponsor().map(|sponsor| sponsor.sponsor_id),
                 transitions: validation_result.into_data()?,
             }
             .into();
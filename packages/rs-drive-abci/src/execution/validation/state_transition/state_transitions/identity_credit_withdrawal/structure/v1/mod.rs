@@ -0,0 +1,92 @@
+use dpp::consensus::basic::identity::{
+    InvalidIdentityCreditWithdrawalTransitionAmountError,
+    InvalidIdentityCreditWithdrawalTransitionCoreFeeError,
+    InvalidIdentityCreditWithdrawalTransitionOutputScriptError,
+};
+use dpp::consensus::ConsensusError;
+
+use crate::error::Error;
+use dpp::state_transition::identity_credit_withdrawal_transition::accessors::IdentityCreditWithdrawalTransitionAccessorsV0;
+use dpp::state_transition::identity_credit_withdrawal_transition::IdentityCreditWithdrawalTransition;
+use dpp::util::is_fibonacci_number::is_fibonacci_number;
+use dpp::validation::SimpleConsensusValidationResult;
+
+const MIN_WITHDRAWAL_AMOUNT: u64 = 1000;
+
+pub(in crate::execution::validation::state_transition::state_transitions::identity_credit_withdrawal) trait IdentityCreditWithdrawalStateTransitionStructureValidationV1 {
+    fn validate_base_structure_v1(&self) -> Result<SimpleConsensusValidationResult, Error>;
+}
+
+impl IdentityCreditWithdrawalStateTransitionStructureValidationV1
+    for IdentityCreditWithdrawalTransition
+{
+    fn validate_base_structure_v1(&self) -> Result<SimpleConsensusValidationResult, Error> {
+        let mut result = SimpleConsensusValidationResult::default();
+
+        if self.amount() < MIN_WITHDRAWAL_AMOUNT {
+            result.add_error(ConsensusError::from(
+                InvalidIdentityCreditWithdrawalTransitionAmountError::new(
+                    self.amount(),
+                    MIN_WITHDRAWAL_AMOUNT,
+                ),
+            ));
+        }
+
+        // all pooling options are supported since withdrawals are pooled
+        // into shared asset unlock transactions
+
+        // validate core_fee is in fibonacci sequence
+
+        if !is_fibonacci_number(self.core_fee_per_byte()) {
+            result.add_error(InvalidIdentityCreditWithdrawalTransitionCoreFeeError::new(
+                self.core_fee_per_byte(),
+            ));
+
+            return Ok(result);
+        }
+
+        // validate output_script types
+        if !self.output_script().is_p2pkh() && !self.output_script().is_p2sh() {
+            result.add_error(
+                InvalidIdentityCreditWithdrawalTransitionOutputScriptError::new(
+                    self.output_script().clone(),
+                ),
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dpp::identity::core_script::CoreScript;
+    use dpp::state_transition::identity_credit_withdrawal_transition::v0::IdentityCreditWithdrawalTransitionV0;
+    use dpp::withdrawal::Pooling;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn should_accept_every_pooling_option() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for pooling in [Pooling::Never, Pooling::IfAvailable, Pooling::Standard] {
+            let transition: IdentityCreditWithdrawalTransition =
+                IdentityCreditWithdrawalTransitionV0 {
+                    amount: 5000,
+                    core_fee_per_byte: 1,
+                    pooling,
+                    output_script: CoreScript::random_p2pkh(&mut rng),
+                    ..Default::default()
+                }
+                .into();
+
+            let result = transition
+                .validate_base_structure_v1()
+                .expect("expected to validate structure");
+
+            assert!(result.is_valid(), "{:?}", result.errors);
+        }
+    }
+}
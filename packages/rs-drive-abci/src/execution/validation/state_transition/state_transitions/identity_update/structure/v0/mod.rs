@@ -5,9 +5,12 @@ use dpp::consensus::basic::identity::{
 };
 use dpp::consensus::state::identity::max_identity_public_key_limit_reached_error::MaxIdentityPublicKeyLimitReachedError;
 use dpp::consensus::ConsensusError;
-use dpp::state_transition::identity_update_transition::accessors::IdentityUpdateTransitionAccessorsV0;
+use dpp::state_transition::identity_update_transition::accessors::{
+    IdentityUpdateTransitionAccessorsV0, IdentityUpdateTransitionAccessorsV1,
+};
 use dpp::state_transition::identity_update_transition::IdentityUpdateTransition;
 use dpp::state_transition::public_key_in_creation::IdentityPublicKeyInCreation;
+use dpp::state_transition::FeatureVersioned;
 use dpp::validation::SimpleConsensusValidationResult;
 use dpp::version::PlatformVersion;
 use std::collections::HashSet;
@@ -27,10 +30,19 @@ impl IdentityUpdateStateTransitionStructureValidationV0 for IdentityUpdateTransi
         &self,
         platform_version: &PlatformVersion,
     ) -> Result<SimpleConsensusValidationResult, Error> {
-        let mut result = SimpleConsensusValidationResult::default();
+        // Sponsorship spending limits are only accepted from the version they were introduced in
+        let mut result = platform_version
+            .validate_identity_update_state_transition_version(self.feature_version());
+
+        if !result.is_valid() {
+            return Ok(result);
+        }
 
-        // Ensure that either disablePublicKeys or addPublicKeys is present
-        if self.public_key_ids_to_disable().is_empty() && self.public_keys_to_add().is_empty() {
+        // Ensure that either disablePublicKeys, addPublicKeys or sponsorshipSpendingLimits is present
+        if self.public_key_ids_to_disable().is_empty()
+            && self.public_keys_to_add().is_empty()
+            && self.sponsorship_spending_limits().is_empty()
+        {
             result.add_error(ConsensusError::from(
                 InvalidIdentityUpdateTransitionEmptyError::new(),
             ));
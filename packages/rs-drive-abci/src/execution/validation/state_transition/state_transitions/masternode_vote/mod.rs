@@ -0,0 +1,114 @@
+mod state;
+mod structure;
+
+use dpp::consensus::basic::state_transition::InvalidStateTransitionTypeError;
+use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use dpp::state_transition::StateTransitionType;
+use dpp::validation::{ConsensusValidationResult, SimpleConsensusValidationResult};
+use dpp::version::PlatformVersion;
+use drive::state_transition_action::StateTransitionAction;
+
+use drive::grovedb::TransactionArg;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::{PlatformRef, PlatformStateRef};
+use crate::rpc::core::CoreRPCLike;
+
+use crate::execution::validation::state_transition::masternode_vote::state::v0::MasternodeVoteStateTransitionStateValidationV0;
+use crate::execution::validation::state_transition::masternode_vote::structure::v0::MasternodeVoteStateTransitionStructureValidationV0;
+use crate::execution::validation::state_transition::processor::v0::{
+    StateTransitionStateValidationV0, StateTransitionStructureValidationV0,
+};
+use crate::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+
+impl StateTransitionActionTransformerV0 for MasternodeVoteTransition {
+    fn transform_into_action<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        _validate: bool,
+        _tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version =
+            PlatformVersion::get(platform.state.current_protocol_version_in_consensus())?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .masternode_vote_state_transition
+            .map(|versions| versions.transform_into_action)
+        {
+            Some(0) => self.transform_into_action_v0(),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "masternode vote transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "masternode vote transition: transform_into_action".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
+
+impl StateTransitionStructureValidationV0 for MasternodeVoteTransition {
+    fn validate_structure(
+        &self,
+        _platform: &PlatformStateRef,
+        _action: Option<&StateTransitionAction>,
+        protocol_version: u32,
+    ) -> Result<SimpleConsensusValidationResult, Error> {
+        let platform_version = PlatformVersion::get(protocol_version)?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .masternode_vote_state_transition
+            .map(|versions| versions.structure)
+        {
+            Some(0) => self.validate_base_structure_v0(),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "masternode vote transition: validate_structure".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // Masternodes can't vote before the protocol version introducing voting
+            None => Ok(SimpleConsensusValidationResult::new_with_error(
+                InvalidStateTransitionTypeError::new(StateTransitionType::MasternodeVote as u8)
+                    .into(),
+            )),
+        }
+    }
+}
+
+impl StateTransitionStateValidationV0 for MasternodeVoteTransition {
+    fn validate_state<C: CoreRPCLike>(
+        &self,
+        _action: Option<StateTransitionAction>,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let platform_version =
+            PlatformVersion::get(platform.state.current_protocol_version_in_consensus())?;
+        match platform_version
+            .drive_abci
+            .validation_and_processing
+            .state_transitions
+            .masternode_vote_state_transition
+            .map(|versions| versions.state)
+        {
+            Some(0) => self.validate_state_v0(platform, tx, platform_version),
+            Some(version) => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "masternode vote transition: validate_state".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            None => Err(Error::Execution(ExecutionError::VersionNotActive {
+                method: "masternode vote transition: validate_state".to_string(),
+                known_versions: vec![0],
+            })),
+        }
+    }
+}
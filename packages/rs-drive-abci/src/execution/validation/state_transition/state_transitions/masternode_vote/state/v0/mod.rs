@@ -0,0 +1,337 @@
+use crate::error::Error;
+use crate::platform_types::platform::{Platform, PlatformRef};
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+
+use dashcore_rpc::dashcore::ProTxHash;
+use dpp::consensus::state::voting::contested_resource_contender_not_found_error::ContestedResourceContenderNotFoundError;
+use dpp::consensus::state::voting::contested_resource_vote_poll_ended_error::ContestedResourceVotePollEndedError;
+use dpp::consensus::state::voting::contested_resource_vote_poll_not_found_error::ContestedResourceVotePollNotFoundError;
+use dpp::consensus::state::voting::masternode_not_found_error::MasternodeNotFoundError;
+use dpp::consensus::state::voting::masternode_vote_changes_exceeded_error::MasternodeVoteChangesExceededError;
+use dpp::consensus::state::voting::masternode_voter_identity_mismatch_error::MasternodeVoterIdentityMismatchError;
+use dpp::dashcore::hashes::Hash;
+use dpp::prelude::ConsensusValidationResult;
+use dpp::state_transition::masternode_vote_transition::accessors::MasternodeVoteTransitionAccessorsV0;
+use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+use dpp::voting::ResourceVoteChoice;
+use drive::state_transition_action::identity::masternode_vote::MasternodeVoteTransitionAction;
+
+use dpp::version::PlatformVersion;
+use drive::grovedb::TransactionArg;
+use drive::state_transition_action::StateTransitionAction;
+
+pub(in crate::execution::validation::state_transition::state_transitions::masternode_vote) trait MasternodeVoteStateTransitionStateValidationV0
+{
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error>;
+}
+
+impl MasternodeVoteStateTransitionStateValidationV0 for MasternodeVoteTransition {
+    fn validate_state_v0<C: CoreRPCLike>(
+        &self,
+        platform: &PlatformRef<C>,
+        tx: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        let pro_tx_hash = ProTxHash::from_byte_array(self.pro_tx_hash().to_buffer());
+
+        let Some(masternode) = platform.state.full_masternode_list().get(&pro_tx_hash) else {
+            return Ok(ConsensusValidationResult::new_with_error(
+                MasternodeNotFoundError::new(self.pro_tx_hash()).into(),
+            ));
+        };
+
+        // Only the voter identity of the masternode can vote on its behalf
+        let voter_identifier = Platform::<C>::get_voter_identifier_from_masternode_list_item(
+            masternode,
+            platform_version,
+        )?;
+
+        if voter_identifier != self.voter_identity_id().to_buffer() {
+            return Ok(ConsensusValidationResult::new_with_error(
+                MasternodeVoterIdentityMismatchError::new(
+                    self.pro_tx_hash(),
+                    self.voter_identity_id(),
+                )
+                .into(),
+            ));
+        }
+
+        let contest_id = self.vote_poll().unique_id()?;
+
+        let Some(end_time_ms) = platform.drive.fetch_contest_end_time(
+            contest_id.as_bytes(),
+            tx,
+            &platform_version.drive,
+        )?
+        else {
+            return Ok(ConsensusValidationResult::new_with_error(
+                ContestedResourceVotePollNotFoundError::new(contest_id).into(),
+            ));
+        };
+
+        if let Some(last_block_time_ms) = platform.state.last_block_time_ms() {
+            if end_time_ms <= last_block_time_ms {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    ContestedResourceVotePollEndedError::new(contest_id, end_time_ms).into(),
+                ));
+            }
+        }
+
+        if let ResourceVoteChoice::TowardsIdentity(contender_id) = self.choice() {
+            if !platform.drive.has_contested_resource_contender(
+                contest_id.as_bytes(),
+                contender_id,
+                tx,
+                &platform_version.drive,
+            )? {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    ContestedResourceContenderNotFoundError::new(contest_id, contender_id).into(),
+                ));
+            }
+        }
+
+        // Votes are free so masternodes can only change their vote a limited number of times
+        if let Some(max_vote_changes) = platform_version.drive_abci.voting.max_vote_changes_per_poll
+        {
+            let vote_changes = platform.drive.fetch_masternode_vote_changes(
+                contest_id.as_bytes(),
+                self.pro_tx_hash(),
+                tx,
+                &mut vec![],
+                &platform_version.drive,
+            )?;
+
+            if vote_changes >= max_vote_changes {
+                return Ok(ConsensusValidationResult::new_with_error(
+                    MasternodeVoteChangesExceededError::new(
+                        self.pro_tx_hash(),
+                        contest_id,
+                        max_vote_changes,
+                    )
+                    .into(),
+                ));
+            }
+        }
+
+        self.transform_into_action_v0()
+    }
+
+    fn transform_into_action_v0(
+        &self,
+    ) -> Result<ConsensusValidationResult<StateTransitionAction>, Error> {
+        Ok(ConsensusValidationResult::new_with_data(
+            MasternodeVoteTransitionAction::from(self).into(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MasternodeVoteStateTransitionStateValidationV0;
+    use crate::config::PlatformConfig;
+    use crate::platform_types::platform::{Platform, PlatformRef};
+    use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dashcore_rpc::dashcore::{ProTxHash, Txid};
+    use dashcore_rpc::dashcore_rpc_json::{DMNState, MasternodeListItem, MasternodeType};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::consensus::state::state_error::StateError;
+    use dpp::consensus::ConsensusError;
+    use dpp::dashcore::hashes::Hash;
+    use dpp::platform_value::{Identifier, Value};
+    use dpp::state_transition::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+    use dpp::state_transition::masternode_vote_transition::MasternodeVoteTransition;
+    use dpp::system_data_contracts::dpns_contract;
+    use dpp::system_data_contracts::dpns_contract::document_types::domain;
+    use dpp::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+    use drive::drive::batch::{DriveOperation, VoteOperationType};
+    use drive::drive::flags::StorageFlags;
+    use std::net::SocketAddr;
+    use std::str::FromStr;
+
+    #[test]
+    fn should_reject_votes_after_max_vote_changes() {
+        let platform = TestPlatformBuilder::new()
+            .with_config(PlatformConfig {
+                initial_protocol_version: 2,
+                ..Default::default()
+            })
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let mut state = platform.state.read().unwrap().clone();
+
+        let platform_version = state
+            .current_platform_version()
+            .expect("expected a platform version");
+
+        let max_vote_changes = platform_version
+            .drive_abci
+            .voting
+            .max_vote_changes_per_poll
+            .expect("expected vote changes to be limited");
+
+        let pro_tx_hash = Identifier::new([1; 32]);
+
+        let masternode = MasternodeListItem {
+            node_type: MasternodeType::Regular,
+            pro_tx_hash: ProTxHash::from_byte_array(pro_tx_hash.to_buffer()),
+            collateral_hash: Txid::from_str(
+                "4eb56228c535db3b234907113fd41d57bcc7cdcb8e0e00e57590af27ee88c119",
+            )
+            .expect("expected to decode collateral hash"),
+            collateral_index: 0,
+            collateral_address: [0; 20],
+            operator_reward: 0.0,
+            state: DMNState {
+                service: SocketAddr::from_str("1.2.3.4:1234").unwrap(),
+                registered_height: 0,
+                pose_revived_height: None,
+                pose_ban_height: None,
+                revocation_reason: 0,
+                owner_address: [1; 20],
+                voting_address: [2; 20],
+                payout_address: [3; 20],
+                pub_key_operator: vec![4; 48],
+                operator_payout_address: None,
+                platform_node_id: None,
+                platform_p2p_port: None,
+                platform_http_port: None,
+            },
+        };
+
+        let voter_identity_id = Identifier::new(
+            Platform::<MockCoreRPCLike>::get_voter_identifier_from_masternode_list_item(
+                &masternode,
+                platform_version,
+            )
+            .expect("expected a voter identifier"),
+        );
+
+        state
+            .full_masternode_list_mut()
+            .insert(masternode.pro_tx_hash, masternode);
+
+        let vote_poll = ContestedDocumentResourceVotePoll {
+            contract_id: dpns_contract::ID,
+            document_type_name: domain::NAME.to_string(),
+            index_name: "parentNameAndLabel".to_string(),
+            index_values: vec![
+                Value::Text("dash".to_string()),
+                Value::Text("alice".to_string()),
+            ],
+        };
+
+        let contenders = [Identifier::new([2; 32]), Identifier::new([3; 32])];
+
+        let block_info = BlockInfo::default_with_time(1);
+
+        platform
+            .drive
+            .apply_drive_operations(
+                contenders
+                    .iter()
+                    .map(|contender_id| {
+                        DriveOperation::VoteOperation(VoteOperationType::AddContender {
+                            vote_poll: vote_poll.clone(),
+                            contender_id: *contender_id,
+                            serialized_document: vec![0],
+                            storage_flags: StorageFlags::new_single_epoch(0, None),
+                        })
+                    })
+                    .collect(),
+                true,
+                &block_info,
+                None,
+                platform_version,
+            )
+            .expect("expected to add contenders");
+
+        let platform_ref = PlatformRef {
+            drive: &platform.drive,
+            state: &state,
+            config: &platform.config,
+            core_rpc: &platform.core_rpc,
+        };
+
+        // The first vote doesn't count as a change
+        for vote in 0..=max_vote_changes {
+            let choice = ResourceVoteChoice::TowardsIdentity(contenders[vote as usize % 2]);
+
+            let state_transition = MasternodeVoteTransition::V0(MasternodeVoteTransitionV0 {
+                pro_tx_hash,
+                voter_identity_id,
+                vote_poll: vote_poll.clone(),
+                choice,
+                ..Default::default()
+            });
+
+            let validation_result = state_transition
+                .validate_state_v0(&platform_ref, None, platform_version)
+                .expect("expected to validate the vote");
+
+            assert!(validation_result.is_valid());
+
+            platform
+                .drive
+                .apply_drive_operations(
+                    vec![DriveOperation::VoteOperation(VoteOperationType::CastVote {
+                        vote_poll: vote_poll.clone(),
+                        pro_tx_hash,
+                        choice,
+                    })],
+                    true,
+                    &block_info,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to cast a vote");
+        }
+
+        let contest_id = vote_poll.unique_id().expect("expected a contest id");
+
+        assert_eq!(
+            platform
+                .drive
+                .fetch_masternode_vote_changes(
+                    contest_id.as_bytes(),
+                    pro_tx_hash,
+                    None,
+                    &mut vec![],
+                    &platform_version.drive,
+                )
+                .expect("expected to fetch the vote changes"),
+            max_vote_changes
+        );
+
+        let state_transition = MasternodeVoteTransition::V0(MasternodeVoteTransitionV0 {
+            pro_tx_hash,
+            voter_identity_id,
+            vote_poll: vote_poll.clone(),
+            choice: ResourceVoteChoice::Abstain,
+            ..Default::default()
+        });
+
+        let validation_result = state_transition
+            .validate_state_v0(&platform_ref, None, platform_version)
+            .expect("expected to validate the vote");
+
+        assert!(matches!(
+            validation_result.errors.as_slice(),
+            [ConsensusError::StateError(
+                StateError::MasternodeVoteChangesExceededError(_)
+            )]
+        ));
+    }
+}
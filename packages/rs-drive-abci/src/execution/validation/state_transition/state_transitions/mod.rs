@@ -16,6 +16,9 @@ pub mod identity_top_up;
 /// Module for updating an existing identity entity.
 pub mod identity_update;
 
+/// Module for masternode votes on contested resources.
+pub mod masternode_vote;
+
 /// Module for creating a data contract entity.
 pub mod data_contract_create;
 
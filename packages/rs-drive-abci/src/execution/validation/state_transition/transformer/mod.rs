@@ -56,6 +56,7 @@ impl StateTransitionActionTransformerV0 for StateTransition {
             StateTransition::IdentityCreditTransfer(st) => {
                 st.transform_into_action(platform, validate, tx)
             }
+            StateTransition::MasternodeVote(st) => st.transform_into_action(platform, validate, tx),
         }
     }
 }
@@ -0,0 +1,179 @@
+//! GroveDB checkpoints of committed state, published by the primary for followers.
+//!
+//! A checkpoint is created in a temporary directory and renamed once it's complete,
+//! so followers never see partial checkpoints. Directory names are zero padded heights
+//! and sort by height.
+
+use crate::error::Error;
+use crate::follower::error::FollowerError;
+use crate::platform_types::platform::Platform;
+use drive::error::Error::GroveDB;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const IN_PROGRESS_SUFFIX: &str = ".tmp";
+
+/// Name of the checkpoint directory of a height
+pub fn checkpoint_dir_name(height: u64) -> String {
+    format!("{:020}", height)
+}
+
+/// Lists complete checkpoints in a directory, ordered by height
+pub fn list_checkpoints(checkpoints_path: &Path) -> Result<Vec<(u64, PathBuf)>, FollowerError> {
+    if !checkpoints_path.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut checkpoints = vec![];
+
+    for entry in fs::read_dir(checkpoints_path)? {
+        let entry = entry?;
+
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
+
+        // Checkpoints in progress don't parse as heights
+        let Some(height) = entry
+            .file_name()
+            .to_str()
+            .and_then(|name| name.parse::<u64>().ok())
+        else {
+            continue;
+        };
+
+        checkpoints.push((height, entry.path()));
+    }
+
+    checkpoints.sort_by_key(|(height, _)| *height);
+
+    Ok(checkpoints)
+}
+
+/// Returns the checkpoint with the highest height
+pub fn latest_checkpoint(checkpoints_path: &Path) -> Result<Option<(u64, PathBuf)>, FollowerError> {
+    Ok(list_checkpoints(checkpoints_path)?.pop())
+}
+
+/// Makes a private copy of a checkpoint.
+///
+/// Table files are immutable and hard linked, other files are copied as RocksDB
+/// writes to them once the copy is opened.
+pub fn link_checkpoint(source: &Path, destination: &Path) -> Result<(), FollowerError> {
+    fs::create_dir_all(destination)?;
+
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let source_path = entry.path();
+        let destination_path = destination.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            link_checkpoint(&source_path, &destination_path)?;
+        } else if source_path.extension().is_some_and(|ext| ext == "sst") {
+            if fs::hard_link(&source_path, &destination_path).is_err() {
+                // Hard links don't work across file systems
+                fs::copy(&source_path, &destination_path)?;
+            }
+        } else {
+            fs::copy(&source_path, &destination_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl<C> Platform<C> {
+    /// Creates a checkpoint of the committed state at the given height and removes
+    /// checkpoints that are not kept anymore.
+    ///
+    /// Does nothing if checkpoints path is not configured.
+    pub fn publish_checkpoint(&self, height: u64) -> Result<(), Error> {
+        let Some(checkpoints_path) = &self.config.follower.checkpoints_path else {
+            return Ok(());
+        };
+
+        fs::create_dir_all(checkpoints_path).map_err(FollowerError::Io)?;
+
+        let name = checkpoint_dir_name(height);
+        let in_progress_path = checkpoints_path.join(format!("{}{}", name, IN_PROGRESS_SUFFIX));
+
+        // A previous attempt could have been interrupted
+        if in_progress_path.exists() {
+            fs::remove_dir_all(&in_progress_path).map_err(FollowerError::Io)?;
+        }
+
+        self.drive
+            .grove
+            .create_checkpoint(&in_progress_path)
+            .map_err(GroveDB)?;
+
+        fs::rename(&in_progress_path, checkpoints_path.join(name)).map_err(FollowerError::Io)?;
+
+        let checkpoints = list_checkpoints(checkpoints_path)?;
+        let checkpoints_to_remove = checkpoints
+            .len()
+            .saturating_sub(self.config.follower.checkpoints_to_keep());
+
+        for (_, path) in checkpoints.into_iter().take(checkpoints_to_remove) {
+            fs::remove_dir_all(path).map_err(FollowerError::Io)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_list_complete_checkpoints_by_height() {
+        let dir = TempDir::new().unwrap();
+
+        for name in [
+            checkpoint_dir_name(10),
+            checkpoint_dir_name(2),
+            format!("{}{}", checkpoint_dir_name(11), IN_PROGRESS_SUFFIX),
+        ] {
+            fs::create_dir(dir.path().join(name)).unwrap();
+        }
+        fs::write(dir.path().join("not-a-checkpoint"), b"").unwrap();
+
+        let heights: Vec<u64> = list_checkpoints(dir.path())
+            .expect("expected to list checkpoints")
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect();
+
+        assert_eq!(heights, vec![2, 10]);
+        assert_eq!(
+            latest_checkpoint(dir.path())
+                .unwrap()
+                .map(|(height, _)| height),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn should_link_checkpoint_files() {
+        let dir = TempDir::new().unwrap();
+        let source = dir.path().join("source");
+        let destination = dir.path().join("destination");
+
+        fs::create_dir(&source).unwrap();
+        fs::write(source.join("000001.sst"), b"table").unwrap();
+        fs::write(source.join("MANIFEST-000001"), b"manifest").unwrap();
+
+        link_checkpoint(&source, &destination).expect("expected to link checkpoint");
+
+        // Removing the source must not affect the copy
+        fs::remove_dir_all(&source).unwrap();
+
+        assert_eq!(fs::read(destination.join("000001.sst")).unwrap(), b"table");
+        assert_eq!(
+            fs::read(destination.join("MANIFEST-000001")).unwrap(),
+            b"manifest"
+        );
+    }
+}
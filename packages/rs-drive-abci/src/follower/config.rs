@@ -0,0 +1,106 @@
+//! Configuration of checkpoints and the read-only follower
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Configuration of checkpoints published by the primary and the follower serving queries
+/// from them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FollowerConfig {
+    /// Directory where GroveDB checkpoints are published.
+    ///
+    /// If set, the primary creates a checkpoint of the state after every committed block
+    /// at a multiple of the checkpoints interval. The follower reads checkpoints from
+    /// this directory.
+    pub checkpoints_path: Option<PathBuf>,
+
+    /// Every how many blocks the primary publishes a checkpoint.
+    ///
+    /// Creating a checkpoint delays the next block, higher intervals trade followers
+    /// lagging behind for block execution time.
+    pub checkpoints_interval: String, // String due to https://github.com/softprops/envy/issues/26
+
+    /// How many latest checkpoints the primary keeps
+    pub checkpoints_to_keep: String, // String due to https://github.com/softprops/envy/issues/26
+
+    /// Address the follower listens for ABCI query connections on
+    ///
+    /// Address should be an URL with scheme `tcp://` or `unix://`, for example:
+    /// - `tcp://127.0.0.1:26659`
+    /// - `unix:///var/run/abci-follower.sock`
+    pub follower_bind_address: String,
+
+    /// How often the follower checks for new checkpoints, in milliseconds
+    pub follower_poll_interval_ms: String, // String due to https://github.com/softprops/envy/issues/26
+}
+
+impl FollowerConfig {
+    /// Returns every how many blocks the primary publishes a checkpoint, at least one
+    pub fn checkpoints_interval(&self) -> u64 {
+        self.checkpoints_interval
+            .parse::<u64>()
+            .expect("CHECKPOINTS_INTERVAL is not an int")
+            .max(1)
+    }
+
+    /// Returns true if the primary should publish a checkpoint at the given height
+    pub fn should_publish_checkpoint(&self, height: u64) -> bool {
+        self.checkpoints_path.is_some() && height % self.checkpoints_interval() == 0
+    }
+
+    /// Returns how many checkpoints the primary keeps, at least one
+    pub fn checkpoints_to_keep(&self) -> usize {
+        self.checkpoints_to_keep
+            .parse::<usize>()
+            .expect("CHECKPOINTS_TO_KEEP is not an int")
+            .max(1)
+    }
+
+    /// Returns how often the follower checks for new checkpoints
+    pub fn follower_poll_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.follower_poll_interval_ms
+                .parse::<u64>()
+                .expect("FOLLOWER_POLL_INTERVAL_MS is not an int"),
+        )
+    }
+}
+
+impl Default for FollowerConfig {
+    fn default() -> Self {
+        Self {
+            checkpoints_path: None,
+            checkpoints_interval: String::from("1"),
+            checkpoints_to_keep: String::from("3"),
+            follower_bind_address: String::from("tcp://127.0.0.1:26659"),
+            follower_poll_interval_ms: String::from("1000"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_publish_checkpoints_at_interval() {
+        let config = FollowerConfig {
+            checkpoints_path: Some(PathBuf::from("/tmp/checkpoints")),
+            checkpoints_interval: String::from("10"),
+            ..Default::default()
+        };
+
+        assert!(config.should_publish_checkpoint(20));
+        assert!(!config.should_publish_checkpoint(21));
+
+        let config = FollowerConfig {
+            checkpoints_interval: String::from("1"),
+            ..Default::default()
+        };
+
+        // Checkpoints aren't published without a checkpoints path
+        assert!(!config.should_publish_checkpoint(20));
+    }
+}
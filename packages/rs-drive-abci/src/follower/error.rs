@@ -0,0 +1,21 @@
+use std::path::PathBuf;
+
+/// Errors of checkpoints and the read-only follower
+#[derive(Debug, thiserror::Error)]
+pub enum FollowerError {
+    /// Checkpoints path is not configured
+    #[error("CHECKPOINTS_PATH is not configured")]
+    CheckpointsPathNotConfigured,
+
+    /// There is no checkpoint to follow yet
+    #[error("no checkpoints found in {0:?}")]
+    NoCheckpoints(PathBuf),
+
+    /// Io error when creating, linking or removing checkpoints
+    #[error("checkpoint io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The request changes state, which a follower never does
+    #[error("read-only follower can't process {0}")]
+    ReadOnly(&'static str),
+}
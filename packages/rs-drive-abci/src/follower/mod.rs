@@ -0,0 +1,206 @@
+//! Read-only follower.
+//!
+//! Query load from DAPI shouldn't compete with block execution. The primary publishes
+//! a GroveDB checkpoint after committed blocks at a configured interval, and followers serve
+//! queries and proofs from a private copy of the latest one. Followers never execute blocks, so any number
+//! of them can be started to scale query capacity.
+
+pub mod checkpoints;
+pub mod config;
+pub mod error;
+#[cfg(any(feature = "server", test))]
+mod server;
+
+#[cfg(feature = "server")]
+pub use server::start_follower;
+#[cfg(any(feature = "server", test))]
+pub use server::FollowerApplication;
+
+use crate::config::PlatformConfig;
+use crate::error::Error;
+use crate::follower::checkpoints::{checkpoint_dir_name, latest_checkpoint, link_checkpoint};
+use crate::follower::error::FollowerError;
+use crate::platform_types::platform::Platform;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+
+/// Opens a platform from a directory, see [Platform::open]
+pub type PlatformOpener<C> = fn(PathBuf, Option<PlatformConfig>) -> Result<Platform<C>, Error>;
+
+/// Platform opened from a private copy of a checkpoint.
+///
+/// The copy is removed once the checkpoint isn't followed and used by queries anymore.
+pub struct FollowedCheckpoint<C> {
+    /// The height of the last committed block in the checkpoint
+    pub height: u64,
+    platform: Option<Platform<C>>,
+    path: PathBuf,
+}
+
+impl<C> FollowedCheckpoint<C> {
+    /// The platform of the checkpoint
+    pub fn platform(&self) -> &Platform<C> {
+        self.platform
+            .as_ref()
+            .expect("platform is only taken when the checkpoint is dropped")
+    }
+}
+
+impl<C> Drop for FollowedCheckpoint<C> {
+    fn drop(&mut self) {
+        // The database must be closed before its files are removed
+        drop(self.platform.take());
+
+        if let Err(error) = fs::remove_dir_all(&self.path) {
+            tracing::warn!(?error, path = ?self.path, "cannot remove followed checkpoint");
+        }
+    }
+}
+
+/// Follows checkpoints published by the primary
+pub struct Follower<C> {
+    config: PlatformConfig,
+    checkpoints_path: PathBuf,
+    open_platform: PlatformOpener<C>,
+    current: RwLock<Option<Arc<FollowedCheckpoint<C>>>>,
+}
+
+impl<C> Follower<C> {
+    /// Creates a follower of checkpoints in the configured checkpoints path.
+    ///
+    /// Copies of checkpoints are kept in the configured database path.
+    pub fn new(config: PlatformConfig, open_platform: PlatformOpener<C>) -> Result<Self, Error> {
+        let checkpoints_path = config
+            .follower
+            .checkpoints_path
+            .clone()
+            .ok_or(FollowerError::CheckpointsPathNotConfigured)?;
+
+        Ok(Self {
+            config,
+            checkpoints_path,
+            open_platform,
+            current: RwLock::new(None),
+        })
+    }
+
+    /// The currently followed checkpoint
+    pub fn current(&self) -> Option<Arc<FollowedCheckpoint<C>>> {
+        self.current.read().unwrap().clone()
+    }
+
+    /// Switches to the latest published checkpoint if it's newer than the followed one.
+    ///
+    /// Queries in progress keep using the previous checkpoint until they finish.
+    /// Returns the followed height.
+    pub fn refresh(&self) -> Result<Option<u64>, Error> {
+        let current_height = self.current().map(|checkpoint| checkpoint.height);
+
+        let Some((height, checkpoint_path)) = latest_checkpoint(&self.checkpoints_path)? else {
+            return Ok(current_height);
+        };
+
+        if current_height.is_some_and(|current_height| current_height >= height) {
+            return Ok(current_height);
+        }
+
+        let path = self.config.db_path.join(checkpoint_dir_name(height));
+
+        if path.exists() {
+            fs::remove_dir_all(&path).map_err(FollowerError::Io)?;
+        }
+
+        link_checkpoint(&checkpoint_path, &path)?;
+
+        let platform = match (self.open_platform)(path.clone(), Some(self.config.clone())) {
+            Ok(platform) => platform,
+            Err(error) => {
+                let _ = fs::remove_dir_all(&path);
+                return Err(error);
+            }
+        };
+
+        self.current
+            .write()
+            .unwrap()
+            .replace(Arc::new(FollowedCheckpoint {
+                height,
+                platform: Some(platform),
+                path,
+            }));
+
+        tracing::debug!(height, "following checkpoint");
+
+        Ok(Some(height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::follower::checkpoints::list_checkpoints;
+    use crate::rpc::core::MockCoreRPCLike;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_follow_latest_published_checkpoint() {
+        let checkpoints_dir = TempDir::new().unwrap();
+        let follower_dir = TempDir::new().unwrap();
+
+        let mut config = PlatformConfig::default();
+        config.follower.checkpoints_path = Some(checkpoints_dir.path().to_path_buf());
+
+        let platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let follower = Follower::new(
+            PlatformConfig {
+                db_path: follower_dir.path().to_path_buf(),
+                ..config
+            },
+            Platform::<MockCoreRPCLike>::open::<PathBuf>,
+        )
+        .expect("expected to create follower");
+
+        assert_eq!(follower.refresh().expect("expected to refresh"), None);
+
+        for height in 1..=4 {
+            platform
+                .publish_checkpoint(height)
+                .expect("expected to publish checkpoint");
+        }
+
+        let published: Vec<u64> = list_checkpoints(checkpoints_dir.path())
+            .unwrap()
+            .into_iter()
+            .map(|(height, _)| height)
+            .collect();
+        assert_eq!(published, vec![2, 3, 4]);
+
+        assert_eq!(follower.refresh().expect("expected to refresh"), Some(4));
+
+        let checkpoint = follower.current().expect("expected a followed checkpoint");
+        let followed_root_hash = checkpoint
+            .platform()
+            .drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("expected root hash");
+        let primary_root_hash = platform
+            .drive
+            .grove
+            .root_hash(None)
+            .unwrap()
+            .expect("expected root hash");
+        assert_eq!(followed_root_hash, primary_root_hash);
+
+        // The followed checkpoint doesn't depend on published checkpoints
+        fs::remove_dir_all(checkpoints_dir.path().join(checkpoint_dir_name(4))).unwrap();
+        assert_eq!(follower.refresh().expect("expected to refresh"), Some(4));
+    }
+}
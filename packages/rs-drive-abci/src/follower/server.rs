@@ -0,0 +1,189 @@
+//! ABCI server of the read-only follower
+
+use crate::abci::handlers::query_response;
+use crate::abci::AbciError;
+use crate::error::Error;
+use crate::follower::error::FollowerError;
+use crate::follower::Follower;
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
+use crate::rpc::core::CoreRPCLike;
+use tenderdash_abci::proto::abci::{self as proto, ResponseException};
+use tenderdash_abci::proto::abci::{
+    RequestCheckTx, RequestFinalizeBlock, RequestInitChain, RequestPrepareProposal,
+    RequestProcessProposal, RequestQuery, ResponseCheckTx, ResponseFinalizeBlock,
+    ResponseInitChain, ResponsePrepareProposal, ResponseProcessProposal, ResponseQuery,
+};
+
+#[cfg(feature = "server")]
+use crate::config::PlatformConfig;
+#[cfg(feature = "server")]
+use crate::platform_types::platform::Platform;
+#[cfg(feature = "server")]
+use crate::rpc::core::DefaultCoreRPC;
+#[cfg(feature = "server")]
+use std::path::PathBuf;
+#[cfg(feature = "server")]
+use tokio_util::sync::CancellationToken;
+
+/// ABCI application of the follower.
+///
+/// It answers `info` and `query` from the followed checkpoint and rejects all requests that
+/// would change state.
+pub struct FollowerApplication<'a, C> {
+    /// The follower
+    pub follower: &'a Follower<C>,
+}
+
+impl<'a, C> FollowerApplication<'a, C> {
+    /// Create new follower ABCI app
+    pub fn new(follower: &'a Follower<C>) -> Self {
+        Self { follower }
+    }
+}
+
+impl<'a, C> std::fmt::Debug for FollowerApplication<'a, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "<FollowerApp>")
+    }
+}
+
+fn read_only(request: &'static str) -> ResponseException {
+    Error::Follower(FollowerError::ReadOnly(request)).into()
+}
+
+impl<'a, C> tenderdash_abci::Application for FollowerApplication<'a, C>
+where
+    C: CoreRPCLike,
+{
+    fn info(&self, request: proto::RequestInfo) -> Result<proto::ResponseInfo, ResponseException> {
+        if !tenderdash_abci::check_version(&request.abci_version) {
+            return Err(ResponseException::from(format!(
+                "tenderdash requires ABCI version {}, our version is {}",
+                request.version,
+                tenderdash_abci::proto::ABCI_VERSION
+            )));
+        }
+
+        let (last_block_height, last_block_app_hash) = match self.follower.current() {
+            Some(checkpoint) => {
+                let state = checkpoint.platform().state.read().unwrap();
+                (
+                    state.last_block_height() as i64,
+                    state
+                        .last_block_app_hash()
+                        .map(|app_hash| app_hash.to_vec())
+                        .unwrap_or_default(),
+                )
+            }
+            None => (0, vec![]),
+        };
+
+        Ok(proto::ResponseInfo {
+            data: "".to_string(),
+            app_version: 1,
+            last_block_height,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            last_block_app_hash,
+        })
+    }
+
+    fn query(&self, request: RequestQuery) -> Result<ResponseQuery, ResponseException> {
+        let _timer = crate::metrics::abci_request_duration("follower_query");
+
+        // Keep the checkpoint for the whole query even if a newer one is followed meanwhile
+        let checkpoint = self.follower.current().ok_or_else(|| {
+            Error::Follower(FollowerError::NoCheckpoints(
+                self.follower.checkpoints_path.clone(),
+            ))
+        })?;
+
+        query_response(checkpoint.platform(), request)
+    }
+
+    fn init_chain(
+        &self,
+        _request: RequestInitChain,
+    ) -> Result<ResponseInitChain, ResponseException> {
+        Err(read_only("init_chain"))
+    }
+
+    fn prepare_proposal(
+        &self,
+        _request: RequestPrepareProposal,
+    ) -> Result<ResponsePrepareProposal, ResponseException> {
+        Err(read_only("prepare_proposal"))
+    }
+
+    fn process_proposal(
+        &self,
+        _request: RequestProcessProposal,
+    ) -> Result<ResponseProcessProposal, ResponseException> {
+        Err(read_only("process_proposal"))
+    }
+
+    fn finalize_block(
+        &self,
+        _request: RequestFinalizeBlock,
+    ) -> Result<ResponseFinalizeBlock, ResponseException> {
+        Err(read_only("finalize_block"))
+    }
+
+    fn check_tx(&self, _request: RequestCheckTx) -> Result<ResponseCheckTx, ResponseException> {
+        Err(read_only("check_tx"))
+    }
+}
+
+/// Start the read-only follower and serve queries until cancelled.
+///
+/// Waits for the first checkpoint to be published, then checks for newer checkpoints
+/// in the background.
+#[cfg(feature = "server")]
+pub fn start_follower(config: &PlatformConfig, cancel: CancellationToken) -> Result<(), Error> {
+    let follower = Follower::new(config.clone(), Platform::<DefaultCoreRPC>::open::<PathBuf>)?;
+
+    let poll_interval = config.follower.follower_poll_interval();
+
+    let refresh = || match follower.refresh() {
+        Ok(height) => height,
+        Err(error) => {
+            tracing::error!(?error, "cannot follow latest checkpoint");
+            None
+        }
+    };
+
+    while refresh().is_none() {
+        if cancel.is_cancelled() {
+            return Ok(());
+        }
+
+        tracing::info!("waiting for the first checkpoint");
+        std::thread::sleep(poll_interval);
+    }
+
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+            while !cancel.is_cancelled() {
+                std::thread::sleep(poll_interval);
+                refresh();
+            }
+        });
+
+        let server = tenderdash_abci::ServerBuilder::new(
+            FollowerApplication::new(&follower),
+            &config.follower.follower_bind_address,
+        )
+        .with_cancel_token(cancel.clone())
+        .build()
+        .map_err(AbciError::from)?;
+
+        while !cancel.is_cancelled() {
+            tracing::info!("waiting for new query connection");
+            match server.next_client() {
+                Err(e) => tracing::error!("query connection terminated: {:?}", e),
+                Ok(_) => tracing::info!("query connection closed"),
+            }
+        }
+
+        Ok(())
+    })
+}
@@ -36,6 +36,10 @@ pub mod metrics;
 #[cfg(any(feature = "mocks", test))]
 pub mod test;
 
+/// In-process publish/subscribe of Platform events
+pub mod event_bus;
+/// Read-only follower serving queries from checkpoints
+pub mod follower;
 /// Mimic of block execution for tests
 #[cfg(any(feature = "mocks", test))]
 pub mod mimic;
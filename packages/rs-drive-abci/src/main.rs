@@ -3,13 +3,28 @@
 //! RS-Drive-ABCI server starts a single-threaded server and listens to connections from Tenderdash.
 
 use clap::{Parser, Subcommand};
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::conversion::json::DataContractJsonConversionMethodsV0;
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+use drive::drive::state_archive::StateArchiveReader;
+use drive::drive::Drive;
 use drive_abci::config::{FromEnv, PlatformConfig};
 use drive_abci::core::wait_for_core_to_sync::v0::wait_for_core_to_sync_v0;
 use drive_abci::logging::{LogBuilder, LogConfig, Loggers};
 use drive_abci::metrics::{Prometheus, DEFAULT_PROMETHEUS_PORT};
+#[cfg(feature = "mocks")]
+use drive_abci::mimic::local_core::{LocalCore, LocalCoreConfig};
+use drive_abci::platform_types::platform::Platform;
+use drive_abci::platform_types::system_identity_public_keys::v0::SystemIdentityPublicKeysV0;
+use drive_abci::rpc::cache::CachedCoreRPC;
+#[cfg(feature = "chain-lock-listener")]
+use drive_abci::rpc::chain_lock_listener::start_chain_lock_listener;
 use drive_abci::rpc::core::DefaultCoreRPC;
 use itertools::Itertools;
-use std::path::PathBuf;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use tokio::runtime::Builder;
 use tokio::signal::unix::{signal, SignalKind};
@@ -24,6 +39,13 @@ enum Commands {
     /// Start server in foreground.
     #[command()]
     Start,
+    /// Start server in foreground with a simulated Dash Core.
+    ///
+    /// Core blocks are mined and chain locked every BLOCK_SPACING_MS. Masternodes and quorums
+    /// are generated deterministically, so no dashd is needed. For local development only.
+    #[cfg(feature = "mocks")]
+    #[command()]
+    StartWithLocalCore,
     /// Dump configuration
     ///
     /// WARNING: output can contain sensitive data!
@@ -35,6 +57,45 @@ enum Commands {
     /// Returns 0 on success.
     #[command()]
     Status,
+
+    /// Lint a data contract and estimate fees of its documents.
+    ///
+    /// Prints a JSON report with document sizes, fees to create, replace and
+    /// delete documents, index layout and warnings. Returns 0 if there are no warnings.
+    #[command()]
+    LintContract {
+        /// Path to the data contract JSON file.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+    },
+
+    /// Export state into a portable archive.
+    ///
+    /// Identities with their balances, data contracts, documents and system state
+    /// are written to the archive.
+    #[command()]
+    ExportState {
+        /// Path to the archive file to create.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+    },
+
+    /// Import a state archive into a fresh state.
+    ///
+    /// Genesis state is created first, then the archive is imported on top of it.
+    #[command()]
+    ImportState {
+        /// Path to the archive file to import.
+        #[arg(value_hint = clap::ValueHint::FilePath)]
+        path: PathBuf,
+    },
+
+    /// Start read-only follower in foreground.
+    ///
+    /// Serves queries from checkpoints published by the primary node in CHECKPOINTS_PATH.
+    /// ABCI queries are accepted on FOLLOWER_BIND_ADDRESS.
+    #[command()]
+    Follow,
 }
 
 /// Server that accepts connections from Tenderdash, and
@@ -82,6 +143,26 @@ impl Cli {
                     config.core.rpc.password.clone(),
                 )
                 .unwrap();
+                let core_rpc = CachedCoreRPC::new(core_rpc, config.core.rpc_cache_size());
+
+                let zmq_address = config
+                    .core
+                    .zmq_address
+                    .as_deref()
+                    .filter(|address| !address.is_empty());
+
+                #[cfg(feature = "chain-lock-listener")]
+                let _chain_lock_listener = zmq_address
+                    .map(|address| {
+                        start_chain_lock_listener(address, core_rpc.cache(), cancel.clone())
+                    })
+                    .transpose()
+                    .map_err(|e| e.to_string())?;
+
+                #[cfg(not(feature = "chain-lock-listener"))]
+                if zmq_address.is_some() {
+                    warn!("CORE_ZMQ_ADDRESS is ignored, drive-abci is built without chain-lock-listener feature");
+                }
 
                 let _prometheus = start_prometheus(&config)?;
 
@@ -93,8 +174,36 @@ impl Cli {
                 drive_abci::abci::start(&config, core_rpc, cancel).map_err(|e| e.to_string())?;
                 return Ok(());
             }
+            #[cfg(feature = "mocks")]
+            Commands::StartWithLocalCore => {
+                let local_core = LocalCore::new(LocalCoreConfig {
+                    genesis_height: config.abci.genesis_core_height,
+                    quorum_type: config.quorum_type(),
+                    quorum_size: config.quorum_size as usize,
+                    dkg_interval: config.core.dkg_interval(),
+                    ..Default::default()
+                });
+
+                let _miner = local_core.start_mining(
+                    Duration::from_millis(config.block_spacing_ms),
+                    cancel.clone(),
+                );
+
+                let _prometheus = start_prometheus(&config)?;
+
+                drive_abci::abci::start(&config, local_core, cancel).map_err(|e| e.to_string())?;
+                return Ok(());
+            }
             Commands::Config => dump_config(&config)?,
             Commands::Status => check_status(&config)?,
+            Commands::LintContract { path } => lint_contract(&path)?,
+            Commands::ExportState { path } => export_state(&config, &path)?,
+            Commands::ImportState { path } => import_state(config, &path)?,
+            Commands::Follow => {
+                let _prometheus = start_prometheus(&config)?;
+
+                drive_abci::follower::start_follower(&config, cancel).map_err(|e| e.to_string())?;
+            }
         };
 
         Ok(())
@@ -228,6 +337,104 @@ fn check_status(config: &PlatformConfig) -> Result<(), String> {
     }
 }
 
+/// Lint data contract without applying it to the state.
+fn lint_contract(path: &Path) -> Result<(), String> {
+    let platform_version = PlatformVersion::latest();
+
+    let file = File::open(path).map_err(|e| format!("cannot open {:?}: {}", path, e))?;
+    let json: serde_json::Value = serde_json::from_reader(BufReader::new(file))
+        .map_err(|e| format!("cannot parse {:?}: {}", path, e))?;
+    let contract =
+        DataContract::from_json(json, true, platform_version).map_err(|e| e.to_string())?;
+
+    // Fees are estimated without state, an empty drive is enough
+    let tmp_dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+    let drive = Drive::open(tmp_dir.path(), None).map_err(|e| e.to_string())?;
+    drive
+        .create_initial_state_structure(None, platform_version)
+        .map_err(|e| e.to_string())?;
+
+    let report = drive
+        .lint_data_contract(&contract, &BlockInfo::default(), platform_version)
+        .map_err(|e| e.to_string())?;
+
+    let serialized = serde_json::to_string_pretty(&report).expect("failed to serialize report");
+
+    println!("{}", serialized);
+
+    if report.has_warnings() {
+        Err("data contract has warnings".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Export state of the configured database into an archive.
+fn export_state(config: &PlatformConfig, path: &Path) -> Result<(), String> {
+    let drive =
+        Drive::open(&config.db_path, Some(config.drive.clone())).map_err(|e| e.to_string())?;
+
+    let protocol_version = drive
+        .fetch_current_protocol_version(None, &PlatformVersion::latest().drive)
+        .map_err(|e| e.to_string())?;
+    let platform_version = match protocol_version {
+        Some(protocol_version) => {
+            PlatformVersion::get(protocol_version).map_err(|e| e.to_string())?
+        }
+        None => PlatformVersion::latest(),
+    };
+
+    let file = File::create(path).map_err(|e| format!("cannot create {:?}: {}", path, e))?;
+    let summary = drive
+        .export_state_archive(BufWriter::new(file), None, platform_version)
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        data_contracts = summary.data_contracts,
+        identities = summary.identities,
+        documents = summary.documents,
+        root_hash = hex::encode(summary.root_hash),
+        "state exported to {:?}",
+        path
+    );
+
+    Ok(())
+}
+
+/// Import an archive into a fresh state of the configured database.
+fn import_state(config: PlatformConfig, path: &Path) -> Result<(), String> {
+    let file = File::open(path).map_err(|e| format!("cannot open {:?}: {}", path, e))?;
+    let archive = StateArchiveReader::new(BufReader::new(file)).map_err(|e| e.to_string())?;
+
+    let platform_version =
+        PlatformVersion::get(archive.header().protocol_version).map_err(|e| e.to_string())?;
+
+    let system_identity_public_keys: SystemIdentityPublicKeysV0 = config.abci.keys.clone().into();
+
+    let platform: Platform<DefaultCoreRPC> =
+        Platform::open(config.db_path.clone(), Some(config)).map_err(|e| e.to_string())?;
+
+    let summary = platform
+        .import_state_archive(
+            archive,
+            system_identity_public_keys.into(),
+            platform_version,
+        )
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        data_contracts = summary.data_contracts,
+        identities = summary.identities,
+        documents = summary.documents,
+        skipped = summary.skipped,
+        root_hash = hex::encode(summary.root_hash),
+        "state imported from {:?}",
+        path
+    );
+
+    Ok(())
+}
+
 fn load_config(path: &Option<PathBuf>) -> PlatformConfig {
     if let Some(path) = path {
         if let Err(e) = dotenvy::from_path(path) {
@@ -4,7 +4,15 @@
 
 use std::{sync::Once, time::Instant};
 
-use metrics::{absolute_counter, describe_counter, describe_histogram, histogram, Label};
+use crate::event_bus::PlatformEventKind;
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use dpp::state_transition::StateTransitionType;
+
+use metrics::{
+    absolute_counter, counter, describe_counter, describe_gauge, describe_histogram, gauge,
+    histogram, increment_counter, Label,
+};
 use metrics_exporter_prometheus::PrometheusBuilder;
 
 /// Default Prometheus port (29090)
@@ -14,7 +22,51 @@ const COUNTER_LAST_BLOCK_TIME: &str = "abci_last_block_time_seconds";
 const COUNTER_LAST_HEIGHT: &str = "abci_last_finalized_height";
 const HISTOGRAM_FINALIZED_ROUND: &str = "abci_finalized_round";
 const HISTOGRAM_ABCI_REQUEST_DURATION: &str = "abci_request_duration_seconds";
+const COUNTER_CORE_RPC_CACHE_REQUESTS: &str = "core_rpc_cache_requests_total";
+const GAUGE_WITHDRAWAL_LIMIT: &str = "withdrawal_limit_credits";
+const GAUGE_WITHDRAWAL_QUEUE_DEPTH: &str = "withdrawal_queue_depth";
+const HISTOGRAM_STATE_TRANSITION_STAGE_DURATION: &str =
+    "abci_state_transition_stage_duration_seconds";
+const COUNTER_STATE_TRANSITION_STAGE_RESULTS: &str = "abci_state_transition_stage_results_total";
+const COUNTER_STORAGE_FEES: &str = "abci_storage_fees_credits_total";
+const COUNTER_PROCESSING_FEES: &str = "abci_processing_fees_credits_total";
+const COUNTER_FEE_REFUNDS: &str = "abci_fee_refunds_credits_total";
+const COUNTER_CHECK_TX_REJECTIONS: &str = "abci_check_tx_rejections_total";
+const COUNTER_PROPOSED_BLOCKS: &str = "abci_proposed_blocks_total";
+const COUNTER_DROPPED_PLATFORM_EVENTS: &str = "platform_events_dropped_total";
 const LABEL_ENDPOINT: &str = "endpoint";
+const LABEL_METHOD: &str = "method";
+const LABEL_RESULT: &str = "result";
+const LABEL_STATE_TRANSITION: &str = "state_transition";
+const LABEL_STAGE: &str = "stage";
+const LABEL_CODE: &str = "code";
+const LABEL_PROPOSER: &str = "proposer";
+const LABEL_EVENT: &str = "event";
+
+/// Stage of state transition execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateTransitionStage {
+    /// Validation of the structure
+    Structure,
+    /// Validation of identity and signatures
+    Signature,
+    /// Validation against the state
+    State,
+    /// Application of the resulting operations to the state
+    Apply,
+}
+
+impl StateTransitionStage {
+    /// Label value of the stage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StateTransitionStage::Structure => "structure",
+            StateTransitionStage::Signature => "signature",
+            StateTransitionStage::State => "state",
+            StateTransitionStage::Apply => "apply",
+        }
+    }
+}
 
 /// Error returned by metrics subsystem
 #[derive(thiserror::Error, Debug)]
@@ -166,7 +218,85 @@ impl Prometheus {
             describe_histogram!(
                 HISTOGRAM_ABCI_REQUEST_DURATION,
                 "Duration of ABCI request execution inside Drive per endpoint, in seconds"
-            )
+            );
+
+            describe_counter!(
+                COUNTER_CORE_RPC_CACHE_REQUESTS,
+                "Core RPC requests per method that were served from the cache (hit) or sent to Core (miss)"
+            );
+
+            describe_gauge!(
+                GAUGE_WITHDRAWAL_LIMIT,
+                "Credits that could be pooled into withdrawal transactions in the last block"
+            );
+
+            describe_gauge!(
+                GAUGE_WITHDRAWAL_QUEUE_DEPTH,
+                "Withdrawals left queued after the last block because of pooling or withdrawal limits"
+            );
+
+            describe_histogram!(
+                HISTOGRAM_STATE_TRANSITION_STAGE_DURATION,
+                metrics::Unit::Seconds,
+                "Duration of state transition execution stages per state transition type, in seconds"
+            );
+
+            describe_counter!(
+                COUNTER_STATE_TRANSITION_STAGE_RESULTS,
+                "State transitions which passed (valid) or failed (invalid) an execution stage"
+            );
+
+            describe_counter!(
+                COUNTER_STORAGE_FEES,
+                "Storage fees charged for executed state transitions, in credits"
+            );
+
+            describe_counter!(
+                COUNTER_PROCESSING_FEES,
+                "Processing fees charged for executed state transitions, in credits"
+            );
+
+            describe_counter!(
+                COUNTER_FEE_REFUNDS,
+                "Storage fees refunded for removed data by executed state transitions, in credits"
+            );
+
+            describe_counter!(
+                COUNTER_CHECK_TX_REJECTIONS,
+                "State transitions rejected from mempool per consensus error code"
+            );
+
+            describe_counter!(
+                COUNTER_PROPOSED_BLOCKS,
+                "Blocks proposed per proposer"
+            );
+
+            describe_counter!(
+                drive::metrics::COUNTER_GROVEDB_SEEKS,
+                "GroveDB seeks performed by applied Drive operations"
+            );
+
+            describe_counter!(
+                drive::metrics::COUNTER_GROVEDB_LOADED_BYTES,
+                metrics::Unit::Bytes,
+                "Bytes loaded from GroveDB storage by applied Drive operations"
+            );
+
+            describe_counter!(
+                drive::metrics::COUNTER_GROVEDB_ADDED_BYTES,
+                metrics::Unit::Bytes,
+                "Bytes added to GroveDB storage by applied Drive operations"
+            );
+
+            describe_counter!(
+                drive::metrics::COUNTER_DATA_CONTRACT_CACHE_REQUESTS,
+                "Data contract lookups served from the cache (hit) or from the state (miss)"
+            );
+
+            describe_counter!(
+                COUNTER_DROPPED_PLATFORM_EVENTS,
+                "Platform events dropped because a subscriber's channel was full"
+            );
         });
     }
 }
@@ -219,3 +349,129 @@ pub fn abci_request_duration(endpoint: &str) -> HistogramTiming {
         metrics::Key::from_name(HISTOGRAM_ABCI_REQUEST_DURATION).with_extra_labels(labels),
     )
 }
+
+/// Count a Core RPC request handled by the response cache.
+///
+/// # Examples
+///
+/// ```
+/// use drive_abci::metrics::core_rpc_cache_request;
+///
+/// core_rpc_cache_request("get_quorum_info", true);
+/// ```
+pub fn core_rpc_cache_request(method: &str, hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    let labels = vec![
+        Label::new(LABEL_METHOD, method.to_string()),
+        Label::new(LABEL_RESULT, result),
+    ];
+    increment_counter!(COUNTER_CORE_RPC_CACHE_REQUESTS, labels);
+}
+
+/// Set credits that could be pooled into withdrawal transactions in the last block
+/// into [GAUGE_WITHDRAWAL_LIMIT].
+pub fn withdrawal_limit(credits: u64) {
+    gauge!(GAUGE_WITHDRAWAL_LIMIT, credits as f64);
+}
+
+/// Set number of withdrawals left queued after the last block into [GAUGE_WITHDRAWAL_QUEUE_DEPTH].
+pub fn withdrawal_queue_depth(depth: usize) {
+    gauge!(GAUGE_WITHDRAWAL_QUEUE_DEPTH, depth as f64);
+}
+
+/// Returns a `[HistogramTiming]` instance for measuring duration of a state transition execution stage.
+///
+/// # Examples
+///
+/// ```
+/// use dpp::state_transition::StateTransitionType;
+/// use drive_abci::metrics::{state_transition_stage_duration, StateTransitionStage};
+///
+/// let timing = state_transition_stage_duration(
+///     StateTransitionType::IdentityCreate,
+///     StateTransitionStage::Structure,
+/// );
+/// // Your code here
+/// drop(timing); // stop measurement and report the metric
+/// ```
+pub fn state_transition_stage_duration(
+    state_transition_type: StateTransitionType,
+    stage: StateTransitionStage,
+) -> HistogramTiming {
+    let labels = vec![
+        Label::new(LABEL_STATE_TRANSITION, state_transition_type.to_string()),
+        Label::new(LABEL_STAGE, stage.as_str()),
+    ];
+    HistogramTiming::new(
+        metrics::Key::from_name(HISTOGRAM_STATE_TRANSITION_STAGE_DURATION)
+            .with_extra_labels(labels),
+    )
+}
+
+/// Count a state transition which passed or failed an execution stage.
+pub fn state_transition_stage_result(
+    state_transition_type: StateTransitionType,
+    stage: StateTransitionStage,
+    valid: bool,
+) {
+    let result = if valid { "valid" } else { "invalid" };
+    let labels = vec![
+        Label::new(LABEL_STATE_TRANSITION, state_transition_type.to_string()),
+        Label::new(LABEL_STAGE, stage.as_str()),
+        Label::new(LABEL_RESULT, result),
+    ];
+    increment_counter!(COUNTER_STATE_TRANSITION_STAGE_RESULTS, labels);
+}
+
+/// Add fees charged and refunded for an executed state transition to the fee counters.
+pub fn state_transition_fees(state_transition_type: StateTransitionType, fee_result: &FeeResult) {
+    let labels = vec![Label::new(
+        LABEL_STATE_TRANSITION,
+        state_transition_type.to_string(),
+    )];
+
+    let refunds: Credits = fee_result
+        .fee_refunds
+        .iter()
+        .flat_map(|(_, credits_per_epoch)| credits_per_epoch.iter().map(|(_, credits)| *credits))
+        .sum();
+
+    counter!(COUNTER_STORAGE_FEES, fee_result.storage_fee, labels.clone());
+    counter!(
+        COUNTER_PROCESSING_FEES,
+        fee_result.processing_fee,
+        labels.clone()
+    );
+    counter!(COUNTER_FEE_REFUNDS, refunds, labels);
+}
+
+/// Count a state transition rejected from mempool by check tx with a consensus error code.
+///
+/// # Examples
+///
+/// ```
+/// use drive_abci::metrics::check_tx_rejection;
+///
+/// check_tx_rejection(10001);
+/// ```
+pub fn check_tx_rejection(code: u32) {
+    let labels = vec![Label::new(LABEL_CODE, code.to_string())];
+    increment_counter!(COUNTER_CHECK_TX_REJECTIONS, labels);
+}
+
+/// Count a block proposed by a proposer.
+///
+/// Blocks proposed per epoch are stored in epoch pools and can be queried from the state.
+pub fn proposed_block(proposer_pro_tx_hash: &[u8; 32]) {
+    let labels = vec![Label::new(
+        LABEL_PROPOSER,
+        hex::encode(proposer_pro_tx_hash),
+    )];
+    increment_counter!(COUNTER_PROPOSED_BLOCKS, labels);
+}
+
+/// Count a platform event dropped because a subscriber's channel was full.
+pub fn platform_event_dropped(kind: PlatformEventKind) {
+    let labels = vec![Label::new(LABEL_EVENT, kind.as_str())];
+    increment_counter!(COUNTER_DROPPED_PLATFORM_EVENTS, labels);
+}
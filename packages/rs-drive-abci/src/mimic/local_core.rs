@@ -0,0 +1,945 @@
+use crate::mimic::test_quorum::TestQuorumInfo;
+use crate::rpc::core::{CoreHeight, CoreRPCLike};
+use dashcore_rpc::dashcore::ephemerealdata::chain_lock::ChainLock;
+use dashcore_rpc::dashcore::hash_types::CycleHash;
+use dashcore_rpc::dashcore::hashes::{sha256d, Hash};
+use dashcore_rpc::dashcore::{
+    Block, BlockHash, OutPoint, ProTxHash, QuorumHash, ScriptBuf, Transaction, TxIn, TxOut, Txid,
+};
+use dashcore_rpc::dashcore_rpc_json::{
+    Bip9SoftforkInfo, Bip9SoftforkStatus, DMNState, DMNStateDiff, ExtendedQuorumDetails,
+    ExtendedQuorumListResult, GetChainTipsResult, MasternodeListDiff, MasternodeListItem,
+    MasternodeType, MnSyncStatus, QuorumInfoResult, QuorumType, SoftforkInfo, SoftforkType,
+};
+use dashcore_rpc::json::GetTransactionResult;
+use dashcore_rpc::jsonrpc::error::RpcError;
+use dashcore_rpc::Error;
+use dpp::bls_signatures;
+use dpp::bls_signatures::PrivateKey as BlsPrivateKey;
+use dpp::dashcore::consensus::{deserialize, serialize};
+use dpp::dashcore::{InstantLock, PublicKey};
+use rand::rngs::StdRng;
+use rand::seq::IteratorRandom;
+use rand::{Rng, SeedableRng};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tenderdash_abci::proto::types::CoreChainLock;
+use tokio_util::sync::CancellationToken;
+
+/// Core answers with this code when a block or transaction is unknown
+const RPC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+/// Core answers with this code when a parameter is out of range
+const RPC_INVALID_PARAMETER: i32 = -8;
+/// Core answers with this code when a method doesn't exist
+const RPC_METHOD_NOT_FOUND: i32 = -32601;
+/// Request ID prefix of chain lock signing sessions
+const CHAIN_LOCK_REQUEST_ID_PREFIX: &[u8] = b"clsig";
+/// Block time of Dash mainnet
+const BLOCK_SPACING_SECONDS: u64 = 150;
+
+/// Parameters of a simulated Core chain
+#[derive(Clone, Debug)]
+pub struct LocalCoreConfig {
+    /// Seed of block hashes, masternodes and quorum keys, the same seed gives the same chain
+    pub seed: u64,
+    /// Chain locked tip height when the simulation starts
+    pub genesis_height: CoreHeight,
+    /// Time of the first block, in seconds since epoch
+    pub genesis_time: u64,
+    /// Number of regular masternodes registered at height 0
+    pub masternode_count: u16,
+    /// Number of evonodes registered at height 0, quorum members are chosen from them
+    pub evonode_count: u16,
+    /// Type of all simulated quorums
+    pub quorum_type: QuorumType,
+    /// Number of members of each quorum
+    pub quorum_size: usize,
+    /// Number of most recent quorums which are active at any height
+    pub active_quorum_count: usize,
+    /// A new quorum is formed every `dkg_interval` blocks
+    pub dkg_interval: u32,
+}
+
+impl Default for LocalCoreConfig {
+    fn default() -> Self {
+        Self {
+            seed: 0,
+            genesis_height: 100,
+            genesis_time: 1_681_094_400,
+            masternode_count: 10,
+            evonode_count: 20,
+            quorum_type: QuorumType::Llmq100_67,
+            quorum_size: 10,
+            active_quorum_count: 4,
+            dkg_interval: 24,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct LocalCoreState {
+    config: LocalCoreConfig,
+    rng: StdRng,
+    block_hashes: Vec<BlockHash>,
+    chain_locked_height: CoreHeight,
+    /// Masternode list is stored at each height it changed
+    masternode_lists: BTreeMap<CoreHeight, BTreeMap<ProTxHash, MasternodeListItem>>,
+    /// Quorums are created lazily and keyed by the height of their DKG cycle
+    quorums: BTreeMap<CoreHeight, TestQuorumInfo>,
+    /// Transactions with the height they were mined at
+    transactions: HashMap<Txid, (Transaction, Option<CoreHeight>)>,
+    mempool: Vec<Txid>,
+    instant_locked: BTreeSet<Txid>,
+}
+
+/// In-process simulation of Dash Core.
+///
+/// Simulates a chain with chain locks, a deterministic masternode list, rotating quorums
+/// with real BLS threshold keys, instant locks and asset lock transactions, so Drive can run
+/// without dashd. Clones share the same chain.
+#[derive(Clone, Debug)]
+pub struct LocalCore {
+    state: Arc<Mutex<LocalCoreState>>,
+}
+
+fn rpc_error(code: i32, message: impl Into<String>) -> Error {
+    Error::JsonRpc(dashcore_rpc::jsonrpc::error::Error::Rpc(RpcError {
+        code,
+        message: message.into(),
+        data: None,
+    }))
+}
+
+fn block_hash_at(seed: u64, height: CoreHeight) -> BlockHash {
+    let mut data = seed.to_le_bytes().to_vec();
+    data.extend_from_slice(&height.to_le_bytes());
+    BlockHash::from_byte_array(sha256d::Hash::hash(&data).to_byte_array())
+}
+
+/// Hash signed by a quorum in an LLMQ signing session
+fn sign_hash(
+    quorum_type: QuorumType,
+    quorum_hash: &QuorumHash,
+    request_id: &[u8],
+    message_hash: &[u8],
+) -> [u8; 32] {
+    let mut data = vec![quorum_type as u8];
+    data.extend_from_slice(&quorum_hash.to_byte_array());
+    data.extend_from_slice(request_id);
+    data.extend_from_slice(message_hash);
+    sha256d::Hash::hash(&data).to_byte_array()
+}
+
+fn chain_lock_request_id(height: CoreHeight) -> [u8; 32] {
+    let mut data = vec![CHAIN_LOCK_REQUEST_ID_PREFIX.len() as u8];
+    data.extend_from_slice(CHAIN_LOCK_REQUEST_ID_PREFIX);
+    data.extend_from_slice(&height.to_le_bytes());
+    sha256d::Hash::hash(&data).to_byte_array()
+}
+
+fn verify_signature(quorum: &TestQuorumInfo, signature: &[u8], hash: &[u8; 32]) -> bool {
+    bls_signatures::Signature::from_bytes(signature)
+        .map(|signature| quorum.public_key.verify(&signature, hash))
+        .unwrap_or(false)
+}
+
+fn generate_masternode(
+    node_type: MasternodeType,
+    index: usize,
+    registered_height: CoreHeight,
+    rng: &mut StdRng,
+) -> MasternodeListItem {
+    let pub_key_operator = BlsPrivateKey::generate_dash(rng)
+        .expect("expected to generate a private key")
+        .g1_element()
+        .expect("expected to get public key")
+        .to_bytes()
+        .to_vec();
+
+    let is_evonode = node_type == MasternodeType::Evo;
+
+    MasternodeListItem {
+        node_type,
+        pro_tx_hash: ProTxHash::from_byte_array(rng.gen()),
+        collateral_hash: Txid::from_byte_array(rng.gen()),
+        collateral_index: 0,
+        collateral_address: rng.gen(),
+        operator_reward: 0.0,
+        state: DMNState {
+            service: SocketAddr::new(
+                IpAddr::V4(Ipv4Addr::new(
+                    10,
+                    is_evonode as u8,
+                    (index / 256) as u8,
+                    (index % 256) as u8,
+                )),
+                19999,
+            ),
+            registered_height,
+            pose_revived_height: None,
+            pose_ban_height: None,
+            revocation_reason: 0,
+            owner_address: rng.gen(),
+            voting_address: rng.gen(),
+            payout_address: rng.gen(),
+            pub_key_operator,
+            operator_payout_address: None,
+            platform_node_id: is_evonode.then(|| rng.gen()),
+            platform_p2p_port: is_evonode.then_some(26656),
+            platform_http_port: is_evonode.then_some(443),
+        },
+    }
+}
+
+impl LocalCoreState {
+    fn tip_height(&self) -> CoreHeight {
+        self.block_hashes.len() as CoreHeight - 1
+    }
+
+    fn block_hash(&self, height: CoreHeight) -> Result<BlockHash, Error> {
+        self.block_hashes
+            .get(height as usize)
+            .copied()
+            .ok_or_else(|| rpc_error(RPC_INVALID_PARAMETER, "Block height out of range"))
+    }
+
+    fn block_height(&self, block_hash: &BlockHash) -> Result<CoreHeight, Error> {
+        self.block_hashes
+            .iter()
+            .position(|hash| hash == block_hash)
+            .map(|height| height as CoreHeight)
+            .ok_or_else(|| rpc_error(RPC_INVALID_ADDRESS_OR_KEY, "Block not found"))
+    }
+
+    fn check_height(&self, height: CoreHeight) -> Result<(), Error> {
+        self.block_hash(height).map(|_| ())
+    }
+
+    fn masternode_list_at(&self, height: CoreHeight) -> BTreeMap<ProTxHash, MasternodeListItem> {
+        self.masternode_lists
+            .range(..=height)
+            .next_back()
+            .map(|(_, list)| list.clone())
+            .unwrap_or_default()
+    }
+
+    /// Changes to the masternode list take effect in the next block
+    fn update_masternode_list(
+        &mut self,
+        update: impl FnOnce(&mut BTreeMap<ProTxHash, MasternodeListItem>, CoreHeight),
+    ) {
+        let height = self.tip_height() + 1;
+        let mut list = self.masternode_list_at(height);
+        update(&mut list, height);
+        self.masternode_lists.insert(height, list);
+    }
+
+    fn quorum_at_cycle(&mut self, cycle_height: CoreHeight) -> TestQuorumInfo {
+        if let Some(quorum) = self.quorums.get(&cycle_height) {
+            return quorum.clone();
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.config.seed ^ cycle_height as u64);
+        let members = self
+            .masternode_list_at(cycle_height)
+            .into_values()
+            .filter(|masternode| {
+                masternode.node_type == MasternodeType::Evo
+                    && masternode.state.pose_ban_height.is_none()
+            })
+            .map(|masternode| masternode.pro_tx_hash)
+            .choose_multiple(&mut rng, self.config.quorum_size);
+
+        // The quorum hash is the hash of the block starting the DKG cycle
+        let quorum_hash = QuorumHash::from_byte_array(
+            block_hash_at(self.config.seed, cycle_height).to_byte_array(),
+        );
+        let quorum = TestQuorumInfo::from_quorum_hash_and_pro_tx_hashes(
+            cycle_height,
+            quorum_hash,
+            members,
+            &mut rng,
+        );
+
+        self.quorums.insert(cycle_height, quorum.clone());
+
+        quorum
+    }
+
+    /// Most recent quorums at the given height, starting with the newest one
+    fn active_quorums(&mut self, height: CoreHeight) -> Vec<TestQuorumInfo> {
+        let dkg_interval = self.config.dkg_interval.max(1);
+        let last_cycle = height / dkg_interval;
+
+        (0..=last_cycle)
+            .rev()
+            .take(self.config.active_quorum_count)
+            .map(|cycle| self.quorum_at_cycle(cycle * dkg_interval))
+            .collect()
+    }
+
+    fn quorum(&mut self, quorum_hash: &QuorumHash) -> Option<TestQuorumInfo> {
+        let dkg_interval = self.config.dkg_interval.max(1);
+
+        (0..=self.tip_height() / dkg_interval)
+            .map(|cycle| cycle * dkg_interval)
+            .filter(|cycle_height| {
+                block_hash_at(self.config.seed, *cycle_height).to_byte_array()
+                    == quorum_hash.to_byte_array()
+            })
+            .map(|cycle_height| self.quorum_at_cycle(cycle_height))
+            .next()
+    }
+
+    fn signing_quorum(&mut self, height: CoreHeight) -> TestQuorumInfo {
+        self.active_quorums(height)
+            .into_iter()
+            .next()
+            .expect("expected at least one active quorum")
+    }
+
+    fn sign_chain_lock(&mut self, height: CoreHeight) -> Result<CoreChainLock, Error> {
+        let block_hash = self.block_hash(height)?;
+        let quorum = self.signing_quorum(height);
+        let hash = sign_hash(
+            self.config.quorum_type,
+            &quorum.quorum_hash,
+            &chain_lock_request_id(height),
+            &block_hash.to_byte_array(),
+        );
+
+        Ok(CoreChainLock {
+            core_block_height: height,
+            core_block_hash: block_hash.to_byte_array().to_vec(),
+            signature: quorum.private_key.sign(&hash).to_bytes().to_vec(),
+        })
+    }
+
+    fn mine_blocks(&mut self, count: u32, chain_lock: bool) {
+        for _ in 0..count {
+            let height = self.tip_height() + 1;
+            self.block_hashes
+                .push(block_hash_at(self.config.seed, height));
+
+            for txid in self.mempool.drain(..) {
+                if let Some((_, mined_at)) = self.transactions.get_mut(&txid) {
+                    *mined_at = Some(height);
+                }
+            }
+        }
+
+        if chain_lock {
+            self.chain_locked_height = self.tip_height();
+        }
+    }
+}
+
+impl LocalCore {
+    /// Start a simulated chain, chain locked at the genesis height
+    pub fn new(config: LocalCoreConfig) -> Self {
+        let mut rng = StdRng::seed_from_u64(config.seed);
+
+        let mut masternode_list = BTreeMap::new();
+        for (node_type, count) in [
+            (MasternodeType::Regular, config.masternode_count),
+            (MasternodeType::Evo, config.evonode_count),
+        ] {
+            for index in 0..count as usize {
+                let masternode = generate_masternode(node_type, index, 0, &mut rng);
+                masternode_list.insert(masternode.pro_tx_hash, masternode);
+            }
+        }
+
+        let block_hashes = (0..=config.genesis_height)
+            .map(|height| block_hash_at(config.seed, height))
+            .collect();
+
+        let state = LocalCoreState {
+            chain_locked_height: config.genesis_height,
+            config,
+            rng,
+            block_hashes,
+            masternode_lists: BTreeMap::from([(0, masternode_list)]),
+            quorums: BTreeMap::new(),
+            transactions: HashMap::new(),
+            mempool: vec![],
+            instant_locked: BTreeSet::new(),
+        };
+
+        Self {
+            state: Arc::new(Mutex::new(state)),
+        }
+    }
+
+    /// Height of the last block
+    pub fn tip_height(&self) -> CoreHeight {
+        self.state.lock().unwrap().tip_height()
+    }
+
+    /// Height of the last chain locked block
+    pub fn chain_locked_height(&self) -> CoreHeight {
+        self.state.lock().unwrap().chain_locked_height
+    }
+
+    /// Mine blocks including transactions from the mempool, and chain lock the new tip
+    pub fn mine_blocks(&self, count: u32) {
+        self.state.lock().unwrap().mine_blocks(count, true);
+    }
+
+    /// Mine blocks including transactions from the mempool, without chain locking them
+    pub fn mine_blocks_without_chain_lock(&self, count: u32) {
+        self.state.lock().unwrap().mine_blocks(count, false);
+    }
+
+    /// Mine and chain lock a block every `block_spacing` in a separate thread until `cancel`
+    /// is cancelled
+    pub fn start_mining(
+        &self,
+        block_spacing: Duration,
+        cancel: CancellationToken,
+    ) -> JoinHandle<()> {
+        let core = self.clone();
+
+        std::thread::spawn(move || {
+            while !cancel.is_cancelled() {
+                std::thread::sleep(block_spacing);
+                core.mine_blocks(1);
+
+                tracing::trace!(height = core.tip_height(), "local core mined a block");
+            }
+        })
+    }
+
+    /// Chain lock all blocks up to the tip
+    pub fn chain_lock_tip(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.chain_locked_height = state.tip_height();
+    }
+
+    /// Register new masternodes in the next block
+    pub fn register_masternodes(&self, count: u16, node_type: MasternodeType) -> Vec<ProTxHash> {
+        let mut state = self.state.lock().unwrap();
+        let mut rng = state.rng.clone();
+        let mut registered = Vec::with_capacity(count as usize);
+
+        state.update_masternode_list(|list, height| {
+            for _ in 0..count {
+                let masternode = generate_masternode(node_type, list.len(), height, &mut rng);
+                registered.push(masternode.pro_tx_hash);
+                list.insert(masternode.pro_tx_hash, masternode);
+            }
+        });
+        state.rng = rng;
+
+        registered
+    }
+
+    /// Proof of service ban a masternode in the next block
+    pub fn pose_ban_masternode(&self, pro_tx_hash: &ProTxHash) {
+        self.state
+            .lock()
+            .unwrap()
+            .update_masternode_list(|list, height| {
+                if let Some(masternode) = list.get_mut(pro_tx_hash) {
+                    masternode.state.pose_ban_height = Some(height);
+                }
+            });
+    }
+
+    /// Revive a proof of service banned masternode in the next block
+    pub fn revive_masternode(&self, pro_tx_hash: &ProTxHash) {
+        self.state
+            .lock()
+            .unwrap()
+            .update_masternode_list(|list, height| {
+                if let Some(masternode) = list.get_mut(pro_tx_hash) {
+                    masternode.state.pose_ban_height = None;
+                    masternode.state.pose_revived_height = Some(height);
+                }
+            });
+    }
+
+    /// Remove a masternode from the list in the next block
+    pub fn remove_masternode(&self, pro_tx_hash: &ProTxHash) {
+        self.state
+            .lock()
+            .unwrap()
+            .update_masternode_list(|list, _| {
+                list.remove(pro_tx_hash);
+            });
+    }
+
+    /// Masternode list at the given height
+    pub fn masternode_list(&self, height: CoreHeight) -> Vec<MasternodeListItem> {
+        self.state
+            .lock()
+            .unwrap()
+            .masternode_list_at(height)
+            .into_values()
+            .collect()
+    }
+
+    /// Quorums active at the given height, starting with the newest one.
+    /// Private keys can be used to sign blocks and vote extensions.
+    pub fn active_quorums(&self, height: CoreHeight) -> Vec<TestQuorumInfo> {
+        self.state.lock().unwrap().active_quorums(height)
+    }
+
+    /// Put a transaction into the mempool, it's mined with the next block
+    pub fn send_transaction(&self, transaction: Transaction) -> Txid {
+        let txid = transaction.txid();
+        let mut state = self.state.lock().unwrap();
+        state.transactions.insert(txid, (transaction, None));
+        state.mempool.push(txid);
+
+        txid
+    }
+
+    /// Create an asset lock transaction burning `amount` duffs to the one time key
+    /// and put it into the mempool
+    pub fn create_asset_lock_transaction(
+        &self,
+        one_time_public_key: &PublicKey,
+        amount: u64,
+    ) -> Transaction {
+        let previous_output = {
+            let mut state = self.state.lock().unwrap();
+            OutPoint::new(Txid::from_byte_array(state.rng.gen()), 0)
+        };
+
+        let transaction = Transaction {
+            version: 3,
+            lock_time: 0,
+            input: vec![TxIn {
+                previous_output,
+                script_sig: ScriptBuf::new(),
+                sequence: u32::MAX,
+                witness: Default::default(),
+            }],
+            output: vec![TxOut {
+                value: amount,
+                script_pubkey: ScriptBuf::new_op_return(&one_time_public_key.pubkey_hash()),
+            }],
+            special_transaction_payload: None,
+        };
+
+        self.send_transaction(transaction.clone());
+
+        transaction
+    }
+
+    /// Instant lock a known transaction with the newest quorum
+    pub fn instant_lock(&self, txid: &Txid) -> Result<InstantLock, Error> {
+        let mut state = self.state.lock().unwrap();
+        let (transaction, _) = state
+            .transactions
+            .get(txid)
+            .cloned()
+            .ok_or_else(|| rpc_error(RPC_INVALID_ADDRESS_OR_KEY, "No such transaction"))?;
+
+        let quorum = state.signing_quorum(state.tip_height());
+
+        let mut instant_lock = InstantLock {
+            version: 1,
+            inputs: transaction
+                .input
+                .iter()
+                .map(|input| input.previous_output)
+                .collect(),
+            txid: *txid,
+            cyclehash: CycleHash::from_byte_array(quorum.quorum_hash.to_byte_array()),
+            signature: deserialize(&[0; 96]).expect("expected to decode signature"),
+        };
+
+        let request_id = instant_lock.request_id()?;
+        let request_id: &[u8] = request_id.as_ref();
+        let hash = sign_hash(
+            state.config.quorum_type,
+            &quorum.quorum_hash,
+            request_id,
+            &txid.to_byte_array(),
+        );
+        instant_lock.signature = deserialize(&quorum.private_key.sign(&hash).to_bytes())
+            .expect("expected to decode signature");
+
+        state.instant_locked.insert(*txid);
+
+        Ok(instant_lock)
+    }
+}
+
+impl CoreRPCLike for LocalCore {
+    fn get_block_hash(&self, height: CoreHeight) -> Result<BlockHash, Error> {
+        self.state.lock().unwrap().block_hash(height)
+    }
+
+    fn get_best_chain_lock(&self) -> Result<CoreChainLock, Error> {
+        let mut state = self.state.lock().unwrap();
+        let height = state.chain_locked_height;
+
+        state.sign_chain_lock(height)
+    }
+
+    fn get_transaction(&self, tx_id: &Txid) -> Result<Transaction, Error> {
+        self.state
+            .lock()
+            .unwrap()
+            .transactions
+            .get(tx_id)
+            .map(|(transaction, _)| transaction.clone())
+            .ok_or_else(|| rpc_error(RPC_INVALID_ADDRESS_OR_KEY, "No such transaction"))
+    }
+
+    fn get_transaction_extended_info(&self, tx_id: &Txid) -> Result<GetTransactionResult, Error> {
+        let state = self.state.lock().unwrap();
+        let (transaction, mined_at) = state
+            .transactions
+            .get(tx_id)
+            .ok_or_else(|| rpc_error(RPC_INVALID_ADDRESS_OR_KEY, "No such transaction"))?;
+
+        let time = state.config.genesis_time;
+        let mut result = json!({
+            "amount": 0.0,
+            "confirmations": 0,
+            "instantlock": state.instant_locked.contains(tx_id),
+            "instantlock_internal": state.instant_locked.contains(tx_id),
+            "chainlock": false,
+            "txid": tx_id.to_string(),
+            "walletconflicts": [],
+            "time": time,
+            "timereceived": time,
+            "bip125-replaceable": "no",
+            "details": [],
+            "hex": hex::encode(serialize(transaction)),
+        });
+
+        if let Some(height) = mined_at {
+            let block_time = time + *height as u64 * BLOCK_SPACING_SECONDS;
+            result["confirmations"] = json!(state.tip_height() - height + 1);
+            result["chainlock"] = json!(*height <= state.chain_locked_height);
+            result["blockhash"] = json!(state.block_hash(*height)?.to_string());
+            result["blockheight"] = json!(height);
+            result["blockindex"] = json!(0);
+            result["blocktime"] = json!(block_time);
+        }
+
+        serde_json::from_value(result).map_err(Error::Json)
+    }
+
+    fn get_fork_info(&self, _name: &str) -> Result<Option<SoftforkInfo>, Error> {
+        // All forks are active from the start
+        Ok(Some(SoftforkInfo {
+            softfork_type: SoftforkType::Bip9,
+            active: true,
+            height: Some(0),
+            bip9: Some(Bip9SoftforkInfo {
+                status: Bip9SoftforkStatus::Active,
+                bit: None,
+                start_time: 0,
+                timeout: 0,
+                since: 0,
+                statistics: None,
+            }),
+        }))
+    }
+
+    fn get_block(&self, _block_hash: &BlockHash) -> Result<Block, Error> {
+        Err(rpc_error(
+            RPC_METHOD_NOT_FOUND,
+            "getblock is not supported by local core",
+        ))
+    }
+
+    fn get_block_json(&self, block_hash: &BlockHash) -> Result<Value, Error> {
+        let state = self.state.lock().unwrap();
+        let height = state.block_height(block_hash)?;
+        let transactions: Vec<String> = state
+            .transactions
+            .iter()
+            .filter(|(_, (_, mined_at))| *mined_at == Some(height))
+            .map(|(txid, _)| txid.to_string())
+            .collect();
+
+        Ok(json!({
+            "hash": block_hash.to_string(),
+            "height": height,
+            "confirmations": state.tip_height() - height + 1,
+            "time": state.config.genesis_time + height as u64 * BLOCK_SPACING_SECONDS,
+            "chainlock": height <= state.chain_locked_height,
+            "tx": transactions,
+        }))
+    }
+
+    fn get_chain_tips(&self) -> Result<GetChainTipsResult, Error> {
+        let state = self.state.lock().unwrap();
+        let height = state.tip_height();
+
+        serde_json::from_value(json!([{
+            "height": height,
+            "hash": state.block_hash(height)?.to_string(),
+            "branchlen": 0,
+            "status": "active",
+        }]))
+        .map_err(Error::Json)
+    }
+
+    fn get_quorum_listextended(
+        &self,
+        height: Option<CoreHeight>,
+    ) -> Result<ExtendedQuorumListResult, Error> {
+        let mut state = self.state.lock().unwrap();
+        let height = height.unwrap_or(state.tip_height());
+        state.check_height(height)?;
+
+        let quorums = state
+            .active_quorums(height)
+            .into_iter()
+            .map(|quorum| {
+                let details = ExtendedQuorumDetails {
+                    creation_height: quorum.core_height,
+                    quorum_index: None,
+                    mined_block_hash: block_hash_at(state.config.seed, quorum.core_height),
+                    num_valid_members: quorum.validator_set.len() as u32,
+                    health_ratio: 1.0,
+                };
+
+                (quorum.quorum_hash, details)
+            })
+            .collect();
+
+        Ok(ExtendedQuorumListResult {
+            quorums_by_type: HashMap::from([(state.config.quorum_type, quorums)]),
+        })
+    }
+
+    fn get_quorum_info(
+        &self,
+        quorum_type: QuorumType,
+        hash: &QuorumHash,
+        _include_secret_key_share: Option<bool>,
+    ) -> Result<QuorumInfoResult, Error> {
+        let mut state = self.state.lock().unwrap();
+        let quorum = state
+            .quorum(hash)
+            .filter(|_| quorum_type == state.config.quorum_type)
+            .ok_or_else(|| rpc_error(RPC_INVALID_PARAMETER, "quorum not found"))?;
+
+        let mut quorum_info: QuorumInfoResult = (&quorum).into();
+        quorum_info.height = quorum.core_height;
+        quorum_info.quorum_type = quorum_type;
+
+        Ok(quorum_info)
+    }
+
+    fn get_protx_diff_with_masternodes(
+        &self,
+        base_block: Option<u32>,
+        block: u32,
+    ) -> Result<MasternodeListDiff, Error> {
+        let state = self.state.lock().unwrap();
+        state.check_height(block)?;
+
+        let base_list = match base_block {
+            Some(base_block) => {
+                state.check_height(base_block)?;
+                state.masternode_list_at(base_block)
+            }
+            None => BTreeMap::new(),
+        };
+        let list = state.masternode_list_at(block);
+
+        let added_mns = list
+            .values()
+            .filter(|masternode| !base_list.contains_key(&masternode.pro_tx_hash))
+            .cloned()
+            .collect();
+        let removed_mns = base_list
+            .keys()
+            .filter(|pro_tx_hash| !list.contains_key(pro_tx_hash))
+            .copied()
+            .collect();
+        let updated_mns: Vec<(ProTxHash, DMNStateDiff)> = base_list
+            .values()
+            .filter_map(|base_masternode| {
+                let masternode = list.get(&base_masternode.pro_tx_hash)?;
+                base_masternode
+                    .state
+                    .compare_to_newer_dmn_state(&masternode.state)
+                    .map(|diff| (masternode.pro_tx_hash, diff))
+            })
+            .collect();
+
+        Ok(MasternodeListDiff {
+            base_height: base_block.unwrap_or(0),
+            block_height: block,
+            added_mns,
+            removed_mns,
+            updated_mns,
+        })
+    }
+
+    fn verify_instant_lock(
+        &self,
+        instant_lock: &InstantLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, Error> {
+        let mut state = self.state.lock().unwrap();
+        let quorum_hash = QuorumHash::from_byte_array(instant_lock.cyclehash.to_byte_array());
+        let height = max_height.unwrap_or(state.tip_height());
+
+        let Some(quorum) = state.quorum(&quorum_hash) else {
+            return Ok(false);
+        };
+        if quorum.core_height > height {
+            return Ok(false);
+        }
+
+        let request_id = instant_lock.request_id()?;
+        let request_id: &[u8] = request_id.as_ref();
+        let hash = sign_hash(
+            state.config.quorum_type,
+            &quorum.quorum_hash,
+            request_id,
+            &instant_lock.txid.to_byte_array(),
+        );
+
+        Ok(verify_signature(
+            &quorum,
+            &serialize(&instant_lock.signature),
+            &hash,
+        ))
+    }
+
+    fn verify_chain_lock(
+        &self,
+        chain_lock: &ChainLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, Error> {
+        let mut state = self.state.lock().unwrap();
+
+        if state.block_hash(chain_lock.block_height).ok() != Some(chain_lock.block_hash) {
+            return Ok(false);
+        }
+
+        let height = max_height.unwrap_or(state.tip_height());
+        let signature = serialize(&chain_lock.signature);
+
+        Ok(state.active_quorums(height).iter().any(|quorum| {
+            let hash = sign_hash(
+                state.config.quorum_type,
+                &quorum.quorum_hash,
+                &chain_lock_request_id(chain_lock.block_height),
+                &chain_lock.block_hash.to_byte_array(),
+            );
+
+            verify_signature(quorum, &signature, &hash)
+        }))
+    }
+
+    fn masternode_sync_status(&self) -> Result<MnSyncStatus, Error> {
+        serde_json::from_value(json!({
+            "AssetID": 999,
+            "AssetName": "MASTERNODE_SYNC_FINISHED",
+            "AssetStartTime": self.state.lock().unwrap().config.genesis_time,
+            "Attempt": 0,
+            "IsBlockchainSynced": true,
+            "IsSynced": true,
+        }))
+        .map_err(Error::Json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_sign_chain_locks_verifiable_by_active_quorums() {
+        let core = LocalCore::new(LocalCoreConfig::default());
+
+        core.mine_blocks(30);
+
+        let best_chain_lock = core.get_best_chain_lock().expect("expected a chain lock");
+        assert_eq!(best_chain_lock.core_block_height, 130);
+
+        let chain_lock = ChainLock {
+            block_height: best_chain_lock.core_block_height,
+            block_hash: BlockHash::from_slice(&best_chain_lock.core_block_hash)
+                .expect("expected a block hash"),
+            signature: deserialize(&best_chain_lock.signature).expect("expected a signature"),
+        };
+
+        assert!(core
+            .verify_chain_lock(&chain_lock, None)
+            .expect("expected to verify chain lock"));
+    }
+
+    #[test]
+    fn should_rotate_quorums_and_report_masternode_list_changes() {
+        let core = LocalCore::new(LocalCoreConfig::default());
+
+        let quorums_before = core
+            .get_quorum_listextended(Some(100))
+            .expect("expected quorums");
+
+        let registered = core.register_masternodes(2, MasternodeType::Evo);
+        core.mine_blocks(24);
+
+        let quorums_after = core
+            .get_quorum_listextended(Some(124))
+            .expect("expected quorums");
+        assert_ne!(
+            quorums_before.quorums_by_type,
+            quorums_after.quorums_by_type
+        );
+
+        let diff = core
+            .get_protx_diff_with_masternodes(Some(100), 124)
+            .expect("expected a diff");
+        let added: Vec<_> = diff.added_mns.iter().map(|mn| mn.pro_tx_hash).collect();
+        assert_eq!(added.len(), 2);
+        assert!(registered
+            .iter()
+            .all(|pro_tx_hash| added.contains(pro_tx_hash)));
+    }
+
+    #[test]
+    fn should_instant_lock_and_chain_lock_asset_lock_transactions() {
+        let core = LocalCore::new(LocalCoreConfig::default());
+        let secp = dpp::dashcore::secp256k1::Secp256k1::new();
+        let one_time_key = dpp::dashcore::PrivateKey::new(
+            dpp::dashcore::secp256k1::SecretKey::from_slice(&[1; 32])
+                .expect("expected a secret key"),
+            dpp::dashcore::Network::Testnet,
+        );
+
+        let transaction =
+            core.create_asset_lock_transaction(&one_time_key.public_key(&secp), 100_000_000);
+        let txid = transaction.txid();
+
+        let instant_lock = core.instant_lock(&txid).expect("expected an instant lock");
+        assert!(core
+            .verify_instant_lock(&instant_lock, None)
+            .expect("expected to verify instant lock"));
+
+        let transaction_info = core
+            .get_transaction_extended_info(&txid)
+            .expect("expected a transaction");
+        assert!(!transaction_info.chainlock);
+
+        core.mine_blocks(1);
+
+        let transaction_info = core
+            .get_transaction_extended_info(&txid)
+            .expect("expected a transaction");
+        assert!(transaction_info.chainlock);
+        assert_eq!(
+            transaction_info.transaction().expect("expected to decode"),
+            transaction
+        );
+    }
+}
@@ -30,6 +30,8 @@ use tenderdash_abci::{signatures::SignDigest, proto::version::Consensus, Applica
 use tenderdash_abci::proto::serializers::timestamp::ToMilis;
 use crate::mimic::test_quorum::TestQuorumInfo;
 
+/// In-process simulation of Dash Core
+pub mod local_core;
 /// Test quorum for mimic block execution
 pub mod test_quorum;
 
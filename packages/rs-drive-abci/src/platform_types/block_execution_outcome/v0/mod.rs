@@ -1,4 +1,5 @@
 use crate::abci::AbciError;
+use crate::event_bus::PlatformEvent;
 use dpp::validation::SimpleValidationResult;
 use tenderdash_abci::proto::abci::{ExecTxResult, ValidatorSetUpdate};
 
@@ -20,10 +21,15 @@ pub struct BlockFinalizationOutcome {
     /// Errors here can happen if the block that we receive to be finalized isn't actually
     /// the one we expect, this could be a replay attack or some other kind of attack.
     pub validation_result: SimpleValidationResult<AbciError>,
+    /// Events to publish once the block is committed
+    pub events: Vec<PlatformEvent>,
 }
 
 impl From<SimpleValidationResult<AbciError>> for BlockFinalizationOutcome {
     fn from(validation_result: SimpleValidationResult<AbciError>) -> Self {
-        BlockFinalizationOutcome { validation_result }
+        BlockFinalizationOutcome {
+            validation_result,
+            events: vec![],
+        }
     }
 }
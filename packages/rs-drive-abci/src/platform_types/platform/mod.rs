@@ -1,6 +1,7 @@
 use crate::config::PlatformConfig;
 use crate::error::execution::ExecutionError;
 use crate::error::Error;
+use crate::event_bus::PlatformEventBus;
 use crate::rpc::core::{CoreRPCLike, DefaultCoreRPC};
 use drive::drive::Drive;
 use std::fmt::{Debug, Formatter};
@@ -8,7 +9,6 @@ use std::fmt::{Debug, Formatter};
 #[cfg(any(feature = "mocks", test))]
 use crate::rpc::core::MockCoreRPCLike;
 use dashcore_rpc::dashcore::hashes::hex::FromHex;
-use drive::drive::defaults::PROTOCOL_VERSION;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::RwLock;
@@ -39,6 +39,8 @@ pub struct Platform<C> {
     pub block_execution_context: RwLock<Option<BlockExecutionContext>>,
     /// Core RPC Client
     pub core_rpc: C,
+    /// Events published after blocks are committed
+    pub event_bus: PlatformEventBus,
 }
 
 // @append_only
@@ -195,12 +197,14 @@ impl<C> Platform<C> {
                 serialized_platform_state,
             )
         } else {
+            let initial_protocol_version = config.initial_protocol_version;
+
             Platform::open_with_client_no_saved_state::<P>(
                 drive,
                 core_rpc,
                 config,
-                PROTOCOL_VERSION,
-                PROTOCOL_VERSION,
+                initial_protocol_version,
+                initial_protocol_version,
             )
         }
     }
@@ -228,6 +232,7 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            event_bus: PlatformEventBus::default(),
         };
 
         Ok(platform)
@@ -257,6 +262,7 @@ impl<C> Platform<C> {
             config,
             block_execution_context: RwLock::new(None),
             core_rpc,
+            event_bus: PlatformEventBus::default(),
         })
     }
 }
@@ -0,0 +1,73 @@
+use dpp::version::PlatformVersion;
+
+/// The health of a validator set.
+///
+/// A validator set whose members are banned, removed or don't propose blocks can't reach
+/// consensus for long, so it is rotated early when its health drops below
+/// the versioned threshold.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ValidatorSetHealth {
+    /// The number of members in the validator set
+    pub member_count: u32,
+    /// Members that are PoSe banned
+    pub banned_member_count: u32,
+    /// Members that are no longer evonodes in the masternode list
+    pub removed_member_count: u32,
+    /// Members that haven't proposed a block in the current epoch
+    pub inactive_member_count: u32,
+}
+
+impl ValidatorSetHealth {
+    /// Members that are neither banned, removed nor inactive
+    pub fn healthy_member_count(&self) -> u32 {
+        self.member_count
+            .saturating_sub(self.banned_member_count)
+            .saturating_sub(self.removed_member_count)
+            .saturating_sub(self.inactive_member_count)
+    }
+
+    /// The percentage of healthy members, an empty validator set has a score of 0
+    pub fn score(&self) -> u8 {
+        if self.member_count == 0 {
+            return 0;
+        }
+
+        (self.healthy_member_count() as u64 * 100 / self.member_count as u64) as u8
+    }
+
+    /// Is the score at least the minimum health of the platform version
+    pub fn is_healthy(&self, platform_version: &PlatformVersion) -> bool {
+        self.score()
+            >= platform_version
+                .drive_abci
+                .validator_set_rotation
+                .minimum_health_percentage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_count_each_unhealthy_member_once() {
+        let health = ValidatorSetHealth {
+            member_count: 10,
+            banned_member_count: 2,
+            removed_member_count: 1,
+            inactive_member_count: 1,
+        };
+
+        assert_eq!(health.healthy_member_count(), 6);
+        assert_eq!(health.score(), 60);
+        assert!(!health.is_healthy(PlatformVersion::latest()));
+    }
+
+    #[test]
+    fn should_not_consider_empty_validator_set_healthy() {
+        let health = ValidatorSetHealth::default();
+
+        assert_eq!(health.score(), 0);
+        assert!(!health.is_healthy(PlatformVersion::latest()));
+    }
+}
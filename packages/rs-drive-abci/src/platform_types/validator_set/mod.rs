@@ -1,13 +1,20 @@
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform_state::PlatformState;
 use crate::platform_types::validator::v0::ValidatorV0;
+use crate::platform_types::validator_set::health::ValidatorSetHealth;
 use crate::platform_types::validator_set::v0::{
     ValidatorSetV0, ValidatorSetV0Getters, ValidatorSetV0Setters,
 };
 use dashcore_rpc::dashcore::{ProTxHash, QuorumHash};
 use dpp::bls_signatures::PublicKey as BlsPublicKey;
+use dpp::version::PlatformVersion;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
 
+/// Health of validator sets
+pub mod health;
 /// Version 0
 pub mod v0;
 
@@ -83,6 +90,41 @@ impl ValidatorSetV0Setters for ValidatorSet {
     }
 }
 
+impl ValidatorSet {
+    /// Computes the health of the validator set.
+    ///
+    /// # Arguments
+    ///
+    /// * `state` - The platform state with the current masternode list.
+    /// * `proposer_block_counts` - Blocks proposed in the current epoch by each proposer.
+    /// * `platform_version` - A `PlatformVersion` reference that dictates which version of
+    ///   the method to call.
+    pub fn health(
+        &self,
+        state: &PlatformState,
+        proposer_block_counts: &BTreeMap<ProTxHash, u64>,
+        platform_version: &PlatformVersion,
+    ) -> Result<ValidatorSetHealth, Error> {
+        match platform_version
+            .drive_abci
+            .methods
+            .block_end
+            .validator_set_health
+        {
+            0 => match self {
+                ValidatorSet::V0(v0) => {
+                    Ok(v0.health_v0(state, proposer_block_counts, platform_version))
+                }
+            },
+            version => Err(Error::Execution(ExecutionError::UnknownVersionMismatch {
+                method: "validator_set_health".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
 /// In this case we are changing to this validator set from another validator set and there are no
 /// changes
 impl From<ValidatorSet> for ValidatorSetUpdate {
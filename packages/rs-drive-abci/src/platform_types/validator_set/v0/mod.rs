@@ -5,9 +5,12 @@ use crate::platform_types::validator::v0::ValidatorV0;
 use dashcore_rpc::dashcore::hashes::Hash;
 use dashcore_rpc::dashcore::{ProTxHash, QuorumHash};
 
+use crate::platform_types::platform_state::v0::PlatformStateV0Methods;
 use crate::platform_types::platform_state::PlatformState;
+use crate::platform_types::validator_set::health::ValidatorSetHealth;
 use dashcore_rpc::json::QuorumInfoResult;
 use dpp::bls_signatures::PublicKey as BlsPublicKey;
+use dpp::version::PlatformVersion;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use tenderdash_abci::proto::abci::ValidatorSetUpdate;
@@ -309,6 +312,56 @@ impl ValidatorSetV0 {
     }
 }
 
+impl ValidatorSetV0 {
+    /// Health of the validator set based on the masternode list and the blocks proposed
+    /// by its members in the current epoch
+    pub(super) fn health_v0(
+        &self,
+        state: &PlatformState,
+        proposer_block_counts: &BTreeMap<ProTxHash, u64>,
+        platform_version: &PlatformVersion,
+    ) -> ValidatorSetHealth {
+        let inactive_proposer_blocks_per_member = platform_version
+            .drive_abci
+            .validator_set_rotation
+            .inactive_proposer_blocks_per_member;
+
+        // Proposers rotate through members, so members missing from the epoch proposers
+        // are only considered inactive once every member had enough turns to propose
+        let proposed_blocks = self
+            .members
+            .keys()
+            .filter_map(|pro_tx_hash| proposer_block_counts.get(pro_tx_hash))
+            .sum::<u64>();
+        let check_participation = proposed_blocks
+            >= self.members.len() as u64 * inactive_proposer_blocks_per_member as u64;
+
+        let mut health = ValidatorSetHealth {
+            member_count: self.members.len() as u32,
+            ..Default::default()
+        };
+
+        for (pro_tx_hash, validator) in &self.members {
+            match state.hpmn_masternode_list().get(pro_tx_hash) {
+                None => health.removed_member_count += 1,
+                Some(masternode)
+                    if validator.is_banned || masternode.state.pose_ban_height.is_some() =>
+                {
+                    health.banned_member_count += 1
+                }
+                Some(_)
+                    if check_participation && !proposer_block_counts.contains_key(pro_tx_hash) =>
+                {
+                    health.inactive_member_count += 1
+                }
+                Some(_) => {}
+            }
+        }
+
+        health
+    }
+}
+
 /// Trait providing getter methods for `ValidatorSetV0` struct
 pub trait ValidatorSetV0Getters {
     /// Returns the quorum hash of the validator set.
@@ -0,0 +1,11 @@
+/// Errors of the HTTP query gateway
+#[derive(Debug, thiserror::Error)]
+pub enum QueryGatewayError {
+    /// Bind address is not a valid `http://` URL
+    #[error("invalid query gateway bind address {0}: {1}")]
+    InvalidBindAddress(String, String),
+
+    /// The HTTP server can't listen on the bind address
+    #[error("query gateway can't listen on {0}: {1}")]
+    Bind(std::net::SocketAddr, String),
+}
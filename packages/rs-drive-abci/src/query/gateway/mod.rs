@@ -0,0 +1,346 @@
+//! HTTP JSON gateway to Platform queries.
+//!
+//! Every query route is exposed with JSON request and response bodies, so Platform can be
+//! queried with plain HTTP tools. Bytes fields are base64-encoded strings and omitted request
+//! fields take their protobuf defaults.
+//!
+//! Requests are converted to protobuf and answered by [Platform::query], exactly like ABCI
+//! queries. The `prove` query parameter overrides the `prove` field of the request and
+//! `protocol_version` selects the platform version used to answer it, the current one by default.
+
+pub mod error;
+#[cfg(feature = "server")]
+mod server;
+
+#[cfg(feature = "server")]
+pub use server::start_query_gateway;
+
+use crate::error::execution::ExecutionError;
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::{query_route, QueryRoute, QUERY_ROUTES};
+use crate::rpc::core::CoreRPCLike;
+use dpp::version::PlatformVersion;
+use prost::Message;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// Default query gateway port (29091)
+pub const DEFAULT_QUERY_GATEWAY_PORT: u16 = 29091;
+
+/// Converts a JSON request to a protobuf request, overriding `prove` if it's set
+pub type RequestFromJson = fn(Map<String, Value>, Option<bool>) -> Result<Vec<u8>, String>;
+
+/// Converts a protobuf response to JSON
+pub type ResponseToJson = fn(&[u8]) -> Result<Value, String>;
+
+/// Response of the gateway, a JSON body with an HTTP status code
+#[derive(Debug, Clone, PartialEq)]
+pub struct GatewayResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// JSON body
+    pub body: Value,
+}
+
+impl GatewayResponse {
+    fn ok(body: Value) -> Self {
+        Self { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl ToString) -> Self {
+        Self {
+            status,
+            body: json!({ "error": message.to_string() }),
+        }
+    }
+}
+
+/// Parameters passed in the query string of a gateway request
+#[derive(Debug, Default, PartialEq)]
+struct QueryParameters {
+    prove: Option<bool>,
+    protocol_version: Option<u32>,
+}
+
+impl QueryParameters {
+    fn parse(query: Option<&str>) -> Result<Self, String> {
+        let mut parameters = Self::default();
+
+        for (name, value) in url::form_urlencoded::parse(query.unwrap_or_default().as_bytes()) {
+            match name.as_ref() {
+                "prove" => {
+                    parameters.prove = Some(
+                        value
+                            .parse()
+                            .map_err(|_| format!("prove must be a bool, got {}", value))?,
+                    );
+                }
+                "protocol_version" => {
+                    parameters.protocol_version = Some(value.parse().map_err(|_| {
+                        format!("protocol_version must be an unsigned int, got {}", value)
+                    })?);
+                }
+                _ => return Err(format!("unknown query parameter {}", name)),
+            }
+        }
+
+        Ok(parameters)
+    }
+}
+
+/// Answers an HTTP request to the gateway.
+///
+/// `GET /` lists query routes. Query routes accept `GET` and `POST` with an optional JSON object
+/// body.
+pub fn handle_request<C>(
+    platform: &Platform<C>,
+    method: &str,
+    path: &str,
+    query: Option<&str>,
+    body: &[u8],
+) -> GatewayResponse
+where
+    C: CoreRPCLike,
+{
+    if path == "/" {
+        return if method == "GET" {
+            let routes: Vec<&str> = QUERY_ROUTES.iter().map(|route| route.path).collect();
+
+            GatewayResponse::ok(json!({ "routes": routes }))
+        } else {
+            GatewayResponse::error(405, format!("method {} not allowed", method))
+        };
+    }
+
+    let Some(route) = query_route(path) else {
+        return GatewayResponse::error(404, format!("unknown query route {}", path));
+    };
+
+    if method != "GET" && method != "POST" {
+        return GatewayResponse::error(405, format!("method {} not allowed", method));
+    }
+
+    let parameters = match QueryParameters::parse(query) {
+        Ok(parameters) => parameters,
+        Err(message) => return GatewayResponse::error(400, message),
+    };
+
+    let request = if body.iter().all(u8::is_ascii_whitespace) {
+        Map::new()
+    } else {
+        match serde_json::from_slice::<Value>(body) {
+            Ok(Value::Object(request)) => request,
+            Ok(_) => return GatewayResponse::error(400, "request body must be a JSON object"),
+            Err(e) => return GatewayResponse::error(400, format!("invalid JSON: {}", e)),
+        }
+    };
+
+    let platform_version = match parameters.protocol_version {
+        Some(protocol_version) => match PlatformVersion::get(protocol_version) {
+            Ok(platform_version) => platform_version,
+            Err(e) => return GatewayResponse::error(400, e),
+        },
+        None => match PlatformVersion::get_maybe_current() {
+            Some(platform_version) => platform_version,
+            None => return GatewayResponse::error(503, "Platform not initialized"),
+        },
+    };
+
+    let request = match (route.request_from_json)(request, parameters.prove) {
+        Ok(request) => request,
+        Err(message) => return GatewayResponse::error(400, message),
+    };
+
+    query(platform, route, &request, platform_version).unwrap_or_else(|error| {
+        tracing::error!(?error, path, "query gateway request failed");
+
+        GatewayResponse::error(500, error)
+    })
+}
+
+fn query<C>(
+    platform: &Platform<C>,
+    route: &QueryRoute,
+    request: &[u8],
+    platform_version: &PlatformVersion,
+) -> Result<GatewayResponse, Error>
+where
+    C: CoreRPCLike,
+{
+    let result = platform.query(route.path, request, platform_version)?;
+
+    if !result.is_valid() {
+        let message = result
+            .errors
+            .first()
+            .map(|error| error.to_string())
+            .unwrap_or_else(|| "Unknown Drive error".to_string());
+
+        return Ok(GatewayResponse::error(400, message));
+    }
+
+    let response = (route.response_to_json)(&result.data.unwrap_or_default()).map_err(|_| {
+        Error::Execution(ExecutionError::CorruptedCodeExecution(
+            "query response must convert to JSON",
+        ))
+    })?;
+
+    Ok(GatewayResponse::ok(response))
+}
+
+/// Converts a JSON request to the protobuf request `Req`.
+///
+/// Omitted fields take protobuf defaults. Unknown fields are rejected.
+pub fn request_from_json<Req>(
+    request: Map<String, Value>,
+    prove: Option<bool>,
+) -> Result<Vec<u8>, String>
+where
+    Req: Message + Default + Serialize + DeserializeOwned,
+{
+    let Ok(Value::Object(mut fields)) = serde_json::to_value(Req::default()) else {
+        return Err("request can't be represented as a JSON object".to_string());
+    };
+
+    for (name, value) in request {
+        if !fields.contains_key(&name) {
+            return Err(format!("unknown request field {}", name));
+        }
+
+        fields.insert(name, value);
+    }
+
+    if let Some(prove) = prove {
+        if !fields.contains_key("prove") {
+            return Err("the query doesn't accept the prove parameter".to_string());
+        }
+
+        fields.insert("prove".to_string(), Value::Bool(prove));
+    }
+
+    let request: Req = serde_json::from_value(Value::Object(fields))
+        .map_err(|e| format!("invalid request: {}", e))?;
+
+    Ok(request.encode_to_vec())
+}
+
+/// Converts the protobuf response `Res` to JSON
+pub fn response_to_json<Res>(response: &[u8]) -> Result<Value, String>
+where
+    Res: Message + Default + Serialize,
+{
+    let response = Res::decode(response).map_err(|e| e.to_string())?;
+
+    serde_json::to_value(response).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+    use dpp::system_data_contracts::dpns_contract;
+
+    fn protocol_version_query() -> String {
+        format!(
+            "protocol_version={}",
+            PlatformVersion::latest().protocol_version
+        )
+    }
+
+    #[test]
+    fn should_list_routes() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let response = handle_request(&platform, "GET", "/", None, &[]);
+
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.body["routes"].as_array().map(Vec::len),
+            Some(QUERY_ROUTES.len())
+        );
+    }
+
+    #[test]
+    fn should_return_data_contract_as_json() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_genesis_state();
+
+        let id = base64::encode(dpns_contract::ID_BYTES);
+        let body = json!({ "id": id }).to_string();
+
+        let response = handle_request(
+            &platform,
+            "POST",
+            "/dataContract",
+            Some(&protocol_version_query()),
+            body.as_bytes(),
+        );
+
+        assert_eq!(response.status, 200, "{}", response.body);
+        assert!(response.body["result"]["data_contract"].is_string());
+        assert!(response.body["metadata"]["height"].is_u64());
+
+        let response = handle_request(
+            &platform,
+            "POST",
+            "/dataContract",
+            Some(&format!("prove=true&{}", protocol_version_query())),
+            body.as_bytes(),
+        );
+
+        assert_eq!(response.status, 200, "{}", response.body);
+        assert!(response.body["result"]["proof"]["grovedb_proof"].is_string());
+    }
+
+    #[test]
+    fn should_reject_invalid_requests() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        let query = protocol_version_query();
+
+        let response = handle_request(&platform, "POST", "/unknown", Some(&query), &[]);
+        assert_eq!(response.status, 404);
+
+        let response = handle_request(&platform, "PUT", "/identity", Some(&query), &[]);
+        assert_eq!(response.status, 405);
+
+        let response = handle_request(&platform, "POST", "/identity", Some(&query), b"[1]");
+        assert_eq!(response.status, 400);
+
+        let response = handle_request(
+            &platform,
+            "POST",
+            "/identity",
+            Some(&query),
+            br#"{"unknown":1}"#,
+        );
+        assert_eq!(response.status, 400);
+
+        let response = handle_request(
+            &platform,
+            "POST",
+            "/proofs",
+            Some(&format!("prove=true&{}", query)),
+            &[],
+        );
+        assert_eq!(response.status, 400);
+
+        // Query validation errors are returned as bad requests
+        let response = handle_request(
+            &platform,
+            "POST",
+            "/identity",
+            Some(&query),
+            br#"{"id":"AQI="}"#,
+        );
+        assert_eq!(response.status, 400);
+        assert!(response.body["error"].is_string());
+    }
+}
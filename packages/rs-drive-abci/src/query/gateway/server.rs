@@ -0,0 +1,101 @@
+//! HTTP server of the query gateway
+
+use crate::error::Error;
+use crate::platform_types::platform::Platform;
+use crate::query::gateway::error::QueryGatewayError;
+use crate::query::gateway::{handle_request, GatewayResponse, DEFAULT_QUERY_GATEWAY_PORT};
+use crate::rpc::core::CoreRPCLike;
+use hyper::header::CONTENT_TYPE;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio_util::sync::CancellationToken;
+
+/// Starts the query gateway on the current tokio runtime.
+///
+/// Bind address should be an URL with scheme `http://`, for example `http://127.0.0.1:29091`.
+/// Port number defaults to [DEFAULT_QUERY_GATEWAY_PORT]. The server stops once `cancel`
+/// is cancelled.
+pub fn start_query_gateway<C>(
+    platform: Arc<Platform<C>>,
+    bind_address: &str,
+    cancel: CancellationToken,
+) -> Result<(), Error>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
+    let invalid_address =
+        |reason: String| QueryGatewayError::InvalidBindAddress(bind_address.to_string(), reason);
+
+    let url = url::Url::parse(bind_address).map_err(|e| invalid_address(e.to_string()))?;
+    if url.scheme() != "http" {
+        return Err(invalid_address(format!("unsupported scheme {}", url.scheme())).into());
+    }
+
+    let addr = url
+        .socket_addrs(|| Some(DEFAULT_QUERY_GATEWAY_PORT))
+        .map_err(|e| invalid_address(e.to_string()))?
+        .first()
+        .copied()
+        .ok_or_else(|| invalid_address("failed to resolve bind address".to_string()))?;
+
+    let make_service = make_service_fn(move |_| {
+        let platform = Arc::clone(&platform);
+
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                serve(Arc::clone(&platform), request)
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)
+        .map_err(|e| QueryGatewayError::Bind(addr, e.to_string()))?
+        .serve(make_service)
+        .with_graceful_shutdown(async move { cancel.cancelled().await });
+
+    tokio::spawn(async move {
+        if let Err(error) = server.await {
+            tracing::error!(?error, "query gateway server failed");
+        }
+    });
+
+    tracing::info!(%addr, "query gateway started");
+
+    Ok(())
+}
+
+async fn serve<C>(
+    platform: Arc<Platform<C>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible>
+where
+    C: CoreRPCLike + Send + Sync + 'static,
+{
+    let _timer = crate::metrics::abci_request_duration("query_gateway");
+
+    let (parts, body) = request.into_parts();
+
+    let response = match hyper::body::to_bytes(body).await {
+        // Queries block on storage
+        Ok(body) => tokio::task::spawn_blocking(move || {
+            handle_request(
+                &platform,
+                parts.method.as_str(),
+                parts.uri.path(),
+                parts.uri.query(),
+                &body,
+            )
+        })
+        .await
+        .unwrap_or_else(|e| GatewayResponse::error(500, e)),
+        Err(e) => GatewayResponse::error(400, format!("cannot read request body: {}", e)),
+    };
+
+    Ok(Response::builder()
+        .status(response.status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(response.body.to_string()))
+        .expect("status and content type are valid"))
+}
@@ -1,16 +1,142 @@
+/// HTTP JSON gateway to queries
+pub mod gateway;
 mod v0;
 
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 
+use dapi_grpc::platform::v0::{
+    EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+    GetContestedResourceVoteTallyRequest, GetContestedResourceVoteTallyResponse,
+    GetDataContractHistoryRequest, GetDataContractHistoryResponse, GetDataContractRequest,
+    GetDataContractResponse, GetDataContractsRequest, GetDataContractsResponse,
+    GetDocumentHistoryRequest, GetDocumentHistoryResponse, GetDocumentsRequest,
+    GetDocumentsResponse, GetDomainCanonicalOwnerRequest, GetDomainCanonicalOwnerResponse,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentitiesByPublicKeyHashesResponse,
+    GetIdentitiesRequest, GetIdentitiesResponse, GetIdentityBalanceAndRevisionResponse,
+    GetIdentityBalanceResponse, GetIdentityByPublicKeyHashesRequest,
+    GetIdentityByPublicKeyHashesResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
+    GetIdentityRequest, GetIdentityResponse, GetIdentityWithdrawalsRequest,
+    GetIdentityWithdrawalsResponse, GetProofsRequest, GetProofsResponse,
+};
 use dpp::validation::ValidationResult;
 use dpp::version::PlatformVersion;
+use drive::error::query::QuerySyntaxError;
 
 /// A query validation result
 pub type QueryValidationResult<TData> = ValidationResult<TData, QueryError>;
 
-impl<C> Platform<C> {
+/// Query route with conversions of its protobuf request and response messages
+pub struct QueryRoute {
+    /// Query path
+    pub path: &'static str,
+    /// Converts a JSON request to the protobuf request, see [gateway::request_from_json]
+    pub request_from_json: gateway::RequestFromJson,
+    /// Converts the protobuf response to JSON, see [gateway::response_to_json]
+    pub response_to_json: gateway::ResponseToJson,
+}
+
+macro_rules! query_route {
+    ($path:literal, $request:ty, $response:ty) => {
+        QueryRoute {
+            path: $path,
+            request_from_json: gateway::request_from_json::<$request>,
+            response_to_json: gateway::response_to_json::<$response>,
+        }
+    };
+}
+
+/// Supported query routes.
+///
+/// Queries to other paths are rejected, so a route must be added here to be served.
+pub const QUERY_ROUTES: &[QueryRoute] = &[
+    query_route!("/identity", GetIdentityRequest, GetIdentityResponse),
+    query_route!("/identities", GetIdentitiesRequest, GetIdentitiesResponse),
+    query_route!(
+        "/identity/balance",
+        GetIdentityRequest,
+        GetIdentityBalanceResponse
+    ),
+    query_route!(
+        "/identity/balanceAndRevision",
+        GetIdentityRequest,
+        GetIdentityBalanceAndRevisionResponse
+    ),
+    query_route!(
+        "/identity/keys",
+        GetIdentityKeysRequest,
+        GetIdentityKeysResponse
+    ),
+    query_route!(
+        "/identity/withdrawals",
+        GetIdentityWithdrawalsRequest,
+        GetIdentityWithdrawalsResponse
+    ),
+    query_route!(
+        "/identity/by-public-key-hash",
+        GetIdentityByPublicKeyHashesRequest,
+        GetIdentityByPublicKeyHashesResponse
+    ),
+    query_route!(
+        "/identities/by-public-key-hash",
+        GetIdentitiesByPublicKeyHashesRequest,
+        GetIdentitiesByPublicKeyHashesResponse
+    ),
+    query_route!(
+        "/dataContract",
+        GetDataContractRequest,
+        GetDataContractResponse
+    ),
+    query_route!(
+        "/dataContracts",
+        GetDataContractsRequest,
+        GetDataContractsResponse
+    ),
+    query_route!(
+        "/dataContractHistory",
+        GetDataContractHistoryRequest,
+        GetDataContractHistoryResponse
+    ),
+    query_route!("/documents", GetDocumentsRequest, GetDocumentsResponse),
+    query_route!(
+        "/dataContract/documents",
+        GetDocumentsRequest,
+        GetDocumentsResponse
+    ),
+    query_route!(
+        "/documentHistory",
+        GetDocumentHistoryRequest,
+        GetDocumentHistoryResponse
+    ),
+    query_route!(
+        "/domain/canonicalOwner",
+        GetDomainCanonicalOwnerRequest,
+        GetDomainCanonicalOwnerResponse
+    ),
+    query_route!(
+        "/contestedResource/voteTally",
+        GetContestedResourceVoteTallyRequest,
+        GetContestedResourceVoteTallyResponse
+    ),
+    query_route!(
+        "/estimateFee",
+        EstimateStateTransitionFeeRequest,
+        EstimateStateTransitionFeeResponse
+    ),
+    query_route!("/proofs", GetProofsRequest, GetProofsResponse),
+];
+
+/// Finds a supported query route
+pub fn query_route(path: &str) -> Option<&'static QueryRoute> {
+    QUERY_ROUTES.iter().find(|route| route.path == path)
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub fn query(
         &self,
@@ -18,7 +144,45 @@ impl<C> Platform<C> {
         query_data: &[u8],
         platform_version: &PlatformVersion,
     ) -> Result<QueryValidationResult<Vec<u8>>, Error> {
+        if query_route(query_path).is_none() {
+            return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                QuerySyntaxError::Unsupported(format!(
+                    "query path '{}' is not supported",
+                    query_path
+                )),
+            )));
+        }
+
         //todo: choose based on protocol version
         self.query_v0(query_path, query_data, platform_version)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::helpers::setup::TestPlatformBuilder;
+
+    #[test]
+    fn should_handle_every_query_route() {
+        let platform = TestPlatformBuilder::new()
+            .build_with_mock_rpc()
+            .set_initial_state_structure();
+
+        for route in QUERY_ROUTES {
+            let result = platform
+                .query(route.path, &[], PlatformVersion::latest())
+                .expect("expected to query");
+
+            assert!(
+                !result.errors.iter().any(|error| matches!(
+                    error,
+                    QueryError::Query(QuerySyntaxError::Unsupported(message))
+                        if message.starts_with("query path")
+                )),
+                "route {} is not handled",
+                route.path
+            );
+        }
+    }
+}
@@ -1,28 +1,53 @@
 use crate::error::query::QueryError;
 use crate::error::Error;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 use dapi_grpc::platform::v0::get_documents_request::Start;
 use dapi_grpc::platform::v0::{
+    estimate_state_transition_fee_response, get_contested_resource_vote_tally_response,
     get_data_contract_history_response, get_data_contract_response, get_data_contracts_response,
-    get_documents_response, get_identities_by_public_key_hashes_response, get_identities_response,
+    get_document_history_response, get_documents_response, get_domain_canonical_owner_response,
+    get_identities_by_public_key_hashes_response, get_identities_response,
     get_identity_balance_and_revision_response, get_identity_balance_response,
     get_identity_by_public_key_hashes_response, get_identity_keys_response, get_identity_response,
-    GetDataContractHistoryRequest, GetDataContractHistoryResponse, GetDataContractRequest,
-    GetDataContractResponse, GetDataContractsRequest, GetDataContractsResponse,
-    GetDocumentsRequest, GetDocumentsResponse, GetIdentitiesByPublicKeyHashesRequest,
-    GetIdentitiesByPublicKeyHashesResponse, GetIdentitiesRequest, GetIdentitiesResponse,
-    GetIdentityBalanceAndRevisionResponse, GetIdentityBalanceResponse,
-    GetIdentityByPublicKeyHashesRequest, GetIdentityByPublicKeyHashesResponse,
-    GetIdentityKeysRequest, GetIdentityKeysResponse, GetIdentityRequest, GetIdentityResponse,
-    GetProofsRequest, GetProofsResponse, Proof, ResponseMetadata,
+    get_identity_withdrawals_response, EstimateStateTransitionFeeRequest,
+    EstimateStateTransitionFeeResponse, GetContestedResourceVoteTallyRequest,
+    GetContestedResourceVoteTallyResponse, GetDataContractHistoryRequest,
+    GetDataContractHistoryResponse, GetDataContractRequest, GetDataContractResponse,
+    GetDataContractsRequest, GetDataContractsResponse, GetDocumentHistoryRequest,
+    GetDocumentHistoryResponse, GetDocumentsRequest, GetDocumentsResponse,
+    GetDomainCanonicalOwnerRequest, GetDomainCanonicalOwnerResponse,
+    GetIdentitiesByPublicKeyHashesRequest, GetIdentitiesByPublicKeyHashesResponse,
+    GetIdentitiesRequest, GetIdentitiesResponse, GetIdentityBalanceAndRevisionResponse,
+    GetIdentityBalanceResponse, GetIdentityByPublicKeyHashesRequest,
+    GetIdentityByPublicKeyHashesResponse, GetIdentityKeysRequest, GetIdentityKeysResponse,
+    GetIdentityRequest, GetIdentityResponse, GetIdentityWithdrawalsRequest,
+    GetIdentityWithdrawalsResponse, GetProofsRequest, GetProofsResponse, Proof, ResponseMetadata,
+    StateTransitionBroadcastError,
 };
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::errors::consensus::codes::ErrorWithCode;
 use dpp::identifier::Identifier;
-use dpp::platform_value::{Bytes20, Bytes32};
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::platform_value::{Bytes20, Bytes32, Value};
+use dpp::serialization::PlatformDeserializable;
+use dpp::state_transition::StateTransition;
+use dpp::system_data_contracts::dpns_contract;
+use dpp::system_data_contracts::dpns_contract::confusables::label_skeleton;
+use dpp::system_data_contracts::dpns_contract::document_types::domain::properties::{
+    LABEL_SKELETON, NORMALIZED_PARENT_DOMAIN_NAME,
+};
+use dpp::system_data_contracts::withdrawals_contract;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
 use std::collections::BTreeMap;
 
 use dpp::serialization::{PlatformSerializable, PlatformSerializableWithPlatformVersion};
 use dpp::validation::ValidationResult;
 use dpp::{check_validation_result_with_data, ProtocolError};
+use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use drive::drive::identity::withdrawals::NUMBER_OF_BLOCKS_BEFORE_EXPIRED;
 use drive::drive::identity::IdentityDriveQuery;
 use drive::drive::identity::IdentityProveRequestType;
 
@@ -41,7 +66,10 @@ use drive::drive::identity::key::fetch::{
 };
 use drive::error::contract::DataContractError;
 use drive::error::query::QuerySyntaxError;
-use drive::query::{DriveQuery, SingleDocumentDriveQuery};
+use drive::query::{
+    DriveQuery, InternalClauses, OrderClause, SingleDocumentDriveQuery, WhereClause, WhereOperator,
+};
+use indexmap::IndexMap;
 use prost::Message;
 
 fn from_i32_to_key_kind_request_type(value: i32) -> Option<KeyKindRequestType> {
@@ -94,7 +122,48 @@ fn convert_key_request_type(
     }
 }
 
-impl<C> Platform<C> {
+fn withdrawal_from_document(
+    document: &Document,
+) -> Result<get_identity_withdrawals_response::Withdrawal, QueryError> {
+    let properties = document.properties();
+    let status: u8 = properties.get_integer(withdrawal::properties::STATUS)?;
+    let transaction_sign_height: u32 = properties
+        .get_optional_integer(withdrawal::properties::TRANSACTION_SIGN_HEIGHT)?
+        .unwrap_or_default();
+
+    // Broadcasted withdrawals complete once the asset unlock transaction is mined,
+    // otherwise they expire and get refunded
+    let expected_completion_core_height =
+        if status == u8::from(withdrawals_contract::WithdrawalStatus::BROADCASTED) {
+            transaction_sign_height + NUMBER_OF_BLOCKS_BEFORE_EXPIRED
+        } else {
+            0
+        };
+
+    Ok(get_identity_withdrawals_response::Withdrawal {
+        id: document.id().to_vec(),
+        amount: properties.get_integer(withdrawal::properties::AMOUNT)?,
+        status: status as i32,
+        transaction_id: properties
+            .get_optional_bytes(withdrawal::properties::TRANSACTION_ID)?
+            .unwrap_or_default(),
+        transaction_sign_height,
+        expected_completion_core_height,
+        failure_reason: properties
+            .get_optional_integer::<u8>(withdrawal::properties::FAILURE_REASON)?
+            .unwrap_or_default() as i32,
+        refunded_at_ms: properties
+            .get_optional_integer(withdrawal::properties::REFUNDED_AT)?
+            .unwrap_or_default(),
+        created_at_ms: document.created_at().unwrap_or_default(),
+        updated_at_ms: document.updated_at().unwrap_or_default(),
+    })
+}
+
+impl<C> Platform<C>
+where
+    C: CoreRPCLike,
+{
     /// Querying
     pub(super) fn query_v0(
         &self,
@@ -386,16 +455,153 @@ impl<C> Platform<C> {
                     };
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
+            "/identity/withdrawals" => {
+                let GetIdentityWithdrawalsRequest {
+                    identity_id,
+                    limit,
+                    start_after,
+                    prove,
+                } = check_validation_result_with_data!(GetIdentityWithdrawalsRequest::decode(
+                    query_data
+                ));
+                let identity_id: Identifier =
+                    check_validation_result_with_data!(identity_id.try_into());
+                let limit = match limit {
+                    Some(limit) if limit > self.config.drive.max_query_limit as u32 => {
+                        return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                            QuerySyntaxError::InvalidLimit(format!(
+                                "limit greater than max limit {}",
+                                self.config.drive.max_query_limit
+                            )),
+                        )));
+                    }
+                    Some(limit) if limit > 0 => limit as u16,
+                    _ => self.config.drive.default_query_limit,
+                };
+                let start_at = if start_after.is_empty() {
+                    None
+                } else {
+                    Some(check_validation_result_with_data!(start_after
+                        .try_into()
+                        .map_err(|_| QueryError::Query(
+                            QuerySyntaxError::InvalidStartsWithClause(
+                                "start after should be a 32 byte identifier",
+                            )
+                        ))))
+                };
+                let (_, contract) = check_validation_result_with_data!(self
+                    .drive
+                    .get_contract_with_fetch_info_and_fee(
+                        withdrawals_contract::ID.to_buffer(),
+                        None,
+                        true,
+                        None,
+                        platform_version,
+                    ));
+                let contract = check_validation_result_with_data!(contract.ok_or(
+                    QueryError::Query(QuerySyntaxError::DataContractNotFound(
+                        "withdrawals contract not found when querying identity withdrawals",
+                    ))
+                ));
+                let contract_ref = &contract.contract;
+                let document_type = check_validation_result_with_data!(contract_ref
+                    .document_type_for_name(
+                        withdrawals_contract::document_types::withdrawal::NAME
+                    ));
+
+                // Uses the identityRecent index so withdrawals are returned
+                // in the order they were last updated
+                let drive_query = DriveQuery {
+                    contract: contract_ref,
+                    document_type,
+                    internal_clauses: InternalClauses {
+                        primary_key_in_clause: None,
+                        primary_key_equal_clause: None,
+                        in_clause: None,
+                        range_clause: None,
+                        equal_clauses: BTreeMap::from([(
+                            withdrawal::properties::OWNER_ID.to_string(),
+                            WhereClause {
+                                field: withdrawal::properties::OWNER_ID.to_string(),
+                                operator: WhereOperator::Equal,
+                                value: Value::Identifier(identity_id.to_buffer()),
+                            },
+                        )]),
+                    },
+                    offset: None,
+                    limit: Some(limit),
+                    order_by: IndexMap::from([(
+                        withdrawal::properties::UPDATED_AT.to_string(),
+                        OrderClause {
+                            field: withdrawal::properties::UPDATED_AT.to_string(),
+                            ascending: true,
+                        },
+                    )]),
+                    start_at,
+                    start_at_included: false,
+                    block_time_ms: None,
+                };
+                let response_data = if prove {
+                    let (proof, _) = check_validation_result_with_data!(
+                        drive_query.execute_with_proof(&self.drive, None, None, platform_version)
+                    );
+                    GetIdentityWithdrawalsResponse {
+                        result: Some(get_identity_withdrawals_response::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                } else {
+                    let documents = check_validation_result_with_data!(self.drive.query_documents(
+                        drive_query,
+                        None,
+                        false,
+                        None,
+                        Some(platform_version.protocol_version)
+                    ))
+                    .documents_owned();
+                    let withdrawals = check_validation_result_with_data!(documents
+                        .iter()
+                        .map(withdrawal_from_document)
+                        .collect::<Result<Vec<_>, _>>());
+                    GetIdentityWithdrawalsResponse {
+                        result: Some(get_identity_withdrawals_response::Result::Withdrawals(
+                            get_identity_withdrawals_response::Withdrawals { withdrawals },
+                        )),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
             "/dataContract" => {
-                let GetDataContractRequest { id, prove } =
-                    check_validation_result_with_data!(GetDataContractRequest::decode(query_data));
+                let GetDataContractRequest {
+                    id,
+                    prove,
+                    at_time_ms,
+                } = check_validation_result_with_data!(GetDataContractRequest::decode(query_data));
                 let contract_id: Identifier = check_validation_result_with_data!(id.try_into());
                 let response_data = if prove {
-                    let proof = check_validation_result_with_data!(self.drive.prove_contract(
-                        contract_id.into_buffer(),
-                        None,
-                        platform_version
-                    ));
+                    let proof = if let Some(at_time_ms) = at_time_ms {
+                        check_validation_result_with_data!(self.drive.prove_contract_at_time(
+                            contract_id.into_buffer(),
+                            at_time_ms,
+                            None,
+                            platform_version
+                        ))
+                    } else {
+                        check_validation_result_with_data!(self.drive.prove_contract(
+                            contract_id.into_buffer(),
+                            None,
+                            platform_version
+                        ))
+                    };
                     GetDataContractResponse {
                         result: Some(get_data_contract_response::Result::Proof(Proof {
                             grovedb_proof: proof,
@@ -408,6 +614,25 @@ impl<C> Platform<C> {
                         metadata: Some(metadata),
                     }
                     .encode_to_vec()
+                } else if let Some(at_time_ms) = at_time_ms {
+                    let contract =
+                        check_validation_result_with_data!(self.drive.fetch_contract_at_time(
+                            contract_id.into_buffer(),
+                            at_time_ms,
+                            None,
+                            platform_version
+                        ))
+                        .map(|(_, contract)| {
+                            contract.serialize_to_bytes_with_platform_version(platform_version)
+                        })
+                        .transpose()?;
+                    GetDataContractResponse {
+                        result: Some(get_data_contract_response::Result::DataContract(
+                            contract.unwrap_or_default(),
+                        )),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
                 } else {
                     let contract = check_validation_result_with_data!(self
                         .drive
@@ -604,6 +829,133 @@ impl<C> Platform<C> {
                 };
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
+            "/documentHistory" => {
+                let GetDocumentHistoryRequest {
+                    data_contract_id,
+                    document_type: document_type_name,
+                    document_id,
+                    start_at_ms,
+                    end_at_ms,
+                    limit,
+                    offset,
+                    prove,
+                } = check_validation_result_with_data!(GetDocumentHistoryRequest::decode(
+                    query_data
+                ));
+                let contract_id: Identifier =
+                    check_validation_result_with_data!(data_contract_id.try_into());
+                let document_id: Identifier =
+                    check_validation_result_with_data!(document_id.try_into());
+
+                let limit = check_validation_result_with_data!(limit
+                    .map(|limit| {
+                        u16::try_from(limit).map_err(|_| {
+                            QueryError::Query(QuerySyntaxError::InvalidLimit(format!(
+                                "limit {} out of bounds",
+                                limit
+                            )))
+                        })
+                    })
+                    .transpose());
+                let offset = check_validation_result_with_data!(offset
+                    .map(|offset| {
+                        u16::try_from(offset).map_err(|_| {
+                            QueryError::Query(QuerySyntaxError::InvalidParameter(format!(
+                                "offset {} out of bounds",
+                                offset
+                            )))
+                        })
+                    })
+                    .transpose());
+
+                let (_, contract) = check_validation_result_with_data!(self
+                    .drive
+                    .get_contract_with_fetch_info_and_fee(
+                        contract_id.to_buffer(),
+                        None,
+                        true,
+                        None,
+                        platform_version,
+                    ));
+                let contract = check_validation_result_with_data!(contract.ok_or(
+                    QueryError::Query(QuerySyntaxError::DataContractNotFound(
+                        "contract not found when querying document history",
+                    ))
+                ));
+                let document_type = check_validation_result_with_data!(contract
+                    .contract
+                    .document_type_for_name(document_type_name.as_str()));
+
+                if !document_type.documents_keep_history() {
+                    return Ok(QueryValidationResult::new_with_error(QueryError::Query(
+                        QuerySyntaxError::InvalidDocumentType(
+                            "document type does not keep history",
+                        ),
+                    )));
+                }
+
+                let response_data = if prove {
+                    let proof =
+                        check_validation_result_with_data!(self.drive.prove_document_history(
+                            contract_id.to_buffer(),
+                            document_type_name.as_str(),
+                            document_id.to_buffer(),
+                            start_at_ms,
+                            end_at_ms,
+                            limit,
+                            offset,
+                            None,
+                            platform_version,
+                        ));
+                    GetDocumentHistoryResponse {
+                        metadata: Some(metadata),
+                        result: Some(get_document_history_response::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                    }
+                    .encode_to_vec()
+                } else {
+                    let revisions =
+                        check_validation_result_with_data!(self.drive.fetch_document_history(
+                            contract_id.to_buffer(),
+                            document_type,
+                            document_id.to_buffer(),
+                            start_at_ms,
+                            end_at_ms,
+                            limit,
+                            offset,
+                            None,
+                            platform_version,
+                        ));
+
+                    let document_entries = check_validation_result_with_data!(revisions
+                        .into_iter()
+                        .map(|(date, revision, document)| Ok::<
+                            get_document_history_response::DocumentHistoryEntry,
+                            ProtocolError,
+                        >(
+                            get_document_history_response::DocumentHistoryEntry {
+                                date,
+                                revision,
+                                value: document.serialize(document_type, platform_version)?,
+                            }
+                        ))
+                        .collect());
+                    GetDocumentHistoryResponse {
+                        result: Some(get_document_history_response::Result::DocumentHistory(
+                            get_document_history_response::DocumentHistory { document_entries },
+                        )),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
             "/documents" | "/dataContract/documents" => {
                 let GetDocumentsRequest {
                     data_contract_id,
@@ -613,6 +965,7 @@ impl<C> Platform<C> {
                     limit,
                     prove,
                     start,
+                    block_time_ms,
                 } = check_validation_result_with_data!(GetDocumentsRequest::decode(query_data));
                 let contract_id: Identifier =
                     check_validation_result_with_data!(data_contract_id.try_into());
@@ -702,7 +1055,7 @@ impl<C> Platform<C> {
                         }),
                         start_at,
                         start_at_included,
-                        None,
+                        block_time_ms,
                         contract_ref,
                         document_type,
                         &self.config.drive,
@@ -737,6 +1090,232 @@ impl<C> Platform<C> {
                 };
                 Ok(QueryValidationResult::new_with_data(response_data))
             }
+            "/domain/canonicalOwner" => {
+                let GetDomainCanonicalOwnerRequest {
+                    label,
+                    normalized_parent_domain_name,
+                    prove,
+                } = check_validation_result_with_data!(GetDomainCanonicalOwnerRequest::decode(
+                    query_data
+                ));
+                let (_, contract) = check_validation_result_with_data!(self
+                    .drive
+                    .get_contract_with_fetch_info_and_fee(
+                        dpns_contract::ID.to_buffer(),
+                        None,
+                        true,
+                        None,
+                        platform_version,
+                    ));
+                let contract = check_validation_result_with_data!(contract.ok_or(
+                    QueryError::Query(QuerySyntaxError::DataContractNotFound(
+                        "dpns contract not found when querying the canonical domain owner",
+                    ))
+                ));
+                let contract_ref = &contract.contract;
+                let document_type = check_validation_result_with_data!(contract_ref
+                    .document_type_for_name(dpns_contract::document_types::domain::NAME));
+
+                // Labels that are confusable with each other share a skeleton and
+                // the DPNS data trigger only lets one identity own a skeleton
+                let drive_query = DriveQuery {
+                    contract: contract_ref,
+                    document_type,
+                    internal_clauses: InternalClauses {
+                        primary_key_in_clause: None,
+                        primary_key_equal_clause: None,
+                        in_clause: None,
+                        range_clause: None,
+                        equal_clauses: BTreeMap::from([
+                            (
+                                NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+                                WhereClause {
+                                    field: NORMALIZED_PARENT_DOMAIN_NAME.to_string(),
+                                    operator: WhereOperator::Equal,
+                                    value: Value::Text(normalized_parent_domain_name),
+                                },
+                            ),
+                            (
+                                LABEL_SKELETON.to_string(),
+                                WhereClause {
+                                    field: LABEL_SKELETON.to_string(),
+                                    operator: WhereOperator::Equal,
+                                    value: Value::Text(label_skeleton(&label)),
+                                },
+                            ),
+                        ]),
+                    },
+                    offset: None,
+                    limit: Some(1),
+                    order_by: Default::default(),
+                    start_at: None,
+                    start_at_included: false,
+                    block_time_ms: None,
+                };
+                let response_data = if prove {
+                    let (proof, _) = check_validation_result_with_data!(
+                        drive_query.execute_with_proof(&self.drive, None, None, platform_version)
+                    );
+                    GetDomainCanonicalOwnerResponse {
+                        result: Some(get_domain_canonical_owner_response::Result::Proof(Proof {
+                            grovedb_proof: proof,
+                            quorum_hash: state.last_quorum_hash().to_vec(),
+                            quorum_type,
+                            block_id_hash: state.last_block_id_hash().to_vec(),
+                            signature: state.last_block_signature().to_vec(),
+                            round: state.last_block_round(),
+                        })),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                } else {
+                    let documents = check_validation_result_with_data!(self.drive.query_documents(
+                        drive_query,
+                        None,
+                        false,
+                        None,
+                        Some(platform_version.protocol_version)
+                    ))
+                    .documents_owned();
+                    let owner_id = documents
+                        .first()
+                        .map(|document| document.owner_id().to_vec())
+                        .unwrap_or_default();
+                    GetDomainCanonicalOwnerResponse {
+                        result: Some(get_domain_canonical_owner_response::Result::OwnerId(
+                            owner_id,
+                        )),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
+            "/contestedResource/voteTally" => {
+                let GetContestedResourceVoteTallyRequest { contest_id, prove } = check_validation_result_with_data!(
+                    GetContestedResourceVoteTallyRequest::decode(query_data)
+                );
+                let contest_id: Identifier =
+                    check_validation_result_with_data!(contest_id.try_into());
+                let response_data = if prove {
+                    let proof = check_validation_result_with_data!(self
+                        .drive
+                        .prove_contested_resource_vote_tally(
+                            contest_id.as_bytes(),
+                            None,
+                            &platform_version.drive,
+                        ));
+                    GetContestedResourceVoteTallyResponse {
+                        result: Some(get_contested_resource_vote_tally_response::Result::Proof(
+                            Proof {
+                                grovedb_proof: proof,
+                                quorum_hash: state.last_quorum_hash().to_vec(),
+                                quorum_type,
+                                block_id_hash: state.last_block_id_hash().to_vec(),
+                                signature: state.last_block_signature().to_vec(),
+                                round: state.last_block_round(),
+                            },
+                        )),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                } else {
+                    // Contests that don't exist or were already resolved have an empty tally
+                    let vote_tally = check_validation_result_with_data!(self
+                        .drive
+                        .fetch_contested_resource_vote_tally(
+                            contest_id.as_bytes(),
+                            None,
+                            &platform_version.drive,
+                        ))
+                    .unwrap_or_default();
+                    GetContestedResourceVoteTallyResponse {
+                        result: Some(
+                            get_contested_resource_vote_tally_response::Result::VoteTally(
+                                get_contested_resource_vote_tally_response::ContestedResourceVoteTally {
+                                    contenders: vote_tally
+                                        .votes_by_contender
+                                        .into_iter()
+                                        .map(|(identity_id, vote_count)| {
+                                            get_contested_resource_vote_tally_response::ContenderVotes {
+                                                identity_id: identity_id.to_vec(),
+                                                vote_count,
+                                            }
+                                        })
+                                        .collect(),
+                                    abstain_vote_count: vote_tally.abstain_votes,
+                                },
+                            ),
+                        ),
+                        metadata: Some(metadata),
+                    }
+                    .encode_to_vec()
+                };
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
+            "/estimateFee" => {
+                let EstimateStateTransitionFeeRequest { state_transition } = check_validation_result_with_data!(
+                    EstimateStateTransitionFeeRequest::decode(query_data)
+                );
+                let state_transition = check_validation_result_with_data!(
+                    StateTransition::deserialize_from_bytes(state_transition.as_slice())
+                );
+
+                // The estimation reads the platform state itself
+                drop(state);
+
+                let estimate_result =
+                    self.estimate_state_transition_fee(state_transition, platform_version)?;
+
+                let error = if let Some(consensus_error) = estimate_result.errors.first() {
+                    Some(StateTransitionBroadcastError {
+                        code: consensus_error.code(),
+                        message: consensus_error.to_string(),
+                        data: check_validation_result_with_data!(consensus_error
+                            .serialize_to_bytes_with_platform_version(platform_version)),
+                    })
+                } else {
+                    None
+                };
+
+                let fee_estimate = estimate_result.data.map(|fee_estimate| {
+                    estimate_state_transition_fee_response::FeeEstimate {
+                        storage_fee: fee_estimate.fee_result.storage_fee,
+                        processing_fee: fee_estimate.fee_result.processing_fee,
+                        document_type_fees: fee_estimate.fee_result.document_type_fees,
+                        storage_fee_per_epoch: fee_estimate
+                            .storage_fee_per_epoch
+                            .into_iter()
+                            .map(|(epoch_index, amount)| {
+                                estimate_state_transition_fee_response::EpochStorageFee {
+                                    epoch_index: epoch_index as u32,
+                                    amount,
+                                }
+                            })
+                            .collect(),
+                        refunds: fee_estimate
+                            .refunds_per_identity
+                            .into_iter()
+                            .map(|(identity_id, amount)| {
+                                estimate_state_transition_fee_response::IdentityRefund {
+                                    identity_id: identity_id.to_vec(),
+                                    amount,
+                                }
+                            })
+                            .collect(),
+                        balance: fee_estimate.balance,
+                        resulting_balance: fee_estimate.resulting_balance,
+                    }
+                });
+
+                let response_data = EstimateStateTransitionFeeResponse {
+                    fee_estimate,
+                    error,
+                    metadata: Some(metadata),
+                }
+                .encode_to_vec();
+                Ok(QueryValidationResult::new_with_data(response_data))
+            }
             "/identity/by-public-key-hash" => {
                 let GetIdentityByPublicKeyHashesRequest {
                     public_key_hash,
@@ -1297,4 +1876,420 @@ mod test {
             }
         }
     }
+
+    pub mod query_domain_canonical_owner {
+        use crate::config::PlatformConfig;
+        use crate::test::helpers::setup::TestPlatformBuilder;
+        use dapi_grpc::platform::v0::{
+            get_domain_canonical_owner_response, GetDomainCanonicalOwnerRequest,
+            GetDomainCanonicalOwnerResponse,
+        };
+        use dpp::system_data_contracts::dpns_contract;
+        use dpp::version::PlatformVersion;
+        use prost::Message;
+
+        fn query_owner_id(label: &str) -> Vec<u8> {
+            let platform_version = PlatformVersion::latest();
+
+            // Label skeletons are stored since the second version of the DPNS contract
+            let platform = TestPlatformBuilder::new()
+                .with_config(PlatformConfig {
+                    initial_protocol_version: 2,
+                    ..Default::default()
+                })
+                .build_with_mock_rpc()
+                .set_genesis_state();
+
+            let request = GetDomainCanonicalOwnerRequest {
+                label: label.to_string(),
+                normalized_parent_domain_name: "".to_string(),
+                prove: false,
+            };
+
+            let result = platform
+                .query_v0(
+                    "/domain/canonicalOwner",
+                    &request.encode_to_vec(),
+                    platform_version,
+                )
+                .expect("expected to run query");
+
+            assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+            let response = GetDomainCanonicalOwnerResponse::decode(
+                result.data.expect("expected data").as_slice(),
+            )
+            .expect("expected to decode response");
+
+            match response.result {
+                Some(get_domain_canonical_owner_response::Result::OwnerId(owner_id)) => owner_id,
+                _ => panic!("expected an owner id"),
+            }
+        }
+
+        #[test]
+        pub fn should_return_owner_of_domain_confusable_with_label() {
+            // "clash" and "DASH" are both confusable with the "dash" top level domain
+            assert_eq!(query_owner_id("clash"), dpns_contract::OWNER_ID.to_vec());
+            assert_eq!(query_owner_id("DASH"), dpns_contract::OWNER_ID.to_vec());
+        }
+
+        #[test]
+        pub fn should_return_empty_owner_if_no_domain_is_confusable_with_label() {
+            assert!(query_owner_id("alice").is_empty());
+        }
+    }
+
+    pub mod query_identity_withdrawals {
+        use crate::test::helpers::setup::TestPlatformBuilder;
+        use dapi_grpc::platform::v0::get_identity_withdrawals_response::withdrawal::Status;
+        use dapi_grpc::platform::v0::{
+            get_identity_withdrawals_response, GetIdentityWithdrawalsRequest,
+            GetIdentityWithdrawalsResponse,
+        };
+        use dpp::data_contract::accessors::v0::DataContractV0Getters;
+        use dpp::identity::core_script::CoreScript;
+        use dpp::platform_value::platform_value;
+        use dpp::prelude::Identifier;
+        use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+        use dpp::system_data_contracts::{
+            load_system_data_contract, withdrawals_contract, SystemDataContract,
+        };
+        use dpp::tests::fixtures::get_withdrawal_document_fixture;
+        use dpp::version::PlatformVersion;
+        use dpp::withdrawal::Pooling;
+        use drive::drive::identity::withdrawals::NUMBER_OF_BLOCKS_BEFORE_EXPIRED;
+        use drive::tests::helpers::setup::setup_document;
+        use prost::Message;
+
+        #[test]
+        pub fn should_return_withdrawals_of_identity() {
+            let platform_version = PlatformVersion::latest();
+
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_genesis_state();
+
+            let data_contract = load_system_data_contract(
+                SystemDataContract::Withdrawals,
+                platform_version.protocol_version,
+            )
+            .expect("to load system data contract");
+
+            let document_type = data_contract
+                .document_type_for_name(withdrawal::NAME)
+                .expect("expected to get document type");
+
+            let owner_id = Identifier::new([1u8; 32]);
+
+            let document = get_withdrawal_document_fixture(
+                &data_contract,
+                owner_id,
+                platform_value!({
+                    "amount": 1000u64,
+                    "coreFeePerByte": 1u32,
+                    "pooling": Pooling::Never,
+                    "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                    "status": withdrawals_contract::WithdrawalStatus::BROADCASTED as u8,
+                    "transactionIndex": 1u64,
+                    "transactionSignHeight": 93u64,
+                    "transactionId": Identifier::new([2u8; 32]),
+                }),
+                None,
+                platform_version.protocol_version,
+            )
+            .expect("expected withdrawal document");
+
+            setup_document(
+                &platform.drive,
+                &document,
+                &data_contract,
+                document_type,
+                None,
+            );
+
+            let request = GetIdentityWithdrawalsRequest {
+                identity_id: owner_id.to_vec(),
+                limit: None,
+                start_after: vec![],
+                prove: false,
+            };
+
+            let result = platform
+                .query_v0(
+                    "/identity/withdrawals",
+                    &request.encode_to_vec(),
+                    platform_version,
+                )
+                .expect("expected to run query");
+
+            assert!(result.errors.is_empty(), "{:?}", result.errors);
+
+            let response = GetIdentityWithdrawalsResponse::decode(
+                result.data.expect("expected data").as_slice(),
+            )
+            .expect("expected to decode response");
+
+            let Some(get_identity_withdrawals_response::Result::Withdrawals(withdrawals)) =
+                response.result
+            else {
+                panic!("expected withdrawals");
+            };
+
+            assert_eq!(withdrawals.withdrawals.len(), 1);
+
+            let withdrawal = &withdrawals.withdrawals[0];
+
+            assert_eq!(withdrawal.amount, 1000);
+            assert_eq!(withdrawal.status(), Status::Broadcasted);
+            assert_eq!(withdrawal.transaction_id, vec![2u8; 32]);
+            assert_eq!(withdrawal.transaction_sign_height, 93);
+            assert_eq!(
+                withdrawal.expected_completion_core_height,
+                93 + NUMBER_OF_BLOCKS_BEFORE_EXPIRED
+            );
+            assert_eq!(withdrawal.refunded_at_ms, 0);
+        }
+    }
+
+    pub mod estimate_state_transition_fee {
+        use crate::error::query::QueryError;
+        use crate::rpc::core::MockCoreRPCLike;
+        use crate::test::helpers::setup::{TempPlatform, TestPlatformBuilder};
+        use crate::test::helpers::signer::SimpleSigner;
+        use dapi_grpc::platform::v0::{
+            EstimateStateTransitionFeeRequest, EstimateStateTransitionFeeResponse,
+        };
+        use dpp::block::block_info::BlockInfo;
+        use dpp::consensus::state::state_error::StateError;
+        use dpp::consensus::ConsensusError;
+        use dpp::data_contract::accessors::v0::DataContractV0Getters;
+        use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+        use dpp::errors::consensus::codes::ErrorWithCode;
+        use dpp::fee::Credits;
+        use dpp::identity::{Identity, IdentityPublicKey, IdentityV0};
+        use dpp::platform_value::{BinaryData, Bytes32};
+        use dpp::prelude::Identifier;
+        use dpp::serialization::{PlatformDeserializable, PlatformSerializable};
+        use dpp::state_transition::documents_batch_transition::methods::v0::DocumentsBatchTransitionMethodsV0;
+        use dpp::state_transition::documents_batch_transition::DocumentsBatchTransition;
+        use dpp::tests::fixtures::get_data_contract_fixture;
+        use dpp::version::PlatformVersion;
+        use prost::Message;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use std::collections::BTreeMap;
+
+        /// Registers an identity with the given balance and a contract, and prepares an
+        /// unsigned documents batch creating a document on behalf of the identity
+        fn setup_unsigned_documents_batch(
+            balance: Credits,
+        ) -> (TempPlatform<MockCoreRPCLike>, Vec<u8>) {
+            let platform_version = PlatformVersion::latest();
+
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_genesis_state();
+
+            let mut rng = StdRng::seed_from_u64(45);
+
+            let mut signer = SimpleSigner::default();
+
+            let (key, private_key) =
+                IdentityPublicKey::random_ecdsa_critical_level_authentication_key(
+                    1,
+                    Some(46),
+                    platform_version,
+                )
+                .expect("expected to get key pair");
+
+            signer.add_key(key.clone(), private_key);
+
+            let identity_id = Identifier::new([1; 32]);
+
+            let identity: Identity = IdentityV0 {
+                id: identity_id,
+                public_keys: BTreeMap::from([(1, key.clone())]),
+                balance,
+                revision: 0,
+            }
+            .into();
+
+            platform
+                .drive
+                .add_new_identity(
+                    identity,
+                    &BlockInfo::default(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add identity");
+
+            let data_contract =
+                get_data_contract_fixture(Some(identity_id), platform_version.protocol_version)
+                    .data_contract_owned();
+
+            platform
+                .drive
+                .apply_contract(
+                    &data_contract,
+                    BlockInfo::default(),
+                    true,
+                    None,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to apply contract");
+
+            let document_type = data_contract
+                .document_type_for_name("niceDocument")
+                .expect("expected a nice document type");
+
+            let entropy = Bytes32::random_with_rng(&mut rng);
+
+            let document = document_type
+                .random_document_with_identifier_and_entropy(
+                    &mut rng,
+                    identity_id,
+                    entropy,
+                    platform_version,
+                )
+                .expect("expected a random document");
+
+            let mut documents_batch_transition =
+                DocumentsBatchTransition::new_document_creation_transition_from_document(
+                    document,
+                    document_type,
+                    entropy.0,
+                    &key,
+                    &signer,
+                    platform_version,
+                    None,
+                    None,
+                    None,
+                )
+                .expect("expected to create documents batch transition");
+
+            documents_batch_transition.set_signature(BinaryData::default());
+
+            let documents_batch_transition = documents_batch_transition
+                .serialize_to_bytes()
+                .expect("expected to serialize documents batch transition");
+
+            (platform, documents_batch_transition)
+        }
+
+        #[test]
+        pub fn should_estimate_fees_of_unsigned_documents_batch() {
+            let platform_version = PlatformVersion::latest();
+
+            let balance = 100_000_000_000;
+
+            let (platform, state_transition) = setup_unsigned_documents_batch(balance);
+
+            let request = EstimateStateTransitionFeeRequest { state_transition };
+
+            let result = platform
+                .query_v0("/estimateFee", &request.encode_to_vec(), platform_version)
+                .expect("expected to run query");
+
+            assert!(result.errors.is_empty());
+
+            let response = EstimateStateTransitionFeeResponse::decode(
+                result.data.expect("expected data").as_slice(),
+            )
+            .expect("expected to decode response");
+
+            assert!(response.error.is_none());
+
+            let fee_estimate = response.fee_estimate.expect("expected a fee estimate");
+
+            assert!(fee_estimate.storage_fee > 0);
+            assert!(fee_estimate.processing_fee > 0);
+            assert!(
+                fee_estimate
+                    .storage_fee_per_epoch
+                    .iter()
+                    .map(|epoch_storage_fee| epoch_storage_fee.amount)
+                    .sum::<Credits>()
+                    <= fee_estimate.storage_fee
+            );
+            assert!(fee_estimate.refunds.is_empty());
+            assert_eq!(fee_estimate.balance, Some(balance));
+            assert_eq!(
+                fee_estimate.resulting_balance,
+                Some(
+                    balance as i64
+                        - fee_estimate.storage_fee as i64
+                        - fee_estimate.processing_fee as i64
+                )
+            );
+        }
+
+        #[test]
+        pub fn should_return_estimate_with_error_if_balance_is_insufficient() {
+            let platform_version = PlatformVersion::latest();
+
+            let balance = 1;
+
+            let (platform, state_transition) = setup_unsigned_documents_batch(balance);
+
+            let request = EstimateStateTransitionFeeRequest { state_transition };
+
+            let result = platform
+                .query_v0("/estimateFee", &request.encode_to_vec(), platform_version)
+                .expect("expected to run query");
+
+            assert!(result.errors.is_empty());
+
+            let response = EstimateStateTransitionFeeResponse::decode(
+                result.data.expect("expected data").as_slice(),
+            )
+            .expect("expected to decode response");
+
+            let fee_estimate = response.fee_estimate.expect("expected a fee estimate");
+
+            assert!(fee_estimate.storage_fee > 0);
+            assert!(fee_estimate.processing_fee > 0);
+            assert_eq!(fee_estimate.balance, Some(balance));
+            assert!(fee_estimate
+                .resulting_balance
+                .is_some_and(|resulting_balance| resulting_balance < 0));
+
+            let error = response.error.expect("expected an error");
+
+            let consensus_error = ConsensusError::deserialize_from_bytes(error.data.as_slice())
+                .expect("expected to deserialize consensus error");
+
+            assert_eq!(error.code, consensus_error.code());
+            assert!(matches!(
+                consensus_error,
+                ConsensusError::StateError(StateError::IdentityInsufficientBalanceError(_))
+            ));
+        }
+
+        #[test]
+        pub fn should_return_error_if_state_transition_is_not_decodable() {
+            let platform_version = PlatformVersion::latest();
+
+            let platform = TestPlatformBuilder::new()
+                .build_with_mock_rpc()
+                .set_genesis_state();
+
+            let request = EstimateStateTransitionFeeRequest {
+                state_transition: vec![0xff; 8],
+            };
+
+            let result = platform
+                .query_v0("/estimateFee", &request.encode_to_vec(), platform_version)
+                .expect("expected to run query");
+
+            assert!(matches!(
+                result.errors.as_slice(),
+                [QueryError::Protocol(_)]
+            ));
+            assert!(result.data.is_none());
+        }
+    }
 }
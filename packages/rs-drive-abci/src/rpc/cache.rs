@@ -0,0 +1,359 @@
+use crate::rpc::core::{CoreHeight, CoreRPCLike};
+use dashcore_rpc::dashcore::ephemerealdata::chain_lock::ChainLock;
+use dashcore_rpc::dashcore::{Block, BlockHash, QuorumHash, Transaction, Txid};
+use dashcore_rpc::dashcore_rpc_json::{
+    ExtendedQuorumListResult, GetChainTipsResult, MasternodeListDiff, MnSyncStatus,
+    QuorumInfoResult, QuorumType, SoftforkInfo,
+};
+use dashcore_rpc::json::GetTransactionResult;
+use dashcore_rpc::Error;
+use dpp::dashcore::consensus::serialize;
+use dpp::dashcore::InstantLock;
+use indexmap::IndexMap;
+use serde_json::Value;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tenderdash_abci::proto::types::CoreChainLock;
+
+/// Default maximum number of cached responses per Core RPC method
+pub const DEFAULT_CORE_RPC_CACHE_SIZE: usize = 1000;
+
+type QuorumInfoKey = (QuorumType, QuorumHash, Option<bool>);
+type ProTxDiffKey = (Option<CoreHeight>, CoreHeight);
+type InstantLockKey = (Txid, Vec<u8>, Option<CoreHeight>);
+
+/// Number of cache hits and misses since the cache was created
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CoreRPCCacheStats {
+    /// Requests answered from the cache
+    pub hits: u64,
+    /// Requests forwarded to Core
+    pub misses: u64,
+}
+
+/// Responses of Core RPC methods which are deterministic for a given core height or quorum hash
+#[derive(Debug, Default)]
+struct CachedResponses {
+    quorum_lists: IndexMap<CoreHeight, ExtendedQuorumListResult>,
+    quorum_infos: IndexMap<QuorumInfoKey, QuorumInfoResult>,
+    protx_diffs: IndexMap<ProTxDiffKey, MasternodeListDiff>,
+    instant_lock_verifications: IndexMap<InstantLockKey, bool>,
+}
+
+/// Storage shared between [CachedCoreRPC] and the chain lock listener invalidating it
+#[derive(Debug)]
+pub struct CoreRPCCache {
+    responses: Mutex<CachedResponses>,
+    max_entries: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CoreRPCCache {
+    /// Create an empty cache keeping at most `max_entries` responses per method.
+    /// Zero disables caching.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            responses: Mutex::new(CachedResponses::default()),
+            max_entries,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns hit and miss counters
+    pub fn stats(&self) -> CoreRPCCacheStats {
+        CoreRPCCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Drop all cached responses
+    pub fn clear(&self) {
+        *self.responses.lock().unwrap() = CachedResponses::default();
+    }
+
+    /// Drop responses that can change once Core receives a new chain lock.
+    ///
+    /// Responses for heights above the chain locked height may belong to a fork that was
+    /// just abandoned, and instant lock verifications without a max height were done against
+    /// quorums active at the previous tip. Quorum info is keyed by quorum hash and never changes.
+    pub fn invalidate_on_chain_lock(&self, chain_locked_height: Option<CoreHeight>) {
+        let mut responses = self.responses.lock().unwrap();
+
+        responses
+            .instant_lock_verifications
+            .retain(
+                |(_, _, max_height), _| match (max_height, chain_locked_height) {
+                    (Some(max_height), Some(chain_locked_height)) => {
+                        *max_height <= chain_locked_height
+                    }
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                },
+            );
+
+        if let Some(chain_locked_height) = chain_locked_height {
+            responses
+                .quorum_lists
+                .retain(|height, _| *height <= chain_locked_height);
+            responses
+                .protx_diffs
+                .retain(|(_, block), _| *block <= chain_locked_height);
+        }
+
+        tracing::trace!(
+            ?chain_locked_height,
+            "core rpc cache invalidated on chain lock"
+        );
+    }
+
+    fn get_or_fetch<K, V, F>(
+        &self,
+        method: &'static str,
+        select: fn(&mut CachedResponses) -> &mut IndexMap<K, V>,
+        key: K,
+        fetch: F,
+    ) -> Result<V, Error>
+    where
+        K: Hash + Eq,
+        V: Clone,
+        F: FnOnce() -> Result<V, Error>,
+    {
+        if self.max_entries == 0 {
+            return fetch();
+        }
+
+        if let Some(value) = select(&mut self.responses.lock().unwrap()).get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            crate::metrics::core_rpc_cache_request(method, true);
+
+            return Ok(value.clone());
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        crate::metrics::core_rpc_cache_request(method, false);
+
+        // We don't hold the lock while waiting for Core
+        let value = fetch()?;
+
+        let mut responses = self.responses.lock().unwrap();
+        let entries = select(&mut responses);
+        while entries.len() >= self.max_entries {
+            entries.shift_remove_index(0);
+        }
+        entries.insert(key, value.clone());
+
+        Ok(value)
+    }
+}
+
+/// Core RPC decorator that caches responses which don't change for a given core height
+/// or quorum hash, so block processing doesn't wait for Core on every call
+#[derive(Debug)]
+pub struct CachedCoreRPC<C> {
+    inner: C,
+    cache: Arc<CoreRPCCache>,
+}
+
+impl<C: CoreRPCLike> CachedCoreRPC<C> {
+    /// Wrap Core RPC client with a cache keeping at most `max_entries` responses per method
+    pub fn new(inner: C, max_entries: usize) -> Self {
+        Self {
+            inner,
+            cache: Arc::new(CoreRPCCache::new(max_entries)),
+        }
+    }
+
+    /// Returns the cache storage, so it can be invalidated from other threads
+    pub fn cache(&self) -> Arc<CoreRPCCache> {
+        Arc::clone(&self.cache)
+    }
+
+    /// Returns hit and miss counters
+    pub fn stats(&self) -> CoreRPCCacheStats {
+        self.cache.stats()
+    }
+}
+
+impl<C: CoreRPCLike> CoreRPCLike for CachedCoreRPC<C> {
+    fn get_block_hash(&self, height: CoreHeight) -> Result<BlockHash, Error> {
+        self.inner.get_block_hash(height)
+    }
+
+    fn get_best_chain_lock(&self) -> Result<CoreChainLock, Error> {
+        self.inner.get_best_chain_lock()
+    }
+
+    fn get_transaction(&self, tx_id: &Txid) -> Result<Transaction, Error> {
+        self.inner.get_transaction(tx_id)
+    }
+
+    fn get_transaction_extended_info(&self, tx_id: &Txid) -> Result<GetTransactionResult, Error> {
+        self.inner.get_transaction_extended_info(tx_id)
+    }
+
+    fn get_fork_info(&self, name: &str) -> Result<Option<SoftforkInfo>, Error> {
+        self.inner.get_fork_info(name)
+    }
+
+    fn get_block(&self, block_hash: &BlockHash) -> Result<Block, Error> {
+        self.inner.get_block(block_hash)
+    }
+
+    fn get_block_json(&self, block_hash: &BlockHash) -> Result<Value, Error> {
+        self.inner.get_block_json(block_hash)
+    }
+
+    fn get_chain_tips(&self) -> Result<GetChainTipsResult, Error> {
+        self.inner.get_chain_tips()
+    }
+
+    fn get_quorum_listextended(
+        &self,
+        height: Option<CoreHeight>,
+    ) -> Result<ExtendedQuorumListResult, Error> {
+        // Without a height the result depends on the current tip
+        let Some(height) = height else {
+            return self.inner.get_quorum_listextended(None);
+        };
+
+        self.cache.get_or_fetch(
+            "get_quorum_listextended",
+            |responses| &mut responses.quorum_lists,
+            height,
+            || self.inner.get_quorum_listextended(Some(height)),
+        )
+    }
+
+    fn get_quorum_info(
+        &self,
+        quorum_type: QuorumType,
+        hash: &QuorumHash,
+        include_secret_key_share: Option<bool>,
+    ) -> Result<QuorumInfoResult, Error> {
+        self.cache.get_or_fetch(
+            "get_quorum_info",
+            |responses| &mut responses.quorum_infos,
+            (quorum_type, *hash, include_secret_key_share),
+            || {
+                self.inner
+                    .get_quorum_info(quorum_type, hash, include_secret_key_share)
+            },
+        )
+    }
+
+    fn get_protx_diff_with_masternodes(
+        &self,
+        base_block: Option<u32>,
+        block: u32,
+    ) -> Result<MasternodeListDiff, Error> {
+        self.cache.get_or_fetch(
+            "get_protx_diff_with_masternodes",
+            |responses| &mut responses.protx_diffs,
+            (base_block, block),
+            || {
+                self.inner
+                    .get_protx_diff_with_masternodes(base_block, block)
+            },
+        )
+    }
+
+    fn verify_instant_lock(
+        &self,
+        instant_lock: &InstantLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, Error> {
+        self.cache.get_or_fetch(
+            "verify_instant_lock",
+            |responses| &mut responses.instant_lock_verifications,
+            (
+                instant_lock.txid,
+                serialize(&instant_lock.signature),
+                max_height,
+            ),
+            || self.inner.verify_instant_lock(instant_lock, max_height),
+        )
+    }
+
+    fn verify_chain_lock(
+        &self,
+        chain_lock: &ChainLock,
+        max_height: Option<u32>,
+    ) -> Result<bool, Error> {
+        self.inner.verify_chain_lock(chain_lock, max_height)
+    }
+
+    fn masternode_sync_status(&self) -> Result<MnSyncStatus, Error> {
+        self.inner.masternode_sync_status()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rpc::core::MockCoreRPCLike;
+    use std::collections::HashMap;
+
+    fn empty_quorum_list() -> ExtendedQuorumListResult {
+        ExtendedQuorumListResult {
+            quorums_by_type: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn should_serve_repeated_quorum_list_requests_from_cache() {
+        let mut core_rpc = MockCoreRPCLike::new();
+        core_rpc
+            .expect_get_quorum_listextended()
+            .times(1)
+            .returning(|_| Ok(empty_quorum_list()));
+
+        let cached_core_rpc = CachedCoreRPC::new(core_rpc, DEFAULT_CORE_RPC_CACHE_SIZE);
+
+        cached_core_rpc
+            .get_quorum_listextended(Some(10))
+            .expect("expected quorum list");
+        cached_core_rpc
+            .get_quorum_listextended(Some(10))
+            .expect("expected quorum list");
+
+        assert_eq!(
+            cached_core_rpc.stats(),
+            CoreRPCCacheStats { hits: 1, misses: 1 }
+        );
+    }
+
+    #[test]
+    fn should_drop_responses_above_chain_locked_height() {
+        let mut core_rpc = MockCoreRPCLike::new();
+        core_rpc
+            .expect_get_quorum_listextended()
+            .times(3)
+            .returning(|_| Ok(empty_quorum_list()));
+
+        let cached_core_rpc = CachedCoreRPC::new(core_rpc, DEFAULT_CORE_RPC_CACHE_SIZE);
+
+        for height in [5, 10] {
+            cached_core_rpc
+                .get_quorum_listextended(Some(height))
+                .expect("expected quorum list");
+        }
+
+        cached_core_rpc.cache().invalidate_on_chain_lock(Some(7));
+
+        // Height 5 is still cached, height 10 is fetched again
+        for height in [5, 10] {
+            cached_core_rpc
+                .get_quorum_listextended(Some(height))
+                .expect("expected quorum list");
+        }
+
+        assert_eq!(
+            cached_core_rpc.stats(),
+            CoreRPCCacheStats { hits: 1, misses: 3 }
+        );
+    }
+}
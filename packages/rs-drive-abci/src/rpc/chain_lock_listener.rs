@@ -0,0 +1,77 @@
+use crate::rpc::cache::CoreRPCCache;
+use crate::rpc::core::CoreHeight;
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Core publishes the hash of every newly chain locked block under this topic
+const TOPIC_HASH_CHAIN_LOCK: &[u8] = b"hashchainlock";
+/// Core publishes the chain locked block followed by its CLSIG under this topic
+const TOPIC_RAW_CHAIN_LOCK_SIG: &[u8] = b"rawchainlocksig";
+/// Serialized CLSIG: height (4 bytes), block hash (32 bytes) and BLS signature (96 bytes)
+const CHAIN_LOCK_SIG_SIZE: usize = 4 + 32 + 96;
+/// How long to block on receive before checking for cancellation
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Subscribe to chain lock notifications published by Core over ZMQ
+/// and invalidate the Core RPC cache whenever a new chain lock arrives.
+///
+/// The listener runs in a separate thread until `cancel` is cancelled.
+pub fn start_chain_lock_listener(
+    address: &str,
+    cache: Arc<CoreRPCCache>,
+    cancel: CancellationToken,
+) -> Result<JoinHandle<()>, zmq::Error> {
+    let context = zmq::Context::new();
+    let socket = context.socket(zmq::SUB)?;
+    socket.set_rcvtimeo(RECEIVE_TIMEOUT.as_millis() as i32)?;
+    socket.connect(address)?;
+    socket.set_subscribe(TOPIC_HASH_CHAIN_LOCK)?;
+    socket.set_subscribe(TOPIC_RAW_CHAIN_LOCK_SIG)?;
+
+    tracing::info!(address, "listening to core chain lock notifications");
+
+    let handle = std::thread::spawn(move || {
+        while !cancel.is_cancelled() {
+            match socket.recv_multipart(0) {
+                Ok(message) => handle_message(&message, &cache),
+                Err(zmq::Error::EAGAIN) => continue,
+                Err(error) => {
+                    tracing::error!(?error, "failed to receive core zmq notification");
+
+                    // Cached responses might be stale since we don't know what we missed
+                    cache.clear();
+                    std::thread::sleep(RECEIVE_TIMEOUT);
+                }
+            }
+        }
+
+        tracing::trace!("core chain lock listener stopped");
+    });
+
+    Ok(handle)
+}
+
+fn handle_message(message: &[Vec<u8>], cache: &CoreRPCCache) {
+    let [topic, body, ..] = message else {
+        tracing::warn!("received malformed core zmq notification");
+        return;
+    };
+
+    match topic.as_slice() {
+        TOPIC_RAW_CHAIN_LOCK_SIG => {
+            cache.invalidate_on_chain_lock(chain_locked_height(body));
+        }
+        TOPIC_HASH_CHAIN_LOCK => cache.invalidate_on_chain_lock(None),
+        _ => {}
+    }
+}
+
+/// Read chain locked height from the CLSIG appended to the raw block
+fn chain_locked_height(body: &[u8]) -> Option<CoreHeight> {
+    let offset = body.len().checked_sub(CHAIN_LOCK_SIG_SIZE)?;
+    let height_bytes = body.get(offset..offset + 4)?.try_into().ok()?;
+
+    Some(CoreHeight::from_le_bytes(height_bytes))
+}
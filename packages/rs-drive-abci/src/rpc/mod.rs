@@ -1,2 +1,7 @@
+/// Caching Core RPC decorator
+pub mod cache;
+/// Core chain lock notifications over ZMQ
+#[cfg(feature = "chain-lock-listener")]
+pub mod chain_lock_listener;
 /// Dash Core RPC
 pub mod core;
@@ -34,7 +34,10 @@
 
 use std::ops::{Deref, DerefMut};
 
+#[cfg(any(feature = "mocks", test))]
+use crate::mimic::local_core::LocalCore;
 use crate::platform_types::platform::Platform;
+use crate::rpc::core::CoreRPCLike;
 #[cfg(any(feature = "mocks", test))]
 use crate::rpc::core::MockCoreRPCLike;
 use crate::test::fixture::abci::static_system_identity_public_keys_v0;
@@ -79,6 +82,18 @@ impl TestPlatformBuilder {
         }
     }
 
+    /// Create a new temp platform backed by a simulated Dash Core
+    #[cfg(any(feature = "mocks", test))]
+    pub fn build_with_local_core(self, local_core: LocalCore) -> TempPlatform<LocalCore> {
+        let platform = Platform::open_with_client(self.tempdir.path(), self.config, local_core)
+            .expect("should open Platform successfully");
+
+        TempPlatform {
+            platform,
+            tempdir: self.tempdir,
+        }
+    }
+
     /// Create a new temp platform with a default core rpc
     pub fn build_with_default_rpc(self) -> TempPlatform<DefaultCoreRPC> {
         let platform = Platform::<DefaultCoreRPC>::open(self.tempdir.path(), self.config)
@@ -101,7 +116,7 @@ impl Default for TestPlatformBuilder {
     }
 }
 
-impl TempPlatform<MockCoreRPCLike> {
+impl<C: CoreRPCLike> TempPlatform<C> {
     /// A function which sets initial state structure for Platform.
     pub fn set_initial_state_structure(self) -> Self {
         self.platform
@@ -133,7 +148,9 @@ impl TempPlatform<MockCoreRPCLike> {
 
         self
     }
+}
 
+impl TempPlatform<MockCoreRPCLike> {
     /// Rebuilds Platform from the tempdir as if it was destroyed and restarted
     pub fn open_with_tempdir(tempdir: TempDir, config: PlatformConfig) -> Self {
         let platform = Platform::<MockCoreRPCLike>::open(tempdir.path(), Some(config))
@@ -1,5 +1,6 @@
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
     use tenderdash_abci::proto::types::CoreChainLock;
 
     use crate::execution::run_chain_for_strategy;
@@ -291,4 +292,85 @@ mod tests {
             "No instances found where validator set size got smaller and then bigger again"
         );
     }
+
+    #[test]
+    fn run_chain_rotates_unhealthy_quorum_early() {
+        let strategy = Strategy {
+            contracts_with_updates: vec![],
+            operations: vec![],
+            identities_inserts: Frequency {
+                times_per_block_range: Default::default(),
+                chance_per_block: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            quorum_count: 24,
+            upgrading_info: None,
+            core_height_increase: Frequency {
+                times_per_block_range: 1..2,
+                chance_per_block: None,
+            },
+            proposer_strategy: MasternodeListChangesStrategy {
+                new_hpmns: Default::default(),
+                removed_hpmns: Default::default(),
+                updated_hpmns: Default::default(),
+                banned_hpmns: Frequency {
+                    times_per_block_range: 2..3,
+                    chance_per_block: None,
+                },
+                unbanned_hpmns: Default::default(),
+                changed_ip_hpmns: Default::default(),
+                changed_p2p_port_hpmns: Default::default(),
+                changed_http_port_hpmns: Default::default(),
+                new_masternodes: Default::default(),
+                removed_masternodes: Default::default(),
+                updated_masternodes: Default::default(),
+                banned_masternodes: Default::default(),
+                unbanned_masternodes: Default::default(),
+                changed_ip_masternodes: Default::default(),
+            },
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: false,
+        };
+
+        // Quorums never rotate on schedule during this chain
+        let config = PlatformConfig {
+            verify_sum_trees: true,
+            quorum_size: 10,
+            validator_set_quorum_rotation_block_count: 1000,
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            // Early rotations were introduced in protocol version 2
+            initial_protocol_version: 2,
+            ..Default::default()
+        };
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: 1,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+        let outcome = run_chain_for_strategy(&mut platform, 40, strategy, config, 13);
+
+        // Bans of the current quorum members update the validator set without rotation
+        // until too many of them are banned, then another quorum takes over
+
+        let rotated_quorum_hashes = outcome
+            .validator_set_updates
+            .values()
+            .map(|validator_set_update| validator_set_update.quorum_hash.clone())
+            .collect::<BTreeSet<_>>();
+
+        assert!(rotated_quorum_hashes.len() > 1);
+    }
 }
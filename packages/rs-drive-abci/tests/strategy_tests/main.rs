@@ -80,7 +80,7 @@ mod tests {
     use dpp::tests::json_document::json_document_to_created_contract;
     use dpp::util::hash::hash_to_hex_string;
     use dpp::version::PlatformVersion;
-    use drive_abci::config::PlatformTestConfig;
+    use drive_abci::config::{CreditSupplyCheckConfig, PlatformTestConfig};
     use drive_abci::platform_types::platform_state::v0::PlatformStateV0Methods;
     use drive_abci::rpc::core::QuorumListExtendedInfo;
     use itertools::Itertools;
@@ -334,6 +334,138 @@ mod tests {
         );
     }
 
+    #[test]
+    fn run_chain_halts_when_credits_are_created() {
+        let strategy = Strategy {
+            contracts_with_updates: vec![],
+            operations: vec![],
+            identities_inserts: Frequency {
+                times_per_block_range: 1..2,
+                chance_per_block: None,
+            },
+            total_hpmns: 100,
+            extra_normal_mns: 0,
+            quorum_count: 24,
+            upgrading_info: None,
+            core_height_increase: Frequency {
+                times_per_block_range: Default::default(),
+                chance_per_block: None,
+            },
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: false,
+        };
+        let config = PlatformConfig {
+            verify_sum_trees: true,
+            quorum_size: 100,
+            validator_set_quorum_rotation_block_count: 25,
+            block_spacing_ms: 3000,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            credit_supply_check: CreditSupplyCheckConfig {
+                interval: 0,
+                debug: true,
+            },
+            // The credit supply is verified since protocol version 2
+            initial_protocol_version: 2,
+            ..Default::default()
+        };
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: 10,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+
+        // Credits add up after every block with new identities
+        let ChainExecutionOutcome {
+            abci_app,
+            proposers,
+            quorums,
+            current_quorum_hash,
+            current_proposer_versions,
+            end_time_ms,
+            ..
+        } = run_chain_for_strategy(&mut platform, 10, strategy.clone(), config.clone(), 15);
+
+        let platform = abci_app.platform;
+        let platform_version = PlatformVersion::latest();
+
+        let credit_supply = platform
+            .drive
+            .calculate_credit_supply(None, platform_version)
+            .expect("expected to calculate credit supply");
+
+        assert!(credit_supply
+            .ok()
+            .expect("expected credits not to overflow"));
+
+        // Credits appear out of thin air
+        platform
+            .drive
+            .add_to_system_credits(100, None, platform_version)
+            .expect("expected to add to system credits");
+
+        let block_start = platform
+            .state
+            .read()
+            .unwrap()
+            .last_committed_block_info()
+            .as_ref()
+            .unwrap()
+            .basic_info()
+            .height
+            + 1;
+
+        let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            continue_chain_for_strategy(
+                abci_app,
+                ChainExecutionParameters {
+                    block_start,
+                    core_height_start: 10,
+                    block_count: 1,
+                    proposers,
+                    quorums,
+                    current_quorum_hash,
+                    current_proposer_versions: Some(current_proposer_versions),
+                    current_time_ms: end_time_ms,
+                },
+                strategy,
+                config,
+                StrategyRandomness::SeedEntropy(15),
+            );
+        }))
+        .expect_err("expected the block not to be finalized");
+
+        let message = panic
+            .downcast_ref::<String>()
+            .expect("expected a panic message");
+
+        assert!(message.contains("credit supply invariant violated"));
+
+        // The block isn't committed
+        let last_committed_height = platform
+            .state
+            .read()
+            .unwrap()
+            .last_committed_block_info()
+            .as_ref()
+            .unwrap()
+            .basic_info()
+            .height;
+
+        assert_eq!(last_committed_height, block_start - 1);
+    }
+
     #[test]
     fn run_chain_one_identity_in_solitude() {
         let platform_version = PlatformVersion::latest();
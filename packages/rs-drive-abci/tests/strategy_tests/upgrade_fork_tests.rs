@@ -1,6 +1,13 @@
 #[cfg(test)]
 mod tests {
     use dpp::block::extended_block_info::v0::ExtendedBlockInfoV0Getters;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+    use dpp::document::DocumentV0Getters;
+    use dpp::platform_value::Value;
+    use dpp::system_data_contracts::dpns_contract;
+    use dpp::system_data_contracts::dpns_contract::document_types::domain;
+    use dpp::system_data_contracts::dpns_contract::document_types::domain::properties::LABEL_SKELETON;
     use dpp::version::PlatformVersion;
     use tenderdash_abci::proto::types::CoreChainLock;
 
@@ -10,7 +17,9 @@ mod tests {
         ChainExecutionOutcome, ChainExecutionParameters, Strategy, StrategyRandomness,
         UpgradingInfo,
     };
-    use drive_abci::config::{PlatformConfig, PlatformTestConfig};
+    use drive::drive::document::query::QueryDocumentsOutcomeV0Methods;
+    use drive::query::DriveQuery;
+    use drive_abci::config::{CreditSupplyCheckConfig, PlatformConfig, PlatformTestConfig};
     use drive_abci::platform_types::platform_state::v0::PlatformStateV0Methods;
     use drive_abci::test::helpers::setup::TestPlatformBuilder;
     use platform_version::version::mocks::v2_test::TEST_PROTOCOL_VERSION_2;
@@ -1028,4 +1037,209 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn run_chain_version_upgrade_to_version_2_creates_credit_supply_counters_and_migrates_dpns() {
+        let strategy = Strategy {
+            contracts_with_updates: vec![],
+            operations: vec![],
+            identities_inserts: Frequency {
+                times_per_block_range: 1..2,
+                chance_per_block: Some(0.1),
+            },
+            total_hpmns: 460,
+            extra_normal_mns: 0,
+            quorum_count: 24,
+            upgrading_info: Some(UpgradingInfo {
+                current_protocol_version: 1,
+                proposed_protocol_versions_with_weight: vec![(2, 1)],
+                upgrade_three_quarters_life: 0.1,
+            }),
+            core_height_increase: Frequency {
+                times_per_block_range: Default::default(),
+                chance_per_block: None,
+            },
+            proposer_strategy: Default::default(),
+            rotate_quorums: false,
+            failure_testing: None,
+            query_testing: None,
+            verify_state_transition_results: false,
+        };
+        let twenty_minutes_in_ms = 1000 * 60 * 20;
+        let mut config = PlatformConfig {
+            verify_sum_trees: true,
+            quorum_size: 100,
+            validator_set_quorum_rotation_block_count: 125,
+            block_spacing_ms: twenty_minutes_in_ms,
+            testing_configs: PlatformTestConfig::default_with_no_block_signing(),
+            // The credit supply is verified after every block since protocol version 2
+            credit_supply_check: CreditSupplyCheckConfig {
+                interval: 0,
+                debug: true,
+            },
+            ..Default::default()
+        };
+        let mut platform = TestPlatformBuilder::new()
+            .with_config(config.clone())
+            .build_with_mock_rpc();
+        platform
+            .core_rpc
+            .expect_get_best_chain_lock()
+            .returning(move || {
+                Ok(CoreChainLock {
+                    core_block_height: 10,
+                    core_block_hash: [1; 32].to_vec(),
+                    signature: [2; 96].to_vec(),
+                })
+            });
+        let ChainExecutionOutcome {
+            abci_app,
+            proposers,
+            quorums,
+            current_quorum_hash,
+            current_proposer_versions,
+            end_time_ms,
+            ..
+        } = run_chain_for_strategy(&mut platform, 1300, strategy.clone(), config.clone(), 13);
+
+        let platform = abci_app.platform;
+
+        // Chains started with protocol version 1 don't have the counters
+        let credit_supply = platform
+            .drive
+            .calculate_credit_supply(None, PlatformVersion::first())
+            .expect("expected to calculate credit supply");
+
+        assert_eq!(credit_supply.minted_credits, None);
+        assert_eq!(credit_supply.completed_withdrawals, None);
+
+        let hour_in_ms = 1000 * 60 * 60;
+        config.block_spacing_ms = hour_in_ms;
+
+        // The upgrade is locked in during the first epoch
+        let block_start = platform
+            .state
+            .read()
+            .unwrap()
+            .last_committed_block_info()
+            .as_ref()
+            .unwrap()
+            .basic_info()
+            .height
+            + 1;
+        let ChainExecutionOutcome {
+            abci_app,
+            proposers,
+            quorums,
+            current_quorum_hash,
+            end_time_ms,
+            ..
+        } = continue_chain_for_strategy(
+            abci_app,
+            ChainExecutionParameters {
+                block_start,
+                core_height_start: 1,
+                block_count: 200,
+                proposers,
+                quorums,
+                current_quorum_hash,
+                current_proposer_versions: Some(current_proposer_versions.clone()),
+                current_time_ms: end_time_ms,
+            },
+            strategy.clone(),
+            config.clone(),
+            StrategyRandomness::SeedEntropy(7),
+        );
+
+        // and activated in the second one, credits are verified after every block from then on
+        let block_start = platform
+            .state
+            .read()
+            .unwrap()
+            .last_committed_block_info()
+            .as_ref()
+            .unwrap()
+            .basic_info()
+            .height
+            + 1;
+        let ChainExecutionOutcome { .. } = continue_chain_for_strategy(
+            abci_app,
+            ChainExecutionParameters {
+                block_start,
+                core_height_start: 1,
+                block_count: 400,
+                proposers,
+                quorums,
+                current_quorum_hash,
+                current_proposer_versions: Some(current_proposer_versions),
+                current_time_ms: end_time_ms,
+            },
+            strategy,
+            config,
+            StrategyRandomness::SeedEntropy(18),
+        );
+
+        assert_eq!(
+            platform
+                .state
+                .read()
+                .unwrap()
+                .current_protocol_version_in_consensus(),
+            2
+        );
+
+        let credit_supply = platform
+            .drive
+            .calculate_credit_supply(None, PlatformVersion::get(2).unwrap())
+            .expect("expected to calculate credit supply");
+
+        assert!(credit_supply.minted_credits.is_some());
+        assert_eq!(credit_supply.completed_withdrawals, Some(0));
+        assert!(credit_supply
+            .ok()
+            .expect("expected credits not to overflow"));
+
+        let dpns_contract_fetch_info = platform
+            .drive
+            .get_contract_with_fetch_info(
+                dpns_contract::ID.to_buffer(),
+                false,
+                None,
+                PlatformVersion::get(2).unwrap(),
+            )
+            .expect("expected to fetch dpns contract")
+            .expect("expected dpns contract to exist");
+
+        let dpns_contract = &dpns_contract_fetch_info.contract;
+
+        assert_eq!(dpns_contract.version(), 2);
+
+        let domain_document_type = dpns_contract
+            .document_type_for_name(domain::NAME)
+            .expect("expected domain document type");
+
+        assert!(domain_document_type
+            .properties()
+            .contains_key(LABEL_SKELETON));
+
+        let domains = platform
+            .drive
+            .query_documents(
+                DriveQuery::any_item_query(dpns_contract, domain_document_type),
+                None,
+                false,
+                None,
+                Some(2),
+            )
+            .expect("expected to query domains")
+            .documents_owned();
+
+        let dash_domain = domains.first().expect("expected dash domain");
+
+        assert_eq!(
+            dash_domain.properties().get(LABEL_SKELETON),
+            Some(&Value::Text("dash".to_string()))
+        );
+        assert!(dash_domain.updated_at().is_some());
+    }
 }
@@ -1,4 +1,8 @@
-use dapi_grpc::platform::v0::{get_proofs_request, GetProofsRequest, GetProofsResponse};
+use dapi_grpc::platform::v0::{
+    get_contested_resource_vote_tally_response, get_proofs_request,
+    GetContestedResourceVoteTallyRequest, GetContestedResourceVoteTallyResponse, GetProofsRequest,
+    GetProofsResponse,
+};
 
 use dpp::document::Document;
 use dpp::identity::PartialIdentity;
@@ -11,6 +15,7 @@ use drive_abci::rpc::core::MockCoreRPCLike;
 
 use dpp::data_contract::accessors::v0::DataContractV0Getters;
 use dpp::version::PlatformVersion;
+use dpp::voting::ResourceVoteChoice;
 use drive::state_transition_action::document::documents_batch::document_transition::DocumentTransitionAction;
 use drive::state_transition_action::StateTransitionAction;
 use drive_abci::execution::validation::state_transition::transformer::StateTransitionActionTransformerV0;
@@ -537,6 +542,65 @@ pub(crate) fn verify_state_transitions_were_executed(
 
                 assert!(balance_recipient >= identity_credit_transfer_action.transfer_amount());
             }
+            StateTransitionAction::MasternodeVoteAction(masternode_vote_action) => {
+                let contest_id = masternode_vote_action
+                    .vote_poll()
+                    .unique_id()
+                    .expect("expected a contest id");
+
+                let result = abci_app
+                    .platform
+                    .query(
+                        "/contestedResource/voteTally",
+                        &GetContestedResourceVoteTallyRequest {
+                            contest_id: contest_id.to_vec(),
+                            prove: true,
+                        }
+                        .encode_to_vec(),
+                        platform_version,
+                    )
+                    .expect("expected to query the vote tally");
+                let serialized_vote_tally_response =
+                    result.into_data().expect("expected queries to be valid");
+
+                let GetContestedResourceVoteTallyResponse {
+                    result,
+                    metadata: _,
+                } = GetContestedResourceVoteTallyResponse::decode(
+                    serialized_vote_tally_response.as_slice(),
+                )
+                .expect("expected to decode vote tally response");
+
+                let Some(get_contested_resource_vote_tally_response::Result::Proof(response_proof)) =
+                    result
+                else {
+                    panic!("expected a proof");
+                };
+
+                let (root_hash, vote_tally) = Drive::verify_contested_resource_vote_tally(
+                    &response_proof.grovedb_proof,
+                    contest_id.as_bytes(),
+                    false,
+                    platform_version,
+                )
+                .expect("expected to verify the vote tally");
+
+                assert_eq!(
+                    &root_hash,
+                    expected_root_hash,
+                    "state last block info {:?}",
+                    platform.state.last_committed_block_info()
+                );
+
+                // the contest is resolved once its voting period ended
+                if let Some(vote_tally) = vote_tally {
+                    if let ResourceVoteChoice::TowardsIdentity(contender_id) =
+                        masternode_vote_action.choice()
+                    {
+                        assert!(vote_tally.votes_by_contender.contains_key(&contender_id));
+                    }
+                }
+            }
         }
     }
 
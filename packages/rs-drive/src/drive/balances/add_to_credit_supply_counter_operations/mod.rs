@@ -0,0 +1,69 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::KeyInfoPath;
+
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+
+use std::collections::HashMap;
+
+impl Drive {
+    /// Provides the operations needed to add to one of the credit supply counters
+    ///
+    /// The counters are created with the initial state structure of protocol versions that
+    /// track them, or by the upgrade to protocol version 2 on chains that started before.
+    ///
+    /// # Arguments
+    ///
+    /// * `counter_key` - The misc tree key of the counter, `TOTAL_MINTED_CREDITS_STORAGE_KEY` or `TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY`.
+    /// * `amount` - The amount of credits to be added.
+    /// * `estimated_costs_only_with_layer_info` - An optional mutable reference to a HashMap which contains the estimated costs for each layer information.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used for adding to the counter.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<LowLevelDriveOperation>, Error>` - If successful, returns a vector of `LowLevelDriveOperation`, empty if the counters aren't tracked in this protocol version. If an error occurs during the operation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the version of the Drive is unknown or the counter
+    /// is not found while the counters are tracked.
+    pub fn add_to_credit_supply_counter_operations(
+        &self,
+        counter_key: &[u8; 1],
+        amount: u64,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .balances
+            .add_to_credit_supply_counter_operations
+        {
+            Some(0) => self.add_to_credit_supply_counter_operations_v0(
+                counter_key,
+                amount,
+                estimated_costs_only_with_layer_info,
+                transaction,
+                platform_version,
+            ),
+            Some(version) => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "add_to_credit_supply_counter_operations".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+            // The credit supply counters aren't tracked in this protocol version
+            None => Ok(vec![]),
+        }
+    }
+}
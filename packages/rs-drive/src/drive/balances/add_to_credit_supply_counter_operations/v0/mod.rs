@@ -0,0 +1,65 @@
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use crate::fee::op::LowLevelDriveOperation::GroveOperation;
+
+use dpp::version::PlatformVersion;
+use grovedb::batch::{GroveDbOp, KeyInfoPath};
+use grovedb::Element::Item;
+use grovedb::{EstimatedLayerInformation, TransactionArg};
+use integer_encoding::VarInt;
+use std::collections::HashMap;
+
+impl Drive {
+    /// The operations to add to a credit supply counter
+    pub(super) fn add_to_credit_supply_counter_operations_v0(
+        &self,
+        counter_key: &[u8; 1],
+        amount: u64,
+        estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        let mut drive_operations = vec![];
+        if let Some(estimated_costs_only_with_layer_info) = estimated_costs_only_with_layer_info {
+            Self::add_estimation_costs_for_total_system_credits_update(
+                estimated_costs_only_with_layer_info,
+                &platform_version.drive,
+            )?;
+        }
+        let path_holding_counter = misc_path();
+        let counter = self
+            .grove_get_raw_value_u64_from_encoded_var_vec(
+                (&path_holding_counter).into(),
+                counter_key,
+                DirectQueryType::StatefulDirectQuery,
+                transaction,
+                &mut drive_operations,
+                &platform_version.drive,
+            )?
+            .ok_or_else(|| {
+                Error::Drive(DriveError::CorruptedDriveState(format!(
+                    "credit supply counter {} not found in Platform",
+                    String::from_utf8_lossy(counter_key)
+                )))
+            })?;
+        let new_counter =
+            counter
+                .checked_add(amount)
+                .ok_or(Error::Drive(DriveError::CriticalCorruptedState(
+                    "trying to add an amount that would overflow a credit supply counter",
+                )))?;
+        let replace_op = GroveDbOp::replace_op(
+            misc_path_vec(),
+            counter_key.to_vec(),
+            Item(new_counter.encode_var_vec(), None),
+        );
+        drive_operations.push(GroveOperation(replace_op));
+        Ok(drive_operations)
+    }
+}
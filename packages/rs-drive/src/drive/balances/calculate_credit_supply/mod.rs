@@ -0,0 +1,184 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::balances::credit_supply::CreditSupply;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Calculates credits held by every part of the state that stores them.
+    ///
+    /// Unlike `calculate_total_credits_balance` it splits the pools into epoch pools,
+    /// the storage fee distribution pool and pending refunds, and sums credits of
+    /// withdrawals that are not completed yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - A `TransactionArg` object representing the transaction to read the state from.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CreditSupply)` with credits held by every part of the state.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn calculate_credit_supply(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<CreditSupply, Error> {
+        match platform_version
+            .drive
+            .methods
+            .balances
+            .calculate_credit_supply
+        {
+            0 => self.calculate_credit_supply_v0(transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "calculate_credit_supply".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::DriveOperation::SystemOperation;
+    use crate::drive::batch::SystemOperationType;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use crate::tests::helpers::setup::{setup_document, setup_system_data_contract};
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::identity::accessors::{IdentityGettersV0, IdentitySettersV0};
+    use dpp::identity::core_script::CoreScript;
+    use dpp::identity::Identity;
+    use dpp::platform_value::platform_value;
+    use dpp::system_data_contracts::withdrawals_contract;
+    use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+    use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+    use dpp::tests::fixtures::get_withdrawal_document_fixture;
+    use dpp::version::PlatformVersion;
+    use dpp::withdrawal::Pooling;
+
+    #[test]
+    fn should_calculate_credit_supply_of_initial_state() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let credit_supply = drive
+            .calculate_credit_supply(Some(&transaction), platform_version)
+            .expect("expected to calculate credit supply");
+
+        assert_eq!(credit_supply.total_credits_in_platform, 0);
+        assert_eq!(credit_supply.minted_credits, Some(0));
+        assert_eq!(credit_supply.completed_withdrawals, Some(0));
+        assert!(credit_supply.ok().unwrap());
+
+        drive
+            .add_to_system_credits(100, Some(&transaction), platform_version)
+            .expect("expected to add to system credits");
+
+        let credit_supply = drive
+            .calculate_credit_supply(Some(&transaction), platform_version)
+            .expect("expected to calculate credit supply");
+
+        // Credits were neither minted nor given to anyone
+        assert_eq!(credit_supply.total_credits_in_platform, 100);
+        assert_eq!(credit_supply.pools_difference().unwrap(), 0);
+        assert_eq!(credit_supply.difference().unwrap(), 0);
+        assert_eq!(credit_supply.system_credits_difference().unwrap(), 100);
+        assert!(!credit_supply.ok().unwrap());
+    }
+
+    #[test]
+    fn should_calculate_credit_supply_with_minted_credits_and_withdrawals() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+        let block_info = BlockInfo::default();
+
+        let data_contract = load_system_data_contract(
+            SystemDataContract::Withdrawals,
+            platform_version.protocol_version,
+        )
+        .expect("to load system data contract");
+
+        setup_system_data_contract(&drive, &data_contract, Some(&transaction));
+
+        let mut identity = Identity::random_identity(3, Some(14), platform_version)
+            .expect("expected a random identity");
+
+        identity.set_balance(600);
+
+        let owner_id = identity.id();
+
+        drive
+            .add_new_identity(
+                identity,
+                &block_info,
+                true,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to add an identity");
+
+        // 1000 credits entered Platform, 100 were withdrawn and 300 are being withdrawn
+        drive
+            .apply_drive_operations(
+                vec![
+                    SystemOperation(SystemOperationType::AddToSystemCredits { amount: 600 }),
+                    SystemOperation(SystemOperationType::AddToMintedCredits { amount: 1000 }),
+                    SystemOperation(SystemOperationType::AddToCompletedWithdrawals { amount: 100 }),
+                ],
+                true,
+                &block_info,
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to apply system operations");
+
+        let document = get_withdrawal_document_fixture(
+            &data_contract,
+            owner_id,
+            platform_value!({
+                "amount": 300u64,
+                "coreFeePerByte": 1u32,
+                "pooling": Pooling::Never as u8,
+                "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                "status": withdrawals_contract::WithdrawalStatus::BROADCASTED as u8,
+                "transactionIndex": 1u64,
+            }),
+            None,
+            platform_version.protocol_version,
+        )
+        .expect("expected withdrawal document");
+
+        let document_type = data_contract
+            .document_type_for_name(withdrawal::NAME)
+            .expect("expected to get document type");
+
+        setup_document(
+            &drive,
+            &document,
+            &data_contract,
+            document_type,
+            Some(&transaction),
+        );
+
+        let credit_supply = drive
+            .calculate_credit_supply(Some(&transaction), platform_version)
+            .expect("expected to calculate credit supply");
+
+        assert_eq!(credit_supply.total_credits_in_platform, 600);
+        assert_eq!(credit_supply.pending_withdrawals, 300);
+        assert_eq!(credit_supply.identity_balances, 600);
+        assert_eq!(credit_supply.minted_credits, Some(1000));
+        assert_eq!(credit_supply.completed_withdrawals, Some(100));
+        assert_eq!(credit_supply.expected_total().unwrap(), 900);
+        assert!(credit_supply.ok().unwrap());
+    }
+}
@@ -0,0 +1,255 @@
+use crate::drive::balances::{
+    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+    TOTAL_SYSTEM_CREDITS_STORAGE_KEY,
+};
+use crate::drive::credit_pools::paths::pools_vec_path;
+use crate::drive::document::query::QueryDocumentsOutcomeV0Methods;
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::system::misc_path;
+use crate::drive::{Drive, RootTree};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee_pools::epochs_root_tree_key_constants::{
+    KEY_PENDING_EPOCH_REFUNDS, KEY_STORAGE_FEE_POOL,
+};
+use crate::query::{DriveQuery, InternalClauses, OrderClause, WhereClause, WhereOperator};
+use dpp::balances::credit_supply::CreditSupply;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::DocumentV0Getters;
+use dpp::fee::Credits;
+use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+use dpp::platform_value::Value;
+use dpp::system_data_contracts::withdrawals_contract;
+use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, Query, TransactionArg};
+use grovedb_path::SubtreePath;
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+
+/// How many withdrawal documents are read at once
+const PENDING_WITHDRAWALS_PAGE_SIZE: u16 = 100;
+
+impl Drive {
+    pub(super) fn calculate_credit_supply_v0(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<CreditSupply, Error> {
+        let drive_version = &platform_version.drive;
+        let mut drive_operations = vec![];
+
+        let path_holding_total_credits = misc_path();
+        let total_credits_in_platform = self
+            .grove_get_raw_value_u64_from_encoded_var_vec(
+                (&path_holding_total_credits).into(),
+                TOTAL_SYSTEM_CREDITS_STORAGE_KEY,
+                DirectQueryType::StatefulDirectQuery,
+                transaction,
+                &mut drive_operations,
+                drive_version,
+            )?
+            .ok_or(Error::Drive(DriveError::CriticalCorruptedState(
+                "Credits not found in Platform",
+            )))?;
+
+        // Counters are only present since protocol version 2
+        let minted_credits = self.grove_get_raw_value_u64_from_encoded_var_vec(
+            (&path_holding_total_credits).into(),
+            TOTAL_MINTED_CREDITS_STORAGE_KEY,
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let completed_withdrawals = self.grove_get_raw_value_u64_from_encoded_var_vec(
+            (&path_holding_total_credits).into(),
+            TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY,
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let identity_balances = self.grove_get_sum_tree_total_value(
+            SubtreePath::empty(),
+            Into::<&[u8; 1]>::into(RootTree::Balances),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let total_in_pools = self.grove_get_sum_tree_total_value(
+            SubtreePath::empty(),
+            Into::<&[u8; 1]>::into(RootTree::Pools),
+            DirectQueryType::StatefulDirectQuery,
+            transaction,
+            &mut drive_operations,
+            drive_version,
+        )?;
+
+        let mut query = Query::new();
+
+        query.insert_all();
+
+        let (query_result, _) = self
+            .grove
+            .query_raw(
+                &PathQuery::new_unsized(pools_vec_path(), query),
+                transaction.is_some(),
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        let mut credit_supply = CreditSupply {
+            total_credits_in_platform,
+            identity_balances,
+            total_in_pools,
+            minted_credits,
+            completed_withdrawals,
+            ..Default::default()
+        };
+
+        for (key, element) in query_result.to_key_elements() {
+            match element {
+                Element::SumItem(credits, _) if key.as_slice() == KEY_STORAGE_FEE_POOL => {
+                    credit_supply.storage_pool = credits;
+                }
+                Element::SumTree(_, credits, _) if key.as_slice() == KEY_PENDING_EPOCH_REFUNDS => {
+                    credit_supply.pending_refunds = credits;
+                }
+                // Epoch pools are the only other sum trees in pools
+                Element::SumTree(_, credits, _) => {
+                    credit_supply.epoch_pools = credit_supply
+                        .epoch_pools
+                        .checked_add(credits)
+                        .ok_or(Error::Drive(DriveError::CriticalCorruptedState(
+                            "overflow of credits in epoch pools",
+                        )))?;
+                }
+                _ => {}
+            }
+        }
+
+        credit_supply.pending_withdrawals =
+            self.calculate_pending_withdrawals_v0(transaction, platform_version)?;
+
+        Ok(credit_supply)
+    }
+
+    /// Sums amounts of withdrawals which credits left identity balances
+    /// but are not completed or expired yet
+    fn calculate_pending_withdrawals_v0(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Credits, Error> {
+        let Some(contract_fetch_info) = self
+            .get_contract_with_fetch_info_and_fee(
+                withdrawals_contract::ID.to_buffer(),
+                None,
+                true,
+                transaction,
+                platform_version,
+            )?
+            .1
+        else {
+            // There can't be any withdrawals without the withdrawals contract
+            return Ok(0);
+        };
+
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(withdrawal::NAME)?;
+
+        let mut pending_withdrawals: Credits = 0;
+
+        for status in [
+            withdrawals_contract::WithdrawalStatus::QUEUED,
+            withdrawals_contract::WithdrawalStatus::POOLED,
+            withdrawals_contract::WithdrawalStatus::BROADCASTED,
+        ] {
+            let mut start_at = None;
+
+            loop {
+                let mut equal_clauses = BTreeMap::new();
+
+                equal_clauses.insert(
+                    withdrawal::properties::STATUS.to_string(),
+                    WhereClause {
+                        field: withdrawal::properties::STATUS.to_string(),
+                        operator: WhereOperator::Equal,
+                        value: Value::U8(status as u8),
+                    },
+                );
+
+                let mut order_by = IndexMap::new();
+
+                order_by.insert(
+                    withdrawal::properties::UPDATED_AT.to_string(),
+                    OrderClause {
+                        field: withdrawal::properties::UPDATED_AT.to_string(),
+                        ascending: true,
+                    },
+                );
+
+                let drive_query = DriveQuery {
+                    contract: &contract_fetch_info.contract,
+                    document_type,
+                    internal_clauses: InternalClauses {
+                        primary_key_in_clause: None,
+                        primary_key_equal_clause: None,
+                        in_clause: None,
+                        range_clause: None,
+                        equal_clauses,
+                    },
+                    offset: None,
+                    limit: Some(PENDING_WITHDRAWALS_PAGE_SIZE),
+                    order_by,
+                    start_at,
+                    start_at_included: false,
+                    block_time_ms: None,
+                };
+
+                let documents = self
+                    .query_documents(
+                        drive_query,
+                        None,
+                        false,
+                        transaction,
+                        Some(platform_version.protocol_version),
+                    )?
+                    .documents_owned();
+
+                for document in documents.iter() {
+                    let amount: Credits = document
+                        .properties()
+                        .get_integer(withdrawal::properties::AMOUNT)
+                        .map_err(|_| {
+                            Error::Drive(DriveError::CorruptedSerialization(
+                                "withdrawal document must have an amount",
+                            ))
+                        })?;
+
+                    pending_withdrawals =
+                        pending_withdrawals.checked_add(amount).ok_or(Error::Drive(
+                            DriveError::CriticalCorruptedState("overflow of pending withdrawals"),
+                        ))?;
+                }
+
+                if documents.len() < PENDING_WITHDRAWALS_PAGE_SIZE as usize {
+                    break;
+                }
+
+                start_at = documents.last().map(|document| document.id().to_buffer());
+            }
+        }
+
+        Ok(pending_withdrawals)
+    }
+}
@@ -0,0 +1,117 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::fee::Credits;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Creates the credit supply counters on chains that started before they were tracked
+    ///
+    /// # Arguments
+    ///
+    /// * `minted_credits` - The initial value of the minted credits counter.
+    /// * `completed_withdrawals` - The initial value of the completed withdrawals counter.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used for creating the counters.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - If successful, returns `Ok(())`. If an error occurs during the operation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the version of Platform is unknown.
+    pub fn create_credit_supply_counters(
+        &self,
+        minted_credits: Credits,
+        completed_withdrawals: Credits,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .balances
+            .create_credit_supply_counters
+        {
+            0 => self.create_credit_supply_counters_v0(
+                minted_credits,
+                completed_withdrawals,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "create_credit_supply_counters".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::DriveOperation::SystemOperation;
+    use crate::drive::batch::SystemOperationType;
+    use crate::error::drive::DriveError;
+    use crate::error::Error;
+    use crate::tests::helpers::setup::setup_drive;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn should_add_to_credit_supply_counters_only_once_they_are_created() {
+        let drive = setup_drive(None);
+        let transaction = drive.grove.start_transaction();
+
+        // Chains started with protocol version 1 don't have the counters
+        drive
+            .create_initial_state_structure(Some(&transaction), PlatformVersion::first())
+            .expect("expected to create structure");
+
+        let platform_version = PlatformVersion::latest();
+
+        let result = drive.apply_drive_operations(
+            vec![SystemOperation(SystemOperationType::AddToMintedCredits {
+                amount: 100,
+            })],
+            true,
+            &BlockInfo::default(),
+            Some(&transaction),
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::CorruptedDriveState(_)))
+        ));
+
+        drive
+            .create_credit_supply_counters(1000, 0, Some(&transaction), platform_version)
+            .expect("expected to create credit supply counters");
+
+        drive
+            .apply_drive_operations(
+                vec![
+                    SystemOperation(SystemOperationType::AddToMintedCredits { amount: 100 }),
+                    SystemOperation(SystemOperationType::AddToCompletedWithdrawals { amount: 10 }),
+                ],
+                true,
+                &BlockInfo::default(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("expected to add to credit supply counters");
+
+        let credit_supply = drive
+            .calculate_credit_supply(Some(&transaction), platform_version)
+            .expect("expected to calculate credit supply");
+
+        assert_eq!(credit_supply.minted_credits, Some(1100));
+        assert_eq!(credit_supply.completed_withdrawals, Some(10));
+    }
+}
@@ -0,0 +1,39 @@
+use crate::drive::balances::{
+    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+};
+use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::system::misc_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::fee::Credits;
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+use integer_encoding::VarInt;
+
+impl Drive {
+    pub(super) fn create_credit_supply_counters_v0(
+        &self,
+        minted_credits: Credits,
+        completed_withdrawals: Credits,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let mut batch = GroveDbOpBatch::new();
+
+        batch.add_insert(
+            misc_path_vec(),
+            TOTAL_MINTED_CREDITS_STORAGE_KEY.to_vec(),
+            Element::Item(minted_credits.encode_var_vec(), None),
+        );
+
+        batch.add_insert(
+            misc_path_vec(),
+            TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY.to_vec(),
+            Element::Item(completed_withdrawals.encode_var_vec(), None),
+        );
+
+        self.grove_apply_batch(batch, false, transaction, &platform_version.drive)
+    }
+}
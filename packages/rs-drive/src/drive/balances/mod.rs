@@ -12,6 +12,16 @@ mod add_to_system_credits_operations;
 #[cfg(feature = "full")]
 pub use add_to_system_credits_operations::*;
 
+#[cfg(feature = "full")]
+mod add_to_credit_supply_counter_operations;
+#[cfg(feature = "full")]
+pub use add_to_credit_supply_counter_operations::*;
+
+#[cfg(feature = "full")]
+mod create_credit_supply_counters;
+#[cfg(feature = "full")]
+pub use create_credit_supply_counters::*;
+
 #[cfg(feature = "full")]
 mod remove_from_system_credits;
 #[cfg(feature = "full")]
@@ -27,6 +37,11 @@ mod calculate_total_credits_balance;
 #[cfg(feature = "full")]
 pub use calculate_total_credits_balance::*;
 
+#[cfg(feature = "full")]
+mod calculate_credit_supply;
+#[cfg(feature = "full")]
+pub use calculate_credit_supply::*;
+
 #[cfg(any(feature = "full", feature = "verify"))]
 use crate::drive::RootTree;
 
@@ -34,6 +49,14 @@ use crate::drive::RootTree;
 #[cfg(feature = "full")]
 pub const TOTAL_SYSTEM_CREDITS_STORAGE_KEY: &[u8; 1] = b"D";
 
+/// Total credits that entered Platform from asset locks and Core block rewards storage key
+#[cfg(feature = "full")]
+pub const TOTAL_MINTED_CREDITS_STORAGE_KEY: &[u8; 1] = b"M";
+
+/// Total credits that left Platform with completed withdrawals storage key
+#[cfg(feature = "full")]
+pub const TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY: &[u8; 1] = b"W";
+
 /// The path for all the credits in the system
 #[cfg(feature = "full")]
 pub fn total_credits_path() -> [&'static [u8]; 2] {
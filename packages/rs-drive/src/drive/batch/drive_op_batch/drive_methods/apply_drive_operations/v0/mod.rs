@@ -2,6 +2,7 @@ use crate::drive::batch::DriveOperation;
 
 use crate::drive::Drive;
 use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
 
 use dpp::block::block_info::BlockInfo;
 use dpp::fee::fee_result::FeeResult;
@@ -66,6 +67,11 @@ impl Drive {
             &mut cost_operations,
             &platform_version.drive,
         )?;
+        if apply {
+            crate::metrics::grovedb_operation_cost(
+                &LowLevelDriveOperation::combine_cost_operations(&cost_operations),
+            );
+        }
         Drive::calculate_fee(
             None,
             Some(cost_operations),
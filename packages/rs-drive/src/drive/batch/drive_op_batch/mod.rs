@@ -2,7 +2,9 @@ mod contract;
 mod document;
 mod drive_methods;
 mod identity;
+mod sponsorship;
 mod system;
+mod votes;
 mod withdrawals;
 
 use crate::drive::batch::GroveDbOpBatch;
@@ -18,7 +20,9 @@ pub use document::DocumentOperationType;
 pub use document::DocumentOperationsForContractDocumentType;
 pub use document::UpdateOperationInfo;
 pub use identity::IdentityOperationType;
+pub use sponsorship::SponsorshipOperationType;
 pub use system::SystemOperationType;
+pub use votes::VoteOperationType;
 pub use withdrawals::WithdrawalOperationType;
 
 use grovedb::{EstimatedLayerInformation, TransactionArg};
@@ -65,6 +69,10 @@ pub enum DriveOperation<'a> {
     IdentityOperation(IdentityOperationType),
     /// A system operation
     SystemOperation(SystemOperationType),
+    /// A contested resource vote operation
+    VoteOperation(VoteOperationType),
+    /// A sponsorship spending limit operation
+    SponsorshipOperation(SponsorshipOperationType),
     /// A single low level groveDB operation
     GroveDBOperation(GroveDbOp),
     /// Multiple low level groveDB operations
@@ -125,6 +133,23 @@ impl DriveLowLevelOperationConverter for DriveOperation<'_> {
                     transaction,
                     platform_version,
                 ),
+            DriveOperation::VoteOperation(vote_operation_type) => vote_operation_type
+                .into_low_level_drive_operations(
+                    drive,
+                    estimated_costs_only_with_layer_info,
+                    block_info,
+                    transaction,
+                    platform_version,
+                ),
+            DriveOperation::SponsorshipOperation(sponsorship_operation_type) => {
+                sponsorship_operation_type.into_low_level_drive_operations(
+                    drive,
+                    estimated_costs_only_with_layer_info,
+                    block_info,
+                    transaction,
+                    platform_version,
+                )
+            }
             DriveOperation::GroveDBOperation(op) => Ok(vec![GroveOperation(op)]),
             DriveOperation::GroveDBOpBatch(operations) => Ok(operations
                 .operations
@@ -0,0 +1,217 @@
+use std::collections::{BTreeMap, HashMap};
+
+use dpp::block::block_info::BlockInfo;
+use dpp::fee::Credits;
+use dpp::identity::sponsorship::SponsorshipSpendingLimit;
+use dpp::platform_value::Identifier;
+use dpp::version::PlatformVersion;
+use grovedb::Element;
+use grovedb::{batch::KeyInfoPath, EstimatedLayerInformation, TransactionArg};
+
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::object_size_info::PathKeyElementInfo;
+use crate::drive::sponsorship::paths::{
+    sponsor_path_vec, sponsorships_path_vec, SPONSORSHIPS_STORAGE_KEY,
+};
+use crate::drive::sponsorship::SponsorshipSpending;
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::error::drive::DriveError;
+use crate::{drive::Drive, error::Error, fee::op::LowLevelDriveOperation};
+
+use super::DriveLowLevelOperationConverter;
+
+/// Operations on sponsorship spending limits
+#[derive(Clone, Debug)]
+pub enum SponsorshipOperationType {
+    /// Sets or removes the spending limits of a sponsor, the spent credits are kept
+    /// when a limit is changed. The last limit wins if a data contract is listed twice
+    SetSpendingLimits {
+        /// the identity id of the sponsor
+        sponsor_id: Identifier,
+        /// the spending limits by data contract, a limit of `None` removes it
+        spending_limits: Vec<SponsorshipSpendingLimit>,
+    },
+    /// Adds credits spent by the sponsor on a sponsored transition, less the storage
+    /// refunds the sponsor received from it. The spent credits never go below zero.
+    /// Nothing is recorded if the sponsor has no spending limit for the contract
+    AddSpentCredits {
+        /// the identity id of the sponsor
+        sponsor_id: Identifier,
+        /// the sponsored data contract
+        data_contract_id: Identifier,
+        /// the credits paid by the sponsor
+        spent_credits: Credits,
+        /// the storage refunds received by the sponsor
+        refunded_credits: Credits,
+    },
+}
+
+impl DriveLowLevelOperationConverter for SponsorshipOperationType {
+    fn into_low_level_drive_operations(
+        self,
+        drive: &Drive,
+        _estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        _block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match self {
+            SponsorshipOperationType::SetSpendingLimits {
+                sponsor_id,
+                spending_limits,
+            } => {
+                let mut drive_operations = vec![];
+
+                if spending_limits.is_empty() {
+                    return Ok(drive_operations);
+                }
+
+                let sponsorships_exist = drive.grove_has_raw(
+                    (&misc_path()).into(),
+                    SPONSORSHIPS_STORAGE_KEY,
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                let sponsor_exists = sponsorships_exist
+                    && drive.grove_has_raw(
+                        sponsorships_path_vec().as_slice().into(),
+                        sponsor_id.as_slice(),
+                        StatefulDirectQuery,
+                        transaction,
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+
+                if !sponsor_exists {
+                    if spending_limits.iter().all(|limit| limit.limit.is_none()) {
+                        // There is nothing to remove
+                        return Ok(drive_operations);
+                    }
+
+                    if !sponsorships_exist {
+                        // The first spending limit creates the sponsorships tree
+                        drive.batch_insert(
+                            PathKeyElementInfo::PathKeyElement::<'_, 0>((
+                                misc_path_vec(),
+                                SPONSORSHIPS_STORAGE_KEY.to_vec(),
+                                Element::empty_tree(),
+                            )),
+                            &mut drive_operations,
+                            &platform_version.drive,
+                        )?;
+                    }
+
+                    drive.batch_insert(
+                        PathKeyElementInfo::PathKeyElement::<'_, 0>((
+                            sponsorships_path_vec(),
+                            sponsor_id.to_vec(),
+                            Element::empty_tree(),
+                        )),
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+                }
+
+                let spending_limits: BTreeMap<Identifier, Option<Credits>> = spending_limits
+                    .into_iter()
+                    .map(|spending_limit| (spending_limit.data_contract_id, spending_limit.limit))
+                    .collect();
+
+                for (data_contract_id, limit) in spending_limits {
+                    let spending = if sponsor_exists {
+                        drive.fetch_sponsorship_spending_operations(
+                            sponsor_id,
+                            data_contract_id,
+                            transaction,
+                            &mut drive_operations,
+                            &platform_version.drive,
+                        )?
+                    } else {
+                        None
+                    };
+
+                    match limit {
+                        Some(limit) => {
+                            let spending = SponsorshipSpending {
+                                limit,
+                                spent: spending.map(|spending| spending.spent).unwrap_or_default(),
+                            };
+
+                            drive.batch_insert(
+                                PathKeyElementInfo::PathKeyElement::<'_, 0>((
+                                    sponsor_path_vec(&sponsor_id.to_buffer()),
+                                    data_contract_id.to_vec(),
+                                    Element::Item(spending.to_bytes(), None),
+                                )),
+                                &mut drive_operations,
+                                &platform_version.drive,
+                            )?;
+                        }
+                        None if spending.is_some() => {
+                            drive.batch_delete(
+                                sponsor_path_vec(&sponsor_id.to_buffer()).as_slice().into(),
+                                data_contract_id.as_slice(),
+                                BatchDeleteApplyType::StatefulBatchDelete {
+                                    is_known_to_be_subtree_with_sum: Some((false, false)),
+                                },
+                                transaction,
+                                &mut drive_operations,
+                                &platform_version.drive,
+                            )?;
+                        }
+                        None => {}
+                    }
+                }
+
+                Ok(drive_operations)
+            }
+            SponsorshipOperationType::AddSpentCredits {
+                sponsor_id,
+                data_contract_id,
+                spent_credits,
+                refunded_credits,
+            } => {
+                let mut drive_operations = vec![];
+
+                let Some(spending) = drive.fetch_sponsorship_spending_operations(
+                    sponsor_id,
+                    data_contract_id,
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?
+                else {
+                    return Ok(drive_operations);
+                };
+
+                let spent = spending
+                    .spent
+                    .checked_add(spent_credits)
+                    .ok_or(Error::Drive(DriveError::CorruptedDriveState(
+                        "sponsorship spent credits overflow".to_string(),
+                    )))?
+                    .saturating_sub(refunded_credits);
+
+                let spending = SponsorshipSpending { spent, ..spending };
+
+                drive.batch_insert(
+                    PathKeyElementInfo::PathKeyElement::<'_, 0>((
+                        sponsor_path_vec(&sponsor_id.to_buffer()),
+                        data_contract_id.to_vec(),
+                        Element::Item(spending.to_bytes(), None),
+                    )),
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                Ok(drive_operations)
+            }
+        }
+    }
+}
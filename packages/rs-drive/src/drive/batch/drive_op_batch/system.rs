@@ -1,8 +1,12 @@
+use crate::drive::balances::{
+    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+};
 use crate::drive::batch::drive_op_batch::DriveLowLevelOperationConverter;
 use crate::drive::Drive;
 use crate::error::Error;
 use crate::fee::op::LowLevelDriveOperation;
 use dpp::block::block_info::BlockInfo;
+use dpp::fee::fee_result::FeeResult;
 use dpp::fee::Credits;
 use dpp::platform_value::Bytes36;
 
@@ -24,11 +28,32 @@ pub enum SystemOperationType {
         /// The amount of credits we are seeking to remove
         amount: Credits,
     },
+    /// We want to count credits that entered the system from asset locks or Core block rewards.
+    AddToMintedCredits {
+        /// The amount of minted credits
+        amount: Credits,
+    },
+    /// We want to count credits that left the system with a completed withdrawal.
+    AddToCompletedWithdrawals {
+        /// The amount of the completed withdrawal
+        amount: Credits,
+    },
     /// Adding a used asset lock
     AddUsedAssetLock {
         /// The asset lock outpoint that should be added
         asset_lock_outpoint: Bytes36,
     },
+    /// We want to charge a fixed processing fee, for example for a name registration.
+    AddFixedProcessingFee {
+        /// The amount of credits that should be charged
+        amount: Credits,
+    },
+    /// We want to charge a document fee declared by a data contract, the credits are transferred
+    /// to the contract owner or to the fee beneficiary instead of going to the epoch pools.
+    AddDocumentTypeFee {
+        /// The amount of credits that should be charged
+        amount: Credits,
+    },
 }
 
 impl DriveLowLevelOperationConverter for SystemOperationType {
@@ -57,6 +82,22 @@ impl DriveLowLevelOperationConverter for SystemOperationType {
                     transaction,
                     platform_version,
                 ),
+            SystemOperationType::AddToMintedCredits { amount } => drive
+                .add_to_credit_supply_counter_operations(
+                    TOTAL_MINTED_CREDITS_STORAGE_KEY,
+                    amount,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
+            SystemOperationType::AddToCompletedWithdrawals { amount } => drive
+                .add_to_credit_supply_counter_operations(
+                    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY,
+                    amount,
+                    estimated_costs_only_with_layer_info,
+                    transaction,
+                    platform_version,
+                ),
             SystemOperationType::AddUsedAssetLock {
                 asset_lock_outpoint,
             } => drive.add_asset_lock_outpoint_operations(
@@ -64,6 +105,16 @@ impl DriveLowLevelOperationConverter for SystemOperationType {
                 estimated_costs_only_with_layer_info,
                 platform_version,
             ),
+            SystemOperationType::AddFixedProcessingFee { amount } => {
+                Ok(vec![LowLevelDriveOperation::PreCalculatedFeeResult(
+                    FeeResult::new_from_processing_fee(amount),
+                )])
+            }
+            SystemOperationType::AddDocumentTypeFee { amount } => {
+                Ok(vec![LowLevelDriveOperation::PreCalculatedFeeResult(
+                    FeeResult::new_from_document_type_fees(amount),
+                )])
+            }
         }
     }
 }
@@ -0,0 +1,519 @@
+use std::collections::HashMap;
+
+use dpp::block::block_info::BlockInfo;
+use dpp::platform_value::Identifier;
+use dpp::serialization::PlatformSerializable;
+use dpp::version::PlatformVersion;
+use dpp::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+use grovedb::Element;
+use grovedb::{batch::KeyInfoPath, EstimatedLayerInformation, TransactionArg};
+
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::BatchDeleteApplyType;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::object_size_info::PathKeyElementInfo;
+use crate::drive::system::{misc_path, misc_path_vec};
+use crate::drive::votes::paths::{
+    contest_choice_tally_path_vec, contest_contenders_path_vec, contest_end_time_key,
+    contest_path_vec, contest_tallies_path_vec, contest_vote_changes_path_vec,
+    contest_votes_path_vec, contested_resources_path_vec, contests_by_end_time_path_vec,
+    contests_path_vec, vote_choice_key, ABSTAIN_VOTE_KEY, CONTESTED_RESOURCES_STORAGE_KEY,
+    CONTESTS_BY_END_TIME_KEY, CONTESTS_KEY, CONTEST_CONTENDERS_KEY, CONTEST_END_TIME_KEY,
+    CONTEST_TALLIES_KEY, CONTEST_VOTES_KEY, CONTEST_VOTE_CHANGES_KEY, CONTEST_VOTE_POLL_KEY,
+};
+use crate::error::drive::DriveError;
+use crate::{drive::Drive, error::Error, fee::op::LowLevelDriveOperation};
+
+use super::DriveLowLevelOperationConverter;
+
+/// Operations on contested resources
+#[derive(Clone, Debug)]
+pub enum VoteOperationType {
+    /// Adds a contender to a contest, the contest is opened if it doesn't exist yet
+    AddContender {
+        /// the contested value
+        vote_poll: ContestedDocumentResourceVotePoll,
+        /// the identity that submitted the document
+        contender_id: Identifier,
+        /// the serialized document
+        serialized_document: Vec<u8>,
+        /// storage flags of the contender, refunds are paid out according to them
+        storage_flags: StorageFlags,
+    },
+    /// Casts or changes the vote of a masternode
+    CastVote {
+        /// the contested value
+        vote_poll: ContestedDocumentResourceVotePoll,
+        /// pro tx hash of the voting masternode
+        pro_tx_hash: Identifier,
+        /// the choice of the masternode
+        choice: ResourceVoteChoice,
+    },
+    /// Removes the contenders, votes and all items of a contest.
+    /// The trees of the contest are removed afterwards with `RemoveContestTrees`
+    RemoveContestEntries {
+        /// the unique id of the contest
+        contest_id: [u8; 32],
+        /// the end of the voting period
+        end_time_ms: u64,
+        /// all contenders of the contest
+        contender_ids: Vec<Identifier>,
+        /// the tally key of the choice of every voter by pro tx hash
+        votes: Vec<(Identifier, Vec<u8>)>,
+        /// the pro tx hashes of voters that changed their vote
+        vote_changes: Vec<Identifier>,
+    },
+    /// Removes the emptied trees of a contest
+    RemoveContestTrees {
+        /// the unique id of the contest
+        contest_id: [u8; 32],
+        /// all contenders of the contest
+        contender_ids: Vec<Identifier>,
+        /// if a voter changed its vote, in which case the vote changes tree exists
+        has_vote_changes: bool,
+    },
+}
+
+impl VoteOperationType {
+    fn insert_element(
+        drive: &Drive,
+        path: Vec<Vec<u8>>,
+        key: Vec<u8>,
+        element: Element,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        drive.batch_insert(
+            PathKeyElementInfo::PathKeyElement::<'_, 0>((path, key, element)),
+            drive_operations,
+            &platform_version.drive,
+        )
+    }
+
+    fn delete_element(
+        drive: &Drive,
+        path: Vec<Vec<u8>>,
+        key: &[u8],
+        is_subtree_with_sum: (bool, bool),
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        drive.batch_delete(
+            path.as_slice().into(),
+            key,
+            BatchDeleteApplyType::StatefulBatchDelete {
+                is_known_to_be_subtree_with_sum: Some(is_subtree_with_sum),
+            },
+            transaction,
+            drive_operations,
+            &platform_version.drive,
+        )
+    }
+}
+
+impl DriveLowLevelOperationConverter for VoteOperationType {
+    fn into_low_level_drive_operations(
+        self,
+        drive: &Drive,
+        _estimated_costs_only_with_layer_info: &mut Option<
+            HashMap<KeyInfoPath, EstimatedLayerInformation>,
+        >,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<LowLevelDriveOperation>, Error> {
+        match self {
+            VoteOperationType::AddContender {
+                vote_poll,
+                contender_id,
+                serialized_document,
+                storage_flags,
+            } => {
+                let mut drive_operations = vec![];
+
+                let contest_id = vote_poll.unique_id()?.to_buffer();
+
+                let contested_resources_exist = drive.grove_has_raw(
+                    (&misc_path()).into(),
+                    CONTESTED_RESOURCES_STORAGE_KEY,
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                if !contested_resources_exist {
+                    // The first contest creates the contested resources tree
+                    Self::insert_element(
+                        drive,
+                        misc_path_vec(),
+                        CONTESTED_RESOURCES_STORAGE_KEY.to_vec(),
+                        Element::empty_tree(),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::insert_element(
+                        drive,
+                        contested_resources_path_vec(),
+                        CONTESTS_BY_END_TIME_KEY.to_vec(),
+                        Element::empty_tree(),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::insert_element(
+                        drive,
+                        contested_resources_path_vec(),
+                        CONTESTS_KEY.to_vec(),
+                        Element::empty_tree(),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                let contest_exists = contested_resources_exist
+                    && drive.grove_has_raw(
+                        contests_path_vec().as_slice().into(),
+                        &contest_id,
+                        StatefulDirectQuery,
+                        transaction,
+                        &mut drive_operations,
+                        &platform_version.drive,
+                    )?;
+
+                if !contest_exists {
+                    let end_time_ms = block_info
+                        .time_ms
+                        .saturating_add(platform_version.drive_abci.voting.voting_period_ms);
+
+                    Self::insert_element(
+                        drive,
+                        contests_path_vec(),
+                        contest_id.to_vec(),
+                        Element::empty_tree(),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::insert_element(
+                        drive,
+                        contest_path_vec(&contest_id),
+                        CONTEST_VOTE_POLL_KEY.to_vec(),
+                        Element::Item(vote_poll.serialize_to_bytes()?, None),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::insert_element(
+                        drive,
+                        contest_path_vec(&contest_id),
+                        CONTEST_END_TIME_KEY.to_vec(),
+                        Element::Item(end_time_ms.to_be_bytes().to_vec(), None),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    for key in [
+                        CONTEST_CONTENDERS_KEY,
+                        CONTEST_TALLIES_KEY,
+                        CONTEST_VOTES_KEY,
+                    ] {
+                        Self::insert_element(
+                            drive,
+                            contest_path_vec(&contest_id),
+                            key.to_vec(),
+                            Element::empty_tree(),
+                            &mut drive_operations,
+                            platform_version,
+                        )?;
+                    }
+
+                    Self::insert_element(
+                        drive,
+                        contest_tallies_path_vec(&contest_id),
+                        ABSTAIN_VOTE_KEY.to_vec(),
+                        Element::empty_sum_tree(),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::insert_element(
+                        drive,
+                        contests_by_end_time_path_vec(),
+                        contest_end_time_key(end_time_ms, &contest_id),
+                        Element::Item(vec![], None),
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                Self::insert_element(
+                    drive,
+                    contest_contenders_path_vec(&contest_id),
+                    contender_id.to_vec(),
+                    Element::Item(serialized_document, storage_flags.to_some_element_flags()),
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+
+                Self::insert_element(
+                    drive,
+                    contest_tallies_path_vec(&contest_id),
+                    contender_id.to_vec(),
+                    Element::empty_sum_tree(),
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+
+                Ok(drive_operations)
+            }
+            VoteOperationType::CastVote {
+                vote_poll,
+                pro_tx_hash,
+                choice,
+            } => {
+                let mut drive_operations = vec![];
+
+                let contest_id = vote_poll.unique_id()?.to_buffer();
+
+                let choice_key = vote_choice_key(&choice);
+
+                let previous_vote = drive.grove_get_raw_optional(
+                    contest_votes_path_vec(&contest_id).as_slice().into(),
+                    pro_tx_hash.as_slice(),
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                match previous_vote {
+                    Some(Element::Item(previous_choice_key, _)) => {
+                        // Votes are free, every vote after the first one is counted so that
+                        // state validation can limit how often a masternode changes its vote
+                        if platform_version
+                            .drive_abci
+                            .voting
+                            .max_vote_changes_per_poll
+                            .is_some()
+                        {
+                            let vote_changes = drive.fetch_masternode_vote_changes(
+                                &contest_id,
+                                pro_tx_hash,
+                                transaction,
+                                &mut drive_operations,
+                                &platform_version.drive,
+                            )?;
+
+                            if vote_changes == 0 {
+                                let vote_changes_exist = drive.grove_has_raw(
+                                    contest_path_vec(&contest_id).as_slice().into(),
+                                    &CONTEST_VOTE_CHANGES_KEY,
+                                    StatefulDirectQuery,
+                                    transaction,
+                                    &mut drive_operations,
+                                    &platform_version.drive,
+                                )?;
+
+                                if !vote_changes_exist {
+                                    Self::insert_element(
+                                        drive,
+                                        contest_path_vec(&contest_id),
+                                        CONTEST_VOTE_CHANGES_KEY.to_vec(),
+                                        Element::empty_tree(),
+                                        &mut drive_operations,
+                                        platform_version,
+                                    )?;
+                                }
+                            }
+
+                            let vote_changes = vote_changes.checked_add(1).ok_or(Error::Drive(
+                                DriveError::CorruptedDriveState(
+                                    "vote changes must not overflow".to_string(),
+                                ),
+                            ))?;
+
+                            Self::insert_element(
+                                drive,
+                                contest_vote_changes_path_vec(&contest_id),
+                                pro_tx_hash.to_vec(),
+                                Element::Item(vote_changes.to_be_bytes().to_vec(), None),
+                                &mut drive_operations,
+                                platform_version,
+                            )?;
+                        }
+
+                        if previous_choice_key == choice_key {
+                            return Ok(drive_operations);
+                        }
+
+                        Self::delete_element(
+                            drive,
+                            contest_choice_tally_path_vec(&contest_id, &previous_choice_key),
+                            pro_tx_hash.as_slice(),
+                            (false, false),
+                            transaction,
+                            &mut drive_operations,
+                            platform_version,
+                        )?;
+                    }
+                    Some(_) => {
+                        return Err(Error::Drive(DriveError::CorruptedElementType(
+                            "vote must be an item",
+                        )))
+                    }
+                    None => {}
+                }
+
+                Self::insert_element(
+                    drive,
+                    contest_choice_tally_path_vec(&contest_id, &choice_key),
+                    pro_tx_hash.to_vec(),
+                    Element::SumItem(1, None),
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+
+                Self::insert_element(
+                    drive,
+                    contest_votes_path_vec(&contest_id),
+                    pro_tx_hash.to_vec(),
+                    Element::Item(choice_key, None),
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+
+                Ok(drive_operations)
+            }
+            VoteOperationType::RemoveContestEntries {
+                contest_id,
+                end_time_ms,
+                contender_ids,
+                votes,
+                vote_changes,
+            } => {
+                let mut drive_operations = vec![];
+
+                for pro_tx_hash in vote_changes {
+                    Self::delete_element(
+                        drive,
+                        contest_vote_changes_path_vec(&contest_id),
+                        pro_tx_hash.as_slice(),
+                        (false, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                for (pro_tx_hash, choice_key) in votes {
+                    Self::delete_element(
+                        drive,
+                        contest_choice_tally_path_vec(&contest_id, &choice_key),
+                        pro_tx_hash.as_slice(),
+                        (false, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+
+                    Self::delete_element(
+                        drive,
+                        contest_votes_path_vec(&contest_id),
+                        pro_tx_hash.as_slice(),
+                        (false, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                // Removing the documents of contenders refunds their storage fees
+                for contender_id in contender_ids {
+                    Self::delete_element(
+                        drive,
+                        contest_contenders_path_vec(&contest_id),
+                        contender_id.as_slice(),
+                        (false, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                for key in [CONTEST_VOTE_POLL_KEY, CONTEST_END_TIME_KEY] {
+                    Self::delete_element(
+                        drive,
+                        contest_path_vec(&contest_id),
+                        &key,
+                        (false, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                Self::delete_element(
+                    drive,
+                    contests_by_end_time_path_vec(),
+                    &contest_end_time_key(end_time_ms, &contest_id),
+                    (false, false),
+                    transaction,
+                    &mut drive_operations,
+                    platform_version,
+                )?;
+
+                Ok(drive_operations)
+            }
+            VoteOperationType::RemoveContestTrees {
+                contest_id,
+                contender_ids,
+                has_vote_changes,
+            } => {
+                let mut drive_operations = vec![];
+
+                let tally_keys = contender_ids
+                    .iter()
+                    .map(|contender_id| contender_id.to_vec())
+                    .chain(std::iter::once(ABSTAIN_VOTE_KEY.to_vec()));
+
+                for tally_key in tally_keys {
+                    Self::delete_element(
+                        drive,
+                        contest_tallies_path_vec(&contest_id),
+                        &tally_key,
+                        (true, true),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                let vote_changes_key = has_vote_changes.then_some(CONTEST_VOTE_CHANGES_KEY);
+
+                for key in [
+                    CONTEST_CONTENDERS_KEY,
+                    CONTEST_TALLIES_KEY,
+                    CONTEST_VOTES_KEY,
+                ]
+                .into_iter()
+                .chain(vote_changes_key)
+                {
+                    Self::delete_element(
+                        drive,
+                        contest_path_vec(&contest_id),
+                        &key,
+                        (true, false),
+                        transaction,
+                        &mut drive_operations,
+                        platform_version,
+                    )?;
+                }
+
+                Ok(drive_operations)
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use dpp::block::block_info::BlockInfo;
+use dpp::fee::Credits;
 use dpp::version::PlatformVersion;
 use grovedb::Element;
 use grovedb::{batch::KeyInfoPath, EstimatedLayerInformation, TransactionArg};
@@ -9,10 +10,13 @@ use crate::drive::grove_operations::BatchDeleteApplyType;
 use crate::drive::identity::withdrawals::paths::{
     get_withdrawal_root_path_vec, get_withdrawal_transactions_expired_ids_path,
     get_withdrawal_transactions_expired_ids_path_vec, get_withdrawal_transactions_queue_path,
-    get_withdrawal_transactions_queue_path_vec, WITHDRAWAL_TRANSACTIONS_COUNTER_ID,
+    get_withdrawal_transactions_queue_path_vec, get_withdrawal_transactions_withdrawn_amounts_path,
+    get_withdrawal_transactions_withdrawn_amounts_path_vec, WITHDRAWAL_TRANSACTIONS_COUNTER_ID,
+    WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS,
 };
 use crate::drive::identity::withdrawals::WithdrawalTransactionIdAndBytes;
 use crate::drive::object_size_info::PathKeyElementInfo;
+use crate::error::drive::DriveError;
 use crate::{drive::Drive, error::Error, fee::op::LowLevelDriveOperation};
 
 use super::DriveLowLevelOperationConverter;
@@ -45,6 +49,20 @@ pub enum WithdrawalOperationType<'a> {
         /// withdrawal transaction tuple with id and bytes
         id: Vec<u8>,
     },
+    /// Insert amount withdrawn at a block time into the rolling window
+    InsertWithdrawnAmount {
+        /// block time
+        time_ms: u64,
+        /// withdrawn amount
+        amount: Credits,
+    },
+    /// Remove amount withdrawn at a block time from the rolling window
+    DeleteWithdrawnAmount {
+        /// block time key
+        key: Vec<u8>,
+    },
+    /// Create the sum tree of the rolling window on chains started before it was introduced
+    InsertWithdrawnAmountsTree,
 }
 
 impl DriveLowLevelOperationConverter for WithdrawalOperationType<'_> {
@@ -153,6 +171,54 @@ impl DriveLowLevelOperationConverter for WithdrawalOperationType<'_> {
 
                 Ok(drive_operations)
             }
+            WithdrawalOperationType::InsertWithdrawnAmount { time_ms, amount } => {
+                let mut drive_operations = vec![];
+
+                let amount: i64 = amount.try_into().map_err(|_| {
+                    Error::Drive(DriveError::CorruptedCodeExecution(
+                        "withdrawn amount must fit into i64",
+                    ))
+                })?;
+
+                let path = get_withdrawal_transactions_withdrawn_amounts_path_vec();
+
+                drive.batch_insert(
+                    PathKeyElementInfo::PathKeyElement::<'_, 1>((
+                        path,
+                        time_ms.to_be_bytes().to_vec(),
+                        Element::SumItem(amount, None),
+                    )),
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                Ok(drive_operations)
+            }
+            WithdrawalOperationType::DeleteWithdrawnAmount { key } => {
+                let mut drive_operations = vec![];
+
+                let path = get_withdrawal_transactions_withdrawn_amounts_path();
+
+                drive.batch_delete(
+                    (&path).into(),
+                    &key,
+                    BatchDeleteApplyType::StatefulBatchDelete {
+                        is_known_to_be_subtree_with_sum: Some((false, false)),
+                    },
+                    transaction,
+                    &mut drive_operations,
+                    &platform_version.drive,
+                )?;
+
+                Ok(drive_operations)
+            }
+            WithdrawalOperationType::InsertWithdrawnAmountsTree => Ok(vec![
+                LowLevelDriveOperation::insert_for_known_path_key_element(
+                    get_withdrawal_root_path_vec(),
+                    WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS.to_vec(),
+                    Element::empty_sum_tree(),
+                ),
+            ]),
         }
     }
 }
@@ -1,11 +1,14 @@
 use crate::drive::batch::transitions::document::DriveHighLevelDocumentOperationConverter;
-use crate::drive::batch::DriveOperation::DocumentOperation;
-use crate::drive::batch::{DocumentOperationType, DriveOperation};
+use crate::drive::batch::DriveOperation::{DocumentOperation, VoteOperation};
+use crate::drive::batch::{DocumentOperationType, DriveOperation, VoteOperationType};
 use crate::drive::flags::StorageFlags;
 use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
 use crate::drive::object_size_info::OwnedDocumentInfo;
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::voting::ContestedDocumentResourceVotePoll;
 
 use dpp::document::Document;
 use dpp::prelude::Identifier;
@@ -19,18 +22,44 @@ impl DriveHighLevelDocumentOperationConverter for DocumentCreateTransitionAction
         self,
         epoch: &Epoch,
         owner_id: Identifier,
+        storage_owner_id: Identifier,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         let data_contract_id = self.base().data_contract_id();
 
         let document_type_name = self.base().document_type_name().clone();
 
+        let data_contract_fetch_info = self.base().data_contract_fetch_info();
+
         let document =
             Document::try_from_owned_create_transition(self, owner_id, platform_version)?;
 
-        let storage_flags = StorageFlags::new_single_epoch(epoch.index, Some(owner_id.to_buffer()));
+        let storage_flags =
+            StorageFlags::new_single_epoch(epoch.index, Some(storage_owner_id.to_buffer()));
+
+        let document_type = data_contract_fetch_info
+            .contract
+            .document_type_for_name(&document_type_name)?;
 
         let mut drive_operations = vec![];
+
+        // A document with a value in a contested index becomes a contender,
+        // it is only inserted once it is awarded to its owner by masternode votes
+        if let Some(vote_poll) = ContestedDocumentResourceVotePoll::for_document(
+            data_contract_id,
+            document_type,
+            &document,
+        ) {
+            drive_operations.push(VoteOperation(VoteOperationType::AddContender {
+                vote_poll,
+                contender_id: owner_id,
+                serialized_document: document.serialize(document_type, platform_version)?,
+                storage_flags,
+            }));
+
+            return Ok(drive_operations);
+        }
+
         drive_operations.push(DocumentOperation(DocumentOperationType::AddDocument {
             owned_document_info: OwnedDocumentInfo {
                 document_info: DocumentOwnedInfo((document, Some(Cow::Owned(storage_flags)))),
@@ -18,6 +18,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentDeleteTransitionAction
         self,
         _epoch: &Epoch,
         _owner_id: Identifier,
+        _storage_owner_id: Identifier,
         _platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         let base = self.base_owned();
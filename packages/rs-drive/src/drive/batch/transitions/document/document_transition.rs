@@ -11,6 +11,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentTransitionAction {
         self,
         epoch: &Epoch,
         owner_id: Identifier,
+        storage_owner_id: Identifier,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         match self {
@@ -18,6 +19,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentTransitionAction {
                 document_create_transition.into_high_level_document_drive_operations(
                     epoch,
                     owner_id,
+                    storage_owner_id,
                     platform_version,
                 )
             }
@@ -25,6 +27,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentTransitionAction {
                 document_replace_transition.into_high_level_document_drive_operations(
                     epoch,
                     owner_id,
+                    storage_owner_id,
                     platform_version,
                 )
             }
@@ -32,6 +35,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentTransitionAction {
                 document_delete_transition.into_high_level_document_drive_operations(
                     epoch,
                     owner_id,
+                    storage_owner_id,
                     platform_version,
                 )
             }
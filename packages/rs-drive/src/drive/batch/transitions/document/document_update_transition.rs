@@ -19,6 +19,7 @@ impl DriveHighLevelDocumentOperationConverter for DocumentReplaceTransitionActio
         self,
         epoch: &Epoch,
         owner_id: Identifier,
+        storage_owner_id: Identifier,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         let data_contract_id = self.base().data_contract_id();
@@ -26,7 +27,8 @@ impl DriveHighLevelDocumentOperationConverter for DocumentReplaceTransitionActio
         let document =
             Document::try_from_owned_replace_transition(self, owner_id, platform_version)?;
 
-        let storage_flags = StorageFlags::new_single_epoch(epoch.index, Some(owner_id.to_buffer()));
+        let storage_flags =
+            StorageFlags::new_single_epoch(epoch.index, Some(storage_owner_id.to_buffer()));
 
         let mut drive_operations = vec![];
         drive_operations.push(DocumentOperation(DocumentOperationType::UpdateDocument {
@@ -13,6 +13,9 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'b>>, Error> {
         let owner_id = self.owner_id();
+        // Storage written by a sponsored batch is attributed to the sponsor,
+        // so that the sponsor that paid for it is the one refunded on removal
+        let storage_owner_id = self.sponsor_id().unwrap_or(owner_id);
         let transitions = self.transitions_owned();
         Ok(transitions
             .into_iter()
@@ -20,6 +23,7 @@ impl DriveHighLevelOperationConverter for DocumentsBatchTransitionAction {
                 transition.into_high_level_document_drive_operations(
                     epoch,
                     owner_id,
+                    storage_owner_id,
                     platform_version,
                 )
             })
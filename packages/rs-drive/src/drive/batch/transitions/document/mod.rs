@@ -12,11 +12,14 @@ mod documents_batch_transition;
 
 /// A converter that will get High Level Drive Operations from State transitions
 pub trait DriveHighLevelDocumentOperationConverter {
-    /// This will get a list of atomic drive operations from a high level operations
+    /// This will get a list of atomic drive operations from a high level operations,
+    /// the storage owner is the identity storage is attributed to for refunds,
+    /// it is the sponsor for sponsored batches and the document owner otherwise
     fn into_high_level_document_drive_operations<'a>(
         self,
         epoch: &Epoch,
         owner_id: Identifier,
+        storage_owner_id: Identifier,
         platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'a>>, Error>;
 }
@@ -26,6 +26,9 @@ impl DriveHighLevelOperationConverter for IdentityCreateTransitionAction {
             SystemOperation(SystemOperationType::AddToSystemCredits {
                 amount: initial_balance_amount,
             }),
+            SystemOperation(SystemOperationType::AddToMintedCredits {
+                amount: initial_balance_amount,
+            }),
             SystemOperation(SystemOperationType::AddUsedAssetLock {
                 asset_lock_outpoint,
             }),
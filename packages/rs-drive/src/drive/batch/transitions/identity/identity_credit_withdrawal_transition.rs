@@ -1,7 +1,10 @@
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation::{DocumentOperation, IdentityOperation};
-use crate::drive::batch::{DocumentOperationType, DriveOperation, IdentityOperationType};
+use crate::drive::batch::DriveOperation::{DocumentOperation, IdentityOperation, SystemOperation};
+use crate::drive::batch::{
+    DocumentOperationType, DriveOperation, IdentityOperationType, SystemOperationType,
+};
 use crate::drive::object_size_info::{DocumentInfo, OwnedDocumentInfo};
+use crate::error::drive::DriveError;
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
 
@@ -12,18 +15,51 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
     fn into_high_level_drive_operations<'a>(
         self,
         _epoch: &Epoch,
-        _platform_version: &PlatformVersion,
+        platform_version: &PlatformVersion,
     ) -> Result<Vec<DriveOperation<'a>>, Error> {
         let identity_id = self.identity_id();
         let revision = self.revision();
+        let amount = self.amount();
         let prepared_withdrawal_document = self.prepared_withdrawal_document_owned();
 
-        let drive_operations = vec![
-            IdentityOperation(IdentityOperationType::UpdateIdentityRevision {
+        let mut drive_operations = vec![IdentityOperation(
+            IdentityOperationType::UpdateIdentityRevision {
                 identity_id: identity_id.into_buffer(),
                 revision,
-            }),
-            DocumentOperation(DocumentOperationType::AddWithdrawalDocument {
+            },
+        )];
+
+        match platform_version
+            .drive
+            .methods
+            .batch_operations
+            .identity_credit_withdrawal_transition_operations
+        {
+            0 => {}
+            // Withdrawn credits leave the identity balance and the system credits
+            // until the withdrawal completes or they are refunded
+            1 => {
+                drive_operations.push(IdentityOperation(
+                    IdentityOperationType::RemoveFromIdentityBalance {
+                        identity_id: identity_id.to_buffer(),
+                        balance_to_remove: amount,
+                    },
+                ));
+                drive_operations.push(SystemOperation(
+                    SystemOperationType::RemoveFromSystemCredits { amount },
+                ));
+            }
+            version => {
+                return Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                    method: "identity_credit_withdrawal_transition_operations".to_string(),
+                    known_versions: vec![0, 1],
+                    received: version,
+                }))
+            }
+        }
+
+        drive_operations.push(DocumentOperation(
+            DocumentOperationType::AddWithdrawalDocument {
                 owned_document_info: OwnedDocumentInfo {
                     document_info: DocumentInfo::DocumentOwnedInfo((
                         prepared_withdrawal_document,
@@ -31,8 +67,8 @@ impl DriveHighLevelOperationConverter for IdentityCreditWithdrawalTransitionActi
                     )),
                     owner_id: None,
                 },
-            }),
-        ];
+            },
+        ));
 
         Ok(drive_operations)
     }
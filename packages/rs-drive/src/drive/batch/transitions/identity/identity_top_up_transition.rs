@@ -25,6 +25,9 @@ impl DriveHighLevelOperationConverter for IdentityTopUpTransitionAction {
             SystemOperation(SystemOperationType::AddToSystemCredits {
                 amount: top_up_balance_amount,
             }),
+            SystemOperation(SystemOperationType::AddToMintedCredits {
+                amount: top_up_balance_amount,
+            }),
             SystemOperation(SystemOperationType::AddUsedAssetLock {
                 asset_lock_outpoint,
             }),
@@ -1,6 +1,6 @@
 use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
-use crate::drive::batch::DriveOperation::IdentityOperation;
-use crate::drive::batch::{DriveOperation, IdentityOperationType};
+use crate::drive::batch::DriveOperation::{IdentityOperation, SponsorshipOperation};
+use crate::drive::batch::{DriveOperation, IdentityOperationType, SponsorshipOperationType};
 
 use crate::error::Error;
 use dpp::block::epoch::Epoch;
@@ -19,6 +19,7 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
         let identity_id = self.identity_id();
         let revision = self.revision();
         let public_keys_disabled_at = self.public_keys_disabled_at();
+        let sponsorship_spending_limits = self.sponsorship_spending_limits().to_vec();
         let (add_public_keys, disable_public_keys) = self.public_keys_to_add_and_disable_owned();
 
         let (unique_keys, non_unique_keys): (Vec<IdentityPublicKey>, Vec<IdentityPublicKey>) =
@@ -56,6 +57,15 @@ impl DriveHighLevelOperationConverter for IdentityUpdateTransitionAction {
             }
         }
 
+        if !sponsorship_spending_limits.is_empty() {
+            drive_operations.push(SponsorshipOperation(
+                SponsorshipOperationType::SetSpendingLimits {
+                    sponsor_id: identity_id,
+                    spending_limits: sponsorship_spending_limits,
+                },
+            ));
+        }
+
         Ok(drive_operations)
     }
 }
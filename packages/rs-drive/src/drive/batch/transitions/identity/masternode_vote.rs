@@ -0,0 +1,23 @@
+use crate::drive::batch::transitions::DriveHighLevelOperationConverter;
+use crate::drive::batch::DriveOperation::VoteOperation;
+use crate::drive::batch::{DriveOperation, VoteOperationType};
+
+use crate::error::Error;
+use crate::state_transition_action::identity::masternode_vote::MasternodeVoteTransitionAction;
+use dpp::block::epoch::Epoch;
+use dpp::version::PlatformVersion;
+
+impl DriveHighLevelOperationConverter for MasternodeVoteTransitionAction {
+    fn into_high_level_drive_operations<'a>(
+        self,
+        _epoch: &Epoch,
+        _platform_version: &PlatformVersion,
+    ) -> Result<Vec<DriveOperation<'a>>, Error> {
+        let drive_operations = vec![VoteOperation(VoteOperationType::CastVote {
+            vote_poll: self.vote_poll().clone(),
+            pro_tx_hash: self.pro_tx_hash(),
+            choice: self.choice(),
+        })];
+        Ok(drive_operations)
+    }
+}
@@ -3,3 +3,4 @@ mod identity_credit_transfer;
 mod identity_credit_withdrawal_transition;
 mod identity_top_up_transition;
 mod identity_update_transition;
+mod masternode_vote;
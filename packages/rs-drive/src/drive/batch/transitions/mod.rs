@@ -58,6 +58,9 @@ impl<'s> DriveHighLevelOperationConverter for StateTransitionAction {
                 identity_credit_transfer_transition,
             ) => identity_credit_transfer_transition
                 .into_high_level_drive_operations(epoch, platform_version),
+            StateTransitionAction::MasternodeVoteAction(masternode_vote_transition) => {
+                masternode_vote_transition.into_high_level_drive_operations(epoch, platform_version)
+            }
         }
     }
 }
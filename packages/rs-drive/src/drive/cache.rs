@@ -66,7 +66,11 @@ impl DataContractCache {
             None
         };
 
-        maybe_fetch_info.or_else(|| self.global_cache.get(&contract_id))
+        let maybe_fetch_info = maybe_fetch_info.or_else(|| self.global_cache.get(&contract_id));
+
+        crate::metrics::data_contract_cache_request(maybe_fetch_info.is_some());
+
+        maybe_fetch_info
     }
 
     /// Merge block cache to global cache
@@ -0,0 +1,139 @@
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+mod v0;
+
+impl Drive {
+    /// Fetches a contract as it was at a given time from the contract's history.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A 32-byte array representing the unique identifier of the contract.
+    ///
+    /// * `time_ms` - A `u64` representing the timestamp in Unix Epoch format at which the
+    /// contract should be resolved.
+    ///
+    /// * `transaction` - A transaction that requests the contract.
+    ///
+    /// * `platform_version` - The platform version used to select the correct method version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<(u64, DataContract)>, Error>` - The time the resolved contract was stored
+    /// at and the contract, or `None` if the contract keeps no history or did not exist yet
+    /// at the given time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` in the following situations:
+    ///
+    /// * If the drive version does not match any of the implemented method versions.
+    ///
+    /// * If the contract cannot be deserialized due to protocol errors.
+    ///
+    /// * If the queried contract path does not refer to a contract element.
+    pub fn fetch_contract_at_time(
+        &self,
+        contract_id: [u8; 32],
+        time_ms: u64,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<(u64, DataContract)>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .get
+            .fetch_contract_at_time
+        {
+            0 => {
+                self.fetch_contract_at_time_v0(contract_id, time_ms, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_contract_at_time".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::{DataContractV0Getters, DataContractV0Setters};
+    use dpp::data_contract::config::v0::DataContractConfigSettersV0;
+    use dpp::tests::fixtures::get_data_contract_fixture;
+
+    fn apply_contract_at(drive: &Drive, data_contract: &DataContract, time_ms: u64) {
+        drive
+            .apply_contract(
+                data_contract,
+                BlockInfo::default_with_time(time_ms),
+                true,
+                None,
+                None,
+                PlatformVersion::latest(),
+            )
+            .expect("expected to apply contract");
+    }
+
+    #[test]
+    fn should_fetch_and_prove_contract_at_time() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let mut data_contract = get_data_contract_fixture(None, platform_version.protocol_version)
+            .data_contract_owned();
+        data_contract.config_mut().set_keeps_history(true);
+        data_contract.config_mut().set_readonly(false);
+        let contract_id = data_contract.id().to_buffer();
+
+        apply_contract_at(&drive, &data_contract, 1000);
+
+        data_contract.increment_version();
+        apply_contract_at(&drive, &data_contract, 2000);
+
+        let before_creation = drive
+            .fetch_contract_at_time(contract_id, 500, None, platform_version)
+            .expect("expected to fetch contract at time");
+        assert!(before_creation.is_none());
+
+        for (time_ms, expected_time_ms, expected_version) in
+            [(1000, 1000, 1), (1500, 1000, 1), (2500, 2000, 2)]
+        {
+            let (stored_at_ms, contract) = drive
+                .fetch_contract_at_time(contract_id, time_ms, None, platform_version)
+                .expect("expected to fetch contract at time")
+                .expect("expected a contract");
+            assert_eq!(stored_at_ms, expected_time_ms);
+            assert_eq!(contract.version(), expected_version);
+
+            let proof = drive
+                .prove_contract_at_time(contract_id, time_ms, None, platform_version)
+                .expect("expected to prove contract at time");
+
+            let (root_hash, proved_contract) =
+                Drive::verify_contract_at_time(&proof, contract_id, time_ms, platform_version)
+                    .expect("expected to verify contract at time");
+
+            assert_eq!(
+                root_hash,
+                drive
+                    .grove
+                    .root_hash(None)
+                    .unwrap()
+                    .expect("expected a root hash")
+            );
+            assert_eq!(proved_contract, Some((stored_at_ms, contract)));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::common::decode::decode_u64;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::data_contract::DataContract;
+
+use dpp::serialization::PlatformDeserializableWithPotentialValidationFromVersionedStructure;
+
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches a contract as it was at a given time from the contract's history.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A 32-byte array representing the unique identifier of the contract.
+    ///
+    /// * `time_ms` - A `u64` representing the timestamp in Unix Epoch format at which the
+    /// contract should be resolved.
+    ///
+    /// * `transaction` - A transaction that requests the contract.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Option<(u64, DataContract)>, Error>` - The time the resolved contract was stored
+    /// at and the contract, or `None` if the contract keeps no history or did not exist yet
+    /// at the given time.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an `Error` in the following situations:
+    ///
+    /// * If the contract cannot be deserialized due to protocol errors.
+    ///
+    /// * If the queried contract path does not refer to a contract element.
+    pub(super) fn fetch_contract_at_time_v0(
+        &self,
+        contract_id: [u8; 32],
+        time_ms: u64,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Option<(u64, DataContract)>, Error> {
+        let mut ops = Vec::new();
+
+        let path_query = Self::fetch_contract_at_time_query(contract_id, time_ms);
+
+        let query_result = self.grove_get_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut ops,
+            &platform_version.drive,
+        );
+
+        let results = match query_result {
+            Err(Error::GroveDB(grovedb::Error::PathKeyNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathNotFound(_)))
+            | Err(Error::GroveDB(grovedb::Error::PathParentLayerNotFound(_))) => return Ok(None),
+            _ => query_result?.0,
+        };
+
+        let Some((key, element)) = results.to_key_elements().into_iter().next() else {
+            return Ok(None);
+        };
+
+        let contract_time = decode_u64(&key).map_err(|_| {
+            Error::Drive(DriveError::CorruptedContractPath(
+                "contract key is not a valid u64",
+            ))
+        })?;
+
+        match element {
+            Element::Item(item, _flags) => {
+                let contract = DataContract::versioned_deserialize(&item, false, platform_version)
+                    .map_err(Error::Protocol)?;
+                Ok(Some((contract_time, contract)))
+            }
+            _ => Err(Error::Drive(DriveError::CorruptedContractPath(
+                "contract path did not refer to a contract element",
+            ))),
+        }
+    }
+}
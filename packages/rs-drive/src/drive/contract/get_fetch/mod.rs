@@ -1,4 +1,5 @@
 mod fetch_contract;
+mod fetch_contract_at_time;
 mod fetch_contract_with_history;
 mod get_cached_contract_with_fetch_info;
 mod get_contract_with_fetch_info;
@@ -0,0 +1,148 @@
+mod report;
+mod v0;
+
+pub use report::*;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Lints a data contract before it's deployed and estimates the costs of its documents.
+    ///
+    /// For every document type the report contains the max and estimated average document
+    /// size, the storage and processing fees to create, replace and delete a document, the
+    /// index layout and warnings about schema definitions that are costly or can't be queried.
+    ///
+    /// Fees are estimated without touching state, the contract doesn't need to be applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - The data contract to lint.
+    /// * `block_info` - The block info, its epoch is used to calculate fees.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the version of the Drive is unknown.
+    pub fn lint_data_contract(
+        &self,
+        contract: &DataContract,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<DataContractLintReport, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .costs
+            .lint_data_contract
+        {
+            0 => self.lint_data_contract_v0(contract, block_info, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "lint_data_contract".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drive::test_utils::TestEntropyGenerator;
+    use dpp::data_contract::DataContractFactory;
+    use dpp::platform_value::{platform_value, Identifier};
+    use tempfile::TempDir;
+
+    #[test]
+    fn should_report_sizes_fees_and_warnings_without_applying_contract() {
+        let tmp_dir = TempDir::new().unwrap();
+        let drive: Drive =
+            Drive::open(&tmp_dir, None).expect("expected to open Drive successfully");
+
+        let platform_version = PlatformVersion::latest();
+        drive
+            .create_initial_state_structure(None, platform_version)
+            .expect("expected to create root tree successfully");
+
+        let documents = platform_value!({
+            "note": {
+                "type": "object",
+                "indices": [
+                    {
+                        "name": "byTitle",
+                        "properties": [{ "title": "asc" }]
+                    },
+                    {
+                        "name": "byUpdatedAt",
+                        "properties": [{ "$updatedAt": "asc" }]
+                    }
+                ],
+                "properties": {
+                    "title": {
+                        "type": "string",
+                        "maxLength": 63
+                    },
+                    "body": {
+                        "type": "string"
+                    }
+                },
+                "required": ["title", "$createdAt"],
+                "additionalProperties": false
+            }
+        });
+
+        let factory = DataContractFactory::new(1, Some(Box::new(TestEntropyGenerator::new())))
+            .expect("expected to create factory");
+
+        let contract = factory
+            .create_with_value_config(Identifier::new([2u8; 32]), documents, None, None)
+            .expect("data in fixture should be correct")
+            .data_contract_owned();
+
+        let report = drive
+            .lint_data_contract(&contract, &BlockInfo::default(), platform_version)
+            .expect("expected to lint contract");
+
+        assert!(report.has_warnings());
+
+        let note = report
+            .document_types
+            .get("note")
+            .expect("expected a report for note");
+
+        assert!(note.max_size >= note.estimated_size);
+        assert_eq!(note.indices.len(), 2);
+        assert_eq!(note.max_index_depth, 1);
+
+        assert!(note.max_size_fees.create.storage_fee > 0);
+        assert!(note.max_size_fees.create.processing_fee > 0);
+        assert!(
+            note.max_size_fees.create.storage_fee >= note.estimated_size_fees.create.storage_fee
+        );
+        assert!(note.max_size_fees.delete.processing_fee > 0);
+
+        assert_eq!(
+            note.warnings,
+            vec![
+                DocumentTypeLintWarning::UnboundedString {
+                    property: "body".to_string()
+                },
+                DocumentTypeLintWarning::UnindexedOrderByField {
+                    property: "$createdAt".to_string()
+                },
+                DocumentTypeLintWarning::UnusableIndex {
+                    index: "byUpdatedAt".to_string(),
+                    reason: "'$updatedAt' isn't required, so documents never have a value for it"
+                        .to_string()
+                },
+            ]
+        );
+    }
+}
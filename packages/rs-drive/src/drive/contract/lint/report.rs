@@ -0,0 +1,141 @@
+use dpp::fee::fee_result::FeeResult;
+use dpp::fee::Credits;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// The result of linting a data contract
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataContractLintReport {
+    /// Reports by document type name
+    pub document_types: BTreeMap<String, DocumentTypeLintReport>,
+}
+
+impl DataContractLintReport {
+    /// Returns true if any document type has warnings
+    pub fn has_warnings(&self) -> bool {
+        self.document_types
+            .values()
+            .any(|report| !report.warnings.is_empty())
+    }
+}
+
+/// Sizes, fees, indices and warnings of a document type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentTypeLintReport {
+    /// The worst case size of a document
+    pub max_size: u16,
+    /// The estimated average size of a document
+    pub estimated_size: u16,
+    /// Fees for a document of the max size
+    pub max_size_fees: DocumentOperationFees,
+    /// Fees for a document of the estimated average size
+    pub estimated_size_fees: DocumentOperationFees,
+    /// The indices of the document type
+    pub indices: Vec<IndexLintReport>,
+    /// The depth of the deepest index
+    pub max_index_depth: usize,
+    /// Warnings about the schema
+    pub warnings: Vec<DocumentTypeLintWarning>,
+}
+
+/// Fees of the document operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentOperationFees {
+    /// Fees to create a document
+    pub create: OperationFees,
+    /// Fees to replace a document.
+    /// Drive estimates a replacement as a worst case insertion, so it matches `create`.
+    pub replace: OperationFees,
+    /// Fees to delete a document
+    pub delete: OperationFees,
+}
+
+/// Storage and processing fees of an operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationFees {
+    /// Storage fee
+    pub storage_fee: Credits,
+    /// Processing fee
+    pub processing_fee: Credits,
+}
+
+impl From<FeeResult> for OperationFees {
+    fn from(fee_result: FeeResult) -> Self {
+        OperationFees {
+            storage_fee: fee_result.storage_fee,
+            processing_fee: fee_result.processing_fee,
+        }
+    }
+}
+
+/// Layout of an index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexLintReport {
+    /// Name of the index
+    pub name: String,
+    /// Indexed properties, in order
+    pub properties: Vec<String>,
+    /// Is the index unique
+    pub unique: bool,
+    /// Amount of index levels a document reference is inserted under
+    pub depth: usize,
+}
+
+/// A warning about a document type schema
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum DocumentTypeLintWarning {
+    /// A string property without `maxLength`, documents are sized for the protocol limit
+    UnboundedString {
+        /// The property path
+        property: String,
+    },
+    /// A byte array property without `maxItems`, documents are sized for the protocol limit
+    UnboundedByteArray {
+        /// The property path
+        property: String,
+    },
+    /// A required timestamp is in no index, so documents can't be ordered by it
+    UnindexedOrderByField {
+        /// The property name
+        property: String,
+    },
+    /// An index which no query can use
+    UnusableIndex {
+        /// The index name
+        index: String,
+        /// Why the index can't be used
+        reason: String,
+    },
+}
+
+impl fmt::Display for DocumentTypeLintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentTypeLintWarning::UnboundedString { property } => write!(
+                f,
+                "string property '{}' has no maxLength, it's sized for the protocol limit",
+                property
+            ),
+            DocumentTypeLintWarning::UnboundedByteArray { property } => write!(
+                f,
+                "byte array property '{}' has no maxItems, it's sized for the protocol limit",
+                property
+            ),
+            DocumentTypeLintWarning::UnindexedOrderByField { property } => write!(
+                f,
+                "'{}' is required but not indexed, documents can't be ordered by it",
+                property
+            ),
+            DocumentTypeLintWarning::UnusableIndex { index, reason } => {
+                write!(f, "index '{}' can't be used by queries: {}", index, reason)
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::drive::contract::lint::{
+    DataContractLintReport, DocumentOperationFees, DocumentTypeLintReport, DocumentTypeLintWarning,
+    IndexLintReport,
+};
+use crate::drive::object_size_info::DocumentInfo::DocumentEstimatedAverageSize;
+use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::methods::DocumentTypeV0Methods;
+use dpp::data_contract::document_type::property::DocumentPropertyType;
+use dpp::data_contract::document_type::{property_names, DocumentTypeRef};
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+use std::collections::BTreeMap;
+
+/// System timestamps are the fields documents are usually ordered by
+const TIMESTAMP_FIELDS: [&str; 2] = [property_names::CREATED_AT, property_names::UPDATED_AT];
+
+impl Drive {
+    pub(super) fn lint_data_contract_v0(
+        &self,
+        contract: &DataContract,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<DataContractLintReport, Error> {
+        let document_types = contract
+            .document_types()
+            .iter()
+            .map(|(name, document_type)| {
+                let report = self.lint_document_type_v0(
+                    contract,
+                    document_type.as_ref(),
+                    block_info,
+                    platform_version,
+                )?;
+                Ok((name.clone(), report))
+            })
+            .collect::<Result<BTreeMap<_, _>, Error>>()?;
+
+        Ok(DataContractLintReport { document_types })
+    }
+
+    fn lint_document_type_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<DocumentTypeLintReport, Error> {
+        let max_size = document_type.max_size(platform_version)?;
+        let estimated_size = document_type.estimated_size(platform_version)?;
+
+        let max_size_fees = self.estimate_document_operation_fees_v0(
+            contract,
+            document_type,
+            max_size,
+            block_info,
+            platform_version,
+        )?;
+        let estimated_size_fees = self.estimate_document_operation_fees_v0(
+            contract,
+            document_type,
+            estimated_size,
+            block_info,
+            platform_version,
+        )?;
+
+        let indices: Vec<IndexLintReport> = document_type
+            .indices()
+            .iter()
+            .map(|index| IndexLintReport {
+                name: index.name.clone(),
+                properties: index
+                    .properties
+                    .iter()
+                    .map(|property| property.name.clone())
+                    .collect(),
+                unique: index.unique,
+                depth: index.properties.len(),
+            })
+            .collect();
+
+        let max_index_depth = indices.iter().map(|index| index.depth).max().unwrap_or(0);
+
+        Ok(DocumentTypeLintReport {
+            max_size,
+            estimated_size,
+            max_size_fees,
+            estimated_size_fees,
+            indices,
+            max_index_depth,
+            warnings: document_type_warnings_v0(document_type),
+        })
+    }
+
+    /// Estimates fees without state, as for a dry run
+    fn estimate_document_operation_fees_v0(
+        &self,
+        contract: &DataContract,
+        document_type: DocumentTypeRef,
+        document_size: u16,
+        block_info: &BlockInfo,
+        platform_version: &PlatformVersion,
+    ) -> Result<DocumentOperationFees, Error> {
+        let insert_fees = |override_document: bool| {
+            self.add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentEstimatedAverageSize(document_size as u32),
+                        owner_id: None,
+                    },
+                    contract,
+                    document_type,
+                },
+                override_document,
+                block_info.clone(),
+                false,
+                None,
+                platform_version,
+            )
+        };
+
+        let create = insert_fees(false)?;
+        // Estimated costs of an update are the costs of an insertion as it's always worse
+        let replace = insert_fees(true)?;
+        let delete = self.delete_document_for_contract(
+            [0; 32],
+            contract,
+            document_type.name(),
+            block_info.clone(),
+            false,
+            None,
+            platform_version,
+        )?;
+
+        Ok(DocumentOperationFees {
+            create: create.into(),
+            replace: replace.into(),
+            delete: delete.into(),
+        })
+    }
+}
+
+fn document_type_warnings_v0(document_type: DocumentTypeRef) -> Vec<DocumentTypeLintWarning> {
+    let mut warnings = vec![];
+
+    for (property_name, property) in document_type.flattened_properties() {
+        match property.property_type {
+            DocumentPropertyType::String(_, None) => {
+                warnings.push(DocumentTypeLintWarning::UnboundedString {
+                    property: property_name.clone(),
+                })
+            }
+            DocumentPropertyType::ByteArray(_, None) => {
+                warnings.push(DocumentTypeLintWarning::UnboundedByteArray {
+                    property: property_name.clone(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let is_indexed = |field: &str| {
+        document_type.indices().iter().any(|index| {
+            index
+                .properties
+                .iter()
+                .any(|property| property.name == field)
+        })
+    };
+
+    for field in TIMESTAMP_FIELDS {
+        if document_type.required_fields().contains(field) && !is_indexed(field) {
+            warnings.push(DocumentTypeLintWarning::UnindexedOrderByField {
+                property: field.to_string(),
+            });
+        }
+    }
+
+    // Timestamps are only set if they are required, otherwise documents are indexed without
+    // a value and are never found by queries on them
+    for index in document_type.indices() {
+        if let Some(property) = index.properties.iter().find(|property| {
+            TIMESTAMP_FIELDS.contains(&property.name.as_str())
+                && !document_type.required_fields().contains(&property.name)
+        }) {
+            warnings.push(DocumentTypeLintWarning::UnusableIndex {
+                index: index.name.clone(),
+                reason: format!(
+                    "'{}' isn't required, so documents never have a value for it",
+                    property.name
+                ),
+            });
+        }
+    }
+
+    warnings
+}
@@ -42,6 +42,8 @@ mod estimation_costs;
 mod get_fetch;
 #[cfg(feature = "full")]
 mod insert;
+#[cfg(feature = "full")]
+mod lint;
 /// Various paths for contract operations
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod paths;
@@ -56,6 +58,8 @@ pub mod test_helpers;
 mod update;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub use contract_fetch_info::*;
+#[cfg(feature = "full")]
+pub use lint::*;
 
 /// How many contracts to fetch at once. This is an arbitrary number and is needed to prevent
 /// the server from being overloaded with requests.
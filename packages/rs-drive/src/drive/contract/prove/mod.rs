@@ -2,6 +2,10 @@
 mod prove_contract;
 pub use prove_contract::*;
 
+/// The `prove_contract_at_time` module provides functionality for getting a proved contract as of a given time.
+mod prove_contract_at_time;
+pub use prove_contract_at_time::*;
+
 /// The `prove_contract_history` module provides functionality for getting contract historical proofs.
 mod prove_contract_history;
 pub use prove_contract_history::*;
@@ -0,0 +1,52 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the entry of the specified contract's history as of a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `time_ms` - The time in milliseconds the contract should be resolved at.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for proving the contract. This is either None or Some(&Transaction).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - If successful, returns a `Vec<u8>` containing the proof data.
+    ///   If an error occurs during the proof generation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the proof generation fails.
+    pub fn prove_contract_at_time(
+        &self,
+        contract_id: [u8; 32],
+        time_ms: u64,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .prove
+            .prove_contract_at_time
+        {
+            0 => {
+                self.prove_contract_at_time_v0(contract_id, time_ms, transaction, platform_version)
+            }
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_contract_at_time".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
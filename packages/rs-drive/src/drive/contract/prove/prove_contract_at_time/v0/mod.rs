@@ -0,0 +1,42 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the entry of the specified contract's history as of a given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `time_ms` - The time in milliseconds the contract should be resolved at.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for proving the contract. This is either None or Some(&Transaction).
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - If successful, returns a `Vec<u8>` containing the proof data.
+    ///   If an error occurs during the proof generation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the proof generation fails.
+    pub(super) fn prove_contract_at_time_v0(
+        &self,
+        contract_id: [u8; 32],
+        time_ms: u64,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let at_time_query = Self::fetch_contract_at_time_query(contract_id, time_ms);
+
+        self.grove_get_proved_path_query(
+            &at_time_query,
+            false,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
@@ -72,6 +72,34 @@ impl Drive {
         PathQuery::merge(queries.iter().collect()).map_err(GroveDB)
     }
 
+    /// Creates a path query for the entry of a specified contract as of a given time.
+    ///
+    /// The query returns the latest historical entry of the contract stored at or before
+    /// the given time.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `time_ms` - The time in milliseconds the contract should be resolved at.
+    ///
+    /// # Returns
+    ///
+    /// * `PathQuery` - A `PathQuery` object representing the query for fetching the contract
+    ///   entry as of the given time.
+    pub fn fetch_contract_at_time_query(contract_id: [u8; 32], time_ms: u64) -> PathQuery {
+        // The reference to the latest entry is stored at key 0 which sorts before
+        // all encoded times, starting at encoded 0 keeps it out of the results
+        let query = Query::new_single_query_item_with_direction(
+            QueryItem::RangeInclusive(encode_u64(0)..=encode_u64(time_ms)),
+            false,
+        );
+
+        PathQuery::new(
+            contract_keeping_history_storage_path_vec(&contract_id),
+            SizedQuery::new(query, Some(1), None),
+        )
+    }
+
     /// Creates a path query for historical entries of a specified contract.
     ///
     /// This function takes a slice of contract IDs and creates a path query for fetching
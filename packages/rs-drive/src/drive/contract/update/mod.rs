@@ -1 +1,2 @@
 mod update_contract;
+mod update_contract_and_reinsert_documents;
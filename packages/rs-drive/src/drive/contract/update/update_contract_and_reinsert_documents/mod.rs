@@ -0,0 +1,72 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::DataContract;
+use dpp::document::Document;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Updates a data contract and inserts its existing documents again.
+    ///
+    /// Documents are serialized and indexed according to their document type, so documents
+    /// of document types that change their properties or indexes have to be inserted again.
+    /// The documents are deleted with the original contract and inserted with the updated
+    /// one, keeping their storage flags. Fees and refunds are not calculated, this is meant
+    /// for migrations of system data contracts during protocol upgrades.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract` - A reference to the updated `DataContract`.
+    /// * `documents` - Existing documents by document type name, with the values they should
+    ///   have with the updated contract.
+    /// * `block_info` - A `BlockInfo` object containing information about the block where
+    ///   the contract is being updated.
+    /// * `transaction` - A `TransactionArg` object representing the transaction to be used
+    ///   for updating the contract and documents.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<(), Error>` - If successful, returns `Ok(())`. If an error occurs during the
+    ///   operation, returns an `Error`.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the version of Platform is unknown, a document
+    /// doesn't exist or its document type keeps history.
+    pub fn update_contract_and_reinsert_documents(
+        &self,
+        contract: &DataContract,
+        documents: BTreeMap<String, Vec<Document>>,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        match platform_version
+            .drive
+            .methods
+            .contract
+            .update
+            .update_contract_and_reinsert_documents
+        {
+            0 => self.update_contract_and_reinsert_documents_v0(
+                contract,
+                documents,
+                block_info,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "update_contract_and_reinsert_documents".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
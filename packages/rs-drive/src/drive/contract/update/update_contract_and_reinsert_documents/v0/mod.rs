@@ -0,0 +1,142 @@
+use crate::drive::batch::{DocumentOperationType, DriveOperation};
+use crate::drive::document::contract_documents_primary_key_path;
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::DirectQueryType;
+use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
+use crate::drive::object_size_info::OwnedDocumentInfo;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::{Document, DocumentV0Getters};
+
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+impl Drive {
+    pub(super) fn update_contract_and_reinsert_documents_v0(
+        &self,
+        contract: &DataContract,
+        documents: BTreeMap<String, Vec<Document>>,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let original_contract_fetch_info = self
+            .get_contract_with_fetch_info(
+                contract.id().to_buffer(),
+                false,
+                transaction,
+                platform_version,
+            )?
+            .ok_or(Error::Drive(DriveError::CorruptedCodeExecution(
+                "contract should exist",
+            )))?;
+
+        let original_contract = &original_contract_fetch_info.contract;
+
+        let mut delete_operations = vec![];
+
+        let mut storage_flags_of_documents = vec![];
+
+        for (document_type_name, documents) in documents.iter() {
+            let document_type = original_contract.document_type_for_name(document_type_name)?;
+
+            if document_type.documents_keep_history() {
+                return Err(Error::Drive(DriveError::NotSupported(
+                    "reinserting documents that keep history is not supported",
+                )));
+            }
+
+            let primary_key_path = contract_documents_primary_key_path(
+                original_contract.id_ref().as_bytes(),
+                document_type_name,
+            );
+
+            for document in documents {
+                let Some(Element::Item(_, element_flags)) = self.grove_get_raw(
+                    (&primary_key_path).into(),
+                    document.id_ref().as_slice(),
+                    DirectQueryType::StatefulDirectQuery,
+                    transaction,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?
+                else {
+                    return Err(Error::Drive(DriveError::UpdatingDocumentThatDoesNotExist(
+                        "document being reinserted does not exist",
+                    )));
+                };
+
+                storage_flags_of_documents
+                    .push(StorageFlags::map_some_element_flags_ref(&element_flags)?);
+
+                delete_operations.push(DriveOperation::DocumentOperation(
+                    DocumentOperationType::DeleteDocumentOfNamedTypeForContract {
+                        document_id: document.id().to_buffer(),
+                        contract: original_contract,
+                        document_type_name: document_type_name.as_str(),
+                    },
+                ));
+            }
+        }
+
+        self.apply_drive_operations(
+            delete_operations,
+            true,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        self.update_contract(
+            contract,
+            block_info.clone(),
+            true,
+            transaction,
+            platform_version,
+        )?;
+
+        let mut storage_flags_of_documents = storage_flags_of_documents.into_iter();
+
+        let mut insert_operations = vec![];
+
+        for (document_type_name, documents) in documents {
+            for document in documents {
+                let storage_flags = storage_flags_of_documents.next().flatten();
+
+                let owner_id = document.owner_id().to_buffer();
+
+                insert_operations.push(DriveOperation::DocumentOperation(
+                    DocumentOperationType::AddDocument {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentOwnedInfo((
+                                document,
+                                storage_flags.map(Cow::Owned),
+                            )),
+                            owner_id: Some(owner_id),
+                        },
+                        contract_id: contract.id(),
+                        document_type_name: Cow::Owned(document_type_name.clone()),
+                        override_document: false,
+                    },
+                ));
+            }
+        }
+
+        self.apply_drive_operations(
+            insert_operations,
+            true,
+            block_info,
+            transaction,
+            platform_version,
+        )?;
+
+        Ok(())
+    }
+}
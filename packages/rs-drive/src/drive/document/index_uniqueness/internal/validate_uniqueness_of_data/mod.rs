@@ -1,4 +1,5 @@
 mod v0;
+mod v1;
 
 use crate::drive::Drive;
 use crate::error::drive::DriveError;
@@ -75,9 +76,10 @@ impl Drive {
             .validate_uniqueness_of_data
         {
             0 => self.validate_uniqueness_of_data_v0(request, transaction, platform_version),
+            1 => self.validate_uniqueness_of_data_v1(request, transaction, platform_version),
             version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
                 method: "validate_uniqueness_of_data".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
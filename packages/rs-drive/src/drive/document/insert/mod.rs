@@ -458,8 +458,7 @@ mod tests {
         let FeeResult {
             storage_fee,
             processing_fee,
-            fee_refunds: _,
-            removed_bytes_from_system: _,
+            ..
         } = drive
             .add_document_for_contract(
                 DocumentAndContractInfo {
@@ -65,9 +65,16 @@ mod index_uniqueness;
 mod insert;
 #[cfg(any(feature = "full", feature = "fixtures-and-mocks"))]
 pub mod query;
+#[cfg(any(feature = "full", feature = "verify"))]
+mod queries;
 #[cfg(any(feature = "full", feature = "fixtures-and-mocks"))]
 mod update;
 
+/// How many revisions of a document keeping history to fetch at once. This is an arbitrary
+/// number and is needed to prevent the server from being overloaded with requests.
+#[cfg(any(feature = "full", feature = "verify"))]
+pub const MAX_DOCUMENT_HISTORY_FETCH_LIMIT: u16 = 100;
+
 #[cfg(any(feature = "full", feature = "verify"))]
 /// Returns the path to a contract document type.
 pub(crate) fn contract_document_type_path<'a>(
@@ -0,0 +1,110 @@
+use crate::common::encode::encode_u64;
+use crate::drive::document::{
+    contract_documents_primary_key_path, MAX_DOCUMENT_HISTORY_FETCH_LIMIT,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::query::{Query, QueryItem};
+use grovedb::{PathQuery, SizedQuery};
+
+impl Drive {
+    /// Creates a path query for historical revisions of a specified document.
+    ///
+    /// Documents of types keeping history store every revision under the document id
+    /// keyed by the time it was stored at, along with a reference to the latest revision.
+    /// Only the revisions are queried.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `document_type_name` - The name of the document type keeping history.
+    /// * `document_id` - A document ID as a 32-byte array.
+    /// * `start_at_ms` - The time (inclusive) to start the history query from.
+    /// * `end_at_ms` - The time (exclusive) to end the history query at. If `None`, the
+    ///   history is queried up to the latest revision.
+    /// * `limit` - The maximum number of revisions to return. If `None`, the limit is set
+    ///   to [MAX_DOCUMENT_HISTORY_FETCH_LIMIT].
+    /// * `offset` - The number of revisions to skip before returning them.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<PathQuery, Error>` - If successful, returns a `PathQuery` object representing the
+    ///   query for fetching the document's history.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the limit is out of the allowed range.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_document_history_query(
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+    ) -> Result<PathQuery, Error> {
+        let limit = limit.unwrap_or(MAX_DOCUMENT_HISTORY_FETCH_LIMIT);
+        if !(1..=MAX_DOCUMENT_HISTORY_FETCH_LIMIT).contains(&limit) {
+            return Err(Error::Drive(DriveError::InvalidDocumentHistoryFetchLimit(
+                limit,
+            )));
+        }
+
+        // Revisions are stored under encoded times, which are always greater than the
+        // key of the reference to the latest revision
+        let query_item = match end_at_ms {
+            Some(end_at_ms) => QueryItem::Range(encode_u64(start_at_ms)..encode_u64(end_at_ms)),
+            None => QueryItem::RangeFrom(encode_u64(start_at_ms)..),
+        };
+
+        let query = Query::new_single_query_item_with_direction(query_item, true);
+
+        Ok(PathQuery::new(
+            Self::document_history_path_vec(&contract_id, document_type_name, &document_id),
+            SizedQuery::new(query, Some(limit), offset),
+        ))
+    }
+
+    /// Returns the path to revisions of a document keeping history
+    pub(crate) fn document_history_path_vec(
+        contract_id: &[u8; 32],
+        document_type_name: &str,
+        document_id: &[u8; 32],
+    ) -> Vec<Vec<u8>> {
+        let mut path: Vec<Vec<u8>> =
+            contract_documents_primary_key_path(contract_id, document_type_name)
+                .into_iter()
+                .map(|segment| segment.to_vec())
+                .collect();
+        path.push(document_id.to_vec());
+        path
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_not_accept_limit_over_max() {
+        let result = Drive::fetch_document_history_query(
+            [1; 32],
+            "note",
+            [2; 32],
+            0,
+            None,
+            Some(MAX_DOCUMENT_HISTORY_FETCH_LIMIT + 1),
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::Drive(DriveError::InvalidDocumentHistoryFetchLimit(
+                _
+            )))
+        ));
+    }
+}
@@ -0,0 +1,386 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::prelude::{Revision, TimestampMillis};
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Fetches revisions of a document of a type keeping history.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `document_type` - The document type keeping history.
+    /// * `document_id` - A document ID as a 32-byte array.
+    /// * `start_at_ms` - The time (inclusive) to start fetching the history from.
+    /// * `end_at_ms` - The time (exclusive) to stop fetching the history at.
+    /// * `limit` - The maximum number of revisions to return.
+    /// * `offset` - The number of revisions to skip before returning them.
+    /// * `transaction` - A transaction that requests the history.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<(TimestampMillis, Revision, Document)>, Error>` - Revisions ordered by
+    ///   the time they were stored at, along with the time and the revision of the document.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the limit is out of the allowed range or a stored
+    /// revision can't be deserialized.
+    #[allow(clippy::too_many_arguments)]
+    pub fn fetch_document_history(
+        &self,
+        contract_id: [u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<(TimestampMillis, Revision, Document)>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .fetch_document_history
+        {
+            0 => self.fetch_document_history_v0(
+                contract_id,
+                document_type,
+                document_id,
+                start_at_ms,
+                end_at_ms,
+                limit,
+                offset,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "fetch_document_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::setup_contract;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::error::query::QuerySyntaxError;
+    use crate::query::DriveQuery;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+    use dpp::document::{DocumentV0Getters, DocumentV0Setters};
+    use dpp::platform_value::{platform_value, Value};
+    use dpp::tests::json_document::json_document_to_document;
+
+    #[test]
+    fn should_fetch_and_prove_document_revisions() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let contract = setup_contract(
+            &drive,
+            "tests/supporting_files/contract/dashpay/dashpay-contract-with-profile-history.json",
+            None,
+            None,
+        );
+
+        let document_type = contract
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let owner_id = [1u8; 32];
+
+        let mut document = json_document_to_document(
+            "tests/supporting_files/contract/dashpay/profile0.json",
+            Some(owner_id.into()),
+            document_type,
+            platform_version,
+        )
+        .expect("expected to get document");
+
+        document.set_revision(Some(1));
+
+        drive
+            .add_document_for_contract(
+                DocumentAndContractInfo {
+                    owned_document_info: OwnedDocumentInfo {
+                        document_info: DocumentRefInfo((
+                            &document,
+                            StorageFlags::optional_default_as_cow(),
+                        )),
+                        owner_id: None,
+                    },
+                    contract: &contract,
+                    document_type,
+                },
+                false,
+                BlockInfo::default_with_time(1000),
+                true,
+                None,
+                platform_version,
+            )
+            .expect("expected to insert a document");
+
+        document.set_revision(Some(2));
+
+        drive
+            .update_document_for_contract(
+                &document,
+                &contract,
+                document_type,
+                Some(owner_id),
+                BlockInfo::default_with_time(2000),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to update a document");
+
+        let contract_id = contract.id().to_buffer();
+        let document_id = document.id().to_buffer();
+
+        let revisions = drive
+            .fetch_document_history(
+                contract_id,
+                document_type,
+                document_id,
+                0,
+                None,
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch document history");
+
+        let times_and_revisions: Vec<_> = revisions
+            .iter()
+            .map(|(time, revision, _)| (*time, *revision))
+            .collect();
+
+        assert_eq!(times_and_revisions, vec![(1000, 1), (2000, 2)]);
+
+        let revisions = drive
+            .fetch_document_history(
+                contract_id,
+                document_type,
+                document_id,
+                1500,
+                Some(2000),
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to fetch document history");
+
+        assert!(revisions.is_empty());
+
+        let proof = drive
+            .prove_document_history(
+                contract_id,
+                "profile",
+                document_id,
+                0,
+                None,
+                None,
+                None,
+                None,
+                platform_version,
+            )
+            .expect("expected to prove document history");
+
+        let (_, proved_revisions) = Drive::verify_document_history(
+            &proof,
+            contract_id,
+            document_type,
+            document_id,
+            0,
+            None,
+            None,
+            None,
+            platform_version,
+        )
+        .expect("expected to verify document history");
+
+        assert_eq!(proved_revisions.len(), 2);
+        assert_eq!(proved_revisions[1].1, 2);
+        assert_eq!(proved_revisions[1].2.id(), document.id());
+
+        for (where_clause, block_time_ms, expected_revisions) in [
+            (
+                platform_value!([["$id", "==", Value::Identifier(document_id)]]),
+                500,
+                vec![],
+            ),
+            (
+                platform_value!([["$id", "==", Value::Identifier(document_id)]]),
+                1500,
+                vec![1],
+            ),
+            (
+                platform_value!([["$id", "in", [Value::Identifier(document_id)]]]),
+                2500,
+                vec![2],
+            ),
+        ] {
+            let query = DriveQuery::from_decomposed_values(
+                where_clause,
+                None,
+                None,
+                None,
+                false,
+                Some(block_time_ms),
+                &contract,
+                document_type,
+                &drive.config,
+            )
+            .expect("expected to create a query");
+
+            let (items, _, _) = query
+                .execute_raw_results_no_proof(&drive, None, None, platform_version)
+                .expect("expected to execute query");
+
+            let (_, proved_items, _) = query
+                .clone()
+                .execute_with_proof_only_get_elements(&drive, None, None, platform_version)
+                .expect("expected to execute query with proof");
+
+            assert_eq!(items, proved_items);
+
+            let revisions = items
+                .iter()
+                .map(|bytes| {
+                    Document::from_bytes(bytes, document_type, platform_version)
+                        .expect("expected to deserialize document")
+                        .revision()
+                        .expect("expected a revision")
+                })
+                .collect::<Vec<Revision>>();
+
+            assert_eq!(revisions, expected_revisions);
+        }
+    }
+
+    #[test]
+    fn should_bound_point_in_time_in_queries_and_reject_unsupported_ones() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let contract = setup_contract(
+            &drive,
+            "tests/supporting_files/contract/dashpay/dashpay-contract-with-profile-history.json",
+            None,
+            None,
+        );
+
+        let profile = contract
+            .document_type_for_name("profile")
+            .expect("expected to get document type");
+
+        let contact_request = contract
+            .document_type_for_name("contactRequest")
+            .expect("expected to get document type");
+
+        let document_ids = [[1u8; 32], [2u8; 32], [3u8; 32]];
+
+        let query = DriveQuery::from_decomposed_values(
+            platform_value!([[
+                "$id",
+                "in",
+                [
+                    Value::Identifier(document_ids[0]),
+                    Value::Identifier(document_ids[1]),
+                    Value::Identifier(document_ids[2])
+                ]
+            ]]),
+            None,
+            None,
+            None,
+            false,
+            Some(1000),
+            &contract,
+            profile,
+            &drive.config,
+        )
+        .expect("expected to create a query");
+
+        let path_query = query
+            .construct_path_query(None, platform_version)
+            .expect("expected to construct a path query");
+
+        // revisions of documents are not limited one by one, the whole query is bounded
+        assert_eq!(path_query.query.limit, query.limit);
+
+        // documents of types not keeping history have no revisions stored by time
+        let error = DriveQuery::from_decomposed_values(
+            platform_value!([["$id", "==", Value::Identifier(document_ids[0])]]),
+            None,
+            None,
+            None,
+            false,
+            Some(1000),
+            &contract,
+            contact_request,
+            &drive.config,
+        )
+        .expect_err("expected point in time queries to require history");
+
+        assert!(matches!(
+            error,
+            Error::Query(QuerySyntaxError::Unsupported(message))
+                if message.contains("to keep history")
+        ));
+
+        // revisions can only be reached through the primary key
+        let error = DriveQuery::from_decomposed_values(
+            platform_value!([["$ownerId", "==", Value::Identifier([1u8; 32])]]),
+            None,
+            None,
+            None,
+            false,
+            Some(1000),
+            &contract,
+            profile,
+            &drive.config,
+        )
+        .expect_err("expected point in time queries to require $id clauses");
+
+        assert!(matches!(
+            error,
+            Error::Query(QuerySyntaxError::Unsupported(message))
+                if message.contains("$id equal and in clauses")
+        ));
+
+        // queries built by hand are rejected when executed
+        let mut query = DriveQuery::any_item_query(&contract, contact_request);
+        query.block_time_ms = Some(1000);
+
+        assert!(matches!(
+            query.execute_raw_results_no_proof(&drive, None, None, platform_version),
+            Err(Error::Query(QuerySyntaxError::Unsupported(_)))
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::common::decode::decode_u64;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters, INITIAL_REVISION};
+use dpp::prelude::{Revision, TimestampMillis};
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::{QueryResultElement, QueryResultType};
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches revisions of a document of a type keeping history.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn fetch_document_history_v0(
+        &self,
+        contract_id: [u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<(TimestampMillis, Revision, Document)>, Error> {
+        let path_query = Self::fetch_document_history_query(
+            contract_id,
+            document_type.name().as_str(),
+            document_id,
+            start_at_ms,
+            end_at_ms,
+            limit,
+            offset,
+        )?;
+
+        let (results, _) = self.grove_get_path_query(
+            &path_query,
+            transaction,
+            QueryResultType::QueryKeyElementPairResultType,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        results
+            .elements
+            .into_iter()
+            .map(|result_element| {
+                let QueryResultElement::KeyElementPairResultItem((key, Element::Item(item, _))) =
+                    result_element
+                else {
+                    return Err(Error::Drive(DriveError::CorruptedDocumentPath(
+                        "document history path did not refer to a document element",
+                    )));
+                };
+
+                let time = decode_u64(&key).map_err(|_| {
+                    Error::Drive(DriveError::CorruptedDocumentPath(
+                        "document history key is not a valid u64",
+                    ))
+                })?;
+
+                let document = Document::from_bytes(&item, document_type, platform_version)
+                    .map_err(Error::Protocol)?;
+
+                let revision = document.revision().unwrap_or(INITIAL_REVISION);
+
+                Ok((time, revision, document))
+            })
+            .collect()
+    }
+}
@@ -35,6 +35,11 @@
 mod query_documents;
 pub use query_documents::*;
 
+#[cfg(feature = "full")]
+mod fetch_document_history;
+#[cfg(feature = "full")]
+mod prove_document_history;
+
 #[cfg(feature = "fixtures-and-mocks")]
 use dpp::block::block_info::BlockInfo;
 #[cfg(feature = "fixtures-and-mocks")]
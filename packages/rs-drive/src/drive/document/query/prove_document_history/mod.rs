@@ -0,0 +1,70 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves revisions of a document of a type keeping history.
+    ///
+    /// # Arguments
+    ///
+    /// * `contract_id` - A contract ID as a 32-byte array.
+    /// * `document_type_name` - The name of the document type keeping history.
+    /// * `document_id` - A document ID as a 32-byte array.
+    /// * `start_at_ms` - The time (inclusive) to start the history from.
+    /// * `end_at_ms` - The time (exclusive) to stop the history at.
+    /// * `limit` - The maximum number of revisions to prove.
+    /// * `offset` - The number of revisions to skip.
+    /// * `transaction` - A transaction that requests the proof.
+    /// * `platform_version` - The platform version.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<u8>, Error>` - If successful, returns a `Vec<u8>` containing the proof data.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the path query generation or proof generation fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prove_document_history(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .document
+            .query
+            .prove_document_history
+        {
+            0 => self.prove_document_history_v0(
+                contract_id,
+                document_type_name,
+                document_id,
+                start_at_ms,
+                end_at_ms,
+                limit,
+                offset,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "prove_document_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
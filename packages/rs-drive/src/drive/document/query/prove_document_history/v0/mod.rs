@@ -0,0 +1,40 @@
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves revisions of a document of a type keeping history.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn prove_document_history_v0(
+        &self,
+        contract_id: [u8; 32],
+        document_type_name: &str,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let history_query = Self::fetch_document_history_query(
+            contract_id,
+            document_type_name,
+            document_id,
+            start_at_ms,
+            end_at_ms,
+            limit,
+            offset,
+        )?;
+
+        self.grove_get_proved_path_query(
+            &history_query,
+            false,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )
+    }
+}
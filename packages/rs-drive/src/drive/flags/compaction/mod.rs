@@ -0,0 +1,247 @@
+mod v0;
+
+use crate::drive::flags::StorageFlags;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::epoch::EpochIndex;
+use dpp::version::drive_versions::DriveVersion;
+
+impl StorageFlags {
+    /// Compacts owned multi epoch storage flags by folding epochs which storage was already
+    /// fully paid out to the epoch pools.
+    ///
+    /// Nothing can be refunded for bytes added in such epochs anymore, so they are folded into
+    /// the oldest of them, or into the base epoch if there are no other epochs left. Refunds for
+    /// removing bytes from compacted flags are the same as for the original flags in the current
+    /// and all future epochs.
+    ///
+    /// Storage fees are paid out over `PERPETUAL_STORAGE_EPOCHS` (1000 epochs, about 50 years),
+    /// so no epoch is fully paid out before that and compaction leaves flags unchanged until
+    /// then. Epochs which are still being paid out are never merged, the refund for their bytes
+    /// depends on the storage fees left to distribute from each of them.
+    ///
+    /// Flags without an owner are returned as they are. Bytes removed from them are accounted
+    /// to the system instead of being refunded and folding epochs would change that amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `current_epoch_index` - The epoch the flags are written in.
+    /// * `drive_version` - The drive version to select the correct function version to run.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StorageFlags)` with the compacted flags.
+    /// * `Err(StorageFlagsError::StorageFlagsOverflow)` if the folded bytes overflow.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn compact(
+        self,
+        current_epoch_index: EpochIndex,
+        drive_version: &DriveVersion,
+    ) -> Result<Self, Error> {
+        match drive_version.methods.storage_flags.compact_storage_flags {
+            0 => self.compact_v0(current_epoch_index),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "compact".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::flags::StorageFlags::{
+        MultiEpoch, MultiEpochOwned, SingleEpoch, SingleEpochOwned,
+    };
+    use dpp::block::epoch::EpochIndex;
+    use dpp::fee::epoch::PERPETUAL_STORAGE_EPOCHS;
+    use dpp::fee::fee_result::refunds::FeeRefunds;
+    use dpp::fee::Credits;
+    use dpp::version::drive_versions::DriveVersion;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    const OWNER_ID: [u8; 32] = [7; 32];
+
+    fn total_refunds(fee_refunds: &FeeRefunds) -> Credits {
+        fee_refunds
+            .iter()
+            .flat_map(|(_, credits_per_epoch)| credits_per_epoch.values())
+            .sum()
+    }
+
+    fn random_owned_flags(rng: &mut StdRng) -> StorageFlags {
+        let base_epoch: EpochIndex = rng.gen_range(0..500);
+
+        let mut epoch_index = base_epoch;
+        let other_epoch_bytes = (0..rng.gen_range(1..10))
+            .map(|_| {
+                epoch_index += rng.gen_range(1..400);
+                (epoch_index, rng.gen_range(0..300))
+            })
+            .collect();
+
+        MultiEpochOwned(base_epoch, other_epoch_bytes, OWNER_ID)
+    }
+
+    #[test]
+    fn should_fold_paid_out_epochs_into_the_oldest_one() {
+        let drive_version = DriveVersion::latest();
+
+        let storage_flags = MultiEpochOwned(
+            1,
+            BTreeMap::from([(3, 100), (5, 40), (900, 20), (1500, 60)]),
+            OWNER_ID,
+        );
+
+        let compacted_storage_flags = storage_flags
+            .clone()
+            .compact(PERPETUAL_STORAGE_EPOCHS + 10, &drive_version)
+            .expect("expected to compact storage flags");
+
+        assert_eq!(
+            compacted_storage_flags,
+            MultiEpochOwned(
+                1,
+                BTreeMap::from([(3, 140), (900, 20), (1500, 60)]),
+                OWNER_ID
+            )
+        );
+        assert!(compacted_storage_flags.serialized_size() < storage_flags.serialized_size());
+    }
+
+    #[test]
+    fn should_compact_to_single_epoch_when_all_epochs_are_paid_out() {
+        let drive_version = DriveVersion::latest();
+
+        let storage_flags = MultiEpochOwned(1, BTreeMap::from([(3, 100), (5, 40)]), OWNER_ID);
+
+        let compacted_storage_flags = storage_flags
+            .compact(PERPETUAL_STORAGE_EPOCHS + 4, &drive_version)
+            .expect("expected to compact storage flags");
+
+        assert_eq!(compacted_storage_flags, SingleEpochOwned(1, OWNER_ID));
+    }
+
+    #[test]
+    fn should_not_compact_epochs_that_are_not_paid_out() {
+        let drive_version = DriveVersion::latest();
+
+        let storage_flags = MultiEpochOwned(1, BTreeMap::from([(3, 100), (5, 40)]), OWNER_ID);
+
+        let compacted_storage_flags = storage_flags
+            .clone()
+            .compact(PERPETUAL_STORAGE_EPOCHS + 3, &drive_version)
+            .expect("expected to compact storage flags");
+
+        assert_eq!(compacted_storage_flags, storage_flags);
+    }
+
+    #[test]
+    fn should_return_error_when_folded_bytes_overflow() {
+        let drive_version = DriveVersion::latest();
+
+        let storage_flags = MultiEpochOwned(
+            1,
+            BTreeMap::from([(3, u32::MAX), (5, 1), (1500, 60)]),
+            OWNER_ID,
+        );
+
+        storage_flags
+            .compact(PERPETUAL_STORAGE_EPOCHS + 10, &drive_version)
+            .expect_err("expected folded bytes to overflow");
+    }
+
+    #[test]
+    fn should_not_compact_storage_flags_without_owner() {
+        let drive_version = DriveVersion::latest();
+
+        let storage_flags = MultiEpoch(1, BTreeMap::from([(3, 100), (5, 40)]));
+
+        let compacted_storage_flags = storage_flags
+            .clone()
+            .compact(PERPETUAL_STORAGE_EPOCHS * 2, &drive_version)
+            .expect("expected to compact storage flags");
+
+        assert_eq!(compacted_storage_flags, storage_flags);
+
+        let compacted_storage_flags = SingleEpoch(1)
+            .compact(PERPETUAL_STORAGE_EPOCHS * 2, &drive_version)
+            .expect("expected to compact storage flags");
+
+        assert_eq!(compacted_storage_flags, SingleEpoch(1));
+    }
+
+    #[test]
+    fn should_keep_refunds_of_random_storage_flags_after_compaction() {
+        let drive_version = DriveVersion::latest();
+
+        let mut rng = StdRng::seed_from_u64(5);
+
+        for _ in 0..500 {
+            let storage_flags = random_owned_flags(&mut rng);
+
+            let last_epoch_index = storage_flags
+                .epoch_index_map()
+                .and_then(|epoch_bytes| epoch_bytes.keys().last().copied())
+                .expect("expected multi epoch storage flags");
+
+            let compaction_epoch_index = last_epoch_index + rng.gen_range(0..2500);
+
+            let compacted_storage_flags = storage_flags
+                .clone()
+                .compact(compaction_epoch_index, &drive_version)
+                .expect("expected to compact storage flags");
+
+            assert!(compacted_storage_flags.serialized_size() <= storage_flags.serialized_size());
+            assert_eq!(
+                compacted_storage_flags
+                    .clone()
+                    .compact(compaction_epoch_index, &drive_version)
+                    .expect("expected to compact storage flags"),
+                compacted_storage_flags
+            );
+
+            let total_bytes: u32 = storage_flags
+                .epoch_index_map()
+                .map(|epoch_bytes| epoch_bytes.values().sum())
+                .unwrap_or_default();
+
+            for _ in 0..20 {
+                let removed_key_bytes = rng.gen_range(0..64);
+                let removed_value_bytes = rng.gen_range(0..total_bytes + 100);
+                let removal_epoch_index = compaction_epoch_index + rng.gen_range(0..1200);
+
+                let fee_refunds = storage_flags
+                    .fee_refunds_for_removed_bytes(
+                        removed_key_bytes,
+                        removed_value_bytes,
+                        removal_epoch_index,
+                    )
+                    .expect("expected to calculate refunds");
+
+                let compacted_fee_refunds = compacted_storage_flags
+                    .fee_refunds_for_removed_bytes(
+                        removed_key_bytes,
+                        removed_value_bytes,
+                        removal_epoch_index,
+                    )
+                    .expect("expected to calculate refunds");
+
+                assert_eq!(
+                    total_refunds(&compacted_fee_refunds),
+                    total_refunds(&fee_refunds),
+                    "refunds changed after compacting {:?} into {:?} for removing {} key and {} value bytes in epoch {}",
+                    storage_flags,
+                    compacted_storage_flags,
+                    removed_key_bytes,
+                    removed_value_bytes,
+                    removal_epoch_index,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::drive::flags::StorageFlags;
+use crate::drive::flags::StorageFlags::{MultiEpochOwned, SingleEpochOwned};
+use crate::error::storage_flags::StorageFlagsError;
+use crate::error::Error;
+use dpp::block::epoch::EpochIndex;
+use dpp::fee::epoch::PERPETUAL_STORAGE_EPOCHS;
+use std::collections::BTreeMap;
+
+impl StorageFlags {
+    pub(super) fn compact_v0(self, current_epoch_index: EpochIndex) -> Result<Self, Error> {
+        let MultiEpochOwned(base_epoch, mut other_epoch_bytes, owner_id) = self else {
+            return Ok(self);
+        };
+
+        // Storage added in an epoch is paid out to the epoch pools over the perpetual storage
+        // epochs, once the last of them is processed nothing is left to be refunded
+        let is_paid_out = |epoch_index: EpochIndex| {
+            epoch_index as u32 + PERPETUAL_STORAGE_EPOCHS as u32 <= current_epoch_index as u32 + 1
+        };
+
+        let paid_out_epochs = other_epoch_bytes
+            .keys()
+            .take_while(|epoch_index| is_paid_out(**epoch_index))
+            .count();
+
+        if paid_out_epochs == other_epoch_bytes.len() {
+            // The base epoch precedes all other epochs so it's paid out as well
+            return Ok(SingleEpochOwned(base_epoch, owner_id));
+        }
+
+        if paid_out_epochs < 2 {
+            return Ok(MultiEpochOwned(base_epoch, other_epoch_bytes, owner_id));
+        }
+
+        let Some((oldest_epoch_index, oldest_epoch_bytes)) = other_epoch_bytes.pop_first() else {
+            return Ok(MultiEpochOwned(base_epoch, other_epoch_bytes, owner_id));
+        };
+
+        // Bytes are removed from the oldest epochs first, folding the paid out epochs
+        // into the oldest of them keeps the bytes removed from the remaining epochs the same
+        let mut epoch_bytes = other_epoch_bytes.into_iter();
+        let paid_out_bytes = epoch_bytes
+            .by_ref()
+            .take(paid_out_epochs - 1)
+            .try_fold(oldest_epoch_bytes, |sum, (_, bytes)| sum.checked_add(bytes))
+            .ok_or(Error::StorageFlags(
+                StorageFlagsError::StorageFlagsOverflow("compacted paid out epoch bytes overflow"),
+            ))?;
+
+        let mut compacted_epoch_bytes = BTreeMap::from([(oldest_epoch_index, paid_out_bytes)]);
+        compacted_epoch_bytes.extend(epoch_bytes);
+
+        Ok(MultiEpochOwned(base_epoch, compacted_epoch_bytes, owner_id))
+    }
+}
@@ -30,6 +30,16 @@
 //! Flags
 //!
 
+#[cfg(feature = "full")]
+mod compaction;
+#[cfg(feature = "full")]
+mod refunds;
+#[cfg(feature = "full")]
+mod size_report;
+
+#[cfg(feature = "full")]
+pub use size_report::StorageFlagsSizeReport;
+
 #[cfg(feature = "full")]
 use crate::drive::defaults::DEFAULT_HASH_SIZE;
 #[cfg(feature = "full")]
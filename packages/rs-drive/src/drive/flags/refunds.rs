@@ -0,0 +1,38 @@
+use crate::drive::flags::StorageFlags;
+use crate::error::Error;
+use dpp::block::epoch::EpochIndex;
+use dpp::fee::fee_result::refunds::FeeRefunds;
+use grovedb_costs::storage_cost::removal::StorageRemovedBytes::SectionedStorageRemoval;
+
+impl StorageFlags {
+    /// Calculates the refunds owners would get for removing bytes of an element with these flags
+    /// in the given epoch.
+    ///
+    /// Bytes are split between epochs the same way as when the element is removed from GroveDB,
+    /// and bytes that don't belong to an owner are not refunded.
+    pub fn fee_refunds_for_removed_bytes(
+        &self,
+        removed_key_bytes: u32,
+        removed_value_bytes: u32,
+        current_epoch_index: EpochIndex,
+    ) -> Result<FeeRefunds, Error> {
+        let (key_storage_removal, value_storage_removal) = self
+            .split_storage_removed_bytes(removed_key_bytes, removed_value_bytes)
+            .map_err(Error::GroveDB)?;
+
+        let mut fee_refunds = FeeRefunds::default();
+
+        for storage_removal in [key_storage_removal, value_storage_removal] {
+            if let SectionedStorageRemoval(mut removal_per_epoch_by_identifier) = storage_removal {
+                removal_per_epoch_by_identifier.remove(&[0; 32]);
+
+                fee_refunds.checked_add_assign(FeeRefunds::from_storage_removal(
+                    removal_per_epoch_by_identifier,
+                    current_epoch_index,
+                )?)?;
+            }
+        }
+
+        Ok(fee_refunds)
+    }
+}
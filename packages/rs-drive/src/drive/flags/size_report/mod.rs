@@ -0,0 +1,166 @@
+mod v0;
+
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::epoch::EpochIndex;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::collections::BTreeMap;
+
+/// How many elements are read from GroveDB at once when building a storage flags size report
+pub(crate) const STORAGE_FLAGS_SIZE_REPORT_PAGE_SIZE: u16 = 100;
+
+/// Sizes of storage flags of elements directly in a subtree
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StorageFlagsSizeReport {
+    /// Number of elements in the subtree
+    pub elements: u64,
+    /// Number of elements with storage flags
+    pub flagged_elements: u64,
+    /// Number of elements with single epoch storage flags
+    pub single_epoch_flags: u64,
+    /// Number of elements with multi epoch storage flags
+    pub multi_epoch_flags: u64,
+    /// Number of elements with storage flags that have an owner
+    pub owned_flags: u64,
+    /// Serialized size of all storage flags
+    pub flags_bytes: u64,
+    /// Number of epochs other than the base epoch in all storage flags
+    pub epoch_entries: u64,
+    /// The biggest number of epochs other than the base epoch in a single storage flags
+    pub max_epoch_entries: u64,
+    /// How many bytes compacting all storage flags would save in the current epoch
+    pub compactable_bytes: u64,
+}
+
+impl Drive {
+    /// Reports sizes of storage flags of elements in a subtree and in all subtrees below it.
+    ///
+    /// Multi epoch storage flags grow with every epoch the element is updated in, the report
+    /// shows where they take the most space and how much compacting them would save.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the subtree to start from.
+    /// * `current_epoch_index` - The epoch used to calculate how many bytes can be compacted.
+    /// * `transaction` - The GroveDB transaction to read the state from.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BTreeMap<Vec<Vec<u8>>, StorageFlagsSizeReport>)` with a report for every subtree path.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn storage_flags_size_report(
+        &self,
+        path: Vec<Vec<u8>>,
+        current_epoch_index: EpochIndex,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Vec<Vec<u8>>, StorageFlagsSizeReport>, Error> {
+        match platform_version
+            .drive
+            .methods
+            .storage_flags
+            .storage_flags_size_report
+        {
+            0 => self.storage_flags_size_report_v0(
+                path,
+                current_epoch_index,
+                transaction,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "storage_flags_size_report".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::flags::size_report::StorageFlagsSizeReport;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::RootTree;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::version::PlatformVersion;
+    use grovedb::Element;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn should_report_storage_flags_sizes_per_subtree() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let transaction = drive.grove.start_transaction();
+
+        let path = vec![vec![RootTree::Misc as u8]];
+        let owner_id = [3; 32];
+
+        let single_epoch_flags = StorageFlags::new_single_epoch(2, Some(owner_id));
+        let multi_epoch_flags = StorageFlags::MultiEpochOwned(
+            1,
+            BTreeMap::from([(3, 100), (5, 40), (2000, 20)]),
+            owner_id,
+        );
+
+        drive
+            .grove
+            .insert(
+                path.as_slice(),
+                b"report",
+                Element::empty_tree_with_flags(single_epoch_flags.to_some_element_flags()),
+                None,
+                Some(&transaction),
+            )
+            .unwrap()
+            .expect("expected to insert tree");
+
+        let mut subtree_path = path.clone();
+        subtree_path.push(b"report".to_vec());
+
+        drive
+            .grove
+            .insert(
+                subtree_path.as_slice(),
+                b"item",
+                Element::new_item_with_flags(
+                    vec![1; 10],
+                    multi_epoch_flags.to_some_element_flags(),
+                ),
+                None,
+                Some(&transaction),
+            )
+            .unwrap()
+            .expect("expected to insert item");
+
+        let report = drive
+            .storage_flags_size_report(path, 2010, Some(&transaction), platform_version)
+            .expect("expected to build storage flags size report");
+
+        let subtree_report = report
+            .get(&subtree_path)
+            .expect("expected a report for the subtree");
+
+        let compacted_flags_size =
+            StorageFlags::MultiEpochOwned(1, BTreeMap::from([(3, 140), (2000, 20)]), owner_id)
+                .serialized_size();
+
+        assert_eq!(
+            subtree_report,
+            &StorageFlagsSizeReport {
+                elements: 1,
+                flagged_elements: 1,
+                single_epoch_flags: 0,
+                multi_epoch_flags: 1,
+                owned_flags: 1,
+                flags_bytes: multi_epoch_flags.serialized_size() as u64,
+                epoch_entries: 3,
+                max_epoch_entries: 3,
+                compactable_bytes: (multi_epoch_flags.serialized_size() - compacted_flags_size)
+                    as u64,
+            }
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use crate::drive::flags::size_report::{
+    StorageFlagsSizeReport, STORAGE_FLAGS_SIZE_REPORT_PAGE_SIZE,
+};
+use crate::drive::flags::StorageFlags;
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::block::epoch::EpochIndex;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+use std::collections::BTreeMap;
+
+impl Drive {
+    pub(super) fn storage_flags_size_report_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        current_epoch_index: EpochIndex,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<BTreeMap<Vec<Vec<u8>>, StorageFlagsSizeReport>, Error> {
+        let mut reports = BTreeMap::new();
+        let mut subtree_paths = vec![path];
+
+        while let Some(subtree_path) = subtree_paths.pop() {
+            let mut report = StorageFlagsSizeReport::default();
+            let mut start_after = None;
+
+            loop {
+                let page = self.storage_flags_size_report_page_v0(
+                    subtree_path.clone(),
+                    start_after,
+                    transaction,
+                )?;
+                let page_len = page.len();
+                start_after = page.last().map(|(key, _)| key.clone());
+
+                for (key, element) in page {
+                    report.elements += 1;
+
+                    if let Some(storage_flags) =
+                        StorageFlags::map_some_element_flags_ref(element.get_flags())?
+                    {
+                        report.add_storage_flags_v0(
+                            storage_flags,
+                            current_epoch_index,
+                            platform_version,
+                        )?;
+                    }
+
+                    if matches!(element, Element::Tree(..) | Element::SumTree(..)) {
+                        let mut child_path = subtree_path.clone();
+                        child_path.push(key);
+                        subtree_paths.push(child_path);
+                    }
+                }
+
+                if page_len < STORAGE_FLAGS_SIZE_REPORT_PAGE_SIZE as usize {
+                    break;
+                }
+            }
+
+            reports.insert(subtree_path, report);
+        }
+
+        Ok(reports)
+    }
+
+    /// Returns a page of elements of a tree, after the given key
+    fn storage_flags_size_report_page_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        start_after: Option<Vec<u8>>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<(Vec<u8>, Element)>, Error> {
+        let mut query = Query::new();
+
+        match start_after {
+            Some(key) => query.insert_range_after(key..),
+            None => query.insert_all(),
+        }
+
+        let path_query = PathQuery::new(
+            path,
+            SizedQuery::new(query, Some(STORAGE_FLAGS_SIZE_REPORT_PAGE_SIZE), None),
+        );
+
+        let (query_result, _) = self
+            .grove
+            .query_raw(
+                &path_query,
+                transaction.is_some(),
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        Ok(query_result.to_key_elements())
+    }
+}
+
+impl StorageFlagsSizeReport {
+    fn add_storage_flags_v0(
+        &mut self,
+        storage_flags: StorageFlags,
+        current_epoch_index: EpochIndex,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let flags_size = storage_flags.serialized_size() as u64;
+        let epoch_entries = storage_flags
+            .epoch_index_map()
+            .map_or(0, |epoch_bytes| epoch_bytes.len() as u64);
+
+        self.flagged_elements += 1;
+        self.flags_bytes += flags_size;
+        self.epoch_entries += epoch_entries;
+        self.max_epoch_entries = self.max_epoch_entries.max(epoch_entries);
+
+        if storage_flags.owner_id().is_some() {
+            self.owned_flags += 1;
+        }
+
+        if storage_flags.epoch_index_map().is_some() {
+            self.multi_epoch_flags += 1;
+        } else {
+            self.single_epoch_flags += 1;
+        }
+
+        let compacted_flags_size = storage_flags
+            .compact(current_epoch_index, &platform_version.drive)?
+            .serialized_size() as u64;
+
+        self.compactable_bytes += flags_size.saturating_sub(compacted_flags_size);
+
+        Ok(())
+    }
+}
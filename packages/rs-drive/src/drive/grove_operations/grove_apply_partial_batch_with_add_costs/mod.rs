@@ -1,4 +1,5 @@
 mod v0;
+mod v1;
 
 use crate::drive::batch::GroveDbOpBatch;
 
@@ -52,9 +53,17 @@ impl Drive {
                 add_on_operations,
                 drive_operations,
             ),
+            1 => self.grove_apply_partial_batch_with_add_costs_v1(
+                ops,
+                validate,
+                transaction,
+                add_on_operations,
+                drive_operations,
+                drive_version,
+            ),
             version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
                 method: "grove_apply_partial_batch_with_add_costs".to_string(),
-                known_versions: vec![0],
+                known_versions: vec![0, 1],
                 received: version,
             })),
         }
@@ -7,6 +7,7 @@ use dpp::platform_value::Value;
 use dpp::system_data_contracts::withdrawals_contract;
 use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
 use dpp::version::PlatformVersion;
+use dpp::withdrawal::Pooling;
 use grovedb::TransactionArg;
 use indexmap::IndexMap;
 
@@ -97,6 +98,103 @@ impl Drive {
         Ok(outcome.documents_owned())
     }
 
+    /// Fetch withdrawal documents by their status and pooling preference
+    /// ordered by fee per byte tier and update time, using the `pooling` index
+    pub fn fetch_withdrawal_documents_by_status_and_pooling(
+        &self,
+        status: u8,
+        pooling: Pooling,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<Vec<Document>, Error> {
+        let data_contract_id = withdrawals_contract::ID;
+
+        let contract_fetch_info = self
+            .get_contract_with_fetch_info_and_fee(
+                data_contract_id.to_buffer(),
+                None,
+                true,
+                transaction,
+                platform_version,
+            )?
+            .1
+            .ok_or_else(|| {
+                Error::Drive(DriveError::CorruptedCodeExecution(
+                    "Can't fetch data contract",
+                ))
+            })?;
+
+        let document_type = contract_fetch_info
+            .contract
+            .document_type_for_name(withdrawal::NAME)?;
+
+        let mut where_clauses = BTreeMap::new();
+
+        where_clauses.insert(
+            withdrawal::properties::STATUS.to_string(),
+            WhereClause {
+                field: withdrawal::properties::STATUS.to_string(),
+                operator: crate::query::WhereOperator::Equal,
+                value: Value::U8(status),
+            },
+        );
+
+        where_clauses.insert(
+            withdrawal::properties::POOLING.to_string(),
+            WhereClause {
+                field: withdrawal::properties::POOLING.to_string(),
+                operator: crate::query::WhereOperator::Equal,
+                value: Value::U8(pooling as u8),
+            },
+        );
+
+        let mut order_by = IndexMap::new();
+
+        order_by.insert(
+            withdrawal::properties::CORE_FEE_PER_BYTE.to_string(),
+            OrderClause {
+                field: withdrawal::properties::CORE_FEE_PER_BYTE.to_string(),
+                ascending: true,
+            },
+        );
+
+        order_by.insert(
+            withdrawal::properties::UPDATED_AT.to_string(),
+            OrderClause {
+                field: withdrawal::properties::UPDATED_AT.to_string(),
+                ascending: true,
+            },
+        );
+
+        let drive_query = DriveQuery {
+            contract: &contract_fetch_info.contract,
+            document_type,
+            internal_clauses: InternalClauses {
+                primary_key_in_clause: None,
+                primary_key_equal_clause: None,
+                in_clause: None,
+                range_clause: None,
+                equal_clauses: where_clauses,
+            },
+            offset: None,
+            limit: Some(100),
+            order_by,
+            start_at: None,
+            start_at_included: false,
+            block_time_ms: None,
+        };
+
+        let outcome = self.query_documents(
+            drive_query,
+            None,
+            false,
+            transaction,
+            Some(platform_version.protocol_version),
+        )?;
+
+        Ok(outcome.documents_owned())
+    }
+
     /// Find one document by it's transactionId field
     pub fn find_withdrawal_document_by_transaction_id(
         &self,
@@ -300,6 +398,93 @@ mod tests {
         }
     }
 
+    mod fetch_withdrawal_documents_by_status_and_pooling {
+        use super::*;
+        use dpp::data_contract::accessors::v0::DataContractV0Getters;
+        use dpp::document::DocumentV0Getters;
+        use dpp::identity::core_script::CoreScript;
+        use dpp::platform_value::btreemap_extensions::BTreeValueMapHelper;
+        use dpp::platform_value::platform_value;
+        use dpp::system_data_contracts::withdrawals_contract::document_types::withdrawal;
+        use dpp::system_data_contracts::{load_system_data_contract, SystemDataContract};
+        use dpp::version::PlatformVersion;
+        use dpp::withdrawal::Pooling;
+
+        #[test]
+        fn test_return_documents_with_pooling_ordered_by_fee() {
+            let drive = setup_drive_with_initial_state_structure();
+
+            let transaction = drive.grove.start_transaction();
+
+            let platform_version = PlatformVersion::latest();
+
+            let data_contract = load_system_data_contract(
+                SystemDataContract::Withdrawals,
+                platform_version.protocol_version,
+            )
+            .expect("to load system data contract");
+
+            setup_system_data_contract(&drive, &data_contract, Some(&transaction));
+
+            let document_type = data_contract
+                .document_type_for_name(withdrawal::NAME)
+                .expect("expected to get document type");
+
+            let owner_id = Identifier::new([1u8; 32]);
+
+            for (pooling, core_fee_per_byte) in [
+                (Pooling::Standard, 2u32),
+                (Pooling::Never, 1u32),
+                (Pooling::Standard, 1u32),
+            ] {
+                let document = get_withdrawal_document_fixture(
+                    &data_contract,
+                    owner_id,
+                    platform_value!({
+                        "amount": 1000u64,
+                        "coreFeePerByte": core_fee_per_byte,
+                        "pooling": pooling as u8,
+                        "outputScript": CoreScript::from_bytes((0..23).collect::<Vec<u8>>()),
+                        "status": withdrawals_contract::WithdrawalStatus::QUEUED as u8,
+                        "transactionIndex": 1u64,
+                    }),
+                    None,
+                    platform_version.protocol_version,
+                )
+                .expect("expected withdrawal document");
+
+                setup_document(
+                    &drive,
+                    &document,
+                    &data_contract,
+                    document_type,
+                    Some(&transaction),
+                );
+            }
+
+            let documents = drive
+                .fetch_withdrawal_documents_by_status_and_pooling(
+                    withdrawals_contract::WithdrawalStatus::QUEUED.into(),
+                    Pooling::Standard,
+                    Some(&transaction),
+                    platform_version,
+                )
+                .expect("to fetch documents by status and pooling");
+
+            let fees: Vec<u32> = documents
+                .iter()
+                .map(|document| {
+                    document
+                        .properties()
+                        .get_integer(withdrawal::properties::CORE_FEE_PER_BYTE)
+                        .expect("expected core fee per byte")
+                })
+                .collect();
+
+            assert_eq!(fees, vec![1, 2]);
+        }
+    }
+
     mod find_document_by_transaction_id {
         use dpp::data_contract::accessors::v0::DataContractV0Getters;
         use dpp::document::DocumentV0Getters;
@@ -6,6 +6,12 @@ pub mod paths;
 pub mod queue;
 /// Functions related to transaction index counter
 pub mod transaction_index;
+/// Functions related to the rolling window of withdrawn amounts
+pub mod withdrawn_amounts;
 
 /// Simple type alias for withdrawal transaction with it's id
 pub type WithdrawalTransactionIdAndBytes = (Vec<u8>, Vec<u8>);
+
+/// Number of core blocks a signed asset unlock transaction has to be mined in
+/// before the withdrawal expires
+pub const NUMBER_OF_BLOCKS_BEFORE_EXPIRED: u32 = 48;
@@ -9,6 +9,8 @@ pub const WITHDRAWAL_TRANSACTIONS_COUNTER_ID: [u8; 1] = [0];
 pub const WITHDRAWAL_TRANSACTIONS_QUEUE_ID: [u8; 1] = [1];
 /// constant id for subtree containing expired transaction ids
 pub const WITHDRAWAL_TRANSACTIONS_EXPIRED_IDS: [u8; 1] = [2];
+/// constant id for sum tree containing amounts withdrawn per block time
+pub const WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS: [u8; 1] = [3];
 
 /// Add operations for creating initial withdrawal state structure
 pub fn add_initial_withdrawal_state_structure_operations(batch: &mut GroveDbOpBatch) {
@@ -27,6 +29,11 @@ pub fn add_initial_withdrawal_state_structure_operations(batch: &mut GroveDbOpBa
         vec![vec![RootTree::WithdrawalTransactions as u8]],
         WITHDRAWAL_TRANSACTIONS_EXPIRED_IDS.to_vec(),
     );
+
+    batch.add_insert_empty_sum_tree(
+        vec![vec![RootTree::WithdrawalTransactions as u8]],
+        WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS.to_vec(),
+    );
 }
 
 /// Helper function to get root path
@@ -70,3 +77,19 @@ pub fn get_withdrawal_transactions_expired_ids_path() -> [&'static [u8]; 2] {
         &WITHDRAWAL_TRANSACTIONS_EXPIRED_IDS,
     ]
 }
+
+/// Helper function to get withdrawn amounts path as Vec
+pub fn get_withdrawal_transactions_withdrawn_amounts_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::WithdrawalTransactions as u8],
+        WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS.to_vec(),
+    ]
+}
+
+/// Helper function to get withdrawn amounts path as [u8]
+pub fn get_withdrawal_transactions_withdrawn_amounts_path() -> [&'static [u8]; 2] {
+    [
+        Into::<&[u8; 1]>::into(RootTree::WithdrawalTransactions),
+        &WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS,
+    ]
+}
@@ -0,0 +1,237 @@
+use dpp::fee::Credits;
+use grovedb::{
+    query_result_type::{QueryResultElement, QueryResultType},
+    Element, PathQuery, Query, SizedQuery, TransactionArg,
+};
+
+use crate::{
+    drive::{
+        batch::{drive_op_batch::WithdrawalOperationType, DriveOperation},
+        Drive,
+    },
+    error::{drive::DriveError, Error},
+};
+
+use super::paths::{
+    get_withdrawal_root_path, get_withdrawal_transactions_withdrawn_amounts_path_vec,
+    WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS,
+};
+
+impl Drive {
+    /// Get the amount of credits withdrawn since `window_start_time_ms` and add operations
+    /// removing amounts withdrawn before it from the rolling window.
+    ///
+    /// The rolling window tree is created on first use on chains started before it existed.
+    pub fn fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+        &self,
+        window_start_time_ms: u64,
+        drive_operation_types: &mut Vec<DriveOperation>,
+        transaction: TransactionArg,
+    ) -> Result<Credits, Error> {
+        let total = match self
+            .grove
+            .get(
+                &get_withdrawal_root_path(),
+                &WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS,
+                transaction,
+            )
+            .unwrap()
+        {
+            Ok(Element::SumTree(_, total, _)) => total,
+            Ok(_) => {
+                return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                    "withdrawn amounts must be a sum tree",
+                )))
+            }
+            Err(grovedb::Error::PathKeyNotFound(_)) => {
+                drive_operation_types.push(DriveOperation::WithdrawalOperation(
+                    WithdrawalOperationType::InsertWithdrawnAmountsTree,
+                ));
+
+                return Ok(0);
+            }
+            Err(e) => return Err(Error::GroveDB(e)),
+        };
+
+        let mut expired_query = Query::new();
+
+        expired_query.insert_range_to(..window_start_time_ms.to_be_bytes().to_vec());
+
+        let expired_path_query = PathQuery::new(
+            get_withdrawal_transactions_withdrawn_amounts_path_vec(),
+            SizedQuery::new(expired_query, None, None),
+        );
+
+        let (expired_elements, _) = self
+            .grove
+            .query_raw(
+                &expired_path_query,
+                transaction.is_some(),
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()?;
+
+        let mut expired = 0;
+
+        for expired_element in expired_elements.elements {
+            let QueryResultElement::KeyElementPairResultItem((key, Element::SumItem(amount, _))) =
+                expired_element
+            else {
+                return Err(Error::Drive(DriveError::CorruptedCodeExecution(
+                    "withdrawn amount must be a sum item",
+                )));
+            };
+
+            expired += amount;
+
+            drive_operation_types.push(DriveOperation::WithdrawalOperation(
+                WithdrawalOperationType::DeleteWithdrawnAmount { key },
+            ));
+        }
+
+        Ok((total - expired) as Credits)
+    }
+
+    /// Add operations recording the amount withdrawn at a block time to the rolling window
+    pub fn add_insert_withdrawn_amount_operation(
+        &self,
+        time_ms: u64,
+        amount: Credits,
+        drive_operation_types: &mut Vec<DriveOperation>,
+    ) {
+        drive_operation_types.push(DriveOperation::WithdrawalOperation(
+            WithdrawalOperationType::InsertWithdrawnAmount { time_ms, amount },
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dpp::block::block_info::BlockInfo;
+    use dpp::version::PlatformVersion;
+
+    use crate::drive::identity::withdrawals::paths::{
+        get_withdrawal_root_path, WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS,
+    };
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+
+    #[test]
+    fn test_withdrawn_amount_in_rolling_window() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let transaction = drive.grove.start_transaction();
+
+        let mut batch = vec![];
+
+        drive.add_insert_withdrawn_amount_operation(1000, 100, &mut batch);
+        drive.add_insert_withdrawn_amount_operation(2000, 200, &mut batch);
+        drive.add_insert_withdrawn_amount_operation(3000, 300, &mut batch);
+
+        drive
+            .apply_drive_operations(
+                batch,
+                true,
+                &BlockInfo::default(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to apply drive ops");
+
+        let mut batch = vec![];
+
+        let withdrawn = drive
+            .fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+                2000,
+                &mut batch,
+                Some(&transaction),
+            )
+            .expect("to fetch withdrawn amount");
+
+        assert_eq!(withdrawn, 500);
+        assert_eq!(batch.len(), 1);
+
+        drive
+            .apply_drive_operations(
+                batch,
+                true,
+                &BlockInfo::default(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to apply drive ops");
+
+        let mut batch = vec![];
+
+        let withdrawn = drive
+            .fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+                2000,
+                &mut batch,
+                Some(&transaction),
+            )
+            .expect("to fetch withdrawn amount");
+
+        assert_eq!(withdrawn, 500);
+        assert!(batch.is_empty());
+    }
+
+    #[test]
+    fn test_withdrawn_amounts_tree_is_created_on_first_use() {
+        let drive = setup_drive_with_initial_state_structure();
+
+        let platform_version = PlatformVersion::latest();
+
+        let transaction = drive.grove.start_transaction();
+
+        // Chains started before the rolling window was introduced don't have the tree
+        drive
+            .grove
+            .delete(
+                &get_withdrawal_root_path(),
+                WITHDRAWAL_TRANSACTIONS_WITHDRAWN_AMOUNTS.as_slice(),
+                None,
+                Some(&transaction),
+            )
+            .unwrap()
+            .expect("should delete withdrawn amounts tree");
+
+        let mut batch = vec![];
+
+        let withdrawn = drive
+            .fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+                0,
+                &mut batch,
+                Some(&transaction),
+            )
+            .expect("to fetch withdrawn amount");
+
+        assert_eq!(withdrawn, 0);
+
+        drive.add_insert_withdrawn_amount_operation(1000, 100, &mut batch);
+
+        drive
+            .apply_drive_operations(
+                batch,
+                true,
+                &BlockInfo::default(),
+                Some(&transaction),
+                platform_version,
+            )
+            .expect("to apply drive ops");
+
+        let mut batch = vec![];
+
+        let withdrawn = drive
+            .fetch_withdrawn_amount_in_window_and_remove_expired_operations(
+                0,
+                &mut batch,
+                Some(&transaction),
+            )
+            .expect("to fetch withdrawn amount");
+
+        assert_eq!(withdrawn, 100);
+        assert!(batch.is_empty());
+    }
+}
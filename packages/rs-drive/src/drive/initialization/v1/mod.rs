@@ -0,0 +1,105 @@
+//! Drive Initialization
+
+use crate::drive::balances::{
+    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+};
+use crate::drive::batch::GroveDbOpBatch;
+
+use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+use crate::drive::system::misc_path_vec;
+use crate::drive::Drive;
+use crate::error::Error;
+
+use dpp::version::PlatformVersion;
+use grovedb::{Element, TransactionArg};
+use integer_encoding::VarInt;
+
+impl Drive {
+    /// Creates the initial state structure with the credit supply counters.
+    pub(super) fn create_initial_state_structure_1(
+        &self,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        self.create_initial_state_structure_0(transaction, platform_version)?;
+
+        let mut batch = GroveDbOpBatch::new();
+
+        // In Misc
+        batch.add_insert(
+            misc_path_vec(),
+            TOTAL_MINTED_CREDITS_STORAGE_KEY.to_vec(),
+            Element::Item(0.encode_var_vec(), None),
+        );
+
+        batch.add_insert(
+            misc_path_vec(),
+            TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY.to_vec(),
+            Element::Item(0.encode_var_vec(), None),
+        );
+
+        self.grove_apply_batch(batch, false, transaction, &platform_version.drive)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(test)]
+mod tests {
+    use crate::drive::balances::{
+        TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+    };
+    use crate::drive::grove_operations::DirectQueryType;
+    use crate::drive::system::misc_path;
+    use crate::drive::Drive;
+
+    use dpp::version::PlatformVersion;
+    use tempfile::TempDir;
+
+    fn fetch_counter(
+        drive: &Drive,
+        key: &[u8; 1],
+        platform_version: &PlatformVersion,
+    ) -> Option<u64> {
+        let path = misc_path();
+        drive
+            .grove_get_raw_value_u64_from_encoded_var_vec(
+                (&path).into(),
+                key,
+                DirectQueryType::StatefulDirectQuery,
+                None,
+                &mut vec![],
+                &platform_version.drive,
+            )
+            .expect("expected to fetch the counter")
+    }
+
+    #[test]
+    fn should_create_credit_supply_counters_from_structure_version_1() {
+        for (platform_version, expected_counter) in [
+            (PlatformVersion::first(), None),
+            (PlatformVersion::latest(), Some(0)),
+        ] {
+            let tmp_dir = TempDir::new().unwrap();
+            let drive: Drive = Drive::open(tmp_dir, None).expect("should open Drive successfully");
+
+            drive
+                .create_initial_state_structure(None, platform_version)
+                .expect("expected to create structure");
+
+            assert_eq!(
+                fetch_counter(&drive, TOTAL_MINTED_CREDITS_STORAGE_KEY, platform_version),
+                expected_counter
+            );
+            assert_eq!(
+                fetch_counter(
+                    &drive,
+                    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY,
+                    platform_version
+                ),
+                expected_counter
+            );
+        }
+    }
+}
@@ -75,6 +75,9 @@ pub mod object_size_info;
 mod protocol_upgrade;
 #[cfg(feature = "full")]
 mod shared_estimation_costs;
+/// State export and import
+#[cfg(feature = "full")]
+pub mod state_archive;
 #[cfg(feature = "full")]
 mod system;
 #[cfg(test)]
@@ -94,6 +97,14 @@ mod system_contracts_cache;
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod verify;
 
+/// Contested resources and masternode votes
+#[cfg(any(feature = "full", feature = "verify"))]
+pub mod votes;
+
+/// Fee sponsorship spending limits
+#[cfg(feature = "full")]
+pub mod sponsorship;
+
 #[cfg(feature = "full")]
 use crate::drive::cache::DriveCache;
 
@@ -0,0 +1,235 @@
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::sponsorship::paths::sponsor_path_vec;
+use crate::drive::sponsorship::SponsorshipSpending;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::platform_value::Identifier;
+use dpp::version::drive_versions::DriveVersion;
+use grovedb::{Element, TransactionArg};
+
+impl Drive {
+    /// Fetches the spending limit of a sponsor for a data contract,
+    /// `None` if the sponsor didn't set a limit for it
+    pub fn fetch_sponsorship_spending(
+        &self,
+        sponsor_id: Identifier,
+        data_contract_id: Identifier,
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Option<SponsorshipSpending>, Error> {
+        self.fetch_sponsorship_spending_operations(
+            sponsor_id,
+            data_contract_id,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+
+    /// Fetches the spending limit of a sponsor for a data contract and adds the cost
+    /// of the lookup to the drive operations
+    pub(crate) fn fetch_sponsorship_spending_operations(
+        &self,
+        sponsor_id: Identifier,
+        data_contract_id: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        drive_version: &DriveVersion,
+    ) -> Result<Option<SponsorshipSpending>, Error> {
+        // Missing parent trees are reported as a missing element
+        let element = self.grove_get_raw_optional(
+            sponsor_path_vec(&sponsor_id.to_buffer()).as_slice().into(),
+            data_contract_id.as_slice(),
+            StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            drive_version,
+        )?;
+
+        match element {
+            Some(Element::Item(bytes, _)) => SponsorshipSpending::from_bytes(&bytes).map(Some),
+            Some(_) => Err(Error::Drive(DriveError::CorruptedElementType(
+                "sponsorship spending must be an item",
+            ))),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::DriveOperation::SponsorshipOperation;
+    use crate::drive::batch::SponsorshipOperationType;
+    use crate::drive::sponsorship::SponsorshipSpending;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::identity::sponsorship::SponsorshipSpendingLimit;
+    use dpp::platform_value::Identifier;
+    use dpp::version::PlatformVersion;
+
+    #[test]
+    fn should_track_spent_credits_until_limit_is_removed() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let sponsor_id = Identifier::new([1; 32]);
+        let data_contract_id = Identifier::new([2; 32]);
+
+        let fetch_spending = || {
+            drive
+                .fetch_sponsorship_spending(
+                    sponsor_id,
+                    data_contract_id,
+                    None,
+                    &platform_version.drive,
+                )
+                .expect("expected to fetch spending")
+        };
+
+        assert_eq!(fetch_spending(), None);
+
+        let set_limit = |limit| {
+            drive
+                .apply_drive_operations(
+                    vec![SponsorshipOperation(
+                        SponsorshipOperationType::SetSpendingLimits {
+                            sponsor_id,
+                            spending_limits: vec![SponsorshipSpendingLimit {
+                                data_contract_id,
+                                limit,
+                            }],
+                        },
+                    )],
+                    true,
+                    &BlockInfo::default(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to set spending limit");
+        };
+
+        set_limit(Some(1000));
+
+        drive
+            .apply_drive_operations(
+                vec![SponsorshipOperation(
+                    SponsorshipOperationType::AddSpentCredits {
+                        sponsor_id,
+                        data_contract_id,
+                        spent_credits: 300,
+                        refunded_credits: 0,
+                    },
+                )],
+                true,
+                &BlockInfo::default(),
+                None,
+                platform_version,
+            )
+            .expect("expected to add spent credits");
+
+        assert_eq!(
+            fetch_spending(),
+            Some(SponsorshipSpending {
+                limit: 1000,
+                spent: 300,
+            })
+        );
+
+        // Raising the limit keeps the spent credits
+        set_limit(Some(2000));
+
+        assert_eq!(
+            fetch_spending(),
+            Some(SponsorshipSpending {
+                limit: 2000,
+                spent: 300,
+            })
+        );
+
+        set_limit(None);
+
+        assert_eq!(fetch_spending(), None);
+    }
+
+    #[test]
+    fn should_deduct_refunded_credits_from_spent_credits() {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+
+        let sponsor_id = Identifier::new([1; 32]);
+        let data_contract_id = Identifier::new([2; 32]);
+
+        let apply = |operation| {
+            drive
+                .apply_drive_operations(
+                    vec![SponsorshipOperation(operation)],
+                    true,
+                    &BlockInfo::default(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to apply sponsorship operation");
+        };
+
+        let fetch_spent = || {
+            drive
+                .fetch_sponsorship_spending(
+                    sponsor_id,
+                    data_contract_id,
+                    None,
+                    &platform_version.drive,
+                )
+                .expect("expected to fetch spending")
+                .map(|spending| spending.spent)
+        };
+
+        // Nothing is recorded without a spending limit
+        apply(SponsorshipOperationType::AddSpentCredits {
+            sponsor_id,
+            data_contract_id,
+            spent_credits: 300,
+            refunded_credits: 0,
+        });
+
+        assert_eq!(fetch_spent(), None);
+
+        apply(SponsorshipOperationType::SetSpendingLimits {
+            sponsor_id,
+            spending_limits: vec![SponsorshipSpendingLimit {
+                data_contract_id,
+                limit: Some(1000),
+            }],
+        });
+
+        apply(SponsorshipOperationType::AddSpentCredits {
+            sponsor_id,
+            data_contract_id,
+            spent_credits: 500,
+            refunded_credits: 0,
+        });
+
+        assert_eq!(fetch_spent(), Some(500));
+
+        // A sponsored deletion refunds the storage the sponsor paid for
+        apply(SponsorshipOperationType::AddSpentCredits {
+            sponsor_id,
+            data_contract_id,
+            spent_credits: 100,
+            refunded_credits: 400,
+        });
+
+        assert_eq!(fetch_spent(), Some(200));
+
+        // Spent credits never go below zero
+        apply(SponsorshipOperationType::AddSpentCredits {
+            sponsor_id,
+            data_contract_id,
+            spent_credits: 0,
+            refunded_credits: 1000,
+        });
+
+        assert_eq!(fetch_spent(), Some(0));
+    }
+}
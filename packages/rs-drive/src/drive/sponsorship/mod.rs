@@ -0,0 +1,87 @@
+//! Fee sponsorship spending limits.
+//!
+//! A sponsor can pay the fees of documents batches submitted by other identities. To cap its
+//! exposure the sponsor sets a spending limit for every data contract it sponsors, the credits
+//! spent on sponsored transitions are accumulated next to the limit.
+//!
+//! Spending limits are stored in the Misc tree:
+//!
+//! ```text
+//! Misc/s/<sponsor id>/<data contract id>    the limit followed by the spent credits
+//! ```
+
+#[cfg(feature = "full")]
+mod fetch;
+/// Paths of sponsorship spending limits
+pub mod paths;
+
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::fee::Credits;
+
+/// The spending limit of a sponsor for a data contract
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SponsorshipSpending {
+    /// The maximum amount of credits the sponsor is willing to pay
+    pub limit: Credits,
+    /// The credits already spent on sponsored transitions
+    pub spent: Credits,
+}
+
+impl SponsorshipSpending {
+    /// The credits that can still be spent before the limit is reached
+    pub fn remaining(&self) -> Credits {
+        self.limit.saturating_sub(self.spent)
+    }
+
+    /// Serializes the limit followed by the spent credits
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.limit.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&self.spent.to_be_bytes());
+        bytes
+    }
+
+    /// Deserializes the limit followed by the spent credits
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let bytes: [u8; 16] = bytes.try_into().map_err(|_| {
+            Error::Drive(DriveError::CorruptedSerialization(
+                "sponsorship spending must be 16 bytes",
+            ))
+        })?;
+
+        let (limit, spent) = bytes.split_at(8);
+
+        Ok(SponsorshipSpending {
+            limit: Credits::from_be_bytes(limit.try_into().expect("expected 8 bytes")),
+            spent: Credits::from_be_bytes(spent.try_into().expect("expected 8 bytes")),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_and_deserialize_spending() {
+        let spending = SponsorshipSpending {
+            limit: 1000,
+            spent: 400,
+        };
+
+        let bytes = spending.to_bytes();
+
+        assert_eq!(SponsorshipSpending::from_bytes(&bytes).unwrap(), spending);
+        assert_eq!(spending.remaining(), 600);
+    }
+
+    #[test]
+    fn should_not_have_remaining_credits_when_limit_is_exceeded() {
+        let spending = SponsorshipSpending {
+            limit: 1000,
+            spent: 1500,
+        };
+
+        assert_eq!(spending.remaining(), 0);
+    }
+}
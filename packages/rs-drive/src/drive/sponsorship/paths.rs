@@ -0,0 +1,19 @@
+use crate::drive::RootTree;
+
+/// Misc tree key of the tree holding sponsorship spending limits
+pub const SPONSORSHIPS_STORAGE_KEY: &[u8; 1] = b"s";
+
+/// Helper function to get the sponsorships path as Vec
+pub fn sponsorships_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        SPONSORSHIPS_STORAGE_KEY.to_vec(),
+    ]
+}
+
+/// Helper function to get the path of the spending limits of a sponsor as Vec
+pub fn sponsor_path_vec(sponsor_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = sponsorships_path_vec();
+    path.push(sponsor_id.to_vec());
+    path
+}
@@ -0,0 +1,48 @@
+mod v0;
+
+use crate::drive::state_archive::StateArchiveSummary;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::io::Write;
+
+impl Drive {
+    /// Exports data contracts, identities with their balances, documents and system state
+    /// into a state archive.
+    ///
+    /// Elements are read from GroveDB page by page and written to the archive as they are read,
+    /// so the state doesn't need to fit into memory.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The stream the archive is written to.
+    /// * `transaction` - The GroveDB transaction to read the state from.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StateArchiveSummary)` with the number of exported records and the exported root hash.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn export_state_archive<W: Write>(
+        &self,
+        writer: W,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_archive
+            .export_state_archive
+        {
+            0 => self.export_state_archive_v0(writer, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "export_state_archive".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
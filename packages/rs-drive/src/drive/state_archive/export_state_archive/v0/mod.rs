@@ -0,0 +1,265 @@
+use crate::drive::contract::paths::{all_contracts_global_root_path, DataContractPaths};
+use crate::drive::grove_operations::QueryType::StatefulQuery;
+use crate::drive::state_archive::{
+    system_state_path_query, StateArchiveHeader, StateArchiveRecord, StateArchiveSummary,
+    StateArchiveWriter, STATE_ARCHIVE_EXPORT_PAGE_SIZE,
+};
+use crate::drive::{Drive, RootTree};
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::serialization::{PlatformSerializable, PlatformSerializableWithPlatformVersion};
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, Query, SizedQuery, TransactionArg};
+use std::io::Write;
+
+impl Drive {
+    pub(super) fn export_state_archive_v0<W: Write>(
+        &self,
+        writer: W,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        let root_hash = self
+            .grove
+            .root_hash(transaction)
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        let header = StateArchiveHeader {
+            protocol_version: platform_version.protocol_version,
+            genesis_time_ms: self.get_genesis_time(transaction)?,
+            source_root_hash: root_hash,
+        };
+
+        let mut archive = StateArchiveWriter::new(writer, header)?;
+
+        let mut summary = StateArchiveSummary {
+            root_hash,
+            ..Default::default()
+        };
+
+        // The system state is proved so the importer can verify it against the root hash
+        let system_state_proof = self.grove_get_proved_path_query(
+            &system_state_path_query()?,
+            false,
+            transaction,
+            &mut vec![],
+            &platform_version.drive,
+        )?;
+
+        archive.write_record(&StateArchiveRecord::SystemState {
+            proof: system_state_proof,
+        })?;
+
+        // Contracts go first, identity keys can be bound to them and documents need them
+        // to be deserialized
+        let contract_ids = self.export_keys_v0(
+            all_contracts_global_root_path()
+                .iter()
+                .map(|key| key.to_vec())
+                .collect(),
+            transaction,
+        )?;
+
+        let mut contracts = Vec::with_capacity(contract_ids.len());
+
+        for contract_id in contract_ids {
+            let contract_id: [u8; 32] = contract_id.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedContractPath(
+                    "contract id must be 32 bytes",
+                ))
+            })?;
+
+            let contract = self
+                .get_contract_with_fetch_info(contract_id, false, transaction, platform_version)?
+                .ok_or(Error::Drive(DriveError::CorruptedContractPath(
+                    "contract must exist in the contracts tree",
+                )))?
+                .contract
+                .clone();
+
+            archive.write_record(&StateArchiveRecord::DataContract(
+                contract.serialize_to_bytes_with_platform_version(platform_version)?,
+            ))?;
+            summary.data_contracts += 1;
+
+            contracts.push(contract);
+        }
+
+        // Identities come with their balances
+        let identities_path = vec![vec![RootTree::Identities as u8]];
+        let mut start_after = None;
+        loop {
+            let identity_ids = self
+                .export_page_v0(identities_path.clone(), start_after, transaction)?
+                .into_iter()
+                .map(|(key, _)| {
+                    key.try_into().map_err(|_| {
+                        Error::Drive(DriveError::CorruptedSerialization(
+                            "identity id must be 32 bytes",
+                        ))
+                    })
+                })
+                .collect::<Result<Vec<[u8; 32]>, Error>>()?;
+
+            let identities =
+                self.fetch_full_identities(&identity_ids, transaction, platform_version)?;
+
+            for identity in identities.into_values() {
+                let identity = identity.ok_or(Error::Drive(DriveError::CorruptedDriveState(
+                    "identity must exist in the identities tree".to_string(),
+                )))?;
+
+                archive.write_record(&StateArchiveRecord::Identity(
+                    identity.serialize_to_bytes()?,
+                ))?;
+                summary.identities += 1;
+            }
+
+            if identity_ids.len() < STATE_ARCHIVE_EXPORT_PAGE_SIZE as usize {
+                break;
+            }
+
+            start_after = identity_ids.last().map(|id| id.to_vec());
+        }
+
+        for contract in contracts {
+            summary.documents +=
+                self.export_documents_v0(&contract, &mut archive, transaction, platform_version)?;
+        }
+
+        archive.finish()?;
+
+        Ok(summary)
+    }
+
+    fn export_documents_v0<W: Write>(
+        &self,
+        contract: &DataContract,
+        archive: &mut StateArchiveWriter<W>,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<u64, Error> {
+        let mut exported = 0;
+
+        for document_type_name in contract.document_types().keys() {
+            let primary_key_path: Vec<Vec<u8>> = contract
+                .documents_primary_key_path(document_type_name)
+                .iter()
+                .map(|key| key.to_vec())
+                .collect();
+
+            let mut start_after = None;
+            loop {
+                let page =
+                    self.export_page_v0(primary_key_path.clone(), start_after, transaction)?;
+                let page_len = page.len();
+                start_after = page.last().map(|(key, _)| key.clone());
+
+                for (document_id, element) in page {
+                    let serialized_document = match element {
+                        Element::Item(serialized_document, _) => serialized_document,
+                        Element::Tree(..) => {
+                            // Documents keeping history reference their latest revision at 0
+                            let mut history_path = primary_key_path.clone();
+                            history_path.push(document_id);
+
+                            match self.grove_get(
+                                history_path.as_slice().into(),
+                                &[0],
+                                StatefulQuery,
+                                transaction,
+                                &mut vec![],
+                                &platform_version.drive,
+                            )? {
+                                Some(Element::Item(serialized_document, _)) => serialized_document,
+                                _ => {
+                                    return Err(Error::Drive(DriveError::CorruptedDocumentNotItem(
+                                        "latest document revision must be an item",
+                                    )))
+                                }
+                            }
+                        }
+                        _ => {
+                            return Err(Error::Drive(DriveError::CorruptedDocumentNotItem(
+                                "document must be an item",
+                            )))
+                        }
+                    };
+
+                    archive.write_record(&StateArchiveRecord::Document {
+                        contract_id: contract.id().to_buffer(),
+                        document_type_name: document_type_name.clone(),
+                        serialized_document,
+                    })?;
+                    exported += 1;
+                }
+
+                if page_len < STATE_ARCHIVE_EXPORT_PAGE_SIZE as usize {
+                    break;
+                }
+            }
+        }
+
+        Ok(exported)
+    }
+
+    /// Returns all keys of a tree, without their elements
+    fn export_keys_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let mut keys = vec![];
+        let mut start_after = None;
+
+        loop {
+            let page = self.export_page_v0(path.clone(), start_after, transaction)?;
+            let page_len = page.len();
+
+            keys.extend(page.into_iter().map(|(key, _)| key));
+
+            if page_len < STATE_ARCHIVE_EXPORT_PAGE_SIZE as usize {
+                return Ok(keys);
+            }
+
+            start_after = keys.last().cloned();
+        }
+    }
+
+    /// Returns a page of elements of a tree, after the given key
+    fn export_page_v0(
+        &self,
+        path: Vec<Vec<u8>>,
+        start_after: Option<Vec<u8>>,
+        transaction: TransactionArg,
+    ) -> Result<Vec<(Vec<u8>, Element)>, Error> {
+        let mut query = Query::new();
+
+        match start_after {
+            Some(key) => query.insert_range_after(key..),
+            None => query.insert_all(),
+        }
+
+        let path_query = PathQuery::new(
+            path,
+            SizedQuery::new(query, Some(STATE_ARCHIVE_EXPORT_PAGE_SIZE), None),
+        );
+
+        let (query_result, _) = self
+            .grove
+            .query_raw(
+                &path_query,
+                transaction.is_some(),
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        Ok(query_result.to_key_elements())
+    }
+}
@@ -0,0 +1,56 @@
+mod v0;
+
+use crate::drive::state_archive::{StateArchiveReader, StateArchiveSummary};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::version::PlatformVersion;
+use grovedb::TransactionArg;
+use std::io::Read;
+
+impl Drive {
+    /// Imports a state archive.
+    ///
+    /// Data contracts, identities and documents are inserted with regular Drive operations,
+    /// so all indices are derived again. The system state is verified against the root hash
+    /// the archive was exported with and replaces the total credits, protocol versions
+    /// and fee pools.
+    /// Data contracts and identities that already exist, like system contracts created
+    /// at genesis, are skipped together with documents that already exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `archive` - The archive to import, with its header already read.
+    /// * `block_info` - The block info used for storage flags and fees.
+    /// * `transaction` - The GroveDB transaction to import into.
+    /// * `platform_version` - A `PlatformVersion` object specifying the version of Platform.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(StateArchiveSummary)` with the number of imported records and the resulting root hash.
+    /// * `Err(StateArchiveError)` if the archive is corrupted, its system state doesn't prove
+    ///   the exported root hash or it was exported with another protocol version.
+    /// * `Err(DriveError::UnknownVersionMismatch)` if the drive version does not match known versions.
+    pub fn import_state_archive<R: Read>(
+        &self,
+        archive: StateArchiveReader<R>,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        match platform_version
+            .drive
+            .methods
+            .state_archive
+            .import_state_archive
+        {
+            0 => self.import_state_archive_v0(archive, block_info, transaction, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "import_state_archive".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+use crate::drive::batch::GroveDbOpBatch;
+use crate::drive::contract::paths::DataContractPaths;
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::object_size_info::DocumentInfo::DocumentOwnedInfo;
+use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+use crate::drive::state_archive::{
+    system_state_path_query, StateArchiveReader, StateArchiveRecord, StateArchiveSummary,
+};
+use crate::drive::system::misc_path_vec;
+use crate::drive::system::misc_tree_constants::PROTOCOL_VERSION_STORAGE_KEY;
+use crate::drive::{Drive, RootTree};
+use crate::error::drive::DriveError;
+use crate::error::state_archive::StateArchiveError;
+use crate::error::Error;
+use dpp::block::block_info::BlockInfo;
+use dpp::data_contract::accessors::v0::DataContractV0Getters;
+use dpp::data_contract::DataContract;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters};
+use dpp::identity::accessors::IdentityGettersV0;
+use dpp::identity::Identity;
+use dpp::platform_value::string_encoding::Encoding;
+use dpp::platform_value::Identifier;
+use dpp::serialization::{
+    PlatformDeserializable, PlatformDeserializableWithPotentialValidationFromVersionedStructure,
+};
+use dpp::util::deserializer::ProtocolVersion;
+use dpp::version::PlatformVersion;
+use grovedb::query_result_type::QueryResultType::QueryPathKeyElementTrioResultType;
+use grovedb::{Element, GroveDb, TransactionArg};
+use integer_encoding::VarInt;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Read;
+
+/// A data contract documents are imported into
+struct ImportedDataContract {
+    contract: DataContract,
+    /// The contract existed before the import, so its documents might exist too
+    existed: bool,
+}
+
+impl Drive {
+    pub(super) fn import_state_archive_v0<R: Read>(
+        &self,
+        mut archive: StateArchiveReader<R>,
+        block_info: &BlockInfo,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<StateArchiveSummary, Error> {
+        let archive_protocol_version = archive.header().protocol_version;
+        if archive_protocol_version != platform_version.protocol_version {
+            return Err(Error::StateArchive(
+                StateArchiveError::ProtocolVersionMismatch {
+                    archive: archive_protocol_version,
+                    platform: platform_version.protocol_version,
+                },
+            ));
+        }
+
+        let source_root_hash = archive.header().source_root_hash;
+
+        let mut summary = StateArchiveSummary::default();
+        let mut contracts: BTreeMap<Identifier, ImportedDataContract> = BTreeMap::new();
+        let mut imported_system_state = false;
+
+        while let Some(record) = archive.next_record()? {
+            match record {
+                StateArchiveRecord::SystemState { proof } => {
+                    if imported_system_state {
+                        return Err(Error::StateArchive(StateArchiveError::UnexpectedRecord(
+                            "an archive can only have one system state",
+                        )));
+                    }
+
+                    self.import_system_state_v0(
+                        &proof,
+                        source_root_hash,
+                        archive_protocol_version,
+                        transaction,
+                        platform_version,
+                    )?;
+                    imported_system_state = true;
+                }
+                StateArchiveRecord::DataContract(serialized_contract) => {
+                    let contract = DataContract::versioned_deserialize(
+                        &serialized_contract,
+                        false,
+                        platform_version,
+                    )?;
+
+                    let existed = self
+                        .get_contract_with_fetch_info(
+                            contract.id().to_buffer(),
+                            false,
+                            transaction,
+                            platform_version,
+                        )?
+                        .is_some();
+
+                    if existed {
+                        summary.skipped += 1;
+                    } else {
+                        self.apply_contract(
+                            &contract,
+                            block_info.clone(),
+                            true,
+                            StorageFlags::optional_default_as_cow(),
+                            transaction,
+                            platform_version,
+                        )?;
+                        summary.data_contracts += 1;
+                    }
+
+                    contracts.insert(contract.id(), ImportedDataContract { contract, existed });
+                }
+                StateArchiveRecord::Identity(serialized_identity) => {
+                    let identity = Identity::deserialize_from_bytes(&serialized_identity)?;
+
+                    let identities_path: [&[u8]; 1] =
+                        [Into::<&[u8; 1]>::into(RootTree::Identities)];
+                    let exists = self.grove_has_raw(
+                        (&identities_path).into(),
+                        identity.id().as_bytes(),
+                        StatefulDirectQuery,
+                        transaction,
+                        &mut vec![],
+                        &platform_version.drive,
+                    )?;
+
+                    if exists {
+                        summary.skipped += 1;
+                        continue;
+                    }
+
+                    self.add_new_identity(
+                        identity,
+                        block_info,
+                        true,
+                        transaction,
+                        platform_version,
+                    )?;
+                    summary.identities += 1;
+                }
+                StateArchiveRecord::Document {
+                    contract_id,
+                    document_type_name,
+                    serialized_document,
+                } => {
+                    let contract_id = Identifier::new(contract_id);
+                    let imported_contract = contracts.get(&contract_id).ok_or_else(|| {
+                        Error::StateArchive(StateArchiveError::UnknownDataContract(
+                            contract_id.to_string(Encoding::Base58),
+                        ))
+                    })?;
+                    let contract = &imported_contract.contract;
+                    let document_type = contract.document_type_for_name(&document_type_name)?;
+
+                    let document = Document::from_bytes(
+                        &serialized_document,
+                        document_type,
+                        platform_version,
+                    )?;
+
+                    if imported_contract.existed {
+                        let exists = self.grove_has_raw(
+                            (&contract.documents_primary_key_path(&document_type_name)).into(),
+                            document.id_ref().as_bytes(),
+                            StatefulDirectQuery,
+                            transaction,
+                            &mut vec![],
+                            &platform_version.drive,
+                        )?;
+
+                        if exists {
+                            summary.skipped += 1;
+                            continue;
+                        }
+                    }
+
+                    let owner_id = document.owner_id().to_buffer();
+                    let storage_flags =
+                        StorageFlags::new_single_epoch(block_info.epoch.index, Some(owner_id));
+
+                    self.add_document_for_contract(
+                        DocumentAndContractInfo {
+                            owned_document_info: OwnedDocumentInfo {
+                                document_info: DocumentOwnedInfo((
+                                    document,
+                                    Some(Cow::Owned(storage_flags)),
+                                )),
+                                owner_id: Some(owner_id),
+                            },
+                            contract,
+                            document_type,
+                        },
+                        false,
+                        block_info.clone(),
+                        true,
+                        transaction,
+                        platform_version,
+                    )?;
+                    summary.documents += 1;
+                }
+                StateArchiveRecord::Header(_) | StateArchiveRecord::Footer(_) => {
+                    return Err(Error::StateArchive(StateArchiveError::UnexpectedRecord(
+                        "header and footer are read by the archive reader",
+                    )))
+                }
+            }
+        }
+
+        // Without the system state the total credits wouldn't match the imported balances
+        if !imported_system_state {
+            return Err(Error::StateArchive(StateArchiveError::UnexpectedRecord(
+                "an archive must contain the system state",
+            )));
+        }
+
+        summary.root_hash = self
+            .grove
+            .root_hash(transaction)
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        Ok(summary)
+    }
+    /// Verifies the system state proof against the exported root hash and replaces
+    /// the total credits, protocol versions and fee pools with the proved elements
+    fn import_system_state_v0(
+        &self,
+        proof: &[u8],
+        source_root_hash: [u8; 32],
+        archive_protocol_version: ProtocolVersion,
+        transaction: TransactionArg,
+        platform_version: &PlatformVersion,
+    ) -> Result<(), Error> {
+        let path_query = system_state_path_query()?;
+
+        let (proved_root_hash, proved_elements) = GroveDb::verify_query(proof, &path_query)
+            .map_err(|e| {
+                Error::StateArchive(StateArchiveError::InvalidSystemStateProof(e.to_string()))
+            })?;
+
+        if proved_root_hash != source_root_hash {
+            return Err(Error::StateArchive(
+                StateArchiveError::SystemStateRootHashMismatch {
+                    exported: hex::encode(source_root_hash),
+                    proved: hex::encode(proved_root_hash),
+                },
+            ));
+        }
+
+        let proved_elements = proved_elements
+            .into_iter()
+            .filter_map(|(path, key, element)| element.map(|element| ((path, key), element)))
+            .collect::<BTreeMap<(Vec<Vec<u8>>, Vec<u8>), Element>>();
+
+        let protocol_version_key = (misc_path_vec(), PROTOCOL_VERSION_STORAGE_KEY.to_vec());
+        if let Some(element) = proved_elements.get(&protocol_version_key) {
+            let Some((protocol_version, _)) = ProtocolVersion::decode_var(element.as_item_bytes()?)
+            else {
+                return Err(Error::Drive(DriveError::CorruptedSerialization(
+                    "protocol version incorrectly serialized",
+                )));
+            };
+
+            if protocol_version != archive_protocol_version {
+                return Err(Error::StateArchive(
+                    StateArchiveError::ProtocolVersionMismatch {
+                        archive: archive_protocol_version,
+                        platform: protocol_version,
+                    },
+                ));
+            }
+        }
+
+        let (current_elements, _) = self
+            .grove
+            .query_raw(
+                &path_query,
+                transaction.is_some(),
+                QueryPathKeyElementTrioResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?;
+
+        let mut batch = GroveDbOpBatch::new();
+
+        // Elements that don't exist in the exported state, like pools of epochs that were
+        // already paid out there, are removed
+        for (path, key, _) in current_elements.to_path_key_elements() {
+            if !proved_elements.contains_key(&(path.clone(), key.clone())) {
+                batch.add_delete(path, key);
+            }
+        }
+
+        let mut existing_trees = BTreeSet::new();
+
+        for ((path, key), element) in proved_elements {
+            // Epochs and proposers that didn't start yet in the importing state are created
+            for depth in 1..path.len() {
+                let tree_path = path[..=depth].to_vec();
+
+                if existing_trees.contains(&tree_path) {
+                    continue;
+                }
+
+                let parent_path = &path[..depth];
+
+                let exists = self.grove_has_raw(
+                    parent_path.into(),
+                    &path[depth],
+                    StatefulDirectQuery,
+                    transaction,
+                    &mut vec![],
+                    &platform_version.drive,
+                )?;
+
+                if !exists {
+                    // Epochs are sum trees in the pools tree, proposers are regular trees
+                    if depth == 1 {
+                        batch.add_insert_empty_sum_tree(parent_path.to_vec(), path[depth].clone());
+                    } else {
+                        batch.add_insert_empty_tree(parent_path.to_vec(), path[depth].clone());
+                    }
+                }
+
+                existing_trees.insert(tree_path);
+            }
+
+            batch.add_insert(path, key, element);
+        }
+
+        self.grove_apply_batch(batch, false, transaction, &platform_version.drive)
+    }
+}
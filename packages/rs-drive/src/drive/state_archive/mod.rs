@@ -0,0 +1,398 @@
+//! State archives.
+//!
+//! A state archive is a portable snapshot of identities with their balances, data contracts,
+//! documents and system state. It's used to move data between networks, for example
+//! from a devnet into a local test environment.
+//!
+//! The archive is a stream of platform serialized records. It starts with a magic and
+//! the format version, followed by a header record. Every record is prefixed with its length
+//! and followed by a checksum, and the archive ends with a footer containing the number of
+//! records and a checksum of all of them.
+//!
+//! Records are imported with regular Drive operations so indices are derived again
+//! in the importing Drive instead of being copied. The importing root hash is therefore
+//! different from the exported one.
+//!
+//! The system state, that is the total credits, the protocol versions and the fee pools,
+//! is exported as a GroveDB proof. The importer verifies it against the exported root hash
+//! and copies the proved elements, so credits in the imported state add up the same way
+//! as in the exported one.
+
+mod export_state_archive;
+mod import_state_archive;
+mod record;
+mod stream;
+
+pub use record::*;
+pub use stream::*;
+
+use crate::drive::balances::{
+    TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY, TOTAL_MINTED_CREDITS_STORAGE_KEY,
+    TOTAL_SYSTEM_CREDITS_STORAGE_KEY,
+};
+use crate::drive::credit_pools::paths::pools_vec_path;
+use crate::drive::system::misc_path_vec;
+use crate::drive::system::misc_tree_constants::{
+    NEXT_PROTOCOL_VERSION_STORAGE_KEY, PROTOCOL_VERSION_STORAGE_KEY,
+};
+use crate::error::Error;
+use grovedb::{PathQuery, Query};
+
+/// Magic bytes a state archive starts with
+pub const STATE_ARCHIVE_MAGIC: [u8; 4] = *b"DPSA";
+
+/// The current state archive format version
+pub const STATE_ARCHIVE_FORMAT_VERSION: u16 = 1;
+
+/// How many elements are read from GroveDB at once during export
+pub(crate) const STATE_ARCHIVE_EXPORT_PAGE_SIZE: u16 = 100;
+
+/// The query of the system state: the credit counters and protocol versions from the misc tree
+/// and all fee pools with the epoch proposers
+pub(crate) fn system_state_path_query() -> Result<PathQuery, Error> {
+    let mut misc_query = Query::new();
+    misc_query.insert_keys(vec![
+        TOTAL_SYSTEM_CREDITS_STORAGE_KEY.to_vec(),
+        TOTAL_MINTED_CREDITS_STORAGE_KEY.to_vec(),
+        TOTAL_COMPLETED_WITHDRAWALS_STORAGE_KEY.to_vec(),
+        PROTOCOL_VERSION_STORAGE_KEY.to_vec(),
+        NEXT_PROTOCOL_VERSION_STORAGE_KEY.to_vec(),
+    ]);
+
+    let mut proposers_query = Query::new();
+    proposers_query.insert_all();
+
+    let mut pool_query = Query::new();
+    pool_query.insert_all();
+    pool_query.set_subquery(proposers_query);
+
+    let mut pools_query = Query::new();
+    pools_query.insert_all();
+    pools_query.set_subquery(pool_query);
+
+    PathQuery::merge(vec![
+        &PathQuery::new_unsized(misc_path_vec(), misc_query),
+        &PathQuery::new_unsized(pools_vec_path(), pools_query),
+    ])
+    .map_err(Error::GroveDB)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::drive::batch::grovedb_op_batch::GroveDbOpBatchV0Methods;
+    use crate::drive::batch::GroveDbOpBatch;
+    use crate::drive::flags::StorageFlags;
+    use crate::drive::object_size_info::DocumentInfo::DocumentRefInfo;
+    use crate::drive::object_size_info::{DocumentAndContractInfo, OwnedDocumentInfo};
+    use crate::drive::state_archive::{StateArchiveReader, StateArchiveRecord, StateArchiveWriter};
+    use crate::drive::Drive;
+    use crate::error::state_archive::StateArchiveError;
+    use crate::error::Error;
+    use crate::fee_pools::epochs::operations_factory::EpochOperations;
+    use crate::fee_pools::update_storage_fee_distribution_pool_operation;
+    use crate::tests::helpers::setup::setup_drive_with_initial_state_structure;
+    use dpp::block::block_info::BlockInfo;
+    use dpp::block::epoch::Epoch;
+    use dpp::data_contract::accessors::v0::DataContractV0Getters;
+    use dpp::data_contract::document_type::random_document::CreateRandomDocument;
+    use dpp::identity::accessors::IdentityGettersV0;
+    use dpp::identity::Identity;
+    use dpp::tests::json_document::json_document_to_contract;
+    use dpp::version::PlatformVersion;
+    use std::borrow::Cow;
+
+    fn setup_drive_with_state() -> Drive {
+        let drive = setup_drive_with_initial_state_structure();
+        let platform_version = PlatformVersion::latest();
+        let block_info = BlockInfo::default();
+
+        let contract = json_document_to_contract(
+            "tests/supporting_files/contract/family/family-contract.json",
+            false,
+            platform_version,
+        )
+        .expect("expected to get contract");
+
+        drive
+            .apply_contract(
+                &contract,
+                block_info.clone(),
+                true,
+                StorageFlags::optional_default_as_cow(),
+                None,
+                platform_version,
+            )
+            .expect("expected to apply contract");
+
+        let mut total_credits = 0;
+
+        for seed in 0..3 {
+            let identity = Identity::random_identity(3, Some(seed), platform_version)
+                .expect("expected a random identity");
+
+            total_credits += identity.balance();
+
+            drive
+                .add_new_identity(identity, &block_info, true, None, platform_version)
+                .expect("expected to add identity");
+        }
+
+        // Fees collected into the pools are part of the system state
+        let mut batch = GroveDbOpBatch::new();
+        batch.push(
+            update_storage_fee_distribution_pool_operation(1000)
+                .expect("expected a storage fee pool operation"),
+        );
+        batch.push(
+            Epoch::new(0)
+                .unwrap()
+                .update_processing_fee_pool_operation(500)
+                .expect("expected a processing fee pool operation"),
+        );
+        drive
+            .grove_apply_batch(batch, false, None, &platform_version.drive)
+            .expect("expected to update the fee pools");
+
+        total_credits += 1500;
+
+        drive
+            .add_to_system_credits(total_credits, None, platform_version)
+            .expect("expected to add system credits");
+
+        let document_type = contract
+            .document_type_for_name("person")
+            .expect("expected to get document type");
+
+        for seed in 0..5 {
+            let document = document_type
+                .random_document(Some(seed), platform_version)
+                .expect("expected a random document");
+
+            let storage_flags = Some(Cow::Owned(StorageFlags::SingleEpoch(0)));
+
+            drive
+                .add_document_for_contract(
+                    DocumentAndContractInfo {
+                        owned_document_info: OwnedDocumentInfo {
+                            document_info: DocumentRefInfo((&document, storage_flags)),
+                            owner_id: None,
+                        },
+                        contract: &contract,
+                        document_type,
+                    },
+                    false,
+                    block_info.clone(),
+                    true,
+                    None,
+                    platform_version,
+                )
+                .expect("expected to add document");
+        }
+
+        drive
+    }
+
+    fn export(drive: &Drive) -> Vec<u8> {
+        let mut archive = vec![];
+
+        drive
+            .export_state_archive(&mut archive, None, PlatformVersion::latest())
+            .expect("expected to export state");
+
+        archive
+    }
+
+    #[test]
+    fn should_import_exported_state_with_stable_root_hash() {
+        let platform_version = PlatformVersion::latest();
+        let source = setup_drive_with_state();
+
+        let archive = export(&source);
+
+        let header = StateArchiveReader::new(archive.as_slice())
+            .expect("expected to read archive")
+            .header()
+            .clone();
+        assert_eq!(header.protocol_version, platform_version.protocol_version);
+
+        let import = |archive: &[u8]| {
+            let drive = setup_drive_with_initial_state_structure();
+            let summary = drive
+                .import_state_archive(
+                    StateArchiveReader::new(archive).expect("expected to read archive"),
+                    &BlockInfo::default(),
+                    None,
+                    platform_version,
+                )
+                .expect("expected to import state");
+            (drive, summary)
+        };
+
+        let (imported, summary) = import(&archive);
+
+        assert_eq!(summary.data_contracts, 1);
+        assert_eq!(summary.identities, 3);
+        assert_eq!(summary.documents, 5);
+        assert_eq!(summary.skipped, 0);
+
+        // Importing the same archive again must result in the same state
+        let (_, second_summary) = import(&archive);
+        assert_eq!(summary.root_hash, second_summary.root_hash);
+
+        // The imported state must export to the same records, the system state is a proof
+        // of another root hash
+        let reexported = export(&imported);
+        let mut records = StateArchiveReader::new(archive.as_slice()).unwrap();
+        let mut reexported_records = StateArchiveReader::new(reexported.as_slice()).unwrap();
+        loop {
+            let record = records.next_record().expect("expected a record");
+            let reexported_record = reexported_records.next_record().expect("expected a record");
+            if matches!(record, Some(StateArchiveRecord::SystemState { .. })) {
+                assert!(matches!(
+                    reexported_record,
+                    Some(StateArchiveRecord::SystemState { .. })
+                ));
+                continue;
+            }
+            assert_eq!(record, reexported_record);
+            if record.is_none() {
+                break;
+            }
+        }
+
+        // The system state is copied, so the credits add up the same way
+        let total_credits = imported
+            .calculate_total_credits_balance(None, &platform_version.drive)
+            .expect("expected to calculate total credits");
+        let source_total_credits = source
+            .calculate_total_credits_balance(None, &platform_version.drive)
+            .expect("expected to calculate total credits");
+        assert_eq!(
+            total_credits.total_credits_in_platform,
+            source_total_credits.total_credits_in_platform
+        );
+        assert_eq!(
+            total_credits.total_in_pools,
+            source_total_credits.total_in_pools
+        );
+        assert!(total_credits.ok().expect("expected credits to add up"));
+
+        assert_eq!(
+            imported
+                .get_storage_fees_from_distribution_pool(None, platform_version)
+                .expect("expected to get the storage fee pool"),
+            1000
+        );
+        assert_eq!(
+            imported
+                .get_epoch_processing_credits_for_distribution(
+                    &Epoch::new(0).unwrap(),
+                    None,
+                    platform_version
+                )
+                .expect("expected to get the epoch processing fees"),
+            500
+        );
+
+        let identity_ids: Vec<[u8; 32]> = (0..3)
+            .map(|seed| {
+                Identity::random_identity(3, Some(seed), platform_version)
+                    .unwrap()
+                    .id()
+                    .to_buffer()
+            })
+            .collect();
+        for identity_id in identity_ids {
+            let balance = imported
+                .fetch_identity_balance(identity_id, None, platform_version)
+                .expect("expected to fetch balance");
+            let source_balance = source
+                .fetch_identity_balance(identity_id, None, platform_version)
+                .expect("expected to fetch balance");
+            assert_eq!(balance, source_balance);
+        }
+    }
+
+    #[test]
+    fn should_reject_system_state_not_proving_exported_root_hash() {
+        let platform_version = PlatformVersion::latest();
+        let source = setup_drive_with_state();
+
+        let archive = export(&source);
+
+        // The same records with the root hash of another state
+        let mut records = StateArchiveReader::new(archive.as_slice()).unwrap();
+        let mut header = records.header().clone();
+        header.source_root_hash = [1; 32];
+
+        let mut forged = StateArchiveWriter::new(vec![], header).unwrap();
+        while let Some(record) = records.next_record().unwrap() {
+            forged.write_record(&record).unwrap();
+        }
+        let forged = forged.finish().unwrap();
+
+        let drive = setup_drive_with_initial_state_structure();
+        let result = drive.import_state_archive(
+            StateArchiveReader::new(forged.as_slice()).expect("expected to read archive"),
+            &BlockInfo::default(),
+            None,
+            platform_version,
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::StateArchive(
+                StateArchiveError::SystemStateRootHashMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_reject_corrupted_archive() {
+        let source = setup_drive_with_state();
+
+        let mut archive = export(&source);
+        let middle = archive.len() / 2;
+        archive[middle] ^= 0xff;
+
+        let drive = setup_drive_with_initial_state_structure();
+        let result = StateArchiveReader::new(archive.as_slice()).and_then(|reader| {
+            drive.import_state_archive(
+                reader,
+                &BlockInfo::default(),
+                None,
+                PlatformVersion::latest(),
+            )
+        });
+
+        assert!(matches!(
+            result,
+            Err(Error::StateArchive(
+                StateArchiveError::InvalidRecordChecksum(_)
+                    | StateArchiveError::Serialization(_)
+                    | StateArchiveError::Truncated
+            ))
+        ));
+    }
+
+    #[test]
+    fn should_reject_truncated_archive() {
+        let source = setup_drive_with_state();
+
+        let archive = export(&source);
+        let truncated = &archive[..archive.len() - 10];
+
+        let drive = setup_drive_with_initial_state_structure();
+        let result = drive.import_state_archive(
+            StateArchiveReader::new(truncated).expect("expected to read header"),
+            &BlockInfo::default(),
+            None,
+            PlatformVersion::latest(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::StateArchive(StateArchiveError::Truncated))
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+use dpp::bincode::{Decode, Encode};
+use dpp::util::deserializer::ProtocolVersion;
+
+/// The header of a state archive
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct StateArchiveHeader {
+    /// The protocol version records were serialized with
+    pub protocol_version: ProtocolVersion,
+    /// The genesis time of the exported chain
+    pub genesis_time_ms: Option<u64>,
+    /// The root hash of the exported state
+    pub source_root_hash: [u8; 32],
+}
+
+/// The footer of a state archive
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub struct StateArchiveFooter {
+    /// The number of records between the header and the footer
+    pub record_count: u64,
+    /// The checksum of all records before the footer
+    pub checksum: [u8; 32],
+}
+
+/// A record of a state archive
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+pub enum StateArchiveRecord {
+    /// The first record of the archive
+    Header(StateArchiveHeader),
+    /// The total credits, protocol versions and fee pools of the exported state
+    SystemState {
+        /// A proof of the system state against the exported root hash
+        proof: Vec<u8>,
+    },
+    /// A platform serialized data contract
+    DataContract(Vec<u8>),
+    /// A platform serialized identity, with its balance
+    Identity(Vec<u8>),
+    /// A platform serialized document
+    Document {
+        /// The contract the document belongs to
+        contract_id: [u8; 32],
+        /// The document type name
+        document_type_name: String,
+        /// The serialized document
+        serialized_document: Vec<u8>,
+    },
+    /// The last record of the archive
+    Footer(StateArchiveFooter),
+}
+
+/// A summary of an exported or imported state archive
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateArchiveSummary {
+    /// Number of data contracts
+    pub data_contracts: u64,
+    /// Number of identities
+    pub identities: u64,
+    /// Number of documents
+    pub documents: u64,
+    /// Number of records that were already present in the state and were not imported
+    pub skipped: u64,
+    /// The state root hash after the export or import
+    pub root_hash: [u8; 32],
+}
@@ -0,0 +1,259 @@
+use crate::drive::state_archive::{
+    StateArchiveFooter, StateArchiveHeader, StateArchiveRecord, STATE_ARCHIVE_FORMAT_VERSION,
+    STATE_ARCHIVE_MAGIC,
+};
+use crate::error::state_archive::StateArchiveError;
+use crate::error::Error;
+use dpp::bincode;
+use dpp::bincode::config;
+use dpp::util::hash::hash;
+use std::io::{ErrorKind, Read, Write};
+
+/// Records bigger than this are considered corrupted
+const MAX_RECORD_SIZE: u32 = 16 * 1024 * 1024;
+
+const RECORD_CHECKSUM_SIZE: usize = 4;
+
+fn encode_record(record: &StateArchiveRecord) -> Result<Vec<u8>, Error> {
+    let config = config::standard().with_big_endian().with_no_limit();
+    bincode::encode_to_vec(record, config).map_err(|e| {
+        Error::StateArchive(StateArchiveError::Serialization(format!(
+            "unable to encode record: {}",
+            e
+        )))
+    })
+}
+
+fn decode_record(data: &[u8]) -> Result<StateArchiveRecord, Error> {
+    let config = config::standard().with_big_endian().with_no_limit();
+    bincode::decode_from_slice(data, config)
+        .map(|(record, _)| record)
+        .map_err(|e| {
+            Error::StateArchive(StateArchiveError::Serialization(format!(
+                "unable to decode record: {}",
+                e
+            )))
+        })
+}
+
+/// The archive checksum is chained over the hashes of all records
+fn chain_checksum(checksum: &[u8; 32], record_hash: &[u8; 32]) -> [u8; 32] {
+    let mut data = [0u8; 64];
+    data[..32].copy_from_slice(checksum);
+    data[32..].copy_from_slice(record_hash);
+    hash(data)
+}
+
+/// Writes a state archive to a stream
+pub struct StateArchiveWriter<W: Write> {
+    writer: W,
+    record_count: u64,
+    checksum: [u8; 32],
+}
+
+impl<W: Write> StateArchiveWriter<W> {
+    /// Starts an archive with the given header
+    pub fn new(mut writer: W, header: StateArchiveHeader) -> Result<Self, Error> {
+        writer
+            .write_all(&STATE_ARCHIVE_MAGIC)
+            .and_then(|_| writer.write_all(&STATE_ARCHIVE_FORMAT_VERSION.to_be_bytes()))
+            .map_err(StateArchiveError::Io)?;
+
+        let mut archive_writer = StateArchiveWriter {
+            writer,
+            record_count: 0,
+            checksum: [0; 32],
+        };
+
+        archive_writer.write_frame(&StateArchiveRecord::Header(header))?;
+
+        Ok(archive_writer)
+    }
+
+    fn write_frame(&mut self, record: &StateArchiveRecord) -> Result<[u8; 32], Error> {
+        let payload = encode_record(record)?;
+        let record_hash = hash(&payload);
+
+        self.writer
+            .write_all(&(payload.len() as u32).to_be_bytes())
+            .and_then(|_| self.writer.write_all(&payload))
+            .and_then(|_| self.writer.write_all(&record_hash[..RECORD_CHECKSUM_SIZE]))
+            .map_err(StateArchiveError::Io)?;
+
+        Ok(record_hash)
+    }
+
+    /// Writes a record
+    pub fn write_record(&mut self, record: &StateArchiveRecord) -> Result<(), Error> {
+        if matches!(
+            record,
+            StateArchiveRecord::Header(_) | StateArchiveRecord::Footer(_)
+        ) {
+            return Err(Error::StateArchive(StateArchiveError::UnexpectedRecord(
+                "header and footer are written by the archive writer",
+            )));
+        }
+
+        let record_hash = self.write_frame(record)?;
+
+        self.checksum = chain_checksum(&self.checksum, &record_hash);
+        self.record_count += 1;
+
+        Ok(())
+    }
+
+    /// Writes the footer and returns the underlying writer
+    pub fn finish(mut self) -> Result<W, Error> {
+        let footer = StateArchiveFooter {
+            record_count: self.record_count,
+            checksum: self.checksum,
+        };
+
+        self.write_frame(&StateArchiveRecord::Footer(footer))?;
+
+        self.writer.flush().map_err(StateArchiveError::Io)?;
+
+        Ok(self.writer)
+    }
+}
+
+/// Reads a state archive from a stream, verifying checksums
+pub struct StateArchiveReader<R: Read> {
+    reader: R,
+    header: StateArchiveHeader,
+    record_count: u64,
+    checksum: [u8; 32],
+    finished: bool,
+}
+
+impl<R: Read> StateArchiveReader<R> {
+    /// Opens an archive and reads its header
+    pub fn new(mut reader: R) -> Result<Self, Error> {
+        let mut magic = [0u8; 4];
+        let mut format_version = [0u8; 2];
+        reader
+            .read_exact(&mut magic)
+            .and_then(|_| reader.read_exact(&mut format_version))
+            .map_err(Self::map_read_error)?;
+
+        if magic != STATE_ARCHIVE_MAGIC {
+            return Err(Error::StateArchive(StateArchiveError::InvalidMagic));
+        }
+
+        let format_version = u16::from_be_bytes(format_version);
+        if format_version != STATE_ARCHIVE_FORMAT_VERSION {
+            return Err(Error::StateArchive(
+                StateArchiveError::UnsupportedFormatVersion(format_version),
+            ));
+        }
+
+        let mut archive_reader = StateArchiveReader {
+            reader,
+            header: StateArchiveHeader {
+                protocol_version: 0,
+                genesis_time_ms: None,
+                source_root_hash: [0; 32],
+            },
+            record_count: 0,
+            checksum: [0; 32],
+            finished: false,
+        };
+
+        let StateArchiveRecord::Header(header) = archive_reader.read_frame()?.0 else {
+            return Err(Error::StateArchive(StateArchiveError::UnexpectedRecord(
+                "an archive must start with a header",
+            )));
+        };
+
+        archive_reader.header = header;
+
+        Ok(archive_reader)
+    }
+
+    fn map_read_error(error: std::io::Error) -> Error {
+        if error.kind() == ErrorKind::UnexpectedEof {
+            Error::StateArchive(StateArchiveError::Truncated)
+        } else {
+            Error::StateArchive(StateArchiveError::Io(error))
+        }
+    }
+
+    fn read_frame(&mut self) -> Result<(StateArchiveRecord, [u8; 32]), Error> {
+        let mut length = [0u8; 4];
+        self.reader
+            .read_exact(&mut length)
+            .map_err(Self::map_read_error)?;
+
+        let length = u32::from_be_bytes(length);
+        if length > MAX_RECORD_SIZE {
+            return Err(Error::StateArchive(StateArchiveError::Serialization(
+                format!(
+                    "record {} is too large: {} bytes",
+                    self.record_count, length
+                ),
+            )));
+        }
+
+        let mut payload = vec![0u8; length as usize];
+        let mut record_checksum = [0u8; RECORD_CHECKSUM_SIZE];
+        self.reader
+            .read_exact(&mut payload)
+            .and_then(|_| self.reader.read_exact(&mut record_checksum))
+            .map_err(Self::map_read_error)?;
+
+        let record_hash = hash(&payload);
+        if record_hash[..RECORD_CHECKSUM_SIZE] != record_checksum {
+            return Err(Error::StateArchive(
+                StateArchiveError::InvalidRecordChecksum(self.record_count),
+            ));
+        }
+
+        Ok((decode_record(&payload)?, record_hash))
+    }
+
+    /// The archive header
+    pub fn header(&self) -> &StateArchiveHeader {
+        &self.header
+    }
+
+    /// Reads the next record, returns `None` once the footer was read and verified
+    pub fn next_record(&mut self) -> Result<Option<StateArchiveRecord>, Error> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let (record, record_hash) = self.read_frame()?;
+
+        match record {
+            StateArchiveRecord::Header(_) => Err(Error::StateArchive(
+                StateArchiveError::UnexpectedRecord("an archive can only have one header"),
+            )),
+            StateArchiveRecord::Footer(footer) => {
+                if footer.record_count != self.record_count {
+                    return Err(Error::StateArchive(StateArchiveError::InvalidFooter(
+                        format!(
+                            "expected {} records, read {}",
+                            footer.record_count, self.record_count
+                        ),
+                    )));
+                }
+
+                if footer.checksum != self.checksum {
+                    return Err(Error::StateArchive(StateArchiveError::InvalidFooter(
+                        "archive checksum mismatch".to_string(),
+                    )));
+                }
+
+                self.finished = true;
+
+                Ok(None)
+            }
+            record => {
+                self.checksum = chain_checksum(&self.checksum, &record_hash);
+                self.record_count += 1;
+
+                Ok(Some(record))
+            }
+        }
+    }
+}
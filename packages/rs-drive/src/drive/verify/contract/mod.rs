@@ -1,2 +1,3 @@
 mod verify_contract;
+mod verify_contract_at_time;
 mod verify_contract_history;
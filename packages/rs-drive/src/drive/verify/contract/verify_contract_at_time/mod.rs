@@ -0,0 +1,54 @@
+mod v0;
+
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::data_contract::DataContract;
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the entry of the contract's history as of a given time is included in the proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `time_ms`: The time in milliseconds the contract should be resolved at.
+    /// - `platform_version`: The platform version.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<(u64, DataContract)>`. The
+    /// `Option<(u64, DataContract)>` holds the time the contract was stored at and the contract
+    /// if it existed at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The contract serialization fails.
+    pub fn verify_contract_at_time(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        time_ms: u64,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<(u64, DataContract)>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .contract
+            .verify_contract_at_time
+        {
+            0 => Drive::verify_contract_at_time_v0(proof, contract_id, time_ms, platform_version),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_contract_at_time".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::drive::contract::paths::contract_keeping_history_storage_path_vec;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use dpp::prelude::DataContract;
+
+use crate::common::decode;
+use crate::error::drive::DriveError;
+use dpp::serialization::PlatformDeserializableWithPotentialValidationFromVersionedStructure;
+use dpp::version::PlatformVersion;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the entry of the contract's history as of a given time is included in the proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `time_ms`: The time in milliseconds the contract should be resolved at.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and `Option<(u64, DataContract)>`. The
+    /// `Option<(u64, DataContract)>` holds the time the contract was stored at and the contract
+    /// if it existed at the given time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The contract serialization fails.
+    pub(super) fn verify_contract_at_time_v0(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        time_ms: u64,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<(u64, DataContract)>), Error> {
+        let path_query = Self::fetch_contract_at_time_query(contract_id, time_ms);
+
+        let (root_hash, mut proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        if proved_key_values.len() > 1 {
+            return Err(Error::Proof(ProofError::TooManyElements(
+                "expected at most one historical contract",
+            )));
+        }
+
+        let Some((path, key, maybe_element)) = proved_key_values.pop() else {
+            return Ok((root_hash, None));
+        };
+
+        if path != contract_keeping_history_storage_path_vec(&contract_id) {
+            return Err(Error::Proof(ProofError::CorruptedProof(
+                "we did not get back an element for the correct path for the historical contract",
+            )));
+        }
+
+        let Some(element) = maybe_element else {
+            return Ok((root_hash, None));
+        };
+
+        let date = decode::decode_u64(&key).map_err(|_| {
+            Error::Drive(DriveError::CorruptedContractPath(
+                "contract key is not a valid u64",
+            ))
+        })?;
+
+        let bytes = element.into_item_bytes().map_err(Error::GroveDB)?;
+        // we don't need to validate the contract locally because it was proved to be in platform
+        // and hence it is valid
+        let contract = DataContract::versioned_deserialize(&bytes, false, platform_version)
+            .map_err(Error::Protocol)?;
+
+        Ok((root_hash, Some((date, contract))))
+    }
+}
@@ -0,0 +1,77 @@
+mod v0;
+
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::Document;
+use dpp::prelude::{Revision, TimestampMillis};
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies that revisions of a document keeping history are included in the proof.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contract_id`: The contract's unique identifier.
+    /// - `document_type`: The document type keeping history.
+    /// - `document_id`: The document's unique identifier.
+    /// - `start_at_ms`: The time (inclusive) the history starts from.
+    /// - `end_at_ms`: An optional time (exclusive) the history ends at.
+    /// - `limit`: An optional limit for the number of revisions to be retrieved.
+    /// - `offset`: An optional offset for the revisions to be retrieved.
+    /// - `platform_version`: The platform version against which to verify the proof.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` with a tuple of `RootHash` and the proved revisions ordered by
+    /// the time they were stored at, along with the time and the revision of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - The document deserialization fails.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_document_history(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<(TimestampMillis, Revision, Document)>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .document
+            .verify_document_history
+        {
+            0 => Self::verify_document_history_v0(
+                proof,
+                contract_id,
+                document_type,
+                document_id,
+                start_at_ms,
+                end_at_ms,
+                limit,
+                offset,
+                platform_version,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_document_history".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
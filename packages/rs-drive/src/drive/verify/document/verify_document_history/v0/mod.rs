@@ -0,0 +1,84 @@
+use crate::common::decode::decode_u64;
+use crate::drive::verify::RootHash;
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use dpp::data_contract::document_type::accessors::DocumentTypeV0Getters;
+use dpp::data_contract::document_type::DocumentTypeRef;
+use dpp::document::serialization_traits::DocumentPlatformConversionMethodsV0;
+use dpp::document::{Document, DocumentV0Getters, INITIAL_REVISION};
+use dpp::prelude::{Revision, TimestampMillis};
+use dpp::version::PlatformVersion;
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies that revisions of a document keeping history are included in the proof.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn verify_document_history_v0(
+        proof: &[u8],
+        contract_id: [u8; 32],
+        document_type: DocumentTypeRef,
+        document_id: [u8; 32],
+        start_at_ms: u64,
+        end_at_ms: Option<u64>,
+        limit: Option<u16>,
+        offset: Option<u16>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Vec<(TimestampMillis, Revision, Document)>), Error> {
+        let document_type_name = document_type.name();
+
+        let path_query = Self::fetch_document_history_query(
+            contract_id,
+            document_type_name.as_str(),
+            document_id,
+            start_at_ms,
+            end_at_ms,
+            limit,
+            offset,
+        )?;
+
+        let (root_hash, proved_key_values) = GroveDb::verify_query(proof, &path_query)?;
+
+        let document_history_path =
+            Self::document_history_path_vec(&contract_id, document_type_name, &document_id);
+
+        let revisions = proved_key_values
+            .into_iter()
+            .map(|(path, key, maybe_element)| {
+                if path != document_history_path {
+                    return Err(Error::Proof(ProofError::CorruptedProof(
+                        "we did not get back an element for the correct path for the document history",
+                    )));
+                }
+
+                let time = decode_u64(&key).map_err(|_| {
+                    Error::Drive(DriveError::CorruptedDocumentPath(
+                        "document history key is not a valid u64",
+                    ))
+                })?;
+
+                let Some(element) = maybe_element else {
+                    return Err(Error::Drive(DriveError::CorruptedDocumentPath(
+                        "expected a document at this path",
+                    )));
+                };
+
+                // we don't need to validate the document locally because it was proved to be in platform
+                let document = Document::from_bytes(
+                    &element.into_item_bytes().map_err(Error::GroveDB)?,
+                    document_type,
+                    platform_version,
+                )
+                .map_err(Error::Protocol)?;
+
+                let revision = document.revision().unwrap_or(INITIAL_REVISION);
+
+                Ok((time, revision, document))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok((root_hash, revisions))
+    }
+}
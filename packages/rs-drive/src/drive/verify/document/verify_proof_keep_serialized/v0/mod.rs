@@ -44,6 +44,18 @@ impl<'a> DriveQuery<'a> {
             GroveDb::verify_query(proof, &path_query)?
         };
 
+        if self.is_as_of_block_time() {
+            let revisions = proved_key_values
+                .into_iter()
+                .filter_map(|(path, _key, element)| element.map(|element| (path, element)));
+            let documents = self
+                .latest_revisions_as_of_block_time(revisions)
+                .into_iter()
+                .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
+                .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+            return Ok((root_hash, documents));
+        }
+
         let documents = proved_key_values
             .into_iter()
             .filter_map(|(_path, _key, element)| element)
@@ -6,6 +6,8 @@ pub mod document;
 pub mod identity;
 /// Single Document verification methods on proofs
 pub mod single_document;
+/// Contested resource vote verification methods on proofs
+pub mod votes;
 
 /// Represents the root hash of the grovedb tree
 pub type RootHash = [u8; 32];
@@ -0,0 +1 @@
+mod verify_contested_resource_vote_tally;
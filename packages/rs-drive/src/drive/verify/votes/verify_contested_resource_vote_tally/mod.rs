@@ -0,0 +1,63 @@
+mod v0;
+
+use crate::drive::Drive;
+
+use crate::error::drive::DriveError;
+
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+use crate::drive::votes::ContestedResourceVoteTally;
+
+use dpp::version::PlatformVersion;
+
+impl Drive {
+    /// Verifies the votes towards every choice of a contest.
+    ///
+    /// # Parameters
+    ///
+    /// - `proof`: A byte slice representing the proof to be verified.
+    /// - `contest_id`: The unique id of the vote poll of the contest.
+    /// - `verify_subset_of_proof`: A boolean indicating whether we are verifying a subset of a larger proof.
+    /// - `platform_version`: The platform version against which to verify the vote tally.
+    ///
+    /// # Returns
+    ///
+    /// If the verification is successful, it returns a `Result` with a tuple of `RootHash` and
+    /// an `Option<ContestedResourceVoteTally>`. The `RootHash` represents the root hash of GroveDB,
+    /// and the `Option<ContestedResourceVoteTally>` represents the votes of the contest if it exists.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Error` if:
+    ///
+    /// - The proof is corrupted.
+    /// - The GroveDb query fails.
+    /// - An unknown or unsupported platform version is provided.
+    ///
+    pub fn verify_contested_resource_vote_tally(
+        proof: &[u8],
+        contest_id: &[u8; 32],
+        verify_subset_of_proof: bool,
+        platform_version: &PlatformVersion,
+    ) -> Result<(RootHash, Option<ContestedResourceVoteTally>), Error> {
+        match platform_version
+            .drive
+            .methods
+            .verify
+            .votes
+            .verify_contested_resource_vote_tally
+        {
+            0 => Self::verify_contested_resource_vote_tally_v0(
+                proof,
+                contest_id,
+                verify_subset_of_proof,
+            ),
+            version => Err(Error::Drive(DriveError::UnknownVersionMismatch {
+                method: "verify_contested_resource_vote_tally".to_string(),
+                known_versions: vec![0],
+                received: version,
+            })),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::drive::votes::paths::contest_tallies_path_vec;
+use crate::drive::votes::ContestedResourceVoteTally;
+use crate::drive::Drive;
+
+use crate::error::proof::ProofError;
+use crate::error::Error;
+
+use crate::drive::verify::RootHash;
+
+use grovedb::GroveDb;
+
+impl Drive {
+    /// Verifies the votes towards every choice of a contest.
+    ///
+    /// A contest without any proved choice doesn't exist, every contender has a sum tree
+    /// of votes from the moment it was submitted.
+    pub(super) fn verify_contested_resource_vote_tally_v0(
+        proof: &[u8],
+        contest_id: &[u8; 32],
+        verify_subset_of_proof: bool,
+    ) -> Result<(RootHash, Option<ContestedResourceVoteTally>), Error> {
+        let path_query = Self::contested_resource_vote_tally_query(contest_id);
+        let (root_hash, proved_key_values) = if verify_subset_of_proof {
+            GroveDb::verify_subset_query(proof, &path_query)?
+        } else {
+            GroveDb::verify_query(proof, &path_query)?
+        };
+
+        let tallies_path = contest_tallies_path_vec(contest_id);
+
+        let key_elements = proved_key_values
+            .into_iter()
+            .filter_map(|(path, key, maybe_element)| {
+                if path != tallies_path {
+                    return Some(Err(Error::Proof(ProofError::CorruptedProof(
+                        "we did not get back an element for the correct path in contest tallies",
+                    ))));
+                }
+                maybe_element.map(|element| Ok((key, element)))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        if key_elements.is_empty() {
+            return Ok((root_hash, None));
+        }
+
+        let vote_tally = ContestedResourceVoteTally::try_from_key_elements(key_elements)?;
+
+        Ok((root_hash, Some(vote_tally)))
+    }
+}
@@ -0,0 +1,347 @@
+use crate::drive::flags::StorageFlags;
+use crate::drive::grove_operations::DirectQueryType::StatefulDirectQuery;
+use crate::drive::system::misc_path;
+use crate::drive::votes::paths::{
+    contest_contenders_path_vec, contest_path_vec, contest_vote_changes_path_vec,
+    contest_votes_path_vec, contests_by_end_time_path_vec, CONTESTED_RESOURCES_STORAGE_KEY,
+    CONTEST_END_TIME_KEY, CONTEST_VOTE_CHANGES_KEY, CONTEST_VOTE_POLL_KEY,
+};
+use crate::drive::votes::{
+    ContestedResource, ContestedResourceContender, ContestedResourceVoteTally,
+};
+use crate::drive::Drive;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use crate::fee::op::LowLevelDriveOperation;
+use dpp::platform_value::Identifier;
+use dpp::prelude::TimestampMillis;
+use dpp::serialization::PlatformDeserializable;
+use dpp::version::drive_versions::DriveVersion;
+use dpp::voting::ContestedDocumentResourceVotePoll;
+use grovedb::query_result_type::QueryResultType;
+use grovedb::{Element, PathQuery, Query, QueryItem, SizedQuery, TransactionArg};
+use std::collections::BTreeMap;
+
+impl Drive {
+    /// Fetches the end of the voting period of a contest, `None` if there is no such contest
+    pub fn fetch_contest_end_time(
+        &self,
+        contest_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Option<TimestampMillis>, Error> {
+        if !self.has_contested_resources(transaction, drive_version)? {
+            return Ok(None);
+        }
+
+        let element = self.grove_get_raw_optional(
+            contest_path_vec(contest_id).as_slice().into(),
+            &CONTEST_END_TIME_KEY,
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )?;
+
+        element
+            .map(|element| Self::end_time_from_element(&element))
+            .transpose()
+    }
+
+    /// Checks if an identity is a contender of a contest
+    pub fn has_contested_resource_contender(
+        &self,
+        contest_id: &[u8; 32],
+        contender_id: Identifier,
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<bool, Error> {
+        if self
+            .fetch_contest_end_time(contest_id, transaction, drive_version)?
+            .is_none()
+        {
+            return Ok(false);
+        }
+
+        self.grove_has_raw(
+            contest_contenders_path_vec(contest_id).as_slice().into(),
+            contender_id.as_slice(),
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+
+    /// Fetches how many times a masternode changed its vote on a contest
+    pub fn fetch_masternode_vote_changes(
+        &self,
+        contest_id: &[u8; 32],
+        pro_tx_hash: Identifier,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        drive_version: &DriveVersion,
+    ) -> Result<u16, Error> {
+        let vote_changes_exist = self.grove_has_raw(
+            contest_path_vec(contest_id).as_slice().into(),
+            &CONTEST_VOTE_CHANGES_KEY,
+            StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            drive_version,
+        )?;
+
+        if !vote_changes_exist {
+            return Ok(0);
+        }
+
+        self.grove_get_raw_optional(
+            contest_vote_changes_path_vec(contest_id).as_slice().into(),
+            pro_tx_hash.as_slice(),
+            StatefulDirectQuery,
+            transaction,
+            drive_operations,
+            drive_version,
+        )?
+        .map(|element| Self::vote_changes_from_element(&element))
+        .transpose()
+        .map(Option::unwrap_or_default)
+    }
+
+    /// Fetches the votes of a contest, `None` if there is no such contest
+    pub fn fetch_contested_resource_vote_tally(
+        &self,
+        contest_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Option<ContestedResourceVoteTally>, Error> {
+        if self
+            .fetch_contest_end_time(contest_id, transaction, drive_version)?
+            .is_none()
+        {
+            return Ok(None);
+        }
+
+        let path_query = Self::contested_resource_vote_tally_query(contest_id);
+
+        let key_elements = self.query_key_elements(&path_query, transaction)?;
+
+        ContestedResourceVoteTally::try_from_key_elements(key_elements).map(Some)
+    }
+
+    /// Fetches the ids of contests with a voting period that ended at the given time or before,
+    /// ordered by the end of their voting period
+    pub fn fetch_ended_contest_ids(
+        &self,
+        ended_at_ms: TimestampMillis,
+        limit: u16,
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<[u8; 32]>, Error> {
+        if !self.has_contested_resources(transaction, drive_version)? {
+            return Ok(vec![]);
+        }
+
+        let mut query = Query::new();
+        query.insert_item(QueryItem::RangeTo(
+            ..ended_at_ms.saturating_add(1).to_be_bytes().to_vec(),
+        ));
+
+        let path_query = PathQuery::new(
+            contests_by_end_time_path_vec(),
+            SizedQuery::new(query, Some(limit), None),
+        );
+
+        self.query_key_elements(&path_query, transaction)?
+            .into_iter()
+            .map(|(key, _)| {
+                key.get(8..)
+                    .and_then(|contest_id| contest_id.try_into().ok())
+                    .ok_or(Error::Drive(DriveError::CorruptedSerialization(
+                        "contest end time key must be the end time followed by the contest id",
+                    )))
+            })
+            .collect()
+    }
+
+    /// Fetches a contest with its contenders and votes, `None` if there is no such contest
+    pub fn fetch_contested_resource(
+        &self,
+        contest_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Option<ContestedResource>, Error> {
+        let Some(end_time_ms) =
+            self.fetch_contest_end_time(contest_id, transaction, drive_version)?
+        else {
+            return Ok(None);
+        };
+
+        let vote_poll_element = self.grove_get_raw(
+            contest_path_vec(contest_id).as_slice().into(),
+            &CONTEST_VOTE_POLL_KEY,
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )?;
+
+        let Some(Element::Item(vote_poll_bytes, _)) = vote_poll_element else {
+            return Err(Error::Drive(DriveError::CorruptedElementType(
+                "contest vote poll must be an item",
+            )));
+        };
+
+        let vote_poll =
+            ContestedDocumentResourceVotePoll::deserialize_from_bytes(&vote_poll_bytes)?;
+
+        let contenders = self
+            .query_key_elements(
+                &Self::all_keys_query(contest_contenders_path_vec(contest_id)),
+                transaction,
+            )?
+            .into_iter()
+            .map(|(key, element)| match element {
+                Element::Item(serialized_document, element_flags) => Ok((
+                    Identifier::from_bytes(&key)?,
+                    ContestedResourceContender {
+                        serialized_document,
+                        storage_flags: StorageFlags::map_some_element_flags_ref(&element_flags)?,
+                    },
+                )),
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "contender document must be an item",
+                ))),
+            })
+            .collect::<Result<BTreeMap<Identifier, ContestedResourceContender>, Error>>()?;
+
+        let vote_tally =
+            ContestedResourceVoteTally::try_from_key_elements(self.query_key_elements(
+                &Self::contested_resource_vote_tally_query(contest_id),
+                transaction,
+            )?)?;
+
+        let votes = self
+            .query_key_elements(
+                &Self::all_keys_query(contest_votes_path_vec(contest_id)),
+                transaction,
+            )?
+            .into_iter()
+            .map(|(key, element)| match element {
+                Element::Item(choice_key, _) => Ok((Identifier::from_bytes(&key)?, choice_key)),
+                _ => Err(Error::Drive(DriveError::CorruptedElementType(
+                    "vote must be an item",
+                ))),
+            })
+            .collect::<Result<BTreeMap<Identifier, Vec<u8>>, Error>>()?;
+
+        let vote_changes_exist = self.grove_has_raw(
+            contest_path_vec(contest_id).as_slice().into(),
+            &CONTEST_VOTE_CHANGES_KEY,
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )?;
+
+        let vote_changes = if vote_changes_exist {
+            self.query_key_elements(
+                &Self::all_keys_query(contest_vote_changes_path_vec(contest_id)),
+                transaction,
+            )?
+            .into_iter()
+            .map(|(key, element)| {
+                Ok((
+                    Identifier::from_bytes(&key)?,
+                    Self::vote_changes_from_element(&element)?,
+                ))
+            })
+            .collect::<Result<BTreeMap<Identifier, u16>, Error>>()?
+        } else {
+            BTreeMap::new()
+        };
+
+        Ok(Some(ContestedResource {
+            contest_id: *contest_id,
+            vote_poll,
+            end_time_ms,
+            contenders,
+            vote_tally,
+            votes,
+            vote_changes,
+        }))
+    }
+
+    fn has_contested_resources(
+        &self,
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<bool, Error> {
+        // The tree is created with the first contest
+        self.grove_has_raw(
+            (&misc_path()).into(),
+            CONTESTED_RESOURCES_STORAGE_KEY,
+            StatefulDirectQuery,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+
+    fn all_keys_query(path: Vec<Vec<u8>>) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_all();
+        PathQuery::new(path, SizedQuery::new(query, None, None))
+    }
+
+    fn query_key_elements(
+        &self,
+        path_query: &PathQuery,
+        transaction: TransactionArg,
+    ) -> Result<Vec<(Vec<u8>, Element)>, Error> {
+        Ok(self
+            .grove
+            .query_raw(
+                path_query,
+                transaction.is_some(),
+                QueryResultType::QueryKeyElementPairResultType,
+                transaction,
+            )
+            .unwrap()
+            .map_err(Error::GroveDB)?
+            .0
+            .to_key_elements())
+    }
+
+    fn end_time_from_element(element: &Element) -> Result<TimestampMillis, Error> {
+        let Element::Item(bytes, _) = element else {
+            return Err(Error::Drive(DriveError::CorruptedElementType(
+                "contest end time must be an item",
+            )));
+        };
+
+        let bytes: [u8; 8] = bytes.as_slice().try_into().map_err(|_| {
+            Error::Drive(DriveError::CorruptedSerialization(
+                "contest end time must be 8 bytes",
+            ))
+        })?;
+
+        Ok(TimestampMillis::from_be_bytes(bytes))
+    }
+
+    fn vote_changes_from_element(element: &Element) -> Result<u16, Error> {
+        let Element::Item(bytes, _) = element else {
+            return Err(Error::Drive(DriveError::CorruptedElementType(
+                "vote changes must be an item",
+            )));
+        };
+
+        let bytes: [u8; 2] = bytes.as_slice().try_into().map_err(|_| {
+            Error::Drive(DriveError::CorruptedSerialization(
+                "vote changes must be 2 bytes",
+            ))
+        })?;
+
+        Ok(u16::from_be_bytes(bytes))
+    }
+}
@@ -0,0 +1,151 @@
+//! Contested resources.
+//!
+//! Documents created with a value in a contested unique index aren't inserted right away,
+//! they are held as contenders of a contest on that value. Masternodes vote on who should
+//! get the document during the voting period, afterwards the document is awarded to
+//! the contender with the most votes.
+//!
+//! Contests are stored in the Misc tree:
+//!
+//! ```text
+//! Misc/c/0/<end time><contest id>                   contests by the end of their voting period
+//! Misc/c/1/<contest id>/0                           the vote poll
+//! Misc/c/1/<contest id>/1                           the end of the voting period
+//! Misc/c/1/<contest id>/2/<identity id>             the serialized document of a contender
+//! Misc/c/1/<contest id>/3/<choice>/<pro tx hash>    a vote towards a choice (sum tree per choice)
+//! Misc/c/1/<contest id>/4/<pro tx hash>             the current choice of a voter
+//! ```
+
+#[cfg(feature = "full")]
+mod fetch;
+/// Paths of contested resources
+pub mod paths;
+#[cfg(feature = "full")]
+mod prove;
+mod queries;
+
+use crate::drive::flags::StorageFlags;
+use crate::drive::votes::paths::ABSTAIN_VOTE_KEY;
+use crate::error::drive::DriveError;
+use crate::error::Error;
+use dpp::platform_value::Identifier;
+use dpp::prelude::TimestampMillis;
+use dpp::voting::ContestedDocumentResourceVotePoll;
+use grovedb::Element;
+use std::collections::BTreeMap;
+
+/// The votes of a contest
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContestedResourceVoteTally {
+    /// Votes towards every contender
+    pub votes_by_contender: BTreeMap<Identifier, u64>,
+    /// Votes of masternodes that don't support any contender
+    pub abstain_votes: u64,
+}
+
+impl ContestedResourceVoteTally {
+    /// The contender with the most votes, ties are won by the lowest identity id
+    pub fn winner(&self) -> Option<Identifier> {
+        self.votes_by_contender
+            .iter()
+            .fold(
+                None,
+                |winner: Option<(&Identifier, &u64)>, (contender_id, votes)| match winner {
+                    Some((_, winner_votes)) if winner_votes >= votes => winner,
+                    _ => Some((contender_id, votes)),
+                },
+            )
+            .map(|(contender_id, _)| *contender_id)
+    }
+
+    /// Builds the tally from the sum trees of votes towards every choice
+    pub(crate) fn try_from_key_elements(
+        key_elements: Vec<(Vec<u8>, Element)>,
+    ) -> Result<Self, Error> {
+        let mut vote_tally = ContestedResourceVoteTally::default();
+
+        for (key, element) in key_elements {
+            let Element::SumTree(_, votes, _) = element else {
+                return Err(Error::Drive(DriveError::CorruptedElementType(
+                    "votes towards a choice must be a sum tree",
+                )));
+            };
+
+            let votes = votes.try_into().map_err(|_| {
+                Error::Drive(DriveError::CorruptedDriveState(
+                    "vote count must be positive".to_string(),
+                ))
+            })?;
+
+            if key == ABSTAIN_VOTE_KEY {
+                vote_tally.abstain_votes = votes;
+            } else {
+                vote_tally
+                    .votes_by_contender
+                    .insert(Identifier::from_bytes(&key)?, votes);
+            }
+        }
+
+        Ok(vote_tally)
+    }
+}
+
+/// A document held as a contender in a contest
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContestedResourceContender {
+    /// The serialized document
+    pub serialized_document: Vec<u8>,
+    /// The storage flags the document was stored with
+    pub storage_flags: Option<StorageFlags>,
+}
+
+/// A contest with everything needed to award it to the winner
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContestedResource {
+    /// The unique id of the vote poll
+    pub contest_id: [u8; 32],
+    /// The contested value
+    pub vote_poll: ContestedDocumentResourceVotePoll,
+    /// The end of the voting period
+    pub end_time_ms: TimestampMillis,
+    /// Documents by contender identity id
+    pub contenders: BTreeMap<Identifier, ContestedResourceContender>,
+    /// The votes of the contest
+    pub vote_tally: ContestedResourceVoteTally,
+    /// The tally key of the choice of every voter by pro tx hash
+    pub votes: BTreeMap<Identifier, Vec<u8>>,
+    /// How many times voters changed their vote by pro tx hash
+    pub vote_changes: BTreeMap<Identifier, u16>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_award_contest_to_the_contender_with_most_votes() {
+        let tally = ContestedResourceVoteTally {
+            votes_by_contender: BTreeMap::from([
+                (Identifier::new([1; 32]), 3),
+                (Identifier::new([2; 32]), 5),
+                (Identifier::new([3; 32]), 4),
+            ]),
+            abstain_votes: 10,
+        };
+
+        assert_eq!(tally.winner(), Some(Identifier::new([2; 32])));
+    }
+
+    #[test]
+    fn should_award_tied_contest_to_the_lowest_identity_id() {
+        let tally = ContestedResourceVoteTally {
+            votes_by_contender: BTreeMap::from([
+                (Identifier::new([2; 32]), 0),
+                (Identifier::new([1; 32]), 0),
+            ]),
+            abstain_votes: 0,
+        };
+
+        assert_eq!(tally.winner(), Some(Identifier::new([1; 32])));
+    }
+}
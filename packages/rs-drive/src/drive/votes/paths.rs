@@ -0,0 +1,109 @@
+use crate::drive::RootTree;
+use dpp::voting::ResourceVoteChoice;
+
+/// Misc tree key of the tree holding contested resources
+pub const CONTESTED_RESOURCES_STORAGE_KEY: &[u8; 1] = b"c";
+/// constant id for subtree containing contest ids by the end of their voting period
+pub const CONTESTS_BY_END_TIME_KEY: [u8; 1] = [0];
+/// constant id for subtree containing contests by their id
+pub const CONTESTS_KEY: [u8; 1] = [1];
+
+/// constant id for the item holding the vote poll of a contest
+pub const CONTEST_VOTE_POLL_KEY: [u8; 1] = [0];
+/// constant id for the item holding the end of the voting period of a contest
+pub const CONTEST_END_TIME_KEY: [u8; 1] = [1];
+/// constant id for subtree containing serialized documents by contender identity id
+pub const CONTEST_CONTENDERS_KEY: [u8; 1] = [2];
+/// constant id for subtree containing a sum tree of votes for every choice
+pub const CONTEST_TALLIES_KEY: [u8; 1] = [3];
+/// constant id for subtree containing the choice of every voter by pro tx hash
+pub const CONTEST_VOTES_KEY: [u8; 1] = [4];
+/// constant id for subtree containing how many times every voter changed its vote by pro tx hash,
+/// it is created with the first vote change
+pub const CONTEST_VOTE_CHANGES_KEY: [u8; 1] = [5];
+
+/// Tally key of abstaining votes, identity ids are 32 bytes so they can't collide with it
+pub const ABSTAIN_VOTE_KEY: [u8; 1] = [0];
+
+/// Helper function to get the contested resources path as Vec
+pub fn contested_resources_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        CONTESTED_RESOURCES_STORAGE_KEY.to_vec(),
+    ]
+}
+
+/// Helper function to get the path of contest ids by end time as Vec
+pub fn contests_by_end_time_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        CONTESTED_RESOURCES_STORAGE_KEY.to_vec(),
+        CONTESTS_BY_END_TIME_KEY.to_vec(),
+    ]
+}
+
+/// Helper function to get the contests path as Vec
+pub fn contests_path_vec() -> Vec<Vec<u8>> {
+    vec![
+        vec![RootTree::Misc as u8],
+        CONTESTED_RESOURCES_STORAGE_KEY.to_vec(),
+        CONTESTS_KEY.to_vec(),
+    ]
+}
+
+/// Helper function to get the path of a contest as Vec
+pub fn contest_path_vec(contest_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = contests_path_vec();
+    path.push(contest_id.to_vec());
+    path
+}
+
+/// Helper function to get the contenders path of a contest as Vec
+pub fn contest_contenders_path_vec(contest_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = contest_path_vec(contest_id);
+    path.push(CONTEST_CONTENDERS_KEY.to_vec());
+    path
+}
+
+/// Helper function to get the tallies path of a contest as Vec
+pub fn contest_tallies_path_vec(contest_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = contest_path_vec(contest_id);
+    path.push(CONTEST_TALLIES_KEY.to_vec());
+    path
+}
+
+/// Helper function to get the path of the votes towards a choice in a contest as Vec
+pub fn contest_choice_tally_path_vec(contest_id: &[u8; 32], choice_key: &[u8]) -> Vec<Vec<u8>> {
+    let mut path = contest_tallies_path_vec(contest_id);
+    path.push(choice_key.to_vec());
+    path
+}
+
+/// Helper function to get the votes path of a contest as Vec
+pub fn contest_votes_path_vec(contest_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = contest_path_vec(contest_id);
+    path.push(CONTEST_VOTES_KEY.to_vec());
+    path
+}
+
+/// Helper function to get the vote changes path of a contest as Vec
+pub fn contest_vote_changes_path_vec(contest_id: &[u8; 32]) -> Vec<Vec<u8>> {
+    let mut path = contest_path_vec(contest_id);
+    path.push(CONTEST_VOTE_CHANGES_KEY.to_vec());
+    path
+}
+
+/// The key of a contest in the tree of contest ids by end time
+pub fn contest_end_time_key(end_time_ms: u64, contest_id: &[u8; 32]) -> Vec<u8> {
+    let mut key = end_time_ms.to_be_bytes().to_vec();
+    key.extend_from_slice(contest_id);
+    key
+}
+
+/// The tally key of a vote choice
+pub fn vote_choice_key(choice: &ResourceVoteChoice) -> Vec<u8> {
+    match choice {
+        ResourceVoteChoice::TowardsIdentity(identity_id) => identity_id.to_vec(),
+        ResourceVoteChoice::Abstain => ABSTAIN_VOTE_KEY.to_vec(),
+    }
+}
@@ -0,0 +1,23 @@
+use crate::drive::Drive;
+use crate::error::Error;
+use dpp::version::drive_versions::DriveVersion;
+use grovedb::TransactionArg;
+
+impl Drive {
+    /// Proves the votes towards every choice of a contest
+    pub fn prove_contested_resource_vote_tally(
+        &self,
+        contest_id: &[u8; 32],
+        transaction: TransactionArg,
+        drive_version: &DriveVersion,
+    ) -> Result<Vec<u8>, Error> {
+        let vote_tally_query = Self::contested_resource_vote_tally_query(contest_id);
+        self.grove_get_proved_path_query(
+            &vote_tally_query,
+            false,
+            transaction,
+            &mut vec![],
+            drive_version,
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use crate::drive::votes::paths::contest_tallies_path_vec;
+use crate::drive::Drive;
+use grovedb::{PathQuery, Query, SizedQuery};
+
+impl Drive {
+    /// The query for the votes towards every choice of a contest
+    pub fn contested_resource_vote_tally_query(contest_id: &[u8; 32]) -> PathQuery {
+        let mut query = Query::new();
+        query.insert_all();
+        PathQuery::new(
+            contest_tallies_path_vec(contest_id),
+            SizedQuery::new(query, None, None),
+        )
+    }
+}
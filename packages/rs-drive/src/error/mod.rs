@@ -8,6 +8,7 @@ use dpp::ProtocolError;
 use fee::FeeError;
 use identity::IdentityError;
 use query::QuerySyntaxError;
+use state_archive::StateArchiveError;
 
 ///DataContract errors
 pub mod contract;
@@ -23,6 +24,8 @@ pub mod identity;
 pub mod proof;
 /// Query module
 pub mod query;
+/// State archive module
+pub mod state_archive;
 /// Storage flags module
 pub mod storage_flags;
 
@@ -62,4 +65,7 @@ pub enum Error {
     ///DataContract error
     #[error("contract: {0}")]
     DataContract(#[from] DataContractError),
+    /// State archive error
+    #[error("state archive: {0}")]
+    StateArchive(#[from] StateArchiveError),
 }
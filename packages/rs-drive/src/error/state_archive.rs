@@ -0,0 +1,65 @@
+/// State archive errors
+#[derive(Debug, thiserror::Error)]
+pub enum StateArchiveError {
+    /// Io error
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Not a state archive
+    #[error("not a state archive")]
+    InvalidMagic,
+
+    /// Unsupported archive format version
+    #[error("unsupported state archive format version {0}")]
+    UnsupportedFormatVersion(u16),
+
+    /// The archive was exported with another protocol version
+    #[error("state archive protocol version {archive} doesn't match platform protocol version {platform}")]
+    ProtocolVersionMismatch {
+        /// Protocol version of the archive
+        archive: u32,
+        /// Protocol version of the platform
+        platform: u32,
+    },
+
+    /// Record checksum doesn't match its content
+    #[error("invalid checksum of state archive record {0}")]
+    InvalidRecordChecksum(u64),
+
+    /// Archive checksum or record count in the footer don't match the records
+    #[error("state archive footer doesn't match records: {0}")]
+    InvalidFooter(String),
+
+    /// The archive ended before the footer
+    #[error("state archive is truncated")]
+    Truncated,
+
+    /// Records are not in the expected order
+    #[error("unexpected state archive record: {0}")]
+    UnexpectedRecord(&'static str),
+
+    /// Record can't be encoded or decoded
+    #[error("state archive record serialization error: {0}")]
+    Serialization(String),
+
+    /// A document references a data contract that isn't in the archive
+    #[error("state archive document references unknown data contract {0}")]
+    UnknownDataContract(String),
+
+    /// The system state proof can't be verified
+    #[error("invalid state archive system state proof: {0}")]
+    InvalidSystemStateProof(String),
+
+    /// The system state proof doesn't prove the root hash the archive was exported with
+    #[error("state archive system state proves root hash {proved} instead of the exported root hash {exported}")]
+    SystemStateRootHashMismatch {
+        /// Root hash in the archive header, hex encoded
+        exported: String,
+        /// Root hash proved by the system state, hex encoded
+        proved: String,
+    },
+
+    /// Import must be done into a fresh state
+    #[error("state is not empty: {0}")]
+    StateNotEmpty(&'static str),
+}
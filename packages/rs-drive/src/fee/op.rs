@@ -241,6 +241,7 @@ impl LowLevelDriveOperation {
                         processing_fee,
                         fee_refunds,
                         removed_bytes_from_system,
+                        ..Default::default()
                     })
                 }
             })
@@ -22,6 +22,9 @@ pub mod error;
 /// Fee pools module
 #[cfg(feature = "full")]
 pub mod fee_pools;
+/// Metrics module
+#[cfg(feature = "full")]
+pub mod metrics;
 /// Query module
 #[cfg(any(feature = "full", feature = "verify"))]
 pub mod query;
@@ -0,0 +1,38 @@
+//! Metrics reported by Drive
+//!
+//! Metrics are recorded with the [metrics] facade and exported by the recorder
+//! installed by the application using Drive (eg. Prometheus exporter in Drive ABCI).
+
+use grovedb_costs::OperationCost;
+use metrics::{counter, increment_counter, Label};
+
+/// Number of GroveDB seeks performed by applied operations
+pub const COUNTER_GROVEDB_SEEKS: &str = "drive_grovedb_seek_count_total";
+/// Number of bytes loaded from GroveDB storage by applied operations
+pub const COUNTER_GROVEDB_LOADED_BYTES: &str = "drive_grovedb_storage_loaded_bytes_total";
+/// Number of bytes added to GroveDB storage by applied operations
+pub const COUNTER_GROVEDB_ADDED_BYTES: &str = "drive_grovedb_storage_added_bytes_total";
+/// Number of data contract cache lookups labelled by result (hit or miss)
+pub const COUNTER_DATA_CONTRACT_CACHE_REQUESTS: &str = "drive_data_contract_cache_requests_total";
+
+const LABEL_RESULT: &str = "result";
+
+/// Add GroveDB seeks, loaded and added bytes of applied operations to the counters
+pub fn grovedb_operation_cost(cost: &OperationCost) {
+    counter!(COUNTER_GROVEDB_SEEKS, cost.seek_count as u64);
+    counter!(
+        COUNTER_GROVEDB_LOADED_BYTES,
+        cost.storage_loaded_bytes as u64
+    );
+    counter!(
+        COUNTER_GROVEDB_ADDED_BYTES,
+        cost.storage_cost.added_bytes as u64
+    );
+}
+
+/// Count a data contract cache lookup
+pub fn data_contract_cache_request(hit: bool) {
+    let result = if hit { "hit" } else { "miss" };
+    let labels = vec![Label::new(LABEL_RESULT, result)];
+    increment_counter!(COUNTER_DATA_CONTRACT_CACHE_REQUESTS, labels);
+}
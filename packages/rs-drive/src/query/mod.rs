@@ -482,7 +482,7 @@ impl<'a> DriveQuery<'a> {
             )));
         }
 
-        Ok(DriveQuery {
+        let query = DriveQuery {
             contract,
             document_type,
             internal_clauses,
@@ -492,7 +492,11 @@ impl<'a> DriveQuery<'a> {
             start_at,
             start_at_included,
             block_time_ms,
-        })
+        };
+
+        query.validate_block_time()?;
+
+        Ok(query)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -563,7 +567,7 @@ impl<'a> DriveQuery<'a> {
             .map(|order_clause| Ok((order_clause.field.clone(), order_clause.to_owned())))
             .collect::<Result<IndexMap<String, OrderClause>, Error>>()?;
 
-        Ok(DriveQuery {
+        let query = DriveQuery {
             contract,
             document_type,
             internal_clauses,
@@ -573,7 +577,11 @@ impl<'a> DriveQuery<'a> {
             start_at,
             start_at_included,
             block_time_ms,
-        })
+        };
+
+        query.validate_block_time()?;
+
+        Ok(query)
     }
 
     #[cfg(any(feature = "full", feature = "verify"))]
@@ -882,6 +890,84 @@ impl<'a> DriveQuery<'a> {
         }
     }
 
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns true if the query resolves documents to their revision as of the block time.
+    pub fn is_as_of_block_time(&self) -> bool {
+        self.block_time_ms.is_some() && self.document_type.documents_keep_history()
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Checks that the block time of the query can be resolved.
+    ///
+    /// Only revisions of documents keeping history are stored by time, and they can only be
+    /// reached through the primary key, so point in time queries are limited to `$id` equal
+    /// and in clauses on document types keeping history.
+    pub fn validate_block_time(&self) -> Result<(), Error> {
+        if self.block_time_ms.is_none() {
+            return Ok(());
+        }
+
+        if !self.document_type.documents_keep_history() {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(format!(
+                "point in time queries need document type {} to keep history",
+                self.document_type.name()
+            ))));
+        }
+
+        if !self.is_for_primary_key()
+            || (self.internal_clauses.primary_key_equal_clause.is_none()
+                && self.internal_clauses.primary_key_in_clause.is_none())
+        {
+            return Err(Error::Query(QuerySyntaxError::Unsupported(
+                "point in time queries are only supported for $id equal and in clauses".to_string(),
+            )));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Returns the subquery of document revisions stored up to and including the block time,
+    /// from the latest one.
+    fn revisions_as_of_block_time_subquery(block_time_ms: u64) -> Query {
+        // The reference to the latest revision is stored at key 0 which sorts before
+        // all encoded times, starting at encoded 0 keeps it out of the results
+        let mut sub_query = Query::new_with_direction(false);
+        sub_query.insert_range_inclusive(encode_u64(0)..=encode_u64(block_time_ms));
+        sub_query
+    }
+
+    #[cfg(any(feature = "full", feature = "verify"))]
+    /// Keeps the latest revision of every document out of revisions as of the block time,
+    /// then applies the offset and limit of the query to the documents.
+    ///
+    /// Revisions of a document are grouped under the document path from the latest one.
+    pub(crate) fn latest_revisions_as_of_block_time(
+        &self,
+        revisions: impl IntoIterator<Item = (Vec<Vec<u8>>, grovedb::Element)>,
+    ) -> Vec<grovedb::Element> {
+        // an equal clause already limits the path query to its single document
+        let offset = if self.internal_clauses.primary_key_equal_clause.is_some() {
+            0
+        } else {
+            self.offset.unwrap_or_default() as usize
+        };
+        let mut last_path: Option<Vec<Vec<u8>>> = None;
+        revisions
+            .into_iter()
+            .filter_map(|(path, element)| {
+                if last_path.as_ref() == Some(&path) {
+                    None
+                } else {
+                    last_path = Some(path);
+                    Some(element)
+                }
+            })
+            .skip(offset)
+            .take(self.limit.map(|limit| limit as usize).unwrap_or(usize::MAX))
+            .collect()
+    }
+
     #[cfg(any(feature = "full", feature = "verify"))]
     /// Returns a path query given a document type path and starting document.
     pub fn get_primary_key_path_query(
@@ -890,6 +976,10 @@ impl<'a> DriveQuery<'a> {
         starts_at_document: Option<(Document, bool)>,
         platform_version: &PlatformVersion,
     ) -> Result<PathQuery, Error> {
+        // point in time queries on ranges or indexes would need limited subqueries
+        // as we only want the first revision before the block time of every document
+        self.validate_block_time()?;
+
         let mut path = document_type_path;
 
         // Add primary key ($id) subtree
@@ -907,10 +997,7 @@ impl<'a> DriveQuery<'a> {
             if self.document_type.documents_keep_history() {
                 // if the documents keep history then we should insert a subquery
                 if let Some(block_time) = self.block_time_ms {
-                    let encoded_block_time = encode_u64(block_time);
-                    let mut sub_query = Query::new_with_direction(false);
-                    sub_query.insert_range_to_inclusive(..=encoded_block_time);
-                    query.set_subquery(sub_query);
+                    query.set_subquery(Self::revisions_as_of_block_time_subquery(block_time));
                 } else {
                     query.set_subquery_key(vec![0]);
                 }
@@ -985,18 +1072,23 @@ impl<'a> DriveQuery<'a> {
 
                 if self.document_type.documents_keep_history() {
                     // if the documents keep history then we should insert a subquery
-                    if let Some(_block_time) = self.block_time_ms {
-                        //todo
-                        return Err(Error::Query(QuerySyntaxError::Unsupported(
-                            "Not yet implemented".to_string(),
-                        )));
-                        // in order to be able to do this we would need limited subqueries
-                        // as we only want the first element before the block_time
-
-                        // let encoded_block_time = encode_float(block_time)?;
-                        // let mut sub_query = Query::new_with_direction(false);
-                        // sub_query.insert_range_to_inclusive(..=encoded_block_time);
-                        // query.set_subquery(sub_query);
+                    if let Some(block_time) = self.block_time_ms {
+                        query.set_subquery(Self::revisions_as_of_block_time_subquery(block_time));
+
+                        // Subqueries can't be limited to the first element, so revisions up to
+                        // the block time are queried from the latest one and the first one of
+                        // every document is kept after the query, limit and offset are applied
+                        // to documents then. The revisions are bounded by the limit and offset
+                        // as well, documents having several revisions fill a page earlier
+                        // and the next ones are fetched starting after the last document.
+                        let revisions_limit = self
+                            .limit
+                            .unwrap_or(in_values.len() as u16)
+                            .saturating_add(self.offset.unwrap_or_default());
+                        return Ok(PathQuery::new(
+                            path,
+                            SizedQuery::new(query, Some(revisions_limit), None),
+                        ));
                     } else {
                         query.set_subquery_key(vec![0]);
                     }
@@ -1026,20 +1118,7 @@ impl<'a> DriveQuery<'a> {
 
                 if self.document_type.documents_keep_history() {
                     // if the documents keep history then we should insert a subquery
-                    if let Some(_block_time) = self.block_time_ms {
-                        return Err(Error::Query(QuerySyntaxError::Unsupported(
-                            "this query is not supported".to_string(),
-                        )));
-                        // in order to be able to do this we would need limited subqueries
-                        // as we only want the first element before the block_time
-
-                        // let encoded_block_time = encode_float(block_time)?;
-                        // let mut sub_query = Query::new_with_direction(false);
-                        // sub_query.insert_range_to_inclusive(..=encoded_block_time);
-                        // query.set_subquery(sub_query);
-                    } else {
-                        query.set_subquery_key(vec![0]);
-                    }
+                    query.set_subquery_key(vec![0]);
                 }
 
                 Ok(PathQuery::new(
@@ -1359,6 +1438,10 @@ impl<'a> DriveQuery<'a> {
         starts_at_document: Option<(Document, bool)>,
         platform_version: &PlatformVersion,
     ) -> Result<PathQuery, Error> {
+        // point in time queries on ranges or indexes would need limited subqueries
+        // as we only want the first revision before the block time of every document
+        self.validate_block_time()?;
+
         let index = self.find_best_index(platform_version)?;
         let ordered_clauses: Vec<&WhereClause> = index
             .properties
@@ -1735,6 +1818,14 @@ impl<'a> DriveQuery<'a> {
         drive_operations: &mut Vec<LowLevelDriveOperation>,
         platform_version: &PlatformVersion,
     ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        if self.is_as_of_block_time() {
+            return self.execute_as_of_block_time_no_proof_internal(
+                drive,
+                transaction,
+                drive_operations,
+                platform_version,
+            );
+        }
         let path_query = self.construct_path_query_operations(
             drive,
             false,
@@ -1761,6 +1852,35 @@ impl<'a> DriveQuery<'a> {
         }
     }
 
+    #[cfg(feature = "full")]
+    /// Executes an internal query with no proof resolving every document to its revision
+    /// as of the block time and returns the values and skipped items.
+    fn execute_as_of_block_time_no_proof_internal(
+        &self,
+        drive: &Drive,
+        transaction: TransactionArg,
+        drive_operations: &mut Vec<LowLevelDriveOperation>,
+        platform_version: &PlatformVersion,
+    ) -> Result<(Vec<Vec<u8>>, u16), Error> {
+        let (query_result, skipped) = self.execute_no_proof_internal(
+            drive,
+            QueryResultType::QueryPathKeyElementTrioResultType,
+            transaction,
+            drive_operations,
+            platform_version,
+        )?;
+        let revisions = query_result
+            .to_path_key_elements()
+            .into_iter()
+            .map(|(path, _key, element)| (path, element));
+        let documents = self
+            .latest_revisions_as_of_block_time(revisions)
+            .into_iter()
+            .map(|element| element.into_item_bytes().map_err(Error::GroveDB))
+            .collect::<Result<Vec<Vec<u8>>, Error>>()?;
+        Ok((documents, skipped))
+    }
+
     #[cfg(feature = "full")]
     /// Executes an internal query with no proof and returns the values and skipped items.
     pub(crate) fn execute_no_proof_internal(
@@ -28,6 +28,13 @@ impl DocumentsBatchTransitionAction {
         }
     }
 
+    /// sponsor id
+    pub fn sponsor_id(&self) -> Option<Identifier> {
+        match self {
+            DocumentsBatchTransitionAction::V0(v0) => v0.sponsor_id,
+        }
+    }
+
     /// transitions
     pub fn transitions(&self) -> &Vec<DocumentTransitionAction> {
         match self {
@@ -6,6 +6,8 @@ use dpp::identifier::Identifier;
 pub struct DocumentsBatchTransitionActionV0 {
     /// The owner making the transitions
     pub owner_id: Identifier,
+    /// The identity paying the fees of the batch, if it's not the owner
+    pub sponsor_id: Option<Identifier>,
     /// The inner transitions
     pub transitions: Vec<DocumentTransitionAction>,
 }
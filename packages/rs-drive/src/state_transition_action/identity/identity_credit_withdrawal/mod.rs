@@ -6,6 +6,7 @@ pub mod v0;
 use crate::state_transition_action::identity::identity_credit_withdrawal::v0::IdentityCreditWithdrawalTransitionActionV0;
 use derive_more::From;
 use dpp::document::Document;
+use dpp::fee::Credits;
 
 use dpp::platform_value::Identifier;
 use dpp::prelude::Revision;
@@ -25,6 +26,13 @@ impl IdentityCreditWithdrawalTransitionAction {
         }
     }
 
+    /// Withdrawal amount
+    pub fn amount(&self) -> Credits {
+        match self {
+            IdentityCreditWithdrawalTransitionAction::V0(transition) => transition.amount,
+        }
+    }
+
     /// Identity Id
     pub fn identity_id(&self) -> Identifier {
         match self {
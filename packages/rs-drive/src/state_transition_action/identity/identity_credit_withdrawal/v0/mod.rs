@@ -1,6 +1,7 @@
 mod transformer;
 
 use dpp::document::Document;
+use dpp::fee::Credits;
 use dpp::identifier::Identifier;
 use dpp::prelude::Revision;
 
@@ -14,6 +15,8 @@ pub struct IdentityCreditWithdrawalTransitionActionV0 {
     pub identity_id: Identifier,
     /// revision
     pub revision: Revision,
+    /// withdrawal amount
+    pub amount: Credits,
     /// prepared withdrawal document
     pub prepared_withdrawal_document: Document,
 }
@@ -5,6 +5,7 @@ pub mod v0;
 
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
 use derive_more::From;
+use dpp::identity::sponsorship::SponsorshipSpendingLimit;
 use dpp::identity::{IdentityPublicKey, KeyID, TimestampMillis};
 use dpp::platform_value::Identifier;
 use dpp::prelude::Revision;
@@ -46,6 +47,15 @@ impl IdentityUpdateTransitionAction {
         }
     }
 
+    /// Sponsorship Spending Limits
+    pub fn sponsorship_spending_limits(&self) -> &[SponsorshipSpendingLimit] {
+        match self {
+            IdentityUpdateTransitionAction::V0(transition) => {
+                &transition.sponsorship_spending_limits
+            }
+        }
+    }
+
     /// Identity Id
     pub fn identity_id(&self) -> Identifier {
         match self {
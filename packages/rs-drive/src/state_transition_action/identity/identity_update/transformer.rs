@@ -6,6 +6,7 @@ impl From<IdentityUpdateTransition> for IdentityUpdateTransitionAction {
     fn from(value: IdentityUpdateTransition) -> Self {
         match value {
             IdentityUpdateTransition::V0(v0) => IdentityUpdateTransitionActionV0::from(v0).into(),
+            IdentityUpdateTransition::V1(v1) => IdentityUpdateTransitionActionV0::from(v1).into(),
         }
     }
 }
@@ -14,6 +15,7 @@ impl From<&IdentityUpdateTransition> for IdentityUpdateTransitionAction {
     fn from(value: &IdentityUpdateTransition) -> Self {
         match value {
             IdentityUpdateTransition::V0(v0) => IdentityUpdateTransitionActionV0::from(v0).into(),
+            IdentityUpdateTransition::V1(v1) => IdentityUpdateTransitionActionV0::from(v1).into(),
         }
     }
 }
@@ -1,6 +1,7 @@
 mod transformer;
 
 use dpp::identifier::Identifier;
+use dpp::identity::sponsorship::SponsorshipSpendingLimit;
 use dpp::identity::{IdentityPublicKey, KeyID, TimestampMillis};
 use dpp::prelude::Revision;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,8 @@ pub struct IdentityUpdateTransitionActionV0 {
     pub disable_public_keys: Vec<KeyID>,
     /// public keys disabled at
     pub public_keys_disabled_at: Option<TimestampMillis>,
+    /// sponsorship spending limits
+    pub sponsorship_spending_limits: Vec<SponsorshipSpendingLimit>,
     /// identity id
     pub identity_id: Identifier,
     /// revision
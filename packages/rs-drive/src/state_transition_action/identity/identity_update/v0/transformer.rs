@@ -1,5 +1,6 @@
 use crate::state_transition_action::identity::identity_update::v0::IdentityUpdateTransitionActionV0;
 use dpp::state_transition::identity_update_transition::v0::IdentityUpdateTransitionV0;
+use dpp::state_transition::identity_update_transition::v1::IdentityUpdateTransitionV1;
 
 impl From<IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
     fn from(value: IdentityUpdateTransitionV0) -> Self {
@@ -15,6 +16,7 @@ impl From<IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
             add_public_keys: add_public_keys.into_iter().map(|a| a.into()).collect(),
             disable_public_keys,
             public_keys_disabled_at,
+            sponsorship_spending_limits: vec![],
             identity_id,
             revision,
         }
@@ -38,6 +40,54 @@ impl From<&IdentityUpdateTransitionV0> for IdentityUpdateTransitionActionV0 {
                 .collect(),
             disable_public_keys: disable_public_keys.clone(),
             public_keys_disabled_at: *public_keys_disabled_at,
+            sponsorship_spending_limits: vec![],
+            identity_id: *identity_id,
+            revision: *revision,
+        }
+    }
+}
+
+impl From<IdentityUpdateTransitionV1> for IdentityUpdateTransitionActionV0 {
+    fn from(value: IdentityUpdateTransitionV1) -> Self {
+        let IdentityUpdateTransitionV1 {
+            identity_id,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            sponsorship_spending_limits,
+            revision,
+            ..
+        } = value;
+        IdentityUpdateTransitionActionV0 {
+            add_public_keys: add_public_keys.into_iter().map(|a| a.into()).collect(),
+            disable_public_keys,
+            public_keys_disabled_at,
+            sponsorship_spending_limits,
+            identity_id,
+            revision,
+        }
+    }
+}
+
+impl From<&IdentityUpdateTransitionV1> for IdentityUpdateTransitionActionV0 {
+    fn from(value: &IdentityUpdateTransitionV1) -> Self {
+        let IdentityUpdateTransitionV1 {
+            identity_id,
+            add_public_keys,
+            disable_public_keys,
+            public_keys_disabled_at,
+            sponsorship_spending_limits,
+            revision,
+            ..
+        } = value;
+        IdentityUpdateTransitionActionV0 {
+            add_public_keys: add_public_keys
+                .iter()
+                .map(|key| key.clone().into())
+                .collect(),
+            disable_public_keys: disable_public_keys.clone(),
+            public_keys_disabled_at: *public_keys_disabled_at,
+            sponsorship_spending_limits: sponsorship_spending_limits.clone(),
             identity_id: *identity_id,
             revision: *revision,
         }
@@ -0,0 +1,46 @@
+/// transformer
+pub mod transformer;
+/// v0
+pub mod v0;
+
+use crate::state_transition_action::identity::masternode_vote::v0::MasternodeVoteTransitionActionV0;
+use derive_more::From;
+use dpp::platform_value::Identifier;
+use dpp::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+
+/// action
+#[derive(Debug, Clone, From)]
+pub enum MasternodeVoteTransitionAction {
+    /// v0
+    V0(MasternodeVoteTransitionActionV0),
+}
+
+impl MasternodeVoteTransitionAction {
+    /// Pro tx hash of the voting masternode
+    pub fn pro_tx_hash(&self) -> Identifier {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => transition.pro_tx_hash,
+        }
+    }
+
+    /// Voter identity Id
+    pub fn voter_identity_id(&self) -> Identifier {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => transition.voter_identity_id,
+        }
+    }
+
+    /// The poll voted on
+    pub fn vote_poll(&self) -> &ContestedDocumentResourceVotePoll {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => &transition.vote_poll,
+        }
+    }
+
+    /// The choice of the voter
+    pub fn choice(&self) -> ResourceVoteChoice {
+        match self {
+            MasternodeVoteTransitionAction::V0(transition) => transition.choice,
+        }
+    }
+}
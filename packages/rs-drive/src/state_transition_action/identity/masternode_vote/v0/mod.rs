@@ -0,0 +1,19 @@
+mod transformer;
+
+use dpp::platform_value::Identifier;
+use dpp::voting::{ContestedDocumentResourceVotePoll, ResourceVoteChoice};
+use serde::{Deserialize, Serialize};
+
+/// action v0
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MasternodeVoteTransitionActionV0 {
+    /// pro tx hash of the voting masternode
+    pub pro_tx_hash: Identifier,
+    /// voter identity id
+    pub voter_identity_id: Identifier,
+    /// the poll voted on
+    pub vote_poll: ContestedDocumentResourceVotePoll,
+    /// the choice of the voter
+    pub choice: ResourceVoteChoice,
+}
@@ -0,0 +1,38 @@
+use crate::state_transition_action::identity::masternode_vote::v0::MasternodeVoteTransitionActionV0;
+use dpp::state_transition::state_transitions::identity::masternode_vote_transition::v0::MasternodeVoteTransitionV0;
+
+impl From<MasternodeVoteTransitionV0> for MasternodeVoteTransitionActionV0 {
+    fn from(value: MasternodeVoteTransitionV0) -> Self {
+        let MasternodeVoteTransitionV0 {
+            pro_tx_hash,
+            voter_identity_id,
+            vote_poll,
+            choice,
+            ..
+        } = value;
+        MasternodeVoteTransitionActionV0 {
+            pro_tx_hash,
+            voter_identity_id,
+            vote_poll,
+            choice,
+        }
+    }
+}
+
+impl From<&MasternodeVoteTransitionV0> for MasternodeVoteTransitionActionV0 {
+    fn from(value: &MasternodeVoteTransitionV0) -> Self {
+        let MasternodeVoteTransitionV0 {
+            pro_tx_hash,
+            voter_identity_id,
+            vote_poll,
+            choice,
+            ..
+        } = value;
+        MasternodeVoteTransitionActionV0 {
+            pro_tx_hash: *pro_tx_hash,
+            voter_identity_id: *voter_identity_id,
+            vote_poll: vote_poll.clone(),
+            choice: *choice,
+        }
+    }
+}
@@ -13,6 +13,7 @@ use crate::state_transition_action::identity::identity_credit_transfer::Identity
 use crate::state_transition_action::identity::identity_credit_withdrawal::IdentityCreditWithdrawalTransitionAction;
 use crate::state_transition_action::identity::identity_topup::IdentityTopUpTransitionAction;
 use crate::state_transition_action::identity::identity_update::IdentityUpdateTransitionAction;
+use crate::state_transition_action::identity::masternode_vote::MasternodeVoteTransitionAction;
 use derive_more::From;
 
 /// ST action
@@ -34,4 +35,6 @@ pub enum StateTransitionAction {
     IdentityUpdateAction(IdentityUpdateTransitionAction),
     /// identity credit transfer
     IdentityCreditTransferAction(IdentityCreditTransferTransitionAction),
+    /// masternode vote
+    MasternodeVoteAction(MasternodeVoteTransitionAction),
 }
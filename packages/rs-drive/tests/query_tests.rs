@@ -5058,6 +5058,153 @@ fn test_query_documents_by_created_at() {
     assert_eq!(query_result.documents().len(), 1);
 }
 
+#[cfg(feature = "full")]
+#[test]
+fn test_query_documents_by_case_insensitive_normalized_index() {
+    let drive = setup_drive_with_initial_state_structure();
+
+    let platform_version = PlatformVersion::latest();
+
+    let contract_value = platform_value!({
+        "$format_version": "0",
+        "id": "BZUodcFoFL6KvnonehrnMVggTvCe8W5MiRnZuqLb6M54",
+        "version": 1,
+        "ownerId": "GZVdTnLFAN2yE9rLeCHBDBCr7YQgmXJuoExkY347j7Z5",
+        "documentSchemas": {
+            "profile": {
+                "type": "object",
+                "indices": [
+                    {
+                        "name": "label",
+                        "properties": [
+                            {"label": {"order": "asc", "caseInsensitive": true, "normalize": "NFKC"}}
+                        ],
+                        "unique": true
+                    }
+                ],
+                "properties":{
+                    "label": {
+                        "type": "string",
+                        "maxLength": 63,
+                    }
+                },
+                "required": ["label"],
+                "additionalProperties": false,
+            },
+        },
+    });
+
+    let contract = DataContract::from_value(contract_value, false, platform_version)
+        .expect("should create a contract from value");
+
+    drive
+        .apply_contract(
+            &contract,
+            BlockInfo::default(),
+            true,
+            None,
+            None,
+            platform_version,
+        )
+        .expect("should apply contract");
+
+    let document_type = contract
+        .document_type_for_name("profile")
+        .expect("should have profile type");
+
+    // The fullwidth letters are only equal to "Alice" in NFKC
+    let document = document_type
+        .create_document_from_data(
+            platform_value!({ "label": "Ａｌｉｃｅ" }),
+            Identifier::random(),
+            random(),
+            platform_version,
+        )
+        .expect("should create document");
+
+    let info = DocumentAndContractInfo {
+        owned_document_info: OwnedDocumentInfo {
+            document_info: DocumentInfo::DocumentOwnedInfo((document, None)),
+            owner_id: None,
+        },
+        contract: &contract,
+        document_type,
+    };
+
+    drive
+        .add_document_for_contract(
+            info,
+            true,
+            BlockInfo::default(),
+            true,
+            None,
+            platform_version,
+        )
+        .expect("should add document");
+
+    // Query operands are normalized the same way as stored values
+
+    for (operator, value) in [("==", "ALICE"), ("startsWith", "al")] {
+        let query_cbor = cbor!({
+            "where" => [
+                ["label", operator, value]
+            ],
+            "orderBy" => [
+                ["label", "asc"]
+            ],
+        })
+        .expect("should create cbor");
+
+        let query_bytes = cbor_serializer::serializable_value_to_cbor(&query_cbor, None)
+            .expect("should serialize cbor value to bytes");
+
+        let query = DriveQuery::from_cbor(
+            &query_bytes,
+            &contract,
+            document_type,
+            &DriveConfig::default(),
+        )
+        .expect("should create a query from cbor");
+
+        let query_result = drive
+            .query_documents(
+                query,
+                None,
+                false,
+                None,
+                Some(platform_version.protocol_version),
+            )
+            .expect("should query documents");
+
+        assert_eq!(query_result.documents().len(), 1);
+    }
+
+    // Values only differing in case conflict in the unique index
+
+    let duplicate_document = document_type
+        .create_document_from_data(
+            platform_value!({ "label": "aLiCe" }),
+            Identifier::random(),
+            random(),
+            platform_version,
+        )
+        .expect("should create document");
+
+    let validation_result = drive
+        .validate_document_uniqueness(
+            &contract,
+            document_type,
+            &duplicate_document,
+            Identifier::random(),
+            false,
+            None,
+            platform_version,
+        )
+        .expect("should validate uniqueness");
+
+    assert!(!validation_result.is_valid());
+}
+
 #[cfg(feature = "full")]
 #[test]
 #[ignore]
@@ -1,4 +1,4 @@
-use crate::version::{FeatureVersion, FeatureVersionBounds};
+use crate::version::{FeatureVersion, FeatureVersionBounds, OptionalFeatureVersion};
 
 #[derive(Clone, Debug, Default)]
 pub struct DPPVersion {
@@ -62,6 +62,15 @@ pub struct JsonSchemaValidatorVersions {
     pub new: FeatureVersion,
     pub validate: FeatureVersion,
     pub compile: FeatureVersion,
+    pub custom_keywords: JsonSchemaCustomKeywordVersions,
+}
+
+/// Versions of platform specific JSON Schema keywords,
+/// `None` if the keyword isn't supported in the platform version
+#[derive(Clone, Debug, Default)]
+pub struct JsonSchemaCustomKeywordVersions {
+    pub byte_array: OptionalFeatureVersion,
+    pub transient: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -93,6 +102,7 @@ pub struct StateTransitionSerializationVersions {
     pub identity_top_up_state_transition: FeatureVersionBounds,
     pub identity_credit_withdrawal_state_transition: FeatureVersionBounds,
     pub identity_credit_transfer_state_transition: FeatureVersionBounds,
+    pub masternode_vote_state_transition: FeatureVersionBounds,
     pub contract_create_state_transition: FeatureVersionBounds,
     pub contract_update_state_transition: FeatureVersionBounds,
     pub documents_batch_state_transition: FeatureVersionBounds,
@@ -161,6 +171,7 @@ pub struct DocumentTypeSchemaVersions {
     pub enrich_with_base_schema: FeatureVersion,
     pub find_identifier_and_binary_paths: FeatureVersion,
     pub validate_max_depth: FeatureVersion,
+    pub document_meta_schema: FeatureVersion,
     pub recursive_schema_validator_versions: RecursiveSchemaValidatorVersions,
     pub validate_schema_compatibility: FeatureVersion,
 }
@@ -170,6 +181,7 @@ pub struct RecursiveSchemaValidatorVersions {
     pub traversal_validator: FeatureVersion,
     pub byte_array_has_no_items_as_parent_validator: FeatureVersion,
     pub pattern_is_valid_regex_validator: FeatureVersion,
+    pub custom_keywords_validator: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -5,6 +5,11 @@ pub struct DriveAbciVersion {
     pub structs: DriveAbciStructureVersions,
     pub methods: DriveAbciMethodVersions,
     pub validation_and_processing: DriveAbciValidationVersions,
+    pub validator_set_rotation: DriveAbciValidatorSetRotationParameters,
+    pub dpns: DriveAbciDpnsParameters,
+    pub withdrawal_limits: DriveAbciWithdrawalLimitParameters,
+    pub voting: DriveAbciVotingParameters,
+    pub document_fees: DriveAbciDocumentFeeParameters,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -68,6 +73,7 @@ pub struct DriveAbciValidationDataTriggerVersions {
     pub create_masternode_reward_shares_data_trigger: FeatureVersion,
     pub delete_withdrawal_data_trigger: FeatureVersion,
     pub reject_data_trigger: FeatureVersion,
+    pub renew_domain_data_trigger: FeatureVersion,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -86,6 +92,8 @@ pub struct DriveAbciStateTransitionValidationVersions {
     pub identity_top_up_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_withdrawal_state_transition: DriveAbciStateTransitionValidationVersion,
     pub identity_credit_transfer_state_transition: DriveAbciStateTransitionValidationVersion,
+    /// Masternode vote state transitions are rejected when not set
+    pub masternode_vote_state_transition: Option<DriveAbciStateTransitionValidationVersion>,
     pub contract_create_state_transition: DriveAbciStateTransitionValidationVersion,
     pub contract_update_state_transition: DriveAbciStateTransitionValidationVersion,
     pub documents_batch_state_transition: DriveAbciDocumentsStateTransitionValidationVersions,
@@ -93,10 +101,12 @@ pub struct DriveAbciStateTransitionValidationVersions {
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct DriveAbciStateTransitionCommonValidationVersions {
+    pub validate_data_contract_fee_beneficiaries_exist_in_state: OptionalFeatureVersion,
     pub validate_identity_public_key_contract_bounds: FeatureVersion,
     pub validate_identity_public_key_ids_dont_exist_in_state: FeatureVersion,
     pub validate_identity_public_key_ids_exist_in_state: FeatureVersion,
     pub validate_state_transition_identity_signed: FeatureVersion,
+    pub validate_state_transition_sponsor_signed: FeatureVersion,
     pub validate_unique_identity_public_key_hashes_in_state: FeatureVersion,
 }
 
@@ -138,6 +148,7 @@ pub struct DriveAbciMasternodeIdentitiesUpdatesMethodVersions {
 pub struct DriveAbciInitializationMethodVersions {
     pub initial_core_height: FeatureVersion,
     pub create_genesis_state: FeatureVersion,
+    pub import_state_archive: FeatureVersion,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -176,6 +187,67 @@ pub struct DriveAbciBlockEndMethodVersions {
     pub store_ephemeral_state: FeatureVersion,
     pub update_state_cache: FeatureVersion,
     pub validator_set_update: FeatureVersion,
+    pub validator_set_health: FeatureVersion,
+    pub clean_up_expired_dpns_documents: OptionalFeatureVersion,
+    pub resolve_ended_contested_resources: OptionalFeatureVersion,
+    pub verify_credit_supply: OptionalFeatureVersion,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DriveAbciValidatorSetRotationParameters {
+    /// The validator set is rotated early when the percentage of its healthy members
+    /// drops below this value
+    pub minimum_health_percentage: u8,
+    /// Members that haven't proposed a block in the current epoch are counted as inactive
+    /// once the members of the set proposed this many blocks per member in the epoch
+    pub inactive_proposer_blocks_per_member: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DriveAbciDpnsParameters {
+    /// Domains expire this long after they were registered or last renewed
+    pub registration_period_ms: u64,
+    /// Expired domains can still be renewed by their owner during this period,
+    /// afterwards they are released
+    pub grace_period_ms: u64,
+    /// Preorders that weren't used to register a domain within this time are purged
+    pub preorder_lifetime_ms: u64,
+    /// The maximum number of released domains and purged preorders per block
+    pub max_cleaned_up_documents_per_block: u16,
+    /// Registration and renewal fees in credits by maximum label length ordered by length,
+    /// the last fee applies to all longer labels, domains are free when empty
+    pub registration_fee_by_label_length: &'static [(u8, u64)],
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DriveAbciWithdrawalLimitParameters {
+    /// The maximum amount of credits pooled into withdrawal transactions in a single block,
+    /// in basis points of the total credits in the system
+    pub max_per_block_basis_points: u16,
+    /// The maximum amount of credits pooled into withdrawal transactions within
+    /// the rolling window, in basis points of the total credits in the system
+    pub max_per_window_basis_points: u16,
+    /// The length of the rolling window
+    pub window_ms: u64,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DriveAbciVotingParameters {
+    /// Masternodes can vote on a contested resource for this long after the first contender
+    /// was submitted
+    pub voting_period_ms: u64,
+    /// The maximum number of contested resources awarded to their winners per block
+    pub max_resolved_contests_per_block: u16,
+    /// How many times a masternode can change its vote on a contested resource,
+    /// votes are free so changes are unlimited when not set
+    pub max_vote_changes_per_poll: Option<u16>,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DriveAbciDocumentFeeParameters {
+    /// The share of document fees declared by data contracts that goes to the epoch pools
+    /// instead of the fee beneficiary, in basis points
+    pub platform_cut_basis_points: u16,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -190,10 +262,12 @@ pub struct DriveAbciIdentityCreditWithdrawalMethodVersions {
 #[derive(Clone, Copy, Debug, Default)]
 pub struct DriveAbciProtocolUpgradeMethodVersions {
     pub check_for_desired_protocol_upgrade: FeatureVersion,
+    pub perform_events_on_first_block_of_protocol_change: OptionalFeatureVersion,
 }
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct DriveAbciStateTransitionProcessingMethodVersions {
+    pub estimate_state_transition_fee: FeatureVersion,
     pub execute_event: FeatureVersion,
     pub process_raw_state_transitions: FeatureVersion,
     pub validate_fees_of_event: FeatureVersion,
@@ -30,6 +30,20 @@ pub struct DriveMethodVersions {
     pub operations: DriveOperationsMethodVersion,
     pub batch_operations: DriveBatchOperationsMethodVersion,
     pub prove: DriveProveMethodVersions,
+    pub state_archive: DriveStateArchiveMethodVersions,
+    pub storage_flags: DriveStorageFlagsMethodVersions,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveStorageFlagsMethodVersions {
+    pub compact_storage_flags: FeatureVersion,
+    pub storage_flags_size_report: FeatureVersion,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DriveStateArchiveMethodVersions {
+    pub export_state_archive: FeatureVersion,
+    pub import_state_archive: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -43,12 +57,14 @@ pub struct DriveVerifyMethodVersions {
     pub document: DriveVerifyDocumentMethodVersions,
     pub identity: DriveVerifyIdentityMethodVersions,
     pub single_document: DriveVerifySingleDocumentMethodVersions,
+    pub votes: DriveVerifyVoteMethodVersions,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveVerifyContractMethodVersions {
     pub verify_contract: FeatureVersion,
     pub verify_contract_history: FeatureVersion,
+    pub verify_contract_at_time: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -56,6 +72,7 @@ pub struct DriveVerifyDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
     pub verify_proof_keep_serialized: FeatureVersion,
     pub verify_start_at_document_in_proof: FeatureVersion,
+    pub verify_document_history: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -70,6 +87,11 @@ pub struct DriveVerifyIdentityMethodVersions {
     pub verify_identity_keys_by_identity_id: FeatureVersion,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct DriveVerifyVoteMethodVersions {
+    pub verify_contested_resource_vote_tally: FeatureVersion,
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct DriveVerifySingleDocumentMethodVersions {
     pub verify_proof: FeatureVersion,
@@ -91,6 +113,9 @@ pub struct DriveBalancesMethodVersions {
     pub remove_from_system_credits: FeatureVersion,
     pub remove_from_system_credits_operations: FeatureVersion,
     pub calculate_total_credits_balance: FeatureVersion,
+    pub calculate_credit_supply: FeatureVersion,
+    pub add_to_credit_supply_counter_operations: OptionalFeatureVersion,
+    pub create_credit_supply_counters: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -119,6 +144,7 @@ pub struct DriveContractMethodVersions {
 pub struct DriveContractProveMethodVersions {
     pub prove_contract: FeatureVersion,
     pub prove_contract_history: FeatureVersion,
+    pub prove_contract_at_time: FeatureVersion,
     pub prove_contracts: FeatureVersion,
 }
 
@@ -137,12 +163,14 @@ pub struct DriveContractInsertMethodVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractUpdateMethodVersions {
     pub update_contract: FeatureVersion,
+    pub update_contract_and_reinsert_documents: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractGetMethodVersions {
     pub fetch_contract: FeatureVersion,
     pub fetch_contract_with_history: FeatureVersion,
+    pub fetch_contract_at_time: FeatureVersion,
     pub get_cached_contract_with_fetch_info: FeatureVersion,
     pub get_contract_with_fetch_info: FeatureVersion,
     pub get_contracts_with_fetch_info: FeatureVersion,
@@ -159,6 +187,7 @@ pub struct DriveContractQueryMethodVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveContractCostsMethodVersions {
     pub add_estimation_costs_for_contract_insertion: FeatureVersion,
+    pub lint_data_contract: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -182,6 +211,7 @@ pub struct DriveOperationsMethodVersion {
 pub struct DriveBatchOperationsMethodVersion {
     pub convert_drive_operations_to_grove_operations: FeatureVersion,
     pub apply_drive_operations: FeatureVersion,
+    pub identity_credit_withdrawal_transition_operations: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -210,6 +240,8 @@ pub struct DriveDocumentMethodVersions {
 #[derive(Clone, Debug, Default)]
 pub struct DriveDocumentQueryMethodVersions {
     pub query_documents: FeatureVersion,
+    pub fetch_document_history: FeatureVersion,
+    pub prove_document_history: FeatureVersion,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -4,16 +4,17 @@ use crate::version::dpp_versions::{
     DocumentTransitionVersions, DocumentTypeClassMethodVersions, DocumentTypeIndexVersions,
     DocumentTypeMethodVersions, DocumentTypeSchemaVersions, DocumentTypeVersions, DocumentVersions,
     DocumentsBatchTransitionValidationVersions, DocumentsBatchTransitionVersions,
-    IdentityKeyTypeMethodVersions, IdentityVersions, JsonSchemaValidatorVersions,
-    PublicKeyInCreationMethodVersions, RecursiveSchemaValidatorVersions,
-    StateTransitionConversionVersions, StateTransitionMethodVersions,
-    StateTransitionSerializationVersions, StateTransitionVersions,
+    IdentityKeyTypeMethodVersions, IdentityVersions, JsonSchemaCustomKeywordVersions,
+    JsonSchemaValidatorVersions, PublicKeyInCreationMethodVersions,
+    RecursiveSchemaValidatorVersions, StateTransitionConversionVersions,
+    StateTransitionMethodVersions, StateTransitionSerializationVersions, StateTransitionVersions,
 };
 use crate::version::drive_abci_versions::{
     DriveAbciBlockEndMethodVersions, DriveAbciBlockFeeProcessingMethodVersions,
     DriveAbciCoreBasedUpdatesMethodVersions, DriveAbciCoreSubsidyMethodVersions,
-    DriveAbciDocumentsStateTransitionValidationVersions, DriveAbciEngineMethodVersions,
-    DriveAbciEpochMethodVersions, DriveAbciFeePoolInwardsDistributionMethodVersions,
+    DriveAbciDocumentFeeParameters, DriveAbciDocumentsStateTransitionValidationVersions,
+    DriveAbciDpnsParameters, DriveAbciEngineMethodVersions, DriveAbciEpochMethodVersions,
+    DriveAbciFeePoolInwardsDistributionMethodVersions,
     DriveAbciFeePoolOutwardsDistributionMethodVersions,
     DriveAbciIdentityCreditWithdrawalMethodVersions, DriveAbciInitializationMethodVersions,
     DriveAbciMasternodeIdentitiesUpdatesMethodVersions, DriveAbciMethodVersions,
@@ -21,7 +22,9 @@ use crate::version::drive_abci_versions::{
     DriveAbciStateTransitionProcessingMethodVersions, DriveAbciStateTransitionValidationVersion,
     DriveAbciStateTransitionValidationVersions, DriveAbciStructureVersions,
     DriveAbciValidationDataTriggerAndBindingVersions, DriveAbciValidationDataTriggerVersions,
-    DriveAbciValidationVersions, DriveAbciVersion, DriveAbciWithdrawalsMethodVersions,
+    DriveAbciValidationVersions, DriveAbciValidatorSetRotationParameters, DriveAbciVersion,
+    DriveAbciVotingParameters, DriveAbciWithdrawalLimitParameters,
+    DriveAbciWithdrawalsMethodVersions,
 };
 use crate::version::drive_versions::{
     DriveAssetLockMethodVersions, DriveBalancesMethodVersions, DriveBatchOperationsMethodVersion,
@@ -47,14 +50,17 @@ use crate::version::drive_versions::{
     DriveIdentityProveMethodVersions, DriveIdentityUpdateMethodVersions,
     DriveInitializationMethodVersions, DriveMethodVersions, DriveOperationsMethodVersion,
     DrivePlatformSystemMethodVersions, DriveProtocolUpgradeVersions, DriveProveMethodVersions,
-    DriveStructureVersion, DriveSystemEstimationCostsMethodVersions,
-    DriveSystemProtocolVersionMethodVersions, DriveVerifyContractMethodVersions,
-    DriveVerifyDocumentMethodVersions, DriveVerifyIdentityMethodVersions,
-    DriveVerifyMethodVersions, DriveVerifySingleDocumentMethodVersions, DriveVersion,
+    DriveStateArchiveMethodVersions, DriveStorageFlagsMethodVersions, DriveStructureVersion,
+    DriveSystemEstimationCostsMethodVersions, DriveSystemProtocolVersionMethodVersions,
+    DriveVerifyContractMethodVersions, DriveVerifyDocumentMethodVersions,
+    DriveVerifyIdentityMethodVersions, DriveVerifyMethodVersions,
+    DriveVerifySingleDocumentMethodVersions, DriveVerifyVoteMethodVersions, DriveVersion,
 };
 use crate::version::mocks::TEST_BYTES;
 use crate::version::protocol_version::{FeatureVersionBounds, PlatformVersion};
-use crate::version::{AbciStructureVersion, PlatformArchitectureVersion};
+use crate::version::{
+    AbciStructureVersion, PlatformArchitectureVersion, SystemDataContractVersions,
+};
 
 pub const TEST_PROTOCOL_VERSION_3: u32 = (1 << TEST_BYTES) + 3;
 
@@ -127,15 +133,30 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 update_validator_proposed_app_version: 0,
             },
             prove: DriveProveMethodVersions { prove_multiple: 0 },
+            state_archive: DriveStateArchiveMethodVersions {
+                export_state_archive: 0,
+                import_state_archive: 0,
+            },
+            storage_flags: DriveStorageFlagsMethodVersions {
+                compact_storage_flags: 0,
+                storage_flags_size_report: 0,
+            },
             balances: DriveBalancesMethodVersions {
                 add_to_system_credits: 0,
                 add_to_system_credits_operations: 0,
                 remove_from_system_credits: 0,
                 remove_from_system_credits_operations: 0,
                 calculate_total_credits_balance: 0,
+                calculate_credit_supply: 0,
+                add_to_credit_supply_counter_operations: None,
+                create_credit_supply_counters: 0,
             },
             document: DriveDocumentMethodVersions {
-                query: DriveDocumentQueryMethodVersions { query_documents: 0 },
+                query: DriveDocumentQueryMethodVersions {
+                    query_documents: 0,
+                    fetch_document_history: 0,
+                    prove_document_history: 0,
+                },
                 delete: DriveDocumentDeleteMethodVersions {
                     add_estimation_costs_for_remove_document_to_primary_storage: 0,
                     delete_document_for_contract: 0,
@@ -183,6 +204,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 prove: DriveContractProveMethodVersions {
                     prove_contract: 0,
                     prove_contract_history: 0,
+                    prove_contract_at_time: 0,
                     prove_contracts: 0,
                 },
                 apply: DriveContractApplyMethodVersions {
@@ -193,13 +215,18 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                     add_contract_to_storage: 0,
                     insert_contract: 0,
                 },
-                update: DriveContractUpdateMethodVersions { update_contract: 0 },
+                update: DriveContractUpdateMethodVersions {
+                    update_contract: 0,
+                    update_contract_and_reinsert_documents: 0,
+                },
                 costs: DriveContractCostsMethodVersions {
                     add_estimation_costs_for_contract_insertion: 0,
+                    lint_data_contract: 0,
                 },
                 get: DriveContractGetMethodVersions {
                     fetch_contract: 0,
                     fetch_contract_with_history: 0,
+                    fetch_contract_at_time: 0,
                     get_cached_contract_with_fetch_info: 0,
                     get_contract_with_fetch_info: 0,
                     get_contracts_with_fetch_info: 0,
@@ -219,11 +246,13 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 contract: DriveVerifyContractMethodVersions {
                     verify_contract: 0,
                     verify_contract_history: 0,
+                    verify_contract_at_time: 0,
                 },
                 document: DriveVerifyDocumentMethodVersions {
                     verify_proof: 0,
                     verify_proof_keep_serialized: 0,
                     verify_start_at_document_in_proof: 0,
+                    verify_document_history: 0,
                 },
                 identity: DriveVerifyIdentityMethodVersions {
                     verify_full_identities_by_public_key_hashes: 0,
@@ -239,6 +268,9 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                     verify_proof: 0,
                     verify_proof_keep_serialized: 0,
                 },
+                votes: DriveVerifyVoteMethodVersions {
+                    verify_contested_resource_vote_tally: 0,
+                },
             },
             identity: DriveIdentityMethodVersions {
                 fetch: DriveIdentityFetchMethodVersions {
@@ -366,6 +398,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
             batch_operations: DriveBatchOperationsMethodVersion {
                 convert_drive_operations_to_grove_operations: 0,
                 apply_drive_operations: 0,
+                identity_credit_withdrawal_transition_operations: 0,
             },
         },
         grove_methods: DriveGroveMethodVersions {
@@ -441,6 +474,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
             initialization: DriveAbciInitializationMethodVersions {
                 initial_core_height: 0,
                 create_genesis_state: 0,
+                import_state_archive: 0,
             },
             core_based_updates: DriveAbciCoreBasedUpdatesMethodVersions {
                 update_core_info: 0,
@@ -465,6 +499,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
             },
             protocol_upgrade: DriveAbciProtocolUpgradeMethodVersions {
                 check_for_desired_protocol_upgrade: 0,
+                perform_events_on_first_block_of_protocol_change: None,
             },
             block_fee_processing: DriveAbciBlockFeeProcessingMethodVersions {
                 add_process_epoch_change_operations: 0,
@@ -491,6 +526,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 update_broadcasted_withdrawal_transaction_statuses: 0,
             },
             state_transition_processing: DriveAbciStateTransitionProcessingMethodVersions {
+                estimate_state_transition_fee: 0,
                 execute_event: 0,
                 process_raw_state_transitions: 0,
                 validate_fees_of_event: 0,
@@ -506,15 +542,21 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 store_ephemeral_state: 0,
                 update_state_cache: 0,
                 validator_set_update: 0,
+                validator_set_health: 0,
+                clean_up_expired_dpns_documents: None,
+                resolve_ended_contested_resources: None,
+                verify_credit_supply: None,
             },
         },
         validation_and_processing: DriveAbciValidationVersions {
             state_transitions: DriveAbciStateTransitionValidationVersions {
                 common_validation_methods: DriveAbciStateTransitionCommonValidationVersions {
+                    validate_data_contract_fee_beneficiaries_exist_in_state: None,
                     validate_identity_public_key_contract_bounds: 0,
                     validate_identity_public_key_ids_dont_exist_in_state: 0,
                     validate_identity_public_key_ids_exist_in_state: 0,
                     validate_state_transition_identity_signed: 0,
+                    validate_state_transition_sponsor_signed: 0,
                     validate_unique_identity_public_key_hashes_in_state: 0,
                 },
                 identity_create_state_transition: DriveAbciStateTransitionValidationVersion {
@@ -549,6 +591,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                         state: 0,
                         transform_into_action: 0,
                     },
+                masternode_vote_state_transition: None,
                 contract_create_state_transition: DriveAbciStateTransitionValidationVersion {
                     structure: 0,
                     identity_signatures: None,
@@ -576,6 +619,7 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                                 create_masternode_reward_shares_data_trigger: 0,
                                 delete_withdrawal_data_trigger: 0,
                                 reject_data_trigger: 0,
+                                renew_domain_data_trigger: 0,
                             },
                         },
                         document_create_transition_structure_validation: 0,
@@ -588,6 +632,30 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
             },
             process_state_transition: 0,
         },
+        validator_set_rotation: DriveAbciValidatorSetRotationParameters {
+            minimum_health_percentage: 67,
+            inactive_proposer_blocks_per_member: 8,
+        },
+        dpns: DriveAbciDpnsParameters {
+            registration_period_ms: 31_536_000_000, // 365 days
+            grace_period_ms: 2_592_000_000,         // 30 days
+            preorder_lifetime_ms: 86_400_000,       // 1 day
+            max_cleaned_up_documents_per_block: 100,
+            registration_fee_by_label_length: &[],
+        },
+        withdrawal_limits: DriveAbciWithdrawalLimitParameters {
+            max_per_block_basis_points: 10,   // 0.1%
+            max_per_window_basis_points: 100, // 1%
+            window_ms: 86_400_000,            // 1 day
+        },
+        voting: DriveAbciVotingParameters {
+            voting_period_ms: 1_209_600_000, // 14 days
+            max_resolved_contests_per_block: 20,
+            max_vote_changes_per_poll: None,
+        },
+        document_fees: DriveAbciDocumentFeeParameters {
+            platform_cut_basis_points: 1_000, // 10%
+        },
     },
     dpp: DPPVersion {
         costs: CostVersions {
@@ -599,6 +667,10 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 new: 0,
                 validate: 0,
                 compile: 0,
+                custom_keywords: JsonSchemaCustomKeywordVersions {
+                    byte_array: None,
+                    transient: None,
+                },
             },
             data_contract: DataContractValidationVersions {
                 validate: 0,
@@ -640,6 +712,11 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                 max_version: 0,
                 default_current_version: 0,
             },
+            masternode_vote_state_transition: FeatureVersionBounds {
+                min_version: 0,
+                max_version: 0,
+                default_current_version: 0,
+            },
             contract_create_state_transition: FeatureVersionBounds {
                 min_version: 0,
                 max_version: 0,
@@ -732,10 +809,12 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
                     enrich_with_base_schema: 0,
                     find_identifier_and_binary_paths: 0,
                     validate_max_depth: 0,
+                    document_meta_schema: 0,
                     recursive_schema_validator_versions: RecursiveSchemaValidatorVersions {
                         traversal_validator: 0,
                         byte_array_has_no_items_as_parent_validator: 0,
                         pattern_is_valid_regex_validator: 0,
+                        custom_keywords_validator: 0,
                     },
                     validate_schema_compatibility: 0,
                 },
@@ -782,4 +861,11 @@ pub(crate) const TEST_PLATFORM_V3: PlatformVersion = PlatformVersion {
             },
         },
     },
+    system_data_contracts: SystemDataContractVersions {
+        withdrawals: 1,
+        dpns: 1,
+        dashpay: 1,
+        masternode_reward_shares: 1,
+        feature_flags: 1,
+    },
 };
@@ -71,7 +71,8 @@ use crate::errors::consensus::basic::state_transition::{
 };
 use crate::errors::consensus::signature::{
     BasicBLSErrorWasm, BasicECDSAErrorWasm, IdentityNotFoundErrorWasm,
-    SignatureShouldNotBePresentErrorWasm,
+    PublicKeyContractBoundsNotMetErrorWasm, SignatureShouldNotBePresentErrorWasm,
+    SponsorPublicKeyNotContractBoundErrorWasm,
 };
 // use crate::errors::consensus::state::data_contract::data_trigger::{
 //     DataTriggerConditionErrorWasm, DataTriggerExecutionErrorWasm,
@@ -89,7 +90,7 @@ use crate::errors::consensus::state::identity::{
     IdentityAlreadyExistsErrorWasm, IdentityPublicKeyDisabledAtWindowViolationErrorWasm,
     IdentityPublicKeyIsDisabledErrorWasm, IdentityPublicKeyIsReadOnlyErrorWasm,
     InvalidIdentityPublicKeyIdErrorWasm, InvalidIdentityRevisionErrorWasm,
-    MaxIdentityPublicKeyLimitReachedErrorWasm,
+    MaxIdentityPublicKeyLimitReachedErrorWasm, SponsorshipSpendingLimitExceededErrorWasm,
 };
 
 use crate::errors::consensus::basic::data_contract::{
@@ -215,6 +216,9 @@ pub fn from_state_error(state_error: &StateError) -> JsValue {
         StateError::DataContractConfigUpdateError(e) => {
             DataContractConfigUpdateErrorWasm::from(e).into()
         }
+        StateError::SponsorshipSpendingLimitExceededError(e) => {
+            SponsorshipSpendingLimitExceededErrorWasm::from(e).into()
+        }
         // TODO(versioning): restore
         _ => todo!(),
     }
@@ -413,6 +417,12 @@ fn from_signature_error(signature_error: &SignatureError) -> JsValue {
         }
         SignatureError::BasicECDSAError(err) => BasicECDSAErrorWasm::from(err).into(),
         SignatureError::BasicBLSError(err) => BasicBLSErrorWasm::from(err).into(),
+        SignatureError::PublicKeyContractBoundsNotMetError(err) => {
+            PublicKeyContractBoundsNotMetErrorWasm::from(err).into()
+        }
+        SignatureError::SponsorPublicKeyNotContractBoundError(err) => {
+            SponsorPublicKeyNotContractBoundErrorWasm::from(err).into()
+        }
     }
 }
 
@@ -1,9 +1,13 @@
 mod basic_bls_error;
 mod basic_ecdsa_error;
 mod identity_not_found_error;
+mod public_key_contract_bounds_not_met_error;
 mod signature_should_not_be_present_error;
+mod sponsor_public_key_not_contract_bound_error;
 
 pub use basic_bls_error::*;
 pub use basic_ecdsa_error::*;
 pub use identity_not_found_error::*;
+pub use public_key_contract_bounds_not_met_error::*;
 pub use signature_should_not_be_present_error::*;
+pub use sponsor_public_key_not_contract_bound_error::*;
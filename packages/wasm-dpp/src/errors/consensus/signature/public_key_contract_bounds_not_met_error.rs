@@ -0,0 +1,53 @@
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::signature::PublicKeyContractBoundsNotMetError;
+use dpp::consensus::ConsensusError;
+use dpp::identity::KeyID;
+use wasm_bindgen::prelude::*;
+
+use crate::buffer::Buffer;
+use crate::identifier::IdentifierWrapper;
+use dpp::serialization::PlatformSerializableWithPlatformVersion;
+use dpp::version::PlatformVersion;
+
+#[wasm_bindgen(js_name=PublicKeyContractBoundsNotMetError)]
+pub struct PublicKeyContractBoundsNotMetErrorWasm {
+    inner: PublicKeyContractBoundsNotMetError,
+}
+
+impl From<&PublicKeyContractBoundsNotMetError> for PublicKeyContractBoundsNotMetErrorWasm {
+    fn from(e: &PublicKeyContractBoundsNotMetError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=PublicKeyContractBoundsNotMetError)]
+impl PublicKeyContractBoundsNotMetErrorWasm {
+    #[wasm_bindgen(js_name=getPublicKeyId)]
+    pub fn get_public_key_id(&self) -> KeyID {
+        self.inner.public_key_id()
+    }
+
+    #[wasm_bindgen(js_name=getDataContractId)]
+    pub fn get_data_contract_id(&self) -> IdentifierWrapper {
+        self.inner.data_contract_id().to_owned().into()
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+
+    #[wasm_bindgen(js_name=serialize)]
+    pub fn serialize(&self) -> Result<Buffer, JsError> {
+        let bytes = ConsensusError::from(self.inner.clone())
+            .serialize_to_bytes_with_platform_version(PlatformVersion::first())
+            .map_err(JsError::from)?;
+
+        Ok(Buffer::from_bytes(bytes.as_slice()))
+    }
+}
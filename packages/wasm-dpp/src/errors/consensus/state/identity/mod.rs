@@ -8,6 +8,7 @@ mod invalid_identity_public_key_id_error;
 mod invalid_identity_revision_error;
 mod max_identity_public_key_limit_reached_error;
 mod missing_identity_public_key_ids_error;
+mod sponsorship_spending_limit_exceeded_error;
 
 pub use duplicated_identity_public_key_id_state_error::*;
 pub use duplicated_identity_public_key_state_error::*;
@@ -19,3 +20,4 @@ pub use invalid_identity_public_key_id_error::*;
 pub use invalid_identity_revision_error::*;
 pub use max_identity_public_key_limit_reached_error::*;
 pub use missing_identity_public_key_ids_error::*;
+pub use sponsorship_spending_limit_exceeded_error::*;
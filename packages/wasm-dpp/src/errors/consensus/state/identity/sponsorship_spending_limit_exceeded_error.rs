@@ -0,0 +1,52 @@
+use crate::buffer::Buffer;
+use dpp::consensus::codes::ErrorWithCode;
+use dpp::consensus::state::identity::sponsorship_spending_limit_exceeded_error::SponsorshipSpendingLimitExceededError;
+use dpp::consensus::ConsensusError;
+use js_sys::Number;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name=SponsorshipSpendingLimitExceededError)]
+pub struct SponsorshipSpendingLimitExceededErrorWasm {
+    inner: SponsorshipSpendingLimitExceededError,
+}
+
+impl From<&SponsorshipSpendingLimitExceededError> for SponsorshipSpendingLimitExceededErrorWasm {
+    fn from(e: &SponsorshipSpendingLimitExceededError) -> Self {
+        Self { inner: e.clone() }
+    }
+}
+
+#[wasm_bindgen(js_class=SponsorshipSpendingLimitExceededError)]
+impl SponsorshipSpendingLimitExceededErrorWasm {
+    #[wasm_bindgen(js_name=getSponsorId)]
+    pub fn sponsor_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.sponsor_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getDataContractId)]
+    pub fn data_contract_id(&self) -> Buffer {
+        Buffer::from_bytes(self.inner.data_contract_id().as_bytes())
+    }
+
+    #[wasm_bindgen(js_name=getRemainingSpendingLimit)]
+    pub fn remaining_spending_limit(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.remaining_spending_limit() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getRequiredAmount)]
+    pub fn required_amount(&self) -> Number {
+        // It might be overflow
+        Number::from(self.inner.required_amount() as f64)
+    }
+
+    #[wasm_bindgen(js_name=getCode)]
+    pub fn get_code(&self) -> u32 {
+        ConsensusError::from(self.inner.clone()).code()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.inner.to_string()
+    }
+}
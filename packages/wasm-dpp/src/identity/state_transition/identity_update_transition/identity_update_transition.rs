@@ -206,6 +206,7 @@ impl IdentityUpdateTransitionWasm {
 
         let version = match self.0 {
             IdentityUpdateTransition::V0(_) => "0",
+            IdentityUpdateTransition::V1(_) => "1",
         };
 
         js_sys::Reflect::set(&js_object, &"$version".to_owned().into(), &version.into())?;
@@ -300,6 +301,7 @@ impl IdentityUpdateTransitionWasm {
 
         let version = match self.0 {
             IdentityUpdateTransition::V0(_) => "0",
+            IdentityUpdateTransition::V1(_) => "1",
         };
 
         js_sys::Reflect::set(&js_object, &"$version".to_owned().into(), &version.into())?;
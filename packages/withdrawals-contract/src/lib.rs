@@ -17,6 +17,8 @@ pub mod document_types {
             pub const POOLING: &str = "pooling";
             pub const OUTPUT_SCRIPT: &str = "outputScript";
             pub const STATUS: &str = "status";
+            pub const FAILURE_REASON: &str = "failureReason";
+            pub const REFUNDED_AT: &str = "refundedAt";
             pub const CREATED_AT: &str = "$createdAt";
             pub const UPDATED_AT: &str = "$updatedAt";
             pub const OWNER_ID: &str = "$ownerId";
@@ -45,6 +47,24 @@ pub enum WithdrawalStatus {
     EXPIRED = 4,
 }
 
+// @append_only
+#[repr(u8)]
+#[derive(
+    Serialize_repr,
+    Deserialize_repr,
+    PartialEq,
+    Eq,
+    Clone,
+    Copy,
+    Debug,
+    TryFromPrimitive,
+    IntoPrimitive,
+)]
+pub enum WithdrawalFailureReason {
+    // Asset unlock transaction wasn't mined on Core before it expired
+    EXPIRED = 1,
+}
+
 pub const ID_BYTES: [u8; 32] = [
     54, 98, 187, 97, 225, 127, 174, 62, 162, 148, 207, 96, 49, 151, 251, 10, 171, 109, 81, 24, 11,
     216, 182, 16, 76, 73, 68, 166, 47, 226, 217, 127,